@@ -15,6 +15,7 @@
 
 pub use asset_hub_paseo_emulated_chain;
 pub use bridge_hub_paseo_emulated_chain;
+pub use bulletin_paseo_emulated_chain;
 pub use collectives_paseo_emulated_chain;
 pub use coretime_paseo_emulated_chain;
 pub use paseo_emulated_chain;
@@ -23,6 +24,7 @@ pub use people_paseo_emulated_chain;
 
 use asset_hub_paseo_emulated_chain::AssetHubPaseo;
 use bridge_hub_paseo_emulated_chain::BridgeHubPaseo;
+use bulletin_paseo_emulated_chain::BulletinPaseo;
 use collectives_paseo_emulated_chain::CollectivesPaseo;
 use coretime_paseo_emulated_chain::CoretimePaseo;
 use paseo_emulated_chain::Paseo;
@@ -41,6 +43,7 @@ decl_test_networks! {
 		parachains = vec![
 			AssetHubPaseo,
 			BridgeHubPaseo,
+			BulletinPaseo,
 			CollectivesPaseo,
 			CoretimePaseo,
 			PenpalA,
@@ -55,6 +58,7 @@ decl_test_sender_receiver_accounts_parameter_types! {
 	PaseoRelay { sender: ALICE, receiver: BOB },
 	AssetHubPaseoPara { sender: ALICE, receiver: BOB },
 	BridgeHubPaseoPara { sender: ALICE, receiver: BOB },
+	BulletinPaseoPara { sender: ALICE, receiver: BOB },
 	CollectivesPaseoPara { sender: ALICE, receiver: BOB },
 	CoretimePaseoPara { sender: ALICE, receiver: BOB },
 	PenpalAPara { sender: ALICE, receiver: BOB },
@@ -45,6 +45,7 @@ pub use paseo_system_emulated_network::{
 		genesis::ED as ASSET_HUB_POLKADOT_ED, AssetHubPaseoParaPallet as AssetHubPaseoPallet,
 	},
 	bridge_hub_paseo_emulated_chain::BridgeHubPaseoParaPallet as BridgeHubPaseoPallet,
+	bulletin_paseo_emulated_chain::BulletinPaseoParaPallet as BulletinPaseoPallet,
 	collectives_paseo_emulated_chain::CollectivesPaseoParaPallet as CollectivesPaseoPallet,
 	coretime_paseo_emulated_chain::CoretimePaseoParaPallet as CoretimePaseoPallet,
 	paseo_emulated_chain::{genesis::ED as POLKADOT_ED, PaseoRelayPallet as PaseoPallet},
@@ -54,6 +55,7 @@ pub use paseo_system_emulated_network::{
 	},
 	AssetHubPaseoPara as AssetHubPaseo, AssetHubPaseoParaReceiver as AssetHubPaseoReceiver,
 	AssetHubPaseoParaSender as AssetHubPaseoSender, BridgeHubPaseoPara as BridgeHubPaseo,
+	BulletinPaseoPara as BulletinPaseo, BulletinPaseoParaReceiver as BulletinPaseoReceiver,
 	CollectivesPaseoPara as CollectivesPaseo, CoretimePaseoPara as CoretimePaseo,
 	PaseoRelay as Paseo, PaseoRelayReceiver as PaseoReceiver, PaseoRelaySender as PaseoSender,
 	PenpalAPara as PenpalA, PeoplePaseoPara as PeoplePaseo,
@@ -0,0 +1,165 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::*;
+use indiv_pallet_resources::{
+	types::{
+		LongTermStorageAllocation, LongTermStorageAuthorization, LongTermStorageAuthorizationStatus,
+		LongTermStorageRequest, MembershipCollection,
+	},
+	LongTermStorageAuthorizations, Origin as ResourcesOrigin, PendingLongTermStorageAcks,
+};
+
+type PeopleRuntime = <PeoplePaseo as Chain>::Runtime;
+type PeopleRuntimeEvent = <PeoplePaseo as Chain>::RuntimeEvent;
+type PeopleRuntimeOrigin = <PeoplePaseo as Chain>::RuntimeOrigin;
+type BulletinRuntime = <BulletinPaseo as Chain>::Runtime;
+type BulletinRuntimeEvent = <BulletinPaseo as Chain>::RuntimeEvent;
+
+fn is_authorized_on_bulletin(who: &AccountId) -> bool {
+	BulletinPaseo::execute_with(|| {
+		pallet_bulletin_transaction_storage::Pallet::<BulletinRuntime>::account_authorization(
+			who.clone(),
+		)
+		.is_some()
+	})
+}
+
+fn assert_bulletin_processed(success: bool) {
+	BulletinPaseo::execute_with(|| {
+		assert_expected_events!(
+			BulletinPaseo,
+			vec![
+				BulletinRuntimeEvent::MessageQueue(
+					pallet_message_queue::Event::Processed { success: s, .. }
+				) => { s: *s == success, },
+			]
+		);
+	});
+}
+
+#[test]
+fn long_term_storage_claim_is_authorized_and_acknowledged_by_bulletin() {
+	let account = BulletinPaseoReceiver::get();
+	assert!(!is_authorized_on_bulletin(&account));
+
+	PeoplePaseo::execute_with(|| {
+		let origin = PeopleRuntimeOrigin::from(ResourcesOrigin::LongTermStorageClaim(
+			[7u8; 32],
+			MembershipCollection::People,
+		));
+		assert_ok!(indiv_pallet_resources::Pallet::<PeopleRuntime>::claim_long_term_storage(
+			origin,
+			0,
+			0,
+			account.clone(),
+		));
+
+		let authorization = LongTermStorageAuthorizations::<PeopleRuntime>::get(&account)
+			.expect("claim records the authorization; qed");
+		assert_eq!(authorization.status, LongTermStorageAuthorizationStatus::Pending);
+		assert!(authorization.pending_ack.is_some());
+		assert_expected_events!(
+			PeoplePaseo,
+			vec![
+				PeopleRuntimeEvent::Resources(
+					indiv_pallet_resources::Event::LongTermStorageClaimed { .. }
+				) => {},
+			]
+		);
+	});
+
+	// Bulletin applies the authorization and reports the outcome back to People.
+	assert_bulletin_processed(true);
+	assert!(is_authorized_on_bulletin(&account));
+
+	PeoplePaseo::execute_with(|| {
+		assert_expected_events!(
+			PeoplePaseo,
+			vec![
+				PeopleRuntimeEvent::Resources(
+					indiv_pallet_resources::Event::LongTermStorageAcknowledged {
+						account: who,
+						accepted: true,
+					}
+				) => { who: *who == account, },
+			]
+		);
+		let authorization = LongTermStorageAuthorizations::<PeopleRuntime>::get(&account)
+			.expect("acknowledgement keeps the authorization; qed");
+		assert_eq!(authorization.status, LongTermStorageAuthorizationStatus::Confirmed);
+		assert_eq!(authorization.pending_ack, None);
+		assert!(PendingLongTermStorageAcks::<PeopleRuntime>::iter().next().is_none());
+	});
+}
+
+#[test]
+fn failed_bulletin_refresh_is_reported_as_rejected() {
+	// People believes the account is authorized, but Bulletin has no authorization to refresh.
+	let account = BulletinPaseoReceiver::get();
+	assert!(!is_authorized_on_bulletin(&account));
+
+	PeoplePaseo::execute_with(|| {
+		LongTermStorageAuthorizations::<PeopleRuntime>::insert(
+			&account,
+			LongTermStorageAuthorization {
+				transactions: 1,
+				bytes: 1024,
+				expires_at: 60 * 60,
+				refreshes: 0,
+				status: LongTermStorageAuthorizationStatus::Confirmed,
+				pending_ack: None,
+				request: LongTermStorageRequest::Authorize(LongTermStorageAllocation {
+					transactions: 1,
+					bytes: 1024,
+				}),
+				requested_at: 0,
+			},
+		);
+		assert_ok!(
+			indiv_pallet_resources::Pallet::<PeopleRuntime>::refresh_long_term_storage_authorization(
+				frame_system::RawOrigin::Authorized.into(),
+				account.clone(),
+			)
+		);
+
+		let authorization = LongTermStorageAuthorizations::<PeopleRuntime>::get(&account)
+			.expect("refresh keeps the authorization; qed");
+		assert_eq!(authorization.status, LongTermStorageAuthorizationStatus::Pending);
+		assert_eq!(authorization.refreshes, 1);
+	});
+
+	// The refresh itself fails on Bulletin, but the message is still processed.
+	assert_bulletin_processed(true);
+	assert!(!is_authorized_on_bulletin(&account));
+
+	PeoplePaseo::execute_with(|| {
+		assert_expected_events!(
+			PeoplePaseo,
+			vec![
+				PeopleRuntimeEvent::Resources(
+					indiv_pallet_resources::Event::LongTermStorageAcknowledged {
+						account: who,
+						accepted: false,
+					}
+				) => { who: *who == account, },
+			]
+		);
+		assert_eq!(
+			LongTermStorageAuthorizations::<PeopleRuntime>::get(&account).map(|a| a.status),
+			Some(LongTermStorageAuthorizationStatus::Rejected)
+		);
+	});
+}
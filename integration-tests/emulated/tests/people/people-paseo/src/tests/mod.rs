@@ -13,4 +13,5 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod bulletin_storage;
 mod reserve_transfers;
@@ -111,6 +111,24 @@ fn create_coin<T: Config>(value: CoinValue, age: u16, seed: u32) -> T::AccountId
 	owner
 }
 
+/// Insert an open swap offer of a fresh coin of `value` asking for `ask`.
+///
+/// Returns the offer id and its receiver.
+fn create_swap_offer<T: Config>(
+	value: CoinValue,
+	ask: SwapAskOf<T>,
+	seed: u32,
+) -> (T::AccountId, T::AccountId) {
+	let offer: T::AccountId = account("offer", seed, 0);
+	let receiver: T::AccountId = account("receiver", seed, 0);
+	let expires_at = T::UnixTime::now().as_secs().saturating_add(T::MaxSwapDuration::get());
+	SwapOffers::<T>::insert(
+		&offer,
+		SwapOffer { coin: Coin { value, age: 0 }, ask, receiver: receiver.clone(), expires_at },
+	);
+	(offer, receiver)
+}
+
 /// Create a signature for proof of ownership.
 fn create_proof_of_ownership<T: Config>(
 	secret: &SecretOf<T>,
@@ -822,6 +840,195 @@ mod benches {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn merge() -> Result<(), BenchmarkError> {
+		common_setup::<T>();
+
+		let value = T::MinimumExponent::get();
+		let age = 0u16;
+		let coins: BoundedVec<(T::AccountId, Coin), T::MaxConsolidation> = vec![
+			(account("coin_owner", 0, 0), Coin { value, age }),
+			(account("coin_owner", 1, 0), Coin { value, age }),
+		]
+		.try_into()
+		.map_err(|_| BenchmarkError::Weightless)?;
+		let dest: T::AccountId = account("dest", 0, 0);
+
+		#[extrinsic_call]
+		_(Origin::Coins { coins }, dest.clone());
+
+		assert!(CoinsByOwner::<T>::contains_key(&dest));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn offer_swap() -> Result<(), BenchmarkError> {
+		common_setup::<T>();
+
+		let value = T::MinimumExponent::get();
+		let age = 0u16;
+		let coin_owner: T::AccountId = account("coin_owner", 0, 0);
+		let receiver: T::AccountId = account("receiver", 0, 0);
+
+		#[extrinsic_call]
+		_(
+			Origin::Coin { coin_id: coin_owner.clone(), coin: Coin { value, age } },
+			SwapAsk::Coin { value },
+			receiver,
+			T::MaxSwapDuration::get(),
+		);
+
+		assert!(SwapOffers::<T>::contains_key(&coin_owner));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn accept_swap_with_coin() -> Result<(), BenchmarkError> {
+		common_setup::<T>();
+
+		let value = T::MinimumExponent::get();
+		let age = 0u16;
+		let (offer, receiver) = create_swap_offer::<T>(value, SwapAsk::Coin { value }, 0);
+		let coin_owner: T::AccountId = account("coin_owner", 0, 0);
+		let dest: T::AccountId = account("dest", 0, 0);
+
+		#[extrinsic_call]
+		_(Origin::Coin { coin_id: coin_owner, coin: Coin { value, age } }, offer, dest.clone());
+
+		assert!(CoinsByOwner::<T>::contains_key(&dest));
+		assert!(CoinsByOwner::<T>::contains_key(&receiver));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn accept_swap_with_asset() -> Result<(), BenchmarkError> {
+		common_setup::<T>();
+
+		let value = T::MinimumExponent::get();
+		let amount = Pallet::<T>::coin_value_to_asset_amount(value)
+			.map_err(|_| BenchmarkError::Weightless)?;
+		let ask = SwapAsk::Asset { id: asset_id::<T>(), amount };
+		let (offer, receiver) = create_swap_offer::<T>(value, ask, 0);
+
+		// Fund the receiver too, so that receiving the amount never falls below the minimum
+		// balance of the asset.
+		let caller: T::AccountId = account("caller", 0, 0);
+		T::BenchmarkHelper::fund_account(&caller, amount.saturating_add(amount));
+		T::BenchmarkHelper::fund_account(&receiver, amount);
+		let dest: T::AccountId = account("dest", 0, 0);
+
+		#[extrinsic_call]
+		_(SystemOrigin::Signed(caller), CodecPreservation::Expendable, offer, dest.clone());
+
+		assert!(CoinsByOwner::<T>::contains_key(&dest));
+		let received = amount.saturating_add(amount);
+		assert_eq!(T::Fungibles::balance(asset_id::<T>(), &receiver), received);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_swap() -> Result<(), BenchmarkError> {
+		common_setup::<T>();
+
+		let value = T::MinimumExponent::get();
+		let (offer, _) = create_swap_offer::<T>(value, SwapAsk::Coin { value }, 0);
+		let dest: T::AccountId = account("dest", 0, 0);
+		let coin = SwapOffers::<T>::get(&offer).ok_or(BenchmarkError::Weightless)?.coin;
+
+		#[extrinsic_call]
+		_(Origin::Coin { coin_id: offer.clone(), coin }, dest.clone());
+
+		assert!(!SwapOffers::<T>::contains_key(&offer));
+		assert!(CoinsByOwner::<T>::contains_key(&dest));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn teleport_coin() -> Result<(), BenchmarkError> {
+		common_setup::<T>();
+
+		let value = T::MinimumExponent::get();
+		let age = 0u16;
+		let coin_owner: T::AccountId = account("coin_owner", 0, 0);
+		let amount = Pallet::<T>::coin_value_to_asset_amount(value)
+			.map_err(|_| BenchmarkError::Weightless)?;
+		fund_pallet_account::<T>(amount);
+		let dest = T::BenchmarkHelper::setup_teleport_destination()?;
+		let to: T::AccountId = account("dest", 0, 0);
+
+		#[extrinsic_call]
+		_(Origin::Coin { coin_id: coin_owner, coin: Coin { value, age } }, dest, to);
+
+		assert_eq!(NextTeleportId::<T>::get(), 1);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn receive_teleported_coin() -> Result<(), BenchmarkError> {
+		common_setup::<T>();
+
+		let origin = T::EnsureTeleportSource::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+		T::BenchmarkHelper::setup_teleport_destination()?;
+		let value = T::MinimumExponent::get();
+		let to: T::AccountId = account("dest", 0, 0);
+
+		// The XCM program deposits the underlying asset to the pallet account before the call,
+		// on top of the existential deposit.
+		let amount = Pallet::<T>::coin_value_to_asset_amount(value)
+			.map_err(|_| BenchmarkError::Weightless)?;
+		let extra: FungiblesBalanceOf<T> = 1000u32.into();
+		T::Fungibles::mint_into(
+			asset_id::<T>(),
+			&Pallet::<T>::pallet_account(),
+			amount.saturating_add(extra),
+		)
+		.map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, 0, to.clone(), Coin { value, age: 0 });
+
+		assert!(CoinsByOwner::<T>::contains_key(&to));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn return_teleported_coin() -> Result<(), BenchmarkError> {
+		common_setup::<T>();
+
+		let origin = T::EnsureTeleportSource::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+		T::BenchmarkHelper::setup_teleport_destination()?;
+		let value = T::MinimumExponent::get();
+		let to: T::AccountId = account("coin_owner", 0, 0);
+
+		// The XCM program deposits the underlying asset to the pallet account before the failed
+		// `receive_teleported_coin`, on top of the existential deposit.
+		let amount = Pallet::<T>::coin_value_to_asset_amount(value)
+			.map_err(|_| BenchmarkError::Weightless)?;
+		let extra: FungiblesBalanceOf<T> = 1000u32.into();
+		T::Fungibles::mint_into(
+			asset_id::<T>(),
+			&Pallet::<T>::pallet_account(),
+			amount.saturating_add(extra),
+		)
+		.map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, 0, to, Coin { value, age: 0 });
+
+		assert_eq!(NextTeleportId::<T>::get(), 1);
+
+		Ok(())
+	}
+
 	#[benchmark]
 	fn load_recycler_with_coin() -> Result<(), BenchmarkError> {
 		common_setup::<T>();
@@ -1111,6 +1318,46 @@ mod benches {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn expire_swap() -> Result<(), BenchmarkError> {
+		common_setup::<T>();
+
+		let value = T::MinimumExponent::get();
+		let (offer, receiver) = create_swap_offer::<T>(value, SwapAsk::Coin { value }, 0);
+		// Worst case: the receiver holds a coin, so the coin goes back to the offer's account.
+		CoinsByOwner::<T>::insert(&receiver, Coin { value, age: 0 });
+
+		// Advance time past expiration
+		let expires_at = SwapOffers::<T>::get(&offer).expect("offer was created").expires_at;
+		T::BenchmarkHelper::set_time(core::time::Duration::from_secs(expires_at));
+
+		#[extrinsic_call]
+		_(SystemOrigin::Authorized, offer.clone());
+
+		assert!(!SwapOffers::<T>::contains_key(&offer));
+		assert!(CoinsByOwner::<T>::contains_key(&offer));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn release_parked_coin() -> Result<(), BenchmarkError> {
+		common_setup::<T>();
+
+		let from = ParaId::from(1000);
+		let value = T::MinimumExponent::get();
+		let to: T::AccountId = account("dest", 0, 0);
+		ParkedTeleportedCoins::<T>::insert((from, 0), (to.clone(), Coin { value, age: 0 }));
+
+		#[extrinsic_call]
+		_(SystemOrigin::Authorized, from, 0);
+
+		assert!(!ParkedTeleportedCoins::<T>::contains_key((from, 0)));
+		assert!(CoinsByOwner::<T>::contains_key(&to));
+
+		Ok(())
+	}
+
 	#[benchmark]
 	fn clean_recycler_dust(
 		n: Linear<0, { pallet::DUST_CLEANUP_BATCH_SIZE }>,
@@ -2166,6 +2413,170 @@ mod benches {
 		Ok(())
 	}
 
+	/// Benchmark for AsCoins extension with merge call, merging `n` coins.
+	#[benchmark]
+	fn as_coins_merge(
+		n: Linear<2, { T::MaxConsolidation::get() }>,
+	) -> Result<(), BenchmarkError> {
+		common_setup::<T>();
+
+		// `n` coins of one or two minimum units, summing to the smallest power of two covering
+		// `n` units: `singles + 2 * (n - singles) == 2^k`.
+		let min_exp = T::MinimumExponent::get();
+		let k = 32 - (n - 1).leading_zeros();
+		let singles = (2 * n).saturating_sub(1 << k);
+		if i32::from(min_exp) + k as i32 > i32::from(T::MaximumExponent::get()) {
+			return Err(BenchmarkError::Skip);
+		}
+		let value_of = |i: u32| if i < singles { min_exp } else { min_exp.saturating_add(1) };
+
+		let age = 0u16;
+		let coin_owner = create_coin::<T>(value_of(0), age, 0);
+		let to: T::AccountId = account("dest", 0, 0);
+
+		let call = Call::<T>::merge { to };
+		let runtime_call: <T as frame_system::Config>::RuntimeCall = call.clone().into();
+		let inherited_implication = ((0u8, &runtime_call), (), ());
+		let proven_msg = sp_core::hashing::blake2_256(&inherited_implication.encode());
+		let payload = Pallet::<T>::merge_signing_payload(&proven_msg);
+
+		let mut other_coins = Vec::new();
+		for i in 1..n {
+			let (other_id, signature) = T::BenchmarkHelper::sign_merge_input(i, &payload)?;
+			CoinsByOwner::<T>::insert(&other_id, Coin { value: value_of(i), age });
+			other_coins.push((other_id, signature));
+		}
+		let other_coins = other_coins.try_into().map_err(|_| BenchmarkError::Weightless)?;
+
+		let tx_ext = AsCoinage::<T>::new(Some(AsCoinageInfo::AsCoins { other_coins }));
+		let origin = SystemOrigin::Signed(coin_owner);
+		let len = call.encode().len();
+
+		#[block]
+		{
+			tx_ext
+				.test_run(origin.into(), &call.into(), &Default::default(), len, 0, |_| {
+					Ok(Default::default())
+				})
+				.unwrap()?;
+		}
+
+		Ok(())
+	}
+
+	/// Benchmark for AsCoin extension with offer_swap call.
+	#[benchmark]
+	fn as_coin_offer_swap() -> Result<(), BenchmarkError> {
+		common_setup::<T>();
+
+		let value = T::MinimumExponent::get();
+		let age = 0u16;
+		let coin_owner = create_coin::<T>(value, age, 0);
+		let receiver: T::AccountId = account("receiver", 0, 0);
+
+		let call = Call::<T>::offer_swap {
+			ask: SwapAsk::Coin { value },
+			receiver,
+			duration: T::MaxSwapDuration::get(),
+		};
+		let tx_ext = AsCoinage::<T>::new(Some(AsCoinageInfo::AsCoin));
+		let origin = SystemOrigin::Signed(coin_owner);
+		let len = call.encode().len();
+
+		#[block]
+		{
+			tx_ext
+				.test_run(origin.into(), &call.into(), &Default::default(), len, 0, |_| {
+					Ok(Default::default())
+				})
+				.unwrap()?;
+		}
+
+		Ok(())
+	}
+
+	/// Benchmark for AsCoin extension with accept_swap_with_coin call.
+	#[benchmark]
+	fn as_coin_accept_swap_with_coin() -> Result<(), BenchmarkError> {
+		common_setup::<T>();
+
+		let value = T::MinimumExponent::get();
+		let age = 0u16;
+		let coin_owner = create_coin::<T>(value, age, 0);
+		let (offer, _receiver) = create_swap_offer::<T>(value, SwapAsk::Coin { value }, 0);
+		let to: T::AccountId = account("dest", 0, 0);
+
+		let call = Call::<T>::accept_swap_with_coin { offer, to };
+		let tx_ext = AsCoinage::<T>::new(Some(AsCoinageInfo::AsCoin));
+		let origin = SystemOrigin::Signed(coin_owner);
+		let len = call.encode().len();
+
+		#[block]
+		{
+			tx_ext
+				.test_run(origin.into(), &call.into(), &Default::default(), len, 0, |_| {
+					Ok(Default::default())
+				})
+				.unwrap()?;
+		}
+
+		Ok(())
+	}
+
+	/// Benchmark for AsCoin extension with teleport_coin call.
+	#[benchmark]
+	fn as_coin_teleport_coin() -> Result<(), BenchmarkError> {
+		common_setup::<T>();
+
+		let value = T::MinimumExponent::get();
+		let age = 0u16;
+		let coin_owner = create_coin::<T>(value, age, 0);
+		let dest = T::BenchmarkHelper::setup_teleport_destination()?;
+		let to: T::AccountId = account("dest", 0, 0);
+
+		let call = Call::<T>::teleport_coin { dest, to };
+		let tx_ext = AsCoinage::<T>::new(Some(AsCoinageInfo::AsCoin));
+		let origin = SystemOrigin::Signed(coin_owner);
+		let len = call.encode().len();
+
+		#[block]
+		{
+			tx_ext
+				.test_run(origin.into(), &call.into(), &Default::default(), len, 0, |_| {
+					Ok(Default::default())
+				})
+				.unwrap()?;
+		}
+
+		Ok(())
+	}
+
+	/// Benchmark for AsCoin extension with cancel_swap call.
+	#[benchmark]
+	fn as_coin_cancel_swap() -> Result<(), BenchmarkError> {
+		common_setup::<T>();
+
+		let value = T::MinimumExponent::get();
+		let (offer, _receiver) = create_swap_offer::<T>(value, SwapAsk::Coin { value }, 0);
+		let to: T::AccountId = account("dest", 0, 0);
+
+		let call = Call::<T>::cancel_swap { to };
+		let tx_ext = AsCoinage::<T>::new(Some(AsCoinageInfo::AsCoin));
+		let origin = SystemOrigin::Signed(offer);
+		let len = call.encode().len();
+
+		#[block]
+		{
+			tx_ext
+				.test_run(origin.into(), &call.into(), &Default::default(), len, 0, |_| {
+					Ok(Default::default())
+				})
+				.unwrap()?;
+		}
+
+		Ok(())
+	}
+
 	/// Benchmark for AsCoin extension with load_recycler_with_coin call.
 	#[benchmark]
 	fn as_coin_load_recycler_with_coin() -> Result<(), BenchmarkError> {
@@ -2846,6 +3257,48 @@ mod benches {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn authorize_expire_swap() -> Result<(), BenchmarkError> {
+		common_setup::<T>();
+
+		let value = T::MinimumExponent::get();
+		let (offer, _receiver) = create_swap_offer::<T>(value, SwapAsk::Coin { value }, 0);
+
+		// Advance time past expiration
+		let expires_at = SwapOffers::<T>::get(&offer).expect("offer was created").expires_at;
+		T::BenchmarkHelper::set_time(core::time::Duration::from_secs(expires_at));
+
+		let call = Call::<T>::expire_swap { offer };
+
+		#[block]
+		{
+			call.authorize(TransactionSource::InBlock)
+				.ok_or("Call must give some authorization")??;
+		}
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn authorize_release_parked_coin() -> Result<(), BenchmarkError> {
+		common_setup::<T>();
+
+		let from = ParaId::from(1000);
+		let value = T::MinimumExponent::get();
+		let to: T::AccountId = account("dest", 0, 0);
+		ParkedTeleportedCoins::<T>::insert((from, 0), (to, Coin { value, age: 0 }));
+
+		let call = Call::<T>::release_parked_coin { from, teleport_id: 0 };
+
+		#[block]
+		{
+			call.authorize(TransactionSource::InBlock)
+				.ok_or("Call must give some authorization")??;
+		}
+
+		Ok(())
+	}
+
 	// ==================== on_poll benchmarks ====================
 
 	/// Benchmark creating a paid token collection for a new period.
@@ -53,7 +53,11 @@ use sp_runtime::{
 #[scale_info(skip_type_params(T))]
 pub enum AsCoinageInfo<T: Config + Send + Sync> {
 	/// Transmute the signed origin into [Origin::Coin] for the calls: [Call::split],
-	/// [Call::transfer], [Call::load_recycler_with_coin].
+	/// [Call::transfer], [Call::load_recycler_with_coin], [Call::offer_swap],
+	/// [Call::accept_swap_with_coin], [Call::teleport_coin].
+	///
+	/// For [Call::cancel_swap], the signer is the account of a swap offer and the coin of the
+	/// origin is the offered coin.
 	AsCoin,
 	/// Transmute the None origin into [Origin::UnloadToken] for the calls:
 	/// [Call::unload_recycler_into_coin], [Call::unload_recycler_into_external_asset],
@@ -159,10 +163,21 @@ pub enum AsCoinageInfo<T: Config + Send + Sync> {
 		/// The nonce of the signer's account, used for replay protection.
 		nonce: T::Nonce,
 	},
+	/// Transmute the signed origin into [Origin::Coins] for the call [Call::merge].
+	///
+	/// The signer's coin is merged alongside the coins of `other_coins`.
+	AsCoins {
+		/// The owners of the other coins to merge, each with their signature of
+		/// [`Pallet::merge_signing_payload`] for the message `inherited_implication` hashed with
+		/// blake2_256.
+		///
+		/// The total number of merged coins is bounded by [Config::MaxConsolidation].
+		other_coins: BoundedVec<(T::AccountId, T::CoinSignature), T::MaxConsolidation>,
+	},
 }
 
-/// The coinage transaction extension. Allows authentication as the origin [Origin::Coin]
-/// or [Origin::UnloadToken].
+/// The coinage transaction extension. Allows authentication as the origin [Origin::Coin],
+/// [Origin::Coins] or [Origin::UnloadToken].
 #[derive(
 	Encode,
 	Decode,
@@ -203,6 +218,48 @@ impl<T: Config + Send + Sync> AsCoinage<T> {
 			_ => (1, 0),
 		}
 	}
+
+	/// Get the coin of `coin_id` if it exists and is not temporarily locked.
+	fn unlocked_coin(coin_id: &T::AccountId, current_time: u64) -> Result<Coin, CustomInvalidity> {
+		Self::ensure_unlocked(coin_id, current_time)?;
+		CoinsByOwner::<T>::get(coin_id).ok_or(CustomInvalidity::NoCoin)
+	}
+
+	/// Ensure the account `coin_id` is not locked after a failed dispatch.
+	fn ensure_unlocked(coin_id: &T::AccountId, current_time: u64) -> Result<(), CustomInvalidity> {
+		if let Some(locked_coin) = LockedCoins::<T>::get(coin_id) {
+			if current_time < locked_coin.until {
+				return Err(CustomInvalidity::CoinTemporarilyLocked);
+			}
+		}
+		Ok(())
+	}
+
+	/// Lock the coin of `coin_id` after a failed dispatch, for a duration growing exponentially
+	/// with the number of consecutive failures.
+	fn lock_coin_after_failed_dispatch(coin_id: &T::AccountId) {
+		// Get the new retries count: 0 on first failure, incremented on subsequent
+		// failures.
+		let new_retries = LockedCoins::<T>::get(coin_id)
+			.and_then(|locked| match locked.reason {
+				LockReason::FailedDispatch { retries } => Some(retries.saturating_add(1)),
+			})
+			.unwrap_or(0);
+
+		// Calculate exponential lock time: 2^retries * lock_period.
+		let base_lock = T::CoinFailureLockPeriod::get();
+		let lock_duration = 2u64.saturating_pow(u32::from(new_retries)).saturating_mul(base_lock);
+
+		let current_time = T::UnixTime::now().as_secs();
+		let lock_until = current_time.saturating_add(lock_duration);
+		LockedCoins::<T>::insert(
+			coin_id,
+			LockedCoin {
+				reason: LockReason::FailedDispatch { retries: new_retries },
+				until: lock_until,
+			},
+		);
+	}
 }
 
 #[derive(Clone, Copy)]
@@ -218,6 +275,13 @@ pub enum Val<T: Config + Send + Sync> {
 	UsingCoin {
 		coin_id: T::AccountId,
 	},
+	UsingCoins {
+		coin_ids: Vec<T::AccountId>,
+	},
+	/// Cancelling the swap offer of `offer`, whose account is locked if the call fails.
+	UsingSwapOffer {
+		offer: T::AccountId,
+	},
 	UsingFreeToken {
 		token: Alias,
 		period: Period,
@@ -250,6 +314,12 @@ pub enum Pre<T: Config + Send + Sync> {
 		coin_id: T::AccountId,
 		coin: Coin,
 	},
+	UsingCoins {
+		coins: Vec<(T::AccountId, Coin)>,
+	},
+	UsingSwapOffer {
+		offer: T::AccountId,
+	},
 	UsingFreeToken,
 	UsingPaidToken,
 	/// Using output token - first alias marked as unloaded in prepare (penalty if call fails).
@@ -295,11 +365,22 @@ impl<T: Config + Send + Sync> TransactionExtension<<T as frame_system::Config>::
 					T::WeightInfo::as_coin_load_recycler_with_coin(),
 				Some(Call::<T>::pay_for_recycler_unload_fee_token_with_coin { .. }) =>
 					T::WeightInfo::as_coin_pay_for_recycler_unload_fee_token_with_coin(),
+				Some(Call::<T>::offer_swap { .. }) => T::WeightInfo::as_coin_offer_swap(),
+				Some(Call::<T>::accept_swap_with_coin { .. }) =>
+					T::WeightInfo::as_coin_accept_swap_with_coin(),
+				Some(Call::<T>::teleport_coin { .. }) => T::WeightInfo::as_coin_teleport_coin(),
+				Some(Call::<T>::cancel_swap { .. }) => T::WeightInfo::as_coin_cancel_swap(),
 				// Default for unknown/invalid calls - use max of other cases
 				_ => T::WeightInfo::as_coin_transfer()
 					.max(T::WeightInfo::as_coin_load_recycler_with_coin())
-					.max(T::WeightInfo::as_coin_pay_for_recycler_unload_fee_token_with_coin()),
+					.max(T::WeightInfo::as_coin_pay_for_recycler_unload_fee_token_with_coin())
+					.max(T::WeightInfo::as_coin_offer_swap())
+					.max(T::WeightInfo::as_coin_accept_swap_with_coin())
+					.max(T::WeightInfo::as_coin_teleport_coin())
+					.max(T::WeightInfo::as_coin_cancel_swap()),
 			},
+			Some(AsCoinageInfo::AsCoins { other_coins }) =>
+				T::WeightInfo::as_coins_merge((other_coins.len() as u32).saturating_add(1)),
 			Some(AsCoinageInfo::AsUnloadTokenPeople { .. }) => {
 				let (r, d) = Self::unload_call_validation_params(call);
 				T::WeightInfo::as_unload_token_people_tx_ext()
@@ -376,14 +457,26 @@ impl<T: Config + Send + Sync> TransactionExtension<<T as frame_system::Config>::
 				};
 				let coin_id = coin_id.clone();
 				let current_time = T::UnixTime::now().as_secs();
-				if let Some(locked_coin) = LockedCoins::<T>::get(&coin_id) {
-					if current_time < locked_coin.until {
-						return Err(CustomInvalidity::CoinTemporarilyLocked.into());
-					}
+
+				// The coin of a swap offer is held by the offer, not by its account.
+				if let Some(Call::<T>::cancel_swap { to }) = call.is_sub_type() {
+					Self::ensure_unlocked(&coin_id, current_time)?;
+					let swap = Pallet::<T>::validate_cancel_swap(&coin_id, to)?;
+					let validity = ValidTransaction::with_tag_prefix("Coinage:coin")
+						.and_provides(twox_64(&("operate", &coin_id).encode()[..]))
+						.and_provides(twox_64(&("swap", &coin_id).encode()[..]))
+						.into();
+					origin.set_caller_from(Origin::Coin {
+						coin_id: coin_id.clone(),
+						coin: swap.coin,
+					});
+					return Ok((validity, Val::UsingSwapOffer { offer: coin_id }, origin));
 				}
 
-				let coin = CoinsByOwner::<T>::get(&coin_id).ok_or(CustomInvalidity::NoCoin)?;
+				let coin = Self::unlocked_coin(&coin_id, current_time)?;
 
+				// The swap offer accepted by the call, if any.
+				let mut accepted_offer = None;
 				match call.is_sub_type() {
 					Some(Call::<T>::split { split_into }) => {
 						Pallet::<T>::validate_split(&coin, split_into)?;
@@ -413,17 +506,66 @@ impl<T: Config + Send + Sync> TransactionExtension<<T as frame_system::Config>::
 							proof_of_ownership,
 						)?;
 					},
+					Some(Call::<T>::offer_swap { ask, receiver: _, duration }) => {
+						Pallet::<T>::validate_offer_swap(&coin, &coin_id, ask, *duration)?;
+					},
+					Some(Call::<T>::accept_swap_with_coin { offer, to }) => {
+						Pallet::<T>::validate_accept_swap_with_coin(&coin, offer, to)?;
+						accepted_offer = Some(offer);
+					},
+					Some(Call::<T>::teleport_coin { dest, to: _ }) => {
+						Pallet::<T>::validate_teleport_coin(&coin, dest)?;
+					},
 					_ => {
 						return Err(CustomInvalidity::InvalidCall.into());
 					},
 				}
 
 				let provides = twox_64(&("operate", &coin_id).encode()[..]);
-				let validity =
-					ValidTransaction::with_tag_prefix("Coinage:coin").and_provides(provides).into();
+				let mut validity =
+					ValidTransaction::with_tag_prefix("Coinage:coin").and_provides(provides);
+				if let Some(offer) = accepted_offer {
+					// Only one acceptance of an offer can be included.
+					validity = validity.and_provides(twox_64(&("swap", offer).encode()[..]));
+				}
+				let validity = validity.into();
 				origin.set_caller_from(Origin::Coin { coin_id: coin_id.clone(), coin });
 				Ok((validity, Val::UsingCoin { coin_id }, origin))
 			},
+			Some(AsCoinageInfo::AsCoins { other_coins }) => {
+				let Some(frame_system::Origin::<T>::Signed(coin_id)) = origin.as_system_ref()
+				else {
+					return Err(CustomInvalidity::OriginToAsCoinMustBeSigned.into());
+				};
+				let Some(Call::<T>::merge { to }) = call.is_sub_type() else {
+					return Err(CustomInvalidity::InvalidCall.into());
+				};
+
+				let current_time = T::UnixTime::now().as_secs();
+				let coins = core::iter::once(coin_id)
+					.chain(other_coins.iter().map(|(other_id, _)| other_id))
+					.map(|id| Self::unlocked_coin(id, current_time).map(|coin| (id.clone(), coin)))
+					.collect::<Result<Vec<_>, _>>()?;
+				Pallet::<T>::validate_merge(&coins, to)?;
+
+				let proven_msg = inherited_implication.using_encoded(blake2_256);
+				let payload = Pallet::<T>::merge_signing_payload(&proven_msg);
+				for (other_id, signature) in other_coins {
+					if !signature.verify(&payload[..], other_id) {
+						return Err(CustomInvalidity::InvalidMergeSignature.into());
+					}
+				}
+
+				let mut validity = ValidTransaction::with_tag_prefix("Coinage:coin");
+				for (coin_id, _) in &coins {
+					validity = validity.and_provides(twox_64(&("operate", coin_id).encode()[..]));
+				}
+				let coin_ids = coins.iter().map(|(coin_id, _)| coin_id.clone()).collect();
+				let coins = BoundedVec::try_from(coins)
+					.map_err(|_| CustomInvalidity::TooManyMergeInputs)?;
+				origin.set_caller_from(Origin::Coins { coins });
+				Ok((validity.into(), Val::UsingCoins { coin_ids }, origin))
+			},
 			Some(AsCoinageInfo::AsUnloadTokenPeople { proof, period, counter, alias_proofs }) => {
 				Pallet::<T>::validate_unload_calls(call, UnloadFee::Prepaid)?;
 
@@ -690,6 +832,20 @@ impl<T: Config + Send + Sync> TransactionExtension<<T as frame_system::Config>::
 				// On dispatch failure it is restored in post_dispatch.
 				Pre::UsingCoin { coin_id, coin }
 			},
+			Val::UsingCoins { coin_ids } => {
+				// Coins are removed in prepare to prevent concurrent use in the same block.
+				// On dispatch failure they are restored in post_dispatch.
+				let coins = coin_ids
+					.into_iter()
+					.map(|coin_id| {
+						CoinsByOwner::<T>::take(&coin_id)
+							.map(|coin| (coin_id, coin))
+							.ok_or(CustomInvalidity::NoCoin)
+					})
+					.collect::<Result<Vec<_>, _>>()?;
+				Pre::UsingCoins { coins }
+			},
+			Val::UsingSwapOffer { offer } => Pre::UsingSwapOffer { offer },
 			Val::UsingFreeToken { token, period, kind } => {
 				ConsumedFreeUnloadTokens::<T>::insert(period, token, ());
 				match kind {
@@ -738,35 +894,31 @@ impl<T: Config + Send + Sync> TransactionExtension<<T as frame_system::Config>::
 			Pre::UsingCoin { coin_id, coin } => {
 				if result.is_err() {
 					CoinsByOwner::<T>::insert(&coin_id, coin);
-
-					// Get the new retries count: 0 on first failure, incremented on subsequent
-					// failures.
-					let new_retries = LockedCoins::<T>::get(&coin_id)
-						.and_then(|locked| match locked.reason {
-							LockReason::FailedDispatch { retries } =>
-								Some(retries.saturating_add(1)),
-						})
-						.unwrap_or(0);
-
-					// Calculate exponential lock time: 2^retries * lock_period.
-					let base_lock = T::CoinFailureLockPeriod::get();
-					let lock_duration =
-						2u64.saturating_pow(u32::from(new_retries)).saturating_mul(base_lock);
-
-					let current_time = T::UnixTime::now().as_secs();
-					let lock_until = current_time.saturating_add(lock_duration);
-					LockedCoins::<T>::insert(
-						&coin_id,
-						LockedCoin {
-							reason: LockReason::FailedDispatch { retries: new_retries },
-							until: lock_until,
-						},
-					);
+					Self::lock_coin_after_failed_dispatch(&coin_id);
 				} else {
 					LockedCoins::<T>::remove(&coin_id);
 				}
 				Ok(Weight::zero())
 			},
+			Pre::UsingCoins { coins } => {
+				for (coin_id, coin) in coins {
+					if result.is_err() {
+						CoinsByOwner::<T>::insert(&coin_id, coin);
+						Self::lock_coin_after_failed_dispatch(&coin_id);
+					} else {
+						LockedCoins::<T>::remove(&coin_id);
+					}
+				}
+				Ok(Weight::zero())
+			},
+			Pre::UsingSwapOffer { offer } => {
+				if result.is_err() {
+					Self::lock_coin_after_failed_dispatch(&offer);
+				} else {
+					LockedCoins::<T>::remove(&offer);
+				}
+				Ok(Weight::zero())
+			},
 			Pre::UsingFreeToken => Ok(Weight::zero()),
 			Pre::UsingPaidToken => Ok(Weight::zero()),
 			Pre::UsingOutputToken { fee_recycler_value } => {
@@ -39,6 +39,7 @@ pub use weights::WeightInfo;
 
 use alloc::{collections::BTreeSet, vec::Vec};
 use codec::Encode;
+use cumulus_primitives_core::ParaId;
 use frame_support::{
 	pallet_prelude::*,
 	storage::types::{Key as NMapKey, StorageNMap},
@@ -60,10 +61,13 @@ use indiv_support::traits::{
 };
 use sp_core::blake2_256;
 use sp_runtime::{
-	traits::{AccountIdConversion, Convert, Zero},
+	traits::{
+		AccountIdConversion, Convert, IdentifyAccount, MaybeEquivalence, TryConvert, Verify, Zero,
+	},
 	SaturatedConversion, Saturating,
 };
 use verifiable::GenerateVerifiable;
+use xcm::latest::{InteriorLocation, Location, SendXcm};
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -82,6 +86,9 @@ pub mod pallet {
 	/// Base prefix for paid token collection identifiers (one per period).
 	pub const PAID_TOKEN_COLLECTION_PREFIX: [u8; 16] = *b"coinage/paidtkn!";
 
+	/// Prefix of the payload signed by the owners of the additional coins consumed by a merge.
+	pub const MERGE_COIN_SIGNING_PREFIX: [u8; 18] = *b"coinage/mergecoin!";
+
 	/// The number of seconds after the period where free unload tokens are still accepted.
 	///
 	/// This allows for a transaction sent at the limit to still have time to be included.
@@ -90,6 +97,18 @@ pub mod pallet {
 	/// Maximum number of storage entries to remove per dust cleanup call.
 	pub(crate) const DUST_CLEANUP_BATCH_SIZE: u32 = 1000;
 
+	/// Maximum number of keys of a storage map checked by one run of the offchain worker.
+	///
+	/// The next run resumes after the last checked key, see [`Pallet::offchain_scan`].
+	pub(crate) const OFFCHAIN_WORKER_SCAN_LIMIT: u32 = 256;
+
+	/// Offchain storage key of the cursor of the offchain worker over
+	/// [`ParkedTeleportedCoins`].
+	pub(crate) const PARKED_COINS_OFFCHAIN_CURSOR: &[u8] = b"coinage::parked-coins-cursor";
+
+	/// Offchain storage key of the cursor of the offchain worker over [`SwapOffers`].
+	pub(crate) const SWAP_OFFERS_OFFCHAIN_CURSOR: &[u8] = b"coinage::swap-offers-cursor";
+
 	/// Tx validity tag prefix for cleaning paid unload token dust.
 	pub(crate) const CLEAN_PAID_UNLOAD_TOKEN_DUST_TX_TAG_PREFIX: &str =
 		"coinage:clean-paid-unload-token-dust";
@@ -160,6 +179,17 @@ pub mod pallet {
 		fn validate_proof(proof: &Self::Proof, context: &[u8], msg: &[u8]) -> Result<Alias, ()>;
 	}
 
+	/// The chains running a coinage pallet that coins can be teleported to and received from.
+	///
+	/// Those chains are trusted both ways and must share the coin exponents, the underlying asset
+	/// and the [`Config::PalletId`] of this chain. Runtimes derive them from the members
+	/// notifier/subscriber link between the people chain and its subscribers.
+	pub trait CoinTeleportDestinations {
+		/// The index of the coinage pallet on the chain `para_id`, or `None` if coins can't be
+		/// teleported to and from that chain.
+		fn coinage_pallet_index(para_id: ParaId) -> Option<u8>;
+	}
+
 	/// Invalidity reasons for the transaction extension validation.
 	#[derive(Clone)]
 	pub enum CustomInvalidity {
@@ -239,6 +269,34 @@ pub mod pallet {
 		EmptyUnpaidLoadBatch = 84,
 		/// The underlying asset id has not been set yet.
 		AssetIdNotSet = 85,
+		/// A merge must consume at least two coins.
+		TooFewMergeInputs = 86,
+		/// A merge consumes more coins than [`Config::MaxConsolidation`].
+		TooManyMergeInputs = 87,
+		/// The same coin is used more than once as a merge input.
+		DuplicateMergeInputs = 88,
+		/// The signature of one of the additional merge inputs is invalid.
+		InvalidMergeSignature = 89,
+		/// The total value of the merged coins is not a valid power of two.
+		InvalidMerge = 90,
+		/// The coin is already offered in a swap.
+		SwapOfferAlreadyExists = 91,
+		/// The swap offer does not exist.
+		NoSwapOffer = 92,
+		/// The swap offer has expired and can no longer be accepted.
+		SwapOfferExpired = 93,
+		/// The counterpart doesn't match what the swap offer asks for.
+		SwapAskMismatch = 94,
+		/// The swap offer duration is zero or exceeds [`Config::MaxSwapDuration`].
+		InvalidSwapDuration = 95,
+		/// The swap offer asks for an invalid coin value or a zero asset amount.
+		InvalidSwapAsk = 96,
+		/// The two coins exchanged by a swap are sent to the same account.
+		DuplicateSwapDestinations = 97,
+		/// The chain is not a [`Config::TeleportDestinations`].
+		UnknownTeleportDestination = 98,
+		/// The parked teleported coin does not exist.
+		NoParkedCoin = 99,
 	}
 
 	impl From<CustomInvalidity> for TransactionValidityError {
@@ -370,6 +428,55 @@ pub mod pallet {
 		pub until: u64,
 	}
 
+	/// What the maker of a swap offer asks in exchange for the offered coin.
+	#[derive(
+		Clone,
+		PartialEq,
+		Eq,
+		Debug,
+		Encode,
+		Decode,
+		DecodeWithMemTracking,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	pub enum SwapAsk<AssetId, Balance> {
+		/// A coin of the given value.
+		Coin { value: CoinValue },
+		/// An amount of an asset of [`Config::Fungibles`].
+		Asset { id: AssetId, amount: Balance },
+	}
+
+	/// A coin offered in a swap, held by the pallet until the offer is accepted or expires.
+	#[derive(
+		Clone,
+		PartialEq,
+		Eq,
+		Debug,
+		Encode,
+		Decode,
+		DecodeWithMemTracking,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	pub struct SwapOffer<AccountId, AssetId, Balance> {
+		/// The offered coin.
+		pub coin: Coin,
+		/// What the maker asks in exchange for the offered coin.
+		pub ask: SwapAsk<AssetId, Balance>,
+		/// The account receiving the counterpart, or the offered coin back if the offer expires.
+		pub receiver: AccountId,
+		/// Unix timestamp (seconds) from which the offer can no longer be accepted.
+		pub expires_at: u64,
+	}
+
+	pub type SwapAskOf<T> = SwapAsk<FungiblesAssetIdOf<T>, FungiblesBalanceOf<T>>;
+	pub type SwapOfferOf<T> = SwapOffer<
+		<T as frame_system::Config>::AccountId,
+		FungiblesAssetIdOf<T>,
+		FungiblesBalanceOf<T>,
+	>;
+
 	/// Input for unloading a recycler.
 	#[derive(
 		CloneNoBound,
@@ -501,6 +608,27 @@ pub mod pallet {
 	pub type LockedCoins<T: Config> =
 		StorageMap<_, Twox64Concat, T::AccountId, LockedCoin, OptionQuery>;
 
+	/// Open swap offers, keyed by the account of the offered coin at the time of the offer.
+	///
+	/// The offered coin is held here until the offer is accepted, cancelled by its maker, or given
+	/// back once expired.
+	#[pallet::storage]
+	pub type SwapOffers<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, SwapOfferOf<T>, OptionQuery>;
+
+	/// The identifier of the next coin teleported out of this chain.
+	#[pallet::storage]
+	pub type NextTeleportId<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	/// Teleported coins received for an address that already had a coin, keyed by source chain
+	/// and teleport identifier.
+	///
+	/// A parked coin is given to its destination by [`Pallet::release_parked_coin`] once the
+	/// destination no longer holds a coin.
+	#[pallet::storage]
+	pub type ParkedTeleportedCoins<T: Config> =
+		StorageMap<_, Twox64Concat, (ParaId, u64), (T::AccountId, Coin), OptionQuery>;
+
 	/// The total value of coins that were burnt.
 	///
 	/// This tracks value that is intentionally destroyed as part of protocol flows (for example:
@@ -920,6 +1048,42 @@ pub mod pallet {
 		#[pallet::constant]
 		type CoinFailureLockPeriod: Get<u64>;
 
+		/// The maximum duration, in seconds, during which a swap offer can be accepted.
+		#[pallet::constant]
+		type MaxSwapDuration: Get<u64>;
+
+		/// The signature by which coin owners authorize their coin to be consumed by a merge
+		/// signed by another coin owner.
+		type CoinSignature: Parameter
+			+ Send
+			+ Sync
+			+ Verify<Signer: IdentifyAccount<AccountId = Self::AccountId>>;
+
+		/// The chains that coins can be teleported to and received from.
+		type TeleportDestinations: CoinTeleportDestinations;
+
+		/// XCM sender for teleporting coins to the [`Config::TeleportDestinations`].
+		type XcmSender: SendXcm;
+
+		/// Origin check for coins teleported from another chain, returning the source chain.
+		type EnsureTeleportSource: EnsureOrigin<Self::RuntimeOrigin, Success = ParaId>;
+
+		/// The equivalence between the assets of [`Config::Fungibles`] and their location relative
+		/// to this chain.
+		///
+		/// Used to teleport the underlying asset of a teleported coin.
+		type UnderlyingAssetLocation: MaybeEquivalence<Location, FungiblesAssetIdOf<Self>>;
+
+		/// The location of an account, relative to its chain.
+		///
+		/// Used to deposit the underlying asset of a teleported coin to the pallet account of the
+		/// destination.
+		type AccountIdToLocation: for<'a> TryConvert<&'a Self::AccountId, Location>;
+
+		/// The location of this chain in the consensus universe, used to reanchor the underlying
+		/// asset of a teleported coin for its destination.
+		type UniversalLocation: Get<InteriorLocation>;
+
 		/// Helper for runtime benchmarks.
 		#[cfg(feature = "runtime-benchmarks")]
 		type BenchmarkHelper: crate::BenchmarkHelper<Self>;
@@ -988,6 +1152,12 @@ pub mod pallet {
 			/// The coin held by the origin.
 			coin: Coin,
 		},
+		/// Multiple coins as origin. The coins are removed by the transaction extension when
+		/// creating this origin. The origin effectively holds the coins.
+		Coins {
+			/// The ids of the coin owners and the coins held by the origin.
+			coins: BoundedVec<(T::AccountId, Coin), T::MaxConsolidation>,
+		},
 		/// An unload token as origin. The unload token is marked as consumed by the transaction
 		/// extension when creating this origin.
 		UnloadToken {
@@ -1030,6 +1200,12 @@ pub mod pallet {
 			value: CoinValue,
 			new_age: u16,
 		},
+		CoinsMerged {
+			to: T::AccountId,
+			input_count: u32,
+			value: CoinValue,
+			new_age: u16,
+		},
 		RecyclerLoadedWithCoin {
 			value: CoinValue,
 		},
@@ -1120,6 +1296,50 @@ pub mod pallet {
 		UnderlyingAssetIdSet {
 			asset_id: FungiblesAssetIdOf<T>,
 		},
+		SwapOffered {
+			offer: T::AccountId,
+			value: CoinValue,
+			ask: SwapAskOf<T>,
+			receiver: T::AccountId,
+			expires_at: u64,
+		},
+		SwapAccepted {
+			offer: T::AccountId,
+			to: T::AccountId,
+		},
+		SwapExpired {
+			offer: T::AccountId,
+			to: T::AccountId,
+		},
+		SwapCancelled {
+			offer: T::AccountId,
+			to: T::AccountId,
+		},
+		CoinTeleported {
+			dest: ParaId,
+			teleport_id: u64,
+			to: T::AccountId,
+			value: CoinValue,
+			age: u16,
+		},
+		TeleportedCoinReceived {
+			source: ParaId,
+			teleport_id: u64,
+			to: T::AccountId,
+			value: CoinValue,
+			age: u16,
+		},
+		TeleportedCoinParked {
+			source: ParaId,
+			teleport_id: u64,
+			to: T::AccountId,
+		},
+		TeleportedCoinReturned {
+			source: ParaId,
+			teleport_id: u64,
+			return_id: u64,
+			to: T::AccountId,
+		},
 	}
 
 	#[pallet::error]
@@ -1171,6 +1391,20 @@ pub mod pallet {
 		AssetIdAlreadySet,
 		/// The proposed underlying asset id does not exist in [`Config::Fungibles`].
 		UnknownAsset,
+		/// The swap offer does not exist.
+		NoSwapOffer,
+		/// The swap offer has expired and can no longer be accepted.
+		SwapOfferExpired,
+		/// The counterpart doesn't match what the swap offer asks for.
+		SwapAskMismatch,
+		/// The chain is not a [`Config::TeleportDestinations`].
+		UnknownTeleportDestination,
+		/// The XCM message teleporting the coin could not be sent.
+		XcmSendFailed,
+		/// The underlying asset or the pallet account has no XCM location.
+		UnknownLocation,
+		/// The parked teleported coin does not exist.
+		NoParkedCoin,
 	}
 
 	/// A reason for the pallet placing a hold on funds.
@@ -1477,6 +1711,33 @@ pub mod pallet {
 				let call = Call::clean_paid_unload_token_dust {};
 				Self::submit_authorized_transaction(call, "Clean Paid Unload Token Dust");
 			}
+
+			// 6. Give back coins of expired swap offers
+			let offers = Self::offchain_scan(SWAP_OFFERS_OFFCHAIN_CURSOR, |last| match last {
+				Some(last) =>
+					SwapOffers::<T>::iter_keys_from(SwapOffers::<T>::hashed_key_for(last)),
+				None => SwapOffers::<T>::iter_keys(),
+			});
+			for offer in offers {
+				if Self::ensure_can_expire_swap(&offer).is_ok() {
+					let call = Call::expire_swap { offer };
+					Self::submit_authorized_transaction(call, "Expire Swap Offer");
+				}
+			}
+
+			// 7. Give parked teleported coins to their destination
+			let parked = Self::offchain_scan(PARKED_COINS_OFFCHAIN_CURSOR, |last| match last {
+				Some(last) => ParkedTeleportedCoins::<T>::iter_keys_from(
+					ParkedTeleportedCoins::<T>::hashed_key_for(last),
+				),
+				None => ParkedTeleportedCoins::<T>::iter_keys(),
+			});
+			for (from, teleport_id) in parked {
+				if Self::ensure_can_release_parked_coin(from, teleport_id).is_ok() {
+					let call = Call::release_parked_coin { from, teleport_id };
+					Self::submit_authorized_transaction(call, "Release Parked Teleported Coin");
+				}
+			}
 		}
 	}
 
@@ -1740,6 +2001,392 @@ pub mod pallet {
 			Ok(Pays::No.into())
 		}
 
+		/// Merge multiple coins into a single coin.
+		///
+		/// The origin must be a [Origin::Coins], which can be obtained from the transaction
+		/// extension [`AsCoinage`](crate::extension::AsCoinage) with
+		/// [`AsCoins`](crate::extension::AsCoinageInfo::AsCoins).
+		///
+		/// The call is free. The resulting coin's age is the maximum age of the merged coins plus
+		/// one.
+		///
+		/// Validity requirements:
+		/// (an invalid transaction won't be included in a block, the coins are not consumed)
+		/// * At least two and at most [Config::MaxConsolidation] coins are merged.
+		/// * Each coin's age must be less than [Config::MaximumAge].
+		/// * The total value of the merged coins must be a coin value within the bounds defined by
+		///   [Config::MinimumExponent] and [Config::MaximumExponent].
+		/// * The destination account must not already have a coin.
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::WeightInfo::merge())]
+		pub fn merge(origin: OriginFor<T>, to: T::AccountId) -> DispatchResultWithPostInfo {
+			let Ok(Origin::Coins { coins }) = origin.into() else {
+				return Err(DispatchError::BadOrigin.into());
+			};
+
+			// This call should not fail; the origin's coins are already consumed by the
+			// transaction extension before dispatch. Validation ensures all preconditions are met.
+
+			let input_count = coins.len() as u32;
+			let Some(new_coin) = Self::merged_coin(coins.iter().map(|(_, coin)| coin)) else {
+				defensive!("coinage: merge inputs checked in validate");
+				return Err(Error::<T>::InternalError.into());
+			};
+
+			// The destination has no coin, as verified during validation.
+			CoinsByOwner::<T>::insert(&to, new_coin);
+			Self::deposit_event(Event::CoinsMerged {
+				to,
+				input_count,
+				value: new_coin.value,
+				new_age: new_coin.age,
+			});
+
+			Ok(Pays::No.into())
+		}
+
+		/// Offer a coin in a swap.
+		///
+		/// The origin must be a [Origin::Coin], which can be obtained from the transaction
+		/// extension [`AsCoinage`](crate::extension::AsCoinage).
+		///
+		/// The call is free. The coin is held by the pallet in [SwapOffers], keyed by the coin's
+		/// account, until the offer is accepted with [Call::accept_swap_with_coin] or
+		/// [Call::accept_swap_with_asset], cancelled with [Call::cancel_swap], or until it expires.
+		/// Once expired, the coin is given back to `receiver`, or to the coin's account if
+		/// `receiver` holds a coin, by [Call::expire_swap].
+		///
+		/// The `receiver` parameter is the account receiving the counterpart. When asking for a
+		/// coin, it must not hold a coin when the offer is accepted.
+		///
+		/// Validity requirements:
+		/// (an invalid transaction won't be included in a block, the coin is not consumed)
+		/// * The coin's age must be less than [Config::MaximumAge].
+		/// * The coin's account must not already have an open offer.
+		/// * The `duration` must be non-zero and at most [Config::MaxSwapDuration].
+		/// * The asked coin value must be within the bounds defined by [Config::MinimumExponent]
+		///   and [Config::MaximumExponent], or the asked asset amount must be non-zero.
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::offer_swap())]
+		pub fn offer_swap(
+			origin: OriginFor<T>,
+			ask: SwapAskOf<T>,
+			receiver: T::AccountId,
+			duration: u64,
+		) -> DispatchResultWithPostInfo {
+			let Ok(Origin::Coin { coin_id, coin }) = origin.into() else {
+				return Err(DispatchError::BadOrigin.into());
+			};
+
+			// This call should not fail; the origin's coin is already consumed by the transaction
+			// extension before dispatch. Validation ensures all preconditions are met.
+
+			let expires_at = T::UnixTime::now().as_secs().saturating_add(duration);
+			SwapOffers::<T>::insert(
+				&coin_id,
+				SwapOffer { coin, ask: ask.clone(), receiver: receiver.clone(), expires_at },
+			);
+			Self::deposit_event(Event::SwapOffered {
+				offer: coin_id,
+				value: coin.value,
+				ask,
+				receiver,
+				expires_at,
+			});
+
+			Ok(Pays::No.into())
+		}
+
+		/// Accept a swap offer asking for a coin, in exchange for the origin's coin.
+		///
+		/// The origin must be a [Origin::Coin], which can be obtained from the transaction
+		/// extension [`AsCoinage`](crate::extension::AsCoinage).
+		///
+		/// The call is free. The origin's coin is sent to the offer's receiver and the offered
+		/// coin to `to`, both aged by one.
+		///
+		/// Validity requirements:
+		/// (an invalid transaction won't be included in a block, the coin is not consumed)
+		/// * The coin's age must be less than [Config::MaximumAge].
+		/// * The offer must exist, not be expired, and ask for a coin of the origin coin's value.
+		/// * Neither `to` nor the offer's receiver may already have a coin, and they must differ.
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::accept_swap_with_coin())]
+		pub fn accept_swap_with_coin(
+			origin: OriginFor<T>,
+			offer: T::AccountId,
+			to: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let Ok(Origin::Coin { coin_id: _, coin }) = origin.into() else {
+				return Err(DispatchError::BadOrigin.into());
+			};
+
+			// Validation ensures all preconditions are met. They are checked again in case an
+			// earlier transaction of the block gave a coin to a destination, in which case the
+			// transaction extension gives the coin back to its owner.
+
+			let swap = SwapOffers::<T>::take(&offer).ok_or(Error::<T>::NoSwapOffer)?;
+			ensure!(
+				!CoinsByOwner::<T>::contains_key(&swap.receiver) &&
+					!CoinsByOwner::<T>::contains_key(&to),
+				Error::<T>::AddressAlreadyHasCoin
+			);
+
+			CoinsByOwner::<T>::insert(
+				&swap.receiver,
+				Coin { value: coin.value, age: coin.age.saturating_add(1) },
+			);
+			CoinsByOwner::<T>::insert(
+				&to,
+				Coin { value: swap.coin.value, age: swap.coin.age.saturating_add(1) },
+			);
+			Self::deposit_event(Event::SwapAccepted { offer, to });
+
+			Ok(Pays::No.into())
+		}
+
+		/// Accept a swap offer asking for an asset, paying the asked amount from the signer.
+		///
+		/// The origin must be a signed origin.
+		///
+		/// The asked amount of the asset is transferred from the signer to the offer's receiver
+		/// and the offered coin is sent to `to`, aged by one.
+		///
+		/// Requirements:
+		/// * The offer must exist, not be expired, and ask for an asset.
+		/// * The destination account must not already have a coin.
+		/// * The signer must have enough balance of the asked asset.
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::accept_swap_with_asset())]
+		pub fn accept_swap_with_asset(
+			origin: OriginFor<T>,
+			preservation: CodecPreservation,
+			offer: T::AccountId,
+			to: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let swap = SwapOffers::<T>::get(&offer).ok_or(Error::<T>::NoSwapOffer)?;
+			ensure!(
+				T::UnixTime::now().as_secs() < swap.expires_at,
+				Error::<T>::SwapOfferExpired
+			);
+			let SwapAsk::Asset { id, amount } = swap.ask else {
+				return Err(Error::<T>::SwapAskMismatch.into());
+			};
+			ensure!(!CoinsByOwner::<T>::contains_key(&to), Error::<T>::AddressAlreadyHasCoin);
+
+			T::Fungibles::transfer(id, &who, &swap.receiver, amount, preservation.into())?;
+			SwapOffers::<T>::remove(&offer);
+			CoinsByOwner::<T>::insert(
+				&to,
+				Coin { value: swap.coin.value, age: swap.coin.age.saturating_add(1) },
+			);
+			Self::deposit_event(Event::SwapAccepted { offer, to });
+
+			Ok(())
+		}
+
+		/// Cancel a swap offer, sending the offered coin to `to`.
+		///
+		/// The origin must be a [Origin::Coin] of the offer's maker, i.e. the account of the
+		/// offered coin at the time of the offer, which can be obtained from the transaction
+		/// extension [`AsCoinage`](crate::extension::AsCoinage). The offer can be cancelled whether
+		/// it is expired or not.
+		///
+		/// The call is free. The coin is aged by one.
+		///
+		/// Validity requirements:
+		/// (an invalid transaction won't be included in a block)
+		/// * The offer must exist.
+		/// * The destination account must not already have a coin.
+		#[pallet::call_index(24)]
+		#[pallet::weight(T::WeightInfo::cancel_swap())]
+		pub fn cancel_swap(origin: OriginFor<T>, to: T::AccountId) -> DispatchResultWithPostInfo {
+			let Ok(Origin::Coin { coin_id: offer, coin: _ }) = origin.into() else {
+				return Err(DispatchError::BadOrigin.into());
+			};
+
+			// Validation ensures all preconditions are met. They are checked again in case an
+			// earlier transaction of the block gave a coin to the destination, in which case the
+			// offer's account is locked by the transaction extension.
+
+			let swap = SwapOffers::<T>::take(&offer).ok_or(Error::<T>::NoSwapOffer)?;
+			ensure!(!CoinsByOwner::<T>::contains_key(&to), Error::<T>::AddressAlreadyHasCoin);
+
+			CoinsByOwner::<T>::insert(
+				&to,
+				Coin { value: swap.coin.value, age: swap.coin.age.saturating_add(1) },
+			);
+			Self::deposit_event(Event::SwapCancelled { offer, to });
+
+			Ok(Pays::No.into())
+		}
+
+		/// Teleport the origin's coin to `to` on the chain `dest`.
+		///
+		/// The origin must be a [Origin::Coin], which can be obtained from the transaction
+		/// extension [`AsCoinage`](crate::extension::AsCoinage).
+		///
+		/// The call is free. The coin and its underlying asset are burnt, and a single XCM program
+		/// is sent to `dest` which teleports the underlying asset to the pallet account of `dest`
+		/// and recreates the coin with the same value and age in
+		/// [`Pallet::receive_teleported_coin`]. If the coin can't be received, the program sends
+		/// it back to the origin's account with [`Pallet::return_teleported_coin`].
+		///
+		/// Validity requirements:
+		/// (an invalid transaction won't be included in a block, the coin is not consumed)
+		/// * The coin's age must be less than [Config::MaximumAge].
+		/// * `dest` must be one of [Config::TeleportDestinations].
+		#[pallet::call_index(21)]
+		#[pallet::weight(T::WeightInfo::teleport_coin())]
+		pub fn teleport_coin(
+			origin: OriginFor<T>,
+			dest: ParaId,
+			to: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let Ok(Origin::Coin { coin_id, coin }) = origin.into() else {
+				return Err(DispatchError::BadOrigin.into());
+			};
+
+			// This call only fails if the underlying asset can't be burnt or the XCM message can't
+			// be sent, in which case the transaction extension gives the coin back to its owner.
+
+			let pallet_index = T::TeleportDestinations::coinage_pallet_index(dest)
+				.ok_or(Error::<T>::UnknownTeleportDestination)?;
+			let teleport_id = NextTeleportId::<T>::mutate(|next| {
+				let id = *next;
+				*next = next.saturating_add(1);
+				id
+			});
+			let asset_amount = Self::coin_value_to_asset_amount(coin.value)
+				.map_err(|e| e.into_pallet_error::<T>())?;
+			T::Fungibles::burn_held(
+				Self::underlying_asset_id()?,
+				&HoldReason::Wrapped.into(),
+				&Self::pallet_account(),
+				asset_amount,
+				Precision::Exact,
+				Fortitude::Force,
+			)?;
+			Self::send_teleported_coin(
+				dest,
+				pallet_index,
+				teleport_id,
+				to.clone(),
+				coin,
+				Some(coin_id),
+			)?;
+			Self::deposit_event(Event::CoinTeleported {
+				dest,
+				teleport_id,
+				to,
+				value: coin.value,
+				age: coin.age,
+			});
+
+			Ok(Pays::No.into())
+		}
+
+		/// Receive a coin teleported from another chain by [`Pallet::teleport_coin`].
+		///
+		/// The origin must satisfy [`Config::EnsureTeleportSource`], and the source chain must be
+		/// one of [Config::TeleportDestinations].
+		///
+		/// The underlying asset of the coin, deposited to the pallet account by the same XCM
+		/// program, is held back for the coin. The coin is given to `to` with its value and age.
+		/// If `to` already has a coin, the teleported coin is parked in [ParkedTeleportedCoins]
+		/// until `to` no longer holds one.
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::WeightInfo::receive_teleported_coin())]
+		pub fn receive_teleported_coin(
+			origin: OriginFor<T>,
+			teleport_id: u64,
+			to: T::AccountId,
+			coin: Coin,
+		) -> DispatchResult {
+			let source = T::EnsureTeleportSource::ensure_origin(origin)?;
+			ensure!(
+				T::TeleportDestinations::coinage_pallet_index(source).is_some(),
+				Error::<T>::UnknownTeleportDestination
+			);
+
+			let asset_amount = Self::coin_value_to_asset_amount(coin.value)
+				.map_err(|e| e.into_pallet_error::<T>())?;
+			let asset_id = Self::underlying_asset_id()?;
+			T::Fungibles::hold(
+				asset_id,
+				&HoldReason::Wrapped.into(),
+				&Self::pallet_account(),
+				asset_amount,
+			)?;
+
+			if CoinsByOwner::<T>::contains_key(&to) {
+				ParkedTeleportedCoins::<T>::insert((source, teleport_id), (to.clone(), coin));
+				Self::deposit_event(Event::TeleportedCoinParked { source, teleport_id, to });
+			} else {
+				CoinsByOwner::<T>::insert(&to, coin);
+				Self::deposit_event(Event::TeleportedCoinReceived {
+					source,
+					teleport_id,
+					to,
+					value: coin.value,
+					age: coin.age,
+				});
+			}
+
+			Ok(())
+		}
+
+		/// Send back a coin teleported from another chain that could not be received.
+		///
+		/// Dispatched by the XCM program of [`Pallet::teleport_coin`] when
+		/// [`Pallet::receive_teleported_coin`] fails. The origin must satisfy
+		/// [`Config::EnsureTeleportSource`], and the source chain must be one of
+		/// [Config::TeleportDestinations].
+		///
+		/// The underlying asset of the coin, deposited to the pallet account by the same XCM
+		/// program and left free by the failed call, is burnt. The coin is teleported back to `to`,
+		/// the account it was teleported from, with its value and age. A returned coin is not sent
+		/// back again if it can't be received.
+		#[pallet::call_index(25)]
+		#[pallet::weight(T::WeightInfo::return_teleported_coin())]
+		pub fn return_teleported_coin(
+			origin: OriginFor<T>,
+			teleport_id: u64,
+			to: T::AccountId,
+			coin: Coin,
+		) -> DispatchResult {
+			let source = T::EnsureTeleportSource::ensure_origin(origin)?;
+			let pallet_index = T::TeleportDestinations::coinage_pallet_index(source)
+				.ok_or(Error::<T>::UnknownTeleportDestination)?;
+
+			let asset_amount = Self::coin_value_to_asset_amount(coin.value)
+				.map_err(|e| e.into_pallet_error::<T>())?;
+			T::Fungibles::burn_from(
+				Self::underlying_asset_id()?,
+				&Self::pallet_account(),
+				asset_amount,
+				Preservation::Preserve,
+				Precision::Exact,
+				Fortitude::Polite,
+			)?;
+			let return_id = NextTeleportId::<T>::mutate(|next| {
+				let id = *next;
+				*next = next.saturating_add(1);
+				id
+			});
+			Self::send_teleported_coin(source, pallet_index, return_id, to.clone(), coin, None)?;
+			Self::deposit_event(Event::TeleportedCoinReturned {
+				source,
+				teleport_id,
+				return_id,
+				to,
+			});
+
+			Ok(())
+		}
+
 		/// Load coin into a recycler.
 		///
 		/// The origin must be a [Origin::Coin], which can be obtained from the transaction
@@ -2868,6 +3515,71 @@ pub mod pallet {
 			Self::deposit_event(Event::ExpiredPaidUnloadTokenCollectionDeleted { period });
 			Ok(())
 		}
+
+		/// Give back the coin of an expired swap offer.
+		///
+		/// This is a maintenance call. The origin must be authorized and from local source.
+		///
+		/// The coin is aged by one and given to the offer's receiver, or to the offer's account if
+		/// the receiver holds a coin. If both hold a coin, the offer stays until its maker cancels
+		/// it with [Call::cancel_swap].
+		#[pallet::authorize(|source, offer| {
+			if !matches!(source, TransactionSource::InBlock | TransactionSource::Local) {
+				return Err(CustomInvalidity::TransactionNotLocal.into());
+			}
+			let (validity, weight) = Self::ensure_can_expire_swap(offer)?;
+			Ok((validity, weight))
+		})]
+		#[pallet::call_index(108)]
+		#[pallet::weight(T::WeightInfo::expire_swap())]
+		#[pallet::weight_of_authorize(T::WeightInfo::authorize_expire_swap())]
+		pub fn expire_swap(origin: OriginFor<T>, offer: T::AccountId) -> DispatchResult {
+			ensure_authorized(origin)?;
+			let swap = SwapOffers::<T>::take(&offer).ok_or(Error::<T>::NoSwapOffer)?;
+			let to = Self::expired_swap_destination(&offer, &swap)
+				.ok_or(Error::<T>::AddressAlreadyHasCoin)?;
+			CoinsByOwner::<T>::insert(
+				&to,
+				Coin { value: swap.coin.value, age: swap.coin.age.saturating_add(1) },
+			);
+			Self::deposit_event(Event::SwapExpired { offer, to });
+			Ok(())
+		}
+
+		/// Give a parked teleported coin to its destination.
+		///
+		/// This is a maintenance call. The origin must be authorized and from local source.
+		///
+		/// The destination must not hold a coin.
+		#[pallet::authorize(|source, from, teleport_id| {
+			if !matches!(source, TransactionSource::InBlock | TransactionSource::Local) {
+				return Err(CustomInvalidity::TransactionNotLocal.into());
+			}
+			let (validity, weight) = Self::ensure_can_release_parked_coin(*from, *teleport_id)?;
+			Ok((validity, weight))
+		})]
+		#[pallet::call_index(109)]
+		#[pallet::weight(T::WeightInfo::release_parked_coin())]
+		#[pallet::weight_of_authorize(T::WeightInfo::authorize_release_parked_coin())]
+		pub fn release_parked_coin(
+			origin: OriginFor<T>,
+			from: ParaId,
+			teleport_id: u64,
+		) -> DispatchResult {
+			ensure_authorized(origin)?;
+			let (to, coin) = ParkedTeleportedCoins::<T>::take((from, teleport_id))
+				.ok_or(Error::<T>::NoParkedCoin)?;
+			ensure!(!CoinsByOwner::<T>::contains_key(&to), Error::<T>::AddressAlreadyHasCoin);
+			CoinsByOwner::<T>::insert(&to, coin);
+			Self::deposit_event(Event::TeleportedCoinReceived {
+				source: from,
+				teleport_id,
+				to,
+				value: coin.value,
+				age: coin.age,
+			});
+			Ok(())
+		}
 	}
 
 	#[derive(Debug, PartialEq)]
@@ -3475,6 +4187,275 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// The coin resulting from merging `coins`, or `None` if their total value is not a
+		/// valid coin value.
+		pub(crate) fn merged_coin<'a>(coins: impl IntoIterator<Item = &'a Coin>) -> Option<Coin> {
+			let mut total_unit: u32 = 0;
+			let mut max_age: u16 = 0;
+			for coin in coins {
+				total_unit = total_unit.checked_add(Self::coin_value_to_unit(coin.value)?)?;
+				max_age = max_age.max(coin.age);
+			}
+			if !total_unit.is_power_of_two() {
+				return None;
+			}
+			let value = T::MinimumExponent::get()
+				.checked_add(i8::try_from(total_unit.trailing_zeros()).ok()?)?;
+			if value > T::MaximumExponent::get() {
+				return None;
+			}
+
+			Some(Coin { value, age: max_age.saturating_add(1) })
+		}
+
+		/// Validate a coin merge operation.
+		pub(crate) fn validate_merge(
+			coins: &[(T::AccountId, Coin)],
+			to: &T::AccountId,
+		) -> Result<(), CustomInvalidity> {
+			ensure!(coins.len() >= 2, CustomInvalidity::TooFewMergeInputs);
+			ensure!(
+				coins.len() <= T::MaxConsolidation::get() as usize,
+				CustomInvalidity::TooManyMergeInputs
+			);
+			if coins.iter().any(|(_, coin)| coin.age >= T::MaximumAge::get()) {
+				return Err(CustomInvalidity::CoinTooOld);
+			}
+
+			let mut coin_ids = coins.iter().map(|(coin_id, _)| coin_id).collect::<Vec<_>>();
+			coin_ids.sort();
+			ensure!(
+				!coin_ids.windows(2).any(|w| w[0] == w[1]),
+				CustomInvalidity::DuplicateMergeInputs
+			);
+
+			// Inputs still hold their coin at this point, so this also rejects merging into one
+			// of the inputs.
+			if CoinsByOwner::<T>::contains_key(to) {
+				return Err(CustomInvalidity::AddressAlreadyHasCoin);
+			}
+
+			Self::merged_coin(coins.iter().map(|(_, coin)| coin))
+				.ok_or(CustomInvalidity::InvalidMerge)?;
+
+			Ok(())
+		}
+
+		/// Validate cancelling the swap offer `offer`, returning the offer.
+		pub(crate) fn validate_cancel_swap(
+			offer: &T::AccountId,
+			to: &T::AccountId,
+		) -> Result<SwapOfferOf<T>, CustomInvalidity> {
+			let swap = SwapOffers::<T>::get(offer).ok_or(CustomInvalidity::NoSwapOffer)?;
+			if CoinsByOwner::<T>::contains_key(to) {
+				return Err(CustomInvalidity::AddressAlreadyHasCoin);
+			}
+			Ok(swap)
+		}
+
+		/// Validate offering a coin in a swap.
+		pub(crate) fn validate_offer_swap(
+			coin: &Coin,
+			coin_id: &T::AccountId,
+			ask: &SwapAskOf<T>,
+			duration: u64,
+		) -> Result<(), CustomInvalidity> {
+			if coin.age >= T::MaximumAge::get() {
+				return Err(CustomInvalidity::CoinTooOld);
+			}
+			ensure!(
+				!SwapOffers::<T>::contains_key(coin_id),
+				CustomInvalidity::SwapOfferAlreadyExists
+			);
+			ensure!(
+				duration > 0 && duration <= T::MaxSwapDuration::get(),
+				CustomInvalidity::InvalidSwapDuration
+			);
+			let valid_ask = match ask {
+				SwapAsk::Coin { value } =>
+					(T::MinimumExponent::get()..=T::MaximumExponent::get()).contains(value),
+				SwapAsk::Asset { amount, .. } => !amount.is_zero(),
+			};
+			ensure!(valid_ask, CustomInvalidity::InvalidSwapAsk);
+			Ok(())
+		}
+
+		/// Validate accepting a swap offer with a coin.
+		pub(crate) fn validate_accept_swap_with_coin(
+			coin: &Coin,
+			offer: &T::AccountId,
+			to: &T::AccountId,
+		) -> Result<(), CustomInvalidity> {
+			if coin.age >= T::MaximumAge::get() {
+				return Err(CustomInvalidity::CoinTooOld);
+			}
+			let swap = SwapOffers::<T>::get(offer).ok_or(CustomInvalidity::NoSwapOffer)?;
+			ensure!(
+				T::UnixTime::now().as_secs() < swap.expires_at,
+				CustomInvalidity::SwapOfferExpired
+			);
+			ensure!(
+				swap.ask == SwapAsk::Coin { value: coin.value },
+				CustomInvalidity::SwapAskMismatch
+			);
+			ensure!(*to != swap.receiver, CustomInvalidity::DuplicateSwapDestinations);
+			if CoinsByOwner::<T>::contains_key(to) ||
+				CoinsByOwner::<T>::contains_key(&swap.receiver)
+			{
+				return Err(CustomInvalidity::AddressAlreadyHasCoin);
+			}
+			Ok(())
+		}
+
+		/// The account the coin of the expired swap offer `offer` is given back to: the offer's
+		/// receiver, or the offer's account if the receiver holds a coin. `None` if both hold one.
+		pub(crate) fn expired_swap_destination(
+			offer: &T::AccountId,
+			swap: &SwapOfferOf<T>,
+		) -> Option<T::AccountId> {
+			[&swap.receiver, offer]
+				.into_iter()
+				.find(|who| !CoinsByOwner::<T>::contains_key(*who))
+				.cloned()
+		}
+
+		/// Ensure the swap offer `offer` is expired and its coin can be given back.
+		pub(crate) fn ensure_can_expire_swap(
+			offer: &T::AccountId,
+		) -> Result<(ValidTransaction, Weight), TransactionValidityError> {
+			let swap = SwapOffers::<T>::get(offer).ok_or(CustomInvalidity::NoSwapOffer)?;
+			if T::UnixTime::now().as_secs() < swap.expires_at {
+				return Err(InvalidTransaction::Future.into());
+			}
+			// Left to the maker, who can still cancel the offer.
+			if Self::expired_swap_destination(offer, &swap).is_none() {
+				return Err(CustomInvalidity::AddressAlreadyHasCoin.into());
+			}
+
+			let validity =
+				ValidTransaction::with_tag_prefix("coinage:expire-swap").and_provides(offer).into();
+			Ok((validity, Weight::zero()))
+		}
+
+		/// Validate teleporting a coin to another chain.
+		pub(crate) fn validate_teleport_coin(
+			coin: &Coin,
+			dest: &ParaId,
+		) -> Result<(), CustomInvalidity> {
+			if coin.age >= T::MaximumAge::get() {
+				return Err(CustomInvalidity::CoinTooOld);
+			}
+			ensure!(
+				T::TeleportDestinations::coinage_pallet_index(*dest).is_some(),
+				CustomInvalidity::UnknownTeleportDestination
+			);
+			Ok(())
+		}
+
+		/// Ensure the teleported coin parked at `(from, teleport_id)` can be given to its
+		/// destination.
+		pub(crate) fn ensure_can_release_parked_coin(
+			from: ParaId,
+			teleport_id: u64,
+		) -> Result<(ValidTransaction, Weight), TransactionValidityError> {
+			let (to, _) = ParkedTeleportedCoins::<T>::get((from, teleport_id))
+				.ok_or(CustomInvalidity::NoParkedCoin)?;
+			if CoinsByOwner::<T>::contains_key(&to) {
+				return Err(CustomInvalidity::AddressAlreadyHasCoin.into());
+			}
+
+			let validity = ValidTransaction::with_tag_prefix("coinage:release-parked-coin")
+				.and_provides((from, teleport_id))
+				.into();
+			Ok((validity, Weight::zero()))
+		}
+
+		/// Send a teleported coin and its underlying asset, already burnt here, to `to` with the
+		/// coinage pallet at `pallet_index` on the chain `dest`.
+		///
+		/// The coin is received by [`Pallet::receive_teleported_coin`]. If it can't be, and
+		/// `return_to` is given, it is sent back to `return_to` with
+		/// [`Pallet::return_teleported_coin`] so that its underlying asset is not stranded in the
+		/// pallet account of `dest`.
+		fn send_teleported_coin(
+			dest: ParaId,
+			pallet_index: u8,
+			teleport_id: u64,
+			to: T::AccountId,
+			coin: Coin,
+			return_to: Option<T::AccountId>,
+		) -> DispatchResult {
+			use xcm::prelude::*;
+
+			let dest_location = Location::new(1, [Parachain(dest.into())]);
+			let asset_amount = Self::coin_value_to_asset_amount(coin.value)
+				.map_err(|e| e.into_pallet_error::<T>())?;
+			let asset_id = Self::underlying_asset_id()?;
+			let asset_location = T::UnderlyingAssetLocation::convert_back(&asset_id)
+				.ok_or(Error::<T>::UnknownLocation)?
+				.reanchored(&dest_location, &T::UniversalLocation::get())
+				.map_err(|_| Error::<T>::UnknownLocation)?;
+			let beneficiary = T::AccountIdToLocation::try_convert(&Self::pallet_account())
+				.map_err(|_| Error::<T>::UnknownLocation)?;
+
+			// The coinage pallet of the destination shares the call encoding and the pallet
+			// account of this pallet.
+			let backing: Asset = (asset_location, asset_amount.saturated_into::<u128>()).into();
+			let transact = |call: Call<T>| Transact {
+				origin_kind: OriginKind::Native,
+				call: (pallet_index, call).encode().into(),
+				fallback_max_weight: None,
+			};
+			let mut message = alloc::vec![
+				UnpaidExecution { weight_limit: Unlimited, check_origin: None },
+				ReceiveTeleportedAsset(backing.into()),
+				DepositAsset { assets: Wild(AllCounted(1)), beneficiary },
+			];
+			if let Some(return_to) = return_to {
+				message.push(SetErrorHandler(Xcm(alloc::vec![transact(
+					Call::<T>::return_teleported_coin { teleport_id, to: return_to, coin }
+				)])));
+			}
+			message.push(transact(Call::<T>::receive_teleported_coin { teleport_id, to, coin }));
+			// A failed dispatch doesn't fail the program on its own.
+			message.push(ExpectTransactStatus(MaybeErrorCode::Success));
+
+			send_xcm::<T::XcmSender>(dest_location, Xcm(message))
+				.map_err(|_| Error::<T>::XcmSendFailed)?;
+			Ok(())
+		}
+
+		/// Keys of a storage map to check in a run of the offchain worker.
+		///
+		/// Returns at most [`OFFCHAIN_WORKER_SCAN_LIMIT`] keys from `keys_after`, which iterates
+		/// the keys of the map following the given key, or all of them. The next run resumes after
+		/// the last returned key, kept under `cursor` in the offchain storage, and the scan starts
+		/// over once the end of the map is reached.
+		pub(crate) fn offchain_scan<K, I>(
+			cursor: &[u8],
+			keys_after: impl FnOnce(Option<K>) -> I,
+		) -> Vec<K>
+		where
+			K: codec::FullCodec,
+			I: Iterator<Item = K>,
+		{
+			let limit = OFFCHAIN_WORKER_SCAN_LIMIT as usize;
+			let mut cursor = sp_runtime::offchain::storage::StorageValueRef::persistent(cursor);
+			let last = cursor.get::<K>().ok().flatten();
+			let keys: Vec<K> = keys_after(last).take(limit).collect();
+			match keys.last() {
+				Some(last) if keys.len() == limit => cursor.set(last),
+				_ => cursor.clear(),
+			}
+			keys
+		}
+
+		/// The payload signed by the owner of an additional coin consumed by a merge, given the
+		/// message proven for the transaction.
+		pub fn merge_signing_payload(proven_msg: &[u8; 32]) -> Vec<u8> {
+			[&MERGE_COIN_SIGNING_PREFIX[..], &proven_msg[..]].concat()
+		}
+
 		/// Validate direct offboarding from coin origin into external asset.
 		pub(crate) fn validate_direct_offboard_coin_into_external_asset(
 			coin: &Coin,
@@ -4092,4 +5073,30 @@ pub trait BenchmarkHelper<T: Config> {
 		let _ = count;
 		Err(frame_benchmarking::BenchmarkError::Weightless)
 	}
+
+	/// Create the coin owner account for `index` and its [`Config::CoinSignature`] of
+	/// `payload`, used to authorize additional inputs of a merge.
+	///
+	/// This helper is optional for runtimes. The default implementation returns
+	/// `Err(Weightless)`, so runtimes that do not benchmark merges do not need to implement it.
+	fn sign_merge_input(
+		index: u32,
+		payload: &[u8],
+	) -> Result<(T::AccountId, T::CoinSignature), frame_benchmarking::BenchmarkError> {
+		let _ = (index, payload);
+		Err(frame_benchmarking::BenchmarkError::Weightless)
+	}
+
+	/// Make a sibling chain one of [`Config::TeleportDestinations`], with the chain ready to send
+	/// XCM messages to it, and return its id.
+	///
+	/// The chain must be the source returned by [`Config::EnsureTeleportSource`] for its
+	/// successful origin.
+	///
+	/// This helper is optional for runtimes. The default implementation returns
+	/// `Err(Weightless)`, so runtimes that do not benchmark teleports do not need to implement
+	/// it.
+	fn setup_teleport_destination() -> Result<ParaId, frame_benchmarking::BenchmarkError> {
+		Err(frame_benchmarking::BenchmarkError::Weightless)
+	}
 }
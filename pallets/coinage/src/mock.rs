@@ -28,7 +28,8 @@ use frame_support::{
 	assert_ok, derive_impl, parameter_types,
 	traits::{
 		fungibles::{InspectHold, MutateHold},
-		AsEnsureOriginWithArg, ConstU16, ConstU32, ConstU64, Currency, OffchainWorker, UnixTime,
+		AsEnsureOriginWithArg, ConstU16, ConstU32, ConstU64, Currency, EnsureOrigin,
+		OffchainWorker, UnixTime,
 	},
 	BoundedVec,
 };
@@ -53,6 +54,10 @@ use verifiable::{
 	ring::{bandersnatch::BandersnatchVrfVerifiable, RingDomainSize},
 	GenerateVerifiable,
 };
+use xcm::latest::{
+	Junction::{AccountIndex64, GeneralIndex, GlobalConsensus, PalletInstance, Parachain},
+	NetworkId,
+};
 
 pub type TransactionExtension = (AuthorizeCall<Test>, crate::extension::AsCoinage<Test>);
 
@@ -156,8 +161,10 @@ impl indiv_pallet_members::Config for Test {
 	type OnboardingQueuePageSize = ConstU32<40>;
 	type MaxFlexibleRingExponent = FlexibleRingExp;
 	type RingBuildingMemberLimit = ConstU32<100>;
+	type RingCompactionThreshold = frame_support::traits::GetDefault;
 	type OldRootRetentionDuration = ConstU64<600>;
 	type OnRingRootChange = ();
+	type CleanUpAlias = ();
 	type OffchainWorkerInterval = ConstU64<1>;
 	type ManagerOrigin = frame_system::EnsureRoot<Self::AccountId>;
 	#[cfg(feature = "runtime-benchmarks")]
@@ -189,6 +196,9 @@ pub const UNLOAD_TOKEN_TIME_PERIOD_PEOPLE_LITE_PEOPLE: u32 = 100;
 pub const MAX_FREE_UNLOAD_TOKENS_PER_TIME_PERIOD: u32 = 8;
 pub const COIN_FAILURE_LOCK_PERIOD: u64 = 5;
 pub const MAX_CONSOLIDATION: u32 = 16;
+pub const MAX_SWAP_DURATION: u64 = 100;
+pub const SIBLING_PARA_ID: u32 = 1000;
+pub const SELF_PARA_ID: u32 = 1004;
 pub const MAX_BATCH_UNPAID_LOAD: u32 = 10;
 pub const FEE_DESTINATION: u64 = 999;
 
@@ -280,6 +290,14 @@ impl crate::Config for Test {
 	type FeeDestination = ConstU64<FEE_DESTINATION>;
 	type OffchainWorkerInterval = ConstU64<1>;
 	type CoinFailureLockPeriod = ConstU64<COIN_FAILURE_LOCK_PERIOD>;
+	type MaxSwapDuration = ConstU64<MAX_SWAP_DURATION>;
+	type CoinSignature = UintAuthorityId;
+	type TeleportDestinations = MockTeleportDestinations;
+	type XcmSender = MockXcmSender;
+	type EnsureTeleportSource = MockEnsureSiblingOrigin;
+	type UnderlyingAssetLocation = MockAssetLocation;
+	type AccountIdToLocation = MockAccountIdToLocation;
+	type UniversalLocation = MockUniversalLocation;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = TestBenchmarkHelper;
 }
@@ -355,6 +373,98 @@ thread_local! {
 	pub static TIME: RefCell<Duration> = RefCell::new(Duration::default());
 }
 
+thread_local! {
+	pub static SENT_XCMS: RefCell<Vec<(xcm::latest::Location, xcm::latest::Xcm<()>)>> =
+		const { RefCell::new(Vec::new()) };
+	pub static XCM_SEND_SHOULD_FAIL: Cell<bool> = const { Cell::new(false) };
+}
+
+/// XCM sender recording the sent messages, or failing when `XCM_SEND_SHOULD_FAIL` is set.
+pub struct MockXcmSender;
+impl xcm::latest::SendXcm for MockXcmSender {
+	type Ticket = (xcm::latest::Location, xcm::latest::Xcm<()>);
+
+	fn validate(
+		destination: &mut Option<xcm::latest::Location>,
+		message: &mut Option<xcm::latest::Xcm<()>>,
+	) -> xcm::latest::SendResult<Self::Ticket> {
+		let dest = destination.take().unwrap_or(xcm::latest::Location::here());
+		let msg = message.take().unwrap_or_default();
+		Ok(((dest, msg), xcm::latest::Assets::new()))
+	}
+
+	fn deliver(ticket: Self::Ticket) -> Result<xcm::latest::XcmHash, xcm::latest::SendError> {
+		if XCM_SEND_SHOULD_FAIL.with(|f| f.get()) {
+			return Err(xcm::latest::SendError::Transport("mock failure"));
+		}
+		SENT_XCMS.with(|x| x.borrow_mut().push(ticket));
+		Ok([0u8; 32])
+	}
+}
+
+/// Origin of the sibling chain [`SIBLING_PARA_ID`], represented by the root origin.
+pub struct MockEnsureSiblingOrigin;
+impl EnsureOrigin<RuntimeOrigin> for MockEnsureSiblingOrigin {
+	type Success = ParaId;
+
+	fn try_origin(o: RuntimeOrigin) -> Result<Self::Success, RuntimeOrigin> {
+		match o.clone().into() {
+			Ok(frame_system::RawOrigin::Root) => Ok(ParaId::from(SIBLING_PARA_ID)),
+			_ => Err(o),
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+		Ok(RuntimeOrigin::root())
+	}
+}
+
+parameter_types! {
+	/// The index of the coinage pallet of the sibling chain [`SIBLING_PARA_ID`], while the
+	/// sibling chain is linked to this chain.
+	pub storage SiblingCoinageIndex: Option<u8> = None;
+	pub MockUniversalLocation: InteriorLocation =
+		[GlobalConsensus(NetworkId::Polkadot), Parachain(SELF_PARA_ID)].into();
+}
+
+/// The sibling chain [`SIBLING_PARA_ID`] as the only teleport destination.
+pub struct MockTeleportDestinations;
+impl CoinTeleportDestinations for MockTeleportDestinations {
+	fn coinage_pallet_index(para_id: ParaId) -> Option<u8> {
+		if para_id == ParaId::from(SIBLING_PARA_ID) {
+			SiblingCoinageIndex::get()
+		} else {
+			None
+		}
+	}
+}
+
+/// The assets of `pallet-assets`, located by their id.
+pub struct MockAssetLocation;
+impl MaybeEquivalence<Location, u32> for MockAssetLocation {
+	fn convert(location: &Location) -> Option<u32> {
+		let pallet_index = <Assets as frame_support::traits::PalletInfoAccess>::index() as u8;
+		match location.unpack() {
+			(0, [PalletInstance(index), GeneralIndex(id)]) if *index == pallet_index =>
+				(*id).try_into().ok(),
+			_ => None,
+		}
+	}
+
+	fn convert_back(id: &u32) -> Option<Location> {
+		let pallet_index = <Assets as frame_support::traits::PalletInfoAccess>::index() as u8;
+		Some(Location::new(0, [PalletInstance(pallet_index), GeneralIndex((*id).into())]))
+	}
+}
+
+pub struct MockAccountIdToLocation;
+impl<'a> TryConvert<&'a u64, Location> for MockAccountIdToLocation {
+	fn try_convert(who: &'a u64) -> Result<Location, &'a u64> {
+		Ok(Location::new(0, [AccountIndex64 { network: None, index: *who }]))
+	}
+}
+
 pub struct MockTime;
 impl UnixTime for MockTime {
 	fn now() -> Duration {
@@ -541,6 +651,26 @@ pub fn build_signed_as_coin_ext(signer: u64, call: crate::Call<Test>, as_coin: b
 	Extrinsic::new_signed(call.into(), signer, UintAuthorityId(signer), extension)
 }
 
+/// Helper to build a merge extrinsic signed by the coin owner `signer`, with the coins of
+/// `others` authorized through `AsCoinageInfo::AsCoins`.
+///
+/// `UintAuthorityId` signatures are valid for any message, so the other coins are authorized by
+/// their account id.
+pub fn build_merge_ext(signer: u64, others: &[u64], to: u64) -> Extrinsic {
+	let other_coins =
+		others.iter().map(|other| (*other, UintAuthorityId(*other))).collect::<Vec<_>>();
+	let info = AsCoinageInfo::AsCoins {
+		other_coins: other_coins.try_into().expect("others must fit MaxConsolidation"),
+	};
+	let extension = (AuthorizeCall::<Test>::new(), AsCoinage::<Test>::new(Some(info)));
+	Extrinsic::new_signed(
+		crate::Call::merge { to }.into(),
+		signer,
+		UintAuthorityId(signer),
+		extension,
+	)
+}
+
 thread_local! {
 	static UNIQUE_SECRET_COUNTER: std::cell::Cell<usize> = const { std::cell::Cell::new(10000) };
 }
@@ -1041,4 +1171,17 @@ impl crate::BenchmarkHelper<Test> for TestBenchmarkHelper {
 
 		Ok((value, ring_index, aliases, proofs, proven_msg))
 	}
+
+	fn sign_merge_input(
+		index: u32,
+		_payload: &[u8],
+	) -> Result<(u64, UintAuthorityId), frame_benchmarking::BenchmarkError> {
+		let who = 10_000 + u64::from(index);
+		Ok((who, UintAuthorityId(who)))
+	}
+
+	fn setup_teleport_destination() -> Result<ParaId, frame_benchmarking::BenchmarkError> {
+		SiblingCoinageIndex::set(&Some(0));
+		Ok(ParaId::from(SIBLING_PARA_ID))
+	}
 }
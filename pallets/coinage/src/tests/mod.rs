@@ -27,6 +27,7 @@ mod test_infallible_unpaid_ext;
 mod test_integrity;
 mod test_load_recycler;
 mod test_load_recycler_with_external_asset;
+mod test_merge;
 mod test_minimum_fee_coin_value_for_output_unload;
 mod test_paid_ring_lifecycle;
 mod test_pay_for_recycler_unload_fee_token_with_coin;
@@ -35,6 +36,8 @@ mod test_pay_for_recycler_unload_fee_token_with_native;
 mod test_recycler_lifecycle;
 mod test_set_underlying_asset_id;
 mod test_split;
+mod test_swap;
+mod test_teleport;
 mod test_transfer;
 mod test_unload_recycler_into_coin;
 mod test_unload_recycler_into_coins;
@@ -0,0 +1,211 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Individuality.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+	extension::{AsCoinage, Pre},
+	mock::*,
+	pallet::CustomInvalidity,
+	*,
+};
+use frame_support::assert_ok;
+use sp_runtime::{
+	traits::TransactionExtension as _, transaction_validity::TransactionSource, DispatchError,
+};
+
+#[test]
+fn merge_valid_success() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let dest = 10;
+		CoinsByOwner::<Test>::insert(1, Coin { value: -1, age: 3 });
+		CoinsByOwner::<Test>::insert(2, Coin { value: -2, age: 7 });
+		CoinsByOwner::<Test>::insert(3, Coin { value: -2, age: 0 });
+
+		let ext = build_merge_ext(1, &[2, 3], dest);
+		assert_eq!(Executive::apply_extrinsic(ext), Ok(Ok(())));
+
+		for coin_id in [1, 2, 3] {
+			assert!(!CoinsByOwner::<Test>::contains_key(coin_id), "Input coin should be removed");
+		}
+		assert_eq!(CoinsByOwner::<Test>::get(dest), Some(Coin { value: 0, age: 8 }));
+		System::assert_has_event(
+			crate::Event::<Test>::CoinsMerged { to: dest, input_count: 3, value: 0, new_age: 8 }
+				.into(),
+		);
+	});
+}
+
+#[test]
+fn merge_total_not_power_of_two_invalid() {
+	new_test_ext().execute_with(|| {
+		CoinsByOwner::<Test>::insert(1, Coin { value: -1, age: 0 });
+		CoinsByOwner::<Test>::insert(2, Coin { value: -2, age: 0 });
+
+		assert_invalid(build_merge_ext(1, &[2], 10), CustomInvalidity::InvalidMerge);
+	});
+}
+
+#[test]
+fn merge_total_above_maximum_exponent_invalid() {
+	new_test_ext().execute_with(|| {
+		let max = MaximumExponent::get();
+		CoinsByOwner::<Test>::insert(1, Coin { value: max, age: 0 });
+		CoinsByOwner::<Test>::insert(2, Coin { value: max, age: 0 });
+
+		assert_invalid(build_merge_ext(1, &[2], 10), CustomInvalidity::InvalidMerge);
+	});
+}
+
+#[test]
+fn merge_single_input_invalid() {
+	new_test_ext().execute_with(|| {
+		CoinsByOwner::<Test>::insert(1, Coin { value: 0, age: 0 });
+
+		assert_invalid(build_merge_ext(1, &[], 10), CustomInvalidity::TooFewMergeInputs);
+	});
+}
+
+#[test]
+fn merge_too_many_inputs_invalid() {
+	new_test_ext().execute_with(|| {
+		// The signer's coin plus `MAX_CONSOLIDATION` others exceeds the bound.
+		let others = (2..2 + u64::from(MAX_CONSOLIDATION)).collect::<Vec<_>>();
+		for coin_id in core::iter::once(1).chain(others.iter().copied()) {
+			CoinsByOwner::<Test>::insert(coin_id, Coin { value: -2, age: 0 });
+		}
+
+		assert_invalid(build_merge_ext(1, &others, 100), CustomInvalidity::TooManyMergeInputs);
+	});
+}
+
+#[test]
+fn merge_duplicate_inputs_invalid() {
+	new_test_ext().execute_with(|| {
+		CoinsByOwner::<Test>::insert(1, Coin { value: -2, age: 0 });
+		CoinsByOwner::<Test>::insert(2, Coin { value: -2, age: 0 });
+
+		assert_invalid(build_merge_ext(1, &[2, 1, 2], 10), CustomInvalidity::DuplicateMergeInputs);
+	});
+}
+
+#[test]
+fn merge_missing_input_coin_invalid() {
+	new_test_ext().execute_with(|| {
+		CoinsByOwner::<Test>::insert(1, Coin { value: -2, age: 0 });
+
+		assert_invalid(build_merge_ext(1, &[2], 10), CustomInvalidity::NoCoin);
+	});
+}
+
+#[test]
+fn merge_input_too_old_invalid() {
+	new_test_ext().execute_with(|| {
+		let max_age = get_u16::<<Test as Config>::MaximumAge>();
+		CoinsByOwner::<Test>::insert(1, Coin { value: -2, age: 0 });
+		CoinsByOwner::<Test>::insert(2, Coin { value: -2, age: max_age });
+
+		assert_invalid(build_merge_ext(1, &[2], 10), CustomInvalidity::CoinTooOld);
+	});
+}
+
+#[test]
+fn merge_into_input_or_existing_coin_invalid() {
+	new_test_ext().execute_with(|| {
+		CoinsByOwner::<Test>::insert(1, Coin { value: -2, age: 0 });
+		CoinsByOwner::<Test>::insert(2, Coin { value: -2, age: 0 });
+		CoinsByOwner::<Test>::insert(10, Coin { value: 3, age: 0 });
+
+		assert_invalid(build_merge_ext(1, &[2], 2), CustomInvalidity::AddressAlreadyHasCoin);
+		assert_invalid(build_merge_ext(1, &[2], 10), CustomInvalidity::AddressAlreadyHasCoin);
+	});
+}
+
+#[test]
+fn merge_locked_input_invalid() {
+	new_test_ext().execute_with(|| {
+		CoinsByOwner::<Test>::insert(1, Coin { value: -2, age: 0 });
+		CoinsByOwner::<Test>::insert(2, Coin { value: -2, age: 0 });
+		LockedCoins::<Test>::insert(
+			2,
+			LockedCoin { reason: LockReason::FailedDispatch { retries: 0 }, until: u64::MAX },
+		);
+
+		assert_invalid(build_merge_ext(1, &[2], 10), CustomInvalidity::CoinTemporarilyLocked);
+	});
+}
+
+#[test]
+fn merge_provides_tag_for_each_input() {
+	new_test_ext().execute_with(|| {
+		CoinsByOwner::<Test>::insert(1, Coin { value: -2, age: 0 });
+		CoinsByOwner::<Test>::insert(2, Coin { value: -2, age: 0 });
+
+		let validity = Executive::validate_transaction(
+			TransactionSource::External,
+			build_merge_ext(1, &[2], 10),
+			Default::default(),
+		)
+		.expect("merge should be valid");
+		assert_eq!(validity.provides.len(), 2);
+
+		// A transfer of one of the merged coins conflicts with the merge in the pool.
+		let transfer = build_signed_as_coin_ext(2, crate::Call::transfer { to: 11 }, true);
+		let transfer_validity = Executive::validate_transaction(
+			TransactionSource::External,
+			transfer,
+			Default::default(),
+		)
+		.expect("transfer should be valid");
+		assert!(validity.provides.contains(&transfer_validity.provides[0]));
+	});
+}
+
+#[test]
+fn merge_bad_origin_fail() {
+	new_test_ext().execute_with(|| {
+		CoinsByOwner::<Test>::insert(1, Coin { value: -2, age: 0 });
+
+		// A single coin origin can't be used to merge.
+		let ext = build_signed_as_coin_ext(1, crate::Call::merge { to: 10 }, true);
+		assert_invalid(ext, CustomInvalidity::InvalidCall);
+
+		let ext = build_signed_as_coin_ext(1, crate::Call::merge { to: 10 }, false);
+		let res = Executive::apply_extrinsic(ext);
+		assert_ok!(res.as_ref());
+		assert_eq!(res.unwrap(), Err(DispatchError::BadOrigin));
+	});
+}
+
+#[test]
+fn merge_failed_dispatch_restores_and_locks_all_inputs() {
+	new_test_ext().execute_with(|| {
+		let coins = vec![(1u64, Coin { value: -2, age: 0 }), (2u64, Coin { value: -2, age: 4 })];
+		let pre = Pre::<Test>::UsingCoins { coins: coins.clone() };
+		let info = Default::default();
+		let post_info = Default::default();
+		let err = Err(DispatchError::Other("failed"));
+
+		assert_ok!(AsCoinage::<Test>::post_dispatch_details(pre, &info, &post_info, 0, &err));
+
+		for (coin_id, coin) in coins {
+			assert_eq!(CoinsByOwner::<Test>::get(coin_id), Some(coin));
+			assert_eq!(
+				LockedCoins::<Test>::get(coin_id).map(|locked| locked.reason),
+				Some(LockReason::FailedDispatch { retries: 0 })
+			);
+		}
+	});
+}
@@ -0,0 +1,371 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Individuality.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{mock::*, pallet::CustomInvalidity, *};
+use core::time::Duration;
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::{
+	transaction_validity::{InvalidTransaction, TransactionSource, TransactionValidityError},
+	DispatchError,
+};
+
+fn set_time(secs: u64) {
+	TIME.with(|t| *t.borrow_mut() = Duration::from_secs(secs));
+}
+
+fn fund(who: u64, amount: u64) {
+	assert_ok!(Assets::mint(RuntimeOrigin::signed(1), TEST_ASSET_ID, who, amount));
+}
+
+/// Helper to build an offer_swap extrinsic.
+fn build_offer_ext(signer: u64, ask: SwapAskOf<Test>, receiver: u64, duration: u64) -> Extrinsic {
+	build_signed_as_coin_ext(signer, crate::Call::offer_swap { ask, receiver, duration }, true)
+}
+
+/// Helper to build an accept_swap_with_coin extrinsic.
+fn build_accept_ext(signer: u64, offer: u64, to: u64) -> Extrinsic {
+	build_signed_as_coin_ext(signer, crate::Call::accept_swap_with_coin { offer, to }, true)
+}
+
+/// Helper to build a cancel_swap extrinsic.
+fn build_cancel_ext(signer: u64, to: u64) -> Extrinsic {
+	build_signed_as_coin_ext(signer, crate::Call::cancel_swap { to }, true)
+}
+
+#[test]
+fn offer_swap_valid_success() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		set_time(10);
+		CoinsByOwner::<Test>::insert(1, Coin { value: 2, age: 3 });
+
+		let ask = SwapAsk::Coin { value: 1 };
+		assert_eq!(Executive::apply_extrinsic(build_offer_ext(1, ask.clone(), 5, 20)), Ok(Ok(())));
+
+		assert!(!CoinsByOwner::<Test>::contains_key(1), "Offered coin should be escrowed");
+		assert_eq!(
+			SwapOffers::<Test>::get(1),
+			Some(SwapOffer {
+				coin: Coin { value: 2, age: 3 },
+				ask: ask.clone(),
+				receiver: 5,
+				expires_at: 30
+			})
+		);
+		System::assert_has_event(
+			crate::Event::<Test>::SwapOffered {
+				offer: 1,
+				value: 2,
+				ask,
+				receiver: 5,
+				expires_at: 30,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn offer_swap_invalid_duration_invalid() {
+	new_test_ext().execute_with(|| {
+		CoinsByOwner::<Test>::insert(1, Coin { value: 0, age: 0 });
+		let ask = SwapAsk::Coin { value: 0 };
+
+		assert_invalid(
+			build_offer_ext(1, ask.clone(), 5, 0),
+			CustomInvalidity::InvalidSwapDuration,
+		);
+		assert_invalid(
+			build_offer_ext(1, ask, 5, MAX_SWAP_DURATION + 1),
+			CustomInvalidity::InvalidSwapDuration,
+		);
+	});
+}
+
+#[test]
+fn offer_swap_invalid_ask_invalid() {
+	new_test_ext().execute_with(|| {
+		CoinsByOwner::<Test>::insert(1, Coin { value: 0, age: 0 });
+
+		let too_big = SwapAsk::Coin { value: MaximumExponent::get() + 1 };
+		assert_invalid(build_offer_ext(1, too_big, 5, 10), CustomInvalidity::InvalidSwapAsk);
+		let nothing = SwapAsk::Asset { id: TEST_ASSET_ID, amount: 0 };
+		assert_invalid(build_offer_ext(1, nothing, 5, 10), CustomInvalidity::InvalidSwapAsk);
+	});
+}
+
+#[test]
+fn offer_swap_already_exists_invalid() {
+	new_test_ext().execute_with(|| {
+		CoinsByOwner::<Test>::insert(1, Coin { value: 0, age: 0 });
+		let ask = SwapAsk::Coin { value: 0 };
+		assert_eq!(Executive::apply_extrinsic(build_offer_ext(1, ask.clone(), 5, 10)), Ok(Ok(())));
+
+		// The address received a new coin, but its previous offer is still pending.
+		CoinsByOwner::<Test>::insert(1, Coin { value: 0, age: 0 });
+		assert_invalid(build_offer_ext(1, ask, 5, 10), CustomInvalidity::SwapOfferAlreadyExists);
+	});
+}
+
+#[test]
+fn accept_swap_with_coin_valid_success() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		CoinsByOwner::<Test>::insert(1, Coin { value: 2, age: 3 });
+		CoinsByOwner::<Test>::insert(2, Coin { value: -1, age: 6 });
+		let ask = SwapAsk::Coin { value: -1 };
+		assert_eq!(Executive::apply_extrinsic(build_offer_ext(1, ask, 5, 10)), Ok(Ok(())));
+
+		assert_eq!(Executive::apply_extrinsic(build_accept_ext(2, 1, 6)), Ok(Ok(())));
+
+		assert!(!SwapOffers::<Test>::contains_key(1), "Offer should be consumed");
+		assert!(!CoinsByOwner::<Test>::contains_key(2), "Acceptor coin should be consumed");
+		assert_eq!(CoinsByOwner::<Test>::get(5), Some(Coin { value: -1, age: 7 }));
+		assert_eq!(CoinsByOwner::<Test>::get(6), Some(Coin { value: 2, age: 4 }));
+		System::assert_has_event(crate::Event::<Test>::SwapAccepted { offer: 1, to: 6 }.into());
+	});
+}
+
+#[test]
+fn accept_swap_with_coin_ask_mismatch_invalid() {
+	new_test_ext().execute_with(|| {
+		CoinsByOwner::<Test>::insert(2, Coin { value: 0, age: 0 });
+		SwapOffers::<Test>::insert(
+			1,
+			SwapOffer {
+				coin: Coin { value: 2, age: 0 },
+				ask: SwapAsk::Coin { value: 1 },
+				receiver: 5,
+				expires_at: 10,
+			},
+		);
+		assert_invalid(build_accept_ext(2, 1, 6), CustomInvalidity::SwapAskMismatch);
+
+		// An offer asking for an asset can't be accepted with a coin.
+		SwapOffers::<Test>::mutate(1, |swap| {
+			swap.as_mut().unwrap().ask = SwapAsk::Asset { id: TEST_ASSET_ID, amount: 1 };
+		});
+		assert_invalid(build_accept_ext(2, 1, 6), CustomInvalidity::SwapAskMismatch);
+	});
+}
+
+#[test]
+fn accept_swap_with_coin_expired_invalid() {
+	new_test_ext().execute_with(|| {
+		CoinsByOwner::<Test>::insert(1, Coin { value: 0, age: 0 });
+		CoinsByOwner::<Test>::insert(2, Coin { value: 0, age: 0 });
+		let ask = SwapAsk::Coin { value: 0 };
+		assert_eq!(Executive::apply_extrinsic(build_offer_ext(1, ask, 5, 10)), Ok(Ok(())));
+
+		set_time(10);
+		assert_invalid(build_accept_ext(2, 1, 6), CustomInvalidity::SwapOfferExpired);
+	});
+}
+
+#[test]
+fn accept_swap_with_coin_destinations_invalid() {
+	new_test_ext().execute_with(|| {
+		CoinsByOwner::<Test>::insert(1, Coin { value: 0, age: 0 });
+		CoinsByOwner::<Test>::insert(2, Coin { value: 0, age: 0 });
+		CoinsByOwner::<Test>::insert(3, Coin { value: 0, age: 0 });
+
+		assert_invalid(build_accept_ext(2, 1, 6), CustomInvalidity::NoSwapOffer);
+
+		let ask = SwapAsk::Coin { value: 0 };
+		assert_eq!(Executive::apply_extrinsic(build_offer_ext(1, ask, 5, 10)), Ok(Ok(())));
+
+		assert_invalid(build_accept_ext(2, 1, 5), CustomInvalidity::DuplicateSwapDestinations);
+		assert_invalid(build_accept_ext(2, 1, 3), CustomInvalidity::AddressAlreadyHasCoin);
+	});
+}
+
+#[test]
+fn accept_swap_with_asset_valid_success() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		CoinsByOwner::<Test>::insert(1, Coin { value: 1, age: 2 });
+		let ask = SwapAsk::Asset { id: TEST_ASSET_ID, amount: 500 };
+		assert_eq!(Executive::apply_extrinsic(build_offer_ext(1, ask, 5, 10)), Ok(Ok(())));
+		fund(2, 1_000);
+
+		assert_ok!(Coinage::accept_swap_with_asset(
+			RuntimeOrigin::signed(2),
+			CodecPreservation::Expendable,
+			1,
+			6,
+		));
+
+		assert_eq!(Assets::balance(TEST_ASSET_ID, 2), 500);
+		assert_eq!(Assets::balance(TEST_ASSET_ID, 5), 500);
+		assert_eq!(CoinsByOwner::<Test>::get(6), Some(Coin { value: 1, age: 3 }));
+		assert!(!SwapOffers::<Test>::contains_key(1), "Offer should be consumed");
+		System::assert_has_event(crate::Event::<Test>::SwapAccepted { offer: 1, to: 6 }.into());
+	});
+}
+
+#[test]
+fn accept_swap_with_asset_fail() {
+	new_test_ext().execute_with(|| {
+		CoinsByOwner::<Test>::insert(1, Coin { value: 1, age: 0 });
+		let ask = SwapAsk::Asset { id: TEST_ASSET_ID, amount: 500 };
+		assert_eq!(Executive::apply_extrinsic(build_offer_ext(1, ask, 5, 10)), Ok(Ok(())));
+		fund(2, 100);
+
+		// Not enough balance to pay the asked amount: the offer stays in place.
+		assert!(Coinage::accept_swap_with_asset(
+			RuntimeOrigin::signed(2),
+			CodecPreservation::Expendable,
+			1,
+			6,
+		)
+		.is_err());
+		assert!(SwapOffers::<Test>::contains_key(1));
+
+		set_time(10);
+		fund(2, 1_000);
+		assert_noop!(
+			Coinage::accept_swap_with_asset(
+				RuntimeOrigin::signed(2),
+				CodecPreservation::Expendable,
+				1,
+				6,
+			),
+			Error::<Test>::SwapOfferExpired
+		);
+	});
+}
+
+#[test]
+fn expire_swap_gives_coin_back_to_receiver() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		CoinsByOwner::<Test>::insert(1, Coin { value: 0, age: 4 });
+		let ask = SwapAsk::Coin { value: 0 };
+		assert_eq!(Executive::apply_extrinsic(build_offer_ext(1, ask, 5, 10)), Ok(Ok(())));
+
+		let ext = build_authorized_ext(crate::Call::expire_swap { offer: 1 });
+		assert_eq!(
+			Executive::validate_transaction(TransactionSource::Local, ext, Default::default()),
+			Err(TransactionValidityError::Invalid(InvalidTransaction::Future))
+		);
+
+		set_time(10);
+		let ext = build_authorized_ext(crate::Call::expire_swap { offer: 1 });
+		assert_eq!(Executive::apply_extrinsic(ext), Ok(Ok(())));
+
+		assert!(!SwapOffers::<Test>::contains_key(1));
+		assert_eq!(CoinsByOwner::<Test>::get(5), Some(Coin { value: 0, age: 5 }));
+		System::assert_has_event(crate::Event::<Test>::SwapExpired { offer: 1, to: 5 }.into());
+	});
+}
+
+#[test]
+fn expire_swap_gives_coin_back_to_offer_when_receiver_has_coin() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		CoinsByOwner::<Test>::insert(1, Coin { value: 0, age: 4 });
+		let ask = SwapAsk::Coin { value: 0 };
+		assert_eq!(Executive::apply_extrinsic(build_offer_ext(1, ask, 5, 10)), Ok(Ok(())));
+		CoinsByOwner::<Test>::insert(5, Coin { value: 1, age: 0 });
+
+		set_time(10);
+		let ext = build_authorized_ext(crate::Call::expire_swap { offer: 1 });
+		assert_eq!(Executive::apply_extrinsic(ext), Ok(Ok(())));
+
+		assert!(!SwapOffers::<Test>::contains_key(1));
+		assert_eq!(CoinsByOwner::<Test>::get(1), Some(Coin { value: 0, age: 5 }));
+		assert_eq!(CoinsByOwner::<Test>::get(5), Some(Coin { value: 1, age: 0 }));
+		System::assert_has_event(crate::Event::<Test>::SwapExpired { offer: 1, to: 1 }.into());
+	});
+}
+
+#[test]
+fn expire_swap_is_left_to_maker_when_both_have_coin() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		CoinsByOwner::<Test>::insert(1, Coin { value: 0, age: 4 });
+		let ask = SwapAsk::Coin { value: 0 };
+		assert_eq!(Executive::apply_extrinsic(build_offer_ext(1, ask, 5, 10)), Ok(Ok(())));
+		CoinsByOwner::<Test>::insert(1, Coin { value: 1, age: 0 });
+		CoinsByOwner::<Test>::insert(5, Coin { value: 1, age: 0 });
+
+		set_time(10);
+		let ext = build_authorized_ext(crate::Call::expire_swap { offer: 1 });
+		assert_eq!(
+			Executive::validate_transaction(TransactionSource::Local, ext, Default::default()),
+			Err(CustomInvalidity::AddressAlreadyHasCoin.into())
+		);
+
+		// The maker can still cancel the offer to a fresh address.
+		assert_eq!(Executive::apply_extrinsic(build_cancel_ext(1, 6)), Ok(Ok(())));
+		assert_eq!(CoinsByOwner::<Test>::get(6), Some(Coin { value: 0, age: 5 }));
+	});
+}
+
+#[test]
+fn cancel_swap_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		CoinsByOwner::<Test>::insert(1, Coin { value: 2, age: 3 });
+		CoinsByOwner::<Test>::insert(6, Coin { value: 0, age: 0 });
+		let ask = SwapAsk::Coin { value: 0 };
+		assert_eq!(Executive::apply_extrinsic(build_offer_ext(1, ask, 5, 10)), Ok(Ok(())));
+
+		assert_eq!(
+			Executive::validate_transaction(
+				TransactionSource::External,
+				build_cancel_ext(2, 7),
+				Default::default()
+			),
+			Err(CustomInvalidity::NoSwapOffer.into())
+		);
+		assert_eq!(
+			Executive::validate_transaction(
+				TransactionSource::External,
+				build_cancel_ext(1, 6),
+				Default::default()
+			),
+			Err(CustomInvalidity::AddressAlreadyHasCoin.into())
+		);
+		assert_noop!(Coinage::cancel_swap(RuntimeOrigin::signed(1), 7), DispatchError::BadOrigin);
+
+		// The offer can be cancelled before it expires.
+		assert_eq!(Executive::apply_extrinsic(build_cancel_ext(1, 7)), Ok(Ok(())));
+
+		assert!(!SwapOffers::<Test>::contains_key(1));
+		assert_eq!(CoinsByOwner::<Test>::get(7), Some(Coin { value: 2, age: 4 }));
+		System::assert_has_event(crate::Event::<Test>::SwapCancelled { offer: 1, to: 7 }.into());
+	});
+}
+
+#[test]
+fn accept_swap_with_coin_dispatch_checks_destinations() {
+	new_test_ext().execute_with(|| {
+		CoinsByOwner::<Test>::insert(1, Coin { value: 0, age: 0 });
+		let ask = SwapAsk::Coin { value: 0 };
+		assert_eq!(Executive::apply_extrinsic(build_offer_ext(1, ask, 5, 10)), Ok(Ok(())));
+
+		// The receiver got a coin after the acceptance was validated.
+		CoinsByOwner::<Test>::insert(5, Coin { value: 1, age: 0 });
+		let origin = pallet::Origin::<Test>::Coin { coin_id: 2, coin: Coin { value: 0, age: 0 } };
+		assert_noop!(
+			Coinage::accept_swap_with_coin(RuntimeOrigin::from(origin), 1, 6),
+			Error::<Test>::AddressAlreadyHasCoin
+		);
+		assert_eq!(CoinsByOwner::<Test>::get(5), Some(Coin { value: 1, age: 0 }));
+		assert!(SwapOffers::<Test>::contains_key(1));
+	});
+}
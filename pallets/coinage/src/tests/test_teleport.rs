@@ -0,0 +1,450 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Individuality.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{mock::*, pallet::CustomInvalidity, *};
+use codec::{Decode, Encode};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{fungibles::InspectHold, PalletInfoAccess, UnfilteredDispatchable},
+};
+use sp_runtime::{
+	transaction_validity::{InvalidTransaction, TransactionSource, TransactionValidityError},
+	DispatchError, TokenError,
+};
+use xcm::latest::{
+	prelude::{AllCounted, MaybeErrorCode, Wild},
+	Asset, Instruction,
+	Junction::{AccountIndex64, GeneralIndex, PalletInstance, Parachain},
+	Location,
+};
+
+/// The pallet index of the coinage pallet of the sibling chain.
+const SIBLING_COINAGE_INDEX: u8 = 42;
+
+fn sibling() -> ParaId {
+	ParaId::from(SIBLING_PARA_ID)
+}
+
+fn link_sibling() {
+	SiblingCoinageIndex::set(&Some(SIBLING_COINAGE_INDEX));
+}
+
+/// Deposit the underlying asset of `coin` to the pallet account, as the XCM program of a
+/// teleported coin does before dispatching [`Pallet::receive_teleported_coin`].
+fn deposit_teleported_backing(coin: Coin) {
+	let amount = Coinage::coin_value_to_asset_amount(coin.value).unwrap();
+	assert_ok!(Assets::mint(
+		RuntimeOrigin::signed(ALICE),
+		TEST_ASSET_ID,
+		Coinage::pallet_account(),
+		amount
+	));
+}
+
+fn held_backing() -> u64 {
+	AssetsWithHolder::balance_on_hold(
+		TEST_ASSET_ID,
+		&HoldReason::Wrapped.into(),
+		&Coinage::pallet_account(),
+	)
+}
+
+/// Helper to build a teleport_coin extrinsic.
+fn build_teleport_ext(signer: u64, dest: ParaId, to: u64) -> Extrinsic {
+	build_signed_as_coin_ext(signer, crate::Call::teleport_coin { dest, to }, true)
+}
+
+/// The encoded call of the `Transact` instruction of a sent message.
+fn transact_call(message: &xcm::latest::Xcm<()>) -> Vec<u8> {
+	message
+		.0
+		.iter()
+		.find_map(|instruction| match instruction {
+			Instruction::Transact { call, .. } => Some(call.clone().into_encoded()),
+			_ => None,
+		})
+		.expect("message has a Transact instruction")
+}
+
+/// The encoded call of the error handler of a sent message, if any.
+fn error_handler_call(message: &xcm::latest::Xcm<()>) -> Option<Vec<u8>> {
+	message.0.iter().find_map(|instruction| match instruction {
+		Instruction::SetErrorHandler(handler) => Some(transact_call(handler)),
+		_ => None,
+	})
+}
+
+fn free_balance() -> u64 {
+	Assets::balance(TEST_ASSET_ID, Coinage::pallet_account()) - held_backing()
+}
+
+#[test]
+fn teleport_coin_valid_success() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		link_sibling();
+		let coin = Coin { value: 2, age: 5 };
+		create_coin(1, coin.value, coin.age);
+		let amount = Coinage::coin_value_to_asset_amount(coin.value).unwrap();
+		let held = held_backing();
+
+		assert_eq!(Executive::apply_extrinsic(build_teleport_ext(1, sibling(), 7)), Ok(Ok(())));
+
+		assert!(!CoinsByOwner::<Test>::contains_key(1), "Teleported coin should be burnt");
+		assert_eq!(held_backing(), held - amount, "The backing should be burnt with the coin");
+		check_accounting();
+		assert_eq!(NextTeleportId::<Test>::get(), 1);
+		System::assert_has_event(
+			crate::Event::<Test>::CoinTeleported {
+				dest: sibling(),
+				teleport_id: 0,
+				to: 7,
+				value: 2,
+				age: 5,
+			}
+			.into(),
+		);
+
+		let sent = SENT_XCMS.with(|x| x.borrow().clone());
+		assert_eq!(sent.len(), 1);
+		let (dest, message) = &sent[0];
+		assert_eq!(*dest, Location::new(1, [Parachain(SIBLING_PARA_ID)]));
+		let pallet_index = <Assets as PalletInfoAccess>::index() as u8;
+		let backing: Asset = (
+			Location::new(
+				1,
+				[
+					Parachain(SELF_PARA_ID),
+					PalletInstance(pallet_index),
+					GeneralIndex(TEST_ASSET_ID.into()),
+				],
+			),
+			u128::from(amount),
+		)
+			.into();
+		assert_eq!(message.0[1], Instruction::ReceiveTeleportedAsset(backing.into()));
+		assert_eq!(
+			message.0[2],
+			Instruction::DepositAsset {
+				assets: Wild(AllCounted(1)),
+				beneficiary: Location::new(
+					0,
+					[AccountIndex64 { network: None, index: Coinage::pallet_account() }],
+				),
+			}
+		);
+		let expected_call = (
+			SIBLING_COINAGE_INDEX,
+			crate::Call::<Test>::receive_teleported_coin { teleport_id: 0, to: 7, coin },
+		)
+			.encode();
+		assert_eq!(transact_call(message), expected_call);
+		// The coin is sent back to its account if it can't be received.
+		let return_call = (
+			SIBLING_COINAGE_INDEX,
+			crate::Call::<Test>::return_teleported_coin { teleport_id: 0, to: 1, coin },
+		)
+			.encode();
+		assert_eq!(error_handler_call(message), Some(return_call));
+		assert_eq!(
+			message.0.last(),
+			Some(&Instruction::ExpectTransactStatus(MaybeErrorCode::Success))
+		);
+	});
+}
+
+#[test]
+fn teleport_coin_round_trip_recreates_coin() {
+	new_test_ext().execute_with(|| {
+		// Link the chain as its own counterpart, so that the sent call can be dispatched here as
+		// it would be on the destination.
+		SiblingCoinageIndex::set(&Some(Coinage::index() as u8));
+		let coin = Coin { value: -1, age: 3 };
+		create_coin(1, coin.value, coin.age);
+		let held = held_backing();
+
+		assert_eq!(Executive::apply_extrinsic(build_teleport_ext(1, sibling(), 7)), Ok(Ok(())));
+
+		let (_, message) = SENT_XCMS.with(|x| x.borrow()[0].clone());
+		let call = RuntimeCall::decode(&mut &transact_call(&message)[..]).unwrap();
+		deposit_teleported_backing(coin);
+		assert_ok!(call.dispatch_bypass_filter(RuntimeOrigin::root()));
+		assert_eq!(CoinsByOwner::<Test>::get(7), Some(coin));
+		assert_eq!(held_backing(), held);
+		check_accounting();
+	});
+}
+
+#[test]
+fn teleport_coin_failed_receive_returns_coin() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		// Link the chain as its own counterpart, so that the sent calls can be dispatched here as
+		// they would be on the destination.
+		SiblingCoinageIndex::set(&Some(Coinage::index() as u8));
+		let coin = Coin { value: 1, age: 2 };
+		create_coin(1, coin.value, coin.age);
+		let held = held_backing();
+
+		assert_eq!(Executive::apply_extrinsic(build_teleport_ext(1, sibling(), 7)), Ok(Ok(())));
+
+		// The backing is deposited but `receive_teleported_coin` fails, so the error handler of
+		// the program returns the coin.
+		let (_, message) = SENT_XCMS.with(|x| x.borrow_mut().remove(0));
+		let return_call =
+			RuntimeCall::decode(&mut &error_handler_call(&message).unwrap()[..]).unwrap();
+		deposit_teleported_backing(coin);
+		let free = free_balance();
+		assert_ok!(return_call.dispatch_bypass_filter(RuntimeOrigin::root()));
+
+		let amount = Coinage::coin_value_to_asset_amount(coin.value).unwrap();
+		assert_eq!(free_balance(), free - amount, "The stranded backing should be burnt");
+		assert_eq!(NextTeleportId::<Test>::get(), 2);
+		System::assert_has_event(
+			crate::Event::<Test>::TeleportedCoinReturned {
+				source: sibling(),
+				teleport_id: 0,
+				return_id: 1,
+				to: 1,
+			}
+			.into(),
+		);
+
+		// The returned coin is not returned again.
+		let (_, message) = SENT_XCMS.with(|x| x.borrow_mut().remove(0));
+		assert_eq!(error_handler_call(&message), None);
+		let call = RuntimeCall::decode(&mut &transact_call(&message)[..]).unwrap();
+		assert_eq!(
+			call,
+			RuntimeCall::Coinage(crate::Call::receive_teleported_coin {
+				teleport_id: 1,
+				to: 1,
+				coin
+			})
+		);
+		deposit_teleported_backing(coin);
+		assert_ok!(call.dispatch_bypass_filter(RuntimeOrigin::root()));
+		assert_eq!(CoinsByOwner::<Test>::get(1), Some(coin));
+		assert_eq!(held_backing(), held);
+		check_accounting();
+	});
+}
+
+#[test]
+fn return_teleported_coin_bad_origin_fail() {
+	new_test_ext().execute_with(|| {
+		let coin = Coin { value: 0, age: 0 };
+
+		assert_noop!(
+			Coinage::return_teleported_coin(RuntimeOrigin::signed(1), 0, 7, coin),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Coinage::return_teleported_coin(RuntimeOrigin::root(), 0, 7, coin),
+			Error::<Test>::UnknownTeleportDestination
+		);
+	});
+}
+
+#[test]
+fn offchain_worker_scans_parked_coins_in_bounded_batches() {
+	new_test_ext().execute_with(|| {
+		let limit = OFFCHAIN_WORKER_SCAN_LIMIT as u64;
+		for teleport_id in 0..limit + 10 {
+			ParkedTeleportedCoins::<Test>::insert(
+				(sibling(), teleport_id),
+				(1000 + teleport_id, Coin { value: 0, age: 0 }),
+			);
+		}
+		let scan = || {
+			Coinage::offchain_scan(PARKED_COINS_OFFCHAIN_CURSOR, |last| match last {
+				Some(last) => ParkedTeleportedCoins::<Test>::iter_keys_from(
+					ParkedTeleportedCoins::<Test>::hashed_key_for(last),
+				),
+				None => ParkedTeleportedCoins::<Test>::iter_keys(),
+			})
+		};
+
+		let first = scan();
+		assert_eq!(first.len() as u64, limit);
+		// The next run resumes where the previous one stopped.
+		let second = scan();
+		assert_eq!(second.len(), 10);
+		assert!(second.iter().all(|key| !first.contains(key)));
+		// Then starts over.
+		assert_eq!(scan(), first);
+	});
+}
+
+#[test]
+fn teleport_coin_unknown_destination_invalid() {
+	new_test_ext().execute_with(|| {
+		CoinsByOwner::<Test>::insert(1, Coin { value: 0, age: 0 });
+
+		assert_invalid(
+			build_teleport_ext(1, sibling(), 7),
+			CustomInvalidity::UnknownTeleportDestination,
+		);
+	});
+}
+
+#[test]
+fn teleport_coin_max_age_invalid() {
+	new_test_ext().execute_with(|| {
+		link_sibling();
+		CoinsByOwner::<Test>::insert(1, Coin { value: 0, age: MAXIMUM_AGE });
+
+		assert_invalid(build_teleport_ext(1, sibling(), 7), CustomInvalidity::CoinTooOld);
+	});
+}
+
+#[test]
+fn teleport_coin_send_failure_gives_coin_back() {
+	new_test_ext().execute_with(|| {
+		link_sibling();
+		let coin = Coin { value: 0, age: 1 };
+		create_coin(1, coin.value, coin.age);
+		let held = held_backing();
+		XCM_SEND_SHOULD_FAIL.with(|f| f.set(true));
+
+		let res = Executive::apply_extrinsic(build_teleport_ext(1, sibling(), 7));
+		assert!(res.unwrap().is_err(), "Dispatch should fail");
+
+		assert_eq!(CoinsByOwner::<Test>::get(1), Some(coin));
+		assert!(LockedCoins::<Test>::contains_key(1), "Coin should be locked after the failure");
+		assert_eq!(NextTeleportId::<Test>::get(), 0);
+		assert_eq!(held_backing(), held, "The backing should not be burnt");
+		assert!(SENT_XCMS.with(|x| x.borrow().is_empty()));
+	});
+}
+
+#[test]
+fn receive_teleported_coin_valid_success() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		link_sibling();
+		let coin = Coin { value: 1, age: 4 };
+		deposit_teleported_backing(coin);
+
+		assert_ok!(Coinage::receive_teleported_coin(RuntimeOrigin::root(), 3, 7, coin));
+
+		assert_eq!(CoinsByOwner::<Test>::get(7), Some(coin));
+		check_accounting();
+		System::assert_has_event(
+			crate::Event::<Test>::TeleportedCoinReceived {
+				source: sibling(),
+				teleport_id: 3,
+				to: 7,
+				value: 1,
+				age: 4,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn receive_teleported_coin_bad_origin_fail() {
+	new_test_ext().execute_with(|| {
+		let coin = Coin { value: 0, age: 0 };
+
+		assert_noop!(
+			Coinage::receive_teleported_coin(RuntimeOrigin::signed(1), 0, 7, coin),
+			DispatchError::BadOrigin
+		);
+		// The source chain must be linked to this chain.
+		assert_noop!(
+			Coinage::receive_teleported_coin(RuntimeOrigin::root(), 0, 7, coin),
+			Error::<Test>::UnknownTeleportDestination
+		);
+	});
+}
+
+#[test]
+fn receive_teleported_coin_without_backing_fail() {
+	new_test_ext().execute_with(|| {
+		link_sibling();
+
+		assert_noop!(
+			Coinage::receive_teleported_coin(
+				RuntimeOrigin::root(),
+				0,
+				7,
+				Coin { value: 0, age: 0 }
+			),
+			TokenError::FundsUnavailable
+		);
+	});
+}
+
+#[test]
+fn receive_teleported_coin_for_busy_address_is_parked_then_released() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		link_sibling();
+		let existing = Coin { value: 0, age: 0 };
+		let teleported = Coin { value: 3, age: 2 };
+		CoinsByOwner::<Test>::insert(7, existing);
+		deposit_teleported_backing(teleported);
+
+		assert_ok!(Coinage::receive_teleported_coin(RuntimeOrigin::root(), 5, 7, teleported));
+
+		assert_eq!(CoinsByOwner::<Test>::get(7), Some(existing));
+		assert_eq!(ParkedTeleportedCoins::<Test>::get((sibling(), 5)), Some((7, teleported)));
+		System::assert_has_event(
+			crate::Event::<Test>::TeleportedCoinParked { source: sibling(), teleport_id: 5, to: 7 }
+				.into(),
+		);
+
+		// The parked coin can't be released while the address holds a coin.
+		let call = crate::Call::release_parked_coin { from: sibling(), teleport_id: 5 };
+		assert_eq!(
+			Executive::validate_transaction(
+				TransactionSource::Local,
+				build_authorized_ext(call.clone()),
+				Default::default(),
+			),
+			Err(TransactionValidityError::Invalid(InvalidTransaction::Custom(
+				CustomInvalidity::AddressAlreadyHasCoin as u8
+			)))
+		);
+
+		CoinsByOwner::<Test>::remove(7);
+		assert_eq!(Executive::apply_extrinsic(build_authorized_ext(call)), Ok(Ok(())));
+
+		assert_eq!(CoinsByOwner::<Test>::get(7), Some(teleported));
+		assert!(!ParkedTeleportedCoins::<Test>::contains_key((sibling(), 5)));
+	});
+}
+
+#[test]
+fn release_parked_coin_not_local_invalid() {
+	new_test_ext().execute_with(|| {
+		ParkedTeleportedCoins::<Test>::insert((sibling(), 0), (7, Coin { value: 0, age: 0 }));
+		let call = crate::Call::release_parked_coin { from: sibling(), teleport_id: 0 };
+
+		assert_eq!(
+			Executive::validate_transaction(
+				TransactionSource::External,
+				build_authorized_ext(call),
+				Default::default(),
+			),
+			Err(TransactionValidityError::Invalid(InvalidTransaction::Custom(
+				CustomInvalidity::TransactionNotLocal as u8
+			)))
+		);
+	});
+}
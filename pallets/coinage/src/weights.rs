@@ -55,6 +55,14 @@ use core::marker::PhantomData;
 pub trait WeightInfo {
 	fn split(n: u32, ) -> Weight;
 	fn transfer() -> Weight;
+	fn merge() -> Weight;
+	fn offer_swap() -> Weight;
+	fn accept_swap_with_coin() -> Weight;
+	fn accept_swap_with_asset() -> Weight;
+	fn cancel_swap() -> Weight;
+	fn teleport_coin() -> Weight;
+	fn receive_teleported_coin() -> Weight;
+	fn return_teleported_coin() -> Weight;
 	fn load_recycler_with_coin() -> Weight;
 	fn pay_for_recycler_unload_fee_token_with_coin() -> Weight;
 	fn load_recycler_with_external_asset() -> Weight;
@@ -64,6 +72,8 @@ pub trait WeightInfo {
 	fn clean_consumed_free_token(n: u32, ) -> Weight;
 	fn clean_paid_unload_token_ring(n: u32, ) -> Weight;
 	fn delete_expired_paid_unload_token_collection() -> Weight;
+	fn expire_swap() -> Weight;
+	fn release_parked_coin() -> Weight;
 	fn clean_recycler_dust(n: u32, ) -> Weight;
 	fn clean_paid_unload_token_dust(n: u32, ) -> Weight;
 	fn unload_recycler_into_coin_1_2(n: u32, ) -> Weight;
@@ -89,6 +99,11 @@ pub trait WeightInfo {
 	fn as_none_tx_ext_unload_recyclers_into_external_asset_non_anonymous(n: u32, ) -> Weight;
 	fn as_coin_split(n: u32, ) -> Weight;
 	fn as_coin_transfer() -> Weight;
+	fn as_coins_merge(n: u32, ) -> Weight;
+	fn as_coin_offer_swap() -> Weight;
+	fn as_coin_accept_swap_with_coin() -> Weight;
+	fn as_coin_teleport_coin() -> Weight;
+	fn as_coin_cancel_swap() -> Weight;
 	fn as_coin_load_recycler_with_coin() -> Weight;
 	fn as_coin_pay_for_recycler_unload_fee_token_with_coin() -> Weight;
 	fn as_unload_token_people_tx_ext() -> Weight;
@@ -106,6 +121,8 @@ pub trait WeightInfo {
 	fn authorize_clean_recycler_dust() -> Weight;
 	fn authorize_clean_paid_unload_token_dust() -> Weight;
 	fn authorize_delete_expired_paid_unload_token_collection() -> Weight;
+	fn authorize_expire_swap() -> Weight;
+	fn authorize_release_parked_coin() -> Weight;
 	fn on_poll_create_paid_token_collection() -> Weight;
 	fn on_poll_initialize_check_condition() -> Weight;
 	fn on_poll_initialize() -> Weight;
@@ -137,6 +154,161 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(13_807_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Coinage::CoinsByOwner` (r:0 w:1)
+	/// Proof: `Coinage::CoinsByOwner` (`max_values`: None, `max_size`: Some(43), added: 2518, mode: `MaxEncodedLen`)
+	fn merge() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 13_481_000 picoseconds.
+		Weight::from_parts(14_122_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::SwapOffers` (r:0 w:1)
+	/// Proof: `Coinage::SwapOffers` (`max_values`: None, `max_size`: Some(702), added: 3177, mode: `MaxEncodedLen`)
+	fn offer_swap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1493`
+		// Minimum execution time: 15_862_000 picoseconds.
+		Weight::from_parts(16_640_000, 1493)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Coinage::SwapOffers` (r:1 w:1)
+	/// Proof: `Coinage::SwapOffers` (`max_values`: None, `max_size`: Some(702), added: 3177, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::CoinsByOwner` (r:2 w:2)
+	/// Proof: `Coinage::CoinsByOwner` (`max_values`: None, `max_size`: Some(43), added: 2518, mode: `MaxEncodedLen`)
+	fn accept_swap_with_coin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `221`
+		//  Estimated: `6026`
+		// Minimum execution time: 27_491_000 picoseconds.
+		Weight::from_parts(28_702_000, 6026)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Coinage::SwapOffers` (r:1 w:1)
+	/// Proof: `Coinage::SwapOffers` (`max_values`: None, `max_size`: Some(702), added: 3177, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::CoinsByOwner` (r:1 w:1)
+	/// Proof: `Coinage::CoinsByOwner` (`max_values`: None, `max_size`: Some(43), added: 2518, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn accept_swap_with_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1038`
+		//  Estimated: `7404`
+		// Minimum execution time: 78_541_000 picoseconds.
+		Weight::from_parts(81_932_000, 7404)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Coinage::SwapOffers` (r:1 w:1)
+	/// Proof: `Coinage::SwapOffers` (`max_values`: None, `max_size`: Some(702), added: 3177, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::CoinsByOwner` (r:1 w:1)
+	/// Proof: `Coinage::CoinsByOwner` (`max_values`: None, `max_size`: Some(43), added: 2518, mode: `MaxEncodedLen`)
+	fn cancel_swap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `221`
+		//  Estimated: `4167`
+		// Minimum execution time: 19_962_000 picoseconds.
+		Weight::from_parts(20_873_000, 4167)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `MembersNotifier::Subscribers` (r:1 w:0)
+	/// Proof: `MembersNotifier::Subscribers` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::UnderlyingAssetId` (r:1 w:0)
+	/// Proof: `Coinage::UnderlyingAssetId` (`max_values`: Some(1), `max_size`: Some(602), added: 1097, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsHolder::Holds` (r:1 w:1)
+	/// Proof: `AssetsHolder::Holds` (`max_values`: None, `max_size`: Some(847), added: 3322, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsHolder::BalancesOnHold` (r:1 w:1)
+	/// Proof: `AssetsHolder::BalancesOnHold` (`max_values`: None, `max_size`: Some(682), added: 3157, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::NextTeleportId` (r:1 w:1)
+	/// Proof: `Coinage::NextTeleportId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `XcmpQueue::DeliveryFeeFactor` (r:1 w:0)
+	/// Proof: `XcmpQueue::DeliveryFeeFactor` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `PolkadotXcm::SupportedVersion` (r:1 w:0)
+	/// Proof: `PolkadotXcm::SupportedVersion` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `XcmpQueue::OutboundXcmpStatus` (r:1 w:1)
+	/// Proof: `XcmpQueue::OutboundXcmpStatus` (`max_values`: Some(1), `max_size`: Some(1794), added: 2289, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainSystem::RelevantMessagingState` (r:1 w:0)
+	/// Proof: `ParachainSystem::RelevantMessagingState` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `XcmpQueue::OutboundXcmpMessages` (r:0 w:1)
+	/// Proof: `XcmpQueue::OutboundXcmpMessages` (`max_values`: None, `max_size`: Some(105506), added: 107981, mode: `MaxEncodedLen`)
+	fn teleport_coin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1042`
+		//  Estimated: `4312`
+		// Minimum execution time: 58_734_000 picoseconds.
+		Weight::from_parts(60_915_000, 4312)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: `MembersNotifier::Subscribers` (r:1 w:0)
+	/// Proof: `MembersNotifier::Subscribers` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::UnderlyingAssetId` (r:1 w:0)
+	/// Proof: `Coinage::UnderlyingAssetId` (`max_values`: Some(1), `max_size`: Some(602), added: 1097, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:0)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsHolder::Holds` (r:1 w:1)
+	/// Proof: `AssetsHolder::Holds` (`max_values`: None, `max_size`: Some(847), added: 3322, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsHolder::BalancesOnHold` (r:1 w:1)
+	/// Proof: `AssetsHolder::BalancesOnHold` (`max_values`: None, `max_size`: Some(682), added: 3157, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::CoinsByOwner` (r:1 w:1)
+	/// Proof: `Coinage::CoinsByOwner` (`max_values`: None, `max_size`: Some(43), added: 2518, mode: `MaxEncodedLen`)
+	fn receive_teleported_coin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `968`
+		//  Estimated: `4312`
+		// Minimum execution time: 36_418_000 picoseconds.
+		Weight::from_parts(37_902_000, 4312)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `MembersNotifier::Subscribers` (r:1 w:0)
+	/// Proof: `MembersNotifier::Subscribers` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::UnderlyingAssetId` (r:1 w:0)
+	/// Proof: `Coinage::UnderlyingAssetId` (`max_values`: Some(1), `max_size`: Some(602), added: 1097, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::NextTeleportId` (r:1 w:1)
+	/// Proof: `Coinage::NextTeleportId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `XcmpQueue::DeliveryFeeFactor` (r:1 w:0)
+	/// Proof: `XcmpQueue::DeliveryFeeFactor` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `PolkadotXcm::SupportedVersion` (r:1 w:0)
+	/// Proof: `PolkadotXcm::SupportedVersion` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `XcmpQueue::OutboundXcmpStatus` (r:1 w:1)
+	/// Proof: `XcmpQueue::OutboundXcmpStatus` (`max_values`: Some(1), `max_size`: Some(1794), added: 2289, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainSystem::RelevantMessagingState` (r:1 w:0)
+	/// Proof: `ParachainSystem::RelevantMessagingState` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `XcmpQueue::OutboundXcmpMessages` (r:0 w:1)
+	/// Proof: `XcmpQueue::OutboundXcmpMessages` (`max_values`: None, `max_size`: Some(105506), added: 107981, mode: `MaxEncodedLen`)
+	fn return_teleported_coin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `981`
+		//  Estimated: `4273`
+		// Minimum execution time: 49_206_000 picoseconds.
+		Weight::from_parts(51_038_000, 4273)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
 	/// Storage: `Coinage::RecyclersCoinToRecycler` (r:1 w:1)
 	/// Proof: `Coinage::RecyclersCoinToRecycler` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
 	/// Storage: `Coinage::RecyclerCollectionCreated` (r:1 w:0)
@@ -397,6 +569,32 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
+	/// Storage: `Coinage::SwapOffers` (r:1 w:1)
+	/// Proof: `Coinage::SwapOffers` (`max_values`: None, `max_size`: Some(702), added: 3177, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::CoinsByOwner` (r:2 w:1)
+	/// Proof: `Coinage::CoinsByOwner` (`max_values`: None, `max_size`: Some(43), added: 2518, mode: `MaxEncodedLen`)
+	fn expire_swap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `286`
+		//  Estimated: `6026`
+		// Minimum execution time: 23_814_000 picoseconds.
+		Weight::from_parts(24_937_000, 6026)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Coinage::ParkedTeleportedCoins` (r:1 w:1)
+	/// Proof: `Coinage::ParkedTeleportedCoins` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::CoinsByOwner` (r:1 w:1)
+	/// Proof: `Coinage::CoinsByOwner` (`max_values`: None, `max_size`: Some(43), added: 2518, mode: `MaxEncodedLen`)
+	fn release_parked_coin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `189`
+		//  Estimated: `3552`
+		// Minimum execution time: 16_483_000 picoseconds.
+		Weight::from_parts(17_120_000, 3552)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 	/// Storage: `Coinage::RecyclersDusting` (r:1 w:1)
 	/// Proof: `Coinage::RecyclersDusting` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
 	/// Storage: `Coinage::RecyclersUnloaded` (r:1000 w:1000)
@@ -1210,6 +1408,104 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Coinage::UnderlyingAssetId` (`max_values`: Some(1), `max_size`: Some(602), added: 1097, mode: `MaxEncodedLen`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::LockedCoins` (r:64 w:64)
+	/// Proof: `Coinage::LockedCoins` (`max_values`: None, `max_size`: Some(50), added: 2525, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::CoinsByOwner` (r:65 w:64)
+	/// Proof: `Coinage::CoinsByOwner` (`max_values`: None, `max_size`: Some(43), added: 2518, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[2, 64]`.
+	fn as_coins_merge(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `385 + n * (103 ±0)`
+		//  Estimated: `3587 + n * (2525 ±0)`
+		// Minimum execution time: 41_530_000 picoseconds.
+		Weight::from_parts(12_804_557, 3587)
+			// Standard Error: 38_417
+			.saturating_add(Weight::from_parts(58_226_090, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2525).saturating_mul(n.into()))
+	}
+	/// Storage: `Coinage::UnderlyingAssetId` (r:1 w:0)
+	/// Proof: `Coinage::UnderlyingAssetId` (`max_values`: Some(1), `max_size`: Some(602), added: 1097, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::LockedCoins` (r:1 w:1)
+	/// Proof: `Coinage::LockedCoins` (`max_values`: None, `max_size`: Some(50), added: 2525, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::CoinsByOwner` (r:1 w:1)
+	/// Proof: `Coinage::CoinsByOwner` (`max_values`: None, `max_size`: Some(43), added: 2518, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::SwapOffers` (r:1 w:0)
+	/// Proof: `Coinage::SwapOffers` (`max_values`: None, `max_size`: Some(702), added: 3177, mode: `MaxEncodedLen`)
+	fn as_coin_offer_swap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `372`
+		//  Estimated: `4167`
+		// Minimum execution time: 26_984_000 picoseconds.
+		Weight::from_parts(28_011_000, 4167)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Coinage::UnderlyingAssetId` (r:1 w:0)
+	/// Proof: `Coinage::UnderlyingAssetId` (`max_values`: Some(1), `max_size`: Some(602), added: 1097, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::LockedCoins` (r:1 w:1)
+	/// Proof: `Coinage::LockedCoins` (`max_values`: None, `max_size`: Some(50), added: 2525, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::CoinsByOwner` (r:3 w:1)
+	/// Proof: `Coinage::CoinsByOwner` (`max_values`: None, `max_size`: Some(43), added: 2518, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::SwapOffers` (r:1 w:0)
+	/// Proof: `Coinage::SwapOffers` (`max_values`: None, `max_size`: Some(702), added: 3177, mode: `MaxEncodedLen`)
+	fn as_coin_accept_swap_with_coin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `614`
+		//  Estimated: `8526`
+		// Minimum execution time: 34_806_000 picoseconds.
+		Weight::from_parts(36_152_000, 8526)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Coinage::UnderlyingAssetId` (r:1 w:0)
+	/// Proof: `Coinage::UnderlyingAssetId` (`max_values`: Some(1), `max_size`: Some(602), added: 1097, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::LockedCoins` (r:1 w:1)
+	/// Proof: `Coinage::LockedCoins` (`max_values`: None, `max_size`: Some(50), added: 2525, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::CoinsByOwner` (r:1 w:1)
+	/// Proof: `Coinage::CoinsByOwner` (`max_values`: None, `max_size`: Some(43), added: 2518, mode: `MaxEncodedLen`)
+	/// Storage: `MembersNotifier::Subscribers` (r:1 w:0)
+	/// Proof: `MembersNotifier::Subscribers` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	fn as_coin_teleport_coin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `372`
+		//  Estimated: `3515`
+		// Minimum execution time: 25_318_000 picoseconds.
+		Weight::from_parts(26_402_000, 3515)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Coinage::UnderlyingAssetId` (r:1 w:0)
+	/// Proof: `Coinage::UnderlyingAssetId` (`max_values`: Some(1), `max_size`: Some(602), added: 1097, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::LockedCoins` (r:1 w:1)
+	/// Proof: `Coinage::LockedCoins` (`max_values`: None, `max_size`: Some(50), added: 2525, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::SwapOffers` (r:1 w:0)
+	/// Proof: `Coinage::SwapOffers` (`max_values`: None, `max_size`: Some(702), added: 3177, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::CoinsByOwner` (r:1 w:0)
+	/// Proof: `Coinage::CoinsByOwner` (`max_values`: None, `max_size`: Some(43), added: 2518, mode: `MaxEncodedLen`)
+	fn as_coin_cancel_swap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `452`
+		//  Estimated: `4167`
+		// Minimum execution time: 21_746_000 picoseconds.
+		Weight::from_parts(22_608_000, 4167)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Coinage::UnderlyingAssetId` (r:1 w:0)
+	/// Proof: `Coinage::UnderlyingAssetId` (`max_values`: Some(1), `max_size`: Some(602), added: 1097, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `Coinage::LockedCoins` (r:1 w:1)
 	/// Proof: `Coinage::LockedCoins` (`max_values`: None, `max_size`: Some(50), added: 2525, mode: `MaxEncodedLen`)
 	/// Storage: `Coinage::CoinsByOwner` (r:1 w:1)
@@ -1544,6 +1840,32 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(42_816_000, 4111)
 			.saturating_add(T::DbWeight::get().reads(5_u64))
 	}
+	/// Storage: `Coinage::SwapOffers` (r:1 w:0)
+	/// Proof: `Coinage::SwapOffers` (`max_values`: None, `max_size`: Some(702), added: 3177, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::CoinsByOwner` (r:1 w:0)
+	/// Proof: `Coinage::CoinsByOwner` (`max_values`: None, `max_size`: Some(43), added: 2518, mode: `MaxEncodedLen`)
+	fn authorize_expire_swap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `221`
+		//  Estimated: `4167`
+		// Minimum execution time: 13_473_000 picoseconds.
+		Weight::from_parts(14_050_000, 4167)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+	}
+	/// Storage: `Coinage::ParkedTeleportedCoins` (r:1 w:0)
+	/// Proof: `Coinage::ParkedTeleportedCoins` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::CoinsByOwner` (r:1 w:0)
+	/// Proof: `Coinage::CoinsByOwner` (`max_values`: None, `max_size`: Some(43), added: 2518, mode: `MaxEncodedLen`)
+	fn authorize_release_parked_coin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `189`
+		//  Estimated: `3552`
+		// Minimum execution time: 10_935_000 picoseconds.
+		Weight::from_parts(11_478_000, 3552)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+	}
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `Coinage::PaidTokenCollectionsCreated` (r:1 w:1)
@@ -1637,6 +1959,161 @@ impl WeightInfo for () {
 		Weight::from_parts(13_807_000, 0)
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Coinage::CoinsByOwner` (r:0 w:1)
+	/// Proof: `Coinage::CoinsByOwner` (`max_values`: None, `max_size`: Some(43), added: 2518, mode: `MaxEncodedLen`)
+	fn merge() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 13_481_000 picoseconds.
+		Weight::from_parts(14_122_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::SwapOffers` (r:0 w:1)
+	/// Proof: `Coinage::SwapOffers` (`max_values`: None, `max_size`: Some(702), added: 3177, mode: `MaxEncodedLen`)
+	fn offer_swap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1493`
+		// Minimum execution time: 15_862_000 picoseconds.
+		Weight::from_parts(16_640_000, 1493)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Coinage::SwapOffers` (r:1 w:1)
+	/// Proof: `Coinage::SwapOffers` (`max_values`: None, `max_size`: Some(702), added: 3177, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::CoinsByOwner` (r:2 w:2)
+	/// Proof: `Coinage::CoinsByOwner` (`max_values`: None, `max_size`: Some(43), added: 2518, mode: `MaxEncodedLen`)
+	fn accept_swap_with_coin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `221`
+		//  Estimated: `6026`
+		// Minimum execution time: 27_491_000 picoseconds.
+		Weight::from_parts(28_702_000, 6026)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Coinage::SwapOffers` (r:1 w:1)
+	/// Proof: `Coinage::SwapOffers` (`max_values`: None, `max_size`: Some(702), added: 3177, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::CoinsByOwner` (r:1 w:1)
+	/// Proof: `Coinage::CoinsByOwner` (`max_values`: None, `max_size`: Some(43), added: 2518, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn accept_swap_with_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1038`
+		//  Estimated: `7404`
+		// Minimum execution time: 78_541_000 picoseconds.
+		Weight::from_parts(81_932_000, 7404)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Coinage::SwapOffers` (r:1 w:1)
+	/// Proof: `Coinage::SwapOffers` (`max_values`: None, `max_size`: Some(702), added: 3177, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::CoinsByOwner` (r:1 w:1)
+	/// Proof: `Coinage::CoinsByOwner` (`max_values`: None, `max_size`: Some(43), added: 2518, mode: `MaxEncodedLen`)
+	fn cancel_swap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `221`
+		//  Estimated: `4167`
+		// Minimum execution time: 19_962_000 picoseconds.
+		Weight::from_parts(20_873_000, 4167)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `MembersNotifier::Subscribers` (r:1 w:0)
+	/// Proof: `MembersNotifier::Subscribers` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::UnderlyingAssetId` (r:1 w:0)
+	/// Proof: `Coinage::UnderlyingAssetId` (`max_values`: Some(1), `max_size`: Some(602), added: 1097, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsHolder::Holds` (r:1 w:1)
+	/// Proof: `AssetsHolder::Holds` (`max_values`: None, `max_size`: Some(847), added: 3322, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsHolder::BalancesOnHold` (r:1 w:1)
+	/// Proof: `AssetsHolder::BalancesOnHold` (`max_values`: None, `max_size`: Some(682), added: 3157, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::NextTeleportId` (r:1 w:1)
+	/// Proof: `Coinage::NextTeleportId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `XcmpQueue::DeliveryFeeFactor` (r:1 w:0)
+	/// Proof: `XcmpQueue::DeliveryFeeFactor` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `PolkadotXcm::SupportedVersion` (r:1 w:0)
+	/// Proof: `PolkadotXcm::SupportedVersion` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `XcmpQueue::OutboundXcmpStatus` (r:1 w:1)
+	/// Proof: `XcmpQueue::OutboundXcmpStatus` (`max_values`: Some(1), `max_size`: Some(1794), added: 2289, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainSystem::RelevantMessagingState` (r:1 w:0)
+	/// Proof: `ParachainSystem::RelevantMessagingState` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `XcmpQueue::OutboundXcmpMessages` (r:0 w:1)
+	/// Proof: `XcmpQueue::OutboundXcmpMessages` (`max_values`: None, `max_size`: Some(105506), added: 107981, mode: `MaxEncodedLen`)
+	fn teleport_coin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1042`
+		//  Estimated: `4312`
+		// Minimum execution time: 58_734_000 picoseconds.
+		Weight::from_parts(60_915_000, 4312)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: `MembersNotifier::Subscribers` (r:1 w:0)
+	/// Proof: `MembersNotifier::Subscribers` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::UnderlyingAssetId` (r:1 w:0)
+	/// Proof: `Coinage::UnderlyingAssetId` (`max_values`: Some(1), `max_size`: Some(602), added: 1097, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:0)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsHolder::Holds` (r:1 w:1)
+	/// Proof: `AssetsHolder::Holds` (`max_values`: None, `max_size`: Some(847), added: 3322, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsHolder::BalancesOnHold` (r:1 w:1)
+	/// Proof: `AssetsHolder::BalancesOnHold` (`max_values`: None, `max_size`: Some(682), added: 3157, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::CoinsByOwner` (r:1 w:1)
+	/// Proof: `Coinage::CoinsByOwner` (`max_values`: None, `max_size`: Some(43), added: 2518, mode: `MaxEncodedLen`)
+	fn receive_teleported_coin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `968`
+		//  Estimated: `4312`
+		// Minimum execution time: 36_418_000 picoseconds.
+		Weight::from_parts(37_902_000, 4312)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `MembersNotifier::Subscribers` (r:1 w:0)
+	/// Proof: `MembersNotifier::Subscribers` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::UnderlyingAssetId` (r:1 w:0)
+	/// Proof: `Coinage::UnderlyingAssetId` (`max_values`: Some(1), `max_size`: Some(602), added: 1097, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::NextTeleportId` (r:1 w:1)
+	/// Proof: `Coinage::NextTeleportId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `XcmpQueue::DeliveryFeeFactor` (r:1 w:0)
+	/// Proof: `XcmpQueue::DeliveryFeeFactor` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `PolkadotXcm::SupportedVersion` (r:1 w:0)
+	/// Proof: `PolkadotXcm::SupportedVersion` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `XcmpQueue::OutboundXcmpStatus` (r:1 w:1)
+	/// Proof: `XcmpQueue::OutboundXcmpStatus` (`max_values`: Some(1), `max_size`: Some(1794), added: 2289, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainSystem::RelevantMessagingState` (r:1 w:0)
+	/// Proof: `ParachainSystem::RelevantMessagingState` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `XcmpQueue::OutboundXcmpMessages` (r:0 w:1)
+	/// Proof: `XcmpQueue::OutboundXcmpMessages` (`max_values`: None, `max_size`: Some(105506), added: 107981, mode: `MaxEncodedLen`)
+	fn return_teleported_coin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `981`
+		//  Estimated: `4273`
+		// Minimum execution time: 49_206_000 picoseconds.
+		Weight::from_parts(51_038_000, 4273)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
 	/// Storage: `Coinage::RecyclersCoinToRecycler` (r:1 w:1)
 	/// Proof: `Coinage::RecyclersCoinToRecycler` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
 	/// Storage: `Coinage::RecyclerCollectionCreated` (r:1 w:0)
@@ -1897,6 +2374,32 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
+	/// Storage: `Coinage::SwapOffers` (r:1 w:1)
+	/// Proof: `Coinage::SwapOffers` (`max_values`: None, `max_size`: Some(702), added: 3177, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::CoinsByOwner` (r:2 w:1)
+	/// Proof: `Coinage::CoinsByOwner` (`max_values`: None, `max_size`: Some(43), added: 2518, mode: `MaxEncodedLen`)
+	fn expire_swap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `286`
+		//  Estimated: `6026`
+		// Minimum execution time: 23_814_000 picoseconds.
+		Weight::from_parts(24_937_000, 6026)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Coinage::ParkedTeleportedCoins` (r:1 w:1)
+	/// Proof: `Coinage::ParkedTeleportedCoins` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::CoinsByOwner` (r:1 w:1)
+	/// Proof: `Coinage::CoinsByOwner` (`max_values`: None, `max_size`: Some(43), added: 2518, mode: `MaxEncodedLen`)
+	fn release_parked_coin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `189`
+		//  Estimated: `3552`
+		// Minimum execution time: 16_483_000 picoseconds.
+		Weight::from_parts(17_120_000, 3552)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 	/// Storage: `Coinage::RecyclersDusting` (r:1 w:1)
 	/// Proof: `Coinage::RecyclersDusting` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
 	/// Storage: `Coinage::RecyclersUnloaded` (r:1000 w:1000)
@@ -2710,6 +3213,104 @@ impl WeightInfo for () {
 	/// Proof: `Coinage::UnderlyingAssetId` (`max_values`: Some(1), `max_size`: Some(602), added: 1097, mode: `MaxEncodedLen`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::LockedCoins` (r:64 w:64)
+	/// Proof: `Coinage::LockedCoins` (`max_values`: None, `max_size`: Some(50), added: 2525, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::CoinsByOwner` (r:65 w:64)
+	/// Proof: `Coinage::CoinsByOwner` (`max_values`: None, `max_size`: Some(43), added: 2518, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[2, 64]`.
+	fn as_coins_merge(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `385 + n * (103 ±0)`
+		//  Estimated: `3587 + n * (2525 ±0)`
+		// Minimum execution time: 41_530_000 picoseconds.
+		Weight::from_parts(12_804_557, 3587)
+			// Standard Error: 38_417
+			.saturating_add(Weight::from_parts(58_226_090, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2525).saturating_mul(n.into()))
+	}
+	/// Storage: `Coinage::UnderlyingAssetId` (r:1 w:0)
+	/// Proof: `Coinage::UnderlyingAssetId` (`max_values`: Some(1), `max_size`: Some(602), added: 1097, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::LockedCoins` (r:1 w:1)
+	/// Proof: `Coinage::LockedCoins` (`max_values`: None, `max_size`: Some(50), added: 2525, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::CoinsByOwner` (r:1 w:1)
+	/// Proof: `Coinage::CoinsByOwner` (`max_values`: None, `max_size`: Some(43), added: 2518, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::SwapOffers` (r:1 w:0)
+	/// Proof: `Coinage::SwapOffers` (`max_values`: None, `max_size`: Some(702), added: 3177, mode: `MaxEncodedLen`)
+	fn as_coin_offer_swap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `372`
+		//  Estimated: `4167`
+		// Minimum execution time: 26_984_000 picoseconds.
+		Weight::from_parts(28_011_000, 4167)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Coinage::UnderlyingAssetId` (r:1 w:0)
+	/// Proof: `Coinage::UnderlyingAssetId` (`max_values`: Some(1), `max_size`: Some(602), added: 1097, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::LockedCoins` (r:1 w:1)
+	/// Proof: `Coinage::LockedCoins` (`max_values`: None, `max_size`: Some(50), added: 2525, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::CoinsByOwner` (r:3 w:1)
+	/// Proof: `Coinage::CoinsByOwner` (`max_values`: None, `max_size`: Some(43), added: 2518, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::SwapOffers` (r:1 w:0)
+	/// Proof: `Coinage::SwapOffers` (`max_values`: None, `max_size`: Some(702), added: 3177, mode: `MaxEncodedLen`)
+	fn as_coin_accept_swap_with_coin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `614`
+		//  Estimated: `8526`
+		// Minimum execution time: 34_806_000 picoseconds.
+		Weight::from_parts(36_152_000, 8526)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Coinage::UnderlyingAssetId` (r:1 w:0)
+	/// Proof: `Coinage::UnderlyingAssetId` (`max_values`: Some(1), `max_size`: Some(602), added: 1097, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::LockedCoins` (r:1 w:1)
+	/// Proof: `Coinage::LockedCoins` (`max_values`: None, `max_size`: Some(50), added: 2525, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::CoinsByOwner` (r:1 w:1)
+	/// Proof: `Coinage::CoinsByOwner` (`max_values`: None, `max_size`: Some(43), added: 2518, mode: `MaxEncodedLen`)
+	/// Storage: `MembersNotifier::Subscribers` (r:1 w:0)
+	/// Proof: `MembersNotifier::Subscribers` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	fn as_coin_teleport_coin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `372`
+		//  Estimated: `3515`
+		// Minimum execution time: 25_318_000 picoseconds.
+		Weight::from_parts(26_402_000, 3515)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Coinage::UnderlyingAssetId` (r:1 w:0)
+	/// Proof: `Coinage::UnderlyingAssetId` (`max_values`: Some(1), `max_size`: Some(602), added: 1097, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::LockedCoins` (r:1 w:1)
+	/// Proof: `Coinage::LockedCoins` (`max_values`: None, `max_size`: Some(50), added: 2525, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::SwapOffers` (r:1 w:0)
+	/// Proof: `Coinage::SwapOffers` (`max_values`: None, `max_size`: Some(702), added: 3177, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::CoinsByOwner` (r:1 w:0)
+	/// Proof: `Coinage::CoinsByOwner` (`max_values`: None, `max_size`: Some(43), added: 2518, mode: `MaxEncodedLen`)
+	fn as_coin_cancel_swap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `452`
+		//  Estimated: `4167`
+		// Minimum execution time: 21_746_000 picoseconds.
+		Weight::from_parts(22_608_000, 4167)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Coinage::UnderlyingAssetId` (r:1 w:0)
+	/// Proof: `Coinage::UnderlyingAssetId` (`max_values`: Some(1), `max_size`: Some(602), added: 1097, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `Coinage::LockedCoins` (r:1 w:1)
 	/// Proof: `Coinage::LockedCoins` (`max_values`: None, `max_size`: Some(50), added: 2525, mode: `MaxEncodedLen`)
 	/// Storage: `Coinage::CoinsByOwner` (r:1 w:1)
@@ -3044,6 +3645,32 @@ impl WeightInfo for () {
 		Weight::from_parts(42_816_000, 4111)
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
 	}
+	/// Storage: `Coinage::SwapOffers` (r:1 w:0)
+	/// Proof: `Coinage::SwapOffers` (`max_values`: None, `max_size`: Some(702), added: 3177, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::CoinsByOwner` (r:1 w:0)
+	/// Proof: `Coinage::CoinsByOwner` (`max_values`: None, `max_size`: Some(43), added: 2518, mode: `MaxEncodedLen`)
+	fn authorize_expire_swap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `221`
+		//  Estimated: `4167`
+		// Minimum execution time: 13_473_000 picoseconds.
+		Weight::from_parts(14_050_000, 4167)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+	}
+	/// Storage: `Coinage::ParkedTeleportedCoins` (r:1 w:0)
+	/// Proof: `Coinage::ParkedTeleportedCoins` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	/// Storage: `Coinage::CoinsByOwner` (r:1 w:0)
+	/// Proof: `Coinage::CoinsByOwner` (`max_values`: None, `max_size`: Some(43), added: 2518, mode: `MaxEncodedLen`)
+	fn authorize_release_parked_coin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `189`
+		//  Estimated: `3552`
+		// Minimum execution time: 10_935_000 picoseconds.
+		Weight::from_parts(11_478_000, 3552)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+	}
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `Coinage::PaidTokenCollectionsCreated` (r:1 w:1)
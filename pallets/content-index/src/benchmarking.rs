@@ -0,0 +1,138 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Individuality.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::*;
+use alloc::vec::Vec;
+use frame_benchmarking::v2::*;
+use frame_support::traits::{Consideration, Get, Hooks};
+use frame_system::{pallet_prelude::BlockNumberFor, Pallet as System, RawOrigin};
+use pallet_bulletin_transaction_storage::Pallet as TransactionStorage;
+use sp_runtime::{traits::One, Saturating};
+
+fn assert_last_event<T: Config>(event: Event<T>) {
+	System::<T>::assert_last_event(event.into());
+}
+
+/// Store `count` pieces of content renewable by `who` and finalize the block storing them.
+///
+/// Returns the stored block along with the content hashes.
+fn store<T: Config>(who: &T::AccountId, count: u32) -> (BlockNumberFor<T>, Vec<ContentHash>) {
+	<T as Config>::BenchmarkHelper::store(who, count);
+	let block = System::<T>::block_number();
+	TransactionStorage::<T>::on_finalize(block);
+	System::<T>::set_block_number(block.saturating_add(One::one()));
+	let content_hashes = TransactionStorage::<T>::transactions_at(block)
+		.map(|transactions| transactions.into_iter().map(|tx| tx.content_hash).collect())
+		.unwrap_or_default();
+	(block, content_hashes)
+}
+
+/// Fill the pinners of `content_hash` with `count` accounts other than the benchmarked caller.
+fn add_pinners<T: Config>(content_hash: ContentHash, count: u32) {
+	let mut pinners = PinnersOf::<T>::default();
+	for i in 0..count {
+		let pinner: T::AccountId = account("pinner", i, 0);
+		T::PinDeposit::ensure_successful(&pinner, Pallet::<T>::pin_footprint());
+		let deposit = T::PinDeposit::new(&pinner, Pallet::<T>::pin_footprint())
+			.expect("we called `ensure_successful` above");
+		pinners.try_push((pinner, deposit)).expect("`count` is below `MaxPinners`");
+	}
+	Pinners::<T>::insert(content_hash, pinners);
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn pin() {
+		let caller: T::AccountId = whitelisted_caller();
+		let (block, content_hashes) = store::<T>(&caller, 1);
+		Pallet::<T>::index_block(block);
+		let content_hash = content_hashes[0];
+		add_pinners::<T>(content_hash, T::MaxPinners::get().saturating_sub(1));
+		T::PinDeposit::ensure_successful(&caller, Pallet::<T>::pin_footprint());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), content_hash);
+
+		assert_last_event::<T>(Event::Pinned { content_hash, who: caller });
+	}
+
+	#[benchmark]
+	fn unpin() {
+		let caller: T::AccountId = whitelisted_caller();
+		let (block, content_hashes) = store::<T>(&caller, 1);
+		Pallet::<T>::index_block(block);
+		let content_hash = content_hashes[0];
+		add_pinners::<T>(content_hash, T::MaxPinners::get().saturating_sub(1));
+		T::PinDeposit::ensure_successful(&caller, Pallet::<T>::pin_footprint());
+		// The caller is the last pinner, the worst case for finding it.
+		assert!(Pallet::<T>::pin(RawOrigin::Signed(caller.clone()).into(), content_hash).is_ok());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), content_hash);
+
+		assert_last_event::<T>(Event::Unpinned { content_hash, who: caller });
+	}
+
+	#[benchmark]
+	fn expire_contents(
+		n: Linear<
+			0,
+			{ <T as pallet_bulletin_transaction_storage::Config>::MaxBlockTransactions::get() },
+		>,
+	) {
+		let caller: T::AccountId = whitelisted_caller();
+		let (block, content_hashes) = store::<T>(&caller, n);
+		Pallet::<T>::index_block(block);
+		let expires_at = content_hashes
+			.first()
+			.and_then(|content_hash| Contents::<T>::get(content_hash))
+			.map_or(block, |location| location.expires_at);
+
+		#[block]
+		{
+			Pallet::<T>::expire_contents(expires_at);
+		}
+
+		assert!(content_hashes
+			.iter()
+			.all(|content_hash| !Contents::<T>::contains_key(content_hash)));
+	}
+
+	#[benchmark]
+	fn index_block(
+		n: Linear<
+			0,
+			{ <T as pallet_bulletin_transaction_storage::Config>::MaxBlockTransactions::get() },
+		>,
+	) {
+		let caller: T::AccountId = whitelisted_caller();
+		let (block, content_hashes) = store::<T>(&caller, n);
+
+		#[block]
+		{
+			Pallet::<T>::index_block(block);
+		}
+
+		assert!(content_hashes
+			.iter()
+			.all(|content_hash| Contents::<T>::contains_key(content_hash)));
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
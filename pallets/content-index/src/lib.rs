@@ -0,0 +1,309 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Individuality.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Content index pallet.
+//!
+//! Content-addressed index over the data kept by `pallet-bulletin-transaction-storage`.
+//!
+//! Every block, the transactions stored in the previous block are recorded under their content
+//! hash (the digest of their CID), so the block and position carrying some content can be looked
+//! up without scanning the chain. Renewals simply re-index the content at its new location.
+//! Entries are dropped when the data leaves the retention window.
+//!
+//! Accounts able to renew some indexed content can also register as its pinners, signalling
+//! that they are committed to keep it around. Each pin holds a [`Config::PinDeposit`] until the
+//! account unpins the content, which remains possible after the content expired.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+#[cfg(test)]
+mod mock;
+pub mod runtime_api;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+/// Helper to set up the transaction storage for the benchmarks of this pallet.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AccountId> {
+	/// Store `count` pieces of distinct content in the current block, and authorize `who` to
+	/// renew them.
+	fn store(who: &AccountId, count: u32);
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::WeightInfo;
+	use alloc::vec::Vec;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{Consideration, Footprint},
+	};
+	use frame_system::pallet_prelude::*;
+	use pallet_bulletin_transaction_storage::TransactionRef;
+	use sp_runtime::{traits::One, Saturating};
+
+	/// The digest of a CID, as recorded by the transaction storage pallet.
+	pub type ContentHash = [u8; 32];
+
+	/// The pinners of a piece of content, along with the deposit held for each pin.
+	pub type PinnersOf<T> = BoundedVec<
+		(<T as frame_system::Config>::AccountId, <T as Config>::PinDeposit),
+		<T as Config>::MaxPinners,
+	>;
+
+	/// Where some indexed content is stored and until when.
+	#[derive(
+		Clone, PartialEq, Eq, Debug, Encode, Decode, DecodeWithMemTracking, TypeInfo, MaxEncodedLen,
+	)]
+	pub struct ContentLocation<BlockNumber> {
+		/// The block that stored (or last renewed) the content.
+		pub block: BlockNumber,
+		/// The position of the content among the transactions stored in `block`, as used by
+		/// `TransactionRef::Position`.
+		pub index: u32,
+		/// The index of the extrinsic carrying the content in `block`.
+		pub extrinsic_index: u32,
+		/// The size of the content in bytes.
+		pub size: u32,
+		/// The block at which the content stops being retained, unless renewed before.
+		pub expires_at: BlockNumber,
+	}
+
+	/// The pallet configuration trait.
+	#[pallet::config]
+	pub trait Config:
+		frame_system::Config<RuntimeEvent: From<Event<Self>>>
+		+ pallet_bulletin_transaction_storage::Config
+	{
+		/// Weight information for extrinsics and hooks in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// Maximum number of pinners per piece of content.
+		#[pallet::constant]
+		type MaxPinners: Get<u32>;
+
+		/// The deposit held from an account for each piece of content it pins.
+		type PinDeposit: Consideration<Self::AccountId, Footprint>;
+
+		/// Helper to set up the transaction storage in benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: super::BenchmarkHelper<Self::AccountId>;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// The latest known location of each piece of stored content.
+	#[pallet::storage]
+	pub type Contents<T: Config> =
+		StorageMap<_, Identity, ContentHash, ContentLocation<BlockNumberFor<T>>>;
+
+	/// Content hashes indexed in some block, keyed by the block at which they expire.
+	#[pallet::storage]
+	pub type Expiring<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<
+			ContentHash,
+			<T as pallet_bulletin_transaction_storage::Config>::MaxBlockTransactions,
+		>,
+		ValueQuery,
+	>;
+
+	/// Accounts committed to renew a piece of content.
+	///
+	/// Pins are kept when the content expires, so their deposits are only released by
+	/// [`Pallet::unpin`].
+	#[pallet::storage]
+	pub type Pinners<T: Config> = StorageMap<_, Identity, ContentHash, PinnersOf<T>, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The content stored in `block` has been indexed.
+		BlockIndexed { block: BlockNumberFor<T>, count: u32, expires_at: BlockNumberFor<T> },
+		/// Content left the retention window and has been removed from the index.
+		ContentsExpired { count: u32 },
+		/// An account registered as a pinner of some content.
+		Pinned { content_hash: ContentHash, who: T::AccountId },
+		/// An account is no longer a pinner of some content.
+		Unpinned { content_hash: ContentHash, who: T::AccountId },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The content is not in the index.
+		UnknownContent,
+		/// The account is not able to renew the content.
+		CannotRenew,
+		/// The account already pins the content.
+		AlreadyPinned,
+		/// The account does not pin the content.
+		NotPinned,
+		/// The content already has the maximum number of pinners.
+		TooManyPinners,
+	}
+
+	/// A reason for this pallet placing a hold on funds.
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		/// The funds are held as deposit for pinning some content.
+		Pin,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			Self::expire_contents(n).saturating_add(Self::index_block(n.saturating_sub(One::one())))
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Register the caller as a pinner of `content_hash`.
+		///
+		/// The caller must be able to renew the content, i.e. hold a storage authorization
+		/// covering it. A [`Config::PinDeposit`] is held until the caller unpins the content.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::pin())]
+		pub fn pin(origin: OriginFor<T>, content_hash: ContentHash) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(Contents::<T>::contains_key(content_hash), Error::<T>::UnknownContent);
+			ensure!(
+				pallet_bulletin_transaction_storage::Pallet::<T>::can_renew(
+					&who,
+					&TransactionRef::ContentHash(content_hash),
+				),
+				Error::<T>::CannotRenew
+			);
+			Pinners::<T>::try_mutate(content_hash, |pinners| -> DispatchResult {
+				ensure!(!pinners.iter().any(|(p, _)| p == &who), Error::<T>::AlreadyPinned);
+				ensure!(pinners.len() < T::MaxPinners::get() as usize, Error::<T>::TooManyPinners);
+				let deposit = T::PinDeposit::new(&who, Self::pin_footprint())?;
+				pinners
+					.try_push((who.clone(), deposit))
+					.map_err(|_| Error::<T>::TooManyPinners)?;
+				Ok(())
+			})?;
+			Self::deposit_event(Event::Pinned { content_hash, who });
+			Ok(())
+		}
+
+		/// Deregister the caller as a pinner of `content_hash`, releasing its deposit.
+		///
+		/// Pins can be removed whether or not the content is still retained.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::unpin())]
+		pub fn unpin(origin: OriginFor<T>, content_hash: ContentHash) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Pinners::<T>::try_mutate_exists(content_hash, |maybe_pinners| -> DispatchResult {
+				let pinners = maybe_pinners.as_mut().ok_or(Error::<T>::NotPinned)?;
+				let position =
+					pinners.iter().position(|(p, _)| p == &who).ok_or(Error::<T>::NotPinned)?;
+				let (_, deposit) = pinners.swap_remove(position);
+				deposit.drop(&who)?;
+				if pinners.is_empty() {
+					*maybe_pinners = None;
+				}
+				Ok(())
+			})?;
+			Self::deposit_event(Event::Unpinned { content_hash, who });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The latest known location of `content_hash`, if it is still retained.
+		pub fn content_location(
+			content_hash: ContentHash,
+		) -> Option<ContentLocation<BlockNumberFor<T>>> {
+			Contents::<T>::get(content_hash)
+		}
+
+		/// The accounts pinning `content_hash`.
+		pub fn pinners(content_hash: ContentHash) -> Vec<T::AccountId> {
+			Pinners::<T>::get(content_hash).into_iter().map(|(who, _)| who).collect()
+		}
+
+		/// The storage footprint a pin is charged for.
+		pub(crate) fn pin_footprint() -> Footprint {
+			Footprint::from_mel::<(ContentHash, T::AccountId)>()
+		}
+
+		/// Remove the content expiring at block `n` from the index.
+		pub(crate) fn expire_contents(n: BlockNumberFor<T>) -> Weight {
+			let expiring = Expiring::<T>::take(n);
+			let weight = <T as Config>::WeightInfo::expire_contents(expiring.len() as u32);
+			let mut expired = 0u32;
+			for content_hash in expiring {
+				// Content renewed in the meantime has been re-indexed with a later expiry.
+				if Contents::<T>::get(content_hash).is_some_and(|l| l.expires_at == n) {
+					Contents::<T>::remove(content_hash);
+					expired.saturating_inc();
+				}
+			}
+			if expired > 0 {
+				Self::deposit_event(Event::ContentsExpired { count: expired });
+			}
+			weight
+		}
+
+		/// Index the transactions stored in `block`.
+		pub(crate) fn index_block(block: BlockNumberFor<T>) -> Weight {
+			let Some(transactions) =
+				pallet_bulletin_transaction_storage::Pallet::<T>::transactions_at(block)
+			else {
+				return <T as Config>::WeightInfo::index_block(0);
+			};
+			let count = transactions.len() as u32;
+			// Data stored at `block` is dropped once `block + retention_period` is left behind.
+			let expires_at = block
+				.saturating_add(pallet_bulletin_transaction_storage::Pallet::<T>::retention_period())
+				.saturating_add(One::one());
+			let mut expiring = Expiring::<T>::get(expires_at);
+			for (index, tx) in transactions.into_iter().enumerate() {
+				Contents::<T>::insert(
+					tx.content_hash,
+					ContentLocation {
+						block,
+						index: index as u32,
+						extrinsic_index: tx.extrinsic_index,
+						size: tx.size,
+						expires_at,
+					},
+				);
+				// Bounded by `MaxBlockTransactions`, like the transactions of the block.
+				if !expiring.contains(&tx.content_hash) {
+					let _ = expiring.try_push(tx.content_hash);
+				}
+			}
+			Expiring::<T>::insert(expires_at, expiring);
+			if count > 0 {
+				Self::deposit_event(Event::BlockIndexed { block, count, expires_at });
+			}
+			<T as Config>::WeightInfo::index_block(count)
+		}
+	}
+}
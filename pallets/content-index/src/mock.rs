@@ -0,0 +1,147 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Individuality.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{self as indiv_pallet_content_index, ContentHash};
+use frame_support::{
+	assert_ok, derive_impl, parameter_types,
+	traits::{fungible::HoldConsideration, Hooks, LinearStoragePrice},
+};
+use frame_system::EnsureRoot;
+use pallet_bulletin_transaction_storage::{
+	AsAuthorizer, DEFAULT_MAX_BLOCK_TRANSACTIONS, DEFAULT_MAX_TRANSACTION_SIZE,
+};
+use sp_core::{ConstU32, ConstU64};
+use sp_runtime::BuildStorage;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		TransactionStorage: pallet_bulletin_transaction_storage,
+		ContentIndex: indiv_pallet_content_index,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+	type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type AccountStore = System;
+	type RuntimeHoldReason = RuntimeHoldReason;
+}
+
+impl pallet_bulletin_transaction_storage::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type Currency = Balances;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type FeeDestination = ();
+	type WeightInfo = ();
+	type MaxBlockTransactions = ConstU32<{ DEFAULT_MAX_BLOCK_TRANSACTIONS }>;
+	type MaxTransactionSize = ConstU32<{ DEFAULT_MAX_TRANSACTION_SIZE }>;
+	type MaxPermanentStorageSize = ConstU64<{ 1024 * 1024 }>;
+	type AuthorizationPeriod = ConstU64<100>;
+	type AuthorizerRegistrarOrigin = EnsureRoot<u64>;
+	type Authorizer = AsAuthorizer<EnsureRoot<u64>, u64, u64>;
+	type StoreRenewPriority = ConstU64<{ u64::MAX / 4 }>;
+	type StoreRenewLongevity = ConstU64<10>;
+	type RemoveExpiredAuthorizationPriority = ConstU64<{ u64::MAX }>;
+	type RemoveExpiredAuthorizationLongevity = ConstU64<10>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper =
+		pallet_bulletin_transaction_storage::benchmarking::DefaultCheckProofHelper;
+}
+
+parameter_types! {
+	pub const PinHoldReason: RuntimeHoldReason =
+		RuntimeHoldReason::ContentIndex(crate::HoldReason::Pin);
+}
+
+/// Deposit held for each pin.
+pub const PIN_DEPOSIT: u64 = 10;
+
+impl crate::Config for Test {
+	type WeightInfo = ();
+	type MaxPinners = ConstU32<3>;
+	type PinDeposit = HoldConsideration<
+		u64,
+		Balances,
+		PinHoldReason,
+		LinearStoragePrice<ConstU64<PIN_DEPOSIT>, ConstU64<0>, u64>,
+	>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = MockBenchmarkHelper;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct MockBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::BenchmarkHelper<u64> for MockBenchmarkHelper {
+	fn store(who: &u64, count: u32) {
+		authorize(*who, count);
+		for i in 0..count {
+			store(i.to_le_bytes().to_vec());
+		}
+	}
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let t = RuntimeGenesisConfig {
+		balances: pallet_balances::GenesisConfig {
+			balances: (1..=5).map(|who| (who, 100)).collect(),
+			..Default::default()
+		},
+		..Default::default()
+	}
+	.build_storage()
+	.unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+/// Authorize `who` to store and renew `transactions` pieces of content.
+pub fn authorize(who: u64, transactions: u32) {
+	assert_ok!(TransactionStorage::authorize_account(
+		RuntimeOrigin::root(),
+		who,
+		transactions,
+		transactions as u64 * 1024,
+	));
+}
+
+/// Store `data` in the current block and return its content hash.
+pub fn store(data: Vec<u8>) -> ContentHash {
+	let content_hash = sp_io::hashing::blake2_256(&data);
+	assert_ok!(TransactionStorage::store(RuntimeOrigin::root(), data));
+	content_hash
+}
+
+/// Finalize the current block and initialize the next one, indexing the stored content.
+pub fn next_block() {
+	let n = System::block_number();
+	<TransactionStorage as Hooks<_>>::on_finalize(n);
+	System::set_block_number(n + 1);
+	<ContentIndex as Hooks<_>>::on_initialize(n + 1);
+}
@@ -0,0 +1,36 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Individuality.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the content index pallet.
+
+use crate::{ContentHash, ContentLocation};
+use alloc::vec::Vec;
+use codec::Codec;
+
+sp_api::decl_runtime_apis! {
+	/// Lookup of content stored on the Bulletin chain by content hash (the digest of its CID).
+	pub trait ContentIndexApi<AccountId, BlockNumber>
+	where
+		AccountId: Codec,
+		BlockNumber: Codec,
+	{
+		/// The latest known location of the content, if it is still retained.
+		fn content_location(content_hash: ContentHash) -> Option<ContentLocation<BlockNumber>>;
+
+		/// The accounts pinning the content.
+		fn pinners(content_hash: ContentHash) -> Vec<AccountId>;
+	}
+}
@@ -0,0 +1,160 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Individuality.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{mock::*, *};
+use frame_support::{assert_noop, assert_ok, traits::Hooks};
+
+#[test]
+fn indexes_stored_content_once_its_block_is_finalized() {
+	new_test_ext().execute_with(|| {
+		authorize(1, 2);
+		let stored_block = System::block_number();
+		let first = store(vec![1u8; 24]);
+		let second = store(vec![2u8; 12]);
+		assert_eq!(ContentIndex::content_location(first), None);
+
+		next_block();
+		let retention_period =
+			pallet_bulletin_transaction_storage::Pallet::<Test>::retention_period();
+		let expires_at = stored_block + retention_period + 1;
+		assert_eq!(
+			ContentIndex::content_location(first),
+			Some(ContentLocation {
+				block: stored_block,
+				index: 0,
+				extrinsic_index: 0,
+				size: 24,
+				expires_at
+			})
+		);
+		let location = ContentIndex::content_location(second).unwrap();
+		assert_eq!((location.index, location.size), (1, 12));
+		// A single event covers the whole block.
+		System::assert_last_event(
+			Event::<Test>::BlockIndexed { block: stored_block, count: 2, expires_at }.into(),
+		);
+		assert_eq!(Expiring::<Test>::get(expires_at).into_inner(), vec![first, second]);
+
+		<ContentIndex as Hooks<_>>::on_initialize(expires_at);
+		assert_eq!(ContentIndex::content_location(first), None);
+		assert_eq!(ContentIndex::content_location(second), None);
+		System::assert_last_event(Event::<Test>::ContentsExpired { count: 2 }.into());
+	});
+}
+
+#[test]
+fn stored_again_content_is_kept_until_its_latest_expiry() {
+	new_test_ext().execute_with(|| {
+		let data = vec![0u8; 24];
+		let content_hash = store(data.clone());
+		next_block();
+		let stored = ContentIndex::content_location(content_hash).unwrap();
+
+		// Storing the content again moves it to the new block.
+		let restored_block = System::block_number();
+		store(data);
+		next_block();
+		let restored = ContentIndex::content_location(content_hash).unwrap();
+		assert_eq!(restored.block, restored_block);
+		assert!(restored.expires_at > stored.expires_at);
+
+		// The original expiry no longer affects the content.
+		System::reset_events();
+		<ContentIndex as Hooks<_>>::on_initialize(stored.expires_at);
+		assert_eq!(ContentIndex::content_location(content_hash), Some(restored.clone()));
+		assert!(System::events().is_empty());
+
+		<ContentIndex as Hooks<_>>::on_initialize(restored.expires_at);
+		assert_eq!(ContentIndex::content_location(content_hash), None);
+	});
+}
+
+#[test]
+fn pinning_requires_renewable_content() {
+	new_test_ext().execute_with(|| {
+		authorize(1, 1);
+		let content_hash = store(vec![0u8; 24]);
+		assert_noop!(
+			ContentIndex::pin(RuntimeOrigin::signed(1), content_hash),
+			Error::<Test>::UnknownContent
+		);
+		next_block();
+
+		// Only accounts able to renew the content can pin it.
+		assert_noop!(
+			ContentIndex::pin(RuntimeOrigin::signed(2), content_hash),
+			Error::<Test>::CannotRenew
+		);
+		assert_ok!(ContentIndex::pin(RuntimeOrigin::signed(1), content_hash));
+		System::assert_last_event(Event::<Test>::Pinned { content_hash, who: 1 }.into());
+		assert_noop!(
+			ContentIndex::pin(RuntimeOrigin::signed(1), content_hash),
+			Error::<Test>::AlreadyPinned
+		);
+		assert_eq!(ContentIndex::pinners(content_hash), vec![1]);
+	});
+}
+
+#[test]
+fn pins_hold_a_deposit() {
+	new_test_ext().execute_with(|| {
+		for who in 1..=4 {
+			authorize(who, 1);
+		}
+		let content_hash = store(vec![0u8; 24]);
+		next_block();
+
+		for who in 1..=3 {
+			assert_ok!(ContentIndex::pin(RuntimeOrigin::signed(who), content_hash));
+			assert_eq!(Balances::free_balance(who), 100 - PIN_DEPOSIT);
+		}
+		assert_noop!(
+			ContentIndex::pin(RuntimeOrigin::signed(4), content_hash),
+			Error::<Test>::TooManyPinners
+		);
+		assert_eq!(Balances::free_balance(4), 100);
+
+		assert_noop!(
+			ContentIndex::unpin(RuntimeOrigin::signed(4), content_hash),
+			Error::<Test>::NotPinned
+		);
+		assert_ok!(ContentIndex::unpin(RuntimeOrigin::signed(1), content_hash));
+		System::assert_last_event(Event::<Test>::Unpinned { content_hash, who: 1 }.into());
+		assert_eq!(Balances::free_balance(1), 100);
+		assert_eq!(ContentIndex::pinners(content_hash), vec![3, 2]);
+	});
+}
+
+#[test]
+fn pins_outlive_expired_content() {
+	new_test_ext().execute_with(|| {
+		authorize(1, 1);
+		let content_hash = store(vec![0u8; 24]);
+		next_block();
+		assert_ok!(ContentIndex::pin(RuntimeOrigin::signed(1), content_hash));
+
+		let location = ContentIndex::content_location(content_hash).unwrap();
+		<ContentIndex as Hooks<_>>::on_initialize(location.expires_at);
+		assert_eq!(ContentIndex::content_location(content_hash), None);
+		assert_eq!(ContentIndex::pinners(content_hash), vec![1]);
+		assert_eq!(Balances::free_balance(1), 100 - PIN_DEPOSIT);
+
+		// The deposit is released once the pin is removed.
+		assert_ok!(ContentIndex::unpin(RuntimeOrigin::signed(1), content_hash));
+		assert!(Pinners::<Test>::get(content_hash).is_empty());
+		assert_eq!(Balances::free_balance(1), 100);
+	});
+}
@@ -0,0 +1,226 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Individuality.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Autogenerated weights for `indiv_pallet_content_index`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 54.0.0
+//! DATE: 2026-10-19, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `parity-weights-6ae2b7a5d7ca634b`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
+//! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: `1024`
+
+// Executed Command:
+// frame-omni-bencher
+// v1
+// benchmark
+// pallet
+// --extrinsic=*
+// --runtime=target/production/wbuild/bulletin-paseo-runtime/bulletin_paseo_runtime.wasm
+// --pallet=indiv_pallet_content_index
+// --header=/home/cattery/actions-runner/_work/individuality/individuality/.github/scripts/cmd/file_header.txt
+// --output=/home/cattery/actions-runner/_work/individuality/individuality/pallets/content-index/src/weights.rs
+// --wasm-execution=compiled
+// --steps=50
+// --repeat=20
+// --min-duration=0
+// --heap-pages=4096
+// --genesis-builder=runtime
+// --no-storage-info
+// --no-min-squares
+// --no-median-slopes
+// --template=templates/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+#![allow(dead_code)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `indiv_pallet_content_index`.
+pub trait WeightInfo {
+	fn pin() -> Weight;
+	fn unpin() -> Weight;
+	fn expire_contents(n: u32, ) -> Weight;
+	fn index_block(n: u32, ) -> Weight;
+}
+
+/// Weights for `indiv_pallet_content_index` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `ContentIndex::Contents` (r:1 w:0)
+	/// Proof: `ContentIndex::Contents` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TransactionStorage::TransactionByContentHash` (r:1 w:0)
+	/// Proof: `TransactionStorage::TransactionByContentHash` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `TransactionStorage::Transactions` (r:1 w:0)
+	/// Proof: `TransactionStorage::Transactions` (`max_values`: None, `max_size`: Some(44054), added: 46529, mode: `MaxEncodedLen`)
+	/// Storage: `TransactionStorage::Authorizations` (r:1 w:0)
+	/// Proof: `TransactionStorage::Authorizations` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `ContentIndex::Pinners` (r:1 w:1)
+	/// Proof: `ContentIndex::Pinners` (`max_values`: None, `max_size`: Some(801), added: 3276, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn pin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1412`
+		//  Estimated: `47519`
+		// Minimum execution time: 68_214_000 picoseconds.
+		Weight::from_parts(70_503_000, 47519)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ContentIndex::Pinners` (r:1 w:1)
+	/// Proof: `ContentIndex::Pinners` (`max_values`: None, `max_size`: Some(801), added: 3276, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn unpin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1103`
+		//  Estimated: `4266`
+		// Minimum execution time: 47_921_000 picoseconds.
+		Weight::from_parts(49_388_000, 4266)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ContentIndex::Expiring` (r:1 w:1)
+	/// Proof: `ContentIndex::Expiring` (`max_values`: None, `max_size`: Some(16398), added: 18873, mode: `MaxEncodedLen`)
+	/// Storage: `ContentIndex::Contents` (r:512 w:512)
+	/// Proof: `ContentIndex::Contents` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 512]`.
+	fn expire_contents(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `121 + n * (93 ±0)`
+		//  Estimated: `19863 + n * (2527 ±0)`
+		// Minimum execution time: 3_942_000 picoseconds.
+		Weight::from_parts(4_318_107, 19863)
+			// Standard Error: 1_906
+			.saturating_add(Weight::from_parts(5_871_426, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2527).saturating_mul(n.into()))
+	}
+	/// Storage: `TransactionStorage::Transactions` (r:1 w:0)
+	/// Proof: `TransactionStorage::Transactions` (`max_values`: None, `max_size`: Some(44054), added: 46529, mode: `MaxEncodedLen`)
+	/// Storage: `TransactionStorage::RetentionPeriod` (r:1 w:0)
+	/// Proof: `TransactionStorage::RetentionPeriod` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `ContentIndex::Expiring` (r:1 w:1)
+	/// Proof: `ContentIndex::Expiring` (`max_values`: None, `max_size`: Some(16398), added: 18873, mode: `MaxEncodedLen`)
+	/// Storage: `ContentIndex::Contents` (r:0 w:512)
+	/// Proof: `ContentIndex::Contents` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 512]`.
+	fn index_block(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `187 + n * (72 ±0)`
+		//  Estimated: `47519`
+		// Minimum execution time: 6_215_000 picoseconds.
+		Weight::from_parts(6_873_541, 47519)
+			// Standard Error: 2_284
+			.saturating_add(Weight::from_parts(3_304_158, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `ContentIndex::Contents` (r:1 w:0)
+	/// Proof: `ContentIndex::Contents` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TransactionStorage::TransactionByContentHash` (r:1 w:0)
+	/// Proof: `TransactionStorage::TransactionByContentHash` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `TransactionStorage::Transactions` (r:1 w:0)
+	/// Proof: `TransactionStorage::Transactions` (`max_values`: None, `max_size`: Some(44054), added: 46529, mode: `MaxEncodedLen`)
+	/// Storage: `TransactionStorage::Authorizations` (r:1 w:0)
+	/// Proof: `TransactionStorage::Authorizations` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `ContentIndex::Pinners` (r:1 w:1)
+	/// Proof: `ContentIndex::Pinners` (`max_values`: None, `max_size`: Some(801), added: 3276, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn pin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1412`
+		//  Estimated: `47519`
+		// Minimum execution time: 68_214_000 picoseconds.
+		Weight::from_parts(70_503_000, 47519)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ContentIndex::Pinners` (r:1 w:1)
+	/// Proof: `ContentIndex::Pinners` (`max_values`: None, `max_size`: Some(801), added: 3276, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn unpin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1103`
+		//  Estimated: `4266`
+		// Minimum execution time: 47_921_000 picoseconds.
+		Weight::from_parts(49_388_000, 4266)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ContentIndex::Expiring` (r:1 w:1)
+	/// Proof: `ContentIndex::Expiring` (`max_values`: None, `max_size`: Some(16398), added: 18873, mode: `MaxEncodedLen`)
+	/// Storage: `ContentIndex::Contents` (r:512 w:512)
+	/// Proof: `ContentIndex::Contents` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 512]`.
+	fn expire_contents(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `121 + n * (93 ±0)`
+		//  Estimated: `19863 + n * (2527 ±0)`
+		// Minimum execution time: 3_942_000 picoseconds.
+		Weight::from_parts(4_318_107, 19863)
+			// Standard Error: 1_906
+			.saturating_add(Weight::from_parts(5_871_426, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2527).saturating_mul(n.into()))
+	}
+	/// Storage: `TransactionStorage::Transactions` (r:1 w:0)
+	/// Proof: `TransactionStorage::Transactions` (`max_values`: None, `max_size`: Some(44054), added: 46529, mode: `MaxEncodedLen`)
+	/// Storage: `TransactionStorage::RetentionPeriod` (r:1 w:0)
+	/// Proof: `TransactionStorage::RetentionPeriod` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `ContentIndex::Expiring` (r:1 w:1)
+	/// Proof: `ContentIndex::Expiring` (`max_values`: None, `max_size`: Some(16398), added: 18873, mode: `MaxEncodedLen`)
+	/// Storage: `ContentIndex::Contents` (r:0 w:512)
+	/// Proof: `ContentIndex::Contents` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 512]`.
+	fn index_block(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `187 + n * (72 ±0)`
+		//  Estimated: `47519`
+		// Minimum execution time: 6_215_000 picoseconds.
+		Weight::from_parts(6_873_541, 47519)
+			// Standard Error: 2_284
+			.saturating_add(Weight::from_parts(3_304_158, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+}
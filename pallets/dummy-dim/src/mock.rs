@@ -112,8 +112,10 @@ impl indiv_pallet_members::Config for Test {
 	type OnboardingQueuePageSize = ConstU32<40>;
 	type MaxFlexibleRingExponent = FlexibleRingExp;
 	type RingBuildingMemberLimit = ConstU32<100>;
+	type RingCompactionThreshold = frame_support::traits::GetDefault;
 	type OldRootRetentionDuration = ConstU64<600>;
 	type OnRingRootChange = ();
+	type CleanUpAlias = ();
 	type OffchainWorkerInterval = ConstU64<1>;
 	type ManagerOrigin = EnsureRoot<Self::AccountId>;
 	#[cfg(feature = "runtime-benchmarks")]
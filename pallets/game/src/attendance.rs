@@ -0,0 +1,95 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Individuality.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Rules deciding the attendance of players from the reports they received.
+
+use crate::{AttendanceRuleKind, Config};
+use core::marker::PhantomData;
+use frame_support::pallet_prelude::Get;
+use indiv_pallet_score::AccountOrPerson;
+use sp_runtime::{PerThing, Percent, SaturatedConversion};
+
+/// Decides the attendance of players from the reports they received in a game.
+///
+/// Each game selects one of the [`AttendanceRuleKind`] variants, which the implementation
+/// interprets. Implementations must be monotonic: more `Person` votes or fewer `NotPerson` votes
+/// can never turn a person into a non-person. Attendance is settled early during reporting on
+/// that assumption, as soon as the remaining votes cannot change the outcome.
+pub trait AttendanceRule<AccountId> {
+	/// The weight of the votes cast by `reporter` in a game decided by `rule`.
+	///
+	/// The weight is snapshotted during the shuffle phase. It must not exceed the greater of
+	/// [`Config::PeopleVoteWeight`] and [`Config::CandidateVoteWeight`], which are bounded by the
+	/// integrity test of the pallet.
+	fn vote_weight(rule: &AttendanceRuleKind, reporter: &AccountOrPerson<AccountId>) -> u8;
+
+	/// Whether a player who received `yes` vote weight for `Person` and `no` vote weight for
+	/// `NotPerson` is considered a person under `rule`.
+	fn is_person(rule: &AttendanceRuleKind, yes: u32, no: u32) -> bool;
+}
+
+/// The attendance rules provided by the pallet, implementing every [`AttendanceRuleKind`].
+pub struct DefaultAttendanceRule<T>(PhantomData<T>);
+
+impl<T: Config> AttendanceRule<T::AccountId> for DefaultAttendanceRule<T> {
+	fn vote_weight(rule: &AttendanceRuleKind, reporter: &AccountOrPerson<T::AccountId>) -> u8 {
+		let people_weight = T::PeopleVoteWeight::get();
+		let candidate_weight = T::CandidateVoteWeight::get();
+		if indiv_pallet_score::Pallet::<T>::reached_personhood(reporter) {
+			return people_weight;
+		}
+
+		match rule {
+			AttendanceRuleKind::StrictMajority | AttendanceRuleKind::Supermajority { .. } =>
+				candidate_weight,
+			AttendanceRuleKind::ScoreWeighted => {
+				// Move from the candidate weight towards the people weight as the score of the
+				// candidate approaches the personhood threshold.
+				let threshold = indiv_pallet_score::PersonhoodThreshold::<T>::get();
+				if threshold == 0 {
+					return candidate_weight;
+				}
+				let score = indiv_pallet_score::Participants::<T>::get(reporter)
+					.map_or(0, |participant| participant.score)
+					.min(threshold);
+				let (people_weight, candidate_weight) =
+					(u32::from(people_weight), u32::from(candidate_weight));
+				let weight = if people_weight >= candidate_weight {
+					candidate_weight.saturating_add(
+						(people_weight - candidate_weight).saturating_mul(score) / threshold,
+					)
+				} else {
+					candidate_weight.saturating_sub(
+						(candidate_weight - people_weight).saturating_mul(score) / threshold,
+					)
+				};
+				weight.saturated_into()
+			},
+		}
+	}
+
+	fn is_person(rule: &AttendanceRuleKind, yes: u32, no: u32) -> bool {
+		match rule {
+			AttendanceRuleKind::StrictMajority | AttendanceRuleKind::ScoreWeighted =>
+				yes.saturating_sub(1) >= no,
+			AttendanceRuleKind::Supermajority { threshold } => {
+				let total = u64::from(yes).saturating_add(u64::from(no));
+				u64::from(yes).saturating_mul(u64::from(Percent::ACCURACY)) >=
+					u64::from(threshold.deconstruct()).saturating_mul(total)
+			},
+		}
+	}
+}
@@ -79,8 +79,9 @@ mod benches {
 		BoundedVec,
 	};
 	use indiv_pallet_score::{
-		AbsenceGraceSchedule, AbsenceGraceTier, AbsenceGraceTiers, PersonhoodThresholdSchedule,
-		PersonhoodThresholdTier, MAX_PERSONHOOD_THRESHOLD_TIERS, SCORE_CONTEXT,
+		benchmarking::max_signal_weights, AbsenceGraceSchedule, AbsenceGraceTier,
+		AbsenceGraceTiers, PersonhoodThresholdSchedule, PersonhoodThresholdTier,
+		SignalWeightSchedule, MAX_PERSONHOOD_THRESHOLD_TIERS, SCORE_CONTEXT,
 	};
 	use indiv_support::traits::CountedMembers;
 	use sp_core::Get;
@@ -106,6 +107,11 @@ mod benches {
 		}
 	}
 
+	/// Weigh every source of personhood signals, so updating a score combines all of them.
+	fn bench_setup_signal_weights<T: Config>() {
+		SignalWeightSchedule::<T>::put(max_signal_weights());
+	}
+
 	/// Ensure the prize asset exists, is enabled in `indiv_pallet_airdrop::SupportedAssets`,
 	/// and that `T::AirdropSource` holds enough of it to fund any schedule a benchmark may
 	/// build. Idempotent.
@@ -221,6 +227,78 @@ mod benches {
 	}
 
 	// Worst case for `new_game`: some airdrop is scheduled.
+	/// Create the game of `schedule` in region 0 with `2 * max_group_size` players, and run the
+	/// shuffle until every player is indexed. Returns the player count.
+	fn bench_index_players<T: Config>(schedule: &GameScheduleOf<T>) -> u32 {
+		let player_count = 2u32 * schedule.max_group_size;
+
+		assert_ok!(pallet::Pallet::<T>::new_game(schedule));
+
+		// Sign up the players so the resulting two groups are both full.
+		for i in 0..player_count {
+			let player: T::AccountId = account("player", i, i);
+			<T as Config>::BenchmarkHelper::fund_account(player.clone());
+			let result = pallet::Pallet::<T>::sign_up_with_account(
+				RawOrigin::Signed(player.clone()).into(),
+				0,
+				DEFAULT_IDENTIFIER_KEY,
+				None,
+			);
+			assert!(
+				result.is_ok(),
+				"sign_up_with_account failed for player {i}: {:?}",
+				result.err().unwrap().error
+			);
+		}
+
+		// Run Step1 to insert every player into the per-round shuffle storages.
+		let parent_hash = frame_system::Pallet::<T>::parent_hash();
+		let mut step1_last_key = None;
+		let mut pending_attendance = 0u32;
+		loop {
+			let r = pallet::Pallet::<T>::shuffle_step_insert(
+				0,
+				&mut step1_last_key,
+				&mut pending_attendance,
+				schedule.rounds,
+				&AttendanceRuleKind::StrictMajority,
+				&parent_hash,
+			);
+			if matches!(r, StepResult::Finished) {
+				break;
+			}
+		}
+
+		// Run Step2 to assign player indices in every round.
+		let mut next_index = 0;
+		let mut recognized_finished = false;
+		loop {
+			let r = pallet::Pallet::<T>::shuffle_step_retrieve(
+				0,
+				&mut next_index,
+				&mut recognized_finished,
+				schedule.rounds,
+			);
+			if matches!(r, StepResult::Finished) {
+				break;
+			}
+		}
+
+		assert_eq!(next_index, player_count);
+		player_count
+	}
+
+	/// Full recent co-players made of `co_players`, completed with accounts outside the game.
+	fn bench_recent_co_players<T: Config>(
+		co_players: impl Iterator<Item = AccountOrPerson<T::AccountId>>,
+	) -> BoundedVec<AccountOrPerson<T::AccountId>, T::MaxRecentCoPlayers> {
+		let mut recent: Vec<_> = co_players.collect();
+		let filler = (0..T::MaxRecentCoPlayers::get())
+			.map(|i| AccountOrPerson::Account(account("co_player", i, i)));
+		recent.extend(filler);
+		BoundedVec::truncate_from(recent)
+	}
+
 	#[benchmark]
 	fn new_game() -> Result<(), BenchmarkError> {
 		let schedule = GameScheduleOf::<T> {
@@ -228,6 +306,10 @@ mod benches {
 			rounds: T::MaxRounds::get() as u8,
 			max_group_size: T::MaxGroupSize::get(),
 			airdrop_prize: Some(bench_airdrop_prize::<T>()),
+			attendance_rule: AttendanceRuleKind::StrictMajority,
+			region: 0,
+			group_assignment: GroupAssignment::Shuffled,
+			phases: None,
 		};
 
 		<T as Config>::BenchmarkHelper::set_valid_time();
@@ -238,7 +320,7 @@ mod benches {
 			assert_ok!(pallet::Pallet::<T>::new_game(&schedule));
 		}
 
-		let game = Game::<T>::get().expect("Game should exist");
+		let game = Game::<T>::get(0).expect("Game should exist");
 		assert_eq!(game.state, GameState::Registration { next_player_index: 0 });
 		assert_eq!(game.max_group_size, schedule.max_group_size);
 		assert_eq!(game.rounds, schedule.rounds);
@@ -258,6 +340,10 @@ mod benches {
 			rounds: T::MaxRounds::get() as u8,
 			max_group_size: T::MaxGroupSize::get(),
 			airdrop_prize: Some(bench_airdrop_prize::<T>()),
+			attendance_rule: AttendanceRuleKind::StrictMajority,
+			region: 0,
+			group_assignment: GroupAssignment::Shuffled,
+			phases: None,
 		};
 		assert_ok!(pallet::Pallet::<T>::new_game(&schedule));
 
@@ -265,7 +351,7 @@ mod benches {
 
 		#[block]
 		{
-			game = Game::<T>::get();
+			game = Game::<T>::get(0);
 		}
 
 		// `get` returns it
@@ -295,6 +381,10 @@ mod benches {
 				rounds: T::MaxRounds::get() as u8,
 				max_group_size: T::MaxGroupSize::get(),
 				airdrop_prize: Some(bench_airdrop_prize::<T>()),
+				attendance_rule: AttendanceRuleKind::StrictMajority,
+				region: 0,
+				group_assignment: GroupAssignment::Shuffled,
+				phases: None,
 			};
 			prev_game_end = GameTimes::<T>::player_process_end(&schedule);
 
@@ -333,18 +423,25 @@ mod benches {
 	fn put_game() -> Result<(), BenchmarkError> {
 		#[block]
 		{
-			Game::<T>::put(GameInfo {
-				index: 0,
-				registration_ends: 0,
-				shuffle_deadline: 1,
-				game_date: 0,
-				report_ends: 0,
-				state: GameState::Registration { next_player_index: 0 },
-				max_group_size: T::MaxGroupSize::get(),
-				rounds: T::MaxRounds::get() as u8,
-				pending_attendance: 0,
-				airdrop_scheduled: false,
-			})
+			Game::<T>::insert(
+				0,
+				GameInfo {
+					index: 0,
+					registration_ends: 0,
+					shuffle_deadline: 1,
+					game_date: 0,
+					report_ends: 0,
+					state: GameState::Registration { next_player_index: 0 },
+					max_group_size: T::MaxGroupSize::get(),
+					rounds: T::MaxRounds::get() as u8,
+					pending_attendance: 0,
+					airdrop_scheduled: false,
+					attendance_rule: AttendanceRuleKind::StrictMajority,
+					region: 0,
+					group_assignment: GroupAssignment::Shuffled,
+					phases: <T as Config>::DefaultPhaseDurations::get(),
+				},
+			)
 		}
 		Ok(())
 	}
@@ -358,6 +455,10 @@ mod benches {
 				rounds: T::MaxRounds::get() as u8,
 				max_group_size: T::MaxGroupSize::get(),
 				airdrop_prize: Some(bench_airdrop_prize::<T>()),
+				attendance_rule: AttendanceRuleKind::StrictMajority,
+				region: 0,
+				group_assignment: GroupAssignment::Shuffled,
+				phases: None,
 			});
 		}
 
@@ -384,6 +485,10 @@ mod benches {
 			rounds: T::MaxRounds::get() as u8,
 			pending_attendance: 0,
 			airdrop_scheduled: false,
+			attendance_rule: AttendanceRuleKind::StrictMajority,
+			region: 0,
+			group_assignment: GroupAssignment::Shuffled,
+			phases: <T as Config>::DefaultPhaseDurations::get(),
 		};
 
 		let mut meter = WeightMeter::new();
@@ -393,7 +498,7 @@ mod benches {
 			pallet::Pallet::<T>::shuffles(&mut meter, game);
 		}
 
-		assert_eq!(Game::<T>::get().unwrap().state, GameState::Reporting { player_count: 0 });
+		assert_eq!(Game::<T>::get(0).unwrap().state, GameState::Reporting { player_count: 0 });
 
 		Ok(())
 	}
@@ -411,6 +516,10 @@ mod benches {
 			rounds,
 			max_group_size: 2,
 			airdrop_prize: Some(bench_airdrop_prize::<T>()),
+			attendance_rule: AttendanceRuleKind::StrictMajority,
+			region: 0,
+			group_assignment: GroupAssignment::Shuffled,
+			phases: None,
 		};
 		assert_ok!(pallet::Pallet::<T>::new_game(&schedule));
 
@@ -423,6 +532,7 @@ mod benches {
 
 		let result = pallet::Pallet::<T>::sign_up_with_account(
 			RawOrigin::Signed(alice.clone()).into(),
+			0,
 			DEFAULT_IDENTIFIER_KEY,
 			None,
 		);
@@ -434,6 +544,7 @@ mod benches {
 
 		let result = pallet::Pallet::<T>::sign_up_with_account(
 			RawOrigin::Signed(bob.clone()).into(),
+			0,
 			DEFAULT_IDENTIFIER_KEY,
 			None,
 		);
@@ -451,9 +562,11 @@ mod benches {
 		// Do one shuffle step insert.
 		assert_eq!(
 			pallet::Pallet::<T>::shuffle_step_insert(
+				0,
 				&mut last_key,
 				&mut pending_attendance,
 				rounds,
+				&AttendanceRuleKind::ScoreWeighted,
 				&parent_hash
 			),
 			StepResult::Continue
@@ -463,15 +576,17 @@ mod benches {
 		#[block]
 		{
 			let _ = pallet::Pallet::<T>::shuffle_step_insert(
+				0,
 				&mut last_key,
 				&mut pending_attendance,
 				rounds,
+				&AttendanceRuleKind::ScoreWeighted,
 				&parent_hash,
 			);
 		}
 
 		for round in 0..rounds {
-			assert_eq!(ShuffleNotRecognized::<T>::iter_prefix(round).count(), 2);
+			assert_eq!(ShuffleNotRecognized::<T>::iter_prefix((0, round)).count(), 2);
 		}
 
 		Ok(())
@@ -490,6 +605,10 @@ mod benches {
 			rounds,
 			max_group_size: 2,
 			airdrop_prize: Some(bench_airdrop_prize::<T>()),
+			attendance_rule: AttendanceRuleKind::StrictMajority,
+			region: 0,
+			group_assignment: GroupAssignment::Shuffled,
+			phases: None,
 		};
 		assert_ok!(pallet::Pallet::<T>::new_game(&schedule));
 
@@ -502,6 +621,7 @@ mod benches {
 
 		let result = pallet::Pallet::<T>::sign_up_with_account(
 			RawOrigin::Signed(alice.clone()).into(),
+			0,
 			DEFAULT_IDENTIFIER_KEY,
 			None,
 		);
@@ -513,6 +633,7 @@ mod benches {
 
 		let result = pallet::Pallet::<T>::sign_up_with_account(
 			RawOrigin::Signed(bob.clone()).into(),
+			0,
 			DEFAULT_IDENTIFIER_KEY,
 			None,
 		);
@@ -528,20 +649,24 @@ mod benches {
 		let mut pending_attendance = 0u32;
 
 		let _ = pallet::Pallet::<T>::shuffle_step_insert(
+			0,
 			&mut last_key,
 			&mut pending_attendance,
 			rounds,
+			&AttendanceRuleKind::StrictMajority,
 			&parent_hash,
 		);
 		let _ = pallet::Pallet::<T>::shuffle_step_insert(
+			0,
 			&mut last_key,
 			&mut pending_attendance,
 			rounds,
+			&AttendanceRuleKind::StrictMajority,
 			&parent_hash,
 		);
 
 		for round in 0..rounds {
-			assert_eq!(ShuffleNotRecognized::<T>::iter_prefix(round).count(), 2);
+			assert_eq!(ShuffleNotRecognized::<T>::iter_prefix((0, round)).count(), 2);
 		}
 
 		let mut recognized_finished = true;
@@ -549,6 +674,7 @@ mod benches {
 
 		// Do one shuffle step retrieve.
 		let _ = pallet::Pallet::<T>::shuffle_step_retrieve(
+			0,
 			&mut next_index,
 			&mut recognized_finished,
 			rounds,
@@ -558,6 +684,7 @@ mod benches {
 		#[block]
 		{
 			let _ = pallet::Pallet::<T>::shuffle_step_retrieve(
+				0,
 				&mut next_index,
 				&mut recognized_finished,
 				rounds,
@@ -569,20 +696,20 @@ mod benches {
 		let bob_aop = AccountOrPerson::Account(bob);
 
 		for round in 0..rounds {
-			assert_eq!(ShuffleNotRecognized::<T>::iter_prefix(round).count(), 0);
+			assert_eq!(ShuffleNotRecognized::<T>::iter_prefix((0, round)).count(), 0);
 
-			let alice_index = PlayerToIndex::<T>::get(&alice_aop).unwrap()[round as usize];
-			let bob_index = PlayerToIndex::<T>::get(&bob_aop).unwrap()[round as usize];
+			let alice_index = PlayerToIndex::<T>::get(0, &alice_aop).unwrap()[round as usize];
+			let bob_index = PlayerToIndex::<T>::get(0, &bob_aop).unwrap()[round as usize];
 
-			assert_eq!(IndexToPlayer::<T>::get((round, alice_index)), Some(alice_aop.clone()));
-			assert_eq!(IndexToPlayer::<T>::get((round, bob_index)), Some(bob_aop.clone()));
+			assert_eq!(IndexToPlayer::<T>::get(0, (round, alice_index)), Some(alice_aop.clone()));
+			assert_eq!(IndexToPlayer::<T>::get(0, (round, bob_index)), Some(bob_aop.clone()));
 		}
 
 		Ok(())
 	}
 
 	#[benchmark]
-	fn shuffle_step_compute_weights(
+	fn shuffle_step_spread_pairings(
 		n: Linear<2, { T::MaxGroupSize::get() }>,
 		r: Linear<1, { T::MaxRounds::get() }>,
 	) -> Result<(), BenchmarkError> {
@@ -590,82 +717,103 @@ mod benches {
 		bench_setup_airdrop_funds::<T>();
 
 		let rounds = r as u8;
-		let player_count = 2u32 * n;
-
-		// One game exists with `max_group_size = n`.
 		let schedule = crate::types::GameScheduleOf::<T> {
 			game_play_time: 1000,
 			rounds,
 			max_group_size: n,
 			airdrop_prize: Some(bench_airdrop_prize::<T>()),
+			attendance_rule: AttendanceRuleKind::StrictMajority,
+			region: 0,
+			group_assignment: GroupAssignment::SpreadPairings,
+			phases: None,
 		};
-		assert_ok!(pallet::Pallet::<T>::new_game(&schedule));
-
-		// Sign up `2 * n` players so the resulting two groups of size `n` are both full.
-		for i in 0..player_count {
-			let player: T::AccountId = account("player", i, i);
-			<T as Config>::BenchmarkHelper::fund_account(player.clone());
-			let result = pallet::Pallet::<T>::sign_up_with_account(
-				RawOrigin::Signed(player.clone()).into(),
-				DEFAULT_IDENTIFIER_KEY,
-				None,
-			);
-			assert!(
-				result.is_ok(),
-				"sign_up_with_account failed for player {i}: {:?}",
-				result.err().unwrap().error
-			);
-		}
+		let player_count = bench_index_players::<T>(&schedule);
+		let groups_setting = GroupsSetting { max_per_group: n, player_count };
+
+		// Spread the last round, which also reads the groups of the previous rounds. The first
+		// player recently played with their whole group so the swap is evaluated.
+		let mut round = rounds - 1;
+		let mut index = 0;
+		let player = IndexToPlayer::<T>::get(0, (round, 0)).expect("player indexed");
+		let co_players = groups_setting
+			.group_members(0)
+			.filter(|&member_index| member_index != 0)
+			.map(|member_index| IndexToPlayer::<T>::get(0, (round, member_index)).unwrap());
+		RecentCoPlayers::<T>::insert(&player, bench_recent_co_players::<T>(co_players));
+		let next_player = IndexToPlayer::<T>::get(0, (round, 1)).expect("player indexed");
+		RecentCoPlayers::<T>::insert(
+			&next_player,
+			bench_recent_co_players::<T>(core::iter::empty()),
+		);
 
-		// Run Step1 to insert every player into the per-round shuffle storages.
-		let parent_hash = frame_system::Pallet::<T>::parent_hash();
-		let mut step1_last_key = None;
-		let mut pending_attendance = 0u32;
-		loop {
-			let r = pallet::Pallet::<T>::shuffle_step_insert(
-				&mut step1_last_key,
-				&mut pending_attendance,
+		#[block]
+		{
+			let _ = pallet::Pallet::<T>::shuffle_step_spread_pairings(
+				0,
+				&mut round,
+				&mut index,
 				rounds,
-				&parent_hash,
+				&groups_setting,
 			);
-			if matches!(r, StepResult::Finished) {
-				break;
-			}
 		}
 
-		// Run Step2 to assign player indices in every round.
-		let mut next_index = 0;
-		let mut recognized_finished = false;
-		loop {
-			let r = pallet::Pallet::<T>::shuffle_step_retrieve(
-				&mut next_index,
-				&mut recognized_finished,
-				rounds,
+		assert_eq!(round, rounds - 1);
+		assert_eq!(index, 1);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn shuffle_step_compute_weights(
+		n: Linear<2, { T::MaxGroupSize::get() }>,
+		r: Linear<1, { T::MaxRounds::get() }>,
+	) -> Result<(), BenchmarkError> {
+		<T as Config>::BenchmarkHelper::set_valid_time();
+		bench_setup_airdrop_funds::<T>();
+
+		let rounds = r as u8;
+
+		// One game exists with `max_group_size = n`, recording the co-players of each player.
+		let schedule = crate::types::GameScheduleOf::<T> {
+			game_play_time: 1000,
+			rounds,
+			max_group_size: n,
+			airdrop_prize: Some(bench_airdrop_prize::<T>()),
+			attendance_rule: AttendanceRuleKind::StrictMajority,
+			region: 0,
+			group_assignment: GroupAssignment::SpreadPairings,
+			phases: None,
+		};
+		let next_index = bench_index_players::<T>(&schedule);
+		// The recent co-players of every player are full, so recording evicts the oldest.
+		for (player, _) in PlayerToIndex::<T>::iter_prefix(0) {
+			RecentCoPlayers::<T>::insert(
+				&player,
+				bench_recent_co_players::<T>(core::iter::empty()),
 			);
-			if matches!(r, StepResult::Finished) {
-				break;
-			}
 		}
 
-		assert_eq!(next_index, player_count);
-
-		// Warm up Step3 once so the benchmarked call uses `iter_from_key`.
+		// Warm up Step4 once so the benchmarked call uses `iter_from_key`.
 		let mut last_iteration = None;
 		let _ = pallet::Pallet::<T>::shuffle_step_compute_weights(
+			0,
 			&mut last_iteration,
 			rounds,
 			next_index,
 			n,
+			GroupAssignment::SpreadPairings,
 		);
 		let warmup_player = last_iteration.clone().expect("warmup processed one player");
 
 		#[block]
 		{
 			let _ = pallet::Pallet::<T>::shuffle_step_compute_weights(
+				0,
 				&mut last_iteration,
 				rounds,
 				next_index,
 				n,
+				GroupAssignment::SpreadPairings,
 			);
 		}
 
@@ -719,7 +867,7 @@ mod benches {
 
 		#[block]
 		{
-			// Mirror the logic inside `shuffles` for the `Step4AwaitSession` step:
+			// Mirror the logic inside `shuffles` for the `Step5AwaitSession` step:
 			// both the `can_start` check and the `start` call are on the hot path,
 			// so they must be included in the benchmarked block.
 			assert!(indiv_pallet_score::Pallet::<T>::can_start_attendance_report_session());
@@ -760,22 +908,26 @@ mod benches {
 			rounds,
 			pending_attendance: 0,
 			airdrop_scheduled: false,
+			attendance_rule: AttendanceRuleKind::StrictMajority,
+			region: 0,
+			group_assignment: GroupAssignment::Shuffled,
+			phases: <T as Config>::DefaultPhaseDurations::get(),
 		};
 
 		let mut meter = WeightMeter::new();
 
 		indiv_pallet_score::Pallet::<T>::start_attendance_report_session().unwrap();
-		Game::<T>::put(game);
+		Game::<T>::insert(0, game);
 
 		// All the players are processed for the given game
 		#[block]
 		{
-			pallet::Pallet::<T>::player_process_step1(&mut meter);
+			pallet::Pallet::<T>::player_process_step1(&mut meter, 0);
 		}
 
 		// Step 1 finished and handed off to step 2.
 		assert!(matches!(
-			Game::<T>::get().unwrap().state,
+			Game::<T>::get(0).unwrap().state,
 			GameState::PlayerProcess { step: PlayerProcessStep::Step2ClearIndices },
 		));
 
@@ -788,6 +940,7 @@ mod benches {
 	// candidates, and drops a deposit after the target reaches personhood.
 	#[benchmark]
 	fn player_process_step1_attended_player() -> Result<(), BenchmarkError> {
+		bench_setup_signal_weights::<T>();
 		<T as Config>::BenchmarkHelper::set_valid_time();
 
 		let max_group_size = T::MaxGroupSize::get();
@@ -813,11 +966,16 @@ mod benches {
 			rounds,
 			pending_attendance: player_count,
 			airdrop_scheduled: false,
+			attendance_rule: AttendanceRuleKind::StrictMajority,
+			region: 0,
+			group_assignment: GroupAssignment::Shuffled,
+			phases: <T as Config>::DefaultPhaseDurations::get(),
 		};
 
 		for i in 0..player_count {
 			let mut player = Player {
 				first_game: 0,
+				region: 0,
 				registered: true,
 				sent_report: true,
 				early_attendance_enactment: None,
@@ -856,16 +1014,16 @@ mod benches {
 
 			let indices =
 				BoundedVec::try_from(vec![i; rounds as usize]).expect("rounds within bound");
-			PlayerToIndex::<T>::insert(&account_or_person, indices);
+			PlayerToIndex::<T>::insert(0, &account_or_person, indices);
 			for round in 0..rounds {
-				IndexToPlayer::<T>::insert((round, i), &account_or_person);
+				IndexToPlayer::<T>::insert(0, (round, i), &account_or_person);
 			}
 		}
 
 		let group_index = groups_setting.group_index_from_player_index(0);
 		for round in 0..rounds {
 			for co_member_index in groups_setting.group_members(group_index).filter(|&j| j != 0) {
-				let Some(co_member) = IndexToPlayer::<T>::get((round, co_member_index)) else {
+				let Some(co_member) = IndexToPlayer::<T>::get(0, (round, co_member_index)) else {
 					continue;
 				};
 				let nft =
@@ -902,8 +1060,10 @@ mod benches {
 		{
 			pallet::Pallet::<T>::process_player_attendance_outcome(
 				game.index,
+				0,
 				game.rounds,
 				game.max_group_size,
+				&game.attendance_rule,
 				&mut game.pending_attendance,
 				player_count,
 				&mut last_iteration,
@@ -934,6 +1094,7 @@ mod benches {
 	// player, and tear down statement-account state.
 	#[benchmark]
 	fn player_process_step1_not_attended_player() -> Result<(), BenchmarkError> {
+		bench_setup_signal_weights::<T>();
 		<T as Config>::BenchmarkHelper::set_valid_time();
 
 		let max_group_size = T::MaxGroupSize::get();
@@ -959,6 +1120,10 @@ mod benches {
 			rounds,
 			pending_attendance: player_count,
 			airdrop_scheduled: false,
+			attendance_rule: AttendanceRuleKind::StrictMajority,
+			region: 0,
+			group_assignment: GroupAssignment::Shuffled,
+			phases: <T as Config>::DefaultPhaseDurations::get(),
 		};
 
 		indiv_pallet_score::Pallet::<T>::onboard_externally_recognized(&target_alias)?;
@@ -972,6 +1137,7 @@ mod benches {
 		for i in 0..player_count {
 			let mut player = Player {
 				first_game: 0,
+				region: 0,
 				registered: true,
 				sent_report: true,
 				early_attendance_enactment: None,
@@ -1006,16 +1172,16 @@ mod benches {
 
 			let indices =
 				BoundedVec::try_from(vec![i; rounds as usize]).expect("rounds within bound");
-			PlayerToIndex::<T>::insert(&account_or_person, indices);
+			PlayerToIndex::<T>::insert(0, &account_or_person, indices);
 			for round in 0..rounds {
-				IndexToPlayer::<T>::insert((round, i), &account_or_person);
+				IndexToPlayer::<T>::insert(0, (round, i), &account_or_person);
 			}
 		}
 
 		let group_index = groups_setting.group_index_from_player_index(0);
 		for round in 0..rounds {
 			for co_member_index in groups_setting.group_members(group_index).filter(|&j| j != 0) {
-				let Some(co_member) = IndexToPlayer::<T>::get((round, co_member_index)) else {
+				let Some(co_member) = IndexToPlayer::<T>::get(0, (round, co_member_index)) else {
 					continue;
 				};
 				let nft =
@@ -1037,8 +1203,10 @@ mod benches {
 		{
 			pallet::Pallet::<T>::process_player_attendance_outcome(
 				game.index,
+				0,
 				game.rounds,
 				game.max_group_size,
+				&game.attendance_rule,
 				&mut game.pending_attendance,
 				player_count,
 				&mut last_iteration,
@@ -1068,27 +1236,34 @@ mod benches {
 		bench_setup_airdrop_funds::<T>();
 
 		let rounds = T::MaxRounds::get() as u8;
-		Game::<T>::put(GameInfo {
-			index: 0,
-			registration_ends: 0,
-			shuffle_deadline: 0,
-			game_date: 0,
-			report_ends: 0,
-			state: GameState::PlayerProcess { step: PlayerProcessStep::Step2ClearIndices },
-			max_group_size: T::MaxGroupSize::get(),
-			rounds,
-			pending_attendance: 0,
-			airdrop_scheduled: false,
-		});
+		Game::<T>::insert(
+			0,
+			GameInfo {
+				index: 0,
+				registration_ends: 0,
+				shuffle_deadline: 0,
+				game_date: 0,
+				report_ends: 0,
+				state: GameState::PlayerProcess { step: PlayerProcessStep::Step2ClearIndices },
+				max_group_size: T::MaxGroupSize::get(),
+				rounds,
+				pending_attendance: 0,
+				airdrop_scheduled: false,
+				attendance_rule: AttendanceRuleKind::StrictMajority,
+				region: 0,
+				group_assignment: GroupAssignment::Shuffled,
+				phases: <T as Config>::DefaultPhaseDurations::get(),
+			},
+		);
 
 		let mut meter = WeightMeter::new();
 
 		#[block]
 		{
-			pallet::Pallet::<T>::player_process_step2(&mut meter);
+			pallet::Pallet::<T>::player_process_step2(&mut meter, 0);
 		}
 
-		assert!(Game::<T>::get().is_none(), "game should be killed after player_process_step2");
+		assert!(Game::<T>::get(0).is_none(), "game should be killed after player_process_step2");
 
 		Ok(())
 	}
@@ -1107,8 +1282,8 @@ mod benches {
 
 			let indices =
 				BoundedVec::try_from(vec![i; rounds as usize]).expect("rounds within bound");
-			PlayerToIndex::<T>::insert(&account_or_person, indices);
-			IndexToPlayer::<T>::insert((0u8, i), &account_or_person);
+			PlayerToIndex::<T>::insert(0, &account_or_person, indices);
+			IndexToPlayer::<T>::insert(0, (0u8, i), &account_or_person);
 		}
 
 		let mut cursor1: Option<Vec<u8>> = None;
@@ -1119,6 +1294,7 @@ mod benches {
 		#[block]
 		{
 			pallet::Pallet::<T>::player_process_step2_inner_loop(
+				0,
 				&mut cursor1,
 				&mut cursor2,
 				&mut done1,
@@ -1146,19 +1322,23 @@ mod benches {
 			rounds: T::MaxRounds::get() as u8,
 			pending_attendance: 0,
 			airdrop_scheduled: false,
+			attendance_rule: AttendanceRuleKind::StrictMajority,
+			region: 0,
+			group_assignment: GroupAssignment::Shuffled,
+			phases: <T as Config>::DefaultPhaseDurations::get(),
 		};
 
 		// No players exists for the game so `process_cancelling_step` should do minimal computation
 
 		let mut meter = WeightMeter::new();
-		Game::<T>::put(game);
+		Game::<T>::insert(0, game);
 
 		#[block]
 		{
-			pallet::Pallet::<T>::process_cancelling(&mut meter);
+			pallet::Pallet::<T>::process_cancelling(&mut meter, 0);
 		}
 
-		assert!(Game::<T>::get().is_none(), "The game should be removed");
+		assert!(Game::<T>::get(0).is_none(), "The game should be removed");
 
 		Ok(())
 	}
@@ -1178,6 +1358,10 @@ mod benches {
 			rounds,
 			max_group_size: T::MaxGroupSize::get(),
 			airdrop_prize: Some(bench_airdrop_prize::<T>()),
+			attendance_rule: AttendanceRuleKind::StrictMajority,
+			region: 0,
+			group_assignment: GroupAssignment::Shuffled,
+			phases: None,
 		};
 		assert_ok!(pallet::Pallet::<T>::new_game(&schedule));
 
@@ -1198,6 +1382,7 @@ mod benches {
 
 		let result = pallet::Pallet::<T>::sign_up_with_alias(
 			origin,
+			0,
 			DEFAULT_IDENTIFIER_KEY,
 			statement_account.clone(),
 			signature,
@@ -1208,15 +1393,15 @@ mod benches {
 		let indices: BoundedVec<PlayerIndex, T::MaxRounds> =
 			BoundedVec::try_from(vec![0u32; rounds as usize])
 				.expect("rounds <= MaxRounds by Linear bound");
-		PlayerToIndex::<T>::insert(&account_or_person, indices);
+		PlayerToIndex::<T>::insert(0, &account_or_person, indices);
 		for round in 0..rounds {
-			IndexToPlayer::<T>::insert((round, 0u32), &account_or_person);
+			IndexToPlayer::<T>::insert(0, (round, 0u32), &account_or_person);
 		}
-		assert!(PlayerToIndex::<T>::contains_key(&account_or_person));
+		assert!(PlayerToIndex::<T>::contains_key(0, &account_or_person));
 
 		#[block]
 		{
-			assert!(!pallet::Pallet::<T>::process_cancelling_step(&mut None, rounds));
+			assert!(!pallet::Pallet::<T>::process_cancelling_step(0, &mut None, rounds));
 		}
 
 		// All the player's info is reset
@@ -1226,14 +1411,14 @@ mod benches {
 
 		// All player's indices were removed
 		assert!(
-			!PlayerToIndex::<T>::contains_key(&account_or_person),
+			!PlayerToIndex::<T>::contains_key(0, &account_or_person),
 			"PlayerToIndex should not contain player "
 		);
 
 		// IndexToPlayer mappings were removed for all rounds
 		for round in 0..rounds {
 			assert!(
-				IndexToPlayer::<T>::get((round, 0)).is_none(),
+				IndexToPlayer::<T>::get(0, (round, 0)).is_none(),
 				"IndexToPlayer should not contain mapping for round {round}",
 			);
 		}
@@ -1254,14 +1439,18 @@ mod benches {
 			rounds: T::MaxRounds::get() as u8,
 			max_group_size: T::MaxGroupSize::get(),
 			airdrop_prize: Some(bench_airdrop_prize::<T>()),
+			attendance_rule: AttendanceRuleKind::StrictMajority,
+			region: 0,
+			group_assignment: GroupAssignment::Shuffled,
+			phases: None,
 		};
 		assert_ok!(pallet::Pallet::<T>::new_game(&schedule));
-		let game_index = Game::<T>::get().expect("game exists").index;
+		let game_index = Game::<T>::get(0).expect("game exists").index;
 
 		let (caller, vrf) = bench_account_vrf::<T>(game_index);
 
 		#[extrinsic_call]
-		_(Origin::Invited(caller.clone()), DEFAULT_IDENTIFIER_KEY, Some(vrf));
+		_(Origin::Invited(caller.clone()), 0, DEFAULT_IDENTIFIER_KEY, Some(vrf));
 
 		// The caller becomes a registered player
 		let account_or_person: AccountOrPerson<T::AccountId> = AccountOrPerson::Account(caller);
@@ -1286,9 +1475,13 @@ mod benches {
 			rounds: T::MaxRounds::get() as u8,
 			max_group_size: T::MaxGroupSize::get(),
 			airdrop_prize: Some(bench_airdrop_prize::<T>()),
+			attendance_rule: AttendanceRuleKind::StrictMajority,
+			region: 0,
+			group_assignment: GroupAssignment::Shuffled,
+			phases: None,
 		};
 		assert_ok!(pallet::Pallet::<T>::new_game(&schedule));
-		let game_index = Game::<T>::get().expect("game exists").index;
+		let game_index = Game::<T>::get(0).expect("game exists").index;
 
 		let (caller, vrf) = bench_account_vrf::<T>(game_index);
 
@@ -1296,7 +1489,7 @@ mod benches {
 		T::PlayDeposit::ensure_successful(&caller, pallet::PlayDepositAmount::<T>::get());
 
 		#[extrinsic_call]
-		sign_up_with_account(RawOrigin::Signed(caller.clone()), DEFAULT_IDENTIFIER_KEY, Some(vrf));
+		sign_up_with_account(RawOrigin::Signed(caller.clone()), 0, DEFAULT_IDENTIFIER_KEY, Some(vrf));
 
 		// New player + onboarding ran (caller wasn't in `Participants` beforehand).
 		let aop: AccountOrPerson<T::AccountId> = AccountOrPerson::Account(caller);
@@ -1319,9 +1512,13 @@ mod benches {
 			rounds: T::MaxRounds::get() as u8,
 			max_group_size: T::MaxGroupSize::get(),
 			airdrop_prize: Some(bench_airdrop_prize::<T>()),
+			attendance_rule: AttendanceRuleKind::StrictMajority,
+			region: 0,
+			group_assignment: GroupAssignment::Shuffled,
+			phases: None,
 		};
 		assert_ok!(pallet::Pallet::<T>::new_game(&schedule));
-		let game_index = Game::<T>::get().expect("game exists").index;
+		let game_index = Game::<T>::get(0).expect("game exists").index;
 
 		let caller: T::AccountId = whitelisted_caller();
 		let aop = AccountOrPerson::Account(caller.clone());
@@ -1351,6 +1548,7 @@ mod benches {
 			&aop,
 			Player {
 				first_game: 0,
+				region: 0,
 				registered: false,
 				sent_report: false,
 				early_attendance_enactment: None,
@@ -1367,7 +1565,7 @@ mod benches {
 		let vrf = bench_alias_vrf::<T>(game_index, &participant_origin);
 
 		#[extrinsic_call]
-		sign_up_with_account(RawOrigin::Signed(caller.clone()), DEFAULT_IDENTIFIER_KEY, Some(vrf));
+		sign_up_with_account(RawOrigin::Signed(caller.clone()), 0, DEFAULT_IDENTIFIER_KEY, Some(vrf));
 
 		let player = <Players<T>>::get(&aop).expect("existing player record");
 		assert!(player.registered, "existing player marked registered for this game");
@@ -1385,9 +1583,13 @@ mod benches {
 			rounds: T::MaxRounds::get() as u8,
 			max_group_size: T::MaxGroupSize::get(),
 			airdrop_prize: Some(bench_airdrop_prize::<T>()),
+			attendance_rule: AttendanceRuleKind::StrictMajority,
+			region: 0,
+			group_assignment: GroupAssignment::Shuffled,
+			phases: None,
 		};
 		Pallet::<T>::new_game(&game_schedule)?;
-		let game_index = Game::<T>::get().expect("game exists").index;
+		let game_index = Game::<T>::get(0).expect("game exists").index;
 
 		let seed = 1u64; // to match the alias in try_successful_origin
 		let person: Alias = [seed as u8; 32];
@@ -1416,6 +1618,7 @@ mod benches {
 		#[extrinsic_call]
 		_(
 			origin as T::RuntimeOrigin,
+			0,
 			DEFAULT_IDENTIFIER_KEY,
 			statement_account.clone(),
 			signature,
@@ -1441,6 +1644,7 @@ mod benches {
 
 	#[benchmark]
 	fn report(p: Linear<0, { Pallet::<T>::max_enactments() }>) -> Result<(), BenchmarkError> {
+		bench_setup_signal_weights::<T>();
 		<T as Config>::BenchmarkHelper::set_valid_time();
 		bench_setup_airdrop_funds::<T>();
 
@@ -1450,6 +1654,10 @@ mod benches {
 			rounds: T::MaxRounds::get() as u8,
 			max_group_size: T::MaxGroupSize::get(),
 			airdrop_prize: Some(bench_airdrop_prize::<T>()),
+			attendance_rule: AttendanceRuleKind::StrictMajority,
+			region: 0,
+			group_assignment: GroupAssignment::Shuffled,
+			phases: None,
 		};
 		assert_ok!(Pallet::<T>::new_game(&game_schedule));
 
@@ -1470,6 +1678,7 @@ mod benches {
 		for player in &players {
 			let result = Pallet::<T>::sign_up_with_account(
 				RawOrigin::Signed(player.clone()).into(),
+				0,
 				DEFAULT_IDENTIFIER_KEY,
 				None,
 			);
@@ -1483,9 +1692,9 @@ mod benches {
 
 		let shuffle_time = GameTimes::<T>::registration_end(&game_schedule);
 		<T as Config>::BenchmarkHelper::set_time(Duration::from_secs(shuffle_time.into()));
-		let game = Game::<T>::get().expect("Game should exist");
+		let game = Game::<T>::get(0).expect("Game should exist");
 		Pallet::<T>::process_game(&mut WeightMeter::new(), 1u32.into(), game);
-		let game = Game::<T>::get().expect("Game should exist");
+		let game = Game::<T>::get(0).expect("Game should exist");
 		Pallet::<T>::process_game(&mut WeightMeter::new(), 1u32.into(), game);
 
 		let game_time = GameTimes::<T>::game_play_time(&game_schedule);
@@ -1504,7 +1713,7 @@ mod benches {
 		let caller_aop = AccountOrPerson::Account(caller.clone());
 		let caller_indices: BoundedVec<PlayerIndex, T::MaxRounds> =
 			vec![0u32; max_rounds as usize].try_into().expect("fits MaxRounds");
-		PlayerToIndex::<T>::insert(&caller_aop, caller_indices);
+		PlayerToIndex::<T>::insert(0, &caller_aop, caller_indices);
 
 		// Group 0 slots other than the caller's slot 0.
 		let group0_other_slots: Vec<u32> = (1..max_group_size).map(|k| k * num_groups).collect();
@@ -1512,7 +1721,7 @@ mod benches {
 			for (k, &slot) in group0_other_slots.iter().enumerate() {
 				let player_idx = 1 + (round as usize) * (max_group_size as usize - 1) + k;
 				let coplayer = AccountOrPerson::Account(players[player_idx].clone());
-				IndexToPlayer::<T>::insert((round, slot), &coplayer);
+				IndexToPlayer::<T>::insert(0, (round, slot), &coplayer);
 			}
 		}
 
@@ -1576,7 +1785,7 @@ mod benches {
 	#[benchmark]
 	fn offboard_account() -> Result<(), BenchmarkError> {
 		// A game does not exist
-		assert!(Game::<T>::get().is_none());
+		assert!(Game::<T>::get(0).is_none());
 
 		// The caller is stored as a player
 		let caller: T::AccountId = whitelisted_caller();
@@ -1596,6 +1805,7 @@ mod benches {
 		let deposit = T::PlayDeposit::new(&caller, pallet::PlayDepositAmount::<T>::get())?;
 		let player: Player<<T as Config>::PlayDeposit> = Player {
 			first_game: 0,
+			region: 0,
 			registered: false,
 			sent_report: false,
 			early_attendance_enactment: None,
@@ -1629,7 +1839,7 @@ mod benches {
 	#[benchmark]
 	fn offboard_person() -> Result<(), BenchmarkError> {
 		// A game does not exist.
-		assert!(Game::<T>::get().is_none());
+		assert!(Game::<T>::get(0).is_none());
 
 		let seed = 1u64; // to match the alias in try_successful_origin
 		let person: Alias = [seed as u8; 32];
@@ -1648,6 +1858,7 @@ mod benches {
 
 		let player: Player<<T as Config>::PlayDeposit> = Player {
 			first_game: 0,
+			region: 0,
 			registered: false,
 			sent_report: false,
 			early_attendance_enactment: Some(EarlyAttendanceEnactment {
@@ -1667,18 +1878,25 @@ mod benches {
 			ArchivedPlayer::Kickable { first_game: 0, archived_since: 0u32.into() },
 		);
 
-		Game::<T>::put(GameInfo {
-			index: 0,
-			registration_ends: 0,
-			shuffle_deadline: 1,
-			game_date: 0,
-			report_ends: 0,
-			state: GameState::Registration { next_player_index: 0 },
-			max_group_size: T::MaxGroupSize::get(),
-			rounds: T::MaxRounds::get() as u8,
-			pending_attendance: 0,
-			airdrop_scheduled: false,
-		});
+		Game::<T>::insert(
+			0,
+			GameInfo {
+				index: 0,
+				registration_ends: 0,
+				shuffle_deadline: 1,
+				game_date: 0,
+				report_ends: 0,
+				state: GameState::Registration { next_player_index: 0 },
+				max_group_size: T::MaxGroupSize::get(),
+				rounds: T::MaxRounds::get() as u8,
+				pending_attendance: 0,
+				airdrop_scheduled: false,
+				attendance_rule: AttendanceRuleKind::StrictMajority,
+				region: 0,
+				group_assignment: GroupAssignment::Shuffled,
+				phases: <T as Config>::DefaultPhaseDurations::get(),
+			},
+		);
 
 		// Seed attendance history at max depth so the removal proof is worst case.
 		let mut attendance = PlayerAttendanceHistory::<T>::get(&person_aop);
@@ -1860,6 +2078,10 @@ mod benches {
 			rounds: T::MaxRounds::get() as u8,
 			max_group_size: T::MaxGroupSize::get(),
 			airdrop_prize: Some(bench_airdrop_prize::<T>()),
+			attendance_rule: AttendanceRuleKind::StrictMajority,
+			region: 0,
+			group_assignment: GroupAssignment::Shuffled,
+			phases: None,
 		};
 		assert_ok!(pallet::Pallet::<T>::new_game(&game_schedule));
 
@@ -1874,6 +2096,10 @@ mod benches {
 				rounds: T::MaxRounds::get() as u8,
 				max_group_size: T::MaxGroupSize::get(),
 				airdrop_prize: Some(bench_airdrop_prize::<T>()),
+				attendance_rule: AttendanceRuleKind::StrictMajority,
+				region: 0,
+				group_assignment: GroupAssignment::Shuffled,
+				phases: None,
 			};
 			prev_game_end = GameTimes::<T>::player_process_end(&schedule);
 
@@ -1906,6 +2132,10 @@ mod benches {
 				rounds: T::MaxRounds::get() as u8,
 				max_group_size: T::MaxGroupSize::get(),
 				airdrop_prize: Some(bench_airdrop_prize::<T>()),
+				attendance_rule: AttendanceRuleKind::StrictMajority,
+				region: 0,
+				group_assignment: GroupAssignment::Shuffled,
+				phases: None,
 			};
 			prev_game_end = GameTimes::<T>::player_process_end(&schedule);
 
@@ -1916,7 +2146,7 @@ mod benches {
 		GameSchedules::<T>::put(BoundedVec::try_from(games_schedules).unwrap());
 
 		#[extrinsic_call]
-		_(RawOrigin::Root, first_game_time);
+		_(RawOrigin::Root, 0, first_game_time);
 
 		// The first schedule is gone.
 		let remaining = GameSchedules::<T>::get();
@@ -1929,6 +2159,108 @@ mod benches {
 		Ok(())
 	}
 
+	/// A weekly schedule rule of region 0, playing its next game at `game_play_time`.
+	fn bench_schedule_rule<T: Config>(game_play_time: u32) -> ScheduleRuleOf<T> {
+		ScheduleRuleOf::<T> {
+			next: GameScheduleOf::<T> {
+				game_play_time,
+				rounds: T::MaxRounds::get() as u8,
+				max_group_size: T::MaxGroupSize::get(),
+				airdrop_prize: Some(bench_airdrop_prize::<T>()),
+				attendance_rule: AttendanceRuleKind::StrictMajority,
+				region: 0,
+				group_assignment: GroupAssignment::Shuffled,
+				phases: Some(<T as Config>::DefaultPhaseDurations::get()),
+			},
+			period: 7 * 24 * 3600,
+			until: u32::MAX,
+			paused: false,
+		}
+	}
+
+	#[benchmark]
+	fn add_schedule_rule() -> Result<(), BenchmarkError> {
+		let rule = bench_schedule_rule::<T>(10_000);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, rule.clone());
+
+		assert_eq!(ScheduleRules::<T>::get(0), Some(rule));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn amend_schedule_rule() -> Result<(), BenchmarkError> {
+		ScheduleRules::<T>::insert(0, bench_schedule_rule::<T>(10_000));
+		let mut rule = bench_schedule_rule::<T>(20_000);
+		rule.paused = true;
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, 0, rule.clone());
+
+		assert_eq!(ScheduleRules::<T>::get(0), Some(rule));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_schedule_rule() -> Result<(), BenchmarkError> {
+		ScheduleRules::<T>::insert(0, bench_schedule_rule::<T>(10_000));
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, 0);
+
+		assert!(ScheduleRules::<T>::get(0).is_none());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn schedule_rule_game() -> Result<(), BenchmarkError> {
+		<T as Config>::BenchmarkHelper::set_valid_time();
+		bench_setup_airdrop_funds::<T>();
+
+		// All schedules but one are used, by games of another region so the ongoing game of the
+		// region of the rule is read.
+		let max_schedules = T::MaxGameSchedules::get();
+		let mut games_schedules = Vec::new();
+		let mut game_play_time = 2000u32;
+		for _ in 1..max_schedules {
+			let schedule = GameScheduleOf::<T> {
+				game_play_time,
+				rounds: T::MaxRounds::get() as u8,
+				max_group_size: T::MaxGroupSize::get(),
+				airdrop_prize: Some(bench_airdrop_prize::<T>()),
+				attendance_rule: AttendanceRuleKind::StrictMajority,
+				region: 1,
+				group_assignment: GroupAssignment::Shuffled,
+				phases: None,
+			};
+			game_play_time = GameTimes::<T>::player_process_end(&schedule) + 1000;
+			games_schedules.push(schedule);
+		}
+		GameSchedules::<T>::put(BoundedVec::try_from(games_schedules).unwrap());
+
+		let rule = bench_schedule_rule::<T>(game_play_time);
+		let registration_start = GameTimes::<T>::registration_start(&rule.next);
+		ScheduleRules::<T>::insert(0, rule);
+		<T as Config>::BenchmarkHelper::set_time(core::time::Duration::from_secs(
+			(registration_start - 1).into(),
+		));
+
+		#[block]
+		{
+			pallet::Pallet::<T>::schedule_rule_games(&mut WeightMeter::new());
+		}
+
+		let schedules = GameSchedules::<T>::get();
+		assert_eq!(schedules.len() as u32, max_schedules);
+		assert_eq!(schedules.last().map(|schedule| schedule.game_play_time), Some(game_play_time));
+
+		Ok(())
+	}
+
 	#[benchmark]
 	fn set_play_deposit() -> Result<(), BenchmarkError> {
 		let amount: NativeBalanceOf<T> = One::one();
@@ -1952,9 +2284,13 @@ mod benches {
 			rounds: T::MaxRounds::get() as u8,
 			max_group_size: T::MaxGroupSize::get(),
 			airdrop_prize: Some(bench_airdrop_prize::<T>()),
+			attendance_rule: AttendanceRuleKind::StrictMajority,
+			region: 0,
+			group_assignment: GroupAssignment::Shuffled,
+			phases: None,
 		};
 		assert_ok!(pallet::Pallet::<T>::new_game(&schedule));
-		let game_index = Game::<T>::get().expect("game exists").index;
+		let game_index = Game::<T>::get(0).expect("game exists").index;
 
 		// A spare invitation ticket exists
 		let inviter: T::AccountId = account("inviter", 0, 0);
@@ -1967,6 +2303,7 @@ mod benches {
 
 		// The call is `sign_up_with_invite` with a valid airdrop VRF.
 		let call: <T as frame_system::Config>::RuntimeCall = Call::sign_up_with_invite {
+			region: 0,
 			identifier_key: DEFAULT_IDENTIFIER_KEY,
 			airdrop: Some(vrf),
 		}
@@ -2010,28 +2347,35 @@ mod benches {
 
 		// Put a game in the Reporting phase whose reporting window has already ended so
 		// `process_reporting` transitions the state to `PlayerProcess`.
-		Game::<T>::put(GameInfo {
-			index: 0,
-			registration_ends: 0,
-			shuffle_deadline: 0,
-			game_date: 0,
-			report_ends: 0, // now (1s) >= report_ends (0s) so reporting can close
-			state: GameState::Reporting { player_count: 0 },
-			max_group_size: T::MaxGroupSize::get(),
-			rounds: T::MaxRounds::get() as u8,
-			pending_attendance: 0,
-			airdrop_scheduled: false,
-		});
+		Game::<T>::insert(
+			0,
+			GameInfo {
+				index: 0,
+				registration_ends: 0,
+				shuffle_deadline: 0,
+				game_date: 0,
+				report_ends: 0, // now (1s) >= report_ends (0s) so reporting can close
+				state: GameState::Reporting { player_count: 0 },
+				max_group_size: T::MaxGroupSize::get(),
+				rounds: T::MaxRounds::get() as u8,
+				pending_attendance: 0,
+				airdrop_scheduled: false,
+				attendance_rule: AttendanceRuleKind::StrictMajority,
+				region: 0,
+				group_assignment: GroupAssignment::Shuffled,
+				phases: <T as Config>::DefaultPhaseDurations::get(),
+			},
+		);
 
 		let mut meter = WeightMeter::new();
 
 		#[block]
 		{
-			pallet::Pallet::<T>::process_reporting(&mut meter);
+			pallet::Pallet::<T>::process_reporting(&mut meter, 0);
 		}
 
 		// The game should have transitioned to PlayerProcess.
-		let game = Game::<T>::get().expect("game should exist after process_reporting");
+		let game = Game::<T>::get(0).expect("game should exist after process_reporting");
 		assert!(matches!(game.state, GameState::PlayerProcess { .. }));
 
 		Ok(())
@@ -2078,16 +2422,20 @@ mod benches {
 			rounds: T::MaxRounds::get() as u8,
 			max_group_size: T::MaxGroupSize::get(),
 			airdrop_prize: Some(bench_airdrop_prize::<T>()),
+			attendance_rule: AttendanceRuleKind::StrictMajority,
+			region: 0,
+			group_assignment: GroupAssignment::Shuffled,
+			phases: None,
 		};
 		assert_ok!(pallet::Pallet::<T>::new_game(&schedule));
-		assert!(Game::<T>::get().is_some());
+		assert!(Game::<T>::get(0).is_some());
 
 		// The caller is of ManagerOrigin, so Root should work in all cases.
 		#[extrinsic_call]
-		_(RawOrigin::Root);
+		_(RawOrigin::Root, 0);
 
 		// The current game is gone.
-		assert!(Game::<T>::get().is_none());
+		assert!(Game::<T>::get(0).is_none());
 
 		Ok(())
 	}
@@ -2104,10 +2452,14 @@ mod benches {
 			rounds: T::MaxRounds::get() as u8,
 			max_group_size: T::MaxGroupSize::get(),
 			airdrop_prize: Some(bench_airdrop_prize::<T>()),
+			attendance_rule: AttendanceRuleKind::StrictMajority,
+			region: 0,
+			group_assignment: GroupAssignment::Shuffled,
+			phases: None,
 		};
 		assert_ok!(pallet::Pallet::<T>::new_game(&schedule));
 		assert!(matches!(
-			Game::<T>::get().expect("game exists").state,
+			Game::<T>::get(0).expect("game exists").state,
 			GameState::Registration { .. },
 		));
 
@@ -2137,9 +2489,13 @@ mod benches {
 			rounds: T::MaxRounds::get() as u8,
 			max_group_size: T::MaxGroupSize::get(),
 			airdrop_prize: Some(bench_airdrop_prize::<T>()),
+			attendance_rule: AttendanceRuleKind::StrictMajority,
+			region: 0,
+			group_assignment: GroupAssignment::Shuffled,
+			phases: None,
 		};
 		assert_ok!(pallet::Pallet::<T>::new_game(&schedule));
-		let game = Game::<T>::get().expect("game exists after new_game");
+		let game = Game::<T>::get(0).expect("game exists after new_game");
 
 		let event_id = pallet::Pallet::<T>::airdrop_event_id(game.index);
 
@@ -2176,9 +2532,13 @@ mod benches {
 			rounds: T::MaxRounds::get() as u8,
 			max_group_size: T::MaxGroupSize::get(),
 			airdrop_prize: Some(bench_airdrop_prize::<T>()),
+			attendance_rule: AttendanceRuleKind::StrictMajority,
+			region: 0,
+			group_assignment: GroupAssignment::Shuffled,
+			phases: None,
 		};
 		assert_ok!(pallet::Pallet::<T>::new_game(&schedule));
-		let game = Game::<T>::get().expect("game exists after new_game");
+		let game = Game::<T>::get(0).expect("game exists after new_game");
 
 		// Transition the airdrop event to `Claiming` so `do_claim` accepts the call, and
 		// place time inside the claim window.
@@ -2237,6 +2597,219 @@ mod benches {
 		Ok(())
 	}
 
+	// Base cost: load the game, observe that there are no reports left to score, and
+	// transition to Step1.
+	#[benchmark]
+	fn player_process_step0() -> Result<(), BenchmarkError> {
+		Game::<T>::insert(
+			0,
+			GameInfo {
+				index: 0,
+				registration_ends: 0,
+				shuffle_deadline: 0,
+				game_date: 0,
+				report_ends: 0,
+				state: GameState::PlayerProcess {
+					step: PlayerProcessStep::Step0ScoreReports { player_count: 0 },
+				},
+				max_group_size: T::MaxGroupSize::get(),
+				rounds: T::MaxRounds::get() as u8,
+				pending_attendance: 0,
+				airdrop_scheduled: false,
+				attendance_rule: AttendanceRuleKind::StrictMajority,
+				region: 0,
+				group_assignment: GroupAssignment::Shuffled,
+				phases: <T as Config>::DefaultPhaseDurations::get(),
+			},
+		);
+
+		let mut meter = WeightMeter::new();
+
+		#[block]
+		{
+			pallet::Pallet::<T>::player_process_step0(&mut meter, 0);
+		}
+
+		assert!(matches!(
+			Game::<T>::get(0).unwrap().state,
+			GameState::PlayerProcess {
+				step: PlayerProcessStep::Step1ProcessPlayers {
+					last_iteration: None,
+					player_count: 0
+				},
+			},
+		));
+
+		Ok(())
+	}
+
+	// Per-reporter worst case: a full group in each of the `r` rounds, every report disagrees
+	// with the outcome, and the reporter reaches the inconsistent streak that is penalized.
+	#[benchmark]
+	fn player_process_step0_reporter(
+		r: Linear<1, { T::MaxRounds::get() }>,
+	) -> Result<(), BenchmarkError> {
+		bench_setup_signal_weights::<T>();
+		let max_group_size = T::MaxGroupSize::get();
+		let rounds = r as u8;
+		let player_count = max_group_size;
+
+		let game = GameInfo {
+			index: 0,
+			registration_ends: 0,
+			shuffle_deadline: 0,
+			game_date: 0,
+			report_ends: 0,
+			state: GameState::PlayerProcess {
+				step: PlayerProcessStep::Step0ScoreReports { player_count },
+			},
+			max_group_size,
+			rounds,
+			pending_attendance: player_count,
+			airdrop_scheduled: false,
+			attendance_rule: AttendanceRuleKind::StrictMajority,
+			region: 0,
+			group_assignment: GroupAssignment::Shuffled,
+			phases: <T as Config>::DefaultPhaseDurations::get(),
+		};
+
+		for i in 0..player_count {
+			let player = AccountOrPerson::Account(account::<T::AccountId>("player", i, 0));
+			Players::<T>::insert(
+				&player,
+				Player {
+					first_game: 0,
+					region: 0,
+					registered: true,
+					sent_report: true,
+					early_attendance_enactment: None,
+					yes_person: max_group_size as u8 - 1,
+					no_not_person: 0,
+					expected_max_vote_weight: max_group_size as u16 - 1,
+					vote_weight: T::CandidateVoteWeight::get(),
+					credibility: PlayerCredibility::Recognized,
+				},
+			);
+			let indices =
+				BoundedVec::try_from(vec![i; rounds as usize]).expect("rounds within bound");
+			PlayerToIndex::<T>::insert(0, &player, indices);
+			for round in 0..rounds {
+				IndexToPlayer::<T>::insert(0, (round, i), &player);
+			}
+		}
+
+		let reporter_account: T::AccountId = account("player", 0, 0);
+		indiv_pallet_score::Pallet::<T>::onboard_for_recognition(&reporter_account)?;
+		let reporter = AccountOrPerson::Account(reporter_account);
+
+		let round_report: BoundedVec<Report, T::MaxGroupSize> =
+			vec![Report::NotPerson; max_group_size as usize - 1]
+				.try_into()
+				.expect("group size within bound");
+		let full_report: FullReport<T> =
+			vec![round_report; rounds as usize].try_into().expect("rounds within bound");
+		SubmittedReports::<T>::insert(0, &reporter, full_report);
+		let streak = T::MaxInconsistentReportStreak::get().saturating_sub(1);
+		InconsistentReportStreak::<T>::insert(&reporter, streak);
+
+		#[block]
+		{
+			let (reporter, full_report) =
+				SubmittedReports::<T>::drain_prefix(0).next().expect("a report was submitted");
+			pallet::Pallet::<T>::score_report_consistency(
+				&game,
+				player_count,
+				&reporter,
+				&full_report,
+			);
+		}
+
+		assert_eq!(InconsistentReportStreak::<T>::get(&reporter), streak + 1);
+		assert!(SubmittedReports::<T>::iter().next().is_none());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn dispute_attendance() -> Result<(), BenchmarkError> {
+		assert!(Game::<T>::get(0).is_none());
+
+		let caller: T::AccountId = whitelisted_caller();
+		indiv_pallet_score::Pallet::<T>::onboard_for_recognition(&caller)?;
+		T::AttendanceDisputeDeposit::ensure_successful(
+			&caller,
+			T::AttendanceDisputeDepositAmount::get(),
+		);
+		let player = AccountOrPerson::Account(caller.clone());
+
+		let groups = BoundedVec::try_from(vec![0; T::MaxRounds::get() as usize])
+			.expect("rounds within bound");
+		let dispute_until = frame_system::Pallet::<T>::block_number()
+			.saturating_add(T::AttendanceDisputeWindow::get());
+		AttendanceDisputes::<T>::insert(
+			&player,
+			AttendanceDispute {
+				game_index: 0,
+				region: 0,
+				groups,
+				dispute_until,
+				case: None,
+				deposit: None,
+			},
+		);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller));
+
+		assert!(AttendanceDisputes::<T>::get(&player).is_some_and(|d| d.case.is_some()));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn attendance_judged() -> Result<(), BenchmarkError> {
+		bench_setup_signal_weights::<T>();
+		let caller: T::AccountId = account("player", 0, 0);
+		indiv_pallet_score::Pallet::<T>::onboard_for_recognition(&caller)?;
+		let amount = T::AttendanceDisputeDepositAmount::get();
+		T::AttendanceDisputeDeposit::ensure_successful(&caller, amount);
+		let deposit = T::AttendanceDisputeDeposit::new(&caller, amount)?;
+		let player = AccountOrPerson::Account(caller);
+		indiv_pallet_score::Participants::<T>::mutate(&player, |maybe_participant| {
+			let participant = maybe_participant.as_mut().expect("participant was onboarded");
+			participant.score = 1;
+			participant.streak = indiv_pallet_score::Streak::Absent(1);
+		});
+
+		let groups = BoundedVec::try_from(vec![0; T::MaxRounds::get() as usize])
+			.expect("rounds within bound");
+		AttendanceDisputes::<T>::insert(
+			&player,
+			AttendanceDispute {
+				game_index: 0,
+				region: 0,
+				groups,
+				dispute_until: frame_system::Pallet::<T>::block_number(),
+				case: Some(Default::default()),
+				deposit: Some(deposit),
+			},
+		);
+		let context = JudgementContext::truncate_from(player.encode());
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, Default::default(), context, Judgement::Truth(Truth::True));
+
+		assert!(!AttendanceDisputes::<T>::contains_key(&player));
+		assert_eq!(
+			indiv_pallet_score::Participants::<T>::get(&player)
+				.expect("participant was onboarded")
+				.last_attended_game,
+			Some(0)
+		);
+
+		Ok(())
+	}
+
 	// Implements a test for each benchmark. Execute with:
 	// `cargo test -p pallet-people --features runtime-benchmarks`.
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
@@ -159,7 +159,7 @@ impl<T: Config + Send + Sync> TransactionExtension<RuntimeCallOf<T>> for GameAsI
 				};
 
 				// Call must be a `sign_up_with_invite` call.
-				let Some(Call::sign_up_with_invite { airdrop: call_airdrop, .. }) =
+				let Some(Call::sign_up_with_invite { region, airdrop: call_airdrop, .. }) =
 					call.is_sub_type()
 				else {
 					return Err(InvalidTransaction::Custom(CallNotSignUpWithInvite as u8).into());
@@ -174,7 +174,8 @@ impl<T: Config + Send + Sync> TransactionExtension<RuntimeCallOf<T>> for GameAsI
 				);
 
 				// Game must be in registration phase.
-				let game = Game::<T>::get().ok_or(InvalidTransaction::Custom(NoGame as u8))?;
+				let game =
+					Game::<T>::get(region).ok_or(InvalidTransaction::Custom(NoGame as u8))?;
 				ensure!(
 					T::UnixTime::now() < Duration::from_secs(game.registration_ends as u64),
 					InvalidTransaction::Custom(GameRegistrationEnded as u8)
@@ -20,11 +20,12 @@
 //! other’s personhood. A typical flow for each game looks like this:
 //!
 //! 1. **Scheduling**: A privileged origin calls `schedule_games` with settings for multiple games,
-//!    including their `game_play_time`.
+//!    including their `game_play_time`. Alternatively, it adds a rule scheduling games
+//!    periodically with `add_schedule_rule`, see [Schedule rules](#schedule-rules).
 //!
-//! 2. **New Game**: if the previous game has finished result processing and there is a game
-//!    scheduled then `on_poll`/`on_idle` will start the new game in the registration phase. The new
-//!    game is stored in [`Game`].
+//! 2. **New Game**: if the previous game of a region has finished result processing and there is a
+//!    game scheduled in this region then `on_poll` will start the new game in the registration
+//!    phase. The new game is stored in [`Game`] under its region.
 //!
 //! 3. **Registration**: Players call [`Pallet::sign_up_with_account`],
 //!    [`Pallet::sign_up_with_invite`] or [`Pallet::sign_up_with_alias`] to be part of the game. A
@@ -51,6 +52,30 @@
 //!      [`indiv_pallet_score`]. Otherwise, they are marked absent.
 //!    - At the end of processing, the game concludes and is removed from storage.
 //!
+//! # Regions
+//!
+//! Each [`GameSchedule`] targets one of the [`Config::MaxRegions`] regions. A region runs at most
+//! one game at a time, but games of different regions run concurrently, so each region can play at
+//! a time suited to its players. The games of different regions have their own shuffle, reporting
+//! and player processing, and their own unique game index.
+//!
+//! A player signs up for the game of one region, and cannot sign up for another game while
+//! registered. The region of the last game a player signed up for is recorded in [`Player`]: only
+//! the games of this region process the player, so the attendance of a player is reported to
+//! [`indiv_pallet_score`] exactly once for each game they are processed by.
+//!
+//! # Schedule rules
+//!
+//! A [`ScheduleRule`] plays a game every `period` from its `next` game until `until`, e.g. every
+//! Saturday at 14:00 UTC, with the settings and the optional phase durations of `next`. Once the
+//! registration of the next game of a rule starts within [`Config::ScheduleRuleHorizon`],
+//! `on_poll` adds the game to [`GameSchedules`] and moves the rule to its following game. A game
+//! which is outdated or overlaps another game of its region is skipped.
+//!
+//! Rules can be paused, amended and removed with `amend_schedule_rule` and
+//! `remove_schedule_rule`. The games already added to [`GameSchedules`] are unchanged, and are
+//! removed with `remove_scheduled_game`.
+//!
 //! # player index, groups, rounds and report.
 //!
 //! After the shuffle phase, each player obtained a unique index for each round. This index is used
@@ -65,6 +90,13 @@
 //! or equivalently:
 //! {group_index+i⋅number_of_group∣i∈{0,1,…,max_per_group−1}}∩{0,1,…,number_of_player−1}
 //! ```
+//! The indices of a round are given by a shuffle of the players from the chain randomness. With
+//! [`GroupAssignment::SpreadPairings`], the indices are then walked in order, and each player
+//! grouped with players they recently played with, in the previous rounds or their previous
+//! games, is swapped with the player of the next index when it reduces the repeated pairings of
+//! both. The recent co-players of a player are bounded by [`Config::MaxRecentCoPlayers`]. The
+//! group of a player is still derived from their index, so validating a report stays cheap.
+//!
 //! After participating in the game in the given group in each round, the player will report on
 //! other players in the group. The report is a list of round reports, one for each round. Each
 //! round report is a list of reports for all other players in the group, ordered by player index.
@@ -74,11 +106,44 @@
 //! A participant is considered attending if:
 //! * they signed up for the game
 //! * they sent a report
-//! * strictly more than half of the voter reported them as a person, or the total number of report
-//!   is 0.
+//! * the reports of the other players satisfy the attendance rule of the game, or the total number
+//!   of report is 0.
 //!
 //! Otherwise the participant is considered absent.
 //!
+//! Each game uses the [`AttendanceRuleKind`] selected in its [`GameSchedule`], interpreted by
+//! [`Config::AttendanceRule`]:
+//! * [`AttendanceRuleKind::StrictMajority`]: strictly more than half of the vote weight reported
+//!   them as a person. This is the default.
+//! * [`AttendanceRuleKind::Supermajority`]: at least the given threshold of the vote weight
+//!   reported them as a person.
+//! * [`AttendanceRuleKind::ScoreWeighted`]: strictly more than half of the vote weight reported
+//!   them as a person, with the votes of candidates weighted by their score.
+//!
+//! # Report consistency
+//!
+//! At the start of the player-process phase, the report of each player is compared with the
+//! outcome of the players they reported on: a report agrees when it says `Person` for a player
+//! the attendance rule considers a person, or `NotPerson` otherwise. The share of agreeing
+//! reports is emitted in [`Event::ReportConsistencyScored`]. Players with less than
+//! [`Config::MinReportConsistency`] agreeing reports extend their [`InconsistentReportStreak`].
+//! Once the streak reaches [`Config::MaxInconsistentReportStreak`], they lose
+//! [`Config::InconsistentReportPenalty`] score for each inconsistent game.
+//!
+//! # Attendance disputes
+//!
+//! A player found absent after being reported as `NotPerson`, and who remains a player, can
+//! dispute their absence with `dispute_attendance` once the game ended, within
+//! [`Config::AttendanceDisputeWindow`] blocks of the absence. The dispute opens a case with
+//! [`Config::Oracle`] for the game index and the groups of the player. If the oracle finds in
+//! favour of the player, their attendance is restored in [`indiv_pallet_score`].
+//!
+//! Account players place [`Config::AttendanceDisputeDeposit`] when disputing, burnt unless the
+//! oracle finds in their favour.
+//!
+//! Only the last game of a player can be disputed. Being processed in a later game ends the window
+//! early: the dispute lapses, even if a case is open, and its deposit is released.
+//!
 //! # Deposit/Credibility/Invitation/Archival
 //!
 //! New players can sign up for a game by proving an initial credibility.
@@ -128,6 +193,7 @@
 //! - `sign_up_with_alias`: sign up for the game using an alias.
 //! - `report`: Each participant’s self-contained reporting of whether their peers are persons.
 //! - `offboard`: Offboard from the game.
+//! - `dispute_attendance`: Dispute an absence after being reported as not being a person.
 //!
 //! Manager origin calls: (configured origin [`Config::ManagerOrigin`])
 //!
@@ -146,15 +212,18 @@
 //! Other calls:
 //!
 //! - `kickout`: Kick out a player that is not playing. Persons are not kickable.
+//! - `attendance_judged`: Callback of [`Config::Oracle`] with the judgement of a dispute.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![recursion_limit = "128"]
 
 extern crate alloc;
 
+mod attendance;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 mod extension;
+pub mod migration;
 #[cfg(test)]
 mod mock;
 pub mod runtime_api;
@@ -165,6 +234,7 @@ pub mod weights;
 
 #[cfg(feature = "runtime-benchmarks")]
 pub use benchmarking::BenchmarkHelper;
+pub use attendance::*;
 pub use extension::{GameAsInvited, GameAsInvitedData};
 pub use pallet::*;
 pub use types::*;
@@ -186,10 +256,14 @@ use indiv_pallet_airdrop::types::{
 	RegistrationEntry as AirdropRegistrationEntry,
 };
 use indiv_pallet_score::AccountOrPerson;
-use indiv_support::traits::{Alias, CommunicationIdentifier};
+use indiv_support::traits::{
+	Alias, Callback, CommunicationIdentifier, GameGroups, Judgement, JudgementContext,
+	Statement::GameAttendance, StatementOracle, Truth, MAX_GAME_ROUNDS,
+};
 use sp_runtime::{
 	traits::{IdentifyAccount, Verify, Zero},
 	transaction_validity::{InvalidTransaction, TransactionValidityError},
+	PerThing, Percent,
 };
 use sp_statement_store::{
 	decrease_allowance_by, increase_allowance_by, runtime_api::statement_store, StatementAllowance,
@@ -222,6 +296,23 @@ pub mod pallet {
 	pub(crate) type TicketOf<T> =
 		<<<T as Config>::TicketSignature as Verify>::Signer as IdentifyAccount>::AccountId;
 
+	/// Type alias to access the ticket of the cases opened with [`Config::Oracle`].
+	pub type OracleTicketOf<T> = <<T as Config>::Oracle as StatementOracle<
+		<T as frame_system::Config>::RuntimeCall,
+	>>::Ticket;
+
+	/// The disputable absence of a player, see [`AttendanceDisputes`].
+	pub type AttendanceDisputeOf<T> = AttendanceDispute<
+		BlockNumberFor<T>,
+		OracleTicketOf<T>,
+		<T as Config>::AttendanceDisputeDeposit,
+	>;
+
+	type CallbackOf<T> = Callback<
+		(OracleTicketOf<T>, JudgementContext, Judgement),
+		<T as frame_system::Config>::RuntimeCall,
+	>;
+
 	/// Native chain balance, used for the play deposit held on the `Balances` pallet.
 	pub type NativeBalanceOf<T> =
 		<<T as Config>::NativeFungible as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
@@ -232,7 +323,11 @@ pub mod pallet {
 
 	pub(crate) const LOG_TARGET: &str = "runtime::indiv-pallet-game";
 
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
@@ -317,6 +412,29 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxGameSchedules: Get<u32>;
 
+		/// The maximum number of schedule rules the pallet can store.
+		#[pallet::constant]
+		type MaxScheduleRules: Get<u32>;
+
+		/// How long in advance, in seconds, the games of the schedule rules are materialized into
+		/// the game schedules, counted until the start of their registration.
+		#[pallet::constant]
+		type ScheduleRuleHorizon: Get<u32>;
+
+		/// The number of regions games can be scheduled in, regions are numbered from `0`.
+		///
+		/// Each region runs at most one game at a time, games of different regions run
+		/// concurrently.
+		#[pallet::constant]
+		type MaxRegions: Get<u32>;
+
+		/// The maximum number of recent co-players recorded for each player, used by games with
+		/// [`GroupAssignment::SpreadPairings`] to avoid repeated pairings.
+		///
+		/// Should cover a few games, i.e. a few times `MaxRounds * (MaxGroupSize - 1)`.
+		#[pallet::constant]
+		type MaxRecentCoPlayers: Get<u32>;
+
 		/// The maximum number of past games for which player attendance is stored. Any attendance
 		/// entries older than this will be imminently purged from storage.
 		#[pallet::constant]
@@ -343,6 +461,48 @@ pub mod pallet {
 		/// The weight for the vote of candidates not yet recognized.
 		type CandidateVoteWeight: Get<u8>;
 
+		/// The minimum share of the reports of a player that must agree with the outcome of the
+		/// reported players for the player to be a consistent reporter in a game.
+		#[pallet::constant]
+		type MinReportConsistency: Get<Percent>;
+
+		/// The number of consecutive games with inconsistent reports after which a player is
+		/// penalized by [`Self::InconsistentReportPenalty`].
+		#[pallet::constant]
+		type MaxInconsistentReportStreak: Get<u32>;
+
+		/// The score removed in `indiv_pallet_score` from a player for each game with
+		/// inconsistent reports once their streak reached [`Self::MaxInconsistentReportStreak`].
+		///
+		/// Zero disables the penalty, the consistency is still scored and emitted in events.
+		#[pallet::constant]
+		type InconsistentReportPenalty: Get<u32>;
+
+		/// The attendance rules selectable by games, see [`AttendanceRuleKind`].
+		///
+		/// [`DefaultAttendanceRule`] implements all of them.
+		type AttendanceRule: AttendanceRule<Self::AccountId>;
+
+		/// Oracle judging the absences disputed by players reported as not being a person, see
+		/// [`Pallet::dispute_attendance`].
+		type Oracle: StatementOracle<<Self as frame_system::Config>::RuntimeCall>;
+
+		/// The number of blocks after the absence of a player is enacted during which the player
+		/// can dispute it.
+		///
+		/// The window ends early when the player is processed in a later game.
+		#[pallet::constant]
+		type AttendanceDisputeWindow: Get<BlockNumberFor<Self>>;
+
+		/// The deposit held from account players disputing their absence, parameterised by
+		/// [`Config::AttendanceDisputeDepositAmount`]. Burnt unless the oracle finds in favour of
+		/// the player.
+		type AttendanceDisputeDeposit: Consideration<Self::AccountId, NativeBalanceOf<Self>>;
+
+		/// The native balance held by [`Config::AttendanceDisputeDeposit`].
+		#[pallet::constant]
+		type AttendanceDisputeDepositAmount: Get<NativeBalanceOf<Self>>;
+
 		/// Asset id used by [`Self::Airdrop`].
 		type AirdropAssetId: Parameter + MaxEncodedLen + Default;
 
@@ -492,13 +652,13 @@ pub mod pallet {
 	pub type Players<T: Config> =
 		StorageMap<_, Blake2_128Concat, AccountOrPerson<T::AccountId>, Player<T::PlayDeposit>>;
 
-	/// The current game index.
+	/// The index of the last game started, in any region.
 	#[pallet::storage]
 	pub type GameIndex<T: Config> = StorageValue<_, u32, ValueQuery>;
 
-	/// The information for the next game or ongoing game.
+	/// The information for the next game or ongoing game of each region.
 	#[pallet::storage]
-	pub type Game<T: Config> = StorageValue<_, GameInfo<T::AccountId>>;
+	pub type Game<T: Config> = StorageMap<_, Twox64Concat, GameRegion, GameInfo<T::AccountId>>;
 
 	/// The mapping from past games, identified by their game index, to the start timestamp, in
 	/// seconds since the UNIX epoch.
@@ -516,6 +676,31 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// The reports submitted in the ongoing game of each region, kept until the consistency of
+	/// each reporter is scored in the player-process phase.
+	#[pallet::storage]
+	pub(crate) type SubmittedReports<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		GameRegion,
+		Blake2_128Concat,
+		AccountOrPerson<T::AccountId>,
+		FullReport<T>,
+	>;
+
+	/// The number of consecutive games in which the reports of a player were inconsistent with
+	/// the outcome of the reported players. Retained on `offboard` and `kickout`.
+	#[pallet::storage]
+	pub type InconsistentReportStreak<T: Config> =
+		StorageMap<_, Blake2_128Concat, AccountOrPerson<T::AccountId>, u32, ValueQuery>;
+
+	/// The last absence of players reported as not being a person, which they can dispute with
+	/// [`Pallet::dispute_attendance`]. Replaced or removed when the player is processed in a
+	/// later game, releasing the deposit of an open dispute.
+	#[pallet::storage]
+	pub type AttendanceDisputes<T: Config> =
+		StorageMap<_, Blake2_128Concat, AccountOrPerson<T::AccountId>, AttendanceDisputeOf<T>>;
+
 	/// Count of confirmed attendees per game index. Incremented by
 	/// [`Pallet::note_attendance`] for every attendance entry, regardless of
 	/// whether the player is recorded as `AccountOrPerson::Person` or
@@ -523,46 +708,79 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type GameParticipantCount<T: Config> = StorageMap<_, Twox64Concat, u32, u32, ValueQuery>;
 
-	/// The mapping from round index and player index to player.
+	/// The mapping from round index and player index to player, in the game of each region.
 	#[pallet::storage]
-	pub(crate) type IndexToPlayer<T: Config> =
-		StorageMap<_, Twox64Concat, (RoundIndex, PlayerIndex), AccountOrPerson<T::AccountId>>;
+	pub(crate) type IndexToPlayer<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		GameRegion,
+		Twox64Concat,
+		(RoundIndex, PlayerIndex),
+		AccountOrPerson<T::AccountId>,
+	>;
 
-	/// The mapping from player to their indices in each round.
+	/// The mapping from player to their indices in each round, in the game of each region.
 	#[pallet::storage]
-	pub(crate) type PlayerToIndex<T: Config> = StorageMap<
+	pub(crate) type PlayerToIndex<T: Config> = StorageDoubleMap<
 		_,
+		Twox64Concat,
+		GameRegion,
 		Blake2_128Concat,
 		AccountOrPerson<T::AccountId>,
 		BoundedVec<PlayerIndex, T::MaxRounds>,
 	>;
 
-	/// Storage used to compute the shuffle order of recognized people.
+	/// Storage used to compute the shuffle order of recognized people, in the game of each
+	/// region.
 	#[pallet::storage]
 	pub(crate) type ShuffleRecognized<T: Config> = StorageDoubleMap<
 		_,
 		Twox64Concat,
-		RoundIndex,
+		(GameRegion, RoundIndex),
 		Identity,
 		[u8; 32],
 		AccountOrPerson<T::AccountId>,
 	>;
 
-	/// Storage used to compute the shuffle order of not recognized people, i.e. candidates.
+	/// Storage used to compute the shuffle order of not recognized people, i.e. candidates, in
+	/// the game of each region.
 	#[pallet::storage]
 	pub(crate) type ShuffleNotRecognized<T: Config> = StorageDoubleMap<
 		_,
 		Twox64Concat,
-		RoundIndex,
+		(GameRegion, RoundIndex),
 		Identity,
 		[u8; 32],
 		AccountOrPerson<T::AccountId>,
 	>;
 
+	/// The most recent co-players of each player, oldest first.
+	///
+	/// Recorded by the games with [`GroupAssignment::SpreadPairings`] once their groups are
+	/// assigned, and used by the next such games to spread the player away from them.
+	#[pallet::storage]
+	pub type RecentCoPlayers<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		AccountOrPerson<T::AccountId>,
+		BoundedVec<AccountOrPerson<T::AccountId>, T::MaxRecentCoPlayers>,
+		ValueQuery,
+	>;
+
 	#[pallet::storage]
 	pub type GameSchedules<T: Config> =
 		StorageValue<_, BoundedVec<GameScheduleOf<T>, T::MaxGameSchedules>, ValueQuery>;
 
+	/// The rules scheduling games periodically, materialized into [`GameSchedules`] by `on_poll`.
+	#[pallet::storage]
+	pub type ScheduleRules<T: Config> =
+		CountedStorageMap<_, Twox64Concat, ScheduleRuleIndex, ScheduleRuleOf<T>>;
+
+	/// The index of the next schedule rule.
+	#[pallet::storage]
+	pub(crate) type NextScheduleRuleIndex<T: Config> =
+		StorageValue<_, ScheduleRuleIndex, ValueQuery>;
+
 	/// Number of invites available to distribute for an account.
 	#[pallet::storage]
 	pub type AvailableInvites<T: Config> =
@@ -610,11 +828,11 @@ pub mod pallet {
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
-		/// A new game is starting.
-		NewGame { registration_ends: u32, game_date: u32, report_ends: u32 },
+		/// A new game is starting in `region`.
+		NewGame { region: GameRegion, registration_ends: u32, game_date: u32, report_ends: u32 },
 		/// The game and its post-process has ended.
 		GameEnded { index: u32 },
-		/// The current game was force-killed by [`Config::ManagerOrigin`].
+		/// The current game of a region was force-killed by [`Config::ManagerOrigin`].
 		GameKilled { index: u32 },
 		/// The game phase durations were overridden by [`Config::ManagerOrigin`].
 		GamePhasesSet { phases: PhaseDurationValues },
@@ -635,7 +853,24 @@ pub mod pallet {
 		/// Games were scheduled.
 		GamesScheduled { count: u32 },
 		/// A scheduled game was removed.
-		ScheduledGameRemoved { game_play_time: u32 },
+		ScheduledGameRemoved { region: GameRegion, game_play_time: u32 },
+		/// A schedule rule was added.
+		ScheduleRuleAdded { index: ScheduleRuleIndex },
+		/// A schedule rule was amended, the games it already scheduled are unchanged.
+		ScheduleRuleAmended { index: ScheduleRuleIndex },
+		/// A schedule rule was removed, the games it already scheduled are unchanged.
+		ScheduleRuleRemoved { index: ScheduleRuleIndex },
+		/// A schedule rule scheduled its next game.
+		ScheduleRuleGameScheduled {
+			index: ScheduleRuleIndex,
+			region: GameRegion,
+			game_play_time: u32,
+		},
+		/// The next game of a schedule rule could not be scheduled, because it was outdated or
+		/// overlapped another game of its region.
+		ScheduleRuleGameSkipped { index: ScheduleRuleIndex, game_play_time: u32 },
+		/// A schedule rule has no more game to schedule and was removed.
+		ScheduleRuleExpired { index: ScheduleRuleIndex },
 		/// Statement store usage removed for the account.
 		StmtUsageRemoved { who: [u8; 32] },
 		/// All invites have been removed for the inviter.
@@ -650,6 +885,36 @@ pub mod pallet {
 		AirdropScheduleFailed { game_index: u32, error: DispatchError },
 		/// Game `game_index` was cancelled.
 		GameCancelled { game_index: u32 },
+		/// The reports of `who` were scored against the outcome of the reported players:
+		/// `agreed` of the `total` reports matched it. `inconsistent_streak` is the resulting
+		/// number of consecutive games with inconsistent reports.
+		ReportConsistencyScored {
+			who: AccountOrPerson<T::AccountId>,
+			game_index: u32,
+			agreed: u32,
+			total: u32,
+			inconsistent_streak: u32,
+		},
+		/// A persistently inconsistent reporter lost `penalty` score, down to `score`.
+		InconsistentReporterPenalized {
+			who: AccountOrPerson<T::AccountId>,
+			game_index: u32,
+			penalty: u32,
+			score: u32,
+		},
+		/// A player disputed their absence from game `game_index`, a case was opened with the
+		/// oracle.
+		AttendanceDisputed { who: AccountOrPerson<T::AccountId>, game_index: u32 },
+		/// The oracle found in favour of a player, their attendance to game `game_index` was
+		/// restored.
+		AttendanceRestored { who: AccountOrPerson<T::AccountId>, game_index: u32 },
+		/// The oracle did not find in favour of a player, their absence from game `game_index`
+		/// stands.
+		AttendanceDisputeRejected {
+			who: AccountOrPerson<T::AccountId>,
+			game_index: u32,
+			judgement: Judgement,
+		},
 	}
 
 	#[pallet::error]
@@ -694,6 +959,13 @@ pub mod pallet {
 		TooManyGameSchedules,
 		/// The game that was supposed to be removed was not found in scheduled games.
 		NoSuchGameScheduled,
+		/// The number of schedule rules exceeds the configured limit.
+		TooManyScheduleRules,
+		/// The schedule rule was not found.
+		NoSuchScheduleRule,
+		/// The schedule rule is invalid: its period must cover the length of its games, and it
+		/// must end after its next game.
+		InvalidScheduleRule,
 		/// The statement account signature is invalid.
 		InvalidStatementAccountSignature,
 		/// The statement account is already in used by another player.
@@ -715,6 +987,16 @@ pub mod pallet {
 		/// `claim_airdrop`: the claimant is not recognized in pallet-score, or their most recent
 		/// attended game does not match the `game_index` of the airdrop.
 		NotEligibleForAirdrop,
+		/// No disputable absence found for the player.
+		NoAttendanceDispute,
+		/// The absence is already disputed.
+		AttendanceAlreadyDisputed,
+		/// The absence can no longer be disputed.
+		AttendanceDisputeWindowClosed,
+		/// The judgement context does not identify a player.
+		BadJudgementContext,
+		/// The judgement is not for the dispute of the player.
+		UnexpectedJudgement,
 	}
 
 	/// Custom transaction-validity errors raised by the `authorize_*` hooks.
@@ -735,6 +1017,30 @@ pub mod pallet {
 	pub enum HoldReason {
 		/// Native balance held as the signup deposit for account-based players.
 		PlayDeposit,
+		/// Native balance held from account-based players disputing their absence.
+		AttendanceDispute,
+	}
+
+	/// Namespacing for getting access to the callback values.
+	///
+	/// Callbacks must be functions since they need pallet index which is not `const`.
+	pub trait Callbacks {
+		type T: Config;
+		fn attendance_judged() -> CallbackOf<Self::T>;
+	}
+	impl<T: Config> Callbacks for Call<T> {
+		type T = T;
+		fn attendance_judged() -> CallbackOf<T> {
+			use frame_support::traits::GetCallIndex;
+			// Parameters to this call do not matter, as we are just extracting the call index.
+			let judgement_call = Call::<T>::attendance_judged {
+				ticket: Default::default(),
+				context: Default::default(),
+				judgement: Judgement::Contempt,
+			};
+			let call_index = judgement_call.get_call_index();
+			Callback::from_parts(Pallet::<T>::index() as u8, call_index)
+		}
 	}
 
 	#[pallet::hooks]
@@ -753,6 +1059,14 @@ pub mod pallet {
 				max_votes as u64 * T::CandidateVoteWeight::get() as u64 <= u8::MAX as u64,
 				"max_votes * candidate_vote_weight must fit in u8"
 			);
+			assert!(
+				T::MaxRounds::get() <= MAX_GAME_ROUNDS,
+				"max rounds must fit in the groups of a disputed attendance"
+			);
+			assert!(
+				(1..=GameRegion::MAX as u32 + 1).contains(&T::MaxRegions::get()),
+				"max regions must be at least 1 and every region must fit in `GameRegion`"
+			);
 		}
 
 		fn on_idle(n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
@@ -788,7 +1102,8 @@ pub mod pallet {
 		/// This is for new players or archived players, other players should use
 		/// [`Pallet::sign_up_with_account`] for free.
 		///
-		/// A game must be ongoing and in its registration phase.
+		/// The game of `region` must be ongoing and in its registration phase. The player must not
+		/// be registered for the game of any region.
 		///
 		/// `airdrop` optionally enters the player into this game's airdrop draw. Pass `None` to
 		/// skip it. When `Some`, it is the player's VRF, which both seeds their draw slot and
@@ -801,6 +1116,7 @@ pub mod pallet {
 		#[pallet::weight(<T as Config>::WeightInfo::sign_up_with_invite())]
 		pub fn sign_up_with_invite(
 			origin: OriginFor<T>,
+			region: GameRegion,
 			identifier_key: CommunicationIdentifier,
 			airdrop: Option<AirdropVrf<AirdropProofOf<T>>>,
 		) -> DispatchResultWithPostInfo {
@@ -809,12 +1125,10 @@ pub mod pallet {
 				_ => return Err(DispatchError::BadOrigin.into()),
 			};
 
-			Self::sign_up_inner(SignUpArgs::Account {
-				who,
-				identifier_key,
-				new_invited: true,
-				airdrop,
-			})
+			Self::sign_up_inner(
+				region,
+				SignUpArgs::Account { who, identifier_key, new_invited: true, airdrop },
+			)
 		}
 
 		/// Sign up for the game using an account.
@@ -822,7 +1136,8 @@ pub mod pallet {
 		/// If the player is new or archived, then a deposit will be taken from the signer.
 		/// Otherwise the call is free.
 		///
-		/// A game must be ongoing and in its registration phase.
+		/// The game of `region` must be ongoing and in its registration phase. The player must not
+		/// be registered for the game of any region.
 		///
 		/// `airdrop` optionally enters the player into this game's airdrop draw. Pass `None` to
 		/// skip it. When `Some`, it is the player's VRF, which both seeds their draw slot and
@@ -839,17 +1154,16 @@ pub mod pallet {
 		)]
 		pub fn sign_up_with_account(
 			origin: OriginFor<T>,
+			region: GameRegion,
 			identifier_key: CommunicationIdentifier,
 			airdrop: Option<AirdropVrf<AirdropProofOf<T>>>,
 		) -> DispatchResultWithPostInfo {
 			let who = indiv_pallet_score::Pallet::<T>::ensure_signed_or_participant(origin)?;
 
-			Self::sign_up_inner(SignUpArgs::Account {
-				who,
-				identifier_key,
-				new_invited: false,
-				airdrop,
-			})
+			Self::sign_up_inner(
+				region,
+				SignUpArgs::Account { who, identifier_key, new_invited: false, airdrop },
+			)
 		}
 
 		/// Sign up for the game.
@@ -859,7 +1173,8 @@ pub mod pallet {
 		/// On top of this their score is never going below personhood threshold and the player will
 		/// never get archived.
 		///
-		/// A game must be ongoing and in its registration phase.
+		/// The game of `region` must be ongoing and in its registration phase. The player must not
+		/// be registered for the game of any region.
 		///
 		/// The origin must be a personal alias.
 		///
@@ -879,6 +1194,7 @@ pub mod pallet {
 		#[pallet::weight(<T as Config>::WeightInfo::sign_up_with_alias())]
 		pub fn sign_up_with_alias(
 			origin: OriginFor<T>,
+			region: GameRegion,
 			identifier_key: CommunicationIdentifier,
 			statement_account: T::AccountId,
 			sig: T::AccountSignature,
@@ -886,18 +1202,21 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			let who = indiv_pallet_score::Pallet::<T>::ensure_person(origin)?;
 
-			Self::sign_up_inner(SignUpArgs::Alias {
-				who,
-				identifier_key,
-				statement_account,
-				statement_account_signature: sig,
-				airdrop,
-			})
+			Self::sign_up_inner(
+				region,
+				SignUpArgs::Alias {
+					who,
+					identifier_key,
+					statement_account,
+					statement_account_signature: sig,
+					airdrop,
+				},
+			)
 		}
 
 		/// After the game, send the full report.
 		///
-		/// The game must be ongoing and in its reporting phase.
+		/// The game the player signed up for must be ongoing and in its reporting phase.
 		///
 		/// The origin must be an alias, or signed by an account, or signed by an account and use
 		/// `ScoreAsParticipant` extension.
@@ -915,7 +1234,8 @@ pub mod pallet {
 			let who =
 				indiv_pallet_score::Pallet::<T>::ensure_signed_or_participant_or_person(origin)?;
 
-			let game = Game::<T>::get().ok_or(Error::<T>::NoGame)?;
+			let region = Players::<T>::get(&who).ok_or(Error::<T>::NotRegistered)?.region;
+			let game = Game::<T>::get(region).ok_or(Error::<T>::NoGame)?;
 			let game_index = game.index;
 			let now = T::UnixTime::now();
 			ensure!(now < Duration::from_secs(game.report_ends as u64), Error::<T>::NoReporting);
@@ -941,7 +1261,7 @@ pub mod pallet {
 			ensure!(full_report.len() == game.rounds as usize, Error::<T>::InvalidReport);
 
 			let reporter_indices =
-				PlayerToIndex::<T>::get(&who).ok_or(Error::<T>::NotRegistered)?;
+				PlayerToIndex::<T>::get(region, &who).ok_or(Error::<T>::NotRegistered)?;
 
 			// Collect all players that received a new vote during this report so we can
 			// check their attendance once, after the loop updates their tallies.
@@ -968,7 +1288,7 @@ pub mod pallet {
 				for reported_index in other_people_in_group {
 					log_other_people_in_group.push(reported_index);
 					let report = report_iter.next().ok_or(Error::<T>::InvalidReport)?;
-					let reported_player = IndexToPlayer::<T>::get((round, reported_index))
+					let reported_player = IndexToPlayer::<T>::get(region, (round, reported_index))
 						.defensive_proof("indiv-pallet-game: index should map to a player")
 						.ok_or(Error::<T>::InternalErrorInvalidState)?;
 					let reported_early_enactment =
@@ -1042,6 +1362,7 @@ pub mod pallet {
 				)
 			}
 
+			SubmittedReports::<T>::insert(region, &who, full_report);
 			Self::deposit_event(Event::<T>::ReportSubmitted { who: who.clone(), game_index });
 
 			let mut enacted_count: u32 = 0;
@@ -1067,8 +1388,8 @@ pub mod pallet {
 		/// The origin must be an alias, or signed by an account, or signed by an account and use
 		/// `ScoreAsParticipant` extension.
 		///
-		/// There must be no game or the existing game must be in registration phase and the player
-		/// must have not signed up for the game.
+		/// There must be no game in the region of the player or the game must be in registration
+		/// phase and the player must have not signed up for the game.
 		#[pallet::call_index(4)]
 		#[pallet::weight(
 			<T as Config>::WeightInfo::offboard_account()
@@ -1080,10 +1401,11 @@ pub mod pallet {
 
 			let archived = ArchivedPlayers::<T>::take(&who);
 			let player = Players::<T>::take(&who);
+			RecentCoPlayers::<T>::remove(&who);
 			let player_is_offboarding = player.is_some();
 			// `PlayerAttendanceHistory` is retained on offboard so an identity that
 			// attended a game keeps its recorded attendance.
-			let game = Game::<T>::get();
+			let game = player.as_ref().and_then(|player| Game::<T>::get(player.region));
 
 			let game_is_none_or_registration =
 				game.is_none_or(|g| matches!(g.state, GameState::Registration { .. }));
@@ -1124,6 +1446,7 @@ pub mod pallet {
 				Self::deposit_event(Event::<T>::StmtUsageRemoved { who: stmt_account.into() });
 			}
 
+			Self::drop_attendance_dispute(&who);
 			indiv_pallet_score::Pallet::<T>::offboard(&who);
 
 			let actual_weight = match &who {
@@ -1152,6 +1475,7 @@ pub mod pallet {
 
 			let archived =
 				ArchivedPlayers::<T>::take(&player).ok_or(Error::<T>::NoArchivedPlayer)?;
+			RecentCoPlayers::<T>::remove(&player);
 			// See `offboard`: `PlayerAttendanceHistory` is retained.
 
 			let ArchivedPlayer::Kickable { archived_since, .. } = archived else {
@@ -1275,7 +1599,8 @@ pub mod pallet {
 		}
 
 		/// Schedules new games according to provided schedules.
-		/// Schedules must be in chronological order, and after the ongoing game (if there is any).
+		/// Schedules of a region must be in chronological order, and after the ongoing game of the
+		/// region (if there is any). Games of different regions can overlap.
 		#[pallet::call_index(10)]
 		#[pallet::weight(<T as Config>::WeightInfo::schedule_games(games_schedules.len() as u32))]
 		pub fn schedule_games(
@@ -1284,67 +1609,20 @@ pub mod pallet {
 		) -> DispatchResult {
 			<T as Config>::ManagerOrigin::ensure_origin_or_root(origin)?;
 
+			let mut schedules = GameSchedules::<T>::get();
 			ensure!(
-				GameSchedules::<T>::get().len().saturating_add(games_schedules.len()) as u32 <=
+				schedules.len().saturating_add(games_schedules.len()) as u32 <=
 					T::MaxGameSchedules::get(),
 				Error::<T>::TooManyGameSchedules
 			);
 
-			// To validate that the newly scheduled games will take place after the currently
-			// planned ones.
-			// Using the now value of time as default allows us to easily validate
-			// that the schedules are not set in the past.
-			let mut last_game_end_time = GameSchedules::<T>::get().last().map_or(
-				Game::<T>::get().map_or(T::UnixTime::now(), |game| {
-					Duration::from_secs(GameTimes::<T>::player_process_end(&game) as u64)
-				}),
-				|schedule| {
-					Duration::from_secs(
-						crate::types::GameTimes::<T>::player_process_end(schedule) as u64
-					)
-				},
-			);
-
-			for schedule in &games_schedules {
-				// Checks that games do not overlap in time and that schedules were provided in
-				// chronological order.
-
-				ensure!(
-					last_game_end_time <=
-						Duration::from_secs(GameTimes::<T>::registration_start(schedule) as u64),
-					Error::<T>::InvalidGameSetup
-				);
-
-				ensure!(schedule.rounds > 0, Error::<T>::InvalidGameSetup);
-				ensure!(
-					u32::from(schedule.rounds) <= T::MaxRounds::get(),
-					Error::<T>::InvalidGameSetup
-				);
-
-				ensure!(
-					// Note: a game is cancelled if groups can't be filled with at least
-					// `max_group_size - 1`. So `>` is used here, ensuring the groups are at least
-					// the required minimum size.
-					schedule.max_group_size > T::MinGroupSize::get(),
-					Error::<T>::InvalidGameSetup
-				);
-				ensure!(
-					schedule.max_group_size <= T::MaxGroupSize::get(),
-					Error::<T>::InvalidGameSetup
-				);
-
-				last_game_end_time =
-					Duration::from_secs(GameTimes::<T>::player_process_end(schedule) as u64);
-			}
-
 			let count = games_schedules.len() as u32;
-			GameSchedules::<T>::try_mutate(|current_schedules| {
-				games_schedules.into_iter().try_for_each(|schedule| {
-					current_schedules
-						.try_push(schedule)
-						.map_err(|_| Error::<T>::TooManyGameSchedules)
-				})
-			})?;
+			for schedule in games_schedules {
+				Self::ensure_valid_game_settings(&schedule)?;
+				Self::ensure_schedule_in_order(&schedules, &schedule)?;
+				schedules.try_push(schedule).map_err(|_| Error::<T>::TooManyGameSchedules)?;
+			}
+			GameSchedules::<T>::put(schedules);
 
 			Self::deposit_event(Event::<T>::GamesScheduled { count });
 
@@ -1353,14 +1631,18 @@ pub mod pallet {
 
 		#[pallet::call_index(11)]
 		#[pallet::weight(<T as Config>::WeightInfo::remove_scheduled_game())]
-		pub fn remove_scheduled_game(origin: OriginFor<T>, game_play_time: u32) -> DispatchResult {
+		pub fn remove_scheduled_game(
+			origin: OriginFor<T>,
+			region: GameRegion,
+			game_play_time: u32,
+		) -> DispatchResult {
 			<T as Config>::ManagerOrigin::ensure_origin_or_root(origin)?;
 
-			// Look for a game with the same reporting_start_time in the scheduled games and remove
-			// it if it exists.
-			if let Ok(index) = GameSchedules::<T>::get()
-				.binary_search_by_key(&game_play_time, |schedule| schedule.game_play_time)
-			{
+			// Look for a game of the region with the same game_play_time in the scheduled games
+			// and remove it if it exists.
+			if let Some(index) = GameSchedules::<T>::get().iter().position(|schedule| {
+				schedule.region == region && schedule.game_play_time == game_play_time
+			}) {
 				GameSchedules::<T>::mutate(|schedules| {
 					schedules.remove(index);
 				});
@@ -1368,7 +1650,76 @@ pub mod pallet {
 				return Err(Error::<T>::NoSuchGameScheduled.into());
 			}
 
-			Self::deposit_event(Event::<T>::ScheduledGameRemoved { game_play_time });
+			Self::deposit_event(Event::<T>::ScheduledGameRemoved { region, game_play_time });
+
+			Ok(())
+		}
+
+		/// Add a rule scheduling games periodically.
+		///
+		/// The games of the rule are added to the game schedules by `on_poll`, once their
+		/// registration starts within [`Config::ScheduleRuleHorizon`]. The period of the rule must
+		/// cover the length of its games, and the rule must end after its next game.
+		#[pallet::call_index(20)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_schedule_rule())]
+		pub fn add_schedule_rule(origin: OriginFor<T>, rule: ScheduleRuleOf<T>) -> DispatchResult {
+			<T as Config>::ManagerOrigin::ensure_origin_or_root(origin)?;
+
+			ensure!(
+				ScheduleRules::<T>::count() < T::MaxScheduleRules::get(),
+				Error::<T>::TooManyScheduleRules
+			);
+			Self::ensure_valid_schedule_rule(&rule)?;
+
+			let index = NextScheduleRuleIndex::<T>::mutate(|next_index| {
+				let index = *next_index;
+				*next_index = next_index.saturating_add(1);
+				index
+			});
+			ScheduleRules::<T>::insert(index, rule);
+
+			Self::deposit_event(Event::<T>::ScheduleRuleAdded { index });
+
+			Ok(())
+		}
+
+		/// Replace the schedule rule `index` with `rule`, e.g. to pause or resume it.
+		///
+		/// The games already scheduled by the rule are unchanged, they can be removed with
+		/// `remove_scheduled_game`.
+		#[pallet::call_index(21)]
+		#[pallet::weight(<T as Config>::WeightInfo::amend_schedule_rule())]
+		pub fn amend_schedule_rule(
+			origin: OriginFor<T>,
+			index: ScheduleRuleIndex,
+			rule: ScheduleRuleOf<T>,
+		) -> DispatchResult {
+			<T as Config>::ManagerOrigin::ensure_origin_or_root(origin)?;
+
+			ensure!(ScheduleRules::<T>::contains_key(index), Error::<T>::NoSuchScheduleRule);
+			Self::ensure_valid_schedule_rule(&rule)?;
+			ScheduleRules::<T>::insert(index, rule);
+
+			Self::deposit_event(Event::<T>::ScheduleRuleAmended { index });
+
+			Ok(())
+		}
+
+		/// Remove the schedule rule `index`.
+		///
+		/// The games already scheduled by the rule are unchanged, they can be removed with
+		/// `remove_scheduled_game`.
+		#[pallet::call_index(22)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_schedule_rule())]
+		pub fn remove_schedule_rule(
+			origin: OriginFor<T>,
+			index: ScheduleRuleIndex,
+		) -> DispatchResult {
+			<T as Config>::ManagerOrigin::ensure_origin_or_root(origin)?;
+
+			ScheduleRules::<T>::take(index).ok_or(Error::<T>::NoSuchScheduleRule)?;
+
+			Self::deposit_event(Event::<T>::ScheduleRuleRemoved { index });
 
 			Ok(())
 		}
@@ -1430,19 +1781,112 @@ pub mod pallet {
 			Ok(Pays::No.into())
 		}
 
-		/// Force start the shuffle before its normal start time.
+		/// Dispute the absence of the caller from their last game, after they were reported as
+		/// not being a person.
+		///
+		/// The origin must be an alias, or signed by an account, or signed by an account and use
+		/// `ScoreAsParticipant` extension.
+		///
+		/// The absence must be recorded in [`AttendanceDisputes`], its game must have ended and
+		/// at most [`Config::AttendanceDisputeWindow`] blocks must have passed since it was
+		/// enacted. The window ends early when the caller is processed in a later game.
+		///
+		/// Account players place [`Config::AttendanceDisputeDeposit`]. A case is opened with
+		/// [`Config::Oracle`], which calls back [`Pallet::attendance_judged`] with its judgement.
+		#[pallet::call_index(18)]
+		#[pallet::weight(<T as Config>::WeightInfo::dispute_attendance())]
+		pub fn dispute_attendance(origin: OriginFor<T>) -> DispatchResult {
+			let who =
+				indiv_pallet_score::Pallet::<T>::ensure_signed_or_participant_or_person(origin)?;
+
+			let mut dispute =
+				AttendanceDisputes::<T>::get(&who).ok_or(Error::<T>::NoAttendanceDispute)?;
+			ensure!(dispute.case.is_none(), Error::<T>::AttendanceAlreadyDisputed);
+			ensure!(
+				Game::<T>::get(dispute.region).is_none_or(|game| game.index != dispute.game_index),
+				Error::<T>::Early
+			);
+			ensure!(
+				frame_system::Pallet::<T>::block_number() <= dispute.dispute_until,
+				Error::<T>::AttendanceDisputeWindowClosed
+			);
+
+			dispute.deposit = match &who {
+				AccountOrPerson::Account(account) => Some(T::AttendanceDisputeDeposit::new(
+					account,
+					T::AttendanceDisputeDepositAmount::get(),
+				)?),
+				AccountOrPerson::Person(_) => None,
+			};
+			let statement =
+				GameAttendance { game_index: dispute.game_index, groups: dispute.groups.clone() };
+			let context = JudgementContext::truncate_from(who.encode());
+			let case =
+				T::Oracle::judge_statement(statement, context, Call::<T>::attendance_judged())?;
+			dispute.case = Some(case);
+
+			let game_index = dispute.game_index;
+			AttendanceDisputes::<T>::insert(&who, dispute);
+			Self::deposit_event(Event::<T>::AttendanceDisputed { who, game_index });
+			Ok(())
+		}
+
+		/// Is called by [`Config::Oracle`] with the judgement of a dispute opened with
+		/// [`Pallet::dispute_attendance`].
+		///
+		/// If the oracle finds the player attended the game as a person, their attendance is
+		/// restored in `indiv_pallet_score` and their deposit released. Otherwise the absence
+		/// stands and the deposit is burnt.
+		#[pallet::call_index(19)]
+		#[pallet::weight(<T as Config>::WeightInfo::attendance_judged())]
+		pub fn attendance_judged(
+			origin: OriginFor<T>,
+			ticket: OracleTicketOf<T>,
+			context: JudgementContext,
+			judgement: Judgement,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			let who = AccountOrPerson::<T::AccountId>::decode(&mut &context[..])
+				.map_err(|_| Error::<T>::BadJudgementContext)?;
+			let dispute =
+				AttendanceDisputes::<T>::get(&who).ok_or(Error::<T>::NoAttendanceDispute)?;
+			ensure!(dispute.case == Some(ticket), Error::<T>::UnexpectedJudgement);
+
+			let game_index = dispute.game_index;
+			AttendanceDisputes::<T>::remove(&who);
+			if judgement == Judgement::Truth(Truth::True) {
+				indiv_pallet_score::Pallet::<T>::restore_attendance(&who, game_index)?;
+				Self::note_attendance(game_index, &who);
+				Self::release_dispute_deposit(&who, dispute.deposit, false);
+				Self::deposit_event(Event::<T>::AttendanceRestored { who, game_index });
+			} else {
+				Self::release_dispute_deposit(&who, dispute.deposit, true);
+				Self::deposit_event(Event::<T>::AttendanceDisputeRejected {
+					who,
+					game_index,
+					judgement,
+				});
+			}
+
+			Ok(Pays::No.into())
+		}
+
+		/// Force start the shuffle of the game of `region` before its normal start time.
 		///
 		/// This action can only be performed by the root origin and is only meant for testing.
 		#[pallet::call_index(101)]
 		#[pallet::weight(Weight::zero())]
 		#[cfg(feature = "testnet")]
-		pub fn testnet_force_start_shuffle(origin: OriginFor<T>) -> DispatchResult {
+		pub fn testnet_force_start_shuffle(
+			origin: OriginFor<T>,
+			region: GameRegion,
+		) -> DispatchResult {
 			if !T::TESTNET {
 				return Err(Error::<T>::InvalidState.into());
 			}
 
 			ensure_root(origin)?;
-			let mut game = Game::<T>::get().ok_or(Error::<T>::NoGame)?;
+			let mut game = Game::<T>::get(region).ok_or(Error::<T>::NoGame)?;
 			let GameState::<T::AccountId>::Registration { .. } = game.state else {
 				return Err(Error::<T>::NoRegistration.into());
 			};
@@ -1450,23 +1894,26 @@ pub mod pallet {
 			game.registration_ends = now.as_secs().try_into().unwrap_or(u32::MAX);
 			game.state =
 				GameState::Shuffle { step: ShuffleStep::Step1Insert { last_iteration: None } };
-			Game::<T>::put(game);
+			Game::<T>::insert(region, game);
 			Ok(())
 		}
 
-		/// Force end a game's reporting phase before its normal end time.
+		/// Force end the reporting phase of the game of `region` before its normal end time.
 		///
 		/// This action can only be performed by the root origin and is only meant for testing.
 		#[pallet::call_index(102)]
 		#[pallet::weight(Weight::zero())]
 		#[cfg(feature = "testnet")]
-		pub fn testnet_force_end_reporting(origin: OriginFor<T>) -> DispatchResult {
+		pub fn testnet_force_end_reporting(
+			origin: OriginFor<T>,
+			region: GameRegion,
+		) -> DispatchResult {
 			if !T::TESTNET {
 				return Err(Error::<T>::InvalidState.into());
 			}
 
 			ensure_root(origin)?;
-			let mut game = Game::<T>::get().ok_or(Error::<T>::NoGame)?;
+			let mut game = Game::<T>::get(region).ok_or(Error::<T>::NoGame)?;
 			let GameState::<T::AccountId>::Reporting { player_count } = game.state else {
 				return Err(Error::<T>::NoReporting.into());
 			};
@@ -1475,21 +1922,22 @@ pub mod pallet {
 			game.shuffle_deadline = core::cmp::min(game.shuffle_deadline, game.report_ends);
 			game.game_date = core::cmp::min(game.game_date, game.report_ends);
 			game.state = GameState::PlayerProcess {
-				step: PlayerProcessStep::Step1ProcessPlayers { last_iteration: None, player_count },
+				step: PlayerProcessStep::Step0ScoreReports { player_count },
 			};
-			Game::<T>::put(game);
+			Game::<T>::insert(region, game);
 			Ok(())
 		}
 
 		/// Override the game phase durations.
 		///
 		/// Restricted to [`Config::ManagerOrigin`] (or root). Until reset, all future
-		/// game schedules use these phases instead of [`Config::DefaultPhaseDurations`].
+		/// game schedules without their own phases use these phases instead of
+		/// [`Config::DefaultPhaseDurations`].
 		/// To revert, the manager re-issues the call with the desired explicit
 		/// values — there is no separate clear extrinsic.
 		///
-		/// Only callable while no game exists or the current game is still in its
-		/// Registration phase; otherwise fails with [`Error::InvalidGameState`]. This
+		/// Only callable while every current game is still in its Registration phase;
+		/// otherwise fails with [`Error::InvalidGameState`]. This
 		/// prevents changing phase durations once players have committed to a game
 		/// whose timing is already locked in.
 		#[pallet::call_index(14)]
@@ -1501,31 +1949,31 @@ pub mod pallet {
 			<T as Config>::ManagerOrigin::ensure_origin_or_root(origin)?;
 			// No game or Registration only: in both, no player is committed to the
 			// timings we're about to change. The no-game case is intentional.
-			if let Some(game) = Game::<T>::get() {
-				ensure!(
-					matches!(game.state, GameState::Registration { .. }),
-					Error::<T>::InvalidGameState,
-				);
-			}
+			ensure!(
+				Game::<T>::iter_values()
+					.all(|game| matches!(game.state, GameState::Registration { .. })),
+				Error::<T>::InvalidGameState,
+			);
 			StoredPhaseDurations::<T>::put(phases.clone());
 			Self::deposit_event(Event::<T>::GamePhasesSet { phases });
 			Ok(())
 		}
 
-		/// Kill the current game, regardless of which phase it is in.
+		/// Kill the current game of `region`, regardless of which phase it is in.
 		///
 		/// Restricted to [`Config::ManagerOrigin`] (or root). Intended as an emergency
 		/// recovery lever when a game is stuck or its state has been corrupted.
 		#[pallet::call_index(15)]
 		#[pallet::weight(<T as Config>::WeightInfo::kill_current_game())]
-		pub fn kill_current_game(origin: OriginFor<T>) -> DispatchResult {
+		pub fn kill_current_game(origin: OriginFor<T>, region: GameRegion) -> DispatchResult {
 			<T as Config>::ManagerOrigin::ensure_origin_or_root(origin)?;
 
-			let game_index = GameIndex::<T>::get();
+			let game = Game::<T>::get(region);
+			let game_index = game.as_ref().map_or_else(GameIndex::<T>::get, |game| game.index);
 
 			// If the game is past the shuffle phase, the attendance report session was
 			// started and needs to be ended here.
-			if let Some(game) = Game::<T>::get() {
+			if let Some(game) = game {
 				if matches!(
 					game.state,
 					GameState::Reporting { .. } | GameState::PlayerProcess { .. }
@@ -1533,11 +1981,16 @@ pub mod pallet {
 					let _ = indiv_pallet_score::Pallet::<T>::end_attendance_report_session();
 				}
 				Self::on_game_cancelled(&game);
+				GameHistory::<T>::remove(game_index);
 			}
 
 			Players::<T>::translate(|_player_id, player_info: Player<T::PlayDeposit>| {
+				if player_info.region != region {
+					return Some(player_info);
+				}
 				Some(Player {
 					first_game: player_info.first_game,
+					region,
 					registered: false,
 					sent_report: false,
 					early_attendance_enactment: None,
@@ -1548,12 +2001,14 @@ pub mod pallet {
 					credibility: player_info.credibility,
 				})
 			});
-			GameHistory::<T>::remove(game_index);
-			IndexToPlayer::<T>::drain().count(); // drain all
-			PlayerToIndex::<T>::drain().count(); // drain all
-			ShuffleRecognized::<T>::drain().count(); // drain all
-			ShuffleNotRecognized::<T>::drain().count(); // drain all
-			Game::<T>::kill();
+			let _ = IndexToPlayer::<T>::clear_prefix(region, u32::MAX, None);
+			let _ = PlayerToIndex::<T>::clear_prefix(region, u32::MAX, None);
+			let _ = SubmittedReports::<T>::clear_prefix(region, u32::MAX, None);
+			for round in (0..=RoundIndex::MAX).take(T::MaxRounds::get() as usize) {
+				let _ = ShuffleRecognized::<T>::clear_prefix((region, round), u32::MAX, None);
+				let _ = ShuffleNotRecognized::<T>::clear_prefix((region, round), u32::MAX, None);
+			}
+			Game::<T>::remove(region);
 
 			Self::deposit_event(Event::<T>::GameKilled { index: game_index });
 
@@ -1562,65 +2017,217 @@ pub mod pallet {
 	}
 
 	impl<T: Config> Pallet<T> {
-		fn do_on_idle(n: BlockNumberFor<T>, weight_meter: &mut WeightMeter) {
-			if weight_meter.try_consume(<T as Config>::WeightInfo::get_game()).is_err() {
-				return;
+		/// The regions games can be scheduled in.
+		pub(crate) fn regions() -> impl Iterator<Item = GameRegion> {
+			(0..=GameRegion::MAX).take(T::MaxRegions::get() as usize)
+		}
+
+		/// Ensure the settings of `schedule` are valid for a game, regardless of its time.
+		pub(crate) fn ensure_valid_game_settings(schedule: &GameScheduleOf<T>) -> DispatchResult {
+			ensure!(
+				u32::from(schedule.region) < T::MaxRegions::get(),
+				Error::<T>::InvalidGameSetup
+			);
+			ensure!(schedule.rounds > 0, Error::<T>::InvalidGameSetup);
+			ensure!(
+				u32::from(schedule.rounds) <= T::MaxRounds::get(),
+				Error::<T>::InvalidGameSetup
+			);
+
+			ensure!(
+				// Note: a game is cancelled if groups can't be filled with at least
+				// `max_group_size - 1`. So `>` is used here, ensuring the groups are at least
+				// the required minimum size.
+				schedule.max_group_size > T::MinGroupSize::get(),
+				Error::<T>::InvalidGameSetup
+			);
+			ensure!(
+				schedule.max_group_size <= T::MaxGroupSize::get(),
+				Error::<T>::InvalidGameSetup
+			);
+			ensure!(schedule.attendance_rule.is_valid(), Error::<T>::InvalidGameSetup);
+
+			Ok(())
+		}
+
+		/// Ensure `schedule` takes place after the games planned in its region: the ongoing game
+		/// and the `planned` schedules.
+		pub(crate) fn ensure_schedule_in_order(
+			planned: &[GameScheduleOf<T>],
+			schedule: &GameScheduleOf<T>,
+		) -> DispatchResult {
+			// Using the now value of time as default allows us to easily validate
+			// that the schedules are not set in the past.
+			let last_game_end_time = planned
+				.iter()
+				.rev()
+				.find(|planned| planned.region == schedule.region)
+				.map(GameTimes::<T>::player_process_end)
+				.or_else(|| {
+					Game::<T>::get(schedule.region)
+						.map(|game| GameTimes::<T>::player_process_end(&game))
+				})
+				.map_or(T::UnixTime::now(), |end| Duration::from_secs(end as u64));
+
+			// Checks that games of a region do not overlap in time and that schedules were
+			// provided in chronological order.
+			ensure!(
+				last_game_end_time <=
+					Duration::from_secs(GameTimes::<T>::registration_start(schedule) as u64),
+				Error::<T>::InvalidGameSetup
+			);
+
+			Ok(())
+		}
+
+		/// Ensure `rule` can schedule games: the settings of its games are valid, its games don't
+		/// overlap each other, and it ends after its next game.
+		fn ensure_valid_schedule_rule(rule: &ScheduleRuleOf<T>) -> DispatchResult {
+			Self::ensure_valid_game_settings(&rule.next)?;
+
+			let game_length = GameTimes::<T>::player_process_end(&rule.next)
+				.saturating_sub(GameTimes::<T>::registration_start(&rule.next));
+			ensure!(rule.period > 0 && rule.period >= game_length, Error::<T>::InvalidScheduleRule);
+			ensure!(rule.until >= rule.next.game_play_time, Error::<T>::InvalidScheduleRule);
+
+			Ok(())
+		}
+
+		/// Add the next game of each schedule rule to [`GameSchedules`], once its registration
+		/// starts within [`Config::ScheduleRuleHorizon`]. At most one game is scheduled per rule
+		/// and per call.
+		pub(crate) fn schedule_rule_games(weight_meter: &mut WeightMeter) {
+			let now = T::UnixTime::now();
+			let horizon =
+				now.saturating_add(Duration::from_secs(T::ScheduleRuleHorizon::get().into()));
+
+			for index in ScheduleRules::<T>::iter_keys().collect::<Vec<_>>() {
+				if weight_meter
+					.try_consume(<T as Config>::WeightInfo::schedule_rule_game())
+					.is_err()
+				{
+					return;
+				}
+
+				let Some(mut rule) = ScheduleRules::<T>::get(index) else {
+					continue;
+				};
+				let registration_start = |schedule: &GameScheduleOf<T>| {
+					Duration::from_secs(GameTimes::<T>::registration_start(schedule) as u64)
+				};
+				if registration_start(&rule.next) > horizon {
+					continue;
+				}
+
+				let game_play_time = rule.next.game_play_time;
+				let mut schedules = GameSchedules::<T>::get();
+				if rule.paused {
+					// A paused rule skips its games silently, and resumes at its next game.
+				} else if registration_start(&rule.next) <= now ||
+					Self::ensure_schedule_in_order(&schedules, &rule.next).is_err()
+				{
+					Self::deposit_event(Event::<T>::ScheduleRuleGameSkipped {
+						index,
+						game_play_time,
+					});
+				} else if schedules.is_full() {
+					// Retry once a scheduled game started.
+					continue;
+				} else {
+					let region = rule.next.region;
+					let _ = schedules
+						.try_push(rule.next.clone())
+						.defensive_proof("indiv-pallet-game: schedules are not full");
+					GameSchedules::<T>::put(schedules);
+					Self::deposit_event(Event::<T>::ScheduleRuleGameScheduled {
+						index,
+						region,
+						game_play_time,
+					});
+				}
+
+				rule.next.game_play_time = rule.next.game_play_time.saturating_add(rule.period);
+				if rule.next.game_play_time > rule.until {
+					ScheduleRules::<T>::remove(index);
+					Self::deposit_event(Event::<T>::ScheduleRuleExpired { index });
+				} else {
+					ScheduleRules::<T>::insert(index, rule);
+				}
 			}
-			if let Some(game) = Game::<T>::get() {
-				Self::process_game(weight_meter, n, game);
+		}
+
+		fn do_on_idle(n: BlockNumberFor<T>, weight_meter: &mut WeightMeter) {
+			for region in Self::regions() {
+				if weight_meter.try_consume(<T as Config>::WeightInfo::get_game()).is_err() {
+					return;
+				}
+				if let Some(game) = Game::<T>::get(region) {
+					Self::process_game(weight_meter, n, game);
+				}
 			}
 		}
 		fn do_on_poll(n: BlockNumberFor<T>, weight_meter: &mut WeightMeter) {
-			if weight_meter.try_consume(<T as Config>::WeightInfo::get_game()).is_err() {
-				return;
+			Self::schedule_rule_games(weight_meter);
+
+			for region in Self::regions() {
+				if weight_meter.try_consume(<T as Config>::WeightInfo::get_game()).is_err() {
+					return;
+				}
+				let game = Game::<T>::get(region);
+				match game {
+					// If there's no ongoing game in the region at a time, take the first scheduled
+					// game of the region and make it the next one.
+					None => Self::start_scheduled_game(weight_meter, region),
+					Some(game) => Self::process_game(weight_meter, n, game),
+				}
 			}
-			let game = Game::<T>::get();
-			match game {
-				// If there's no ongoing game at a time, take the first scheduled game and make it
-				// the next one.
-				None => {
-					if weight_meter
-						.try_consume(<T as Config>::WeightInfo::get_game_schedules(
-							T::MaxGameSchedules::get(),
-						))
-						.is_err()
-					{
-						return;
-					}
-					let mut schedules = <GameSchedules<T>>::get();
-					if !schedules.is_empty() {
-						let weight = <T as Config>::WeightInfo::new_game()
-							.saturating_add(<T as Config>::WeightInfo::put_game_schedules());
+		}
 
-						if weight_meter.try_consume(weight).is_err() {
-							return;
-						}
+		/// Start the first scheduled game of `region`, if any.
+		fn start_scheduled_game(weight_meter: &mut WeightMeter, region: GameRegion) {
+			if weight_meter
+				.try_consume(<T as Config>::WeightInfo::get_game_schedules(
+					T::MaxGameSchedules::get(),
+				))
+				.is_err()
+			{
+				return;
+			}
+			let mut schedules = <GameSchedules<T>>::get();
+			let Some(index) = schedules.iter().position(|schedule| schedule.region == region)
+			else {
+				return;
+			};
+			let weight = <T as Config>::WeightInfo::new_game()
+				.saturating_add(<T as Config>::WeightInfo::put_game_schedules());
 
-						let schedule = schedules.remove(0);
-						let _ = Self::new_game(&schedule).inspect_err(|e| {
-							log::error!(
-								target: LOG_TARGET,
-								"Failed to start new game from schedule {e:?}",
-							);
-						});
-						// Regardless the outcome of new_game call, the next scheduled game is
-						// removed. If new_game call fails, regardless of the reason, that
-						// game must be removed from scheduled ones as the call will never
-						// succeed. The reason for failure may be that the previous game took
-						// longer to finish than expected.
-						GameSchedules::<T>::put(schedules);
-					}
-				},
-				Some(game) => Self::process_game(weight_meter, n, game),
+			if weight_meter.try_consume(weight).is_err() {
+				return;
 			}
+
+			let schedule = schedules.remove(index);
+			let _ = Self::new_game(&schedule).inspect_err(|e| {
+				log::error!(
+					target: LOG_TARGET,
+					"Failed to start new game from schedule {e:?}",
+				);
+			});
+			// Regardless the outcome of new_game call, the next scheduled game is
+			// removed. If new_game call fails, regardless of the reason, that
+			// game must be removed from scheduled ones as the call will never
+			// succeed. The reason for failure may be that the previous game took
+			// longer to finish than expected.
+			GameSchedules::<T>::put(schedules);
 		}
 
 		/// Create a new game.
 		///
 		/// Parameters:
-		/// - `schedule`: The schedule of the game.
+		/// - `schedule`: The schedule of the game, the game is created in its region.
 		pub(crate) fn new_game(schedule: &GameScheduleOf<T>) -> DispatchResult {
-			ensure!(!Game::<T>::exists(), Error::<T>::GameOngoing);
+			let region = schedule.region;
+			ensure!(u32::from(region) < T::MaxRegions::get(), Error::<T>::InvalidGameSetup);
+			ensure!(!Game::<T>::contains_key(region), Error::<T>::GameOngoing);
 
 			let now = T::UnixTime::now();
 
@@ -1654,8 +2261,7 @@ pub mod pallet {
 				*index = index.saturating_add(1);
 				*index
 			});
-			let phases =
-				StoredPhaseDurations::<T>::get().unwrap_or_else(T::DefaultPhaseDurations::get);
+			let phases = GameTimes::<T>::phases(schedule);
 			let airdrop_end_time = (game_play_time as u64)
 				.saturating_add(phases.reporting as u64)
 				.saturating_add(phases.airdrop_claim_window as u64);
@@ -1668,26 +2274,34 @@ pub mod pallet {
 				airdrop_end_time,
 				schedule,
 			);
-			Game::<T>::put(GameInfo {
-				index,
-				registration_ends,
-				game_date: game_play_time,
-				shuffle_deadline: GameTimes::<T>::shuffle_deadline(schedule),
-				report_ends,
-				state: GameState::Registration { next_player_index: 0 },
-				max_group_size: schedule.max_group_size,
-				rounds: schedule.rounds,
-				pending_attendance: 0,
-				airdrop_scheduled,
-			});
+			Game::<T>::insert(
+				region,
+				GameInfo {
+					index,
+					region,
+					registration_ends,
+					game_date: game_play_time,
+					shuffle_deadline: GameTimes::<T>::shuffle_deadline(schedule),
+					report_ends,
+					state: GameState::Registration { next_player_index: 0 },
+					max_group_size: schedule.max_group_size,
+					rounds: schedule.rounds,
+					pending_attendance: 0,
+					airdrop_scheduled,
+					attendance_rule: schedule.attendance_rule,
+					group_assignment: schedule.group_assignment,
+					phases,
+				},
+			);
 			GameHistory::<T>::insert(index, game_play_time);
 
 			log::trace!(
 				target: LOG_TARGET,
-				"New game, index: {index}"
+				"New game, index: {index}, region: {region}"
 			);
 
 			Self::deposit_event(Event::NewGame {
+				region,
 				registration_ends,
 				game_date: game_play_time,
 				report_ends,
@@ -1755,6 +2369,7 @@ pub mod pallet {
 
 		/// **Warning**: Storage must be rollbacked on error.
 		fn sign_up_inner(
+			region: GameRegion,
 			args: SignUpArgs<T::AccountId, T::AccountSignature, AirdropProofOf<T>>,
 		) -> DispatchResultWithPostInfo {
 			// Some pre-condition here are duplicated in validation checks in `GameAsInvited`
@@ -1769,7 +2384,7 @@ pub mod pallet {
 			// `sign_up_with_invite`.
 
 			// Check the game state.
-			let mut game = Game::<T>::get().ok_or(Error::<T>::NoGame)?;
+			let mut game = Game::<T>::get(region).ok_or(Error::<T>::NoGame)?;
 			ensure!(
 				T::UnixTime::now() < Duration::from_secs(game.registration_ends as u64),
 				Error::<T>::NoRegistration
@@ -1848,7 +2463,7 @@ pub mod pallet {
 			// Ensure the player is not playing if using an invite.
 			ensure!(!new_invited || !already_playing, Error::<T>::UseInviteButAlreadyPlaying);
 
-			// Ensure the player isn't registered yet for the game
+			// Ensure the player isn't registered yet for the game of any region.
 			ensure!(
 				maybe_player.as_ref().is_none_or(|player| !player.registered),
 				Error::<T>::AlreadyRegistered
@@ -1889,6 +2504,7 @@ pub mod pallet {
 				&who,
 				Player {
 					first_game,
+					region,
 					registered: true,
 					sent_report: false,
 					early_attendance_enactment: None,
@@ -1910,7 +2526,7 @@ pub mod pallet {
 			}
 
 			next_player_index.saturating_inc();
-			Game::<T>::put(game);
+			Game::<T>::insert(region, game);
 
 			Self::deposit_event(Event::<T>::SignedUp { who: who.clone() });
 
@@ -2049,13 +2665,15 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// Writes into `ShuffleRecognized` and `ShuffleNotRecognized`.
+		/// Writes into `ShuffleRecognized` and `ShuffleNotRecognized` for the game of `region`.
 		/// Update the `last_player_key` to the inserted player.
 		/// Update the `pending_attendance` for the game when a registered player is inserted.
 		pub(crate) fn shuffle_step_insert(
+			region: GameRegion,
 			last_player_key: &mut Option<AccountOrPerson<T::AccountId>>,
 			pending_attendance: &mut u32,
 			rounds: u8,
+			attendance_rule: &AttendanceRuleKind,
 			parent_hash: &T::Hash,
 		) -> StepResult {
 			let next_player = last_player_key
@@ -2071,7 +2689,7 @@ pub mod pallet {
 
 			// Ideally we should iterate on registered players only, or at least refund the weight
 			// when skipping this part.
-			if player_info.registered {
+			if player_info.registered && player_info.region == region {
 				*pending_attendance = pending_attendance.saturating_add(1);
 
 				let player_recognized_as_person =
@@ -2080,11 +2698,7 @@ pub mod pallet {
 				// Snapshot the player's vote weight so it stays consistent across the
 				// reporting phase even if their personhood changes mid-game (e.g. via
 				// early attendance enactment).
-				let vote_weight = if player_recognized_as_person {
-					T::PeopleVoteWeight::get()
-				} else {
-					T::CandidateVoteWeight::get()
-				};
+				let vote_weight = T::AttendanceRule::vote_weight(attendance_rule, &player_id);
 				Players::<T>::mutate(&player_id, |maybe_player| {
 					if let Some(player) = maybe_player.as_mut() {
 						player.vote_weight = vote_weight;
@@ -2099,9 +2713,9 @@ pub mod pallet {
 					);
 
 					if player_recognized_as_person {
-						ShuffleRecognized::<T>::insert(round, hash, &player_id);
+						ShuffleRecognized::<T>::insert((region, round), hash, &player_id);
 					} else {
-						ShuffleNotRecognized::<T>::insert(round, hash, &player_id);
+						ShuffleNotRecognized::<T>::insert((region, round), hash, &player_id);
 					}
 
 					log::trace!(target: LOG_TARGET, "Shuffle: 1 player inserted in round {round}");
@@ -2113,18 +2727,20 @@ pub mod pallet {
 			StepResult::Continue
 		}
 
-		/// Read and Write into `PlayerToIndex`, `IndexToPlayer`, `ShuffleRecognized`.
+		/// Read and Write into `PlayerToIndex`, `IndexToPlayer`, `ShuffleRecognized` for the game
+		/// of `region`.
 		/// Update the `next_player_index` to the next index if one is processed.
 		pub(crate) fn shuffle_step_retrieve(
+			region: GameRegion,
 			next_player_index: &mut PlayerIndex,
 			recognized_finished: &mut bool,
 			rounds: u8,
 		) -> StepResult {
 			for round in 0..rounds {
 				let next_recognized_player = if *recognized_finished {
-					ShuffleNotRecognized::<T>::drain_prefix(round).next()
+					ShuffleNotRecognized::<T>::drain_prefix((region, round)).next()
 				} else {
-					ShuffleRecognized::<T>::drain_prefix(round).next()
+					ShuffleRecognized::<T>::drain_prefix((region, round)).next()
 				};
 
 				let Some((_hash, player_id)) = next_recognized_player else {
@@ -2138,18 +2754,19 @@ pub mod pallet {
 					}
 				};
 
-				let mut indices = PlayerToIndex::<T>::get(&player_id).unwrap_or_else(|| {
-					let mut indices = BoundedVec::default();
-					indices.bounded_resize(usize::from(rounds), 0);
-					indices
-				});
+				let mut indices =
+					PlayerToIndex::<T>::get(region, &player_id).unwrap_or_else(|| {
+						let mut indices = BoundedVec::default();
+						indices.bounded_resize(usize::from(rounds), 0);
+						indices
+					});
 				if let Some(index) = indices.get_mut(usize::from(round)).defensive_proof(
 					"indiv-pallet-game: indices are initialized with length = rounds",
 				) {
 					*index = *next_player_index;
 				}
-				PlayerToIndex::<T>::insert(&player_id, &indices);
-				IndexToPlayer::<T>::insert((round, *next_player_index), &player_id);
+				PlayerToIndex::<T>::insert(region, &player_id, &indices);
+				IndexToPlayer::<T>::insert(region, (round, *next_player_index), &player_id);
 
 				log::trace!(target: LOG_TARGET, "Shuffle: player {player_id:?}, indices: {indices:?}");
 			}
@@ -2159,20 +2776,149 @@ pub mod pallet {
 			StepResult::Continue
 		}
 
+		/// Spread the players of the game of `region` away from their recent co-players, one index
+		/// of one round at a time.
+		///
+		/// The player at `index` is swapped with the player at the next index, which is in another
+		/// group, if it reduces the number of recent co-players grouped with either of them. A
+		/// player grouped with recent co-players thus moves forward until a better group is found.
+		/// Updates `round` and `index` to the next position to process.
+		///
+		/// Read and Write into `PlayerToIndex`, `IndexToPlayer` for the game of `region`, reads
+		/// `RecentCoPlayers`.
+		pub(crate) fn shuffle_step_spread_pairings(
+			region: GameRegion,
+			round: &mut RoundIndex,
+			index: &mut PlayerIndex,
+			rounds: u8,
+			groups_setting: &GroupsSetting,
+		) -> StepResult {
+			if *round >= rounds {
+				return StepResult::Finished;
+			}
+
+			let next_index = index.saturating_add(1);
+			let group_index = groups_setting.group_index_from_player_index(*index);
+			let next_group_index = groups_setting.group_index_from_player_index(next_index);
+			if next_index >= groups_setting.player_count || group_index == next_group_index {
+				// End of the round, or a single group: nothing to spread.
+				*round = round.saturating_add(1);
+				*index = 0;
+				return StepResult::Continue;
+			}
+
+			let player = IndexToPlayer::<T>::get(region, (*round, *index));
+			let next_player = IndexToPlayer::<T>::get(region, (*round, next_index));
+			let (Some(player), Some(next_player)) = (player, next_player) else {
+				defensive!("indiv-pallet-game: index should map to a player");
+				*index = next_index;
+				return StepResult::Continue;
+			};
+
+			let group_members = |group_index: PlayerIndex, excluded: PlayerIndex| {
+				groups_setting
+					.group_members(group_index)
+					.filter(|&member_index| member_index != excluded)
+					.filter_map(|member_index| {
+						IndexToPlayer::<T>::get(region, (*round, member_index))
+					})
+					.collect::<Vec<_>>()
+			};
+			let count_avoided = |members: &[AccountOrPerson<T::AccountId>],
+			                     avoided: &[AccountOrPerson<T::AccountId>]| {
+				members.iter().filter(|member| avoided.contains(member)).count()
+			};
+
+			let members = group_members(group_index, *index);
+			let avoided = Self::avoided_co_players(region, &player, *round, groups_setting);
+			let conflicts = count_avoided(&members, &avoided);
+
+			if conflicts > 0 {
+				let next_members = group_members(next_group_index, next_index);
+				let next_avoided =
+					Self::avoided_co_players(region, &next_player, *round, groups_setting);
+
+				let before = conflicts.saturating_add(count_avoided(&next_members, &next_avoided));
+				let after = count_avoided(&next_members, &avoided)
+					.saturating_add(count_avoided(&members, &next_avoided));
+
+				if after < before {
+					IndexToPlayer::<T>::insert(region, (*round, *index), &next_player);
+					IndexToPlayer::<T>::insert(region, (*round, next_index), &player);
+					for (player, new_index) in [(&player, next_index), (&next_player, *index)] {
+						PlayerToIndex::<T>::mutate(region, player, |maybe_indices| {
+							if let Some(index) = maybe_indices
+								.as_mut()
+								.and_then(|indices| indices.get_mut(usize::from(*round)))
+							{
+								*index = new_index;
+							} else {
+								defensive!("indiv-pallet-game: player should have an index");
+							}
+						});
+					}
+					log::trace!(
+						target: LOG_TARGET,
+						"Shuffle: swapped {player:?} and {next_player:?} in round {round}",
+					);
+				}
+			}
+
+			*index = next_index;
+
+			StepResult::Continue
+		}
+
+		/// The co-players `player` should avoid in `round` of the game of `region`: their recent
+		/// co-players and their co-players in the previous rounds of the game.
+		fn avoided_co_players(
+			region: GameRegion,
+			player: &AccountOrPerson<T::AccountId>,
+			round: RoundIndex,
+			groups_setting: &GroupsSetting,
+		) -> Vec<AccountOrPerson<T::AccountId>> {
+			let mut avoided = RecentCoPlayers::<T>::get(player).into_inner();
+			let Some(indices) = PlayerToIndex::<T>::get(region, player) else {
+				defensive!("indiv-pallet-game: player should have indices");
+				return avoided;
+			};
+
+			for (previous_round, &player_index) in (0..round).zip(indices.iter()) {
+				let group_index = groups_setting.group_index_from_player_index(player_index);
+				avoided.extend(
+					groups_setting
+						.group_members(group_index)
+						.filter(|&member_index| member_index != player_index)
+						.filter_map(|member_index| {
+							IndexToPlayer::<T>::get(region, (previous_round, member_index))
+						}),
+				);
+			}
+
+			avoided
+		}
+
 		/// Compute the exact `expected_max_vote_weight` for one registered player by summing
 		/// the personhood-derived vote weights of all their co-players across every round.
 		///
-		/// Reads `PlayerToIndex`, `IndexToPlayer`, and the score pallet's `reached_personhood`,
-		/// then writes the result into `Players`.
+		/// Reads `PlayerToIndex`, `IndexToPlayer` of the game of `region`, and the score pallet's
+		/// `reached_personhood`, then writes the result into `Players`.
+		/// With [`GroupAssignment::SpreadPairings`], also records the co-players into
+		/// `RecentCoPlayers`.
 		pub(crate) fn shuffle_step_compute_weights(
+			region: GameRegion,
 			last_iteration: &mut Option<AccountOrPerson<T::AccountId>>,
 			rounds: u8,
 			player_count: u32,
 			max_per_group: u32,
+			group_assignment: GroupAssignment,
 		) -> StepResult {
 			let mut remaining_players = match last_iteration.clone() {
-				None => PlayerToIndex::<T>::iter(),
-				Some(last) => PlayerToIndex::<T>::iter_from_key(last),
+				None => PlayerToIndex::<T>::iter_prefix(region),
+				Some(last) => PlayerToIndex::<T>::iter_prefix_from(
+					region,
+					PlayerToIndex::<T>::hashed_key_for(region, last),
+				),
 			};
 
 			let Some((player_id, round_indices)) = remaining_players.next() else {
@@ -2181,6 +2927,7 @@ pub mod pallet {
 
 			let groups_setting = GroupsSetting { max_per_group, player_count };
 			let mut total_weight: u32 = 0;
+			let mut co_players = Vec::new();
 
 			for round in 0..rounds {
 				let Some(&player_idx) = round_indices
@@ -2196,7 +2943,7 @@ pub mod pallet {
 					if member_idx == player_idx {
 						continue;
 					}
-					let Some(member_id) = IndexToPlayer::<T>::get((round, member_idx))
+					let Some(member_id) = IndexToPlayer::<T>::get(region, (round, member_idx))
 						.defensive_proof("indiv-pallet-game: index should map to a player")
 					else {
 						continue;
@@ -2210,9 +2957,24 @@ pub mod pallet {
 						continue;
 					};
 					total_weight = total_weight.saturating_add(member.vote_weight as u32);
+					co_players.push(member_id);
 				}
 			}
 
+			if group_assignment == GroupAssignment::SpreadPairings {
+				RecentCoPlayers::<T>::mutate(&player_id, |recent| {
+					for co_player in co_players {
+						// Keep a single entry for each co-player, at the most recent position.
+						recent.retain(|recent_co_player| *recent_co_player != co_player);
+						if recent.is_full() {
+							recent.remove(0);
+						}
+						// Fails only when `MaxRecentCoPlayers` is zero.
+						let _ = recent.try_push(co_player);
+					}
+				});
+			}
+
 			let expected_max_vote_weight: u16 = total_weight.try_into().unwrap_or(u16::MAX);
 
 			Players::<T>::mutate(&player_id, |maybe_player| {
@@ -2253,7 +3015,7 @@ pub mod pallet {
 			if now > Duration::from_secs(game.shuffle_deadline.into()) {
 				Self::on_game_cancelled(&game);
 				game.state = GameState::Cancelling { last_iteration: None };
-				Game::<T>::put(game);
+				Game::<T>::insert(game.region, game);
 				return;
 			}
 
@@ -2272,9 +3034,11 @@ pub mod pallet {
 						}
 
 						let step_result = Self::shuffle_step_insert(
+							game.region,
 							last_iteration,
 							&mut game.pending_attendance,
 							game.rounds,
+							&game.attendance_rule,
 							&parent_hash,
 						);
 
@@ -2302,22 +3066,68 @@ pub mod pallet {
 						}
 
 						let step_result = Self::shuffle_step_retrieve(
+							game.region,
 							next_player_index,
 							recognized_finished,
 							game.rounds,
 						);
 
+						match step_result {
+							StepResult::Finished => match game.group_assignment {
+								GroupAssignment::Shuffled => {
+									*step = ShuffleStep::Step4ComputeWeights {
+										last_iteration: None,
+										player_count: *next_player_index,
+									};
+								},
+								GroupAssignment::SpreadPairings => {
+									*step = ShuffleStep::Step3SpreadPairings {
+										round: 0,
+										index: 0,
+										player_count: *next_player_index,
+									};
+								},
+							},
+							StepResult::Continue => {},
+						}
+					},
+					ShuffleStep::Step3SpreadPairings {
+						ref mut round,
+						ref mut index,
+						player_count,
+					} => {
+						if weight_meter
+							.try_consume(<T as Config>::WeightInfo::shuffle_step_spread_pairings(
+								game.max_group_size,
+								game.rounds.into(),
+							))
+							.is_err()
+						{
+							break;
+						}
+
+						let step_result = Self::shuffle_step_spread_pairings(
+							game.region,
+							round,
+							index,
+							game.rounds,
+							&GroupsSetting {
+								max_per_group: game.max_group_size,
+								player_count: *player_count,
+							},
+						);
+
 						match step_result {
 							StepResult::Finished => {
-								*step = ShuffleStep::Step3ComputeWeights {
+								*step = ShuffleStep::Step4ComputeWeights {
 									last_iteration: None,
-									player_count: *next_player_index,
+									player_count: *player_count,
 								};
 							},
 							StepResult::Continue => {},
 						}
 					},
-					ShuffleStep::Step3ComputeWeights { ref mut last_iteration, player_count } => {
+					ShuffleStep::Step4ComputeWeights { ref mut last_iteration, player_count } => {
 						if weight_meter
 							.try_consume(<T as Config>::WeightInfo::shuffle_step_compute_weights(
 								game.max_group_size,
@@ -2329,21 +3139,23 @@ pub mod pallet {
 						}
 
 						let step_result = Self::shuffle_step_compute_weights(
+							game.region,
 							last_iteration,
 							game.rounds,
 							*player_count,
 							game.max_group_size,
+							game.group_assignment,
 						);
 
 						match step_result {
 							StepResult::Finished => {
 								*step =
-									ShuffleStep::Step4AwaitSession { player_count: *player_count };
+									ShuffleStep::Step5AwaitSession { player_count: *player_count };
 							},
 							StepResult::Continue => {},
 						}
 					},
-					ShuffleStep::Step4AwaitSession { player_count } => {
+					ShuffleStep::Step5AwaitSession { player_count } => {
 						if weight_meter
 							.try_consume(<T as Config>::WeightInfo::shuffle_step_start_session())
 							.is_err()
@@ -2370,11 +3182,12 @@ pub mod pallet {
 				}
 			}
 
-			Game::<T>::put(game);
+			Game::<T>::insert(game.region, game);
 		}
 
-		/// Process the reporting phase: end the reporting when the conditions are met.
-		pub(crate) fn process_reporting(weight_meter: &mut WeightMeter) {
+		/// Process the reporting phase of the game of `region`: end the reporting when the
+		/// conditions are met.
+		pub(crate) fn process_reporting(weight_meter: &mut WeightMeter, region: GameRegion) {
 			if weight_meter
 				.try_consume(<T as Config>::WeightInfo::process_reporting())
 				.is_err()
@@ -2382,7 +3195,7 @@ pub mod pallet {
 				return;
 			}
 
-			let Some(mut game) = Game::<T>::get() else {
+			let Some(mut game) = Game::<T>::get(region) else {
 				defensive!("game should exist while processing reporting");
 				return;
 			};
@@ -2403,16 +3216,16 @@ pub mod pallet {
 			}
 
 			game.state = GameState::PlayerProcess {
-				step: PlayerProcessStep::Step1ProcessPlayers { last_iteration: None, player_count },
+				step: PlayerProcessStep::Step0ScoreReports { player_count },
 			};
-			Game::<T>::put(game);
+			Game::<T>::insert(region, game);
 		}
 
 		/// Attempt to enact the attendance of a player early.
 		///
 		/// This is a no-op if the player does not exist, has already been early-enacted,
-		/// or if their attendance is still pending. Reads and writes `Game` storage
-		/// directly.
+		/// or if their attendance is still pending. Reads and writes the `Game` storage of the
+		/// region of the player directly.
 		///
 		/// Returns `true` if the full enactment path ran (i.e. attendance was applied and
 		/// the NFT routing performed), `false` for every early-bail case.
@@ -2423,11 +3236,11 @@ pub mod pallet {
 			if player.early_attendance_enactment.is_some() {
 				return false;
 			}
-			let Some(mut game) = Game::<T>::get() else {
+			let Some(mut game) = Game::<T>::get(player.region) else {
 				defensive!("indiv-pallet-game: game should exist");
 				return false;
 			};
-			let attendance = match Self::determine_attendance(&player) {
+			let attendance = match Self::determine_attendance(&player, &game.attendance_rule) {
 				AttendanceStatus::Attended => true,
 				AttendanceStatus::NotAttended => false,
 				AttendanceStatus::Pending => return false,
@@ -2445,10 +3258,19 @@ pub mod pallet {
 				game.index,
 				&mut game.pending_attendance,
 			);
+			Self::note_attendance_dispute(
+				player_id,
+				&player,
+				&result,
+				game.index,
+				game.region,
+				GroupsSetting { max_per_group: game.max_group_size, player_count },
+			);
 
 			let mint_time = T::UnixTime::now().as_secs() as u32;
 			Self::apply_attendance_to_nfts(
 				game.index,
+				game.region,
 				game.rounds,
 				game.max_group_size,
 				player_count,
@@ -2457,7 +3279,7 @@ pub mod pallet {
 				mint_time,
 			);
 
-			Game::<T>::put(game);
+			Game::<T>::insert(game.region, game);
 
 			// Cache the result on the player so `player_process_step1` can reuse it instead
 			// of calling the score pallet a second time.
@@ -2521,8 +3343,89 @@ pub mod pallet {
 			EarlyAttendanceEnactment { attendance, disposition }
 		}
 
-		/// Determine the attendance status of a player given a partial vote state.
-		pub(crate) fn determine_attendance(player: &Player<T::PlayDeposit>) -> AttendanceStatus {
+		/// Record the absence of `player_id` from game `game_index` in [`AttendanceDisputes`] if
+		/// they were reported as not being a person and remain a player.
+		///
+		/// Any dispute of an earlier absence is dropped, even if a case is open: the score pallet
+		/// can only restore the last game recorded for the player.
+		pub(crate) fn note_attendance_dispute(
+			player_id: &AccountOrPerson<T::AccountId>,
+			player: &Player<T::PlayDeposit>,
+			enactment: &EarlyAttendanceEnactment,
+			game_index: u32,
+			region: GameRegion,
+			groups_setting: GroupsSetting,
+		) {
+			Self::drop_attendance_dispute(player_id);
+			if enactment.attendance ||
+				enactment.disposition != PlayerDisposition::Keep ||
+				!player.registered ||
+				player.no_not_person == 0
+			{
+				return;
+			}
+
+			let Some(indices) = PlayerToIndex::<T>::get(region, player_id)
+				.defensive_proof("indiv-pallet-game: registered player should have indices")
+			else {
+				return;
+			};
+			let groups = indices
+				.iter()
+				.map(|&index| groups_setting.group_index_from_player_index(index))
+				.collect::<Vec<_>>();
+			let Ok(groups) = GameGroups::try_from(groups)
+				.defensive_proof("indiv-pallet-game: rounds are bounded by `MAX_GAME_ROUNDS`")
+			else {
+				return;
+			};
+
+			let dispute_until = frame_system::Pallet::<T>::block_number()
+				.saturating_add(T::AttendanceDisputeWindow::get());
+			AttendanceDisputes::<T>::insert(
+				player_id,
+				AttendanceDispute {
+					game_index,
+					region,
+					groups,
+					dispute_until,
+					case: None,
+					deposit: None,
+				},
+			);
+		}
+
+		/// Remove the attendance dispute of `who`, releasing its deposit.
+		fn drop_attendance_dispute(who: &AccountOrPerson<T::AccountId>) {
+			if let Some(dispute) = AttendanceDisputes::<T>::take(who) {
+				Self::release_dispute_deposit(who, dispute.deposit, false);
+			}
+		}
+
+		/// Release the deposit of an attendance dispute of `who`, burning it if `slash`.
+		fn release_dispute_deposit(
+			who: &AccountOrPerson<T::AccountId>,
+			deposit: Option<T::AttendanceDisputeDeposit>,
+			slash: bool,
+		) {
+			let (Some(deposit), AccountOrPerson::Account(account)) = (deposit, who) else {
+				return;
+			};
+			if slash {
+				deposit.burn(account);
+			} else {
+				let _ = deposit
+					.drop(account)
+					.defensive_proof("indiv-pallet-game: dispute deposit was held from account");
+			}
+		}
+
+		/// Determine the attendance status of a player given a partial vote state, under the
+		/// attendance rule of the game.
+		pub(crate) fn determine_attendance(
+			player: &Player<T::PlayDeposit>,
+			attendance_rule: &AttendanceRuleKind,
+		) -> AttendanceStatus {
 			if !player.registered {
 				return AttendanceStatus::NotAttended;
 			}
@@ -2538,7 +3441,7 @@ pub mod pallet {
 			// attendance vote still fails, the player cannot attend regardless of future
 			// reports.
 			let best_case_yes = yes.saturating_add(remaining);
-			if best_case_yes.saturating_sub(1) < no {
+			if !T::AttendanceRule::is_person(attendance_rule, best_case_yes, no) {
 				return AttendanceStatus::NotAttended;
 			}
 
@@ -2547,7 +3450,7 @@ pub mod pallet {
 			// are definitely attending.
 			if player.sent_report {
 				let worst_case_no = no.saturating_add(remaining);
-				if yes.saturating_sub(1) >= worst_case_no {
+				if T::AttendanceRule::is_person(attendance_rule, yes, worst_case_no) {
 					return AttendanceStatus::Attended;
 				}
 			}
@@ -2563,8 +3466,10 @@ pub mod pallet {
 		/// - Updates `last_iteration` and returns the next item from `iterator`.
 		pub(crate) fn process_player_attendance_outcome(
 			game_index: u32,
+			region: GameRegion,
 			rounds: u8,
 			max_group_size: u32,
+			attendance_rule: &AttendanceRuleKind,
 			pending_attendance: &mut u32,
 			player_count: u32,
 			last_iteration: &mut Option<AccountOrPerson<T::AccountId>>,
@@ -2578,8 +3483,12 @@ pub mod pallet {
 				if let Some(cached) = player.early_attendance_enactment {
 					cached
 				} else {
-					let half_plus_one = player.yes_person.saturating_sub(1) >= player.no_not_person;
-					let attendance = player.registered && player.sent_report && half_plus_one;
+					let is_person = T::AttendanceRule::is_person(
+						attendance_rule,
+						player.yes_person.into(),
+						player.no_not_person.into(),
+					);
+					let attendance = player.registered && player.sent_report && is_person;
 
 					let enactment = Self::apply_attendance(
 						&player_id,
@@ -2588,8 +3497,17 @@ pub mod pallet {
 						game_index,
 						pending_attendance,
 					);
+					Self::note_attendance_dispute(
+						&player_id,
+						&player,
+						&enactment,
+						game_index,
+						region,
+						GroupsSetting { max_per_group: max_group_size, player_count },
+					);
 					Self::apply_attendance_to_nfts(
 						game_index,
+						region,
 						rounds,
 						max_group_size,
 						player_count,
@@ -2662,6 +3580,7 @@ pub mod pallet {
 					&player_id,
 					Player {
 						first_game: player.first_game,
+						region,
 						registered: false,
 						sent_report: false,
 						early_attendance_enactment: None,
@@ -2683,7 +3602,147 @@ pub mod pallet {
 			iterator.next()
 		}
 
-		pub(crate) fn player_process_step1(weight_meter: &mut WeightMeter) {
+		/// Score the consistency of the submitted reports of the game of `region`, one reporter at
+		/// a time, then continue with step 1 in the same block once every report has been scored.
+		pub(crate) fn player_process_step0(weight_meter: &mut WeightMeter, region: GameRegion) {
+			if weight_meter
+				.try_consume(<T as Config>::WeightInfo::player_process_step0())
+				.is_err()
+			{
+				return;
+			}
+
+			let Some(mut game) = Game::<T>::get(region) else {
+				defensive!("indiv-pallet-game: game should exist while processing players");
+				return;
+			};
+
+			let GameState::PlayerProcess {
+				step: PlayerProcessStep::Step0ScoreReports { player_count },
+			} = game.state
+			else {
+				defensive!("indiv-pallet-game: game state is not process player step 0");
+				return;
+			};
+
+			let per_reporter_weight =
+				<T as Config>::WeightInfo::player_process_step0_reporter(game.rounds.into());
+
+			for _ in 0..OP_UPPER_BOUND {
+				if weight_meter.try_consume(per_reporter_weight).is_err() {
+					return;
+				}
+
+				// Reports are removed as they are scored, so the next one is always the first.
+				let Some((reporter, full_report)) =
+					SubmittedReports::<T>::drain_prefix(region).next()
+				else {
+					game.state = GameState::PlayerProcess {
+						step: PlayerProcessStep::Step1ProcessPlayers {
+							last_iteration: None,
+							player_count,
+						},
+					};
+					Game::<T>::insert(region, game);
+					Self::player_process_step1(weight_meter, region);
+					return;
+				};
+
+				Self::score_report_consistency(&game, player_count, &reporter, &full_report);
+			}
+		}
+
+		/// Score how the report of `reporter` agreed with the outcome of the reported players
+		/// under the attendance rule of the game, and penalize persistently inconsistent
+		/// reporters.
+		///
+		/// Must be called before the tallies of the players are reset in step 1.
+		pub(crate) fn score_report_consistency(
+			game: &GameInfo<T::AccountId>,
+			player_count: u32,
+			reporter: &AccountOrPerson<T::AccountId>,
+			full_report: &FullReport<T>,
+		) {
+			let Some(reporter_indices) = PlayerToIndex::<T>::get(game.region, reporter)
+				.defensive_proof("indiv-pallet-game: reporter should have indices")
+			else {
+				return;
+			};
+
+			let groups_setting = GroupsSetting { max_per_group: game.max_group_size, player_count };
+			let mut agreed: u32 = 0;
+			let mut total: u32 = 0;
+			for ((round, round_report), &reporter_index) in
+				(0..game.rounds).zip(full_report.iter()).zip(reporter_indices.iter())
+			{
+				let group_index = groups_setting.group_index_from_player_index(reporter_index);
+				let other_people_in_group =
+					groups_setting.group_members(group_index).filter(|&i| i != reporter_index);
+
+				for (reported_index, report) in other_people_in_group.zip(round_report.iter()) {
+					let Some(reported_info) =
+						IndexToPlayer::<T>::get(game.region, (round, reported_index))
+							.and_then(|reported_player| Players::<T>::get(reported_player))
+					else {
+						defensive!("indiv-pallet-game: reported player should exist");
+						continue;
+					};
+
+					let is_person = T::AttendanceRule::is_person(
+						&game.attendance_rule,
+						reported_info.yes_person.into(),
+						reported_info.no_not_person.into(),
+					);
+					total.saturating_inc();
+					if is_person == (*report == Report::Person) {
+						agreed.saturating_inc();
+					}
+				}
+			}
+
+			let consistent = T::MinReportConsistency::get().mul_ceil(total) <= agreed;
+			let inconsistent_streak = if consistent {
+				InconsistentReportStreak::<T>::remove(reporter);
+				0
+			} else {
+				InconsistentReportStreak::<T>::mutate(reporter, |streak| {
+					streak.saturating_inc();
+					*streak
+				})
+			};
+
+			Self::deposit_event(Event::<T>::ReportConsistencyScored {
+				who: reporter.clone(),
+				game_index: game.index,
+				agreed,
+				total,
+				inconsistent_streak,
+			});
+
+			let penalty = T::InconsistentReportPenalty::get();
+			if consistent ||
+				penalty.is_zero() ||
+				inconsistent_streak < T::MaxInconsistentReportStreak::get()
+			{
+				return;
+			}
+
+			if let Ok(score) = indiv_pallet_score::Pallet::<T>::reduce_score(reporter, penalty)
+				.defensive_proof("indiv-pallet-game: reporter should have a score")
+			{
+				Self::deposit_event(Event::<T>::InconsistentReporterPenalized {
+					who: reporter.clone(),
+					game_index: game.index,
+					penalty,
+					score,
+				});
+			}
+		}
+
+		/// Settle the attendance of the players of the region of the game, one player at a time.
+		///
+		/// Players of other regions are skipped: they are processed by the game of their region.
+		pub(crate) fn player_process_step1(weight_meter: &mut WeightMeter, region: GameRegion) {
 			if weight_meter
 				.try_consume(<T as Config>::WeightInfo::player_process_step1())
 				.is_err()
@@ -2691,7 +3750,7 @@ pub mod pallet {
 				return;
 			}
 
-			let Some(mut game) = Game::<T>::get() else {
+			let Some(mut game) = Game::<T>::get(region) else {
 				defensive!("game should exist while processing players");
 				return;
 			};
@@ -2721,14 +3780,22 @@ pub mod pallet {
 			for _ in 0..OP_UPPER_BOUND {
 				let Some(next_player_to_process) = next_player.take() else { break };
 				if weight_meter.try_consume(per_player_weight).is_err() {
-					Game::<T>::put(game);
+					Game::<T>::insert(region, game);
 					return;
 				}
 
+				if next_player_to_process.1.region != region {
+					*last_iteration = Some(next_player_to_process.0);
+					next_player = iterator.next();
+					continue;
+				}
+
 				next_player = Self::process_player_attendance_outcome(
 					game.index,
+					region,
 					game.rounds,
 					game.max_group_size,
+					&game.attendance_rule,
 					&mut game.pending_attendance,
 					player_count,
 					last_iteration,
@@ -2747,29 +3814,38 @@ pub mod pallet {
 				game.state =
 					GameState::PlayerProcess { step: PlayerProcessStep::Step2ClearIndices };
 			}
-			Game::<T>::put(game);
+			Game::<T>::insert(region, game);
 		}
 
-		/// Clear one bounded chunk from each per-game index <-> player map.
+		/// Clear one bounded chunk from each per-game index <-> player map of `region`.
 		pub(crate) fn player_process_step2_inner_loop(
+			region: GameRegion,
 			cursor1: &mut Option<Vec<u8>>,
 			cursor2: &mut Option<Vec<u8>>,
 			done1: &mut bool,
 			done2: &mut bool,
 		) {
 			if !*done1 {
-				let r = IndexToPlayer::<T>::clear(PLAYER_PROCESS_STEP2_CHUNK, cursor1.as_deref());
+				let r = IndexToPlayer::<T>::clear_prefix(
+					region,
+					PLAYER_PROCESS_STEP2_CHUNK,
+					cursor1.as_deref(),
+				);
 				*cursor1 = r.maybe_cursor;
 				*done1 = cursor1.is_none();
 			}
 			if !*done2 {
-				let r = PlayerToIndex::<T>::clear(PLAYER_PROCESS_STEP2_CHUNK, cursor2.as_deref());
+				let r = PlayerToIndex::<T>::clear_prefix(
+					region,
+					PLAYER_PROCESS_STEP2_CHUNK,
+					cursor2.as_deref(),
+				);
 				*cursor2 = r.maybe_cursor;
 				*done2 = cursor2.is_none();
 			}
 		}
 
-		pub(crate) fn player_process_step2(weight_meter: &mut WeightMeter) {
+		pub(crate) fn player_process_step2(weight_meter: &mut WeightMeter, region: GameRegion) {
 			if weight_meter
 				.try_consume(<T as Config>::WeightInfo::player_process_step2())
 				.is_err()
@@ -2777,7 +3853,7 @@ pub mod pallet {
 				return;
 			}
 
-			let Some(game) = Game::<T>::get() else {
+			let Some(game) = Game::<T>::get(region) else {
 				defensive!("indiv-pallet-game: game should exist while processing players");
 				return;
 			};
@@ -2802,6 +3878,7 @@ pub mod pallet {
 				}
 
 				Self::player_process_step2_inner_loop(
+					region,
 					&mut cursor1,
 					&mut cursor2,
 					&mut done1,
@@ -2822,11 +3899,13 @@ pub mod pallet {
 			}
 
 			Self::deposit_event(Event::<T>::GameEnded { index: game.index });
-			Game::<T>::kill();
+			Game::<T>::remove(region);
 		}
 
-		/// Process the cancelling of the game, one single step, return true if finished.
+		/// Process the cancelling of the game of `region`, one single step, return true if
+		/// finished.
 		pub(crate) fn process_cancelling_step(
+			region: GameRegion,
 			last_iteration: &mut Option<AccountOrPerson<T::AccountId>>,
 			rounds: u8,
 		) -> bool {
@@ -2836,11 +3915,17 @@ pub mod pallet {
 			};
 
 			if let Some((player_id, player)) = iterator.next() {
+				if player.region != region {
+					*last_iteration = Some(player_id);
+					return false;
+				}
+
 				// Reset the player info.
 				Players::<T>::insert(
 					&player_id,
 					Player {
 						first_game: player.first_game,
+						region,
 						registered: false,
 						sent_report: false,
 						early_attendance_enactment: None,
@@ -2853,12 +3938,12 @@ pub mod pallet {
 				);
 
 				// Clean index <-> player mapping.
-				if let Some(indices) = PlayerToIndex::<T>::take(&player_id) {
+				if let Some(indices) = PlayerToIndex::<T>::take(region, &player_id) {
 					for round in 0..rounds {
 						if let Some(index) = indices.get(round as usize).defensive_proof(
 							"indiv-pallet-game: indices are consistent with rounds",
 						) {
-							IndexToPlayer::<T>::remove((round, index));
+							IndexToPlayer::<T>::remove(region, (round, index));
 						}
 					}
 				}
@@ -2870,8 +3955,8 @@ pub mod pallet {
 			}
 		}
 
-		/// Process the cancelling of the game.
-		pub(crate) fn process_cancelling(weight_meter: &mut WeightMeter) {
+		/// Process the cancelling of the game of `region`.
+		pub(crate) fn process_cancelling(weight_meter: &mut WeightMeter, region: GameRegion) {
 			if weight_meter
 				.try_consume(<T as Config>::WeightInfo::process_cancelling())
 				.is_err()
@@ -2879,7 +3964,7 @@ pub mod pallet {
 				return;
 			}
 
-			let Some(mut game) = Game::<T>::get() else {
+			let Some(mut game) = Game::<T>::get(region) else {
 				defensive!("game should exist in order to be cancelled");
 				return;
 			};
@@ -2899,17 +3984,17 @@ pub mod pallet {
 					break;
 				}
 
-				finished = Self::process_cancelling_step(last_iteration, game.rounds);
+				finished = Self::process_cancelling_step(region, last_iteration, game.rounds);
 				if finished {
 					break;
 				}
 			}
 
 			if finished {
-				Game::<T>::kill();
+				Game::<T>::remove(region);
 				GameHistory::<T>::remove(game.index);
 			} else {
-				Game::<T>::put(game);
+				Game::<T>::insert(region, game);
 			}
 		}
 
@@ -2965,25 +4050,28 @@ pub mod pallet {
 								game.max_group_size,
 							);
 						}
-						Game::<T>::put(game);
+						Game::<T>::insert(game.region, game);
 					}
 				},
 				GameState::Shuffle { .. } => {
 					Self::shuffles(weight_meter, game);
 				},
 				GameState::Reporting { .. } => {
-					Self::process_reporting(weight_meter);
+					Self::process_reporting(weight_meter, game.region);
 				},
 				GameState::PlayerProcess { step } => match step {
+					PlayerProcessStep::Step0ScoreReports { .. } => {
+						Self::player_process_step0(weight_meter, game.region);
+					},
 					PlayerProcessStep::Step1ProcessPlayers { .. } => {
-						Self::player_process_step1(weight_meter);
+						Self::player_process_step1(weight_meter, game.region);
 					},
 					PlayerProcessStep::Step2ClearIndices => {
-						Self::player_process_step2(weight_meter);
+						Self::player_process_step2(weight_meter, game.region);
 					},
 				},
 				GameState::Cancelling { .. } => {
-					Self::process_cancelling(weight_meter);
+					Self::process_cancelling(weight_meter, game.region);
 				},
 			}
 		}
@@ -3026,13 +4114,14 @@ pub mod pallet {
 		/// value (a Unix-seconds `mint_time`) is refreshed to the backfill timestamp.
 		pub(crate) fn mint_attendance_nfts(
 			game_index: u32,
+			region: GameRegion,
 			rounds: u8,
 			max_group_size: u32,
 			player_count: u32,
 			attendee: &AccountOrPerson<T::AccountId>,
 			mint_time: u32,
 		) {
-			let Some(attendee_indices) = PlayerToIndex::<T>::get(attendee) else {
+			let Some(attendee_indices) = PlayerToIndex::<T>::get(region, attendee) else {
 				// Unregistered attendee (should not happen for `attendance == true`,
 				// since `determine_attendance` short-circuits non-registered players).
 				defensive!("indiv-pallet-game: attended player must have round indices");
@@ -3052,7 +4141,8 @@ pub mod pallet {
 					groups_setting.group_members(group_index).filter(|&i| i != attendee_index);
 
 				for co_member_index in co_members {
-					let Some(co_member) = IndexToPlayer::<T>::get((round, co_member_index)) else {
+					let Some(co_member) = IndexToPlayer::<T>::get(region, (round, co_member_index))
+					else {
 						defensive!(
 							"indiv-pallet-game: index should map to a player when minting NFTs"
 						);
@@ -3108,6 +4198,7 @@ pub mod pallet {
 		/// [`Self::player_process_step1`] (after reporting).
 		pub(crate) fn apply_attendance_to_nfts(
 			game_index: u32,
+			region: GameRegion,
 			rounds: u8,
 			max_group_size: u32,
 			player_count: u32,
@@ -3118,6 +4209,7 @@ pub mod pallet {
 			if attendance {
 				Self::mint_attendance_nfts(
 					game_index,
+					region,
 					rounds,
 					max_group_size,
 					player_count,
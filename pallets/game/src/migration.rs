@@ -0,0 +1,504 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Individuality.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Storage migrations for the game pallet.
+
+use crate::*;
+use frame_support::{
+	migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
+	pallet_prelude::*,
+	weights::WeightMeter,
+};
+use indiv_pallet_airdrop::types::AirdropPrize;
+
+const LOG_TARGET: &str = "runtime::indiv-pallet-game::migration";
+
+/// The identifier of the migrations of the pallet.
+const PALLET_MIGRATIONS_ID: &[u8; 17] = b"indiv-pallet-game";
+
+/// Migration to storage version 1, in which games run per region.
+///
+/// Every existing game, schedule and player belongs to the first region afterwards. The game of
+/// the pallet keeps its state, including in the middle of a game, and is played with the rules of
+/// the pallet before the games were configurable: [`AttendanceRuleKind::StrictMajority`],
+/// [`GroupAssignment::Shuffled`] and the phase durations of the pallet.
+pub mod v1 {
+	use super::*;
+
+	/// The storage of the pallet before games ran per region.
+	pub(crate) mod v0 {
+		use super::*;
+
+		#[derive(Encode, Decode)]
+		pub(crate) struct Player<PlayDeposit> {
+			pub first_game: u32,
+			pub registered: bool,
+			pub sent_report: bool,
+			pub early_attendance_enactment: Option<EarlyAttendanceEnactment>,
+			pub yes_person: u8,
+			pub no_not_person: u8,
+			pub expected_max_vote_weight: u16,
+			pub vote_weight: u8,
+			pub credibility: PlayerCredibility<PlayDeposit>,
+		}
+
+		#[derive(Encode, Decode)]
+		pub(crate) enum ShuffleStep<AccountId> {
+			Step1Insert {
+				last_iteration: Option<AccountOrPerson<AccountId>>,
+			},
+			Step2Retrieve {
+				next_player_index: u32,
+				recognized_finished: bool,
+			},
+			Step3ComputeWeights {
+				last_iteration: Option<AccountOrPerson<AccountId>>,
+				player_count: u32,
+			},
+			Step4AwaitSession {
+				player_count: u32,
+			},
+		}
+
+		#[derive(Encode, Decode)]
+		pub(crate) enum PlayerProcessStep<AccountId> {
+			Step1ProcessPlayers {
+				last_iteration: Option<AccountOrPerson<AccountId>>,
+				player_count: u32,
+			},
+			Step2ClearIndices,
+		}
+
+		#[derive(Encode, Decode)]
+		pub(crate) enum GameState<AccountId> {
+			Registration { next_player_index: u32 },
+			Shuffle { step: ShuffleStep<AccountId> },
+			Reporting { player_count: u32 },
+			PlayerProcess { step: PlayerProcessStep<AccountId> },
+			Cancelling { last_iteration: Option<AccountOrPerson<AccountId>> },
+		}
+
+		#[derive(Encode, Decode)]
+		pub(crate) struct GameInfo<AccountId> {
+			pub index: u32,
+			pub registration_ends: u32,
+			pub shuffle_deadline: u32,
+			pub game_date: u32,
+			pub report_ends: u32,
+			pub state: GameState<AccountId>,
+			pub max_group_size: u32,
+			pub rounds: u8,
+			pub pending_attendance: u32,
+			pub airdrop_scheduled: bool,
+		}
+
+		#[derive(Encode, Decode)]
+		pub(crate) struct GameSchedule<AssetId, Balance> {
+			pub game_play_time: u32,
+			pub rounds: u8,
+			pub max_group_size: u32,
+			pub airdrop_prize: Option<AirdropPrize<AssetId, Balance>>,
+		}
+
+		pub(crate) type AccountOrPersonOf<T> =
+			AccountOrPerson<<T as frame_system::Config>::AccountId>;
+
+		#[frame_support::storage_alias]
+		pub(crate) type Players<T: Config> = StorageMap<
+			Pallet<T>,
+			Blake2_128Concat,
+			AccountOrPersonOf<T>,
+			Player<<T as Config>::PlayDeposit>,
+		>;
+
+		#[frame_support::storage_alias]
+		pub(crate) type Game<T: Config> =
+			StorageValue<Pallet<T>, GameInfo<<T as frame_system::Config>::AccountId>>;
+
+		#[frame_support::storage_alias]
+		pub(crate) type GameSchedules<T: Config> = StorageValue<
+			Pallet<T>,
+			Vec<GameSchedule<<T as Config>::AirdropAssetId, <T as Config>::AirdropAssetBalance>>,
+			ValueQuery,
+		>;
+
+		#[frame_support::storage_alias]
+		pub(crate) type IndexToPlayer<T: Config> =
+			StorageMap<Pallet<T>, Twox64Concat, (RoundIndex, PlayerIndex), AccountOrPersonOf<T>>;
+
+		#[frame_support::storage_alias]
+		pub(crate) type PlayerToIndex<T: Config> = StorageMap<
+			Pallet<T>,
+			Blake2_128Concat,
+			AccountOrPersonOf<T>,
+			BoundedVec<PlayerIndex, <T as Config>::MaxRounds>,
+		>;
+
+		#[frame_support::storage_alias]
+		pub(crate) type ShuffleRecognized<T: Config> = StorageDoubleMap<
+			Pallet<T>,
+			Twox64Concat,
+			RoundIndex,
+			Identity,
+			[u8; 32],
+			AccountOrPersonOf<T>,
+		>;
+
+		#[frame_support::storage_alias]
+		pub(crate) type ShuffleNotRecognized<T: Config> = StorageDoubleMap<
+			Pallet<T>,
+			Twox64Concat,
+			RoundIndex,
+			Identity,
+			[u8; 32],
+			AccountOrPersonOf<T>,
+		>;
+	}
+
+	/// The entries of the rekeyed maps, moved out of their old keys before being inserted at
+	/// their new keys so that the old and new keys of a map are never iterated together.
+	#[frame_support::storage_alias]
+	type StagedIndexToPlayer<T: Config> =
+		StorageMap<Pallet<T>, Twox64Concat, (RoundIndex, PlayerIndex), v0::AccountOrPersonOf<T>>;
+
+	#[frame_support::storage_alias]
+	type StagedPlayerToIndex<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		v0::AccountOrPersonOf<T>,
+		BoundedVec<PlayerIndex, <T as Config>::MaxRounds>,
+	>;
+
+	#[frame_support::storage_alias]
+	type StagedShuffleRecognized<T: Config> = StorageDoubleMap<
+		Pallet<T>,
+		Twox64Concat,
+		RoundIndex,
+		Identity,
+		[u8; 32],
+		v0::AccountOrPersonOf<T>,
+	>;
+
+	#[frame_support::storage_alias]
+	type StagedShuffleNotRecognized<T: Config> = StorageDoubleMap<
+		Pallet<T>,
+		Twox64Concat,
+		RoundIndex,
+		Identity,
+		[u8; 32],
+		v0::AccountOrPersonOf<T>,
+	>;
+
+	/// The region every existing game, schedule and player belongs to.
+	const REGION: GameRegion = 0;
+
+	/// The progress of [`MigrateToRegions`].
+	#[derive(Encode, Decode, MaxEncodedLen, Debug, Clone, PartialEq, Eq)]
+	pub enum MigrationCursor<AccountId> {
+		/// Move the game of the pallet to the first region.
+		Game,
+		/// Move the game schedules to the first region.
+		GameSchedules,
+		/// Move the players to the first region, after the last migrated player.
+		Players { last: Option<AccountOrPerson<AccountId>> },
+		/// Move the entries of `IndexToPlayer` out of their old keys.
+		StageIndexToPlayer,
+		/// Insert the entries of `IndexToPlayer` at the keys of the first region.
+		IndexToPlayer,
+		/// Move the entries of `PlayerToIndex` out of their old keys.
+		StagePlayerToIndex,
+		/// Insert the entries of `PlayerToIndex` at the keys of the first region.
+		PlayerToIndex,
+		/// Move the entries of `ShuffleRecognized` out of their old keys.
+		StageShuffleRecognized,
+		/// Insert the entries of `ShuffleRecognized` at the keys of the first region.
+		ShuffleRecognized,
+		/// Move the entries of `ShuffleNotRecognized` out of their old keys.
+		StageShuffleNotRecognized,
+		/// Insert the entries of `ShuffleNotRecognized` at the keys of the first region.
+		ShuffleNotRecognized,
+	}
+
+	/// Migrates the storage of the pallet to version 1, see the [module docs](self).
+	pub struct MigrateToRegions<T>(PhantomData<T>);
+
+	impl<T: Config> SteppedMigration for MigrateToRegions<T> {
+		type Cursor = MigrationCursor<T::AccountId>;
+		type Identifier = MigrationId<17>;
+
+		fn id() -> Self::Identifier {
+			MigrationId { pallet_id: *PALLET_MIGRATIONS_ID, version_from: 0, version_to: 1 }
+		}
+
+		fn step(
+			cursor: Option<Self::Cursor>,
+			meter: &mut WeightMeter,
+		) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+			if Pallet::<T>::on_chain_storage_version() != Self::id().version_from as u16 {
+				return Ok(None);
+			}
+
+			// Each entry is read, removed from its old key and written to its new key.
+			let required = T::DbWeight::get().reads_writes(1, 2);
+			if meter.remaining().any_lt(required) {
+				return Err(SteppedMigrationError::InsufficientWeight { required });
+			}
+
+			let mut cursor = cursor.unwrap_or(MigrationCursor::Game);
+			loop {
+				let next = match &cursor {
+					MigrationCursor::Game => {
+						meter.consume(required);
+						if let Some(game) = v0::Game::<T>::take() {
+							Game::<T>::insert(REGION, migrate_game::<T>(game));
+						}
+						Some(MigrationCursor::GameSchedules)
+					},
+					MigrationCursor::GameSchedules => {
+						meter.consume(required);
+						let schedules = v0::GameSchedules::<T>::take()
+							.into_iter()
+							.map(migrate_schedule)
+							.collect::<Vec<_>>();
+						// The number of schedules was bounded by the same `MaxGameSchedules`.
+						GameSchedules::<T>::put(BoundedVec::truncate_from(schedules));
+						Some(MigrationCursor::Players { last: None })
+					},
+					MigrationCursor::Players { last } => {
+						// The players are migrated in place, the new values are never decoded
+						// as old ones.
+						let mut iter = match last {
+							Some(last) =>
+								v0::Players::<T>::iter_from(v0::Players::<T>::hashed_key_for(last)),
+							None => v0::Players::<T>::iter(),
+						};
+						let mut last = last.clone();
+						loop {
+							if meter.try_consume(required).is_err() {
+								return Ok(Some(MigrationCursor::Players { last }));
+							}
+							match iter.next() {
+								Some((who, player)) => {
+									Players::<T>::insert(&who, migrate_player(player));
+									last = Some(who);
+								},
+								None => break,
+							}
+						}
+						Some(MigrationCursor::StageIndexToPlayer)
+					},
+					MigrationCursor::StageIndexToPlayer => drain_with(
+						meter,
+						required,
+						v0::IndexToPlayer::<T>::drain(),
+						|(key, who)| StagedIndexToPlayer::<T>::insert(key, who),
+					)
+					.then_some(MigrationCursor::IndexToPlayer),
+					MigrationCursor::IndexToPlayer => drain_with(
+						meter,
+						required,
+						StagedIndexToPlayer::<T>::drain(),
+						|(key, who)| IndexToPlayer::<T>::insert(REGION, key, who),
+					)
+					.then_some(MigrationCursor::StagePlayerToIndex),
+					MigrationCursor::StagePlayerToIndex => drain_with(
+						meter,
+						required,
+						v0::PlayerToIndex::<T>::drain(),
+						|(who, indices)| StagedPlayerToIndex::<T>::insert(who, indices),
+					)
+					.then_some(MigrationCursor::PlayerToIndex),
+					MigrationCursor::PlayerToIndex => drain_with(
+						meter,
+						required,
+						StagedPlayerToIndex::<T>::drain(),
+						|(who, indices)| PlayerToIndex::<T>::insert(REGION, who, indices),
+					)
+					.then_some(MigrationCursor::StageShuffleRecognized),
+					MigrationCursor::StageShuffleRecognized => drain_with(
+						meter,
+						required,
+						v0::ShuffleRecognized::<T>::drain(),
+						|(round, order, who)| {
+							StagedShuffleRecognized::<T>::insert(round, order, who)
+						},
+					)
+					.then_some(MigrationCursor::ShuffleRecognized),
+					MigrationCursor::ShuffleRecognized => drain_with(
+						meter,
+						required,
+						StagedShuffleRecognized::<T>::drain(),
+						|(round, order, who)| {
+							ShuffleRecognized::<T>::insert((REGION, round), order, who)
+						},
+					)
+					.then_some(MigrationCursor::StageShuffleNotRecognized),
+					MigrationCursor::StageShuffleNotRecognized => drain_with(
+						meter,
+						required,
+						v0::ShuffleNotRecognized::<T>::drain(),
+						|(round, order, who)| {
+							StagedShuffleNotRecognized::<T>::insert(round, order, who)
+						},
+					)
+					.then_some(MigrationCursor::ShuffleNotRecognized),
+					MigrationCursor::ShuffleNotRecognized => {
+						let finished = drain_with(
+							meter,
+							required,
+							StagedShuffleNotRecognized::<T>::drain(),
+							|(round, order, who)| {
+								ShuffleNotRecognized::<T>::insert((REGION, round), order, who)
+							},
+						);
+						if !finished {
+							return Ok(Some(MigrationCursor::ShuffleNotRecognized));
+						}
+						StorageVersion::new(Self::id().version_to as u16).put::<Pallet<T>>();
+						log::info!(target: LOG_TARGET, "migrated the games to regions");
+						return Ok(None);
+					},
+				};
+
+				match next {
+					Some(next) if meter.can_consume(required) => cursor = next,
+					// The current map isn't drained yet.
+					None => return Ok(Some(cursor)),
+					Some(next) => return Ok(Some(next)),
+				}
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			let players = v0::Players::<T>::iter_keys().count() as u32;
+			let schedules = v0::GameSchedules::<T>::get().len() as u32;
+			Ok((players, schedules, v0::Game::<T>::exists()).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let (players, schedules, game): (u32, u32, bool) =
+				Decode::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+			ensure!(Pallet::<T>::on_chain_storage_version() == 1, "storage version not updated");
+			ensure!(Players::<T>::iter_values().count() as u32 == players, "players lost");
+			ensure!(
+				Players::<T>::iter_values().all(|player| player.region == REGION),
+				"player not in the first region"
+			);
+			ensure!(GameSchedules::<T>::get().len() as u32 == schedules, "schedules lost");
+			ensure!(Game::<T>::contains_key(REGION) == game, "game lost");
+			ensure!(Game::<T>::iter_keys().all(|region| region == REGION), "game not migrated");
+			Ok(())
+		}
+	}
+
+	/// Move the entries of `drain` with `f` while `meter` allows it, returns whether all entries
+	/// were moved.
+	fn drain_with<I>(
+		meter: &mut WeightMeter,
+		required: Weight,
+		mut drain: impl Iterator<Item = I>,
+		mut f: impl FnMut(I),
+	) -> bool {
+		loop {
+			if meter.try_consume(required).is_err() {
+				return false;
+			}
+			match drain.next() {
+				Some(entry) => f(entry),
+				None => return true,
+			}
+		}
+	}
+
+	fn migrate_game<T: Config>(game: v0::GameInfo<T::AccountId>) -> GameInfo<T::AccountId> {
+		let state = match game.state {
+			v0::GameState::Registration { next_player_index } =>
+				GameState::Registration { next_player_index },
+			v0::GameState::Shuffle { step } => GameState::Shuffle {
+				step: match step {
+					v0::ShuffleStep::Step1Insert { last_iteration } =>
+						ShuffleStep::Step1Insert { last_iteration },
+					v0::ShuffleStep::Step2Retrieve { next_player_index, recognized_finished } =>
+						ShuffleStep::Step2Retrieve { next_player_index, recognized_finished },
+					v0::ShuffleStep::Step3ComputeWeights { last_iteration, player_count } =>
+						ShuffleStep::Step4ComputeWeights { last_iteration, player_count },
+					v0::ShuffleStep::Step4AwaitSession { player_count } =>
+						ShuffleStep::Step5AwaitSession { player_count },
+				},
+			},
+			v0::GameState::Reporting { player_count } => GameState::Reporting { player_count },
+			// No report was kept to score, the players are processed right away.
+			v0::GameState::PlayerProcess { step } => GameState::PlayerProcess {
+				step: match step {
+					v0::PlayerProcessStep::Step1ProcessPlayers { last_iteration, player_count } =>
+						PlayerProcessStep::Step1ProcessPlayers { last_iteration, player_count },
+					v0::PlayerProcessStep::Step2ClearIndices =>
+						PlayerProcessStep::Step2ClearIndices,
+				},
+			},
+			v0::GameState::Cancelling { last_iteration } =>
+				GameState::Cancelling { last_iteration },
+		};
+		GameInfo {
+			index: game.index,
+			region: REGION,
+			registration_ends: game.registration_ends,
+			shuffle_deadline: game.shuffle_deadline,
+			game_date: game.game_date,
+			report_ends: game.report_ends,
+			state,
+			max_group_size: game.max_group_size,
+			rounds: game.rounds,
+			pending_attendance: game.pending_attendance,
+			airdrop_scheduled: game.airdrop_scheduled,
+			attendance_rule: AttendanceRuleKind::StrictMajority,
+			group_assignment: GroupAssignment::Shuffled,
+			phases: crate::types::configured_phases::<T>(),
+		}
+	}
+
+	fn migrate_schedule<AssetId, Balance>(
+		schedule: v0::GameSchedule<AssetId, Balance>,
+	) -> GameSchedule<AssetId, Balance> {
+		GameSchedule {
+			game_play_time: schedule.game_play_time,
+			rounds: schedule.rounds,
+			max_group_size: schedule.max_group_size,
+			airdrop_prize: schedule.airdrop_prize,
+			attendance_rule: AttendanceRuleKind::StrictMajority,
+			region: REGION,
+			group_assignment: GroupAssignment::Shuffled,
+			phases: None,
+		}
+	}
+
+	fn migrate_player<PlayDeposit>(player: v0::Player<PlayDeposit>) -> Player<PlayDeposit> {
+		Player {
+			first_game: player.first_game,
+			region: REGION,
+			registered: player.registered,
+			sent_report: player.sent_report,
+			early_attendance_enactment: player.early_attendance_enactment,
+			yes_person: player.yes_person,
+			no_not_person: player.no_not_person,
+			expected_max_vote_weight: player.expected_max_vote_weight,
+			vote_weight: player.vote_weight,
+			credibility: player.credibility,
+		}
+	}
+}
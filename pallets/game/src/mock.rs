@@ -31,7 +31,10 @@ use frame_system::{
 };
 use indiv_pallet_people::Origin::PersonalAlias;
 use indiv_pallet_score::{AccountOrPerson, SCORE_CONTEXT};
-use indiv_support::traits::{ContextualAlias, RevisedContextualAlias, RingExponent};
+use indiv_support::traits::{
+	Callback, ContextualAlias, Judgement, JudgementContext, RevisedContextualAlias, RingExponent,
+	Statement as OracleStatement, StatementOracle,
+};
 use scale_info::TypeInfo;
 use sp_core::{ConstU32, ConstU64, ConstUint, H256};
 use sp_runtime::{
@@ -41,7 +44,7 @@ use sp_runtime::{
 		TransactionExtension as TransactionExtensionTrait, ValidateResult,
 	},
 	transaction_validity::{InvalidTransaction, TransactionSource, TransactionValidityError},
-	AccountId32, BuildStorage, DispatchError, Permill, TransactionOutcome, Weight,
+	AccountId32, BuildStorage, DispatchError, Percent, Permill, TransactionOutcome, Weight,
 };
 use sp_statement_store::{runtime_api::StatementSource, Statement, Topic};
 use std::{
@@ -369,8 +372,10 @@ impl indiv_pallet_members::Config for Test {
 	type OnboardingQueuePageSize = ConstU32<40>;
 	type MaxFlexibleRingExponent = FlexibleRingExp;
 	type RingBuildingMemberLimit = ConstU32<100>;
+	type RingCompactionThreshold = frame_support::traits::GetDefault;
 	type OldRootRetentionDuration = ConstU64<600>;
 	type OnRingRootChange = ();
+	type CleanUpAlias = ();
 	type OffchainWorkerInterval = ConstU64<1>;
 	type ManagerOrigin = EnsureRoot<Self::AccountId>;
 	#[cfg(feature = "runtime-benchmarks")]
@@ -448,6 +453,12 @@ impl WeightInfo for MockWeightInfo {
 	fn shuffle_step_start_session() -> Weight {
 		Weight::from_parts(10, 10)
 	}
+	fn player_process_step0() -> Weight {
+		Weight::from_parts(15, 15)
+	}
+	fn player_process_step0_reporter(_r: u32) -> Weight {
+		Weight::from_parts(10, 10)
+	}
 	fn player_process_step1() -> Weight {
 		Weight::from_parts(15, 15)
 	}
@@ -553,14 +564,48 @@ impl WeightInfo for MockWeightInfo {
 	fn on_game_cancelled() -> Weight {
 		Weight::zero()
 	}
+	fn dispute_attendance() -> Weight {
+		Weight::zero()
+	}
+	fn attendance_judged() -> Weight {
+		Weight::zero()
+	}
 }
 
 parameter_types! {
 	pub storage PeopleVoteWeight: u8 = 1;
 	pub storage CandidateVoteWeight: u8 = 1;
+	pub storage MinReportConsistency: Percent = Percent::from_percent(50);
+	pub storage MaxInconsistentReportStreak: u32 = 2;
+	pub storage InconsistentReportPenalty: u32 = 0;
 	pub const PlayDepositDefault: u64 = 2;
 }
 
+thread_local! {
+	/// The statements submitted to [`MockOracle`] with their context, indexed by ticket.
+	pub(crate) static ORACLE_STATEMENTS: RefCell<Vec<(OracleStatement, JudgementContext)>> =
+		const { RefCell::new(Vec::new()) };
+}
+
+/// Oracle recording the statements to judge, the tests dispatch the judgements.
+pub struct MockOracle;
+
+impl<C> StatementOracle<C> for MockOracle {
+	type Ticket = u32;
+
+	fn judge_statement(
+		statement: OracleStatement,
+		context: JudgementContext,
+		_: Callback<(Self::Ticket, JudgementContext, Judgement), C>,
+	) -> Result<Self::Ticket, DispatchError> {
+		ORACLE_STATEMENTS.with(|statements| {
+			let mut statements = statements.borrow_mut();
+			statements.push((statement, context));
+			Ok(statements.len() as u32 - 1)
+		})
+	}
+}
+
 thread_local! {
 	pub(crate) static AIRDROP_RANDOMNESS: RefCell<Option<[u8; 32]>> =
 		const { RefCell::new(Some([42u8; 32])) };
@@ -762,12 +807,24 @@ impl crate::Config for Test {
 	type DefaultPlayDeposit = PlayDepositDefault;
 	type DefaultPhaseDurations = GamePhaseDurations;
 	type MaxGameSchedules = ConstUint<5>;
+	type MaxScheduleRules = ConstUint<4>;
+	type ScheduleRuleHorizon = ConstUint<50>;
+	type MaxRegions = ConstUint<2>;
+	type MaxRecentCoPlayers = ConstUint<30>;
 	type MaxAttendanceHistoryDepth = ConstUint<2>;
 	type TicketSignature = TestSignature;
 	type PlayerStatementLimit = PlayerStatementLimit;
 	type AccountSignature = AccountAuthority;
 	type PeopleVoteWeight = PeopleVoteWeight;
 	type CandidateVoteWeight = CandidateVoteWeight;
+	type AttendanceRule = DefaultAttendanceRule<Test>;
+	type MinReportConsistency = MinReportConsistency;
+	type MaxInconsistentReportStreak = MaxInconsistentReportStreak;
+	type InconsistentReportPenalty = InconsistentReportPenalty;
+	type Oracle = MockOracle;
+	type AttendanceDisputeWindow = ConstU64<10>;
+	type AttendanceDisputeDeposit = deposit::MockConsideration;
+	type AttendanceDisputeDepositAmount = ConstU64<3>;
 	type AirdropAssetId = u32;
 	type AirdropAssetBalance = u128;
 	type Airdrop = Airdrop;
@@ -871,6 +928,7 @@ impl indiv_pallet_score::Config for Test {
 	type MaxPayoutRoundSchedules = ConstUint<10>;
 	type OffchainWorkInterval = ConstUint<1>;
 	type People = People;
+	type Signals = ();
 	type Crypto = Mock;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = Test;
@@ -1186,6 +1244,7 @@ pub fn run_game_scenario<F>(
 ) where
 	F: Fn(&AccountOrPerson<AccountId32>) -> Option<FullReport<Test>> + Copy,
 {
+	let region = schedule.region;
 	run_game_scenario_with_phase(
 		schedule,
 		|| {
@@ -1194,6 +1253,7 @@ pub fn run_game_scenario<F>(
 					AccountOrPerson::Account(acc) => {
 						assert_ok!(Game::sign_up_with_account(
 							RuntimeOrigin::signed(acc.clone()),
+							region,
 							DEFAULT_IDENTIFIER_KEY,
 							None,
 						));
@@ -1202,6 +1262,7 @@ pub fn run_game_scenario<F>(
 						let account = AccountId32::new(*alias);
 						assert_ok!(Game::sign_up_with_alias(
 							runtime_origin_for_alias(alias),
+							region,
 							DEFAULT_IDENTIFIER_KEY,
 							account.clone(),
 							AccountAuthority(account),
@@ -1320,6 +1381,7 @@ pub fn run_game_scenario_with_hooks<F>(
 			AccountOrPerson::Account(acc) => {
 				assert_ok!(Game::sign_up_with_account(
 					RuntimeOrigin::signed(acc.clone()),
+					schedule.region,
 					DEFAULT_IDENTIFIER_KEY,
 					None,
 				));
@@ -1328,6 +1390,7 @@ pub fn run_game_scenario_with_hooks<F>(
 				let account = AccountId32::new(*alias);
 				assert_ok!(Game::sign_up_with_alias(
 					runtime_origin_for_alias(alias),
+					schedule.region,
 					DEFAULT_IDENTIFIER_KEY,
 					account.clone(),
 					AccountAuthority(account),
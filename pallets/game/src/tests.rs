@@ -27,7 +27,9 @@ use frame_support::{
 use indiv_pallet_people::PEOPLE_MEMBER_IDENTIFIER;
 use indiv_support::traits::{AppendOnlyMembers, RingExponent, RingMode};
 use sp_core::{crypto::VrfSecret, ed25519, sr25519, Pair};
-use sp_runtime::{testing::TestSignature, transaction_validity::InvalidTransaction, AccountId32};
+use sp_runtime::{
+	testing::TestSignature, transaction_validity::InvalidTransaction, AccountId32, Percent,
+};
 use sp_statement_store::Statement;
 use std::{slice, time::Duration};
 use verifiable::{mock::Mock, GenerateVerifiable};
@@ -69,7 +71,8 @@ fn basic_game() {
 
 			let mut full_report = Vec::new();
 			for round in 0..rounds {
-				let player_shuffled_position = PlayerToIndex::<Test>::get(player).unwrap()[round];
+				let player_shuffled_position =
+					PlayerToIndex::<Test>::get(0, player).unwrap()[round];
 				let player_group_index = player_shuffled_position % number_of_group;
 				let other_players = (0..max_per_group)
 					.map(|x| player_group_index + x * number_of_group)
@@ -78,8 +81,8 @@ fn basic_game() {
 
 				let partial_report = other_players
 					.map(|x| {
-						if x == PlayerToIndex::<Test>::get(AccountOrPerson::Account(BOB)).unwrap()
-							[round]
+						if x == PlayerToIndex::<Test>::get(0, AccountOrPerson::Account(BOB))
+							.unwrap()[round]
 						{
 							Report::NotPerson
 						} else {
@@ -161,7 +164,7 @@ fn attended_player_gets_nfts_from_non_reporting_co_members() {
 
 			// Alice's group in round 0 is the entire player set; she reports
 			// every co-member as `Person`.
-			let alice_index = PlayerToIndex::<Test>::get(&alice).unwrap()[0];
+			let alice_index = PlayerToIndex::<Test>::get(0, &alice).unwrap()[0];
 			let group_size: u32 = 3;
 			let number_of_group: u32 = 1;
 			let partial_report = (0..group_size)
@@ -203,7 +206,7 @@ fn game_with_zero_player() {
 			..Default::default()
 		};
 		run_game_scenario(schedule, &[], |_| unreachable!());
-		assert!(crate::Game::<Test>::get().is_none());
+		assert!(crate::Game::<Test>::get(0).is_none());
 	});
 }
 
@@ -264,7 +267,7 @@ fn outdated_game_schedule_multi_game() {
 
 		// Start the first game.
 		advance_process();
-		assert!(crate::Game::<Test>::get().is_some());
+		assert!(crate::Game::<Test>::get(0).is_some());
 		assert_eq!(GameSchedules::<Test>::get().len(), 1);
 
 		// 2. The first game runs through all phases with hooks.
@@ -278,6 +281,7 @@ fn outdated_game_schedule_multi_game() {
 				AccountOrPerson::Account(acc) => {
 					assert_ok!(Game::sign_up_with_account(
 						RuntimeOrigin::signed(acc.clone()),
+						0,
 						DEFAULT_IDENTIFIER_KEY,
 						None,
 					));
@@ -286,6 +290,7 @@ fn outdated_game_schedule_multi_game() {
 					let account = AccountId32::new(*alias);
 					assert_ok!(Game::sign_up_with_alias(
 						runtime_origin_for_alias(alias),
+						0,
 						DEFAULT_IDENTIFIER_KEY,
 						account.clone(),
 						AccountAuthority(account),
@@ -326,7 +331,7 @@ fn outdated_game_schedule_multi_game() {
 		advance_process(); // step2 -> done
 
 		// First game is done, second game still in schedule.
-		assert!(crate::Game::<Test>::get().is_none());
+		assert!(crate::Game::<Test>::get(0).is_none());
 		assert_eq!(GameSchedules::<Test>::get().len(), 1);
 
 		// 3. Time is advanced past the second game's registration_start (simulating delay).
@@ -337,7 +342,7 @@ fn outdated_game_schedule_multi_game() {
 		// 4. Attempting to start the second game fails with OutdatedGameSetup.
 		advance_process(); // on_poll tries to start the game but it is already too late -> skipped.
 		assert_eq!(GameSchedules::<Test>::get().len(), 0);
-		assert!(crate::Game::<Test>::get().is_none());
+		assert!(crate::Game::<Test>::get(0).is_none());
 	});
 }
 
@@ -604,6 +609,7 @@ fn cannot_offboard_during_game() {
 		// Sign up
 		assert_ok!(Game::sign_up_with_account(
 			RuntimeOrigin::signed(ALICE),
+			0,
 			DEFAULT_IDENTIFIER_KEY,
 			None,
 		));
@@ -634,6 +640,7 @@ fn cannot_register_twice() {
 		// 2) First sign_up should succeed
 		assert_ok!(Game::sign_up_with_account(
 			RuntimeOrigin::signed(ALICE),
+			0,
 			DEFAULT_IDENTIFIER_KEY,
 			None,
 		));
@@ -645,7 +652,7 @@ fn cannot_register_twice() {
 
 		// 3) Second sign_up should fail with `AlreadyRegistered`
 		assert_noop!(
-			Game::sign_up_with_account(RuntimeOrigin::signed(ALICE), DEFAULT_IDENTIFIER_KEY, None),
+			Game::sign_up_with_account(RuntimeOrigin::signed(ALICE), 0, DEFAULT_IDENTIFIER_KEY, None),
 			Error::<Test>::AlreadyRegistered
 		);
 	});
@@ -666,6 +673,7 @@ fn cannot_report_twice() {
 		// Sign up player.
 		assert_ok!(Game::sign_up_with_account(
 			RuntimeOrigin::signed(ALICE),
+			0,
 			DEFAULT_IDENTIFIER_KEY,
 			None,
 		));
@@ -716,7 +724,7 @@ mod games_scheduling {
 		new_test_ext().execute_with(|| {
 			System::set_block_number(1);
 			// No ongoing game
-			assert!(crate::Game::<Test>::get().is_none());
+			assert!(crate::Game::<Test>::get(0).is_none());
 
 			// No scheduled games
 			assert_eq!(GameSchedules::<Test>::get().len(), 0);
@@ -767,13 +775,57 @@ mod games_scheduling {
 		});
 	}
 
+	#[test]
+	fn schedule_games_validates_attendance_rule() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+
+			// A supermajority must be more than half of the votes.
+			for threshold in [Percent::zero(), Percent::from_percent(50)] {
+				assert_noop!(
+					Game::schedule_games(
+						RuntimeOrigin::root(),
+						vec![GameSchedule::<u32, u128> {
+							game_play_time: 100,
+							rounds: 2,
+							max_group_size: 2,
+							attendance_rule: AttendanceRuleKind::Supermajority { threshold },
+							region: 0,
+							..Default::default()
+						}]
+					),
+					Error::<Test>::InvalidGameSetup
+				);
+			}
+
+			let attendance_rule =
+				AttendanceRuleKind::Supermajority { threshold: Percent::from_percent(67) };
+			assert_ok!(Game::schedule_games(
+				RuntimeOrigin::root(),
+				vec![GameSchedule::<u32, u128> {
+					game_play_time: 100,
+					rounds: 2,
+					max_group_size: 2,
+					attendance_rule,
+					region: 0,
+					..Default::default()
+				}]
+			));
+			assert_eq!(GameSchedules::<Test>::get()[0].attendance_rule, attendance_rule);
+
+			// The rule of the schedule is carried to the started game.
+			assert_ok!(Game::new_game(&GameSchedules::<Test>::get()[0]));
+			assert_eq!(crate::Game::<Test>::get(0).unwrap().attendance_rule, attendance_rule);
+		});
+	}
+
 	#[test]
 	fn schedule_games_checks_if_max_games_scheduled_limit_is_respected() {
 		new_test_ext().execute_with(|| {
 			// let offset: u32 = <Test as Config>::GamePhasesOffset::get();
 
 			// No ongoing game
-			assert!(crate::Game::<Test>::get().is_none());
+			assert!(crate::Game::<Test>::get(0).is_none());
 
 			// No scheduled games
 			assert_eq!(GameSchedules::<Test>::get().len(), 0);
@@ -849,7 +901,7 @@ mod games_scheduling {
 	fn schedule_games_checks_chronological_order_of_provided_schedules() {
 		new_test_ext().execute_with(|| {
 			// No ongoing game
-			assert!(crate::Game::<Test>::get().is_none());
+			assert!(crate::Game::<Test>::get(0).is_none());
 
 			// No scheduled games
 			assert_eq!(GameSchedules::<Test>::get().len(), 0);
@@ -929,7 +981,7 @@ mod games_scheduling {
 	fn schedule_games_checks_for_time_overlaps_between_games() {
 		new_test_ext().execute_with(|| {
 			// No ongoing game
-			assert!(crate::Game::<Test>::get().is_none());
+			assert!(crate::Game::<Test>::get(0).is_none());
 
 			// No scheduled games
 			assert_eq!(GameSchedules::<Test>::get().len(), 0);
@@ -993,7 +1045,7 @@ mod games_scheduling {
 	fn games_to_schedule_must_be_after_currently_scheduled_ones() {
 		new_test_ext().execute_with(|| {
 			// No ongoing game
-			assert!(crate::Game::<Test>::get().is_none());
+			assert!(crate::Game::<Test>::get(0).is_none());
 
 			// Several scheduled games
 			let existing_games = vec![
@@ -1086,7 +1138,7 @@ mod games_scheduling {
 
 			// Attempt to remove a game that doesn't exist in the schedule fails
 			assert_noop!(
-				Game::remove_scheduled_game(RuntimeOrigin::root(), 300),
+				Game::remove_scheduled_game(RuntimeOrigin::root(), 0, 300),
 				Error::<Test>::NoSuchGameScheduled
 			);
 
@@ -1138,23 +1190,23 @@ mod games_scheduling {
 			assert_eq!(GameSchedules::<Test>::get().len(), 5);
 
 			// Attempt to remove the 2nd game succeeds
-			assert_ok!(Game::remove_scheduled_game(RuntimeOrigin::root(), 200));
+			assert_ok!(Game::remove_scheduled_game(RuntimeOrigin::root(), 0, 200));
 			System::assert_has_event(
-				Event::<Test>::ScheduledGameRemoved { game_play_time: 200 }.into(),
+				Event::<Test>::ScheduledGameRemoved { region: 0, game_play_time: 200 }.into(),
 			);
 			assert_eq!(GameSchedules::<Test>::get().len(), 4);
 
 			// Attempt to remove the last game succeeds
-			assert_ok!(Game::remove_scheduled_game(RuntimeOrigin::root(), 500));
+			assert_ok!(Game::remove_scheduled_game(RuntimeOrigin::root(), 0, 500));
 			System::assert_has_event(
-				Event::<Test>::ScheduledGameRemoved { game_play_time: 500 }.into(),
+				Event::<Test>::ScheduledGameRemoved { region: 0, game_play_time: 500 }.into(),
 			);
 			assert_eq!(GameSchedules::<Test>::get().len(), 3);
 
 			// Attempt to remove the first game succeeds
-			assert_ok!(Game::remove_scheduled_game(RuntimeOrigin::root(), 100));
+			assert_ok!(Game::remove_scheduled_game(RuntimeOrigin::root(), 0, 100));
 			System::assert_has_event(
-				Event::<Test>::ScheduledGameRemoved { game_play_time: 100 }.into(),
+				Event::<Test>::ScheduledGameRemoved { region: 0, game_play_time: 100 }.into(),
 			);
 			assert_eq!(GameSchedules::<Test>::get().len(), 2);
 		});
@@ -1203,7 +1255,7 @@ mod games_scheduling {
 			// 2. Enough time passes for the first scheduled game to become the ongoing game. No
 			// ongoing game, so the first one from the schedule will become one.
 			advance_process();
-			assert!(crate::Game::<Test>::get().is_some());
+			assert!(crate::Game::<Test>::get(0).is_some());
 			assert_eq!(GameSchedules::<Test>::get().len(), 2);
 
 			// 3. The first game passes through all of its phases and is no longer the ongoing game.
@@ -1213,8 +1265,8 @@ mod games_scheduling {
 			// 4. The second game from the schedule is scheduled as next game.
 			let game_2 = &schedules[1];
 			assert_eq!(GameSchedules::<Test>::get().len(), 1);
-			assert!(crate::Game::<Test>::get().is_some());
-			assert_eq!(crate::Game::<Test>::get().unwrap().game_date, game_2.game_play_time);
+			assert!(crate::Game::<Test>::get(0).is_some());
+			assert_eq!(crate::Game::<Test>::get(0).unwrap().game_date, game_2.game_play_time);
 
 			// 5. The process repeats till there's no more scheduled games.
 
@@ -1224,12 +1276,12 @@ mod games_scheduling {
 			// The last game becomes the ongoing game
 			let game_3 = &schedules[2];
 			assert_eq!(GameSchedules::<Test>::get().len(), 0);
-			assert!(crate::Game::<Test>::get().is_some());
-			assert_eq!(crate::Game::<Test>::get().unwrap().game_date, game_3.game_play_time);
+			assert!(crate::Game::<Test>::get(0).is_some());
+			assert_eq!(crate::Game::<Test>::get(0).unwrap().game_date, game_3.game_play_time);
 
 			// The last game is executed
 			run_basic_game_scenario_with_hooks(game_3.clone(), &players);
-			assert!(crate::Game::<Test>::get().is_none());
+			assert!(crate::Game::<Test>::get(0).is_none());
 		});
 	}
 
@@ -1264,10 +1316,10 @@ mod games_scheduling {
 
 			// The first game registration starts
 			advance_process();
-			assert!(crate::Game::<Test>::get().is_some());
+			assert!(crate::Game::<Test>::get(0).is_some());
 			assert_eq!(GameSchedules::<Test>::get().len(), 1);
 			assert!(
-				crate::Game::<Test>::get().unwrap().state ==
+				crate::Game::<Test>::get(0).unwrap().state ==
 					GameState::Registration { next_player_index: 0 }
 			);
 
@@ -1280,6 +1332,7 @@ mod games_scheduling {
 					AccountOrPerson::Account(account_id) => {
 						assert_ok!(Game::sign_up_with_account(
 							RuntimeOrigin::signed(account_id.clone()),
+							0,
 							DEFAULT_IDENTIFIER_KEY,
 							None,
 						));
@@ -1288,6 +1341,7 @@ mod games_scheduling {
 						let stmt_account = AccountId32::new(*alias);
 						assert_ok!(Game::sign_up_with_alias(
 							runtime_origin_for_alias(alias),
+							0,
 							DEFAULT_IDENTIFIER_KEY,
 							stmt_account.clone(),
 							AccountAuthority(stmt_account),
@@ -1305,14 +1359,14 @@ mod games_scheduling {
 			});
 			advance_process_with_weights(weights, Weight::zero()); // register to shuffle
 			assert_eq!(
-				crate::Game::<Test>::get().unwrap().state,
+				crate::Game::<Test>::get(0).unwrap().state,
 				GameState::Shuffle { step: ShuffleStep::Step1Insert { last_iteration: None } }
 			);
 
 			advance_process_with_weights(weights, weights); // some incomplete shuffle
 			assert!(
 				matches!(
-					crate::Game::<Test>::get().unwrap().state,
+					crate::Game::<Test>::get(0).unwrap().state,
 					GameState::Shuffle {
 						step: ShuffleStep::Step1Insert { last_iteration: Some(_) }
 					},
@@ -1323,10 +1377,10 @@ mod games_scheduling {
 			let mut stop_at_step2 = false;
 
 			// Finish the shuffle
-			while matches!(crate::Game::<Test>::get().unwrap().state, GameState::Shuffle { .. }) {
+			while matches!(crate::Game::<Test>::get(0).unwrap().state, GameState::Shuffle { .. }) {
 				advance_process_with_weights(weights, weights); // continue the shuffle
 				stop_at_step2 |= matches!(
-					crate::Game::<Test>::get().unwrap().state,
+					crate::Game::<Test>::get(0).unwrap().state,
 					GameState::Shuffle { step: ShuffleStep::Step2Retrieve { .. }}
 				);
 			}
@@ -1335,7 +1389,7 @@ mod games_scheduling {
 
 			// Now state is in report
 			assert_eq!(
-				crate::Game::<Test>::get().unwrap().state,
+				crate::Game::<Test>::get(0).unwrap().state,
 				GameState::Reporting { player_count: 1000 }
 			);
 
@@ -1368,16 +1422,34 @@ mod games_scheduling {
 			});
 			advance_process_with_weights(weights, Weight::zero()); // report to player process
 			assert!(matches!(
-				crate::Game::<Test>::get().unwrap().state,
-				GameState::PlayerProcess {
-					step: PlayerProcessStep::Step1ProcessPlayers { last_iteration: None, .. },
-				}
+				crate::Game::<Test>::get(0).unwrap().state,
+				GameState::PlayerProcess { step: PlayerProcessStep::Step0ScoreReports { .. } }
+			));
+
+			advance_process_with_weights(weights, weights); // some incomplete report scoring
+			assert!(
+				matches!(
+					crate::Game::<Test>::get(0).unwrap().state,
+					GameState::PlayerProcess { step: PlayerProcessStep::Step0ScoreReports { .. } }
+				),
+				"Phase must still be in report scoring, we are testing for multi block processing",
+			);
+			while matches!(
+				crate::Game::<Test>::get(0).unwrap().state,
+				GameState::PlayerProcess { step: PlayerProcessStep::Step0ScoreReports { .. } }
+			) {
+				advance_process_with_weights(weights, Weight::zero());
+			}
+			assert_eq!(SubmittedReports::<Test>::iter().count(), 0);
+			assert!(matches!(
+				crate::Game::<Test>::get(0).unwrap().state,
+				GameState::PlayerProcess { step: PlayerProcessStep::Step1ProcessPlayers { .. } }
 			));
 
 			advance_process_with_weights(weights, weights); // some incomplete player process
 			assert!(
 				matches!(
-					crate::Game::<Test>::get().unwrap().state,
+					crate::Game::<Test>::get(0).unwrap().state,
 					GameState::PlayerProcess {
 						step: PlayerProcessStep::Step1ProcessPlayers {
 							last_iteration: Some(_),
@@ -1389,19 +1461,19 @@ mod games_scheduling {
 			);
 
 			// finish the player process, then the clearing-indices and ending phases
-			while crate::Game::<Test>::get().is_some() {
+			while crate::Game::<Test>::get(0).is_some() {
 				// We use zero for idle weight in order not to trigger the next game in on_idle.
 				advance_process_with_weights(weights, Weight::zero());
 			}
 
 			// Now the game is finished
-			assert!(crate::Game::<Test>::get().is_none());
+			assert!(crate::Game::<Test>::get(0).is_none());
 
 			advance_process_with_weights(weights, weights); // finished game to next game
 
 			// New game has started
 			assert_eq!(
-				crate::Game::<Test>::get().unwrap().state,
+				crate::Game::<Test>::get(0).unwrap().state,
 				GameState::Registration { next_player_index: 0 }
 			);
 		});
@@ -1608,6 +1680,7 @@ fn play_deposit_change_only_applies_to_future_signups() {
 
 		assert_ok!(Game::sign_up_with_account(
 			RuntimeOrigin::signed(ALICE),
+			0,
 			DEFAULT_IDENTIFIER_KEY,
 			None,
 		));
@@ -1625,6 +1698,7 @@ fn play_deposit_change_only_applies_to_future_signups() {
 
 		assert_ok!(Game::sign_up_with_account(
 			RuntimeOrigin::signed(BOB),
+			0,
 			DEFAULT_IDENTIFIER_KEY,
 			None
 		));
@@ -1671,6 +1745,7 @@ fn deposit_required_if_no_invite_and_account() {
 		// An Account (ID=1) signs up with NO invite => deposit must be created.
 		assert_ok!(Game::sign_up_with_account(
 			RuntimeOrigin::signed(ALICE),
+			0,
 			DEFAULT_IDENTIFIER_KEY,
 			None,
 		));
@@ -1709,6 +1784,7 @@ fn deposit_not_required_for_person() {
 		let stmt_account = AccountId32::new(alias_person);
 		assert_ok!(Game::sign_up_with_alias(
 			alias_origin.clone(),
+			0,
 			DEFAULT_IDENTIFIER_KEY,
 			stmt_account.clone(),
 			AccountAuthority(stmt_account),
@@ -1764,6 +1840,7 @@ fn deposit_not_required_for_person_reached_personhood() {
 		// Signs up => no deposit required.
 		assert_ok!(Game::sign_up_with_account(
 			RuntimeOrigin::signed(ALICE),
+			0,
 			DEFAULT_IDENTIFIER_KEY,
 			None,
 		));
@@ -2010,6 +2087,7 @@ fn invited_player_deposit_scenarios() {
 					INVITED,
 					crate::GameAsInvitedData { nonce, inviter: INVITER, ticket, signature },
 					Call::sign_up_with_invite {
+						region: 0,
 						identifier_key: DEFAULT_IDENTIFIER_KEY,
 						airdrop: None
 					},
@@ -2018,6 +2096,7 @@ fn invited_player_deposit_scenarios() {
 				assert_ok!(exec_signed_tx(
 					INVITED,
 					Call::sign_up_with_account {
+						region: 0,
 						identifier_key: DEFAULT_IDENTIFIER_KEY,
 						airdrop: None
 					},
@@ -2258,6 +2337,7 @@ fn test_invited_player_never_pay_fees() {
 						signature: signature.clone()
 					},
 					Call::sign_up_with_invite {
+						region: 0,
 						identifier_key: DEFAULT_IDENTIFIER_KEY,
 						airdrop: None
 					}
@@ -2289,6 +2369,7 @@ fn test_invited_player_never_pay_fees() {
 					NOT_FUNDED_ACCOUNT,
 					2, // participant nonce
 					Call::sign_up_with_account {
+						region: 0,
 						identifier_key: DEFAULT_IDENTIFIER_KEY,
 						airdrop: None
 					}
@@ -2327,6 +2408,7 @@ fn test_non_invited_player_scenario_dont_pay_fees() {
 				// We don't use a transaction extension, because we want to be able to pay.
 				assert_ok!(Game::sign_up_with_account(
 					RuntimeOrigin::signed(NOT_FUNDED_ACCOUNT),
+					0,
 					DEFAULT_IDENTIFIER_KEY,
 					None,
 				));
@@ -2354,6 +2436,7 @@ fn test_non_invited_player_scenario_dont_pay_fees() {
 					NOT_FUNDED_ACCOUNT,
 					1, // nonce
 					Call::sign_up_with_account {
+						region: 0,
 						identifier_key: DEFAULT_IDENTIFIER_KEY,
 						airdrop: None
 					}
@@ -2411,6 +2494,7 @@ fn sufficient_scenario_1_offboarded() {
 						signature: signature.clone()
 					},
 					Call::sign_up_with_invite {
+						region: 0,
 						identifier_key: DEFAULT_IDENTIFIER_KEY,
 						airdrop: None
 					}
@@ -2471,6 +2555,7 @@ fn sufficient_scenario_2_archived() {
 						signature: signature.clone()
 					},
 					Call::sign_up_with_invite {
+						region: 0,
 						identifier_key: DEFAULT_IDENTIFIER_KEY,
 						airdrop: None
 					}
@@ -2533,7 +2618,11 @@ fn use_invite_but_already_playing() {
 			exec_invited_tx(
 				ALICE,
 				crate::GameAsInvitedData { nonce: 0, inviter: INVITER, ticket, signature },
-				Call::sign_up_with_invite { identifier_key: DEFAULT_IDENTIFIER_KEY, airdrop: None },
+				Call::sign_up_with_invite {
+					region: 0,
+					identifier_key: DEFAULT_IDENTIFIER_KEY,
+					airdrop: None
+				},
 			),
 			InvalidTransaction::Custom(142),
 		);
@@ -2558,6 +2647,7 @@ fn sign_up_fails_if_statement_account_already_used() {
 		let alias_1 = [1u8; 32];
 		assert_ok!(Game::sign_up_with_alias(
 			runtime_origin_for_alias(&alias_1),
+			0,
 			DEFAULT_IDENTIFIER_KEY,
 			BOB, // statement account
 			AccountAuthority(BOB),
@@ -2566,7 +2656,7 @@ fn sign_up_fails_if_statement_account_already_used() {
 
 		// Now the same AccountId (`BOB`) tries to sign-up as an account player -> must fail
 		assert_noop!(
-			Game::sign_up_with_account(RuntimeOrigin::signed(BOB), DEFAULT_IDENTIFIER_KEY, None),
+			Game::sign_up_with_account(RuntimeOrigin::signed(BOB), 0, DEFAULT_IDENTIFIER_KEY, None),
 			Error::<Test>::StatementAccountAlreadyInUse
 		);
 	});
@@ -2586,6 +2676,7 @@ fn sign_up_fails_if_statement_account_already_used() {
 		let alias_1 = [1u8; 32];
 		assert_ok!(Game::sign_up_with_alias(
 			runtime_origin_for_alias(&alias_1),
+			0,
 			DEFAULT_IDENTIFIER_KEY,
 			BOB,
 			AccountAuthority(BOB),
@@ -2597,6 +2688,7 @@ fn sign_up_fails_if_statement_account_already_used() {
 		assert_noop!(
 			Game::sign_up_with_alias(
 				runtime_origin_for_alias(&alias_2),
+				0,
 				DEFAULT_IDENTIFIER_KEY,
 				BOB,
 				AccountAuthority(BOB),
@@ -2620,6 +2712,7 @@ fn sign_up_fails_if_statement_account_already_used() {
 		// Account-based player (`BOB`) signs up first
 		assert_ok!(Game::sign_up_with_account(
 			RuntimeOrigin::signed(BOB),
+			0,
 			DEFAULT_IDENTIFIER_KEY,
 			None
 		));
@@ -2629,6 +2722,7 @@ fn sign_up_fails_if_statement_account_already_used() {
 		assert_noop!(
 			Game::sign_up_with_alias(
 				runtime_origin_for_alias(&alias_1),
+				0,
 				DEFAULT_IDENTIFIER_KEY,
 				BOB,
 				AccountAuthority(BOB),
@@ -2706,6 +2800,7 @@ fn statements_cleared() {
 		assert_ok!(Game::new_game(&schedule));
 		assert_ok!(Game::sign_up_with_alias(
 			runtime_origin_for_alias(&alias),
+			0,
 			DEFAULT_IDENTIFIER_KEY,
 			old_stmt.public().into(),
 			AccountAuthority(old_stmt.public().into()),
@@ -2760,6 +2855,7 @@ fn statements_cleared() {
 		assert_ok!(Game::new_game(&schedule2));
 		assert_ok!(Game::sign_up_with_alias(
 			runtime_origin_for_alias(&alias),
+			0,
 			DEFAULT_IDENTIFIER_KEY,
 			new_stmt.public().into(),
 			AccountAuthority(new_stmt.public().into()),
@@ -2803,6 +2899,7 @@ fn statements_cleared() {
 		// Bind alias -> stmt_pair (so any statements signed by stmt_pair belong to this alias)
 		assert_ok!(Game::sign_up_with_alias(
 			runtime_origin_for_alias(&alias),
+			0,
 			DEFAULT_IDENTIFIER_KEY,
 			stmt_pair.public().into(),
 			AccountAuthority(stmt_pair.public().into()),
@@ -2885,6 +2982,7 @@ fn statements_cleared() {
 		// Sign up as a normal account player (no invite)
 		assert_ok!(Game::sign_up_with_account(
 			RuntimeOrigin::signed(account.clone()),
+			0,
 			DEFAULT_IDENTIFIER_KEY,
 			None,
 		));
@@ -2995,6 +3093,10 @@ mod game_cancellation {
 				rounds: 2,
 				max_group_size: 4,
 				airdrop_prize: Some(test_airdrop_prize()),
+				attendance_rule: AttendanceRuleKind::StrictMajority,
+				region: 0,
+				group_assignment: GroupAssignment::Shuffled,
+				phases: None,
 			};
 			assert_ok!(Game::new_game(&schedule));
 			let game_index = GameIndex::<Test>::get();
@@ -3005,6 +3107,7 @@ mod game_cancellation {
 			// Alice signs up for the game
 			assert_ok!(Game::sign_up_with_account(
 				RuntimeOrigin::signed(ALICE),
+				0,
 				DEFAULT_IDENTIFIER_KEY,
 				None,
 			));
@@ -3014,6 +3117,7 @@ mod game_cancellation {
 			let stmt_account = id_to_account(999);
 			assert_ok!(Game::sign_up_with_alias(
 				runtime_origin_for_alias(&alias),
+				0,
 				DEFAULT_IDENTIFIER_KEY,
 				stmt_account.clone(),
 				AccountAuthority(stmt_account.clone()),
@@ -3025,9 +3129,9 @@ mod game_cancellation {
 			assert_eq!(AliasToStmtAccount::<Test>::iter().count(), 1);
 
 			// Game should be in registration state
-			assert!(crate::Game::<Test>::get().is_some());
+			assert!(crate::Game::<Test>::get(0).is_some());
 			assert_eq!(
-				crate::Game::<Test>::get().unwrap().state,
+				crate::Game::<Test>::get(0).unwrap().state,
 				GameState::Registration { next_player_index: 2 }
 			);
 
@@ -3038,15 +3142,15 @@ mod game_cancellation {
 
 			// Game should be in cancelling state
 			advance_process_with_on_poll_only();
-			assert!(crate::Game::<Test>::get().is_some());
+			assert!(crate::Game::<Test>::get(0).is_some());
 			assert_eq!(
-				crate::Game::<Test>::get().unwrap().state,
+				crate::Game::<Test>::get(0).unwrap().state,
 				GameState::Cancelling { last_iteration: None }
 			);
 
 			// Game should be removed
 			advance_process();
-			assert!(crate::Game::<Test>::get().is_none());
+			assert!(crate::Game::<Test>::get(0).is_none());
 
 			// Game history entry is removed when the game is cancelled.
 			assert!(GameHistory::<Test>::get(game_index).is_none());
@@ -3072,7 +3176,7 @@ mod game_cancellation {
 			assert_eq!(score.score, 0);
 			assert_eq!(score.streak.absence(), 0);
 
-			assert!(PlayerToIndex::<Test>::get(AccountOrPerson::Account(ALICE)).is_none());
+			assert!(PlayerToIndex::<Test>::get(0, AccountOrPerson::Account(ALICE)).is_none());
 
 			// Statement account mapping is no longer cleared on cancellation.
 			assert_eq!(StmtAccountToAlias::<Test>::iter().count(), 1);
@@ -3112,6 +3216,7 @@ fn offboard_allowed_when_game_in_registration_and_player_not_registered() {
 		assert_ok!(Game::new_game(&registering));
 		assert_ok!(Game::sign_up_with_account(
 			RuntimeOrigin::signed(ALICE),
+			0,
 			DEFAULT_IDENTIFIER_KEY,
 			None,
 		));
@@ -3166,6 +3271,7 @@ fn candidate_and_people_vote_weights_applied() {
 		// Person signs-up (instantly recognised)
 		assert_ok!(Game::sign_up_with_alias(
 			alias_origin.clone(),
+			0,
 			DEFAULT_IDENTIFIER_KEY,
 			stmt_acc_person.clone(),
 			AccountAuthority(stmt_acc_person.clone()),
@@ -3175,11 +3281,13 @@ fn candidate_and_people_vote_weights_applied() {
 		// Candidate & target sign-up normally
 		assert_ok!(Game::sign_up_with_account(
 			RuntimeOrigin::signed(candidate_account.clone()),
+			0,
 			DEFAULT_IDENTIFIER_KEY,
 			None,
 		));
 		assert_ok!(Game::sign_up_with_account(
 			RuntimeOrigin::signed(target_account.clone()),
+			0,
 			DEFAULT_IDENTIFIER_KEY,
 			None,
 		));
@@ -3193,10 +3301,10 @@ fn candidate_and_people_vote_weights_applied() {
 
 		// Helper that builds a 1-round FullReport with the desired opinion on `target`.
 		let build_report = |reporter: &AccountOrPerson<AccountId32>, target_is_person: bool| {
-			let reporter_indices = PlayerToIndex::<Test>::get(reporter).unwrap();
+			let reporter_indices = PlayerToIndex::<Test>::get(0, reporter).unwrap();
 			let reporter_idx = reporter_indices[0];
 
-			let game = crate::Game::<Test>::get().unwrap();
+			let game = crate::Game::<Test>::get(0).unwrap();
 			let player_count = match game.state {
 				GameState::Reporting { player_count } => player_count,
 				_ => unreachable!("must be Reporting"),
@@ -3209,9 +3317,9 @@ fn candidate_and_people_vote_weights_applied() {
 			let mut partial: Vec<Report> = Vec::new();
 			for member_idx in groups.group_members(group_idx) {
 				if member_idx == reporter_idx {
-					continue
+					continue;
 				}
-				let member_player = IndexToPlayer::<Test>::get((0, member_idx)).unwrap();
+				let member_player = IndexToPlayer::<Test>::get(0, (0, member_idx)).unwrap();
 				let about_target =
 					member_player == AccountOrPerson::Account(target_account.clone());
 				partial.push(if about_target {
@@ -3275,6 +3383,7 @@ fn recognised_people_are_evenly_distributed() {
 			let stmt_acc = id_to_account(1_000 + i as u64);
 			assert_ok!(Game::sign_up_with_alias(
 				runtime_origin_for_alias(alias),
+				0,
 				DEFAULT_IDENTIFIER_KEY,
 				stmt_acc.clone(),
 				AccountAuthority(stmt_acc),
@@ -3287,6 +3396,7 @@ fn recognised_people_are_evenly_distributed() {
 			let acc = id_to_account(2_000 + i);
 			assert_ok!(Game::sign_up_with_account(
 				RuntimeOrigin::signed(acc),
+				0,
 				DEFAULT_IDENTIFIER_KEY,
 				None,
 			));
@@ -3299,7 +3409,7 @@ fn recognised_people_are_evenly_distributed() {
 		// Advance blocks until the game reaches Reporting.
 		loop {
 			advance_process(); // run on-poll / on-idle once
-			if let Some(g) = crate::Game::<Test>::get() {
+			if let Some(g) = crate::Game::<Test>::get(0) {
 				if matches!(g.state, GameState::Reporting { .. }) {
 					break
 				}
@@ -3310,7 +3420,7 @@ fn recognised_people_are_evenly_distributed() {
 
 		// 4. Build the GroupsSetting helper
 		let (player_cnt, max_per_group) = {
-			let game = crate::Game::<Test>::get().unwrap();
+			let game = crate::Game::<Test>::get(0).unwrap();
 			let player_count = match game.state {
 				GameState::Reporting { player_count } => player_count,
 				_ => unreachable!("must be Reporting"),
@@ -3325,7 +3435,7 @@ fn recognised_people_are_evenly_distributed() {
 			let mut grp_set: HashSet<u32> = HashSet::new();
 
 			for alias in aliases {
-				let indices = PlayerToIndex::<Test>::get(AccountOrPerson::Person(alias))
+				let indices = PlayerToIndex::<Test>::get(0, AccountOrPerson::Person(alias))
 					.expect("alias must have indices");
 				let idx = indices[round as usize];
 				grp_set.insert(groups.group_index_from_player_index(idx));
@@ -3565,6 +3675,7 @@ fn game_cancelled_due_to_shuffle_deadline_missed() {
 		// Register one account player and one person (alias + statement account).
 		assert_ok!(Game::sign_up_with_account(
 			RuntimeOrigin::signed(ALICE),
+			0,
 			DEFAULT_IDENTIFIER_KEY,
 			None,
 		));
@@ -3572,6 +3683,7 @@ fn game_cancelled_due_to_shuffle_deadline_missed() {
 		let stmt_account = id_to_account(777);
 		assert_ok!(Game::sign_up_with_alias(
 			runtime_origin_for_alias(&alias),
+			0,
 			DEFAULT_IDENTIFIER_KEY,
 			stmt_account.clone(),
 			AccountAuthority(stmt_account),
@@ -3579,9 +3691,9 @@ fn game_cancelled_due_to_shuffle_deadline_missed() {
 		));
 
 		// Sanity: we are in registration with two registered players.
-		assert!(crate::Game::<Test>::get().is_some());
+		assert!(crate::Game::<Test>::get(0).is_some());
 		assert_eq!(
-			crate::Game::<Test>::get().unwrap().state,
+			crate::Game::<Test>::get(0).unwrap().state,
 			GameState::Registration { next_player_index: 2 }
 		);
 
@@ -3592,9 +3704,9 @@ fn game_cancelled_due_to_shuffle_deadline_missed() {
 
 		// One on_poll: Registration -> Shuffle (no shuffle work yet).
 		advance_process_with_on_poll_only();
-		assert!(crate::Game::<Test>::get().is_some());
+		assert!(crate::Game::<Test>::get(0).is_some());
 		assert_eq!(
-			crate::Game::<Test>::get().unwrap().state,
+			crate::Game::<Test>::get(0).unwrap().state,
 			GameState::Shuffle { step: ShuffleStep::Step1Insert { last_iteration: None } }
 		);
 
@@ -3602,15 +3714,15 @@ fn game_cancelled_due_to_shuffle_deadline_missed() {
 		MOCK_UNIX_TIME
 			.with(|t| *t.borrow_mut() = Duration::from_secs((shuffle_deadline + 1) as u64));
 		advance_process_with_on_poll_only();
-		assert!(crate::Game::<Test>::get().is_some());
+		assert!(crate::Game::<Test>::get(0).is_some());
 		assert_eq!(
-			crate::Game::<Test>::get().unwrap().state,
+			crate::Game::<Test>::get(0).unwrap().state,
 			GameState::Cancelling { last_iteration: None }
 		);
 
 		// Let the Cancelling phase do its cleanup in the next block.
 		advance_process();
-		assert!(crate::Game::<Test>::get().is_none());
+		assert!(crate::Game::<Test>::get(0).is_none());
 
 		// Participant (ALICE) remains in score with zero score and no absence recorded.
 		let score =
@@ -3619,7 +3731,7 @@ fn game_cancelled_due_to_shuffle_deadline_missed() {
 		assert_eq!(score.streak.absence(), 0);
 
 		// No PlayerToIndex mappings should remain.
-		assert!(PlayerToIndex::<Test>::get(AccountOrPerson::Account(ALICE)).is_none());
+		assert!(PlayerToIndex::<Test>::get(0, AccountOrPerson::Account(ALICE)).is_none());
 
 		// The corresponding GameHistory entry was removed.
 		let current_index = GameIndex::<Test>::get(); // == 1 in a fresh test ext
@@ -3750,6 +3862,7 @@ fn credibility_flow_invited_to_recognized_in_score_to_archived() {
 						signature: signature.clone()
 					},
 					Call::sign_up_with_invite {
+						region: 0,
 						identifier_key: DEFAULT_IDENTIFIER_KEY,
 						airdrop: None
 					}
@@ -3799,6 +3912,7 @@ fn credibility_flow_invited_to_recognized_in_score_to_archived() {
 					// Not archived yet -> sign_up_with_account is free of deposit in this pallet.
 					assert_ok!(Game::sign_up_with_account(
 						RuntimeOrigin::signed(invited.clone()),
+						0,
 						DEFAULT_IDENTIFIER_KEY,
 						None,
 					));
@@ -3858,11 +3972,13 @@ fn vote_weight_tracks_current_recognition_and_drops_when_lost() {
 			assert_ok!(Game::new_game(&schedule));
 			assert_ok!(Game::sign_up_with_account(
 				RuntimeOrigin::signed(reporter_acc.clone()),
+				0,
 				DEFAULT_IDENTIFIER_KEY,
 				None,
 			));
 			assert_ok!(Game::sign_up_with_account(
 				RuntimeOrigin::signed(target_acc.clone()),
+				0,
 				DEFAULT_IDENTIFIER_KEY,
 				None,
 			));
@@ -4047,6 +4163,7 @@ fn statement_allowance_for_account_players() {
 		// Player 1 (ALICE) signs up - allowance should increase
 		assert_ok!(Game::sign_up_with_account(
 			RuntimeOrigin::signed(ALICE),
+			0,
 			DEFAULT_IDENTIFIER_KEY,
 			None,
 		));
@@ -4055,6 +4172,7 @@ fn statement_allowance_for_account_players() {
 		// Player 2 (BOB) signs up - allowance should increase
 		assert_ok!(Game::sign_up_with_account(
 			RuntimeOrigin::signed(BOB),
+			0,
 			DEFAULT_IDENTIFIER_KEY,
 			None
 		));
@@ -4123,6 +4241,7 @@ fn statement_allowance_for_account_players() {
 		// BOB signs up for game 2 - allowance should NOT increase (already has allowance)
 		assert_ok!(Game::sign_up_with_account(
 			RuntimeOrigin::signed(BOB),
+			0,
 			DEFAULT_IDENTIFIER_KEY,
 			None
 		));
@@ -4237,6 +4356,7 @@ fn statement_allowance_for_alias_players() {
 		assert_ok!(Game::new_game(&schedule1));
 		assert_ok!(Game::sign_up_with_alias(
 			runtime_origin_for_alias(&alias),
+			0,
 			DEFAULT_IDENTIFIER_KEY,
 			stmt_account_1.clone(),
 			AccountAuthority(stmt_account_1.clone()),
@@ -4265,6 +4385,7 @@ fn statement_allowance_for_alias_players() {
 		assert_ok!(Game::new_game(&schedule2));
 		assert_ok!(Game::sign_up_with_alias(
 			runtime_origin_for_alias(&alias),
+			0,
 			DEFAULT_IDENTIFIER_KEY,
 			stmt_account_1.clone(),
 			AccountAuthority(stmt_account_1.clone()),
@@ -4292,6 +4413,7 @@ fn statement_allowance_for_alias_players() {
 		assert_ok!(Game::new_game(&schedule3));
 		assert_ok!(Game::sign_up_with_alias(
 			runtime_origin_for_alias(&alias),
+			0,
 			DEFAULT_IDENTIFIER_KEY,
 			stmt_account_2.clone(),
 			AccountAuthority(stmt_account_2.clone()),
@@ -4320,6 +4442,7 @@ fn statement_allowance_for_alias_players() {
 		assert_ok!(Game::new_game(&schedule4));
 		assert_ok!(Game::sign_up_with_alias(
 			runtime_origin_for_alias(&alias),
+			0,
 			DEFAULT_IDENTIFIER_KEY,
 			stmt_account_2.clone(),
 			AccountAuthority(stmt_account_2.clone()),
@@ -4347,8 +4470,8 @@ fn build_report_with_opinion(
 	reporter: &AccountOrPerson<AccountId32>,
 	opinion: impl Fn(&AccountOrPerson<AccountId32>) -> Report,
 ) -> FullReport<Test> {
-	let reporter_indices = PlayerToIndex::<Test>::get(reporter).expect("reporter has indices");
-	let game = crate::Game::<Test>::get().expect("game exists");
+	let reporter_indices = PlayerToIndex::<Test>::get(0, reporter).expect("reporter has indices");
+	let game = crate::Game::<Test>::get(0).expect("game exists");
 	let player_count = match game.state {
 		GameState::Reporting { player_count } => player_count,
 		_ => panic!("game must be in Reporting state"),
@@ -4362,7 +4485,7 @@ fn build_report_with_opinion(
 			.group_members(group_idx)
 			.filter(|&idx| idx != reporter_idx)
 			.map(|idx| {
-				let member = IndexToPlayer::<Test>::get((round, idx)).expect("member exists");
+				let member = IndexToPlayer::<Test>::get(0, (round, idx)).expect("member exists");
 				opinion(&member)
 			})
 			.collect();
@@ -4377,7 +4500,7 @@ fn advance_to_reporting_phase(schedule: &GameSchedule<u32, u128>) {
 	MOCK_UNIX_TIME.with(|t| *t.borrow_mut() = Duration::from_secs((reg_end + 1) as u64));
 	advance_process(); // registration -> shuffle
 	advance_process(); // shuffle -> reporting (steps 1-4)
-	assert!(matches!(crate::Game::<Test>::get().unwrap().state, GameState::Reporting { .. }));
+	assert!(matches!(crate::Game::<Test>::get(0).unwrap().state, GameState::Reporting { .. }));
 }
 
 #[test]
@@ -4392,11 +4515,13 @@ fn early_enactment_attended_when_group_saturates_yes() {
 		assert_ok!(Game::new_game(&schedule));
 		assert_ok!(Game::sign_up_with_account(
 			RuntimeOrigin::signed(ALICE),
+			0,
 			DEFAULT_IDENTIFIER_KEY,
 			None,
 		));
 		assert_ok!(Game::sign_up_with_account(
 			RuntimeOrigin::signed(BOB),
+			0,
 			DEFAULT_IDENTIFIER_KEY,
 			None
 		));
@@ -4418,7 +4543,7 @@ fn early_enactment_attended_when_group_saturates_yes() {
 				"{acc:?} should be early-enacted as attended"
 			);
 		}
-		assert_eq!(crate::Game::<Test>::get().unwrap().pending_attendance, 0);
+		assert_eq!(crate::Game::<Test>::get(0).unwrap().pending_attendance, 0);
 	});
 }
 
@@ -4435,6 +4560,7 @@ fn early_enactment_not_attended_without_own_report() {
 		for acc in [ALICE, BOB, CHARLIE] {
 			assert_ok!(Game::sign_up_with_account(
 				RuntimeOrigin::signed(acc),
+				0,
 				DEFAULT_IDENTIFIER_KEY,
 				None,
 			));
@@ -4482,11 +4608,13 @@ fn nft_value_records_mint_time() {
 		assert_ok!(Game::new_game(&schedule));
 		assert_ok!(Game::sign_up_with_account(
 			RuntimeOrigin::signed(ALICE),
+			0,
 			DEFAULT_IDENTIFIER_KEY,
 			None,
 		));
 		assert_ok!(Game::sign_up_with_account(
 			RuntimeOrigin::signed(BOB),
+			0,
 			DEFAULT_IDENTIFIER_KEY,
 			None
 		));
@@ -4526,6 +4654,7 @@ fn notperson_nft_promoted_when_attendance_decided() {
 		for acc in [ALICE, BOB, CHARLIE, DAVE] {
 			assert_ok!(Game::sign_up_with_account(
 				RuntimeOrigin::signed(acc),
+				0,
 				DEFAULT_IDENTIFIER_KEY,
 				None,
 			));
@@ -4594,6 +4723,7 @@ fn notperson_nft_discarded_when_attestee_does_not_attend() {
 		for acc in [ALICE, BOB, CHARLIE] {
 			assert_ok!(Game::sign_up_with_account(
 				RuntimeOrigin::signed(acc),
+				0,
 				DEFAULT_IDENTIFIER_KEY,
 				None,
 			));
@@ -4647,6 +4777,7 @@ fn notperson_nft_routed_directly_when_attestee_already_attended() {
 		for acc in [ALICE, BOB, CHARLIE, DAVE] {
 			assert_ok!(Game::sign_up_with_account(
 				RuntimeOrigin::signed(acc),
+				0,
 				DEFAULT_IDENTIFIER_KEY,
 				None,
 			));
@@ -4702,6 +4833,7 @@ fn early_enactment_pending_with_partial_votes() {
 		for acc in [ALICE, BOB, CHARLIE, DAVE] {
 			assert_ok!(Game::sign_up_with_account(
 				RuntimeOrigin::signed(acc),
+				0,
 				DEFAULT_IDENTIFIER_KEY,
 				None,
 			));
@@ -4762,17 +4894,19 @@ fn pending_attendance_counts_registered_only() {
 		assert_ok!(Game::new_game(&schedule2));
 		assert_ok!(Game::sign_up_with_account(
 			RuntimeOrigin::signed(DAVE),
+			0,
 			DEFAULT_IDENTIFIER_KEY,
 			None
 		));
 		assert_ok!(Game::sign_up_with_account(
 			RuntimeOrigin::signed(EVE),
+			0,
 			DEFAULT_IDENTIFIER_KEY,
 			None
 		));
 		advance_to_reporting_phase(&schedule2);
 
-		assert_eq!(crate::Game::<Test>::get().unwrap().pending_attendance, 2);
+		assert_eq!(crate::Game::<Test>::get(0).unwrap().pending_attendance, 2);
 	});
 }
 
@@ -4788,11 +4922,13 @@ fn reporting_ends_early_when_all_attendance_determined() {
 		assert_ok!(Game::new_game(&schedule));
 		assert_ok!(Game::sign_up_with_account(
 			RuntimeOrigin::signed(ALICE),
+			0,
 			DEFAULT_IDENTIFIER_KEY,
 			None,
 		));
 		assert_ok!(Game::sign_up_with_account(
 			RuntimeOrigin::signed(BOB),
+			0,
 			DEFAULT_IDENTIFIER_KEY,
 			None
 		));
@@ -4806,7 +4942,7 @@ fn reporting_ends_early_when_all_attendance_determined() {
 		assert_ok!(Game::report(RuntimeOrigin::signed(ALICE), report.clone()));
 		assert_ok!(Game::report(RuntimeOrigin::signed(BOB), report));
 
-		let game = crate::Game::<Test>::get().unwrap();
+		let game = crate::Game::<Test>::get(0).unwrap();
 		assert_eq!(game.pending_attendance, 0);
 		assert!(
 			matches!(game.state, GameState::Reporting { .. }),
@@ -4819,7 +4955,7 @@ fn reporting_ends_early_when_all_attendance_determined() {
 		assert!(time_before < report_ends as u64);
 
 		// Run to completion without ever advancing time past `report_ends`.
-		while crate::Game::<Test>::get().is_some() {
+		while crate::Game::<Test>::get(0).is_some() {
 			advance_process();
 		}
 		let time_after = MOCK_UNIX_TIME.with(|t| *t.borrow()).as_secs();
@@ -4852,22 +4988,29 @@ fn player_process_step2_uses_marginal_weight_for_follow_up_chunks() {
 
 			let indices =
 				BoundedVec::try_from(vec![i; rounds as usize]).expect("rounds within bound");
-			PlayerToIndex::<Test>::insert(&account_or_person, indices);
-			IndexToPlayer::<Test>::insert((0u8, i), &account_or_person);
+			PlayerToIndex::<Test>::insert(0, &account_or_person, indices);
+			IndexToPlayer::<Test>::insert(0, (0u8, i), &account_or_person);
 		}
 
-		crate::Game::<Test>::put(GameInfo {
-			index: 0,
-			registration_ends: 0,
-			shuffle_deadline: 0,
-			game_date: 0,
-			report_ends: 0,
-			state: GameState::PlayerProcess { step: PlayerProcessStep::Step2ClearIndices },
-			max_group_size: <Test as Config>::MaxGroupSize::get(),
-			rounds,
-			pending_attendance: 0,
-			airdrop_scheduled: false,
-		});
+		crate::Game::<Test>::insert(
+			0,
+			GameInfo {
+				index: 0,
+				registration_ends: 0,
+				shuffle_deadline: 0,
+				game_date: 0,
+				report_ends: 0,
+				state: GameState::PlayerProcess { step: PlayerProcessStep::Step2ClearIndices },
+				max_group_size: <Test as Config>::MaxGroupSize::get(),
+				rounds,
+				pending_attendance: 0,
+				airdrop_scheduled: false,
+				attendance_rule: AttendanceRuleKind::StrictMajority,
+				region: 0,
+				group_assignment: GroupAssignment::Shuffled,
+				phases: <Test as Config>::DefaultPhaseDurations::get(),
+			},
+		);
 
 		let first_chunk_weight = <MockWeightInfo as WeightInfo>::player_process_step2();
 		let marginal_chunk_weight =
@@ -4875,9 +5018,9 @@ fn player_process_step2_uses_marginal_weight_for_follow_up_chunks() {
 		let mut meter =
 			WeightMeter::with_limit(first_chunk_weight.saturating_add(marginal_chunk_weight));
 
-		Game::player_process_step2(&mut meter);
+		Game::player_process_step2(&mut meter, 0);
 
-		assert!(crate::Game::<Test>::get().is_none());
+		assert!(crate::Game::<Test>::get(0).is_none());
 		assert_eq!(IndexToPlayer::<Test>::iter().count(), 0);
 		assert_eq!(PlayerToIndex::<Test>::iter().count(), 0);
 	});
@@ -4900,6 +5043,7 @@ fn vote_weight_snapshotted_during_shuffle() {
 		assert_ok!(Game::new_game(&schedule));
 		assert_ok!(Game::sign_up_with_alias(
 			runtime_origin_for_alias(&alias),
+			0,
 			DEFAULT_IDENTIFIER_KEY,
 			stmt_acc.clone(),
 			AccountAuthority(stmt_acc),
@@ -4907,6 +5051,7 @@ fn vote_weight_snapshotted_during_shuffle() {
 		));
 		assert_ok!(Game::sign_up_with_account(
 			RuntimeOrigin::signed(ALICE),
+			0,
 			DEFAULT_IDENTIFIER_KEY,
 			None,
 		));
@@ -4920,6 +5065,110 @@ fn vote_weight_snapshotted_during_shuffle() {
 	});
 }
 
+#[test]
+fn score_weighted_vote_weight_snapshotted_during_shuffle() {
+	new_test_ext().execute_with(|| {
+		PeopleVoteWeight::set(&5u8);
+		CandidateVoteWeight::set(&1u8);
+		indiv_pallet_score::PersonhoodThreshold::<Test>::put(4);
+
+		let alias: Alias = [10u8; 32];
+		let stmt_acc = id_to_account(10);
+		let schedule = GameSchedule::<u32, u128> {
+			game_play_time: 20,
+			rounds: 1,
+			max_group_size: 3,
+			attendance_rule: AttendanceRuleKind::ScoreWeighted,
+			region: 0,
+			..Default::default()
+		};
+		assert_ok!(Game::new_game(&schedule));
+		assert_ok!(Game::sign_up_with_alias(
+			runtime_origin_for_alias(&alias),
+			0,
+			DEFAULT_IDENTIFIER_KEY,
+			stmt_acc.clone(),
+			AccountAuthority(stmt_acc),
+			None,
+		));
+		assert_ok!(Game::sign_up_with_account(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			DEFAULT_IDENTIFIER_KEY,
+			None,
+		));
+		assert_ok!(Game::sign_up_with_account(
+			RuntimeOrigin::signed(BOB),
+			0,
+			DEFAULT_IDENTIFIER_KEY,
+			None
+		));
+		indiv_pallet_score::Participants::<Test>::mutate(
+			AccountOrPerson::Account(BOB),
+			|participant| participant.as_mut().unwrap().score = 2,
+		);
+		advance_to_reporting_phase(&schedule);
+
+		let alias_info = Players::<Test>::get(AccountOrPerson::Person(alias)).unwrap();
+		let alice_info = Players::<Test>::get(AccountOrPerson::Account(ALICE)).unwrap();
+		let bob_info = Players::<Test>::get(AccountOrPerson::Account(BOB)).unwrap();
+
+		assert_eq!(alias_info.vote_weight, 5, "alias is externally recognized");
+		assert_eq!(alice_info.vote_weight, 1, "candidate without score");
+		assert_eq!(bob_info.vote_weight, 3, "candidate halfway to personhood");
+	});
+}
+
+#[test]
+fn attendance_rules_decide_personhood() {
+	type Rule = DefaultAttendanceRule<Test>;
+	let supermajority = AttendanceRuleKind::Supermajority { threshold: Percent::from_percent(67) };
+
+	for rule in [AttendanceRuleKind::StrictMajority, AttendanceRuleKind::ScoreWeighted] {
+		assert!(Rule::is_person(&rule, 0, 0));
+		assert!(Rule::is_person(&rule, 2, 1));
+		assert!(!Rule::is_person(&rule, 1, 1));
+		assert!(!Rule::is_person(&rule, 0, 1));
+	}
+
+	assert!(Rule::is_person(&supermajority, 0, 0));
+	assert!(Rule::is_person(&supermajority, 3, 1));
+	assert!(Rule::is_person(&supermajority, 67, 33));
+	// A simple majority is not enough.
+	assert!(!Rule::is_person(&supermajority, 2, 1));
+	assert!(!Rule::is_person(&supermajority, 66, 34));
+}
+
+#[test]
+fn supermajority_attendance_settles_early_on_reachable_threshold() {
+	let rule = AttendanceRuleKind::Supermajority { threshold: Percent::from_percent(75) };
+	let player = |yes_person, no_not_person, sent_report| Player::<<Test as Config>::PlayDeposit> {
+		first_game: 0,
+		registered: true,
+		sent_report,
+		early_attendance_enactment: None,
+		yes_person,
+		no_not_person,
+		expected_max_vote_weight: 8,
+		vote_weight: 1,
+		credibility: PlayerCredibility::Invited,
+	};
+
+	// 3 of the 8 votes are already against: 75% can no longer be reached.
+	assert_eq!(
+		Game::determine_attendance(&player(0, 3, false), &rule),
+		AttendanceStatus::NotAttended
+	);
+	// Under a strict majority the outcome is still open.
+	assert_eq!(
+		Game::determine_attendance(&player(0, 3, false), &AttendanceRuleKind::StrictMajority),
+		AttendanceStatus::Pending
+	);
+	// 6 of the 8 votes are for: the remaining votes cannot drop below 75%.
+	assert_eq!(Game::determine_attendance(&player(6, 0, true), &rule), AttendanceStatus::Attended);
+	assert_eq!(Game::determine_attendance(&player(5, 0, true), &rule), AttendanceStatus::Pending);
+}
+
 #[test]
 fn expected_max_vote_weight_is_sum_over_coplayers() {
 	new_test_ext().execute_with(|| {
@@ -4937,6 +5186,7 @@ fn expected_max_vote_weight_is_sum_over_coplayers() {
 		assert_ok!(Game::new_game(&schedule));
 		assert_ok!(Game::sign_up_with_alias(
 			runtime_origin_for_alias(&alias),
+			0,
 			DEFAULT_IDENTIFIER_KEY,
 			stmt_acc.clone(),
 			AccountAuthority(stmt_acc),
@@ -4944,11 +5194,13 @@ fn expected_max_vote_weight_is_sum_over_coplayers() {
 		));
 		assert_ok!(Game::sign_up_with_account(
 			RuntimeOrigin::signed(ALICE),
+			0,
 			DEFAULT_IDENTIFIER_KEY,
 			None,
 		));
 		assert_ok!(Game::sign_up_with_account(
 			RuntimeOrigin::signed(BOB),
+			0,
 			DEFAULT_IDENTIFIER_KEY,
 			None
 		));
@@ -4987,6 +5239,7 @@ fn reporter_vote_weight_read_from_stored_snapshot() {
 		// Externally recognized reporter.
 		assert_ok!(Game::sign_up_with_alias(
 			runtime_origin_for_alias(&alias),
+			0,
 			DEFAULT_IDENTIFIER_KEY,
 			stmt_acc.clone(),
 			AccountAuthority(stmt_acc),
@@ -4995,6 +5248,7 @@ fn reporter_vote_weight_read_from_stored_snapshot() {
 		// Account-based target.
 		assert_ok!(Game::sign_up_with_account(
 			RuntimeOrigin::signed(ALICE),
+			0,
 			DEFAULT_IDENTIFIER_KEY,
 			None,
 		));
@@ -5055,6 +5309,7 @@ fn expected_max_vote_weight_does_not_truncate() {
 			let stmt_acc = id_to_account(20 + i);
 			assert_ok!(Game::sign_up_with_alias(
 				runtime_origin_for_alias(&alias),
+				0,
 				DEFAULT_IDENTIFIER_KEY,
 				stmt_acc.clone(),
 				AccountAuthority(stmt_acc),
@@ -5100,6 +5355,7 @@ fn report_post_dispatch_refunds_unused_weight() {
 		assert_ok!(Game::new_game(&schedule));
 		assert_ok!(Game::sign_up_with_alias(
 			runtime_origin_for_alias(&alias),
+			0,
 			DEFAULT_IDENTIFIER_KEY,
 			stmt_account.clone(),
 			AccountAuthority(stmt_account.clone()),
@@ -5167,19 +5423,24 @@ mod kill_current_game {
 				rounds: 2,
 				max_group_size: 3,
 				airdrop_prize: Some(test_airdrop_prize()),
+				attendance_rule: AttendanceRuleKind::StrictMajority,
+				region: 0,
+				group_assignment: GroupAssignment::Shuffled,
+				phases: None,
 			};
 			assert_ok!(Game::new_game(&schedule));
 			let game_index = crate::GameIndex::<Test>::get();
 			assert_ok!(Game::sign_up_with_account(
 				RuntimeOrigin::signed(ALICE),
+				0,
 				DEFAULT_IDENTIFIER_KEY,
 				None,
 			));
-			assert!(crate::Game::<Test>::get().is_some());
+			assert!(crate::Game::<Test>::get(0).is_some());
 
-			assert_ok!(Game::kill_current_game(RuntimeOrigin::root()));
+			assert_ok!(Game::kill_current_game(RuntimeOrigin::root(), 0));
 
-			assert!(crate::Game::<Test>::get().is_none());
+			assert!(crate::Game::<Test>::get(0).is_none());
 			// The signed-up player should no longer be marked as registered.
 			let player = AccountOrPerson::<AccountId32>::Account(ALICE);
 			let info = crate::Players::<Test>::get(&player).expect("player record retained");
@@ -5199,26 +5460,30 @@ mod kill_current_game {
 				rounds: 2,
 				max_group_size: 3,
 				airdrop_prize: Some(test_airdrop_prize()),
+				attendance_rule: AttendanceRuleKind::StrictMajority,
+				region: 0,
+				group_assignment: GroupAssignment::Shuffled,
+				phases: None,
 			};
 			assert_ok!(Game::new_game(&schedule));
 
 			assert_noop!(
-				Game::kill_current_game(RuntimeOrigin::signed(ALICE)),
+				Game::kill_current_game(RuntimeOrigin::signed(ALICE), 0),
 				DispatchError::BadOrigin,
 			);
 
 			// The game must still be in storage.
-			assert!(crate::Game::<Test>::get().is_some());
+			assert!(crate::Game::<Test>::get(0).is_some());
 		});
 	}
 
 	#[test]
 	fn errors_when_no_game_exists() {
 		new_test_ext().execute_with(|| {
-			assert!(crate::Game::<Test>::get().is_none());
+			assert!(crate::Game::<Test>::get(0).is_none());
 
 			// Nothing to kill — but the call still succeeds (no-op cleanup).
-			assert_ok!(Game::kill_current_game(RuntimeOrigin::root()));
+			assert_ok!(Game::kill_current_game(RuntimeOrigin::root(), 0));
 		});
 	}
 }
@@ -5240,18 +5505,25 @@ mod set_game_phases {
 	}
 
 	fn put_game_in_state(state: GameState<AccountId32>) {
-		GameStorage::<Test>::put(GameInfo {
-			index: 1,
-			registration_ends: 0,
-			shuffle_deadline: 0,
-			game_date: 0,
-			report_ends: 0,
-			state,
-			max_group_size: 3,
-			rounds: 1,
-			pending_attendance: 0,
-			airdrop_scheduled: false,
-		});
+		GameStorage::<Test>::insert(
+			0,
+			GameInfo {
+				index: 1,
+				registration_ends: 0,
+				shuffle_deadline: 0,
+				game_date: 0,
+				report_ends: 0,
+				state,
+				max_group_size: 3,
+				rounds: 1,
+				pending_attendance: 0,
+				airdrop_scheduled: false,
+				attendance_rule: AttendanceRuleKind::StrictMajority,
+				region: 0,
+				group_assignment: GroupAssignment::Shuffled,
+				phases: <Test as Config>::DefaultPhaseDurations::get(),
+			},
+		);
 	}
 
 	#[test]
@@ -5303,7 +5575,7 @@ mod set_game_phases {
 			};
 			assert_ok!(Game::new_game(&schedule));
 
-			let game = GameStorage::<Test>::get().expect("game exists");
+			let game = GameStorage::<Test>::get(0).expect("game exists");
 			// With our overrides:
 			//   registration_ends = game_play_time - shuffle - post_shuffle_margin = 998
 			//   report_ends       = game_play_time + reporting                     = 1001
@@ -5490,6 +5762,10 @@ mod airdrop {
 				rounds: 2,
 				max_group_size: 3,
 				airdrop_prize: Some(test_airdrop_prize()),
+				attendance_rule: AttendanceRuleKind::StrictMajority,
+				region: 0,
+				group_assignment: GroupAssignment::Shuffled,
+				phases: None,
 			};
 			let now = <Test as crate::Config>::UnixTime::now().as_secs();
 			assert_ok!(Game::new_game(&schedule));
@@ -5499,7 +5775,7 @@ mod airdrop {
 			assert_eq!(event.info.registration_starts, now);
 			assert_eq!(event.info.draw_time, GameTimes::<Test>::game_play_time(&schedule) as u64);
 			// end_time = game_play_time + reporting + the chain-configured `airdrop_claim_window`.
-			let game = GameStorage::<Test>::get().expect("game exists");
+			let game = GameStorage::<Test>::get(0).expect("game exists");
 			assert!(game.airdrop_scheduled);
 			assert_eq!(event.info.end_time, 10 + 2 + TEST_AIRDROP_CLAIM_WINDOW);
 			// The schedule call carried the schedule's airdrop_prize.
@@ -5516,6 +5792,10 @@ mod airdrop {
 				rounds: 2,
 				max_group_size: 3,
 				airdrop_prize: Some(test_airdrop_prize()),
+				attendance_rule: AttendanceRuleKind::StrictMajority,
+				region: 0,
+				group_assignment: GroupAssignment::Shuffled,
+				phases: None,
 			};
 			// `now` is set strictly before the game's registration phase starts, so the two
 			// timestamps are distinct and the test can tell them apart.
@@ -5529,7 +5809,7 @@ mod airdrop {
 			// schedule and calls `new_game` itself.
 			assert_ok!(Game::schedule_games(RuntimeOrigin::root(), vec![schedule.clone()]));
 			advance_process();
-			assert!(crate::Game::<Test>::get().is_some(), "automation started the game");
+			assert!(crate::Game::<Test>::get(0).is_some(), "automation started the game");
 			assert!(GameSchedules::<Test>::get().is_empty(), "schedule consumed by automation");
 
 			let game_index = GameIndex::<Test>::get();
@@ -5551,10 +5831,14 @@ mod airdrop {
 				rounds: 2,
 				max_group_size: 3,
 				airdrop_prize: Some(test_airdrop_prize()),
+				attendance_rule: AttendanceRuleKind::StrictMajority,
+				region: 0,
+				group_assignment: GroupAssignment::Shuffled,
+				phases: None,
 			};
 			assert_ok!(Game::new_game(&schedule));
-			assert!(crate::Game::<Test>::exists());
-			let game = GameStorage::<Test>::get().expect("game exists");
+			assert!(crate::Game::<Test>::contains_key(0));
+			let game = GameStorage::<Test>::get(0).expect("game exists");
 			// Scheduling was attempted but failed, so the event isn't in airdrop storage and
 			// `airdrop_scheduled` is `false`.
 			assert!(!game.airdrop_scheduled);
@@ -5574,11 +5858,16 @@ mod airdrop {
 				rounds: 2,
 				max_group_size: 3,
 				airdrop_prize: Some(test_airdrop_prize()),
+				attendance_rule: AttendanceRuleKind::StrictMajority,
+				region: 0,
+				group_assignment: GroupAssignment::Shuffled,
+				phases: None,
 			};
 			assert_ok!(Game::new_game(&schedule));
 			let event_id = Game::airdrop_event_id(GameIndex::<Test>::get());
 			assert_ok!(Game::sign_up_with_account(
 				RuntimeOrigin::signed(ALICE),
+				0,
 				DEFAULT_IDENTIFIER_KEY,
 				Some(account_proof()),
 			));
@@ -5598,6 +5887,10 @@ mod airdrop {
 				rounds: 2,
 				max_group_size: 3,
 				airdrop_prize: Some(test_airdrop_prize()),
+				attendance_rule: AttendanceRuleKind::StrictMajority,
+				region: 0,
+				group_assignment: GroupAssignment::Shuffled,
+				phases: None,
 			};
 			assert_ok!(Game::new_game(&schedule));
 			let event_id = Game::airdrop_event_id(GameIndex::<Test>::get());
@@ -5617,6 +5910,7 @@ mod airdrop {
 
 			assert_ok!(Game::sign_up_with_account(
 				RuntimeOrigin::signed(ALICE),
+				0,
 				DEFAULT_IDENTIFIER_KEY,
 				Some(AirdropVrf::Account(signature.clone())),
 			));
@@ -5645,6 +5939,10 @@ mod airdrop {
 				rounds: 2,
 				max_group_size: 3,
 				airdrop_prize: Some(test_airdrop_prize()),
+				attendance_rule: AttendanceRuleKind::StrictMajority,
+				region: 0,
+				group_assignment: GroupAssignment::Shuffled,
+				phases: None,
 			};
 			assert_ok!(Game::new_game(&schedule));
 			let event_id = Game::airdrop_event_id(GameIndex::<Test>::get());
@@ -5654,6 +5952,7 @@ mod airdrop {
 			let stmt_account = id_to_account(0xA11A5);
 			assert_ok!(Game::sign_up_with_alias(
 				runtime_origin_for_alias(&alias),
+				0,
 				DEFAULT_IDENTIFIER_KEY,
 				stmt_account.clone(),
 				AccountAuthority(stmt_account),
@@ -5678,11 +5977,16 @@ mod airdrop {
 				rounds: 2,
 				max_group_size: 3,
 				airdrop_prize: Some(test_airdrop_prize()),
+				attendance_rule: AttendanceRuleKind::StrictMajority,
+				region: 0,
+				group_assignment: GroupAssignment::Shuffled,
+				phases: None,
 			};
 			assert_ok!(Game::new_game(&schedule));
 			assert_noop!(
 				Game::sign_up_with_account(
 					RuntimeOrigin::signed(ALICE),
+					0,
 					DEFAULT_IDENTIFIER_KEY,
 					Some(alias_proof()),
 				),
@@ -5699,6 +6003,10 @@ mod airdrop {
 				rounds: 2,
 				max_group_size: 3,
 				airdrop_prize: Some(test_airdrop_prize()),
+				attendance_rule: AttendanceRuleKind::StrictMajority,
+				region: 0,
+				group_assignment: GroupAssignment::Shuffled,
+				phases: None,
 			};
 			assert_ok!(Game::new_game(&schedule));
 			// Pre-recognized account player: in `Participants` as `Recognized(_)` and in
@@ -5711,6 +6019,7 @@ mod airdrop {
 			assert_noop!(
 				Game::sign_up_with_account(
 					RuntimeOrigin::signed(ALICE),
+					0,
 					DEFAULT_IDENTIFIER_KEY,
 					Some(account_proof()),
 				),
@@ -5731,6 +6040,10 @@ mod airdrop {
 				rounds: 2,
 				max_group_size: 3,
 				airdrop_prize: Some(test_airdrop_prize()),
+				attendance_rule: AttendanceRuleKind::StrictMajority,
+				region: 0,
+				group_assignment: GroupAssignment::Shuffled,
+				phases: None,
 			};
 			assert_ok!(Game::new_game(&schedule));
 			let event_id = Game::airdrop_event_id(GameIndex::<Test>::get());
@@ -5738,6 +6051,7 @@ mod airdrop {
 			assert_noop!(
 				Game::sign_up_with_account(
 					RuntimeOrigin::signed(ALICE),
+					0,
 					DEFAULT_IDENTIFIER_KEY,
 					Some(account_proof()),
 				),
@@ -5762,6 +6076,10 @@ mod airdrop {
 				rounds: 2,
 				max_group_size: 3,
 				airdrop_prize: Some(test_airdrop_prize()),
+				attendance_rule: AttendanceRuleKind::StrictMajority,
+				region: 0,
+				group_assignment: GroupAssignment::Shuffled,
+				phases: None,
 			};
 			assert_ok!(Game::new_game(&schedule));
 
@@ -5783,6 +6101,7 @@ mod airdrop {
 					ALICE,
 					tx_ext,
 					Call::sign_up_with_invite {
+						region: 0,
 						identifier_key: DEFAULT_IDENTIFIER_KEY,
 						airdrop: Some(account_proof()),
 					},
@@ -5808,6 +6127,10 @@ mod airdrop {
 				rounds: 2,
 				max_group_size: 3,
 				airdrop_prize: Some(test_airdrop_prize()),
+				attendance_rule: AttendanceRuleKind::StrictMajority,
+				region: 0,
+				group_assignment: GroupAssignment::Shuffled,
+				phases: None,
 			};
 			assert_ok!(Game::new_game(&schedule));
 
@@ -5825,6 +6148,7 @@ mod airdrop {
 					ALICE,
 					tx_ext,
 					Call::sign_up_with_invite {
+						region: 0,
 						identifier_key: DEFAULT_IDENTIFIER_KEY,
 						airdrop: Some(alias_proof()),
 					},
@@ -5856,6 +6180,10 @@ mod airdrop {
 				rounds: 2,
 				max_group_size: 3,
 				airdrop_prize: Some(test_airdrop_prize()),
+				attendance_rule: AttendanceRuleKind::StrictMajority,
+				region: 0,
+				group_assignment: GroupAssignment::Shuffled,
+				phases: None,
 			};
 			assert_ok!(Game::new_game(&schedule));
 			let event_id = Game::airdrop_event_id(GameIndex::<Test>::get());
@@ -5879,6 +6207,7 @@ mod airdrop {
 			let nonce = frame_system::Account::<Test>::get(&ALICE).nonce;
 			let x = mock::Extrinsic::new_signed(
 				Call::sign_up_with_invite {
+					region: 0,
 					identifier_key: DEFAULT_IDENTIFIER_KEY,
 					airdrop: Some(AirdropVrf::Account(signature.clone())),
 				}
@@ -5974,6 +6303,10 @@ mod airdrop {
 				rounds: 2,
 				max_group_size: 3,
 				airdrop_prize: Some(test_airdrop_prize()),
+				attendance_rule: AttendanceRuleKind::StrictMajority,
+				region: 0,
+				group_assignment: GroupAssignment::Shuffled,
+				phases: None,
 			};
 			assert_ok!(Game::new_game(&schedule));
 			let game_index = GameIndex::<Test>::get();
@@ -5989,6 +6322,7 @@ mod airdrop {
 			);
 			assert_ok!(Game::sign_up_with_account(
 				RuntimeOrigin::signed(ALICE),
+				0,
 				DEFAULT_IDENTIFIER_KEY,
 				Some(AirdropVrf::Account(signature)),
 			));
@@ -6026,6 +6360,10 @@ mod airdrop {
 				rounds: 2,
 				max_group_size: 3,
 				airdrop_prize: Some(test_airdrop_prize()),
+				attendance_rule: AttendanceRuleKind::StrictMajority,
+				region: 0,
+				group_assignment: GroupAssignment::Shuffled,
+				phases: None,
 			};
 			assert_ok!(Game::new_game(&schedule));
 			let game_index = GameIndex::<Test>::get();
@@ -6043,6 +6381,7 @@ mod airdrop {
 			// Sign up with the Alias VRF (only valid because the player is recognized).
 			assert_ok!(Game::sign_up_with_account(
 				RuntimeOrigin::signed(ALICE),
+				0,
 				DEFAULT_IDENTIFIER_KEY,
 				Some(alias_proof()),
 			));
@@ -6194,6 +6533,10 @@ mod airdrop {
 				rounds: 2,
 				max_group_size: 2,
 				airdrop_prize: Some(test_airdrop_prize()),
+				attendance_rule: AttendanceRuleKind::StrictMajority,
+				region: 0,
+				group_assignment: GroupAssignment::Shuffled,
+				phases: None,
 			};
 			assert_ok!(Game::new_game(&schedule));
 			let game_index = GameIndex::<Test>::get();
@@ -6217,6 +6560,7 @@ mod airdrop {
 			);
 			assert_ok!(Game::sign_up_with_account(
 				RuntimeOrigin::signed(ALICE),
+				0,
 				DEFAULT_IDENTIFIER_KEY,
 				Some(AirdropVrf::Account(signature)),
 			));
@@ -6228,7 +6572,7 @@ mod airdrop {
 			advance_process(); // registration -> shuffle
 			advance_process(); // shuffle -> reporting
 			assert!(matches!(
-				GameStorage::<Test>::get().expect("game remains running").state,
+				GameStorage::<Test>::get(0).expect("game remains running").state,
 				GameState::Reporting { .. },
 			));
 
@@ -6274,7 +6618,7 @@ mod airdrop {
 				))
 			}));
 
-			assert_ok!(Game::kill_current_game(RuntimeOrigin::root()));
+			assert_ok!(Game::kill_current_game(RuntimeOrigin::root(), 0));
 			assert!(matches!(
 				AirdropEvents::<Test>::get(event_id)
 					.expect("event is clearing after cancel")
@@ -6301,6 +6645,10 @@ mod airdrop {
 				rounds: 2,
 				max_group_size: 3,
 				airdrop_prize: Some(test_airdrop_prize()),
+				attendance_rule: AttendanceRuleKind::StrictMajority,
+				region: 0,
+				group_assignment: GroupAssignment::Shuffled,
+				phases: None,
 			};
 			assert_ok!(Game::new_game(&schedule));
 			let game_index = GameIndex::<Test>::get();
@@ -6312,9 +6660,995 @@ mod airdrop {
 					.status,
 				indiv_pallet_airdrop::types::Status::Scheduled,
 			));
-			assert_ok!(Game::kill_current_game(RuntimeOrigin::root()));
+			assert_ok!(Game::kill_current_game(RuntimeOrigin::root(), 0));
 			// Cancelling a not-yet-started event drops it and releases the full prize allocation.
 			assert!(indiv_pallet_airdrop::Events::<Test>::get(event_id).is_none());
 		});
 	}
 }
+
+mod report_consistency {
+	use super::*;
+
+	const PLAYERS: [AccountId32; 4] = [ALICE, BOB, CHARLIE, DAVE];
+
+	// All the players play in one group. Dave reports everyone as not a person, the others
+	// report everyone as a person.
+	fn play_game_with_outlier() {
+		let schedule = GameSchedule::<u32, u128> {
+			game_play_time: 20,
+			rounds: 1,
+			max_group_size: 4,
+			..Default::default()
+		};
+		assert_ok!(Game::new_game(&schedule));
+		for player in PLAYERS {
+			assert_ok!(Game::sign_up_with_account(
+				RuntimeOrigin::signed(player),
+				0,
+				DEFAULT_IDENTIFIER_KEY,
+				None,
+			));
+		}
+		indiv_pallet_score::Participants::<Test>::mutate(
+			AccountOrPerson::Account(DAVE),
+			|participant| participant.as_mut().unwrap().score = 10,
+		);
+		advance_to_reporting_phase(&schedule);
+
+		for player in PLAYERS {
+			let report = if player == DAVE { Report::NotPerson } else { Report::Person };
+			assert_ok!(Game::report(
+				RuntimeOrigin::signed(player),
+				vec![vec![report; 3].try_into().unwrap()].try_into().unwrap()
+			));
+		}
+		assert_eq!(SubmittedReports::<Test>::iter().count(), 4);
+
+		let report_ends = GameTimes::<Test>::reporting_end(&schedule);
+		MOCK_UNIX_TIME.with(|t| *t.borrow_mut() = Duration::from_secs((report_ends + 1) as u64));
+		advance_process(); // report to player process step0
+		advance_process(); // step0 and step1 to step2
+		assert!(SubmittedReports::<Test>::iter().next().is_none());
+	}
+
+	#[test]
+	fn reports_are_scored_against_the_outcome() {
+		new_test_ext().execute_with(|| {
+			play_game_with_outlier();
+
+			for player in [ALICE, BOB, CHARLIE] {
+				System::assert_has_event(
+					Event::<Test>::ReportConsistencyScored {
+						who: AccountOrPerson::Account(player.clone()),
+						game_index: GameIndex::<Test>::get(),
+						agreed: 3,
+						total: 3,
+						inconsistent_streak: 0,
+					}
+					.into(),
+				);
+			}
+			// Everyone is a person despite Dave's reports.
+			System::assert_has_event(
+				Event::<Test>::ReportConsistencyScored {
+					who: AccountOrPerson::Account(DAVE),
+					game_index: GameIndex::<Test>::get(),
+					agreed: 0,
+					total: 3,
+					inconsistent_streak: 1,
+				}
+				.into(),
+			);
+			assert_eq!(InconsistentReportStreak::<Test>::get(AccountOrPerson::Account(DAVE)), 1);
+			assert_eq!(InconsistentReportStreak::<Test>::get(AccountOrPerson::Account(ALICE)), 0);
+
+			// No penalty is configured.
+			assert!(!System::events().iter().any(|record| matches!(
+				record.event,
+				RuntimeEvent::Game(Event::InconsistentReporterPenalized { .. })
+			)));
+		});
+	}
+
+	#[test]
+	fn persistently_inconsistent_reporter_is_penalized() {
+		new_test_ext().execute_with(|| {
+			MaxInconsistentReportStreak::set(&2);
+			InconsistentReportPenalty::set(&3);
+			let dave = AccountOrPerson::Account(DAVE);
+			InconsistentReportStreak::<Test>::insert(&dave, 1);
+
+			play_game_with_outlier();
+
+			assert_eq!(InconsistentReportStreak::<Test>::get(&dave), 2);
+			System::assert_has_event(
+				Event::<Test>::InconsistentReporterPenalized {
+					who: dave.clone(),
+					game_index: GameIndex::<Test>::get(),
+					penalty: 3,
+					score: 8,
+				}
+				.into(),
+			);
+			// Dave attended the game, then lost the penalty.
+			assert_eq!(indiv_pallet_score::Participants::<Test>::get(&dave).unwrap().score, 8);
+		});
+	}
+
+	#[test]
+	fn consistent_report_resets_the_streak() {
+		new_test_ext().execute_with(|| {
+			InconsistentReportPenalty::set(&3);
+			let alice = AccountOrPerson::Account(ALICE);
+			InconsistentReportStreak::<Test>::insert(&alice, 5);
+
+			play_game_with_outlier();
+
+			assert!(!InconsistentReportStreak::<Test>::contains_key(&alice));
+			assert_eq!(indiv_pallet_score::Participants::<Test>::get(&alice).unwrap().score, 1);
+		});
+	}
+}
+
+mod attendance_disputes {
+	use super::*;
+	use indiv_support::traits::{Judgement, JudgementContext, Statement as OracleStatement, Truth};
+
+	const PLAYERS: [AccountId32; 4] = [ALICE, BOB, CHARLIE, DAVE];
+
+	// All the players play in one group. Dave is reported as not a person by everyone else, the
+	// others are reported as persons. Dave keeps some score so they remain a player after their
+	// absence. Returns the index of the game, which is ended.
+	fn play_game_reporting_dave() -> u32 {
+		let schedule = GameSchedule::<u32, u128> {
+			game_play_time: 20,
+			rounds: 1,
+			max_group_size: 4,
+			..Default::default()
+		};
+		assert_ok!(Game::new_game(&schedule));
+		for player in PLAYERS {
+			assert_ok!(Game::sign_up_with_account(
+				RuntimeOrigin::signed(player),
+				0,
+				DEFAULT_IDENTIFIER_KEY,
+				None,
+			));
+		}
+		let dave = AccountOrPerson::Account(DAVE);
+		indiv_pallet_score::Participants::<Test>::mutate(&dave, |participant| {
+			participant.as_mut().unwrap().score = 10
+		});
+		advance_to_reporting_phase(&schedule);
+		let game_index = crate::Game::<Test>::get(0).unwrap().index;
+
+		let dave_index = PlayerToIndex::<Test>::get(0, &dave).unwrap()[0];
+		for player in PLAYERS {
+			let index = PlayerToIndex::<Test>::get(0, AccountOrPerson::Account(player.clone()))
+				.unwrap()[0];
+			let round_report = (0..4)
+				.filter(|&i| i != index)
+				.map(|i| if i == dave_index { Report::NotPerson } else { Report::Person })
+				.collect::<Vec<_>>();
+			assert_ok!(Game::report(
+				RuntimeOrigin::signed(player),
+				vec![round_report.try_into().unwrap()].try_into().unwrap()
+			));
+		}
+
+		// Dave's absence was enacted early, it can only be disputed once the game ended.
+		assert_noop!(Game::dispute_attendance(RuntimeOrigin::signed(DAVE)), Error::<Test>::Early);
+
+		let report_ends = GameTimes::<Test>::reporting_end(&schedule);
+		MOCK_UNIX_TIME.with(|t| *t.borrow_mut() = Duration::from_secs((report_ends + 1) as u64));
+		while crate::Game::<Test>::get(0).is_some() {
+			advance_process();
+		}
+
+		game_index
+	}
+
+	#[test]
+	fn absence_is_restored_when_the_oracle_finds_for_the_player() {
+		new_test_ext().execute_with(|| {
+			let game_index = play_game_reporting_dave();
+			let dave = AccountOrPerson::Account(DAVE);
+
+			// Only the player reported as not a person can dispute their absence.
+			let dispute = AttendanceDisputes::<Test>::get(&dave).unwrap();
+			assert_eq!(dispute.game_index, game_index);
+			assert_eq!(dispute.groups.into_inner(), vec![0]);
+			assert_eq!(dispute.case, None);
+			for player in [ALICE, BOB, CHARLIE] {
+				assert_noop!(
+					Game::dispute_attendance(RuntimeOrigin::signed(player)),
+					Error::<Test>::NoAttendanceDispute
+				);
+			}
+			assert_eq!(indiv_pallet_score::Participants::<Test>::get(&dave).unwrap().score, 9);
+
+			assert_ok!(Game::dispute_attendance(RuntimeOrigin::signed(DAVE)));
+			System::assert_last_event(
+				Event::<Test>::AttendanceDisputed { who: dave.clone(), game_index }.into(),
+			);
+			let context = JudgementContext::truncate_from(dave.encode());
+			let groups = BoundedVec::truncate_from(vec![0]);
+			ORACLE_STATEMENTS.with(|statements| {
+				assert_eq!(
+					*statements.borrow(),
+					vec![(OracleStatement::GameAttendance { game_index, groups }, context.clone())]
+				);
+			});
+			let dispute = AttendanceDisputes::<Test>::get(&dave).unwrap();
+			assert_eq!(dispute.case, Some(0));
+			let deposit = dispute.deposit.unwrap();
+			assert!(deposit::DepositStorage::<Test>::get().active.contains(&(DAVE, deposit)));
+			assert_noop!(
+				Game::dispute_attendance(RuntimeOrigin::signed(DAVE)),
+				Error::<Test>::AttendanceAlreadyDisputed
+			);
+
+			assert_ok!(Game::attendance_judged(
+				RuntimeOrigin::root(),
+				0,
+				context,
+				Judgement::Truth(Truth::True)
+			));
+			System::assert_last_event(
+				Event::<Test>::AttendanceRestored { who: dave.clone(), game_index }.into(),
+			);
+			assert!(!AttendanceDisputes::<Test>::contains_key(&dave));
+			assert!(deposit::DepositStorage::<Test>::get().dropped.contains(&deposit));
+
+			// The penalty is refunded and the attendance credited.
+			let participant = indiv_pallet_score::Participants::<Test>::get(&dave).unwrap();
+			assert_eq!(participant.score, 11);
+			assert_eq!(participant.last_attended_game, Some(game_index));
+			assert!(PlayerAttendanceHistory::<Test>::get(&dave).contains(&game_index));
+		});
+	}
+
+	#[test]
+	fn absence_stands_when_the_oracle_does_not_find_for_the_player() {
+		new_test_ext().execute_with(|| {
+			let game_index = play_game_reporting_dave();
+			let dave = AccountOrPerson::Account(DAVE);
+			assert_ok!(Game::dispute_attendance(RuntimeOrigin::signed(DAVE)));
+			let deposit = AttendanceDisputes::<Test>::get(&dave).unwrap().deposit.unwrap();
+			let context = JudgementContext::truncate_from(dave.encode());
+
+			assert_noop!(
+				Game::attendance_judged(
+					RuntimeOrigin::signed(DAVE),
+					0,
+					context.clone(),
+					Judgement::Truth(Truth::True)
+				),
+				sp_runtime::DispatchError::BadOrigin
+			);
+			assert_noop!(
+				Game::attendance_judged(
+					RuntimeOrigin::root(),
+					1,
+					context.clone(),
+					Judgement::Truth(Truth::True)
+				),
+				Error::<Test>::UnexpectedJudgement
+			);
+			assert_noop!(
+				Game::attendance_judged(
+					RuntimeOrigin::root(),
+					0,
+					Default::default(),
+					Judgement::Truth(Truth::True)
+				),
+				Error::<Test>::BadJudgementContext
+			);
+
+			assert_ok!(Game::attendance_judged(
+				RuntimeOrigin::root(),
+				0,
+				context,
+				Judgement::Truth(Truth::False)
+			));
+			System::assert_last_event(
+				Event::<Test>::AttendanceDisputeRejected {
+					who: dave.clone(),
+					game_index,
+					judgement: Judgement::Truth(Truth::False),
+				}
+				.into(),
+			);
+			assert!(!AttendanceDisputes::<Test>::contains_key(&dave));
+			assert!(deposit::DepositStorage::<Test>::get().burned.contains(&deposit));
+			assert_eq!(indiv_pallet_score::Participants::<Test>::get(&dave).unwrap().score, 9);
+		});
+	}
+
+	#[test]
+	fn offboarding_releases_the_dispute_deposit() {
+		new_test_ext().execute_with(|| {
+			play_game_reporting_dave();
+			let dave = AccountOrPerson::Account(DAVE);
+			assert_ok!(Game::dispute_attendance(RuntimeOrigin::signed(DAVE)));
+			let deposit = AttendanceDisputes::<Test>::get(&dave).unwrap().deposit.unwrap();
+
+			assert_ok!(Game::offboard(RuntimeOrigin::signed(DAVE)));
+			assert!(!AttendanceDisputes::<Test>::contains_key(&dave));
+			assert!(deposit::DepositStorage::<Test>::get().dropped.contains(&deposit));
+		});
+	}
+
+	#[test]
+	fn dispute_window_is_bounded() {
+		new_test_ext().execute_with(|| {
+			play_game_reporting_dave();
+			let dave = AccountOrPerson::Account(DAVE);
+
+			let dispute_until = AttendanceDisputes::<Test>::get(&dave).unwrap().dispute_until;
+			System::set_block_number(dispute_until + 1);
+			assert_noop!(
+				Game::dispute_attendance(RuntimeOrigin::signed(DAVE)),
+				Error::<Test>::AttendanceDisputeWindowClosed
+			);
+		});
+	}
+}
+
+mod regions {
+	use super::*;
+
+	fn schedule_in(region: GameRegion, game_play_time: u32) -> GameSchedule<u32, u128> {
+		GameSchedule::<u32, u128> {
+			game_play_time,
+			rounds: 2,
+			max_group_size: 3,
+			region,
+			..Default::default()
+		}
+	}
+
+	#[test]
+	fn games_of_different_regions_run_concurrently() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(Game::new_game(&schedule_in(0, 10)));
+			assert_ok!(Game::new_game(&schedule_in(1, 10)));
+
+			// A region only runs one game at a time.
+			assert_noop!(Game::new_game(&schedule_in(1, 20)), Error::<Test>::GameOngoing);
+
+			assert_ok!(Game::sign_up_with_account(
+				RuntimeOrigin::signed(ALICE),
+				0,
+				DEFAULT_IDENTIFIER_KEY,
+				None,
+			));
+			assert_ok!(Game::sign_up_with_account(
+				RuntimeOrigin::signed(BOB),
+				1,
+				DEFAULT_IDENTIFIER_KEY,
+				None,
+			));
+
+			let alice = AccountOrPerson::<AccountId32>::Account(ALICE);
+			let bob = AccountOrPerson::<AccountId32>::Account(BOB);
+			assert_eq!(crate::Players::<Test>::get(&alice).unwrap().region, 0);
+			assert_eq!(crate::Players::<Test>::get(&bob).unwrap().region, 1);
+
+			// Killing the game of a region leaves the other region untouched.
+			assert_ok!(Game::kill_current_game(RuntimeOrigin::root(), 1));
+			assert!(crate::Game::<Test>::get(1).is_none());
+			assert!(crate::Game::<Test>::get(0).is_some());
+			assert!(crate::Players::<Test>::get(&alice).unwrap().registered);
+			assert!(!crate::Players::<Test>::get(&bob).unwrap().registered);
+		});
+	}
+
+	#[test]
+	fn player_cannot_sign_up_in_two_regions() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(Game::new_game(&schedule_in(0, 10)));
+			assert_ok!(Game::new_game(&schedule_in(1, 10)));
+
+			assert_ok!(Game::sign_up_with_account(
+				RuntimeOrigin::signed(ALICE),
+				0,
+				DEFAULT_IDENTIFIER_KEY,
+				None,
+			));
+			assert_noop!(
+				Game::sign_up_with_account(
+					RuntimeOrigin::signed(ALICE),
+					1,
+					DEFAULT_IDENTIFIER_KEY,
+					None,
+				),
+				Error::<Test>::AlreadyRegistered
+			);
+		});
+	}
+
+	#[test]
+	fn schedules_only_conflict_within_their_region() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Game::schedule_games(
+				RuntimeOrigin::root(),
+				vec![schedule_in(0, 100), schedule_in(1, 100)]
+			));
+			assert_eq!(GameSchedules::<Test>::get().len(), 2);
+
+			// Overlapping a game of the same region is rejected.
+			assert_noop!(
+				Game::schedule_games(RuntimeOrigin::root(), vec![schedule_in(1, 100)]),
+				Error::<Test>::InvalidGameSetup
+			);
+
+			// Regions beyond `MaxRegions` are rejected.
+			assert_noop!(
+				Game::schedule_games(RuntimeOrigin::root(), vec![schedule_in(2, 200)]),
+				Error::<Test>::InvalidGameSetup
+			);
+
+			// Removing a schedule requires its region.
+			assert_noop!(
+				Game::remove_scheduled_game(RuntimeOrigin::root(), 2, 100),
+				Error::<Test>::NoSuchGameScheduled
+			);
+			assert_ok!(Game::remove_scheduled_game(RuntimeOrigin::root(), 1, 100));
+			assert_eq!(GameSchedules::<Test>::get()[0].region, 0);
+		});
+	}
+
+	#[test]
+	fn processing_ignores_players_of_other_regions() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(Game::new_game(&schedule_in(1, 100)));
+			assert_ok!(Game::sign_up_with_account(
+				RuntimeOrigin::signed(BOB),
+				1,
+				DEFAULT_IDENTIFIER_KEY,
+				None,
+			));
+
+			// A game of region 0 runs to completion meanwhile.
+			run_game_scenario(schedule_in(0, 10), &[], |_| None);
+
+			let bob = AccountOrPerson::<AccountId32>::Account(BOB);
+			let info = crate::Players::<Test>::get(&bob).unwrap();
+			assert!(info.registered);
+			assert_eq!(info.region, 1);
+			assert!(matches!(crate::Game::<Test>::get(1).unwrap().state, GameState::Registration));
+		});
+	}
+}
+
+mod group_assignment {
+	use super::*;
+
+	/// Index the players in order, with the same indices in each round.
+	fn index_players(players: &[AccountOrPerson<AccountId32>], rounds: u8) {
+		for (index, player) in players.iter().enumerate() {
+			let indices = vec![index as PlayerIndex; usize::from(rounds)];
+			PlayerToIndex::<Test>::insert(0, player, BoundedVec::truncate_from(indices));
+			for round in 0..rounds {
+				IndexToPlayer::<Test>::insert(0, (round, index as PlayerIndex), player);
+			}
+		}
+	}
+
+	fn spread(rounds: u8, groups_setting: &GroupsSetting) {
+		let (mut round, mut index) = (0, 0);
+		while let StepResult::Continue =
+			Game::shuffle_step_spread_pairings(0, &mut round, &mut index, rounds, groups_setting)
+		{}
+	}
+
+	fn group_of(
+		player: &AccountOrPerson<AccountId32>,
+		round: u8,
+		groups_setting: &GroupsSetting,
+	) -> u32 {
+		let index = PlayerToIndex::<Test>::get(0, player).unwrap()[usize::from(round)];
+		assert_eq!(IndexToPlayer::<Test>::get(0, (round, index)).as_ref(), Some(player));
+		groups_setting.group_index_from_player_index(index)
+	}
+
+	#[test]
+	fn spread_pairings_separates_recent_co_players() {
+		new_test_ext().execute_with(|| {
+			let players = [ALICE, BOB, CHARLIE, DAVE].map(AccountOrPerson::Account);
+			// Two groups: indices 0 and 2 are grouped, as are indices 1 and 3.
+			let groups_setting = GroupsSetting { max_per_group: 2, player_count: 4 };
+			index_players(&players, 1);
+			assert_eq!(group_of(&players[0], 0, &groups_setting), 0);
+			assert_eq!(group_of(&players[2], 0, &groups_setting), 0);
+
+			// Alice recently played with Charlie.
+			RecentCoPlayers::<Test>::insert(
+				&players[0],
+				BoundedVec::truncate_from(vec![players[2].clone()]),
+			);
+
+			spread(1, &groups_setting);
+
+			assert_ne!(
+				group_of(&players[0], 0, &groups_setting),
+				group_of(&players[2], 0, &groups_setting)
+			);
+			// Every player is still indexed once.
+			for player in &players {
+				group_of(player, 0, &groups_setting);
+			}
+		});
+	}
+
+	#[test]
+	fn spread_pairings_separates_co_players_of_previous_rounds() {
+		new_test_ext().execute_with(|| {
+			let players = [ALICE, BOB, CHARLIE, DAVE].map(AccountOrPerson::Account);
+			let groups_setting = GroupsSetting { max_per_group: 2, player_count: 4 };
+			// The shuffle grouped Alice with Charlie, and Bob with Dave, in both rounds.
+			index_players(&players, 2);
+
+			spread(2, &groups_setting);
+
+			// The first round is kept, the second round changes both pairs.
+			assert_eq!(
+				group_of(&players[0], 0, &groups_setting),
+				group_of(&players[2], 0, &groups_setting)
+			);
+			assert_ne!(
+				group_of(&players[0], 1, &groups_setting),
+				group_of(&players[2], 1, &groups_setting)
+			);
+			assert_ne!(
+				group_of(&players[1], 1, &groups_setting),
+				group_of(&players[3], 1, &groups_setting)
+			);
+		});
+	}
+
+	#[test]
+	fn spread_pairings_keeps_a_single_group() {
+		new_test_ext().execute_with(|| {
+			let players = [ALICE, BOB, CHARLIE].map(AccountOrPerson::Account);
+			let groups_setting = GroupsSetting { max_per_group: 3, player_count: 3 };
+			index_players(&players, 1);
+			RecentCoPlayers::<Test>::insert(
+				&players[0],
+				BoundedVec::truncate_from(vec![players[1].clone(), players[2].clone()]),
+			);
+
+			spread(1, &groups_setting);
+
+			for (index, player) in players.iter().enumerate() {
+				assert_eq!(PlayerToIndex::<Test>::get(0, player).unwrap()[0], index as PlayerIndex);
+			}
+		});
+	}
+
+	#[test]
+	fn game_records_recent_co_players_when_spreading_pairings() {
+		new_test_ext().execute_with(|| {
+			let players = [ALICE, BOB, CHARLIE, DAVE, EVE].map(AccountOrPerson::Account);
+			let schedule = GameSchedule::<u32, u128> {
+				game_play_time: 10,
+				rounds: 2,
+				max_group_size: 3,
+				group_assignment: GroupAssignment::SpreadPairings,
+				..Default::default()
+			};
+
+			run_game_scenario(schedule, &players, |player| {
+				// The co-players are recorded once the groups are assigned.
+				let groups_setting = GroupsSetting { max_per_group: 3, player_count: 5 };
+				let indices = PlayerToIndex::<Test>::get(0, player).unwrap();
+				let recorded = RecentCoPlayers::<Test>::get(player);
+				for (round, &index) in indices.iter().enumerate() {
+					let group_index = groups_setting.group_index_from_player_index(index);
+					for member in groups_setting.group_members(group_index).filter(|&i| i != index)
+					{
+						let member = IndexToPlayer::<Test>::get(0, (round as u8, member)).unwrap();
+						assert!(recorded.contains(&member));
+					}
+				}
+				None
+			});
+
+			// The co-players are recorded without duplicates, and outlive the game.
+			for player in &players {
+				let recorded = RecentCoPlayers::<Test>::get(player);
+				assert!(!recorded.is_empty());
+				assert!(!recorded.contains(player));
+				let mut unique = recorded.clone().into_inner();
+				unique.sort_by_key(|p| p.encode());
+				unique.dedup();
+				assert_eq!(unique.len(), recorded.len());
+			}
+		});
+	}
+
+	#[test]
+	fn shuffled_game_does_not_record_co_players() {
+		new_test_ext().execute_with(|| {
+			let players = [ALICE, BOB, CHARLIE, DAVE, EVE].map(AccountOrPerson::Account);
+			let schedule = GameSchedule::<u32, u128> {
+				game_play_time: 10,
+				rounds: 2,
+				max_group_size: 3,
+				..Default::default()
+			};
+
+			run_game_scenario(schedule, &players, |_| None);
+
+			for player in &players {
+				assert!(RecentCoPlayers::<Test>::get(player).is_empty());
+			}
+		});
+	}
+}
+
+mod schedule_rules {
+	use super::*;
+	use sp_runtime::DispatchError;
+
+	// With the mock phases, a game lasts from 4s before its play time to 4s after.
+	fn rule(game_play_time: u32, period: u32, until: u32) -> ScheduleRuleOf<Test> {
+		ScheduleRule {
+			next: GameSchedule::<u32, u128> {
+				game_play_time,
+				rounds: 2,
+				max_group_size: 3,
+				..Default::default()
+			},
+			period,
+			until,
+			paused: false,
+		}
+	}
+
+	fn set_now(secs: u64) {
+		MOCK_UNIX_TIME.with(|t| *t.borrow_mut() = Duration::from_secs(secs));
+	}
+
+	fn schedule_rule_games() {
+		Game::schedule_rule_games(&mut WeightMeter::new());
+	}
+
+	fn scheduled_times() -> Vec<u32> {
+		GameSchedules::<Test>::get()
+			.iter()
+			.map(|schedule| schedule.game_play_time)
+			.collect()
+	}
+
+	#[test]
+	fn add_schedule_rule_validates_the_rule() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			assert_noop!(
+				Game::add_schedule_rule(RuntimeOrigin::signed(ALICE), rule(100, 20, 200)),
+				DispatchError::BadOrigin
+			);
+			// The games of the rule would overlap.
+			assert_noop!(
+				Game::add_schedule_rule(RuntimeOrigin::root(), rule(100, 7, 200)),
+				Error::<Test>::InvalidScheduleRule
+			);
+			// The rule ends before its next game.
+			assert_noop!(
+				Game::add_schedule_rule(RuntimeOrigin::root(), rule(100, 20, 99)),
+				Error::<Test>::InvalidScheduleRule
+			);
+			let mut invalid = rule(100, 20, 200);
+			invalid.next.rounds = 0;
+			assert_noop!(
+				Game::add_schedule_rule(RuntimeOrigin::root(), invalid),
+				Error::<Test>::InvalidGameSetup
+			);
+
+			for index in 0..<Test as Config>::MaxScheduleRules::get() {
+				assert_ok!(Game::add_schedule_rule(RuntimeOrigin::root(), rule(100, 8, 200)));
+				System::assert_last_event(Event::<Test>::ScheduleRuleAdded { index }.into());
+			}
+			assert_noop!(
+				Game::add_schedule_rule(RuntimeOrigin::root(), rule(100, 8, 200)),
+				Error::<Test>::TooManyScheduleRules
+			);
+		});
+	}
+
+	#[test]
+	fn rule_schedules_its_games_within_the_horizon_until_it_expires() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(Game::add_schedule_rule(RuntimeOrigin::root(), rule(100, 20, 140)));
+
+			// The registration of the next game starts at 96, beyond the horizon of 50s.
+			set_now(45);
+			schedule_rule_games();
+			assert!(scheduled_times().is_empty());
+
+			set_now(46);
+			schedule_rule_games();
+			assert_eq!(scheduled_times(), vec![100]);
+			System::assert_last_event(
+				Event::<Test>::ScheduleRuleGameScheduled {
+					index: 0,
+					region: 0,
+					game_play_time: 100,
+				}
+				.into(),
+			);
+			assert_eq!(ScheduleRules::<Test>::get(0).unwrap().next.game_play_time, 120);
+
+			// At most one game per rule and per call.
+			set_now(90);
+			schedule_rule_games();
+			assert_eq!(scheduled_times(), vec![100, 120]);
+			schedule_rule_games();
+			assert_eq!(scheduled_times(), vec![100, 120, 140]);
+			System::assert_last_event(Event::<Test>::ScheduleRuleExpired { index: 0 }.into());
+			assert!(ScheduleRules::<Test>::get(0).is_none());
+		});
+	}
+
+	#[test]
+	fn on_poll_schedules_and_starts_the_games_of_a_rule() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Game::add_schedule_rule(RuntimeOrigin::root(), rule(100, 20, 200)));
+
+			set_now(60);
+			advance_process();
+			advance_process();
+
+			let game = crate::Game::<Test>::get(0).expect("game started from the rule");
+			assert_eq!(game.game_date, 100);
+			assert_eq!(ScheduleRules::<Test>::get(0).unwrap().next.game_play_time, 120);
+		});
+	}
+
+	#[test]
+	fn outdated_and_overlapping_games_are_skipped() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(Game::add_schedule_rule(RuntimeOrigin::root(), rule(100, 20, 200)));
+
+			// The registration of the game at 100 already started.
+			set_now(96);
+			schedule_rule_games();
+			assert!(scheduled_times().is_empty());
+			System::assert_last_event(
+				Event::<Test>::ScheduleRuleGameSkipped { index: 0, game_play_time: 100 }.into(),
+			);
+
+			// A game scheduled explicitly overlaps the game at 120.
+			assert_ok!(Game::schedule_games(
+				RuntimeOrigin::root(),
+				vec![GameSchedule::<u32, u128> {
+					game_play_time: 115,
+					rounds: 2,
+					max_group_size: 3,
+					..Default::default()
+				}]
+			));
+			schedule_rule_games();
+			assert_eq!(scheduled_times(), vec![115]);
+			System::assert_last_event(
+				Event::<Test>::ScheduleRuleGameSkipped { index: 0, game_play_time: 120 }.into(),
+			);
+			assert_eq!(ScheduleRules::<Test>::get(0).unwrap().next.game_play_time, 140);
+		});
+	}
+
+	#[test]
+	fn amending_and_removing_a_rule_keeps_its_scheduled_games() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(Game::add_schedule_rule(RuntimeOrigin::root(), rule(100, 20, 300)));
+			set_now(60);
+			schedule_rule_games();
+			assert_eq!(scheduled_times(), vec![100]);
+
+			// Pausing skips the games silently.
+			let mut paused = ScheduleRules::<Test>::get(0).unwrap();
+			paused.paused = true;
+			assert_ok!(Game::amend_schedule_rule(RuntimeOrigin::root(), 0, paused));
+			System::assert_last_event(Event::<Test>::ScheduleRuleAmended { index: 0 }.into());
+			set_now(80);
+			schedule_rule_games();
+			assert_eq!(scheduled_times(), vec![100]);
+			System::assert_last_event(Event::<Test>::ScheduleRuleAmended { index: 0 }.into());
+			assert_eq!(ScheduleRules::<Test>::get(0).unwrap().next.game_play_time, 140);
+
+			// Resuming with other settings changes only the next games.
+			let mut amended = rule(140, 30, 300);
+			amended.next.max_group_size = 4;
+			assert_ok!(Game::amend_schedule_rule(RuntimeOrigin::root(), 0, amended));
+			set_now(100);
+			schedule_rule_games();
+			let schedules = GameSchedules::<Test>::get();
+			assert_eq!(scheduled_times(), vec![100, 140]);
+			assert_eq!(schedules[0].max_group_size, 3);
+			assert_eq!(schedules[1].max_group_size, 4);
+
+			assert_ok!(Game::remove_schedule_rule(RuntimeOrigin::root(), 0));
+			System::assert_last_event(Event::<Test>::ScheduleRuleRemoved { index: 0 }.into());
+			assert_eq!(scheduled_times(), vec![100, 140]);
+
+			assert_noop!(
+				Game::remove_schedule_rule(RuntimeOrigin::root(), 0),
+				Error::<Test>::NoSuchScheduleRule
+			);
+			assert_noop!(
+				Game::amend_schedule_rule(RuntimeOrigin::root(), 0, rule(200, 20, 300)),
+				Error::<Test>::NoSuchScheduleRule
+			);
+		});
+	}
+
+	#[test]
+	fn rule_games_use_the_phases_of_the_rule() {
+		new_test_ext().execute_with(|| {
+			let mut with_phases = rule(100, 40, 200);
+			with_phases.next.phases = Some(PhaseDurationValues {
+				registration: 10,
+				shuffle: 3,
+				post_shuffle_margin: 2,
+				reporting: 6,
+				player_process: 4,
+				airdrop_claim_window: 100,
+			});
+			assert_ok!(Game::add_schedule_rule(RuntimeOrigin::root(), with_phases));
+
+			// The registration starts at 85 with the phases of the rule.
+			set_now(34);
+			schedule_rule_games();
+			assert!(scheduled_times().is_empty());
+			set_now(35);
+			schedule_rule_games();
+			assert_eq!(scheduled_times(), vec![100]);
+
+			let schedule = GameSchedules::<Test>::take().into_inner().remove(0);
+			assert_ok!(Game::new_game(&schedule));
+			let game = crate::Game::<Test>::get(0).unwrap();
+			assert_eq!(game.registration_ends, 95);
+			assert_eq!(game.report_ends, 106);
+		});
+	}
+}
+
+mod migration_to_regions {
+	use super::*;
+	use crate::migration::v1::{v0, MigrateToRegions};
+	use frame_support::{
+		migrations::SteppedMigration,
+		traits::{GetStorageVersion, StorageVersion},
+		weights::WeightMeter,
+	};
+
+	fn migrate() {
+		let mut cursor = None;
+		loop {
+			// A tight meter to migrate over several steps.
+			let mut meter = WeightMeter::with_limit(
+				<Test as frame_system::Config>::DbWeight::get().reads_writes(3, 6),
+			);
+			cursor = MigrateToRegions::<Test>::step(cursor, &mut meter).unwrap();
+			if cursor.is_none() {
+				break;
+			}
+		}
+	}
+
+	fn old_player(first_game: u32) -> v0::Player<MockConsideration> {
+		v0::Player {
+			first_game,
+			registered: true,
+			sent_report: false,
+			early_attendance_enactment: None,
+			yes_person: 2,
+			no_not_person: 1,
+			expected_max_vote_weight: 4,
+			vote_weight: 2,
+			credibility: PlayerCredibility::Invited,
+		}
+	}
+
+	#[test]
+	fn migrates_an_ongoing_game_to_the_first_region() {
+		new_test_ext().execute_with(|| {
+			StorageVersion::new(0).put::<Game>();
+			let players = [ALICE, BOB, CHARLIE].map(AccountOrPerson::Account);
+			for (i, player) in players.iter().enumerate() {
+				v0::Players::<Test>::insert(player, old_player(i as u32));
+				v0::IndexToPlayer::<Test>::insert((0, i as PlayerIndex), player);
+				v0::PlayerToIndex::<Test>::insert(
+					player,
+					BoundedVec::truncate_from(vec![i as u32]),
+				);
+				v0::ShuffleRecognized::<Test>::insert(0, [i as u8; 32], player);
+				v0::ShuffleNotRecognized::<Test>::insert(1, [i as u8; 32], player);
+			}
+			v0::Game::<Test>::put(v0::GameInfo {
+				index: 7,
+				registration_ends: 10,
+				shuffle_deadline: 20,
+				game_date: 30,
+				report_ends: 40,
+				state: v0::GameState::Shuffle {
+					step: v0::ShuffleStep::Step3ComputeWeights {
+						last_iteration: Some(players[0].clone()),
+						player_count: 3,
+					},
+				},
+				max_group_size: 5,
+				rounds: 2,
+				pending_attendance: 3,
+				airdrop_scheduled: false,
+			});
+			v0::GameSchedules::<Test>::put(vec![v0::GameSchedule {
+				game_play_time: 100,
+				rounds: 3,
+				max_group_size: 6,
+				airdrop_prize: None,
+			}]);
+
+			migrate();
+
+			assert_eq!(Game::on_chain_storage_version(), 1);
+			let game = crate::Game::<Test>::get(0).unwrap();
+			assert_eq!((game.index, game.region, game.report_ends), (7, 0, 40));
+			assert_eq!(
+				game.state,
+				GameState::Shuffle {
+					step: ShuffleStep::Step4ComputeWeights {
+						last_iteration: Some(players[0].clone()),
+						player_count: 3,
+					},
+				}
+			);
+			assert_eq!(game.attendance_rule, AttendanceRuleKind::StrictMajority);
+			assert_eq!(game.phases, crate::types::configured_phases::<Test>());
+
+			let schedules = GameSchedules::<Test>::get();
+			assert_eq!(schedules.len(), 1);
+			assert_eq!((schedules[0].game_play_time, schedules[0].region), (100, 0));
+			assert_eq!(schedules[0].phases, None);
+
+			for (i, player) in players.iter().enumerate() {
+				let migrated = Players::<Test>::get(player).unwrap();
+				assert_eq!((migrated.first_game, migrated.region), (i as u32, 0));
+				assert!(migrated.registered);
+				assert_eq!(migrated.yes_person, 2);
+				assert_eq!(
+					IndexToPlayer::<Test>::get(0, (0, i as PlayerIndex)).as_ref(),
+					Some(player)
+				);
+				assert_eq!(
+					PlayerToIndex::<Test>::get(0, player).unwrap().into_inner(),
+					vec![i as u32]
+				);
+				assert_eq!(
+					ShuffleRecognized::<Test>::get((0, 0), [i as u8; 32]).as_ref(),
+					Some(player)
+				);
+				assert_eq!(
+					ShuffleNotRecognized::<Test>::get((0, 1), [i as u8; 32]).as_ref(),
+					Some(player)
+				);
+			}
+			assert_eq!(IndexToPlayer::<Test>::iter().count(), 3);
+			assert_eq!(PlayerToIndex::<Test>::iter().count(), 3);
+			assert_eq!(ShuffleRecognized::<Test>::iter().count(), 3);
+			assert_eq!(ShuffleNotRecognized::<Test>::iter().count(), 3);
+
+			// Migrating again is a no-op.
+			assert_eq!(MigrateToRegions::<Test>::step(None, &mut WeightMeter::new()), Ok(None));
+		});
+	}
+}
@@ -19,9 +19,10 @@ use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use frame_support::{pallet_prelude::Get, BoundedVec};
 use indiv_pallet_airdrop::{types::AirdropPrize, RegistrationEntry};
 use indiv_pallet_score::AccountOrPerson;
-use indiv_support::traits::{Alias, RevisionIndex, RingIndex};
+use indiv_support::traits::{Alias, GameGroups, RevisionIndex, RingIndex};
 use scale_info::TypeInfo;
 use sp_core::sr25519::vrf::VrfSignature;
+use sp_runtime::Percent;
 
 /// The index of a player in a round in a game.
 ///
@@ -31,6 +32,12 @@ pub type PlayerIndex = u32;
 /// The index of a round in a game.
 pub type RoundIndex = u8;
 
+/// The region a game is played in.
+///
+/// Each region runs at most one game at a time, so games of different regions run concurrently,
+/// each at a time suited to its players.
+pub type GameRegion = u8;
+
 /// The report made by one player about another player.
 #[derive(
 	Encode,
@@ -66,7 +73,10 @@ pub type FullReport<T> =
 pub struct Player<PlayDeposit> {
 	/// The index of the first game this player was eligible to play.
 	pub first_game: u32,
-	/// The player has registered for the current game.
+	/// The region of the last game the player signed up for. Only the games of this region
+	/// process the player.
+	pub region: GameRegion,
+	/// The player has registered for the current game of their region.
 	pub registered: bool,
 	/// The player sent a report for the current game.
 	pub sent_report: bool,
@@ -84,7 +94,7 @@ pub struct Player<PlayDeposit> {
 	/// snapshotted [`Self::vote_weight`].
 	pub expected_max_vote_weight: u16,
 	/// The vote weight this player casts when reporting, snapshotted during the shuffle
-	/// phase from the score pallet's personhood status. Using a frozen value keeps the
+	/// phase by the attendance rule of the game. Using a frozen value keeps the
 	/// received tallies consistent with [`Self::expected_max_vote_weight`] even if the
 	/// player's personhood changes mid-game (e.g. via early attendance enactment).
 	pub vote_weight: u8,
@@ -158,6 +168,77 @@ pub enum AttendanceStatus {
 	Pending,
 }
 
+/// The rule deciding the attendance of the players of a game from the reports they received.
+///
+/// Selected for each game by [`GameSchedule::attendance_rule`] and applied through
+/// [`Config::AttendanceRule`].
+#[derive(
+	Encode,
+	Decode,
+	MaxEncodedLen,
+	TypeInfo,
+	Debug,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	DecodeWithMemTracking,
+	Default,
+)]
+pub enum AttendanceRuleKind {
+	/// Strictly more than half of the received vote weight reported the player as a person, or
+	/// the player received no report. Votes are weighted by the personhood of the reporter.
+	#[default]
+	StrictMajority,
+	/// At least `threshold` of the received vote weight reported the player as a person, or the
+	/// player received no report. Votes are weighted by the personhood of the reporter.
+	///
+	/// The threshold must be more than half.
+	Supermajority { threshold: Percent },
+	/// Strictly more than half of the received vote weight reported the player as a person, or
+	/// the player received no report. Votes of candidates are weighted by their score relative to
+	/// the personhood threshold.
+	ScoreWeighted,
+}
+
+impl AttendanceRuleKind {
+	/// Whether the rule can be used for a game.
+	pub fn is_valid(&self) -> bool {
+		match self {
+			Self::StrictMajority | Self::ScoreWeighted => true,
+			Self::Supermajority { threshold } => *threshold > Percent::from_percent(50),
+		}
+	}
+}
+
+/// How the players of a game are assigned to their groups.
+///
+/// Selected for each game by [`GameSchedule::group_assignment`]. Whatever the mode, the group of a
+/// player in a round is derived from their index in the round, see [`GroupsSetting`].
+#[derive(
+	Encode,
+	Decode,
+	MaxEncodedLen,
+	TypeInfo,
+	Debug,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	DecodeWithMemTracking,
+	Default,
+)]
+pub enum GroupAssignment {
+	/// The indices of the players are shuffled independently in each round.
+	#[default]
+	Shuffled,
+	/// The indices of the players are shuffled, then spread so that players avoid the players
+	/// they were recently grouped with, in earlier rounds of the game or in their previous games.
+	///
+	/// The co-players of each player are recorded in [`crate::RecentCoPlayers`].
+	SpreadPairings,
+}
+
 /// Per-event airdrop registration data supplied by the player at game sign-up.
 ///
 /// If the player is recognized (pallet-score `Recognition` is `Recognized` or
@@ -242,11 +323,30 @@ impl<BlockNumber> ArchivedPlayer<BlockNumber> {
 	}
 }
 
+/// The absence of a player reported as not being a person, which the player can dispute.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Debug, Clone, PartialEq, Eq)]
+pub struct AttendanceDispute<BlockNumber, Ticket, Deposit> {
+	/// The game the player was absent from.
+	pub game_index: u32,
+	/// The region of the game.
+	pub region: GameRegion,
+	/// The group the player played in, for each round of the game.
+	pub groups: GameGroups,
+	/// The last block at which the dispute can be opened.
+	pub dispute_until: BlockNumber,
+	/// The oracle case judging the dispute, once opened.
+	pub case: Option<Ticket>,
+	/// The deposit held while the case is open, for account players.
+	pub deposit: Option<Deposit>,
+}
+
 /// The information of a game.
 #[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Debug, DecodeWithMemTracking)]
 pub struct GameInfo<AccountId: Into<sp_statement_store::AccountId>> {
 	/// The index of the game, used as an identifier.
 	pub index: u32,
+	/// The region of the game.
+	pub region: GameRegion,
 	/// The end of the registration period in seconds since Unix epoch.
 	pub registration_ends: u32,
 	/// The shuffle deadline in seconds since Unix epoch.
@@ -276,6 +376,12 @@ pub struct GameInfo<AccountId: Into<sp_statement_store::AccountId>> {
 	/// registration for airdrop at sign-up is skipped and no cancel is dispatched on
 	/// game cancellation.
 	pub airdrop_scheduled: bool,
+	/// The rule deciding the attendance of the players of this game.
+	pub attendance_rule: AttendanceRuleKind,
+	/// How the players of this game are assigned to their groups.
+	pub group_assignment: GroupAssignment,
+	/// The phase durations of this game.
+	pub phases: PhaseDurationValues,
 }
 
 /// The state of a game.
@@ -318,7 +424,10 @@ pub enum GameState<AccountId: Into<sp_statement_store::AccountId>> {
 /// The steps for the player-process phase.
 #[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Debug, PartialEq)]
 pub enum PlayerProcessStep<AccountId: Into<sp_statement_store::AccountId>> {
-	/// Iterate every player to settle attendance and burn deposits.
+	/// Drain the submitted reports and score how each reporter agreed with the outcome of the
+	/// players they reported on. Must run before [`Self::Step1ProcessPlayers`] resets the tallies.
+	Step0ScoreReports { player_count: u32 },
+	/// Iterate every player of the region to settle attendance and burn deposits.
 	Step1ProcessPlayers { last_iteration: Option<AccountOrPerson<AccountId>>, player_count: u32 },
 	/// Drain the per-game index <-> player maps (`IndexToPlayer` and
 	/// `PlayerToIndex`) in bounded chunks, then kill the game.
@@ -333,11 +442,14 @@ pub enum ShuffleStep<AccountId: Into<sp_statement_store::AccountId>> {
 	/// Retrieval of player from storage to get their order.
 	/// First we index recognized players, then not recognized players.
 	Step2Retrieve { next_player_index: u32, recognized_finished: bool },
+	/// Only for [`GroupAssignment::SpreadPairings`]: walk the indices of each round and swap
+	/// players with the next index when it reduces the repeated pairings.
+	Step3SpreadPairings { round: RoundIndex, index: PlayerIndex, player_count: u32 },
 	/// Iterate over each registered player and compute their `expected_max_vote_weight`
-	/// from the actual group composition produced by [`Self::Step2Retrieve`].
-	Step3ComputeWeights { last_iteration: Option<AccountOrPerson<AccountId>>, player_count: u32 },
+	/// from the actual group composition produced by the previous steps.
+	Step4ComputeWeights { last_iteration: Option<AccountOrPerson<AccountId>>, player_count: u32 },
 	/// All players have been indexed. We now try to start the attendance report session.
-	Step4AwaitSession { player_count: u32 },
+	Step5AwaitSession { player_count: u32 },
 }
 
 /// A setting for a distribution of players in groups.
@@ -419,23 +531,63 @@ pub struct GameSchedule<AssetId, Balance> {
 	pub max_group_size: u32,
 	/// Prize spec for the per-game airdrop event. `None` skips airdrop scheduling for this game.
 	pub airdrop_prize: Option<AirdropPrize<AssetId, Balance>>,
+	/// The rule deciding the attendance of the players of the scheduled game.
+	pub attendance_rule: AttendanceRuleKind,
+	/// The region of the scheduled game. Games of different regions can run concurrently.
+	pub region: GameRegion,
+	/// How the players of the scheduled game are assigned to their groups.
+	pub group_assignment: GroupAssignment,
+	/// The phase durations of the scheduled game. `None` uses the phase durations of the pallet,
+	/// set by `set_game_phases`, when the game starts.
+	pub phases: Option<PhaseDurationValues>,
 }
 
 /// `GameSchedule` for the runtime.
 pub type GameScheduleOf<T> =
 	crate::types::GameSchedule<<T as Config>::AirdropAssetId, <T as Config>::AirdropAssetBalance>;
 
+/// The index of a schedule rule, used as an identifier.
+pub type ScheduleRuleIndex = u32;
+
+/// A rule scheduling games periodically, e.g. every Saturday at 14:00 UTC until a given date.
+///
+/// The games of the rule are materialized into the game schedules ahead of their registration,
+/// see [`Config::ScheduleRuleHorizon`]. Amending, pausing or removing the rule doesn't change the
+/// games already materialized.
+#[derive(
+	Encode, Decode, MaxEncodedLen, TypeInfo, Debug, Clone, PartialEq, DecodeWithMemTracking,
+)]
+pub struct ScheduleRule<AssetId, Balance> {
+	/// The schedule of the next game of the rule. The next games are played every `period`
+	/// after it, with the same settings.
+	pub next: GameSchedule<AssetId, Balance>,
+	/// The time between two games of the rule, in seconds.
+	pub period: u32,
+	/// The time after which the rule plays no game, in seconds since Unix epoch.
+	pub until: u32,
+	/// Whether the rule is paused. A paused rule materializes no game, and resumes at its next
+	/// game that is not outdated.
+	pub paused: bool,
+}
+
+/// `ScheduleRule` for the runtime.
+pub type ScheduleRuleOf<T> =
+	crate::types::ScheduleRule<<T as Config>::AirdropAssetId, <T as Config>::AirdropAssetBalance>;
+
 /// A trait for calculating various game phase timestamps based on a game play start time.
 /// All returned times in seconds since Unix epoch.
 pub trait GameTimes<T: Config> {
 	/// Calculates the time at which game should start.
 	fn game_play_time(&self) -> u32;
 
+	/// The phase durations of the game.
+	fn phases(&self) -> PhaseDurationValues;
+
 	/// Calculates the latest time at which registration phase of a game should start, if passed
 	/// then the game should be skipped. The calculation assumes a maximum time that the
 	/// registration phase should take.
 	fn registration_start(&self) -> u32 {
-		let phases = configured_phases::<T>();
+		let phases = self.phases();
 		self.game_play_time()
 			.saturating_sub(phases.shuffle)
 			.saturating_sub(phases.post_shuffle_margin)
@@ -446,7 +598,7 @@ pub trait GameTimes<T: Config> {
 	/// assumes a maximum time that the shuffle phase should take, which is the phase between
 	/// registration and reporting.
 	fn registration_end(&self) -> u32 {
-		let phases = configured_phases::<T>();
+		let phases = self.phases();
 		self.game_play_time()
 			.saturating_sub(phases.shuffle)
 			.saturating_sub(phases.post_shuffle_margin)
@@ -454,14 +606,14 @@ pub trait GameTimes<T: Config> {
 
 	/// The time at which if the shuffle phase is not finished, the game will be cancelled.
 	fn shuffle_deadline(&self) -> u32 {
-		let phases = configured_phases::<T>();
+		let phases = self.phases();
 		self.game_play_time().saturating_sub(phases.post_shuffle_margin)
 	}
 
 	/// Calculates the time at which reporting phase of a game should end. The calculation assumes a
 	/// maximum time that the reporting phase should take.
 	fn reporting_end(&self) -> u32 {
-		let phases = configured_phases::<T>();
+		let phases = self.phases();
 		self.game_play_time().saturating_add(phases.reporting)
 	}
 
@@ -469,14 +621,14 @@ pub trait GameTimes<T: Config> {
 	/// means the end of the game. The calculation assumes a maximum time that the player process
 	/// phase should take.
 	fn player_process_end(&self) -> u32 {
-		let phases = configured_phases::<T>();
+		let phases = self.phases();
 		self.game_play_time()
 			.saturating_add(phases.reporting)
 			.saturating_add(phases.player_process)
 	}
 }
 
-fn configured_phases<T: Config>() -> PhaseDurationValues {
+pub(crate) fn configured_phases<T: Config>() -> PhaseDurationValues {
 	StoredPhaseDurations::<T>::get().unwrap_or_else(T::DefaultPhaseDurations::get)
 }
 
@@ -484,6 +636,10 @@ impl<T: Config, AssetId, Balance> GameTimes<T> for GameSchedule<AssetId, Balance
 	fn game_play_time(&self) -> u32 {
 		self.game_play_time
 	}
+
+	fn phases(&self) -> PhaseDurationValues {
+		self.phases.clone().unwrap_or_else(configured_phases::<T>)
+	}
 }
 
 impl<T: Config, AccountId: Into<sp_statement_store::AccountId>> GameTimes<T>
@@ -492,6 +648,10 @@ impl<T: Config, AccountId: Into<sp_statement_store::AccountId>> GameTimes<T>
 	fn game_play_time(&self) -> u32 {
 		self.game_date
 	}
+
+	fn phases(&self) -> PhaseDurationValues {
+		self.phases.clone()
+	}
 }
 
 /// Contains the duration values for each game phase in seconds.
@@ -63,6 +63,7 @@ pub trait WeightInfo {
 	fn shuffles_base() -> Weight;
 	fn shuffle_step_insert(n: u32, ) -> Weight;
 	fn shuffle_step_retrieve(n: u32, ) -> Weight;
+	fn shuffle_step_spread_pairings(n: u32, r: u32, ) -> Weight;
 	fn shuffle_step_compute_weights(n: u32, r: u32, ) -> Weight;
 	fn shuffle_step_start_session() -> Weight;
 	fn player_process_step1() -> Weight;
@@ -86,6 +87,10 @@ pub trait WeightInfo {
 	fn cancel_invite_ticket() -> Weight;
 	fn schedule_games(n: u32, ) -> Weight;
 	fn remove_scheduled_game() -> Weight;
+	fn add_schedule_rule() -> Weight;
+	fn amend_schedule_rule() -> Weight;
+	fn remove_schedule_rule() -> Weight;
+	fn schedule_rule_game() -> Weight;
 	fn set_play_deposit() -> Weight;
 	fn as_invited_tx_ext() -> Weight;
 	fn process_reporting() -> Weight;
@@ -94,13 +99,17 @@ pub trait WeightInfo {
 	fn set_game_phases() -> Weight;
 	fn on_game_cancelled() -> Weight;
 	fn claim_airdrop() -> Weight;
+	fn player_process_step0() -> Weight;
+	fn player_process_step0_reporter(r: u32, ) -> Weight;
+	fn dispute_attendance() -> Weight;
+	fn attendance_judged() -> Weight;
 }
 
 /// Weights for `indiv_pallet_game` using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `Game::Game` (r:1 w:1)
-	/// Proof: `Game::Game` (`max_values`: Some(1), `max_size`: Some(70), added: 565, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Game` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
 	/// Storage: `Game::StoredPhaseDurations` (r:1 w:0)
 	/// Proof: `Game::StoredPhaseDurations` (`max_values`: Some(1), `max_size`: Some(24), added: 519, mode: `MaxEncodedLen`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
@@ -133,7 +142,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(10_u64))
 	}
 	/// Storage: `Game::Game` (r:1 w:0)
-	/// Proof: `Game::Game` (`max_values`: Some(1), `max_size`: Some(70), added: 565, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Game` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
 	fn get_game() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `230`
@@ -166,7 +175,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 	}
 	/// Storage: `Game::Game` (r:0 w:1)
-	/// Proof: `Game::Game` (`max_values`: Some(1), `max_size`: Some(70), added: 565, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Game` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
 	fn put_game() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
@@ -190,13 +199,13 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `System::ParentHash` (r:1 w:0)
 	/// Proof: `System::ParentHash` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
 	/// Storage: `Game::Players` (r:1 w:0)
-	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
 	/// Storage: `Game::ShuffleRecognized` (r:1 w:0)
-	/// Proof: `Game::ShuffleRecognized` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	/// Proof: `Game::ShuffleRecognized` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
 	/// Storage: `Game::ShuffleNotRecognized` (r:1 w:0)
-	/// Proof: `Game::ShuffleNotRecognized` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	/// Proof: `Game::ShuffleNotRecognized` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
 	/// Storage: `Game::PlayerToIndex` (r:1 w:0)
-	/// Proof: `Game::PlayerToIndex` (`max_values`: None, `max_size`: Some(62), added: 2537, mode: `MaxEncodedLen`)
+	/// Proof: `Game::PlayerToIndex` (`max_values`: None, `max_size`: Some(71), added: 2546, mode: `MaxEncodedLen`)
 	/// Storage: `Members::RingsState` (r:1 w:0)
 	/// Proof: `Members::RingsState` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
 	/// Storage: `Members::ActiveMembers` (r:1 w:0)
@@ -212,7 +221,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `Score::PersonhoodThreshold` (r:0 w:1)
 	/// Proof: `Score::PersonhoodThreshold` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `Game::Game` (r:0 w:1)
-	/// Proof: `Game::Game` (`max_values`: Some(1), `max_size`: Some(70), added: 565, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Game` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
 	fn shuffles_base() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `391`
@@ -223,11 +232,11 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Game::Players` (r:2 w:1)
-	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
 	/// Storage: `Score::Participants` (r:1 w:0)
 	/// Proof: `Score::Participants` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
 	/// Storage: `Game::ShuffleNotRecognized` (r:0 w:3)
-	/// Proof: `Game::ShuffleNotRecognized` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	/// Proof: `Game::ShuffleNotRecognized` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 3]`.
 	fn shuffle_step_insert(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -242,11 +251,11 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
 	}
 	/// Storage: `Game::ShuffleNotRecognized` (r:6 w:3)
-	/// Proof: `Game::ShuffleNotRecognized` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	/// Proof: `Game::ShuffleNotRecognized` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
 	/// Storage: `Game::PlayerToIndex` (r:2 w:2)
-	/// Proof: `Game::PlayerToIndex` (`max_values`: None, `max_size`: Some(62), added: 2537, mode: `MaxEncodedLen`)
+	/// Proof: `Game::PlayerToIndex` (`max_values`: None, `max_size`: Some(71), added: 2546, mode: `MaxEncodedLen`)
 	/// Storage: `Game::IndexToPlayer` (r:0 w:3)
-	/// Proof: `Game::IndexToPlayer` (`max_values`: None, `max_size`: Some(46), added: 2521, mode: `MaxEncodedLen`)
+	/// Proof: `Game::IndexToPlayer` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 3]`.
 	fn shuffle_step_retrieve(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -261,27 +270,54 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(0, 5098).saturating_mul(n.into()))
 	}
 	/// Storage: `Game::PlayerToIndex` (r:2 w:0)
-	/// Proof: `Game::PlayerToIndex` (`max_values`: None, `max_size`: Some(62), added: 2537, mode: `MaxEncodedLen`)
+	/// Proof: `Game::PlayerToIndex` (`max_values`: None, `max_size`: Some(71), added: 2546, mode: `MaxEncodedLen`)
 	/// Storage: `Game::IndexToPlayer` (r:15 w:0)
-	/// Proof: `Game::IndexToPlayer` (`max_values`: None, `max_size`: Some(46), added: 2521, mode: `MaxEncodedLen`)
+	/// Proof: `Game::IndexToPlayer` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	/// Storage: `Game::IndexToPlayer` (r:27 w:2)
+	/// Proof: `Game::IndexToPlayer` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	/// Storage: `Game::RecentCoPlayers` (r:2 w:0)
+	/// Proof: `Game::RecentCoPlayers` (`max_values`: None, `max_size`: Some(1040), added: 3515, mode: `MaxEncodedLen`)
+	/// Storage: `Game::PlayerToIndex` (r:4 w:2)
+	/// Proof: `Game::PlayerToIndex` (`max_values`: None, `max_size`: Some(71), added: 2546, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[2, 6]`.
+	/// The range of component `r` is `[1, 3]`.
+	fn shuffle_step_spread_pairings(n: u32, r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2287 + n * (312 ±0) + r * (498 ±0)`
+		//  Estimated: `12122 + n * (5060 ±402) + r * (5060 ±803)`
+		// Minimum execution time: 61_205_000 picoseconds.
+		Weight::from_parts(38_417_000, 12122)
+			// Standard Error: 251_774
+			.saturating_add(Weight::from_parts(9_812_340, 0).saturating_mul(n.into()))
+			// Standard Error: 503_102
+			.saturating_add(Weight::from_parts(12_604_117, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(Weight::from_parts(0, 5060).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 5060).saturating_mul(r.into()))
+	}
 	/// Storage: `Game::Players` (r:10 w:1)
-	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `Game::RecentCoPlayers` (r:1 w:1)
+	/// Proof: `Game::RecentCoPlayers` (`max_values`: None, `max_size`: Some(1040), added: 3515, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[2, 6]`.
 	/// The range of component `r` is `[1, 3]`.
 	fn shuffle_step_compute_weights(n: u32, r: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0 + n * (465 ±0) + r * (690 ±0)`
-		//  Estimated: `8655 + n * (2077 ±331) + r * (2163 ±685)`
-		// Minimum execution time: 45_594_000 picoseconds.
-		Weight::from_parts(47_671_000, 8655)
+		//  Measured:  `1007 + n * (465 ±0) + r * (690 ±0)`
+		//  Estimated: `12170 + n * (2077 ±331) + r * (2163 ±685)`
+		// Minimum execution time: 52_318_000 picoseconds.
+		Weight::from_parts(54_902_000, 12170)
 			// Standard Error: 214_035
 			.saturating_add(Weight::from_parts(5_984_369, 0).saturating_mul(n.into()))
 			// Standard Error: 443_300
 			.saturating_add(Weight::from_parts(4_990_746, 0).saturating_mul(r.into()))
-			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(r.into())))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 			.saturating_add(Weight::from_parts(0, 2077).saturating_mul(n.into()))
 			.saturating_add(Weight::from_parts(0, 2163).saturating_mul(r.into()))
 	}
@@ -309,11 +345,11 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Game::Game` (r:1 w:1)
-	/// Proof: `Game::Game` (`max_values`: Some(1), `max_size`: Some(70), added: 565, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Game` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `Game::Players` (r:1 w:0)
-	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
 	/// Storage: `Members::Collections` (r:1 w:0)
 	/// Proof: `Members::Collections` (`max_values`: None, `max_size`: Some(646), added: 3121, mode: `MaxEncodedLen`)
 	fn player_process_step1() -> Weight {
@@ -329,6 +365,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Score::Participants` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
 	/// Storage: `Score::PersonhoodThreshold` (r:1 w:0)
 	/// Proof: `Score::PersonhoodThreshold` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Score::SignalWeightSchedule` (r:1 w:0)
+	/// Proof: `Score::SignalWeightSchedule` (`max_values`: Some(1), `max_size`: Some(41), added: 536, mode: `MaxEncodedLen`)
+	/// Storage: `ProofOfInk::Candidates` (r:1 w:0)
+	/// Proof: `ProofOfInk::Candidates` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
+	/// Storage: `PeopleLite::LitePeople` (r:1 w:0)
+	/// Proof: `PeopleLite::LitePeople` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Honour::Tally` (r:1 w:0)
+	/// Proof: `Honour::Tally` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `Score::AbsenceGraceRatio` (r:1 w:0)
 	/// Proof: `Score::AbsenceGraceRatio` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
 	/// Storage: `Game::PlayerAttendanceHistory` (r:1 w:1)
@@ -336,9 +380,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `Game::GameParticipantCount` (r:1 w:1)
 	/// Proof: `Game::GameParticipantCount` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
 	/// Storage: `Game::PlayerToIndex` (r:1 w:0)
-	/// Proof: `Game::PlayerToIndex` (`max_values`: None, `max_size`: Some(62), added: 2537, mode: `MaxEncodedLen`)
+	/// Proof: `Game::PlayerToIndex` (`max_values`: None, `max_size`: Some(71), added: 2546, mode: `MaxEncodedLen`)
 	/// Storage: `Game::IndexToPlayer` (r:15 w:0)
-	/// Proof: `Game::IndexToPlayer` (`max_values`: None, `max_size`: Some(46), added: 2521, mode: `MaxEncodedLen`)
+	/// Proof: `Game::IndexToPlayer` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
 	/// Storage: `Game::NftCandidates` (r:16 w:15)
 	/// Proof: `Game::NftCandidates` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
@@ -346,17 +390,19 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `Balances::Holds` (r:1 w:1)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
 	/// Storage: `Game::Players` (r:2 w:1)
-	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
 	/// Storage: `Game::Nfts` (r:0 w:15)
 	/// Proof: `Game::Nfts` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Game::AttendanceDisputes` (r:0 w:1)
+	/// Proof: `Game::AttendanceDisputes` (`max_values`: None, `max_size`: Some(145), added: 2620, mode: `MaxEncodedLen`)
 	fn player_process_step1_attended_player() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `3476`
 		//  Estimated: `42142`
 		// Minimum execution time: 299_690_000 picoseconds.
 		Weight::from_parts(311_228_000, 42142)
-			.saturating_add(T::DbWeight::get().reads(41_u64))
-			.saturating_add(T::DbWeight::get().writes(36_u64))
+			.saturating_add(T::DbWeight::get().reads(45_u64))
+			.saturating_add(T::DbWeight::get().writes(37_u64))
 	}
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
@@ -364,6 +410,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Score::Participants` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
 	/// Storage: `Score::PersonhoodThreshold` (r:1 w:0)
 	/// Proof: `Score::PersonhoodThreshold` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Score::SignalWeightSchedule` (r:1 w:0)
+	/// Proof: `Score::SignalWeightSchedule` (`max_values`: Some(1), `max_size`: Some(41), added: 536, mode: `MaxEncodedLen`)
+	/// Storage: `ProofOfInk::Candidates` (r:1 w:0)
+	/// Proof: `ProofOfInk::Candidates` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
+	/// Storage: `PeopleLite::LitePeople` (r:1 w:0)
+	/// Proof: `PeopleLite::LitePeople` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Honour::Tally` (r:1 w:0)
+	/// Proof: `Honour::Tally` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `Score::AbsenceGraceRatio` (r:1 w:0)
 	/// Proof: `Score::AbsenceGraceRatio` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
 	/// Storage: `Game::NftCandidates` (r:15 w:15)
@@ -373,22 +427,24 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: UNKNOWN KEY `0x3a73746174656d656e745f616c6c6f77616e63653ae1ef2fe6f211f7399a8a6a` (r:1 w:1)
 	/// Proof: UNKNOWN KEY `0x3a73746174656d656e745f616c6c6f77616e63653ae1ef2fe6f211f7399a8a6a` (r:1 w:1)
 	/// Storage: `Game::Players` (r:1 w:1)
-	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
 	/// Storage: `Game::StmtAccountToAlias` (r:0 w:1)
 	/// Proof: `Game::StmtAccountToAlias` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	/// Storage: `Game::ArchivedPlayers` (r:0 w:1)
 	/// Proof: `Game::ArchivedPlayers` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
+	/// Storage: `Game::AttendanceDisputes` (r:0 w:1)
+	/// Proof: `Game::AttendanceDisputes` (`max_values`: None, `max_size`: Some(145), added: 2620, mode: `MaxEncodedLen`)
 	fn player_process_step1_not_attended_player() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2151`
 		//  Estimated: `39570`
 		// Minimum execution time: 75_519_000 picoseconds.
 		Weight::from_parts(77_579_000, 39570)
-			.saturating_add(T::DbWeight::get().reads(22_u64))
-			.saturating_add(T::DbWeight::get().writes(21_u64))
+			.saturating_add(T::DbWeight::get().reads(26_u64))
+			.saturating_add(T::DbWeight::get().writes(22_u64))
 	}
 	/// Storage: `Game::Game` (r:1 w:1)
-	/// Proof: `Game::Game` (`max_values`: Some(1), `max_size`: Some(70), added: 565, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Game` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
 	fn player_process_step2() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `165`
@@ -399,9 +455,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Game::IndexToPlayer` (r:101 w:100)
-	/// Proof: `Game::IndexToPlayer` (`max_values`: None, `max_size`: Some(46), added: 2521, mode: `MaxEncodedLen`)
+	/// Proof: `Game::IndexToPlayer` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
 	/// Storage: `Game::PlayerToIndex` (r:101 w:100)
-	/// Proof: `Game::PlayerToIndex` (`max_values`: None, `max_size`: Some(62), added: 2537, mode: `MaxEncodedLen`)
+	/// Proof: `Game::PlayerToIndex` (`max_values`: None, `max_size`: Some(71), added: 2546, mode: `MaxEncodedLen`)
 	fn player_process_step2_inner_loop() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `12777`
@@ -412,9 +468,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(200_u64))
 	}
 	/// Storage: `Game::Game` (r:1 w:1)
-	/// Proof: `Game::Game` (`max_values`: Some(1), `max_size`: Some(70), added: 565, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Game` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
 	/// Storage: `Game::Players` (r:1 w:0)
-	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
 	/// Storage: `Game::GameHistory` (r:0 w:1)
 	/// Proof: `Game::GameHistory` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
 	fn process_cancelling() -> Weight {
@@ -427,11 +483,11 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Game::Players` (r:2 w:1)
-	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
 	/// Storage: `Game::PlayerToIndex` (r:1 w:1)
-	/// Proof: `Game::PlayerToIndex` (`max_values`: None, `max_size`: Some(62), added: 2537, mode: `MaxEncodedLen`)
+	/// Proof: `Game::PlayerToIndex` (`max_values`: None, `max_size`: Some(71), added: 2546, mode: `MaxEncodedLen`)
 	/// Storage: `Game::IndexToPlayer` (r:0 w:3)
-	/// Proof: `Game::IndexToPlayer` (`max_values`: None, `max_size`: Some(46), added: 2521, mode: `MaxEncodedLen`)
+	/// Proof: `Game::IndexToPlayer` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 3]`.
 	fn process_cancelling_step(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -446,13 +502,13 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
 	}
 	/// Storage: `Game::Game` (r:1 w:1)
-	/// Proof: `Game::Game` (`max_values`: Some(1), `max_size`: Some(70), added: 565, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Game` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `Game::StmtAccountToAlias` (r:1 w:0)
 	/// Proof: `Game::StmtAccountToAlias` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	/// Storage: `Game::Players` (r:1 w:1)
-	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
 	/// Storage: `Game::ArchivedPlayers` (r:1 w:0)
 	/// Proof: `Game::ArchivedPlayers` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
 	/// Storage: `Score::Participants` (r:1 w:1)
@@ -477,13 +533,13 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(8_u64))
 	}
 	/// Storage: `Game::Game` (r:1 w:1)
-	/// Proof: `Game::Game` (`max_values`: Some(1), `max_size`: Some(70), added: 565, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Game` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `Game::StmtAccountToAlias` (r:1 w:0)
 	/// Proof: `Game::StmtAccountToAlias` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	/// Storage: `Game::Players` (r:1 w:1)
-	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
 	/// Storage: `Game::ArchivedPlayers` (r:1 w:0)
 	/// Proof: `Game::ArchivedPlayers` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
 	/// Storage: `Score::Participants` (r:1 w:1)
@@ -512,13 +568,13 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
 	/// Storage: `Game::Game` (r:1 w:1)
-	/// Proof: `Game::Game` (`max_values`: Some(1), `max_size`: Some(70), added: 565, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Game` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `Game::StmtAccountToAlias` (r:1 w:0)
 	/// Proof: `Game::StmtAccountToAlias` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	/// Storage: `Game::Players` (r:1 w:1)
-	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
 	/// Storage: `Game::ArchivedPlayers` (r:1 w:0)
 	/// Proof: `Game::ArchivedPlayers` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
 	/// Storage: `Score::Participants` (r:1 w:0)
@@ -543,7 +599,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Game::Game` (r:1 w:1)
-	/// Proof: `Game::Game` (`max_values`: Some(1), `max_size`: Some(70), added: 565, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Game` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `Game::AliasToStmtAccount` (r:1 w:1)
@@ -553,7 +609,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `Game::StmtAccountToAlias` (r:1 w:2)
 	/// Proof: `Game::StmtAccountToAlias` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	/// Storage: `Game::Players` (r:2 w:1)
-	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
 	/// Storage: `Game::ArchivedPlayers` (r:2 w:0)
 	/// Proof: `Game::ArchivedPlayers` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
 	/// Storage: UNKNOWN KEY `0x3a73746174656d656e745f616c6c6f77616e63653acecc1507dc1ddd7295951c` (r:1 w:1)
@@ -582,19 +638,27 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(11_u64))
 	}
 	/// Storage: `Game::Game` (r:1 w:1)
-	/// Proof: `Game::Game` (`max_values`: Some(1), `max_size`: Some(70), added: 565, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Game` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `Game::Players` (r:16 w:16)
-	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
 	/// Storage: `Game::PlayerToIndex` (r:16 w:0)
-	/// Proof: `Game::PlayerToIndex` (`max_values`: None, `max_size`: Some(62), added: 2537, mode: `MaxEncodedLen`)
+	/// Proof: `Game::PlayerToIndex` (`max_values`: None, `max_size`: Some(71), added: 2546, mode: `MaxEncodedLen`)
 	/// Storage: `Game::IndexToPlayer` (r:100 w:0)
-	/// Proof: `Game::IndexToPlayer` (`max_values`: None, `max_size`: Some(46), added: 2521, mode: `MaxEncodedLen`)
+	/// Proof: `Game::IndexToPlayer` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
 	/// Storage: `Score::Participants` (r:16 w:16)
 	/// Proof: `Score::Participants` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
 	/// Storage: `Score::PersonhoodThreshold` (r:1 w:0)
 	/// Proof: `Score::PersonhoodThreshold` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Score::SignalWeightSchedule` (r:1 w:0)
+	/// Proof: `Score::SignalWeightSchedule` (`max_values`: Some(1), `max_size`: Some(41), added: 536, mode: `MaxEncodedLen`)
+	/// Storage: `ProofOfInk::Candidates` (r:16 w:0)
+	/// Proof: `ProofOfInk::Candidates` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
+	/// Storage: `PeopleLite::LitePeople` (r:16 w:0)
+	/// Proof: `PeopleLite::LitePeople` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Honour::Tally` (r:16 w:0)
+	/// Proof: `Honour::Tally` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `Score::AbsenceGraceRatio` (r:1 w:0)
 	/// Proof: `Score::AbsenceGraceRatio` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
 	/// Storage: `Game::PlayerAttendanceHistory` (r:16 w:16)
@@ -605,33 +669,41 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Game::NftCandidates` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
 	/// Storage: `Game::Nfts` (r:0 w:251)
 	/// Proof: `Game::Nfts` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Game::SubmittedReports` (r:0 w:1)
+	/// Proof: `Game::SubmittedReports` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `Game::AttendanceDisputes` (r:0 w:1)
+	/// Proof: `Game::AttendanceDisputes` (`max_values`: None, `max_size`: Some(145), added: 2620, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[0, 16]`.
 	fn report(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `7695 + p * (284 ±0)`
-		//  Estimated: `84233 + p * (13014 ±13)`
+		//  Estimated: `84233 + p * (20775 ±13)`
 		// Minimum execution time: 282_876_000 picoseconds.
 		Weight::from_parts(370_023_048, 84233)
 			// Standard Error: 348_960
 			.saturating_add(Weight::from_parts(111_738_774, 0).saturating_mul(p.into()))
-			.saturating_add(T::DbWeight::get().reads(55_u64))
-			.saturating_add(T::DbWeight::get().reads((10_u64).saturating_mul(p.into())))
-			.saturating_add(T::DbWeight::get().writes(33_u64))
-			.saturating_add(T::DbWeight::get().writes((18_u64).saturating_mul(p.into())))
-			.saturating_add(Weight::from_parts(0, 13014).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(56_u64))
+			.saturating_add(T::DbWeight::get().reads((13_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes(34_u64))
+			.saturating_add(T::DbWeight::get().writes((19_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 20775).saturating_mul(p.into()))
 	}
 	/// Storage: `Game::ArchivedPlayers` (r:1 w:0)
 	/// Proof: `Game::ArchivedPlayers` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
 	/// Storage: `Game::Players` (r:1 w:1)
-	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
 	/// Storage: `Game::Game` (r:1 w:0)
-	/// Proof: `Game::Game` (`max_values`: Some(1), `max_size`: Some(70), added: 565, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Game` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Holds` (r:1 w:1)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
 	/// Storage: UNKNOWN KEY `0x3a73746174656d656e745f616c6c6f77616e63653ad861ea1ebf4800d4b89f4f` (r:1 w:1)
 	/// Proof: UNKNOWN KEY `0x3a73746174656d656e745f616c6c6f77616e63653ad861ea1ebf4800d4b89f4f` (r:1 w:1)
 	/// Storage: `Score::Participants` (r:0 w:1)
 	/// Proof: `Score::Participants` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Game::AttendanceDisputes` (r:0 w:1)
+	/// Proof: `Game::AttendanceDisputes` (`max_values`: None, `max_size`: Some(145), added: 2620, mode: `MaxEncodedLen`)
+	/// Storage: `Game::RecentCoPlayers` (r:0 w:1)
+	/// Proof: `Game::RecentCoPlayers` (`max_values`: None, `max_size`: Some(1040), added: 3515, mode: `MaxEncodedLen`)
 	fn offboard_account() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `302`
@@ -639,14 +711,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 61_101_000 picoseconds.
 		Weight::from_parts(62_836_000, 3767)
 			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: `Game::ArchivedPlayers` (r:1 w:1)
 	/// Proof: `Game::ArchivedPlayers` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
 	/// Storage: `Game::Players` (r:1 w:1)
-	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
 	/// Storage: `Game::Game` (r:1 w:0)
-	/// Proof: `Game::Game` (`max_values`: Some(1), `max_size`: Some(70), added: 565, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Game` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
 	/// Storage: `Game::AliasToStmtAccount` (r:1 w:1)
 	/// Proof: `Game::AliasToStmtAccount` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	/// Storage: UNKNOWN KEY `0x3a73746174656d656e745f616c6c6f77616e63653acecc1507dc1ddd7295951c` (r:1 w:1)
@@ -655,6 +727,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Score::Participants` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
 	/// Storage: `Game::StmtAccountToAlias` (r:0 w:1)
 	/// Proof: `Game::StmtAccountToAlias` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Game::AttendanceDisputes` (r:0 w:1)
+	/// Proof: `Game::AttendanceDisputes` (`max_values`: None, `max_size`: Some(145), added: 2620, mode: `MaxEncodedLen`)
+	/// Storage: `Game::RecentCoPlayers` (r:0 w:1)
+	/// Proof: `Game::RecentCoPlayers` (`max_values`: None, `max_size`: Some(1040), added: 3515, mode: `MaxEncodedLen`)
 	fn offboard_person() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `509`
@@ -662,7 +738,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 35_371_000 picoseconds.
 		Weight::from_parts(36_489_000, 3974)
 			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
 	}
 	/// Storage: `Game::ArchivedPlayers` (r:1 w:1)
 	/// Proof: `Game::ArchivedPlayers` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
@@ -670,6 +746,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Score::Participants` (r:0 w:1)
 	/// Proof: `Score::Participants` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Game::RecentCoPlayers` (r:0 w:1)
+	/// Proof: `Game::RecentCoPlayers` (`max_values`: None, `max_size`: Some(1040), added: 3515, mode: `MaxEncodedLen`)
 	fn kickout() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `330`
@@ -677,7 +755,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 21_862_000 picoseconds.
 		Weight::from_parts(22_529_000, 3593)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Game::AvailableInvites` (r:1 w:1)
 	/// Proof: `Game::AvailableInvites` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
@@ -737,7 +815,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `Game::GameSchedules` (r:1 w:1)
 	/// Proof: `Game::GameSchedules` (`max_values`: Some(1), `max_size`: Some(7633), added: 8128, mode: `MaxEncodedLen`)
 	/// Storage: `Game::Game` (r:1 w:0)
-	/// Proof: `Game::Game` (`max_values`: Some(1), `max_size`: Some(70), added: 565, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Game` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `Game::StoredPhaseDurations` (r:1 w:0)
@@ -765,6 +843,60 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Game::CounterForScheduleRules` (r:1 w:1)
+	/// Proof: `Game::CounterForScheduleRules` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Game::NextScheduleRuleIndex` (r:1 w:1)
+	/// Proof: `Game::NextScheduleRuleIndex` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Game::ScheduleRules` (r:1 w:1)
+	/// Proof: `Game::ScheduleRules` (`max_values`: None, `max_size`: Some(131), added: 2606, mode: `MaxEncodedLen`)
+	fn add_schedule_rule() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `3596`
+		// Minimum execution time: 21_473_000 picoseconds.
+		Weight::from_parts(22_315_000, 3596)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Game::ScheduleRules` (r:1 w:1)
+	/// Proof: `Game::ScheduleRules` (`max_values`: None, `max_size`: Some(131), added: 2606, mode: `MaxEncodedLen`)
+	fn amend_schedule_rule() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `195`
+		//  Estimated: `3596`
+		// Minimum execution time: 18_902_000 picoseconds.
+		Weight::from_parts(19_640_000, 3596)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Game::ScheduleRules` (r:1 w:1)
+	/// Proof: `Game::ScheduleRules` (`max_values`: None, `max_size`: Some(131), added: 2606, mode: `MaxEncodedLen`)
+	/// Storage: `Game::CounterForScheduleRules` (r:1 w:1)
+	/// Proof: `Game::CounterForScheduleRules` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn remove_schedule_rule() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `216`
+		//  Estimated: `3596`
+		// Minimum execution time: 19_807_000 picoseconds.
+		Weight::from_parts(20_522_000, 3596)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Game::ScheduleRules` (r:1 w:1)
+	/// Proof: `Game::ScheduleRules` (`max_values`: None, `max_size`: Some(131), added: 2606, mode: `MaxEncodedLen`)
+	/// Storage: `Game::GameSchedules` (r:1 w:1)
+	/// Proof: `Game::GameSchedules` (`max_values`: Some(1), `max_size`: Some(7633), added: 8128, mode: `MaxEncodedLen`)
+	/// Storage: `Game::Game` (r:1 w:0)
+	/// Proof: `Game::Game` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	fn schedule_rule_game() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `7741`
+		//  Estimated: `9118`
+		// Minimum execution time: 47_356_000 picoseconds.
+		Weight::from_parts(49_018_000, 9118)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 	/// Storage: `Game::PlayDepositAmount` (r:0 w:1)
 	/// Proof: `Game::PlayDepositAmount` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	fn set_play_deposit() -> Weight {
@@ -776,9 +908,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Game::Players` (r:1 w:0)
-	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
 	/// Storage: `Game::Game` (r:1 w:0)
-	/// Proof: `Game::Game` (`max_values`: Some(1), `max_size`: Some(70), added: 565, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Game` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `Game::StmtAccountToAlias` (r:1 w:0)
@@ -805,7 +937,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Game::Game` (r:1 w:1)
-	/// Proof: `Game::Game` (`max_values`: Some(1), `max_size`: Some(70), added: 565, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Game` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	fn process_reporting() -> Weight {
@@ -833,7 +965,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `Game::GameIndex` (r:1 w:0)
 	/// Proof: `Game::GameIndex` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `Game::Game` (r:1 w:1)
-	/// Proof: `Game::Game` (`max_values`: Some(1), `max_size`: Some(70), added: 565, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Game` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
 	/// Storage: `Airdrop::Events` (r:1 w:1)
 	/// Proof: `Airdrop::Events` (`max_values`: None, `max_size`: Some(767), added: 3242, mode: `MaxEncodedLen`)
 	/// Storage: `Assets::Asset` (r:1 w:1)
@@ -845,15 +977,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `AssetsHolder::BalancesOnHold` (r:1 w:1)
 	/// Proof: `AssetsHolder::BalancesOnHold` (`max_values`: None, `max_size`: Some(682), added: 3157, mode: `MaxEncodedLen`)
 	/// Storage: `Game::Players` (r:1 w:0)
-	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
 	/// Storage: `Game::IndexToPlayer` (r:1 w:0)
-	/// Proof: `Game::IndexToPlayer` (`max_values`: None, `max_size`: Some(46), added: 2521, mode: `MaxEncodedLen`)
+	/// Proof: `Game::IndexToPlayer` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
 	/// Storage: `Game::PlayerToIndex` (r:1 w:0)
-	/// Proof: `Game::PlayerToIndex` (`max_values`: None, `max_size`: Some(62), added: 2537, mode: `MaxEncodedLen`)
+	/// Proof: `Game::PlayerToIndex` (`max_values`: None, `max_size`: Some(71), added: 2546, mode: `MaxEncodedLen`)
 	/// Storage: `Game::ShuffleRecognized` (r:1 w:0)
-	/// Proof: `Game::ShuffleRecognized` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	/// Proof: `Game::ShuffleRecognized` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
 	/// Storage: `Game::ShuffleNotRecognized` (r:1 w:0)
-	/// Proof: `Game::ShuffleNotRecognized` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	/// Proof: `Game::ShuffleNotRecognized` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
 	/// Storage: `Game::GameHistory` (r:0 w:1)
 	/// Proof: `Game::GameHistory` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
 	/// Storage: `Airdrop::ActionSchedule` (r:0 w:1)
@@ -868,7 +1000,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(8_u64))
 	}
 	/// Storage: `Game::Game` (r:1 w:0)
-	/// Proof: `Game::Game` (`max_values`: Some(1), `max_size`: Some(70), added: 565, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Game` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
 	/// Storage: `Game::StoredPhaseDurations` (r:0 w:1)
 	/// Proof: `Game::StoredPhaseDurations` (`max_values`: Some(1), `max_size`: Some(24), added: 519, mode: `MaxEncodedLen`)
 	fn set_game_phases() -> Weight {
@@ -930,12 +1062,113 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(11_u64))
 			.saturating_add(T::DbWeight::get().writes(8_u64))
 	}
+	/// Storage: `Game::Game` (r:1 w:1)
+	/// Proof: `Game::Game` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Game::SubmittedReports` (r:1 w:0)
+	/// Proof: `Game::SubmittedReports` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	fn player_process_step0() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `312`
+		//  Estimated: `3559`
+		// Minimum execution time: 9_874_000 picoseconds.
+		Weight::from_parts(10_312_000, 3559)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Game::SubmittedReports` (r:1 w:1)
+	/// Proof: `Game::SubmittedReports` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `Game::PlayerToIndex` (r:1 w:0)
+	/// Proof: `Game::PlayerToIndex` (`max_values`: None, `max_size`: Some(71), added: 2546, mode: `MaxEncodedLen`)
+	/// Storage: `Game::IndexToPlayer` (r:27 w:0)
+	/// Proof: `Game::IndexToPlayer` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	/// Storage: `Game::Players` (r:27 w:0)
+	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `Game::InconsistentReportStreak` (r:1 w:1)
+	/// Proof: `Game::InconsistentReportStreak` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `Score::Participants` (r:1 w:1)
+	/// Proof: `Score::Participants` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Score::PersonhoodThreshold` (r:1 w:0)
+	/// Proof: `Score::PersonhoodThreshold` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Score::SignalWeightSchedule` (r:1 w:0)
+	/// Proof: `Score::SignalWeightSchedule` (`max_values`: Some(1), `max_size`: Some(41), added: 536, mode: `MaxEncodedLen`)
+	/// Storage: `ProofOfInk::Candidates` (r:1 w:0)
+	/// Proof: `ProofOfInk::Candidates` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
+	/// Storage: `PeopleLite::LitePeople` (r:1 w:0)
+	/// Proof: `PeopleLite::LitePeople` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Honour::Tally` (r:1 w:0)
+	/// Proof: `Honour::Tally` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 3]`.
+	fn player_process_step0_reporter(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1024 + r * (640 ±0)`
+		//  Estimated: `4040 + r * (12775 ±0)`
+		// Minimum execution time: 41_503_000 picoseconds.
+		Weight::from_parts(36_178_452, 4040)
+			// Standard Error: 21_877
+			.saturating_add(Weight::from_parts(19_204_381, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().reads((18_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 12775).saturating_mul(r.into()))
+	}
+	/// Storage: `Game::AttendanceDisputes` (r:1 w:1)
+	/// Proof: `Game::AttendanceDisputes` (`max_values`: None, `max_size`: Some(145), added: 2620, mode: `MaxEncodedLen`)
+	/// Storage: `Game::Game` (r:1 w:0)
+	/// Proof: `Game::Game` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::CaseCount` (r:1 w:1)
+	/// Proof: `MobRule::CaseCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::OpenCases` (r:0 w:1)
+	/// Proof: `MobRule::OpenCases` (`max_values`: None, `max_size`: Some(187), added: 2662, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	fn dispute_attendance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `583`
+		//  Estimated: `3694`
+		// Minimum execution time: 61_382_000 picoseconds.
+		Weight::from_parts(63_117_000, 3694)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Game::AttendanceDisputes` (r:1 w:1)
+	/// Proof: `Game::AttendanceDisputes` (`max_values`: None, `max_size`: Some(145), added: 2620, mode: `MaxEncodedLen`)
+	/// Storage: `Score::Participants` (r:1 w:1)
+	/// Proof: `Score::Participants` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Score::PersonhoodThreshold` (r:1 w:0)
+	/// Proof: `Score::PersonhoodThreshold` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Score::SignalWeightSchedule` (r:1 w:0)
+	/// Proof: `Score::SignalWeightSchedule` (`max_values`: Some(1), `max_size`: Some(41), added: 536, mode: `MaxEncodedLen`)
+	/// Storage: `ProofOfInk::Candidates` (r:1 w:0)
+	/// Proof: `ProofOfInk::Candidates` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
+	/// Storage: `PeopleLite::LitePeople` (r:1 w:0)
+	/// Proof: `PeopleLite::LitePeople` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Honour::Tally` (r:1 w:0)
+	/// Proof: `Honour::Tally` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Game::PlayerAttendanceHistory` (r:1 w:1)
+	/// Proof: `Game::PlayerAttendanceHistory` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `Game::GameParticipantCount` (r:1 w:1)
+	/// Proof: `Game::GameParticipantCount` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	fn attendance_judged() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `812`
+		//  Estimated: `3694`
+		// Minimum execution time: 64_205_000 picoseconds.
+		Weight::from_parts(66_340_000, 3694)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
 }
 
 // For backwards compatibility and tests.
 impl WeightInfo for () {
 	/// Storage: `Game::Game` (r:1 w:1)
-	/// Proof: `Game::Game` (`max_values`: Some(1), `max_size`: Some(70), added: 565, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Game` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
 	/// Storage: `Game::StoredPhaseDurations` (r:1 w:0)
 	/// Proof: `Game::StoredPhaseDurations` (`max_values`: Some(1), `max_size`: Some(24), added: 519, mode: `MaxEncodedLen`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
@@ -968,7 +1201,7 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(10_u64))
 	}
 	/// Storage: `Game::Game` (r:1 w:0)
-	/// Proof: `Game::Game` (`max_values`: Some(1), `max_size`: Some(70), added: 565, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Game` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
 	fn get_game() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `230`
@@ -1001,7 +1234,7 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 	}
 	/// Storage: `Game::Game` (r:0 w:1)
-	/// Proof: `Game::Game` (`max_values`: Some(1), `max_size`: Some(70), added: 565, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Game` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
 	fn put_game() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
@@ -1025,13 +1258,13 @@ impl WeightInfo for () {
 	/// Storage: `System::ParentHash` (r:1 w:0)
 	/// Proof: `System::ParentHash` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
 	/// Storage: `Game::Players` (r:1 w:0)
-	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
 	/// Storage: `Game::ShuffleRecognized` (r:1 w:0)
-	/// Proof: `Game::ShuffleRecognized` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	/// Proof: `Game::ShuffleRecognized` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
 	/// Storage: `Game::ShuffleNotRecognized` (r:1 w:0)
-	/// Proof: `Game::ShuffleNotRecognized` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	/// Proof: `Game::ShuffleNotRecognized` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
 	/// Storage: `Game::PlayerToIndex` (r:1 w:0)
-	/// Proof: `Game::PlayerToIndex` (`max_values`: None, `max_size`: Some(62), added: 2537, mode: `MaxEncodedLen`)
+	/// Proof: `Game::PlayerToIndex` (`max_values`: None, `max_size`: Some(71), added: 2546, mode: `MaxEncodedLen`)
 	/// Storage: `Members::RingsState` (r:1 w:0)
 	/// Proof: `Members::RingsState` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
 	/// Storage: `Members::ActiveMembers` (r:1 w:0)
@@ -1047,7 +1280,7 @@ impl WeightInfo for () {
 	/// Storage: `Score::PersonhoodThreshold` (r:0 w:1)
 	/// Proof: `Score::PersonhoodThreshold` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `Game::Game` (r:0 w:1)
-	/// Proof: `Game::Game` (`max_values`: Some(1), `max_size`: Some(70), added: 565, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Game` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
 	fn shuffles_base() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `391`
@@ -1058,11 +1291,11 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Game::Players` (r:2 w:1)
-	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
 	/// Storage: `Score::Participants` (r:1 w:0)
 	/// Proof: `Score::Participants` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
 	/// Storage: `Game::ShuffleNotRecognized` (r:0 w:3)
-	/// Proof: `Game::ShuffleNotRecognized` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	/// Proof: `Game::ShuffleNotRecognized` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 3]`.
 	fn shuffle_step_insert(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -1077,11 +1310,11 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
 	}
 	/// Storage: `Game::ShuffleNotRecognized` (r:6 w:3)
-	/// Proof: `Game::ShuffleNotRecognized` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	/// Proof: `Game::ShuffleNotRecognized` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
 	/// Storage: `Game::PlayerToIndex` (r:2 w:2)
-	/// Proof: `Game::PlayerToIndex` (`max_values`: None, `max_size`: Some(62), added: 2537, mode: `MaxEncodedLen`)
+	/// Proof: `Game::PlayerToIndex` (`max_values`: None, `max_size`: Some(71), added: 2546, mode: `MaxEncodedLen`)
 	/// Storage: `Game::IndexToPlayer` (r:0 w:3)
-	/// Proof: `Game::IndexToPlayer` (`max_values`: None, `max_size`: Some(46), added: 2521, mode: `MaxEncodedLen`)
+	/// Proof: `Game::IndexToPlayer` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 3]`.
 	fn shuffle_step_retrieve(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -1096,27 +1329,54 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(0, 5098).saturating_mul(n.into()))
 	}
 	/// Storage: `Game::PlayerToIndex` (r:2 w:0)
-	/// Proof: `Game::PlayerToIndex` (`max_values`: None, `max_size`: Some(62), added: 2537, mode: `MaxEncodedLen`)
+	/// Proof: `Game::PlayerToIndex` (`max_values`: None, `max_size`: Some(71), added: 2546, mode: `MaxEncodedLen`)
 	/// Storage: `Game::IndexToPlayer` (r:15 w:0)
-	/// Proof: `Game::IndexToPlayer` (`max_values`: None, `max_size`: Some(46), added: 2521, mode: `MaxEncodedLen`)
+	/// Proof: `Game::IndexToPlayer` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	/// Storage: `Game::IndexToPlayer` (r:27 w:2)
+	/// Proof: `Game::IndexToPlayer` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	/// Storage: `Game::RecentCoPlayers` (r:2 w:0)
+	/// Proof: `Game::RecentCoPlayers` (`max_values`: None, `max_size`: Some(1040), added: 3515, mode: `MaxEncodedLen`)
+	/// Storage: `Game::PlayerToIndex` (r:4 w:2)
+	/// Proof: `Game::PlayerToIndex` (`max_values`: None, `max_size`: Some(71), added: 2546, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[2, 6]`.
+	/// The range of component `r` is `[1, 3]`.
+	fn shuffle_step_spread_pairings(n: u32, r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2287 + n * (312 ±0) + r * (498 ±0)`
+		//  Estimated: `12122 + n * (5060 ±402) + r * (5060 ±803)`
+		// Minimum execution time: 61_205_000 picoseconds.
+		Weight::from_parts(38_417_000, 12122)
+			// Standard Error: 251_774
+			.saturating_add(Weight::from_parts(9_812_340, 0).saturating_mul(n.into()))
+			// Standard Error: 503_102
+			.saturating_add(Weight::from_parts(12_604_117, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(Weight::from_parts(0, 5060).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 5060).saturating_mul(r.into()))
+	}
 	/// Storage: `Game::Players` (r:10 w:1)
-	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `Game::RecentCoPlayers` (r:1 w:1)
+	/// Proof: `Game::RecentCoPlayers` (`max_values`: None, `max_size`: Some(1040), added: 3515, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[2, 6]`.
 	/// The range of component `r` is `[1, 3]`.
 	fn shuffle_step_compute_weights(n: u32, r: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0 + n * (465 ±0) + r * (690 ±0)`
-		//  Estimated: `8655 + n * (2077 ±331) + r * (2163 ±685)`
-		// Minimum execution time: 45_594_000 picoseconds.
-		Weight::from_parts(47_671_000, 8655)
+		//  Measured:  `1007 + n * (465 ±0) + r * (690 ±0)`
+		//  Estimated: `12170 + n * (2077 ±331) + r * (2163 ±685)`
+		// Minimum execution time: 52_318_000 picoseconds.
+		Weight::from_parts(54_902_000, 12170)
 			// Standard Error: 214_035
 			.saturating_add(Weight::from_parts(5_984_369, 0).saturating_mul(n.into()))
 			// Standard Error: 443_300
 			.saturating_add(Weight::from_parts(4_990_746, 0).saturating_mul(r.into()))
-			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
 			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
 			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(r.into())))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 			.saturating_add(Weight::from_parts(0, 2077).saturating_mul(n.into()))
 			.saturating_add(Weight::from_parts(0, 2163).saturating_mul(r.into()))
 	}
@@ -1144,11 +1404,11 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Game::Game` (r:1 w:1)
-	/// Proof: `Game::Game` (`max_values`: Some(1), `max_size`: Some(70), added: 565, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Game` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `Game::Players` (r:1 w:0)
-	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
 	/// Storage: `Members::Collections` (r:1 w:0)
 	/// Proof: `Members::Collections` (`max_values`: None, `max_size`: Some(646), added: 3121, mode: `MaxEncodedLen`)
 	fn player_process_step1() -> Weight {
@@ -1164,6 +1424,14 @@ impl WeightInfo for () {
 	/// Proof: `Score::Participants` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
 	/// Storage: `Score::PersonhoodThreshold` (r:1 w:0)
 	/// Proof: `Score::PersonhoodThreshold` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Score::SignalWeightSchedule` (r:1 w:0)
+	/// Proof: `Score::SignalWeightSchedule` (`max_values`: Some(1), `max_size`: Some(41), added: 536, mode: `MaxEncodedLen`)
+	/// Storage: `ProofOfInk::Candidates` (r:1 w:0)
+	/// Proof: `ProofOfInk::Candidates` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
+	/// Storage: `PeopleLite::LitePeople` (r:1 w:0)
+	/// Proof: `PeopleLite::LitePeople` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Honour::Tally` (r:1 w:0)
+	/// Proof: `Honour::Tally` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `Score::AbsenceGraceRatio` (r:1 w:0)
 	/// Proof: `Score::AbsenceGraceRatio` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
 	/// Storage: `Game::PlayerAttendanceHistory` (r:1 w:1)
@@ -1171,9 +1439,9 @@ impl WeightInfo for () {
 	/// Storage: `Game::GameParticipantCount` (r:1 w:1)
 	/// Proof: `Game::GameParticipantCount` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
 	/// Storage: `Game::PlayerToIndex` (r:1 w:0)
-	/// Proof: `Game::PlayerToIndex` (`max_values`: None, `max_size`: Some(62), added: 2537, mode: `MaxEncodedLen`)
+	/// Proof: `Game::PlayerToIndex` (`max_values`: None, `max_size`: Some(71), added: 2546, mode: `MaxEncodedLen`)
 	/// Storage: `Game::IndexToPlayer` (r:15 w:0)
-	/// Proof: `Game::IndexToPlayer` (`max_values`: None, `max_size`: Some(46), added: 2521, mode: `MaxEncodedLen`)
+	/// Proof: `Game::IndexToPlayer` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
 	/// Storage: `Game::NftCandidates` (r:16 w:15)
 	/// Proof: `Game::NftCandidates` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
@@ -1181,17 +1449,19 @@ impl WeightInfo for () {
 	/// Storage: `Balances::Holds` (r:1 w:1)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
 	/// Storage: `Game::Players` (r:2 w:1)
-	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
 	/// Storage: `Game::Nfts` (r:0 w:15)
 	/// Proof: `Game::Nfts` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Game::AttendanceDisputes` (r:0 w:1)
+	/// Proof: `Game::AttendanceDisputes` (`max_values`: None, `max_size`: Some(145), added: 2620, mode: `MaxEncodedLen`)
 	fn player_process_step1_attended_player() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `3476`
 		//  Estimated: `42142`
 		// Minimum execution time: 299_690_000 picoseconds.
 		Weight::from_parts(311_228_000, 42142)
-			.saturating_add(RocksDbWeight::get().reads(41_u64))
-			.saturating_add(RocksDbWeight::get().writes(36_u64))
+			.saturating_add(RocksDbWeight::get().reads(45_u64))
+			.saturating_add(RocksDbWeight::get().writes(37_u64))
 	}
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
@@ -1199,6 +1469,14 @@ impl WeightInfo for () {
 	/// Proof: `Score::Participants` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
 	/// Storage: `Score::PersonhoodThreshold` (r:1 w:0)
 	/// Proof: `Score::PersonhoodThreshold` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Score::SignalWeightSchedule` (r:1 w:0)
+	/// Proof: `Score::SignalWeightSchedule` (`max_values`: Some(1), `max_size`: Some(41), added: 536, mode: `MaxEncodedLen`)
+	/// Storage: `ProofOfInk::Candidates` (r:1 w:0)
+	/// Proof: `ProofOfInk::Candidates` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
+	/// Storage: `PeopleLite::LitePeople` (r:1 w:0)
+	/// Proof: `PeopleLite::LitePeople` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Honour::Tally` (r:1 w:0)
+	/// Proof: `Honour::Tally` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `Score::AbsenceGraceRatio` (r:1 w:0)
 	/// Proof: `Score::AbsenceGraceRatio` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
 	/// Storage: `Game::NftCandidates` (r:15 w:15)
@@ -1208,22 +1486,24 @@ impl WeightInfo for () {
 	/// Storage: UNKNOWN KEY `0x3a73746174656d656e745f616c6c6f77616e63653ae1ef2fe6f211f7399a8a6a` (r:1 w:1)
 	/// Proof: UNKNOWN KEY `0x3a73746174656d656e745f616c6c6f77616e63653ae1ef2fe6f211f7399a8a6a` (r:1 w:1)
 	/// Storage: `Game::Players` (r:1 w:1)
-	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
 	/// Storage: `Game::StmtAccountToAlias` (r:0 w:1)
 	/// Proof: `Game::StmtAccountToAlias` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	/// Storage: `Game::ArchivedPlayers` (r:0 w:1)
 	/// Proof: `Game::ArchivedPlayers` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
+	/// Storage: `Game::AttendanceDisputes` (r:0 w:1)
+	/// Proof: `Game::AttendanceDisputes` (`max_values`: None, `max_size`: Some(145), added: 2620, mode: `MaxEncodedLen`)
 	fn player_process_step1_not_attended_player() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2151`
 		//  Estimated: `39570`
 		// Minimum execution time: 75_519_000 picoseconds.
 		Weight::from_parts(77_579_000, 39570)
-			.saturating_add(RocksDbWeight::get().reads(22_u64))
-			.saturating_add(RocksDbWeight::get().writes(21_u64))
+			.saturating_add(RocksDbWeight::get().reads(26_u64))
+			.saturating_add(RocksDbWeight::get().writes(22_u64))
 	}
 	/// Storage: `Game::Game` (r:1 w:1)
-	/// Proof: `Game::Game` (`max_values`: Some(1), `max_size`: Some(70), added: 565, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Game` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
 	fn player_process_step2() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `165`
@@ -1234,9 +1514,9 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Game::IndexToPlayer` (r:101 w:100)
-	/// Proof: `Game::IndexToPlayer` (`max_values`: None, `max_size`: Some(46), added: 2521, mode: `MaxEncodedLen`)
+	/// Proof: `Game::IndexToPlayer` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
 	/// Storage: `Game::PlayerToIndex` (r:101 w:100)
-	/// Proof: `Game::PlayerToIndex` (`max_values`: None, `max_size`: Some(62), added: 2537, mode: `MaxEncodedLen`)
+	/// Proof: `Game::PlayerToIndex` (`max_values`: None, `max_size`: Some(71), added: 2546, mode: `MaxEncodedLen`)
 	fn player_process_step2_inner_loop() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `12777`
@@ -1247,9 +1527,9 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(200_u64))
 	}
 	/// Storage: `Game::Game` (r:1 w:1)
-	/// Proof: `Game::Game` (`max_values`: Some(1), `max_size`: Some(70), added: 565, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Game` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
 	/// Storage: `Game::Players` (r:1 w:0)
-	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
 	/// Storage: `Game::GameHistory` (r:0 w:1)
 	/// Proof: `Game::GameHistory` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
 	fn process_cancelling() -> Weight {
@@ -1262,11 +1542,11 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Game::Players` (r:2 w:1)
-	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
 	/// Storage: `Game::PlayerToIndex` (r:1 w:1)
-	/// Proof: `Game::PlayerToIndex` (`max_values`: None, `max_size`: Some(62), added: 2537, mode: `MaxEncodedLen`)
+	/// Proof: `Game::PlayerToIndex` (`max_values`: None, `max_size`: Some(71), added: 2546, mode: `MaxEncodedLen`)
 	/// Storage: `Game::IndexToPlayer` (r:0 w:3)
-	/// Proof: `Game::IndexToPlayer` (`max_values`: None, `max_size`: Some(46), added: 2521, mode: `MaxEncodedLen`)
+	/// Proof: `Game::IndexToPlayer` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 3]`.
 	fn process_cancelling_step(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -1281,13 +1561,13 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
 	}
 	/// Storage: `Game::Game` (r:1 w:1)
-	/// Proof: `Game::Game` (`max_values`: Some(1), `max_size`: Some(70), added: 565, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Game` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `Game::StmtAccountToAlias` (r:1 w:0)
 	/// Proof: `Game::StmtAccountToAlias` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	/// Storage: `Game::Players` (r:1 w:1)
-	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
 	/// Storage: `Game::ArchivedPlayers` (r:1 w:0)
 	/// Proof: `Game::ArchivedPlayers` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
 	/// Storage: `Score::Participants` (r:1 w:1)
@@ -1312,13 +1592,13 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(8_u64))
 	}
 	/// Storage: `Game::Game` (r:1 w:1)
-	/// Proof: `Game::Game` (`max_values`: Some(1), `max_size`: Some(70), added: 565, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Game` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `Game::StmtAccountToAlias` (r:1 w:0)
 	/// Proof: `Game::StmtAccountToAlias` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	/// Storage: `Game::Players` (r:1 w:1)
-	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
 	/// Storage: `Game::ArchivedPlayers` (r:1 w:0)
 	/// Proof: `Game::ArchivedPlayers` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
 	/// Storage: `Score::Participants` (r:1 w:1)
@@ -1347,13 +1627,13 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(9_u64))
 	}
 	/// Storage: `Game::Game` (r:1 w:1)
-	/// Proof: `Game::Game` (`max_values`: Some(1), `max_size`: Some(70), added: 565, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Game` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `Game::StmtAccountToAlias` (r:1 w:0)
 	/// Proof: `Game::StmtAccountToAlias` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	/// Storage: `Game::Players` (r:1 w:1)
-	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
 	/// Storage: `Game::ArchivedPlayers` (r:1 w:0)
 	/// Proof: `Game::ArchivedPlayers` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
 	/// Storage: `Score::Participants` (r:1 w:0)
@@ -1378,7 +1658,7 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Game::Game` (r:1 w:1)
-	/// Proof: `Game::Game` (`max_values`: Some(1), `max_size`: Some(70), added: 565, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Game` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `Game::AliasToStmtAccount` (r:1 w:1)
@@ -1388,7 +1668,7 @@ impl WeightInfo for () {
 	/// Storage: `Game::StmtAccountToAlias` (r:1 w:2)
 	/// Proof: `Game::StmtAccountToAlias` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	/// Storage: `Game::Players` (r:2 w:1)
-	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
 	/// Storage: `Game::ArchivedPlayers` (r:2 w:0)
 	/// Proof: `Game::ArchivedPlayers` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
 	/// Storage: UNKNOWN KEY `0x3a73746174656d656e745f616c6c6f77616e63653acecc1507dc1ddd7295951c` (r:1 w:1)
@@ -1417,19 +1697,27 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(11_u64))
 	}
 	/// Storage: `Game::Game` (r:1 w:1)
-	/// Proof: `Game::Game` (`max_values`: Some(1), `max_size`: Some(70), added: 565, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Game` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `Game::Players` (r:16 w:16)
-	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
 	/// Storage: `Game::PlayerToIndex` (r:16 w:0)
-	/// Proof: `Game::PlayerToIndex` (`max_values`: None, `max_size`: Some(62), added: 2537, mode: `MaxEncodedLen`)
+	/// Proof: `Game::PlayerToIndex` (`max_values`: None, `max_size`: Some(71), added: 2546, mode: `MaxEncodedLen`)
 	/// Storage: `Game::IndexToPlayer` (r:100 w:0)
-	/// Proof: `Game::IndexToPlayer` (`max_values`: None, `max_size`: Some(46), added: 2521, mode: `MaxEncodedLen`)
+	/// Proof: `Game::IndexToPlayer` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
 	/// Storage: `Score::Participants` (r:16 w:16)
 	/// Proof: `Score::Participants` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
 	/// Storage: `Score::PersonhoodThreshold` (r:1 w:0)
 	/// Proof: `Score::PersonhoodThreshold` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Score::SignalWeightSchedule` (r:1 w:0)
+	/// Proof: `Score::SignalWeightSchedule` (`max_values`: Some(1), `max_size`: Some(41), added: 536, mode: `MaxEncodedLen`)
+	/// Storage: `ProofOfInk::Candidates` (r:16 w:0)
+	/// Proof: `ProofOfInk::Candidates` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
+	/// Storage: `PeopleLite::LitePeople` (r:16 w:0)
+	/// Proof: `PeopleLite::LitePeople` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Honour::Tally` (r:16 w:0)
+	/// Proof: `Honour::Tally` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `Score::AbsenceGraceRatio` (r:1 w:0)
 	/// Proof: `Score::AbsenceGraceRatio` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
 	/// Storage: `Game::PlayerAttendanceHistory` (r:16 w:16)
@@ -1440,33 +1728,41 @@ impl WeightInfo for () {
 	/// Proof: `Game::NftCandidates` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
 	/// Storage: `Game::Nfts` (r:0 w:251)
 	/// Proof: `Game::Nfts` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Game::SubmittedReports` (r:0 w:1)
+	/// Proof: `Game::SubmittedReports` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `Game::AttendanceDisputes` (r:0 w:1)
+	/// Proof: `Game::AttendanceDisputes` (`max_values`: None, `max_size`: Some(145), added: 2620, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[0, 16]`.
 	fn report(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `7695 + p * (284 ±0)`
-		//  Estimated: `84233 + p * (13014 ±13)`
+		//  Estimated: `84233 + p * (20775 ±13)`
 		// Minimum execution time: 282_876_000 picoseconds.
 		Weight::from_parts(370_023_048, 84233)
 			// Standard Error: 348_960
 			.saturating_add(Weight::from_parts(111_738_774, 0).saturating_mul(p.into()))
-			.saturating_add(RocksDbWeight::get().reads(55_u64))
-			.saturating_add(RocksDbWeight::get().reads((10_u64).saturating_mul(p.into())))
-			.saturating_add(RocksDbWeight::get().writes(33_u64))
-			.saturating_add(RocksDbWeight::get().writes((18_u64).saturating_mul(p.into())))
-			.saturating_add(Weight::from_parts(0, 13014).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(56_u64))
+			.saturating_add(RocksDbWeight::get().reads((13_u64).saturating_mul(p.into())))
+			.saturating_add(RocksDbWeight::get().writes(34_u64))
+			.saturating_add(RocksDbWeight::get().writes((19_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 20775).saturating_mul(p.into()))
 	}
 	/// Storage: `Game::ArchivedPlayers` (r:1 w:0)
 	/// Proof: `Game::ArchivedPlayers` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
 	/// Storage: `Game::Players` (r:1 w:1)
-	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
 	/// Storage: `Game::Game` (r:1 w:0)
-	/// Proof: `Game::Game` (`max_values`: Some(1), `max_size`: Some(70), added: 565, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Game` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Holds` (r:1 w:1)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
 	/// Storage: UNKNOWN KEY `0x3a73746174656d656e745f616c6c6f77616e63653ad861ea1ebf4800d4b89f4f` (r:1 w:1)
 	/// Proof: UNKNOWN KEY `0x3a73746174656d656e745f616c6c6f77616e63653ad861ea1ebf4800d4b89f4f` (r:1 w:1)
 	/// Storage: `Score::Participants` (r:0 w:1)
 	/// Proof: `Score::Participants` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Game::AttendanceDisputes` (r:0 w:1)
+	/// Proof: `Game::AttendanceDisputes` (`max_values`: None, `max_size`: Some(145), added: 2620, mode: `MaxEncodedLen`)
+	/// Storage: `Game::RecentCoPlayers` (r:0 w:1)
+	/// Proof: `Game::RecentCoPlayers` (`max_values`: None, `max_size`: Some(1040), added: 3515, mode: `MaxEncodedLen`)
 	fn offboard_account() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `302`
@@ -1474,14 +1770,14 @@ impl WeightInfo for () {
 		// Minimum execution time: 61_101_000 picoseconds.
 		Weight::from_parts(62_836_000, 3767)
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: `Game::ArchivedPlayers` (r:1 w:1)
 	/// Proof: `Game::ArchivedPlayers` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
 	/// Storage: `Game::Players` (r:1 w:1)
-	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
 	/// Storage: `Game::Game` (r:1 w:0)
-	/// Proof: `Game::Game` (`max_values`: Some(1), `max_size`: Some(70), added: 565, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Game` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
 	/// Storage: `Game::AliasToStmtAccount` (r:1 w:1)
 	/// Proof: `Game::AliasToStmtAccount` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	/// Storage: UNKNOWN KEY `0x3a73746174656d656e745f616c6c6f77616e63653acecc1507dc1ddd7295951c` (r:1 w:1)
@@ -1490,6 +1786,10 @@ impl WeightInfo for () {
 	/// Proof: `Score::Participants` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
 	/// Storage: `Game::StmtAccountToAlias` (r:0 w:1)
 	/// Proof: `Game::StmtAccountToAlias` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Game::AttendanceDisputes` (r:0 w:1)
+	/// Proof: `Game::AttendanceDisputes` (`max_values`: None, `max_size`: Some(145), added: 2620, mode: `MaxEncodedLen`)
+	/// Storage: `Game::RecentCoPlayers` (r:0 w:1)
+	/// Proof: `Game::RecentCoPlayers` (`max_values`: None, `max_size`: Some(1040), added: 3515, mode: `MaxEncodedLen`)
 	fn offboard_person() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `509`
@@ -1497,7 +1797,7 @@ impl WeightInfo for () {
 		// Minimum execution time: 35_371_000 picoseconds.
 		Weight::from_parts(36_489_000, 3974)
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
 	}
 	/// Storage: `Game::ArchivedPlayers` (r:1 w:1)
 	/// Proof: `Game::ArchivedPlayers` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
@@ -1505,6 +1805,8 @@ impl WeightInfo for () {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Score::Participants` (r:0 w:1)
 	/// Proof: `Score::Participants` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Game::RecentCoPlayers` (r:0 w:1)
+	/// Proof: `Game::RecentCoPlayers` (`max_values`: None, `max_size`: Some(1040), added: 3515, mode: `MaxEncodedLen`)
 	fn kickout() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `330`
@@ -1512,7 +1814,7 @@ impl WeightInfo for () {
 		// Minimum execution time: 21_862_000 picoseconds.
 		Weight::from_parts(22_529_000, 3593)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Game::AvailableInvites` (r:1 w:1)
 	/// Proof: `Game::AvailableInvites` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
@@ -1572,7 +1874,7 @@ impl WeightInfo for () {
 	/// Storage: `Game::GameSchedules` (r:1 w:1)
 	/// Proof: `Game::GameSchedules` (`max_values`: Some(1), `max_size`: Some(7633), added: 8128, mode: `MaxEncodedLen`)
 	/// Storage: `Game::Game` (r:1 w:0)
-	/// Proof: `Game::Game` (`max_values`: Some(1), `max_size`: Some(70), added: 565, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Game` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `Game::StoredPhaseDurations` (r:1 w:0)
@@ -1600,6 +1902,60 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Game::CounterForScheduleRules` (r:1 w:1)
+	/// Proof: `Game::CounterForScheduleRules` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Game::NextScheduleRuleIndex` (r:1 w:1)
+	/// Proof: `Game::NextScheduleRuleIndex` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Game::ScheduleRules` (r:1 w:1)
+	/// Proof: `Game::ScheduleRules` (`max_values`: None, `max_size`: Some(131), added: 2606, mode: `MaxEncodedLen`)
+	fn add_schedule_rule() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `3596`
+		// Minimum execution time: 21_473_000 picoseconds.
+		Weight::from_parts(22_315_000, 3596)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Game::ScheduleRules` (r:1 w:1)
+	/// Proof: `Game::ScheduleRules` (`max_values`: None, `max_size`: Some(131), added: 2606, mode: `MaxEncodedLen`)
+	fn amend_schedule_rule() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `195`
+		//  Estimated: `3596`
+		// Minimum execution time: 18_902_000 picoseconds.
+		Weight::from_parts(19_640_000, 3596)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Game::ScheduleRules` (r:1 w:1)
+	/// Proof: `Game::ScheduleRules` (`max_values`: None, `max_size`: Some(131), added: 2606, mode: `MaxEncodedLen`)
+	/// Storage: `Game::CounterForScheduleRules` (r:1 w:1)
+	/// Proof: `Game::CounterForScheduleRules` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn remove_schedule_rule() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `216`
+		//  Estimated: `3596`
+		// Minimum execution time: 19_807_000 picoseconds.
+		Weight::from_parts(20_522_000, 3596)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Game::ScheduleRules` (r:1 w:1)
+	/// Proof: `Game::ScheduleRules` (`max_values`: None, `max_size`: Some(131), added: 2606, mode: `MaxEncodedLen`)
+	/// Storage: `Game::GameSchedules` (r:1 w:1)
+	/// Proof: `Game::GameSchedules` (`max_values`: Some(1), `max_size`: Some(7633), added: 8128, mode: `MaxEncodedLen`)
+	/// Storage: `Game::Game` (r:1 w:0)
+	/// Proof: `Game::Game` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	fn schedule_rule_game() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `7741`
+		//  Estimated: `9118`
+		// Minimum execution time: 47_356_000 picoseconds.
+		Weight::from_parts(49_018_000, 9118)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 	/// Storage: `Game::PlayDepositAmount` (r:0 w:1)
 	/// Proof: `Game::PlayDepositAmount` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	fn set_play_deposit() -> Weight {
@@ -1611,9 +1967,9 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Game::Players` (r:1 w:0)
-	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
 	/// Storage: `Game::Game` (r:1 w:0)
-	/// Proof: `Game::Game` (`max_values`: Some(1), `max_size`: Some(70), added: 565, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Game` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `Game::StmtAccountToAlias` (r:1 w:0)
@@ -1640,7 +1996,7 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Game::Game` (r:1 w:1)
-	/// Proof: `Game::Game` (`max_values`: Some(1), `max_size`: Some(70), added: 565, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Game` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	fn process_reporting() -> Weight {
@@ -1668,7 +2024,7 @@ impl WeightInfo for () {
 	/// Storage: `Game::GameIndex` (r:1 w:0)
 	/// Proof: `Game::GameIndex` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `Game::Game` (r:1 w:1)
-	/// Proof: `Game::Game` (`max_values`: Some(1), `max_size`: Some(70), added: 565, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Game` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
 	/// Storage: `Airdrop::Events` (r:1 w:1)
 	/// Proof: `Airdrop::Events` (`max_values`: None, `max_size`: Some(767), added: 3242, mode: `MaxEncodedLen`)
 	/// Storage: `Assets::Asset` (r:1 w:1)
@@ -1680,15 +2036,15 @@ impl WeightInfo for () {
 	/// Storage: `AssetsHolder::BalancesOnHold` (r:1 w:1)
 	/// Proof: `AssetsHolder::BalancesOnHold` (`max_values`: None, `max_size`: Some(682), added: 3157, mode: `MaxEncodedLen`)
 	/// Storage: `Game::Players` (r:1 w:0)
-	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
 	/// Storage: `Game::IndexToPlayer` (r:1 w:0)
-	/// Proof: `Game::IndexToPlayer` (`max_values`: None, `max_size`: Some(46), added: 2521, mode: `MaxEncodedLen`)
+	/// Proof: `Game::IndexToPlayer` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
 	/// Storage: `Game::PlayerToIndex` (r:1 w:0)
-	/// Proof: `Game::PlayerToIndex` (`max_values`: None, `max_size`: Some(62), added: 2537, mode: `MaxEncodedLen`)
+	/// Proof: `Game::PlayerToIndex` (`max_values`: None, `max_size`: Some(71), added: 2546, mode: `MaxEncodedLen`)
 	/// Storage: `Game::ShuffleRecognized` (r:1 w:0)
-	/// Proof: `Game::ShuffleRecognized` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	/// Proof: `Game::ShuffleRecognized` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
 	/// Storage: `Game::ShuffleNotRecognized` (r:1 w:0)
-	/// Proof: `Game::ShuffleNotRecognized` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	/// Proof: `Game::ShuffleNotRecognized` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
 	/// Storage: `Game::GameHistory` (r:0 w:1)
 	/// Proof: `Game::GameHistory` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
 	/// Storage: `Airdrop::ActionSchedule` (r:0 w:1)
@@ -1703,7 +2059,7 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(8_u64))
 	}
 	/// Storage: `Game::Game` (r:1 w:0)
-	/// Proof: `Game::Game` (`max_values`: Some(1), `max_size`: Some(70), added: 565, mode: `MaxEncodedLen`)
+	/// Proof: `Game::Game` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
 	/// Storage: `Game::StoredPhaseDurations` (r:0 w:1)
 	/// Proof: `Game::StoredPhaseDurations` (`max_values`: Some(1), `max_size`: Some(24), added: 519, mode: `MaxEncodedLen`)
 	fn set_game_phases() -> Weight {
@@ -1765,4 +2121,105 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(11_u64))
 			.saturating_add(RocksDbWeight::get().writes(8_u64))
 	}
+	/// Storage: `Game::Game` (r:1 w:1)
+	/// Proof: `Game::Game` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Game::SubmittedReports` (r:1 w:0)
+	/// Proof: `Game::SubmittedReports` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	fn player_process_step0() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `312`
+		//  Estimated: `3559`
+		// Minimum execution time: 9_874_000 picoseconds.
+		Weight::from_parts(10_312_000, 3559)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Game::SubmittedReports` (r:1 w:1)
+	/// Proof: `Game::SubmittedReports` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `Game::PlayerToIndex` (r:1 w:0)
+	/// Proof: `Game::PlayerToIndex` (`max_values`: None, `max_size`: Some(71), added: 2546, mode: `MaxEncodedLen`)
+	/// Storage: `Game::IndexToPlayer` (r:27 w:0)
+	/// Proof: `Game::IndexToPlayer` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	/// Storage: `Game::Players` (r:27 w:0)
+	/// Proof: `Game::Players` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `Game::InconsistentReportStreak` (r:1 w:1)
+	/// Proof: `Game::InconsistentReportStreak` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `Score::Participants` (r:1 w:1)
+	/// Proof: `Score::Participants` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Score::PersonhoodThreshold` (r:1 w:0)
+	/// Proof: `Score::PersonhoodThreshold` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Score::SignalWeightSchedule` (r:1 w:0)
+	/// Proof: `Score::SignalWeightSchedule` (`max_values`: Some(1), `max_size`: Some(41), added: 536, mode: `MaxEncodedLen`)
+	/// Storage: `ProofOfInk::Candidates` (r:1 w:0)
+	/// Proof: `ProofOfInk::Candidates` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
+	/// Storage: `PeopleLite::LitePeople` (r:1 w:0)
+	/// Proof: `PeopleLite::LitePeople` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Honour::Tally` (r:1 w:0)
+	/// Proof: `Honour::Tally` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 3]`.
+	fn player_process_step0_reporter(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1024 + r * (640 ±0)`
+		//  Estimated: `4040 + r * (12775 ±0)`
+		// Minimum execution time: 41_503_000 picoseconds.
+		Weight::from_parts(36_178_452, 4040)
+			// Standard Error: 21_877
+			.saturating_add(Weight::from_parts(19_204_381, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().reads((18_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 12775).saturating_mul(r.into()))
+	}
+	/// Storage: `Game::AttendanceDisputes` (r:1 w:1)
+	/// Proof: `Game::AttendanceDisputes` (`max_values`: None, `max_size`: Some(145), added: 2620, mode: `MaxEncodedLen`)
+	/// Storage: `Game::Game` (r:1 w:0)
+	/// Proof: `Game::Game` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::CaseCount` (r:1 w:1)
+	/// Proof: `MobRule::CaseCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::OpenCases` (r:0 w:1)
+	/// Proof: `MobRule::OpenCases` (`max_values`: None, `max_size`: Some(187), added: 2662, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	fn dispute_attendance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `583`
+		//  Estimated: `3694`
+		// Minimum execution time: 61_382_000 picoseconds.
+		Weight::from_parts(63_117_000, 3694)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Game::AttendanceDisputes` (r:1 w:1)
+	/// Proof: `Game::AttendanceDisputes` (`max_values`: None, `max_size`: Some(145), added: 2620, mode: `MaxEncodedLen`)
+	/// Storage: `Score::Participants` (r:1 w:1)
+	/// Proof: `Score::Participants` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Score::PersonhoodThreshold` (r:1 w:0)
+	/// Proof: `Score::PersonhoodThreshold` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Score::SignalWeightSchedule` (r:1 w:0)
+	/// Proof: `Score::SignalWeightSchedule` (`max_values`: Some(1), `max_size`: Some(41), added: 536, mode: `MaxEncodedLen`)
+	/// Storage: `ProofOfInk::Candidates` (r:1 w:0)
+	/// Proof: `ProofOfInk::Candidates` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
+	/// Storage: `PeopleLite::LitePeople` (r:1 w:0)
+	/// Proof: `PeopleLite::LitePeople` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Honour::Tally` (r:1 w:0)
+	/// Proof: `Honour::Tally` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Game::PlayerAttendanceHistory` (r:1 w:1)
+	/// Proof: `Game::PlayerAttendanceHistory` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `Game::GameParticipantCount` (r:1 w:1)
+	/// Proof: `Game::GameParticipantCount` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	fn attendance_judged() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `812`
+		//  Estimated: `3694`
+		// Minimum execution time: 64_205_000 picoseconds.
+		Weight::from_parts(66_340_000, 3694)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
 }
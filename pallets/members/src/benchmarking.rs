@@ -187,6 +187,93 @@ fn setup_build_ring_bench<T: Config + Send + Sync>(
 	Ok((identifier, pre_build, ring_size))
 }
 
+/// Largest number of members a single compaction step can move in a benchmark, keeping the
+/// source ring below the compaction threshold with at least one member left behind.
+fn max_compaction_step<T: Config>() -> u32 {
+	let capacity = T::MaxFlexibleRingExponent::get().ring_capacity();
+	(T::RingCompactionThreshold::get() * capacity)
+		.saturating_sub(2)
+		.min(T::RingBuildingMemberLimit::get())
+		.max(1)
+}
+
+/// Set up a flexible collection for a compaction step moving `n` members.
+///
+/// Ring 1 is the sparse source ring with `n + 1` members and a built root, so the step resets
+/// its root rather than deleting it. Ring 0 is the target ring with room for exactly `n`
+/// members. The current onboarding ring is moved past both.
+fn setup_ring_compaction<T: Config + Send + Sync>(
+	n: u32,
+) -> Result<Identifier, frame_benchmarking::BenchmarkError> {
+	let identifier = BENCH_IDENTIFIER;
+	let ring_exponent = T::MaxFlexibleRingExponent::get();
+	let capacity = ring_exponent.ring_capacity();
+	if n + 1 >= T::RingCompactionThreshold::get() * capacity {
+		return Err(frame_benchmarking::BenchmarkError::Weightless);
+	}
+
+	setup_collection::<T>(identifier, 1, ring_exponent, RingMode::Flexible);
+
+	let target_members = generate_members::<T>(SEED, 0, capacity - n);
+	let source_members = generate_members::<T>(SEED, capacity - n, capacity + 1);
+	for (ring_index, members) in [(RI_ZERO, target_members), (1, source_members)] {
+		let keys: Vec<MemberOf<T>> = members.into_iter().map(|(_, member)| member).collect();
+		for (ring_position, key) in keys.iter().enumerate() {
+			let position = RingPosition::Included {
+				ring_index,
+				ring_page: 0,
+				ring_position: ring_position as u32,
+			};
+			Members::<T>::insert(identifier, key, position);
+		}
+		let total = keys.len() as u32;
+		let keys = BoundedVec::try_from(keys).expect("ring fits in a single page");
+		RingKeys::<T>::insert((identifier, ring_index, 0u32), keys);
+		RingKeysStatus::<T>::insert(
+			identifier,
+			ring_index,
+			RingStatus { total, included: 0, immutable_since: None },
+		);
+	}
+	CurrentRingIndex::<T>::insert(identifier, 2);
+	assert_ok!(Pallet::<T>::build_ring(&identifier, 1, n + 1));
+	assert!(Root::<T>::get(identifier, 1).is_some());
+	assert_eq!(Pallet::<T>::compactable_count(&identifier, 1, RI_ZERO), Some(n));
+
+	Ok(identifier)
+}
+
+/// Set up a flexible collection with a built ring of `n` members, left below the minimum
+/// population and past the current onboarding ring.
+fn setup_underpopulated_ring<T: Config + Send + Sync>(n: u32) -> Identifier {
+	let identifier = BENCH_IDENTIFIER;
+	let ring_exponent = T::MaxFlexibleRingExponent::get();
+	setup_collection::<T>(identifier, 1, ring_exponent, RingMode::Flexible);
+
+	let keys: Vec<MemberOf<T>> =
+		generate_members::<T>(SEED, 0, n).into_iter().map(|(_, member)| member).collect();
+	for (ring_position, key) in keys.iter().enumerate() {
+		let position = RingPosition::Included {
+			ring_index: RI_ZERO,
+			ring_page: 0,
+			ring_position: ring_position as u32,
+		};
+		Members::<T>::insert(identifier, key, position);
+	}
+	let keys = BoundedVec::try_from(keys).expect("ring fits in a single page");
+	RingKeys::<T>::insert((identifier, RI_ZERO, 0u32), keys);
+	RingKeysStatus::<T>::insert(
+		identifier,
+		RI_ZERO,
+		RingStatus { total: n, included: 0, immutable_since: None },
+	);
+	assert_ok!(Pallet::<T>::build_ring(&identifier, RI_ZERO, n));
+	CurrentRingIndex::<T>::insert(identifier, 1);
+	MinRingPopulation::<T>::insert(identifier, n + 1);
+
+	identifier
+}
+
 /// Worst-case setup for `ensure_can_delete_ring_page`: the deletion queue
 /// already contains the targeted (identifier, ring_index, page_index) entry so
 /// `RingDeletionQueue::contains_key` succeeds.
@@ -428,6 +515,27 @@ mod benches {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn set_min_ring_population() -> Result<(), BenchmarkError> {
+		T::BenchmarkHelper::set_valid_time();
+
+		let identifier = BENCH_IDENTIFIER;
+		setup_collection::<T>(identifier, 1, T::MaxFlexibleRingExponent::get(), RingMode::Flexible);
+
+		let min_ring_population = T::RingBuildingMemberLimit::get()
+			.min(T::MaxFlexibleRingExponent::get().ring_capacity());
+
+		let origin =
+			T::ManagerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, identifier, min_ring_population);
+
+		assert_eq!(MinRingPopulation::<T>::get(identifier), min_ring_population);
+
+		Ok(())
+	}
+
 	#[benchmark]
 	fn merge_rings() -> Result<(), BenchmarkError> {
 		T::BenchmarkHelper::set_valid_time();
@@ -1548,6 +1656,284 @@ mod benches {
 		Ok(())
 	}
 
+	/// `rotate_member_key` with the old key included in a ring, so the suspension is registered
+	/// in `PendingSuspensions`, while the new key is appended to the onboarding queue. The old key
+	/// replaced another key whose release window is over, so that key is pruned.
+	#[benchmark]
+	fn rotate_member_key() -> Result<(), BenchmarkError> {
+		T::BenchmarkHelper::set_valid_time();
+
+		let identifier = BENCH_IDENTIFIER;
+		let ring_size: u32 = T::MaxFlexibleRingExponent::get().ring_capacity();
+
+		setup_collection::<T>(
+			identifier,
+			ring_size,
+			T::MaxFlexibleRingExponent::get(),
+			RingMode::Flexible,
+		);
+
+		let members = generate_members_for_ring::<T>(SEED, ring_size);
+		add_members_to_collection::<T>(&identifier, &members);
+		assert_ok!(pallet::Pallet::<T>::onboard_members(&identifier, false));
+
+		let old_key = members[0].1.clone();
+		let (_, new_key) = new_member_from::<T>(0, SEED + 1);
+		let (_, replaced_key) = new_member_from::<T>(0, SEED + 2);
+		Members::<T>::insert(identifier, &replaced_key, RingPosition::Suspended);
+		let rotated_at =
+			T::Clock::now().as_secs().saturating_sub(T::OldRootRetentionDuration::get());
+		PendingAliasReleases::<T>::insert(
+			identifier,
+			&old_key,
+			AliasRelease { old_key: replaced_key.clone(), rotated_at, nonce: 0 },
+		);
+
+		#[extrinsic_call]
+		_(Origin::RotateKey(old_key.clone()), identifier, old_key.clone(), new_key.clone(), 0);
+
+		assert_eq!(Members::<T>::get(identifier, &old_key), Some(RingPosition::Suspended));
+		assert_eq!(Members::<T>::get(identifier, &replaced_key), None);
+		assert!(matches!(
+			Members::<T>::get(identifier, &new_key),
+			Some(RingPosition::Onboarding { .. })
+		));
+
+		Ok(())
+	}
+
+	/// Benchmark for the `AsMember` transaction extension validation of a key rotation.
+	#[benchmark]
+	fn validate_rotate_member_key() -> Result<(), BenchmarkError> {
+		T::BenchmarkHelper::set_valid_time();
+
+		use crate::extension::{AsMember, AsMemberInfo};
+		use sp_runtime::traits::DispatchTransaction;
+
+		let identifier = BENCH_IDENTIFIER;
+		let ring_size: u32 = T::MaxFlexibleRingExponent::get().ring_capacity();
+
+		setup_collection::<T>(
+			identifier,
+			ring_size,
+			T::MaxFlexibleRingExponent::get(),
+			RingMode::Flexible,
+		);
+
+		let (secret, old_key) = new_member_from::<T>(0, SEED);
+		pallet::Pallet::<T>::add_members(&identifier, vec![old_key.clone()])
+			.expect("Failed to add member");
+		assert_ok!(pallet::Pallet::<T>::onboard_members(&identifier, false));
+		let (_, new_key) = new_member_from::<T>(0, SEED + 1);
+
+		// Sign the inherited implication with the old key, as in `validate_self_include`.
+		let call_valid_at = T::Clock::now().as_secs();
+		let call = Call::<T>::rotate_member_key { identifier, old_key, new_key, call_valid_at };
+		let runtime_call: <T as frame_system::Config>::RuntimeCall = call.into();
+		let msg = (0u8, &runtime_call).using_encoded(sp_io::hashing::blake2_256);
+		let signature = T::Crypto::sign(&secret, &msg[..]).expect("signing should not fail");
+
+		let tx_ext = AsMember::<T>::new(Some(AsMemberInfo::RotateKey(signature)));
+		let origin = frame_system::RawOrigin::None;
+		let len = runtime_call.encode().len();
+
+		#[block]
+		{
+			tx_ext
+				.test_run(origin.into(), &runtime_call, &Default::default(), len, 0, |_| {
+					Ok(Default::default())
+				})
+				.unwrap()?;
+		}
+
+		Ok(())
+	}
+
+	/// `release_alias` verifying a proof of a rotated-out key against a ring made of that key.
+	#[benchmark]
+	fn release_alias() -> Result<(), BenchmarkError> {
+		T::BenchmarkHelper::set_valid_time();
+
+		let identifier = BENCH_IDENTIFIER;
+		let ring_exponent = T::MaxFlexibleRingExponent::get();
+		let ring_size: u32 = ring_exponent.ring_capacity();
+
+		setup_collection::<T>(identifier, ring_size, ring_exponent, RingMode::Flexible);
+
+		let members = generate_members_for_ring::<T>(SEED, ring_size);
+		add_members_to_collection::<T>(&identifier, &members);
+		assert_ok!(pallet::Pallet::<T>::onboard_all_and_build_ring(&identifier, RI_ZERO));
+
+		let (secret, member) = &members[0];
+		let (new_secret, new_key) = new_member_from::<T>(0, SEED + ring_size);
+		let capacity: CapacityOf<T> =
+			ring_exponent.try_into().map_err(|_| BenchmarkError::Stop("invalid capacity"))?;
+		let commitment = T::Crypto::open(capacity, member, core::iter::once(member.clone()))
+			.map_err(|_| BenchmarkError::Stop("failed to open commitment"))?;
+		assert_ok!(pallet::Pallet::<T>::do_rotate_member_key(
+			&identifier,
+			member.clone(),
+			new_key.clone()
+		));
+
+		let context = [7u8; 32];
+		let msg = (RELEASE_ALIAS_MESSAGE, identifier, &new_key, 0u32).encode();
+		let (proof, alias) = T::Crypto::create(commitment, secret, &context[..], &msg[..])
+			.map_err(|_| BenchmarkError::Stop("failed to create proof"))?;
+		let signed_msg = (&msg, ContextualAlias { alias, context }).encode();
+		let signature = T::Crypto::sign(&new_secret, &signed_msg[..])
+			.map_err(|_| BenchmarkError::Stop("failed to sign"))?;
+
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(SystemOrigin::Signed(caller), identifier, new_key.clone(), context, proof, signature);
+
+		assert_eq!(
+			PendingAliasReleases::<T>::get(identifier, &new_key).map(|release| release.nonce),
+			Some(1)
+		);
+		Ok(())
+	}
+
+	/// `ensure_can_prune_rotated_key` with the release window of the rotated-out key over.
+	#[benchmark]
+	fn ensure_can_prune_rotated_key() -> Result<(), BenchmarkError> {
+		T::BenchmarkHelper::set_valid_time();
+
+		let identifier = BENCH_IDENTIFIER;
+		let (_, old_key) = new_member_from::<T>(0, SEED);
+		let (_, new_key) = new_member_from::<T>(0, SEED + 1);
+		PendingAliasReleases::<T>::insert(
+			identifier,
+			&new_key,
+			AliasRelease { old_key, rotated_at: 0, nonce: 0 },
+		);
+		let retention = T::OldRootRetentionDuration::get();
+		let current_time = T::Clock::now().as_secs();
+		T::BenchmarkHelper::set_time(Duration::from_secs(current_time + retention + 1));
+
+		let call = Call::<T>::prune_rotated_key_authorized { identifier, new_key };
+
+		#[block]
+		{
+			call.authorize(TransactionSource::Local).unwrap().unwrap();
+		}
+
+		Ok(())
+	}
+
+	/// `prune_rotated_key_authorized` removing a suspended rotated-out key.
+	#[benchmark]
+	fn prune_rotated_key_authorized() -> Result<(), BenchmarkError> {
+		T::BenchmarkHelper::set_valid_time();
+
+		let identifier = BENCH_IDENTIFIER;
+		let (_, old_key) = new_member_from::<T>(0, SEED);
+		let (_, new_key) = new_member_from::<T>(0, SEED + 1);
+		Members::<T>::insert(identifier, &old_key, RingPosition::Suspended);
+		PendingAliasReleases::<T>::insert(
+			identifier,
+			&new_key,
+			AliasRelease { old_key: old_key.clone(), rotated_at: 0, nonce: 0 },
+		);
+		let retention = T::OldRootRetentionDuration::get();
+		let current_time = T::Clock::now().as_secs();
+		T::BenchmarkHelper::set_time(Duration::from_secs(current_time + retention + 1));
+
+		#[extrinsic_call]
+		_(SystemOrigin::Authorized, identifier, new_key.clone());
+
+		assert!(!PendingAliasReleases::<T>::contains_key(identifier, &new_key));
+		assert_eq!(Members::<T>::get(identifier, &old_key), None);
+
+		Ok(())
+	}
+
+	/// `ensure_can_compact_rings` reading the state of both rings.
+	#[benchmark]
+	fn ensure_can_compact_rings() -> Result<(), BenchmarkError> {
+		T::BenchmarkHelper::set_valid_time();
+
+		let n = max_compaction_step::<T>();
+		let identifier = setup_ring_compaction::<T>(n)?;
+		let revision = Root::<T>::get(identifier, 1).map(|root| root.revision);
+
+		let call = Call::<T>::compact_rings_authorized {
+			identifier,
+			source_ring_index: 1,
+			target_ring_index: RI_ZERO,
+			count: n,
+			revision,
+		};
+
+		#[block]
+		{
+			call.authorize(TransactionSource::Local).unwrap().unwrap();
+		}
+
+		Ok(())
+	}
+
+	/// `compact_rings_authorized` moving `n` members out of a source ring which keeps a member
+	/// and therefore has its root reset.
+	#[benchmark]
+	fn compact_rings_authorized(
+		n: Linear<1, { max_compaction_step::<T>() }>,
+	) -> Result<(), BenchmarkError> {
+		T::BenchmarkHelper::set_valid_time();
+
+		let identifier = setup_ring_compaction::<T>(n)?;
+		let revision = Root::<T>::get(identifier, 1).map(|root| root.revision);
+
+		#[extrinsic_call]
+		_(SystemOrigin::Authorized, identifier, 1, RI_ZERO, n, revision);
+
+		let capacity = T::MaxFlexibleRingExponent::get().ring_capacity();
+		assert_eq!(RingKeysStatus::<T>::get(identifier, RI_ZERO).total, capacity);
+		assert_eq!(RingKeysStatus::<T>::get(identifier, 1).total, 1);
+		assert!(StaleRings::<T>::contains_key(identifier, 1));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn requeue_ring_members(
+		n: Linear<1, { T::MaxFlexibleRingExponent::get().ring_capacity() - 1 }>,
+	) -> Result<(), BenchmarkError> {
+		T::BenchmarkHelper::set_valid_time();
+
+		let identifier = setup_underpopulated_ring::<T>(n);
+
+		#[block]
+		{
+			assert_eq!(Pallet::<T>::requeue_underpopulated_ring(&identifier, RI_ZERO), n);
+		}
+
+		assert!(Root::<T>::get(identifier, RI_ZERO).is_none());
+		assert!(!RingKeysStatus::<T>::contains_key(identifier, RI_ZERO));
+		let (head, _) = QueuePageIndices::<T>::get(identifier);
+		assert!(!OnboardingQueue::<T>::get(identifier, head).is_empty());
+
+		Ok(())
+	}
+
+	/// `ensure_can_requeue_ring_members` with the ring below a raised minimum population.
+	#[benchmark]
+	fn ensure_can_requeue_ring_members() -> Result<(), BenchmarkError> {
+		T::BenchmarkHelper::set_valid_time();
+
+		let identifier = setup_underpopulated_ring::<T>(1);
+		let call = Call::<T>::requeue_ring_members_authorized { identifier, ring_index: RI_ZERO };
+
+		#[block]
+		{
+			call.authorize(TransactionSource::Local).unwrap().unwrap();
+		}
+
+		Ok(())
+	}
+
 	// Implements a test for each benchmark. Execute with:
 	// `cargo test -p indiv-pallet-members --features runtime-benchmarks`.
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
@@ -16,10 +16,12 @@
 
 //! Transaction extension for members.
 //!
-//! Provides a single variant:
+//! Provides two variants:
 //! - `SelfInclude(signature)`: Validates that the member has waited long enough in the onboarding
 //!   queue and authenticates the call by verifying the member's signature, ensuring this action is
 //!   an explicit user action.
+//! - `RotateKey(signature)`: Authenticates a key rotation by verifying the signature of the old
+//!   member key.
 
 use crate::*;
 use codec::{Decode, DecodeWithMemTracking, Encode};
@@ -52,6 +54,12 @@ pub enum CustomValidity {
 	NotOnboarding = 205,
 	/// The member has not waited long enough in the onboarding queue.
 	SelfInclusionTooEarly = 206,
+	/// The call is not a `rotate_member_key` call.
+	NotRotateKeyCall = 207,
+	/// Key rotation is not supported for this collection.
+	RotationNotSupported = 208,
+	/// The member key replaced another key too recently to be rotated out.
+	RotationTooSoon = 209,
 }
 
 impl From<CustomValidity> for TransactionValidityError {
@@ -69,9 +77,12 @@ pub enum AsMemberInfo<T: Config + Send + Sync> {
 	/// Self-include a member from the onboarding queue. The signature is over the
 	/// transaction's inherited implication, proving ownership of the member key.
 	SelfInclude(SignatureOf<T>),
+	/// Rotate a member key. The signature is over the transaction's inherited implication,
+	/// proving ownership of the old member key.
+	RotateKey(SignatureOf<T>),
 }
 
-/// Transaction extension for self-inclusion of queued members.
+/// Transaction extension for self-inclusion of queued members and member key rotation.
 #[derive(
 	Encode,
 	Decode,
@@ -95,43 +106,12 @@ impl<T: Config + Send + Sync> AsMember<T> {
 	pub fn new(info: Option<AsMemberInfo<T>>) -> Self {
 		Self(info)
 	}
-}
 
-impl<T: Config + Send + Sync> TransactionExtension<<T as frame_system::Config>::RuntimeCall>
-	for AsMember<T>
-{
-	const IDENTIFIER: &'static str = "AsMember";
-	type Implicit = ();
-	type Val = ();
-	type Pre = ();
-
-	fn weight(&self, _call: &<T as frame_system::Config>::RuntimeCall) -> Weight {
-		match &self.0 {
-			None => Weight::zero(),
-			Some(AsMemberInfo::SelfInclude(_)) => T::WeightInfo::validate_self_include(),
-		}
-	}
-
-	fn validate(
-		&self,
-		origin: <T as frame_system::Config>::RuntimeOrigin,
+	fn validate_self_include(
+		signature: &SignatureOf<T>,
 		call: &<T as frame_system::Config>::RuntimeCall,
-		_info: &DispatchInfoOf<<T as frame_system::Config>::RuntimeCall>,
-		_len: usize,
-		_self_implicit: Self::Implicit,
 		inherited_implication: &impl Implication,
-		_source: TransactionSource,
-	) -> ValidateResult<Self::Val, <T as frame_system::Config>::RuntimeCall> {
-		let Some(AsMemberInfo::SelfInclude(signature)) = &self.0 else {
-			return Ok((ValidTransaction::default(), (), origin));
-		};
-
-		// The origin must not have already been authorized by another extension.
-		ensure!(
-			matches!(origin.as_system_ref(), Some(frame_system::RawOrigin::None)),
-			InvalidTransaction::BadSigner
-		);
-
+	) -> Result<(ValidTransaction, pallet::Origin<T>), TransactionValidityError> {
 		// Extract call parameters.
 		let Some(Call::<T>::self_include { identifier, member, call_valid_at }) =
 			call.is_sub_type()
@@ -180,8 +160,98 @@ impl<T: Config + Send + Sync> TransactionExtension<<T as frame_system::Config>::
 		let valid_transaction =
 			ValidTransaction::with_tag_prefix("Members").and_provides(provides).build()?;
 
-		// Set the origin to SelfInclude with the authenticated member key.
-		let local_origin = pallet::Origin::<T>::SelfInclude(member.clone());
+		Ok((valid_transaction, pallet::Origin::<T>::SelfInclude(member.clone())))
+	}
+
+	fn validate_rotate_key(
+		signature: &SignatureOf<T>,
+		call: &<T as frame_system::Config>::RuntimeCall,
+		inherited_implication: &impl Implication,
+	) -> Result<(ValidTransaction, pallet::Origin<T>), TransactionValidityError> {
+		// Extract call parameters.
+		let Some(Call::<T>::rotate_member_key { identifier, old_key, call_valid_at, .. }) =
+			call.is_sub_type()
+		else {
+			return Err(CustomValidity::NotRotateKeyCall.into());
+		};
+
+		// Validate call_valid_at is within the time tolerance window.
+		let now = T::Clock::now().as_secs();
+		let window = Pallet::<T>::self_inclusion_time_tolerance();
+		if now < *call_valid_at {
+			return Err(InvalidTransaction::Future.into());
+		}
+		if now > call_valid_at.saturating_add(window) {
+			return Err(InvalidTransaction::Stale.into());
+		}
+
+		// Verify the collection supports key rotation, the old key is included in a ring and
+		// it did not replace another key too recently.
+		Pallet::<T>::ensure_can_rotate_member_key(identifier, old_key).map_err(|e| match e {
+			e if e == Error::<T>::CollectionNotFound.into() => CustomValidity::CollectionNotFound,
+			e if e == Error::<T>::RotationNotSupported.into() =>
+				CustomValidity::RotationNotSupported,
+			e if e == Error::<T>::RotationTooSoon.into() => CustomValidity::RotationTooSoon,
+			_ => CustomValidity::MemberNotFound,
+		})?;
+
+		// Verify the signature of the old key over the inherited implication.
+		let msg = inherited_implication.using_encoded(sp_io::hashing::blake2_256);
+		if !T::Crypto::verify_signature(signature, &msg[..], old_key) {
+			return Err(InvalidTransaction::BadProof.into());
+		}
+
+		let provides = sp_core::twox_64(&("rotate-key", &identifier, &old_key).encode()[..]);
+		let valid_transaction =
+			ValidTransaction::with_tag_prefix("Members").and_provides(provides).build()?;
+
+		Ok((valid_transaction, pallet::Origin::<T>::RotateKey(old_key.clone())))
+	}
+}
+
+impl<T: Config + Send + Sync> TransactionExtension<<T as frame_system::Config>::RuntimeCall>
+	for AsMember<T>
+{
+	const IDENTIFIER: &'static str = "AsMember";
+	type Implicit = ();
+	type Val = ();
+	type Pre = ();
+
+	fn weight(&self, _call: &<T as frame_system::Config>::RuntimeCall) -> Weight {
+		match &self.0 {
+			None => Weight::zero(),
+			Some(AsMemberInfo::SelfInclude(_)) => T::WeightInfo::validate_self_include(),
+			Some(AsMemberInfo::RotateKey(_)) => T::WeightInfo::validate_rotate_member_key(),
+		}
+	}
+
+	fn validate(
+		&self,
+		origin: <T as frame_system::Config>::RuntimeOrigin,
+		call: &<T as frame_system::Config>::RuntimeCall,
+		_info: &DispatchInfoOf<<T as frame_system::Config>::RuntimeCall>,
+		_len: usize,
+		_self_implicit: Self::Implicit,
+		inherited_implication: &impl Implication,
+		_source: TransactionSource,
+	) -> ValidateResult<Self::Val, <T as frame_system::Config>::RuntimeCall> {
+		let Some(info) = &self.0 else {
+			return Ok((ValidTransaction::default(), (), origin));
+		};
+
+		// The origin must not have already been authorized by another extension.
+		ensure!(
+			matches!(origin.as_system_ref(), Some(frame_system::RawOrigin::None)),
+			InvalidTransaction::BadSigner
+		);
+
+		let (valid_transaction, local_origin) = match info {
+			AsMemberInfo::SelfInclude(signature) =>
+				Self::validate_self_include(signature, call, inherited_implication)?,
+			AsMemberInfo::RotateKey(signature) =>
+				Self::validate_rotate_key(signature, call, inherited_implication)?,
+		};
+
 		let mut origin = origin;
 		origin.set_caller_from(local_origin);
 
@@ -47,14 +47,14 @@ use frame_support::{
 use frame_system::offchain::{CreateAuthorizedTransaction, SubmitTransaction};
 use indiv_pallet_chunks_manager::ChunksApi;
 use indiv_support::traits::{
-	AppendOnlyMembers, AppendOnlyMembersWeightInfo, Context, ContextualAlias, FlexibleMembers,
-	MembershipMultiProver, MembershipProver, OnRingRootChange, PageIndex, RevisedContextualAlias,
-	RingExponent, RingIndex, RingMembersState, RingRootOp,
+	AppendOnlyMembers, AppendOnlyMembersWeightInfo, CleanUpAlias, Context, ContextualAlias,
+	FlexibleMembers, MembershipMultiProver, MembershipProver, OnRingRootChange, PageIndex,
+	RevisedContextualAlias, RingExponent, RingIndex, RingMembersState, RingRootOp,
 };
 use scale_info::TypeInfo;
 use sp_runtime::{
 	traits::{Dispatchable, Zero},
-	ArithmeticError, SaturatedConversion, Saturating,
+	ArithmeticError, Percent, SaturatedConversion, Saturating,
 };
 use verifiable::{BatchProofItem, GenerateVerifiable};
 
@@ -80,6 +80,11 @@ pub mod pallet {
 	/// worker.
 	pub(crate) const OFFCHAIN_WORKER_OLD_ROOT_CLEANUP_LIMIT: u32 = 1000;
 
+	/// The message prefix signed by the ring proof and the new member key authorizing
+	/// [`Pallet::release_alias`]. The collection identifier, the new key and the release nonce
+	/// are appended to it.
+	pub const RELEASE_ALIAS_MESSAGE: &[u8] = b"indiv-members:release-alias";
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
@@ -153,6 +158,12 @@ pub mod pallet {
 		#[pallet::constant]
 		type RingBuildingMemberLimit: Get<u32>;
 
+		/// Occupancy below which a ring of a flexible collection is compacted into fuller rings
+		/// by the offchain worker. Members are moved in steps of at most `RingBuildingMemberLimit`
+		/// keys. Zero disables compaction.
+		#[pallet::constant]
+		type RingCompactionThreshold: Get<Percent>;
+
 		/// Duration in seconds that old ring roots are retained before they can be cleaned up.
 		/// This allows proofs generated with older roots to remain valid for a grace period.
 		#[pallet::constant]
@@ -161,6 +172,10 @@ pub mod pallet {
 		/// Callback invoked when a ring root is inserted or updated.
 		type OnRingRootChange: OnRingRootChange<MembersOf<Self>>;
 
+		/// Hook used to release contextual aliases derived from a member key which was rotated
+		/// out, so that the new key can claim them again.
+		type CleanUpAlias: CleanUpAlias;
+
 		/// The number of blocks between offchain worker executions.
 		#[pallet::constant]
 		type OffchainWorkerInterval: Get<BlockNumberFor<Self>>;
@@ -226,6 +241,12 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type OnboardingSize<T: Config> = StorageMap<_, Identity, Identifier, u32, ValueQuery>;
 
+	/// Minimum number of keys which must be included in a ring root before membership proofs are
+	/// accepted against it, so that a ring is never provable with a small anonymity set. Zero
+	/// means no minimum.
+	#[pallet::storage]
+	pub type MinRingPopulation<T: Config> = StorageMap<_, Identity, Identifier, u32, ValueQuery>;
+
 	/// Both the keys that are included in built rings and the keys that will be used in future
 	/// rings. Paginated by (Identifier, RingIndex, PageIndex) where each page contains up to
 	/// `RingCapacityFromExponent` keys. The page size equals the flexible ring exponent's capacity,
@@ -283,6 +304,21 @@ pub mod pallet {
 	pub type Members<T: Config> =
 		StorageDoubleMap<_, Identity, Identifier, Blake2_128Concat, MemberOf<T>, RingPosition>;
 
+	/// Aliases of rotated-out member keys which can still be released, keyed by the new key.
+	///
+	/// Written by every key rotation and pruned together with the suspended old key once
+	/// `OldRootRetentionDuration` has passed since the rotation. Until then, the new key cannot be
+	/// rotated out.
+	#[pallet::storage]
+	pub type PendingAliasReleases<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		Identifier,
+		Blake2_128Concat,
+		MemberOf<T>,
+		AliasRelease<MemberOf<T>>,
+	>;
+
 	/// The current state of all rings managed for a given identifier.
 	#[pallet::storage]
 	pub type RingsState<T> = StorageMap<_, Identity, Identifier, RingMembersState, ValueQuery>;
@@ -347,10 +383,30 @@ pub mod pallet {
 		},
 		/// The onboarding size was set for a collection.
 		OnboardingSizeSet { identifier: Identifier, onboarding_size: u32 },
+		/// The minimum ring population was set for a collection.
+		MinRingPopulationSet { identifier: Identifier, min_ring_population: u32 },
 		/// A member self-included into a ring.
 		MemberSelfIncluded { identifier: Identifier, key: MemberOf<T> },
 		/// An old root revision has been cleaned up.
 		OldRootCleanedUp { identifier: Identifier, ring_index: RingIndex, revision: u32 },
+		/// A member key was rotated. The old key is suspended and the new one is queued for
+		/// onboarding.
+		MemberKeyRotated { identifier: Identifier, old_key: MemberOf<T>, new_key: MemberOf<T> },
+		/// A contextual alias derived from a member key was released.
+		AliasReleased { identifier: Identifier, ca: ContextualAlias },
+		/// Members of a sparse ring were moved into a fuller ring.
+		RingCompacted {
+			identifier: Identifier,
+			source_ring_index: RingIndex,
+			target_ring_index: RingIndex,
+			moved: u32,
+		},
+		/// The members of a ring left below the minimum ring population were moved back to the
+		/// onboarding queue and the ring was removed.
+		RingMembersRequeued { identifier: Identifier, ring_index: RingIndex, count: u32 },
+		/// The alias release window of a rotated-out key is over. The old key was removed from
+		/// the collection.
+		RotatedKeyPruned { identifier: Identifier, old_key: MemberOf<T>, new_key: MemberOf<T> },
 	}
 
 	#[pallet::error]
@@ -390,6 +446,11 @@ pub mod pallet {
 		KeyAlreadySuspended,
 		/// The onboarding size must not exceed the maximum ring size.
 		InvalidOnboardingSize,
+		/// The minimum ring population must not exceed the maximum ring size nor the number of
+		/// members included in a single ring build.
+		InvalidMinRingPopulation,
+		/// The ring does not have enough members included in its root to accept proofs.
+		RingNotProvable,
 		/// The member key is not valid for the crypto.
 		InvalidMemberKey,
 		/// The collection does not exist.
@@ -412,6 +473,18 @@ pub mod pallet {
 		NotOnboarding,
 		/// There is no ring root to build.
 		NothingToBuild,
+		/// Key rotation is only supported for flexible collections.
+		RotationNotSupported,
+		/// The key does not replace a rotated-out key whose aliases can be released.
+		NoPendingAliasRelease,
+		/// The aliases of the rotated-out key can no longer be released.
+		AliasReleaseExpired,
+		/// The signature of the new member key is invalid.
+		InvalidSignature,
+		/// The key replaced another key too recently to be rotated out.
+		RotationTooSoon,
+		/// The ring is not left below the minimum ring population.
+		RingNotUnderpopulated,
 	}
 
 	/// Custom transaction validity errors for authorize closures.
@@ -444,6 +517,8 @@ pub mod pallet {
 		MemberAlias(Identifier, RevisedContextualAlias),
 		/// An unincluded member origin which can onboard themselves.
 		SelfInclude(MemberOf<T>),
+		/// A member origin authenticated by its current key, which can rotate it.
+		RotateKey(MemberOf<T>),
 	}
 
 	#[pallet::hooks]
@@ -563,6 +638,48 @@ pub mod pallet {
 
 			// 7. Clean up expired old roots.
 			Self::process_old_roots_cleanup();
+
+			// 8. Compact sparse rings.
+			for identifier in Collections::<T>::iter_keys() {
+				if let Some((source_ring_index, target_ring_index, count)) =
+					Self::should_compact_rings(&identifier)
+				{
+					let revision = Self::ring_revision(&identifier, source_ring_index);
+					let call = Call::compact_rings_authorized {
+						identifier,
+						source_ring_index,
+						target_ring_index,
+						count,
+						revision,
+					};
+					Self::submit_authorized_transaction(call, "Compact sparse rings");
+				}
+			}
+
+			// 9. Prune rotated-out keys whose aliases can no longer be released.
+			for (identifier, new_key, release) in PendingAliasReleases::<T>::iter() {
+				if Self::alias_release_expired(&release) {
+					let call = Call::prune_rotated_key_authorized { identifier, new_key };
+					Self::submit_authorized_transaction(call, "Prune rotated-out keys");
+				}
+			}
+
+			// 10. Requeue the members of rings left below a raised minimum population.
+			for identifier in Collections::<T>::iter_keys() {
+				if MinRingPopulation::<T>::get(identifier) == 0 ||
+					SuspendedCollections::<T>::contains_key(identifier)
+				{
+					continue;
+				}
+				for (ring_index, ring_status) in RingKeysStatus::<T>::iter_prefix(identifier) {
+					if Self::is_ring_underpopulated(&identifier, ring_index, &ring_status) &&
+						!PendingSuspensions::<T>::contains_key(identifier, ring_index)
+					{
+						let call = Call::requeue_ring_members_authorized { identifier, ring_index };
+						Self::submit_authorized_transaction(call, "Requeue underpopulated rings");
+					}
+				}
+			}
 		}
 
 		#[cfg(feature = "std")]
@@ -723,6 +840,40 @@ pub mod pallet {
 			Ok(Pays::No.into())
 		}
 
+		/// Force set the minimum number of members a ring of a collection must have included in
+		/// its root before membership proofs are accepted against it. This call requires root
+		/// privileges.
+		///
+		/// Members are held back in the onboarding queue and rings are not built until the
+		/// minimum can be met. Members of rings other than the current one which are left below a
+		/// raised minimum are requeued by the offchain worker.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::set_min_ring_population())]
+		pub fn set_min_ring_population(
+			origin: OriginFor<T>,
+			identifier: Identifier,
+			min_ring_population: u32,
+		) -> DispatchResultWithPostInfo {
+			T::ManagerOrigin::ensure_origin_or_root(origin)?;
+			let collection =
+				Collections::<T>::get(identifier).ok_or(Error::<T>::CollectionNotFound)?;
+			// Rings are built from scratch in steps of `RingBuildingMemberLimit` keys, so a higher
+			// minimum would publish roots below it.
+			ensure!(
+				min_ring_population <= collection.ring_size.ring_capacity() &&
+					min_ring_population <= T::RingBuildingMemberLimit::get(),
+				Error::<T>::InvalidMinRingPopulation
+			);
+			MinRingPopulation::<T>::insert(identifier, min_ring_population);
+
+			Self::deposit_event(Event::<T>::MinRingPopulationSet {
+				identifier,
+				min_ring_population,
+			});
+
+			Ok(Pays::No.into())
+		}
+
 		/// Allow a member waiting in the onboarding queue to include themselves into a ring
 		/// after enough time has passed. This bypasses the normal cohort-based onboarding size
 		/// requirement.
@@ -757,6 +908,90 @@ pub mod pallet {
 			Ok(Pays::No.into())
 		}
 
+		/// Rotate the key of a member, suspending the old key and queueing the new one for
+		/// onboarding. Only supported for flexible collections.
+		///
+		/// This call must be dispatched with a `RotateKey` origin, authenticated by the
+		/// `AsMember` transaction extension through a signature of the old key. Since the old key
+		/// needs to be suspended, it is disclosed and linked to the new one by this operation.
+		///
+		/// Only included keys can be rotated out, and a key can only be rotated out once the alias
+		/// release window of the key it replaced is over. Aliases derived from the old key remain
+		/// valid for as long as the ring revisions that include it are accepted and can be
+		/// released by the new key through [`Self::release_alias`] during that window.
+		///
+		/// The `call_valid_at` parameter dictates the time window in which this transaction is
+		/// valid and represents the timestamp (in seconds since the UNIX epoch) when this call
+		/// becomes valid.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::rotate_member_key())]
+		pub fn rotate_member_key(
+			origin: OriginFor<T>,
+			identifier: Identifier,
+			old_key: MemberOf<T>,
+			new_key: MemberOf<T>,
+			_call_valid_at: u64,
+		) -> DispatchResultWithPostInfo {
+			let origin_member = match origin.into_caller().try_into() {
+				Ok(Origin::<T>::RotateKey(m)) => m,
+				_ => return Err(frame_support::error::BadOrigin.into()),
+			};
+			ensure!(origin_member == old_key, Error::<T>::NotMember);
+
+			Self::do_rotate_member_key(&identifier, old_key, new_key)?;
+
+			Ok(Pays::No.into())
+		}
+
+		/// Release a contextual alias of a rotated-out key through the `CleanUpAlias` hook, so
+		/// that `new_key` can claim it again.
+		///
+		/// Only possible after a rotation to `new_key`, until `OldRootRetentionDuration` has passed
+		/// since the rotation. The alias is authenticated by a proof against a ring made of the
+		/// old key alone, so that no other member can release it, over [`RELEASE_ALIAS_MESSAGE`]
+		/// followed by the collection identifier, `new_key` and the rotation's release nonce.
+		/// `signature` is the signature of `new_key` over the same message followed by the
+		/// released alias.
+		///
+		/// The nonce is incremented by every release, so that a proof cannot be replayed.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::release_alias())]
+		pub fn release_alias(
+			origin: OriginFor<T>,
+			identifier: Identifier,
+			new_key: MemberOf<T>,
+			context: Context,
+			proof: <T::Crypto as GenerateVerifiable>::Proof,
+			signature: SignatureOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let _ = ensure_signed(origin)?;
+			let mut release = PendingAliasReleases::<T>::get(identifier, &new_key)
+				.ok_or(Error::<T>::NoPendingAliasRelease)?;
+			ensure!(!Self::alias_release_expired(&release), Error::<T>::AliasReleaseExpired);
+
+			let msg = (RELEASE_ALIAS_MESSAGE, identifier, &new_key, release.nonce).encode();
+			let ca = Self::verify_single_member(
+				&identifier,
+				&proof,
+				&release.old_key,
+				context,
+				&msg[..],
+			)?;
+			let signed_msg = (&msg, &ca).encode();
+			ensure!(
+				T::Crypto::verify_signature(&signature, &signed_msg[..], &new_key),
+				Error::<T>::InvalidSignature
+			);
+
+			release.nonce.saturating_inc();
+			PendingAliasReleases::<T>::insert(identifier, &new_key, release);
+			T::CleanUpAlias::clean_up_alias(ca.clone())?;
+
+			Self::deposit_event(Event::<T>::AliasReleased { identifier, ca });
+
+			Ok(().into())
+		}
+
 		/// Build a ring root for a specific ring in a collection.
 		///
 		/// Submitted by the OCW with a `to_include` snapshot from
@@ -878,7 +1113,12 @@ pub mod pallet {
 			Self::ensure_can_remove_suspended_keys(identifier, *ring_index, *revision)
 		})]
 		#[pallet::call_index(103)]
-		#[pallet::weight(T::WeightInfo::remove_suspended_keys(Pallet::<T>::flexible_ring_capacity()))]
+		#[pallet::weight(
+			T::WeightInfo::remove_suspended_keys(Pallet::<T>::flexible_ring_capacity())
+				.saturating_add(T::WeightInfo::requeue_ring_members(
+					Pallet::<T>::flexible_ring_capacity()
+				))
+		)]
 		#[pallet::weight_of_authorize(T::WeightInfo::ensure_can_remove_suspended_keys())]
 		pub fn remove_suspended_keys_authorized(
 			origin: OriginFor<T>,
@@ -1091,6 +1331,116 @@ pub mod pallet {
 
 			Ok((Some(T::WeightInfo::clean_up_old_roots_authorized(removed)), Pays::No).into())
 		}
+
+		/// Move `count` members from the end of a sparse ring into a fuller ring of the same
+		/// flexible collection.
+		///
+		/// The root of the source ring is archived and rebuilt from scratch under a new revision,
+		/// or deleted if the ring is left empty. The moved members are included in the target
+		/// ring through regular ring building.
+		///
+		/// Submitted by the offchain worker.
+		#[pallet::authorize(|source, identifier, source_ring_index, target_ring_index, count, revision| {
+			if !matches!(source, TransactionSource::InBlock | TransactionSource::Local) {
+				return Err(AuthorizeInvalidity::TransactionNotLocal.into());
+			}
+
+			Self::ensure_can_compact_rings(
+				identifier,
+				*source_ring_index,
+				*target_ring_index,
+				*count,
+				*revision,
+			)
+		})]
+		#[pallet::call_index(110)]
+		#[pallet::weight(
+			T::WeightInfo::compact_rings_authorized(*count).saturating_add(
+				T::WeightInfo::requeue_ring_members(Pallet::<T>::flexible_ring_capacity())
+			)
+		)]
+		#[pallet::weight_of_authorize(T::WeightInfo::ensure_can_compact_rings())]
+		pub fn compact_rings_authorized(
+			origin: OriginFor<T>,
+			identifier: Identifier,
+			source_ring_index: RingIndex,
+			target_ring_index: RingIndex,
+			count: u32,
+			_revision: Option<RevisionIndex>,
+		) -> DispatchResultWithPostInfo {
+			ensure_authorized(origin)?;
+
+			Self::compact_rings(&identifier, source_ring_index, target_ring_index, count)?;
+
+			Ok(Pays::No.into())
+		}
+
+		/// Remove a rotated-out key from its collection once the release window of its aliases is
+		/// over, dropping the pending release recorded under `new_key`.
+		///
+		/// The transaction source must be `Local` or `InBlock`.
+		///
+		/// This is a maintenance call. Submitted by the offchain worker.
+		#[pallet::authorize(|source, identifier, new_key| {
+			if !matches!(source, TransactionSource::InBlock | TransactionSource::Local) {
+				return Err(AuthorizeInvalidity::TransactionNotLocal.into());
+			}
+
+			Self::ensure_can_prune_rotated_key(identifier, new_key)
+		})]
+		#[pallet::call_index(111)]
+		#[pallet::weight(T::WeightInfo::prune_rotated_key_authorized())]
+		#[pallet::weight_of_authorize(T::WeightInfo::ensure_can_prune_rotated_key())]
+		pub fn prune_rotated_key_authorized(
+			origin: OriginFor<T>,
+			identifier: Identifier,
+			new_key: MemberOf<T>,
+		) -> DispatchResultWithPostInfo {
+			ensure_authorized(origin)?;
+
+			let release = PendingAliasReleases::<T>::get(identifier, &new_key)
+				.ok_or(Error::<T>::NoPendingAliasRelease)?;
+			ensure!(Self::alias_release_expired(&release), Error::<T>::RotationTooSoon);
+			Self::prune_rotated_key(&identifier, new_key, release);
+
+			Ok(Pays::No.into())
+		}
+
+		/// Move the members of a ring left below the minimum ring population of its collection
+		/// back to the onboarding queue, and remove the ring along with its root.
+		///
+		/// Rings are left below the minimum when it is raised through `set_min_ring_population`.
+		///
+		/// The transaction source must be `Local` or `InBlock`.
+		///
+		/// This is a maintenance call. Submitted by the offchain worker.
+		#[pallet::authorize(|source, identifier, ring_index| {
+			if !matches!(source, TransactionSource::InBlock | TransactionSource::Local) {
+				return Err(AuthorizeInvalidity::TransactionNotLocal.into());
+			}
+
+			Self::ensure_can_requeue_ring_members(identifier, *ring_index)
+		})]
+		#[pallet::call_index(112)]
+		#[pallet::weight(T::WeightInfo::requeue_ring_members(Pallet::<T>::flexible_ring_capacity()))]
+		#[pallet::weight_of_authorize(T::WeightInfo::ensure_can_requeue_ring_members())]
+		pub fn requeue_ring_members_authorized(
+			origin: OriginFor<T>,
+			identifier: Identifier,
+			ring_index: RingIndex,
+		) -> DispatchResultWithPostInfo {
+			ensure_authorized(origin)?;
+			ensure!(
+				!PendingSuspensions::<T>::contains_key(identifier, ring_index),
+				Error::<T>::RingNotUnderpopulated
+			);
+
+			let requeued = Self::requeue_underpopulated_ring(&identifier, ring_index);
+			ensure!(requeued > 0, Error::<T>::RingNotUnderpopulated);
+
+			let actual_weight = T::WeightInfo::requeue_ring_members(requeued);
+			Ok((Some(actual_weight), Pays::No).into())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -1267,6 +1617,24 @@ pub mod pallet {
 					Self::finalize_collection_deletion(identifier);
 				}
 			}
+
+			// 8. Compact sparse rings.
+			let collections: Vec<_> = Collections::<T>::iter_keys().collect();
+			for identifier in collections {
+				if let Some((source_ring_index, target_ring_index, count)) =
+					Self::should_compact_rings(&identifier)
+				{
+					with_storage_layer(|| {
+						Self::compact_rings(
+							&identifier,
+							source_ring_index,
+							target_ring_index,
+							count,
+						)
+					})
+					.ok();
+				}
+			}
 		}
 
 		/// If the conditions to build a ring are met, this function returns the number of members
@@ -1296,6 +1664,12 @@ pub mod pallet {
 			if to_include == 0 {
 				return None;
 			}
+			// Roots are not published for rings below the minimum population.
+			if ring_status.included.saturating_add(to_include) <
+				MinRingPopulation::<T>::get(identifier)
+			{
+				return None;
+			}
 
 			Some(to_include)
 		}
@@ -1334,6 +1708,13 @@ pub mod pallet {
 				return None;
 			}
 
+			// Members are batched in the queue until the ring can reach its minimum population.
+			if ring_status.total.saturating_add(to_include) <
+				MinRingPopulation::<T>::get(identifier)
+			{
+				return None;
+			}
+
 			// Here we check we have enough items in the queue so that the onboarding group size is
 			// respected, but also that we can support another queue of at least onboarding size
 			// in a future call.
@@ -1733,52 +2114,150 @@ pub mod pallet {
 		) -> DispatchResult {
 			ensure!(RingsState::<T>::get(identifier).mutating(), Error::<T>::NoRemovalSession);
 			for key in suspensions {
-				let position =
-					Members::<T>::get(identifier, key).ok_or(Error::<T>::InvalidSuspensions)?;
-				match position {
-					RingPosition::Included { ring_index, ring_position, .. } => {
-						let mut suspended_indices =
-							PendingSuspensions::<T>::get(identifier, ring_index);
-						let Err(insert_idx) = suspended_indices.binary_search(&ring_position)
-						else {
-							return Err(Error::<T>::KeyAlreadySuspended.into())
-						};
-						suspended_indices
-							.try_insert(insert_idx, ring_position)
-							.defensive()
-							.map_err(|_| Error::<T>::TooManyMembers)?;
-						PendingSuspensions::<T>::insert(identifier, ring_index, suspended_indices);
-					},
-					RingPosition::Onboarding { queue_page, .. } => {
-						let mut keys = OnboardingQueue::<T>::get(identifier, queue_page);
-						let queue_idx = keys.iter().position(|k| *k == *key);
-						if let Some(idx) = queue_idx {
-							// It is expensive to shift the whole vec in the worst case to remove a
-							// suspended member from onboarding, but the pages will be small and
-							// suspension of members who are not yet onboarded is supposed to be
-							// extremely rare if not impossible as the pallet hooks should have
-							// plenty of time to include someone recognized before the beginning of
-							// the next suspension round. The only legitimate case when this could
-							// happen is if someone is sitting in the onboarding queue for a long
-							// time and cannot be included because not enough members are joining,
-							// but it should be a rare case.
-							keys.remove(idx);
-							OnboardingQueue::<T>::insert(identifier, queue_page, keys);
-						} else {
-							defensive!("No entry found at the position in the record of {}", key);
-						}
-					},
-					RingPosition::Suspended => {
-						defensive!("Suspension queued for member {} while already suspended", key);
-					},
-				}
+				Self::suspend_member(identifier, key)?;
+			}
 
-				Members::<T>::insert(identifier, key, RingPosition::Suspended);
+			Ok(())
+		}
+
+		// Mark a member key as suspended, registering its ring position in the pending
+		// suspensions or dropping it from the onboarding queue.
+		fn suspend_member(identifier: &Identifier, key: &MemberOf<T>) -> DispatchResult {
+			let position =
+				Members::<T>::get(identifier, key).ok_or(Error::<T>::InvalidSuspensions)?;
+			match position {
+				RingPosition::Included { ring_index, ring_position, .. } => {
+					let mut suspended_indices =
+						PendingSuspensions::<T>::get(identifier, ring_index);
+					let Err(insert_idx) = suspended_indices.binary_search(&ring_position) else {
+						return Err(Error::<T>::KeyAlreadySuspended.into());
+					};
+					suspended_indices
+						.try_insert(insert_idx, ring_position)
+						.defensive()
+						.map_err(|_| Error::<T>::TooManyMembers)?;
+					PendingSuspensions::<T>::insert(identifier, ring_index, suspended_indices);
+				},
+				RingPosition::Onboarding { queue_page, .. } => {
+					let mut keys = OnboardingQueue::<T>::get(identifier, queue_page);
+					let queue_idx = keys.iter().position(|k| *k == *key);
+					if let Some(idx) = queue_idx {
+						// It is expensive to shift the whole vec in the worst case to remove a
+						// suspended member from onboarding, but the pages will be small and
+						// suspension of members who are not yet onboarded is supposed to be
+						// extremely rare if not impossible as the pallet hooks should have
+						// plenty of time to include someone recognized before the beginning of
+						// the next suspension round. The only legitimate case when this could
+						// happen is if someone is sitting in the onboarding queue for a long
+						// time and cannot be included because not enough members are joining,
+						// but it should be a rare case.
+						keys.remove(idx);
+						OnboardingQueue::<T>::insert(identifier, queue_page, keys);
+					} else {
+						defensive!("No entry found at the position in the record of {}", key);
+					}
+				},
+				RingPosition::Suspended => {
+					defensive!("Suspension queued for member {} while already suspended", key);
+				},
 			}
 
+			Members::<T>::insert(identifier, key, RingPosition::Suspended);
 			Ok(())
 		}
 
+		/// Ensure that `old_key` can be rotated out of a collection: the collection must be
+		/// flexible, the key must be included in a ring and the alias release window of the key it
+		/// replaced, if any, must be over.
+		pub(crate) fn ensure_can_rotate_member_key(
+			identifier: &Identifier,
+			old_key: &MemberOf<T>,
+		) -> DispatchResult {
+			let collection_info =
+				Collections::<T>::get(identifier).ok_or(Error::<T>::CollectionNotFound)?;
+			ensure!(collection_info.mode == RingMode::Flexible, Error::<T>::RotationNotSupported);
+			ensure!(
+				matches!(
+					Members::<T>::get(identifier, old_key),
+					Some(RingPosition::Included { .. })
+				),
+				Error::<T>::NotMember
+			);
+			if let Some(release) = PendingAliasReleases::<T>::get(identifier, old_key) {
+				ensure!(Self::alias_release_expired(&release), Error::<T>::RotationTooSoon);
+			}
+			Ok(())
+		}
+
+		/// Suspend `old_key` and queue `new_key` for onboarding in a flexible collection.
+		///
+		/// The suspension of the old key does not require a removal session, it is processed as
+		/// soon as the rings are back in append-only mode.
+		pub(crate) fn do_rotate_member_key(
+			identifier: &Identifier,
+			old_key: MemberOf<T>,
+			new_key: MemberOf<T>,
+		) -> DispatchResult {
+			Self::ensure_can_rotate_member_key(identifier, &old_key)?;
+			ensure!(old_key != new_key, Error::<T>::KeyAlreadyInUse);
+			match Members::<T>::get(identifier, &new_key) {
+				None => ensure!(T::Crypto::is_member_valid(&new_key), Error::<T>::InvalidMemberKey),
+				Some(position) if position.suspended() => (),
+				_ => return Err(Error::<T>::KeyAlreadyInUse.into()),
+			}
+
+			// The release window of the key replaced by the old key is over, so that key can go.
+			if let Some(release) = PendingAliasReleases::<T>::get(identifier, &old_key) {
+				Self::prune_rotated_key(identifier, old_key.clone(), release);
+			}
+			PendingAliasReleases::<T>::insert(
+				identifier,
+				&new_key,
+				AliasRelease {
+					old_key: old_key.clone(),
+					rotated_at: T::Clock::now().as_secs(),
+					nonce: 0,
+				},
+			);
+
+			Self::suspend_member(identifier, &old_key)?;
+			Self::push_to_onboarding_queue(*identifier, new_key.clone())?;
+
+			Self::deposit_event(Event::<T>::MemberKeyRotated {
+				identifier: *identifier,
+				old_key,
+				new_key,
+			});
+
+			Ok(())
+		}
+
+		/// Whether `OldRootRetentionDuration` has passed since the rotation recorded by `release`,
+		/// closing its alias release window.
+		pub(crate) fn alias_release_expired(release: &AliasRelease<MemberOf<T>>) -> bool {
+			T::Clock::now().as_secs() >=
+				release.rotated_at.saturating_add(T::OldRootRetentionDuration::get())
+		}
+
+		/// Drop the pending release recorded under `new_key` and remove the rotated-out key from
+		/// the collection, unless it was added back since the rotation.
+		pub(crate) fn prune_rotated_key(
+			identifier: &Identifier,
+			new_key: MemberOf<T>,
+			release: AliasRelease<MemberOf<T>>,
+		) {
+			PendingAliasReleases::<T>::remove(identifier, &new_key);
+			if Members::<T>::get(identifier, &release.old_key) == Some(RingPosition::Suspended) {
+				Members::<T>::remove(identifier, &release.old_key);
+			}
+
+			Self::deposit_event(Event::<T>::RotatedKeyPruned {
+				identifier: *identifier,
+				old_key: release.old_key,
+				new_key,
+			});
+		}
+
 		fn push_to_onboarding_queue(identifier: Identifier, key: MemberOf<T>) -> DispatchResult {
 			let (head, mut tail) = QueuePageIndices::<T>::get(identifier);
 			let mut keys = OnboardingQueue::<T>::get(identifier, tail);
@@ -1914,6 +2393,87 @@ pub mod pallet {
 				RingKeys::<T>::insert((*identifier, ring_index, 0u32), new_keys);
 			}
 
+			// Make sure to remove the entry from the map so that the pallet hooks don't iterate
+			// over it.
+			PendingSuspensions::<T>::remove(identifier, ring_index);
+
+			let requeued = Self::requeue_underpopulated_ring(identifier, ring_index);
+			if requeued == 0 {
+				Self::reset_root(identifier, ring_index, capacity);
+				if retained_keys_count > 0 {
+					StaleRings::<T>::insert(identifier, ring_index, ());
+				}
+			}
+
+			// Weight scales with number of retained keys
+			T::WeightInfo::remove_suspended_keys(retained_keys_count)
+				.saturating_add(T::WeightInfo::requeue_ring_members(requeued))
+		}
+
+		/// Move the members of a ring which is left below the collection's minimum ring
+		/// population back to the onboarding queue, and remove the ring along with its root.
+		///
+		/// Without this, the ring would never be rebuilt, as no root is built below the minimum
+		/// population, and only the current ring grows through onboarding. The current ring is
+		/// therefore left untouched.
+		///
+		/// Returns the number of requeued members.
+		pub(crate) fn requeue_underpopulated_ring(
+			identifier: &Identifier,
+			ring_index: RingIndex,
+		) -> u32 {
+			let ring_status = RingKeysStatus::<T>::get(identifier, ring_index);
+			if !Self::is_ring_underpopulated(identifier, ring_index, &ring_status) {
+				return 0;
+			}
+
+			let page_size = Self::flexible_ring_capacity();
+			let page_count = ring_status.total.div_ceil(page_size);
+			for page in 0..page_count {
+				for key in RingKeys::<T>::take((*identifier, ring_index, page)) {
+					if let Err(e) = Self::push_to_onboarding_queue(*identifier, key.clone()) {
+						defensive!("Failed to requeue member {:?}: {:?}", key, e);
+					}
+				}
+			}
+
+			if Self::archive_root(*identifier, ring_index) {
+				T::OnRingRootChange::on_ring_root_change(
+					*identifier,
+					ring_index,
+					RingRootOp::Deleted,
+				);
+			}
+			RingKeysStatus::<T>::remove(identifier, ring_index);
+			StaleRings::<T>::remove(identifier, ring_index);
+			ActiveMembers::<T>::mutate(identifier, |active| {
+				*active = active.saturating_sub(ring_status.total)
+			});
+
+			Self::deposit_event(Event::<T>::RingMembersRequeued {
+				identifier: *identifier,
+				ring_index,
+				count: ring_status.total,
+			});
+
+			ring_status.total
+		}
+
+		/// Whether a ring other than the current one holds members, but fewer than the
+		/// collection's minimum ring population.
+		pub(crate) fn is_ring_underpopulated(
+			identifier: &Identifier,
+			ring_index: RingIndex,
+			ring_status: &RingStatus,
+		) -> bool {
+			ring_status.total > 0 &&
+				ring_status.total < MinRingPopulation::<T>::get(identifier) &&
+				ring_index != CurrentRingIndex::<T>::get(identifier)
+		}
+
+		/// Archive the current root of a ring and replace it with the root of an empty ring under
+		/// the next revision, so that the ring is rebuilt from scratch.
+		fn reset_root(identifier: &Identifier, ring_index: RingIndex, capacity: CapacityOf<T>) {
 			Root::<T>::mutate(identifier, ring_index, |maybe_root| {
 				if let Some(root) = maybe_root {
 					// Store the old root for the retention period.
@@ -1930,16 +2490,177 @@ pub mod pallet {
 					root.revision = root.revision.saturating_add(1);
 				}
 			});
+		}
 
-			if retained_keys_count > 0 {
-				StaleRings::<T>::insert(identifier, ring_index, ());
+		/// Returns the number of members which can be moved from `source_ring_index` into
+		/// `target_ring_index` in a single compaction step, or `None` if the rings are not
+		/// eligible for compaction.
+		///
+		/// Compaction only happens in flexible collections whose rings are in append-only mode.
+		/// The source ring must be below the `RingCompactionThreshold` occupancy, while the target
+		/// ring must hold more members than the source and still have room. Neither ring may be
+		/// the current onboarding ring, which onboarding alone fills, nor have pending
+		/// suspensions.
+		pub(crate) fn compactable_count(
+			identifier: &Identifier,
+			source_ring_index: RingIndex,
+			target_ring_index: RingIndex,
+		) -> Option<u32> {
+			let collection_info = Collections::<T>::get(identifier)?;
+			let current_ring_index = CurrentRingIndex::<T>::get(identifier);
+			if collection_info.mode != RingMode::Flexible ||
+				!RingsState::<T>::get(identifier).append_only() ||
+				source_ring_index == target_ring_index ||
+				source_ring_index == current_ring_index ||
+				target_ring_index == current_ring_index
+			{
+				return None;
 			}
+			let capacity = collection_info.ring_size.ring_capacity();
+			let threshold = T::RingCompactionThreshold::get() * capacity;
 
-			// Make sure to remove the entry from the map so that the pallet hooks don't iterate
-			// over it.
-			PendingSuspensions::<T>::remove(identifier, ring_index);
-			// Weight scales with number of retained keys
-			T::WeightInfo::remove_suspended_keys(retained_keys_count)
+			let source_status = RingKeysStatus::<T>::get(identifier, source_ring_index);
+			let target_status = RingKeysStatus::<T>::get(identifier, target_ring_index);
+			if source_status.total == 0 ||
+				source_status.total >= threshold ||
+				target_status.total <= source_status.total ||
+				target_status.total >= capacity
+			{
+				return None;
+			}
+			if PendingSuspensions::<T>::decode_len(identifier, source_ring_index).unwrap_or(0) > 0 ||
+				PendingSuspensions::<T>::decode_len(identifier, target_ring_index).unwrap_or(0) > 0
+			{
+				return None;
+			}
+
+			let count = source_status
+				.total
+				.min(capacity.saturating_sub(target_status.total))
+				.min(T::RingBuildingMemberLimit::get());
+			(count > 0).then_some(count)
+		}
+
+		/// Find the next compaction step for a collection, if any.
+		///
+		/// The sparsest eligible ring is drained into the fullest ring which still has room, both
+		/// picked in a single pass over the rings of the collection. Returns the source ring, the
+		/// target ring and the number of members to move.
+		pub fn should_compact_rings(
+			identifier: &Identifier,
+		) -> Option<(RingIndex, RingIndex, u32)> {
+			if T::RingCompactionThreshold::get().is_zero() {
+				return None;
+			}
+			let collection_info = Collections::<T>::get(identifier)?;
+			if collection_info.mode != RingMode::Flexible ||
+				!RingsState::<T>::get(identifier).append_only()
+			{
+				return None;
+			}
+			let capacity = collection_info.ring_size.ring_capacity();
+			let threshold = T::RingCompactionThreshold::get() * capacity;
+			let current_ring_index = CurrentRingIndex::<T>::get(identifier);
+
+			let mut source: Option<(RingIndex, u32)> = None;
+			let mut target: Option<(RingIndex, u32)> = None;
+			for (ring_index, status) in RingKeysStatus::<T>::iter_prefix(identifier) {
+				if ring_index == current_ring_index ||
+					status.total == 0 ||
+					status.total >= capacity ||
+					PendingSuspensions::<T>::decode_len(identifier, ring_index).unwrap_or(0) > 0
+				{
+					continue;
+				}
+				if status.total < threshold && source.is_none_or(|(_, total)| status.total < total)
+				{
+					source = Some((ring_index, status.total));
+				}
+				if target.is_none_or(|(_, total)| status.total > total) {
+					target = Some((ring_index, status.total));
+				}
+			}
+			let ((source_ring_index, _), (target_ring_index, _)) = (source?, target?);
+			Self::compactable_count(identifier, source_ring_index, target_ring_index)
+				.map(|count| (source_ring_index, target_ring_index, count))
+		}
+
+		/// Move `count` members from the end of the source ring into the target ring.
+		///
+		/// The source ring is reset to be rebuilt under a new revision, or removed along with its
+		/// root if no members are left in it. Subscribers are notified of a removed root right
+		/// away, and of rebuilt roots through ring building.
+		pub(crate) fn compact_rings(
+			identifier: &Identifier,
+			source_ring_index: RingIndex,
+			target_ring_index: RingIndex,
+			count: u32,
+		) -> DispatchResult {
+			let max_count =
+				Self::compactable_count(identifier, source_ring_index, target_ring_index)
+					.ok_or(Error::<T>::InvalidRing)?;
+			ensure!(count > 0 && count <= max_count, Error::<T>::InvalidRing);
+			let collection_info =
+				Collections::<T>::get(identifier).ok_or(Error::<T>::CollectionNotFound)?;
+			let capacity: CapacityOf<T> = collection_info
+				.ring_size
+				.try_into()
+				.map_err(|_| Error::<T>::InvalidRingExponent)?;
+
+			// Flexible collections only use the first page of a ring.
+			let mut source_keys = RingKeys::<T>::get((*identifier, source_ring_index, 0u32));
+			let mut target_keys = RingKeys::<T>::get((*identifier, target_ring_index, 0u32));
+			let split_at = source_keys.len().saturating_sub(count as usize);
+			let moved_keys: Vec<_> = source_keys.drain(split_at..).collect();
+
+			for key in moved_keys {
+				let position = RingPosition::Included {
+					ring_index: target_ring_index,
+					ring_page: 0,
+					ring_position: target_keys.len().saturated_into(),
+				};
+				Members::<T>::insert(identifier, &key, position);
+				target_keys.try_push(key).map_err(|_| Error::<T>::TooManyMembers)?;
+			}
+
+			// The moved members are included in the target root by the next ring build.
+			let mut target_status = RingKeysStatus::<T>::get(identifier, target_ring_index);
+			target_status.total = target_keys.len().saturated_into();
+			RingKeys::<T>::insert((*identifier, target_ring_index, 0u32), target_keys);
+			RingKeysStatus::<T>::insert(identifier, target_ring_index, target_status);
+			StaleRings::<T>::insert(identifier, target_ring_index, ());
+
+			if source_keys.is_empty() {
+				if Self::archive_root(*identifier, source_ring_index) {
+					T::OnRingRootChange::on_ring_root_change(
+						*identifier,
+						source_ring_index,
+						RingRootOp::Deleted,
+					);
+				}
+				RingKeys::<T>::remove((*identifier, source_ring_index, 0u32));
+				RingKeysStatus::<T>::remove(identifier, source_ring_index);
+				StaleRings::<T>::remove(identifier, source_ring_index);
+			} else {
+				RingKeysStatus::<T>::mutate(identifier, source_ring_index, |status| {
+					status.total = source_keys.len().saturated_into();
+					status.included = 0;
+				});
+				RingKeys::<T>::insert((*identifier, source_ring_index, 0u32), source_keys);
+				if Self::requeue_underpopulated_ring(identifier, source_ring_index) == 0 {
+					Self::reset_root(identifier, source_ring_index, capacity);
+					StaleRings::<T>::insert(identifier, source_ring_index, ());
+				}
+			}
+
+			Self::deposit_event(Event::<T>::RingCompacted {
+				identifier: *identifier,
+				source_ring_index,
+				target_ring_index,
+				moved: count,
+			});
+
+			Ok(())
 		}
 
 		/// Merges the two pages at the front of the onboarding queue. After a round of suspensions,
@@ -2091,6 +2812,7 @@ pub mod pallet {
 			SuspendedCollections::<T>::remove(identifier);
 			CurrentRingIndex::<T>::remove(identifier);
 			OnboardingSize::<T>::remove(identifier);
+			MinRingPopulation::<T>::remove(identifier);
 			ActiveMembers::<T>::remove(identifier);
 			RingsState::<T>::remove(identifier);
 			QueuePageIndices::<T>::remove(identifier);
@@ -2174,6 +2896,10 @@ pub mod pallet {
 			let current_root = Root::<T>::get(identifier, ring_index);
 			if let Some(ref root) = current_root {
 				if root.revision == revision {
+					ensure!(
+						Self::meets_min_ring_population(identifier, ring_index),
+						Error::<T>::RingNotProvable
+					);
 					return Ok(root.root.clone());
 				}
 			}
@@ -2193,6 +2919,32 @@ pub mod pallet {
 			}
 		}
 
+		/// Returns the current root of a ring, provided that it meets the collection's minimum
+		/// ring population.
+		fn provable_root(
+			identifier: &Identifier,
+			ring_index: RingIndex,
+		) -> Result<RingRoot<T>, DispatchError> {
+			let ring = Root::<T>::get(identifier, ring_index).ok_or(Error::<T>::NoRoot)?;
+			ensure!(
+				Self::meets_min_ring_population(identifier, ring_index),
+				Error::<T>::RingNotProvable
+			);
+			Ok(ring)
+		}
+
+		/// Whether the current root of a ring includes enough members to meet the collection's
+		/// minimum ring population.
+		///
+		/// Older revisions are not checked, as no root is built below the minimum population.
+		pub(crate) fn meets_min_ring_population(
+			identifier: &Identifier,
+			ring_index: RingIndex,
+		) -> bool {
+			RingKeysStatus::<T>::get(identifier, ring_index).included >=
+				MinRingPopulation::<T>::get(identifier)
+		}
+
 		/// Returns the maximum number of pages any ring can have. Corresponds to the maximum number
 		/// of pages for rings with the greatest ring exponent.
 		pub(crate) fn ring_pages_absolute_upper_limit() -> u32 {
@@ -2525,6 +3277,37 @@ pub mod pallet {
 			Ok((validity, T::WeightInfo::ensure_can_mark_ring_stale()))
 		}
 
+		/// Validate that the rings are eligible for a compaction step moving `count` members and
+		/// that the source ring is still at the expected revision.
+		pub(crate) fn ensure_can_compact_rings(
+			identifier: &Identifier,
+			source_ring_index: RingIndex,
+			target_ring_index: RingIndex,
+			count: u32,
+			revision: Option<RevisionIndex>,
+		) -> Result<(ValidTransaction, Weight), TransactionValidityError> {
+			let max_count =
+				Self::compactable_count(identifier, source_ring_index, target_ring_index)
+					.ok_or(InvalidTransaction::Stale)?;
+			if count == 0 || count > max_count {
+				return Err(InvalidTransaction::Stale.into());
+			}
+
+			let current_revision = Self::ring_revision(identifier, source_ring_index);
+			if revision < current_revision {
+				return Err(InvalidTransaction::Stale.into());
+			} else if revision > current_revision {
+				return Err(InvalidTransaction::Future.into());
+			}
+
+			let validity = ValidTransaction::with_tag_prefix("members:compact-rings")
+				.and_provides((identifier, source_ring_index, revision))
+				.propagate(false)
+				.build()?;
+
+			Ok((validity, T::WeightInfo::ensure_can_compact_rings()))
+		}
+
 		/// Validate that at least one expired old root exists for the given identifier and ring.
 		pub(crate) fn ensure_can_clean_up_old_roots(
 			identifier: &Identifier,
@@ -2561,6 +3344,50 @@ pub mod pallet {
 
 			Ok((validity, T::WeightInfo::ensure_can_clean_up_old_roots()))
 		}
+
+		/// Validate that the alias release window of the key rotated out for `new_key` is over.
+		pub(crate) fn ensure_can_prune_rotated_key(
+			identifier: &Identifier,
+			new_key: &MemberOf<T>,
+		) -> Result<(ValidTransaction, Weight), TransactionValidityError> {
+			let release = PendingAliasReleases::<T>::get(identifier, new_key)
+				.ok_or(InvalidTransaction::Stale)?;
+			if !Self::alias_release_expired(&release) {
+				return Err(InvalidTransaction::Future.into());
+			}
+
+			let validity = ValidTransaction::with_tag_prefix("members:prune-rotated-key")
+				.and_provides((identifier, new_key))
+				.propagate(false)
+				.build()?;
+
+			Ok((validity, T::WeightInfo::ensure_can_prune_rotated_key()))
+		}
+
+		/// Validate that a ring is left below the minimum ring population of its collection and
+		/// has no pending suspensions, which requeue the ring themselves once removed.
+		pub(crate) fn ensure_can_requeue_ring_members(
+			identifier: &Identifier,
+			ring_index: RingIndex,
+		) -> Result<(ValidTransaction, Weight), TransactionValidityError> {
+			if SuspendedCollections::<T>::contains_key(identifier) {
+				return Err(InvalidTransaction::Stale.into());
+			}
+			if PendingSuspensions::<T>::contains_key(identifier, ring_index) {
+				return Err(InvalidTransaction::Stale.into());
+			}
+			let ring_status = RingKeysStatus::<T>::get(identifier, ring_index);
+			if !Self::is_ring_underpopulated(identifier, ring_index, &ring_status) {
+				return Err(InvalidTransaction::Stale.into());
+			}
+
+			let validity = ValidTransaction::with_tag_prefix("members:requeue-ring-members")
+				.and_provides((identifier, ring_index))
+				.propagate(false)
+				.build()?;
+
+			Ok((validity, T::WeightInfo::ensure_can_requeue_ring_members()))
+		}
 	}
 
 	impl<T: Config> MembershipProver for Pallet<T> {
@@ -2579,7 +3406,7 @@ pub mod pallet {
 				.ring_size
 				.try_into()
 				.map_err(|_| Error::<T>::InvalidRingExponent)?;
-			let ring = Root::<T>::get(identifier, ring_index).ok_or(Error::<T>::NoRoot)?;
+			let ring = Self::provable_root(identifier, ring_index)?;
 			let alias = T::Crypto::validate(capacity, proof, &ring.root, &context[..], msg)
 				.map_err(|_| Error::<T>::InvalidProof)?;
 			Ok(RevisedContextualAlias {
@@ -2620,6 +3447,33 @@ pub mod pallet {
 			Ok(ContextualAlias { alias, context })
 		}
 
+		/// Verify a membership proof against a ring made of `member` alone, proving that the
+		/// alias was derived from that key.
+		fn verify_single_member(
+			identifier: &Identifier,
+			proof: &<T::Crypto as GenerateVerifiable>::Proof,
+			member: &MemberOf<T>,
+			context: Context,
+			msg: &[u8],
+		) -> Result<ContextualAlias, DispatchError> {
+			let collection_info =
+				Collections::<T>::get(identifier).ok_or(Error::<T>::CollectionNotFound)?;
+			let ring_exponent = collection_info.ring_size;
+			let capacity: CapacityOf<T> =
+				ring_exponent.try_into().map_err(|_| Error::<T>::InvalidRingExponent)?;
+
+			let mut intermediate = T::Crypto::start_members(capacity);
+			T::Crypto::push_members(&mut intermediate, core::iter::once(member.clone()), |range| {
+				Self::fetch_chunks(ring_exponent, range)
+			})
+			.map_err(|_| Error::<T>::CouldNotPush)?;
+			let root = T::Crypto::finish_members(intermediate);
+
+			let alias = T::Crypto::validate(capacity, proof, &root, &context[..], msg)
+				.map_err(|_| Error::<T>::InvalidProof)?;
+			Ok(ContextualAlias { alias, context })
+		}
+
 		fn verify_memberships_in_ring(
 			identifier: &Identifier,
 			ring_index: RingIndex,
@@ -2631,7 +3485,7 @@ pub mod pallet {
 				.ring_size
 				.try_into()
 				.map_err(|_| Error::<T>::InvalidRingExponent)?;
-			let ring = Root::<T>::get(identifier, ring_index).ok_or(Error::<T>::NoRoot)?;
+			let ring = Self::provable_root(identifier, ring_index)?;
 
 			let aliases = T::Crypto::batch_validate(capacity, &ring.root, items)
 				.map_err(|_| Error::<T>::InvalidProof)?;
@@ -2703,7 +3557,7 @@ pub mod pallet {
 			// Check if the revision matches the current root
 			if let Some(ref root) = current_root {
 				if root.revision == revision {
-					return true;
+					return Self::meets_min_ring_population(identifier, ring_index);
 				}
 			}
 
@@ -2752,7 +3606,7 @@ pub mod pallet {
 				.ring_size
 				.try_into()
 				.map_err(|_| Error::<T>::InvalidRingExponent)?;
-			let ring = Root::<T>::get(identifier, ring_index).ok_or(Error::<T>::NoRoot)?;
+			let ring = Self::provable_root(identifier, ring_index)?;
 
 			let context_slices: Vec<&[u8]> = contexts.iter().map(|c| &c[..]).collect();
 			let aliases = T::Crypto::validate_multi_context(
@@ -2879,6 +3733,21 @@ pub mod pallet {
 			Ok(())
 		}
 
+		fn rotate_member(
+			owner: T::Location,
+			identifier: &Identifier,
+			old: MemberOf<T>,
+			new: MemberOf<T>,
+		) -> DispatchResult {
+			let collection_info =
+				Collections::<T>::get(identifier).ok_or(Error::<T>::CollectionNotFound)?;
+			ensure!(
+				collection_info.owner == CollectionOwner::External(owner),
+				Error::<T>::NotCollectionOwner
+			);
+			Self::do_rotate_member_key(identifier, old, new)
+		}
+
 		fn remove_ring(identifier: &Identifier, ring_index: RingIndex) -> DispatchResult {
 			ensure!(Collections::<T>::contains_key(identifier), Error::<T>::CollectionNotFound);
 			let current_ring = CurrentRingIndex::<T>::get(identifier);
@@ -2902,6 +3771,11 @@ pub mod pallet {
 			Some(RingKeysStatus::<T>::get(identifier, ring_index))
 		}
 
+		fn is_ring_provable(identifier: &Identifier, ring_index: RingIndex) -> bool {
+			Root::<T>::contains_key(identifier, ring_index) &&
+				Self::meets_min_ring_population(identifier, ring_index)
+		}
+
 		fn member_status(identifier: &Identifier, member: &MemberOf<T>) -> Option<RingPosition> {
 			Members::<T>::get(identifier, member)
 		}
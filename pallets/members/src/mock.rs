@@ -19,7 +19,7 @@ use alloc::sync::Arc;
 use codec::{Decode, Encode, MaxEncodedLen};
 use core::{cell::RefCell, ops::Range, time::Duration};
 use frame_support::{
-	derive_impl, parameter_types,
+	derive_impl, ensure, parameter_types,
 	traits::{OffchainWorker, UnixTime},
 	weights::RuntimeDbWeight,
 };
@@ -36,7 +36,7 @@ use sp_runtime::{
 	},
 	testing::UintAuthorityId,
 	traits::{BlakeTwo256, IdentityLookup},
-	BuildStorage, Weight,
+	BuildStorage, DispatchError, DispatchResult, Weight,
 };
 pub use verifiable::{mock::Mock, Alias, AliasVec, GenerateVerifiable};
 
@@ -303,6 +303,46 @@ impl crate::WeightInfo for MockWeights {
 	fn mark_ring_stale_authorized() -> Weight {
 		Weight::from_parts(29, 29)
 	}
+
+	fn rotate_member_key() -> Weight {
+		Weight::from_parts(30, 30)
+	}
+
+	fn validate_rotate_member_key() -> Weight {
+		Weight::from_parts(31, 31)
+	}
+
+	fn release_alias() -> Weight {
+		Weight::from_parts(32, 32)
+	}
+
+	fn ensure_can_compact_rings() -> Weight {
+		Weight::from_parts(33, 33)
+	}
+
+	fn compact_rings_authorized(n: u32) -> Weight {
+		Weight::from_parts(34 + n as u64 * 2, 34)
+	}
+
+	fn set_min_ring_population() -> Weight {
+		Weight::from_parts(35, 35)
+	}
+
+	fn ensure_can_prune_rotated_key() -> Weight {
+		Weight::from_parts(36, 36)
+	}
+
+	fn prune_rotated_key_authorized() -> Weight {
+		Weight::from_parts(37, 37)
+	}
+
+	fn requeue_ring_members(n: u32) -> Weight {
+		Weight::from_parts(38 + n as u64 * 2, 38)
+	}
+
+	fn ensure_can_requeue_ring_members() -> Weight {
+		Weight::from_parts(39, 39)
+	}
 }
 
 pub const INVALID_MEMBER: [u8; 32] = [
@@ -385,8 +425,37 @@ impl GenerateVerifiable for MockCrypto {
 	}
 }
 
+thread_local! {
+	static RELEASED_ALIASES: RefCell<Vec<ContextualAlias>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Records the aliases released through the `CleanUpAlias` hook.
+pub struct MockCleanUpAlias;
+impl CleanUpAlias for MockCleanUpAlias {
+	fn clean_up_alias(ca: ContextualAlias) -> DispatchResult {
+		RELEASED_ALIASES.with(|released| {
+			let mut released = released.borrow_mut();
+			ensure!(!released.contains(&ca), DispatchError::Other("alias already released"));
+			released.push(ca);
+			Ok(())
+		})
+	}
+}
+
+/// Aliases released through the `CleanUpAlias` hook so far.
+pub fn released_aliases() -> Vec<ContextualAlias> {
+	RELEASED_ALIASES.with(|released| released.borrow().clone())
+}
+
 parameter_types! {
 	pub const FlexibleRingExp: indiv_support::traits::RingExponent = indiv_support::traits::RingExponent::R2e9;
+	// Compaction is disabled in tests unless enabled explicitly, while benchmarks need sparse
+	// rings to be eligible for compaction.
+	pub static RingCompactionThreshold: Percent = if cfg!(feature = "runtime-benchmarks") {
+		Percent::from_percent(50)
+	} else {
+		Percent::zero()
+	};
 }
 
 impl crate::Config for Test {
@@ -403,8 +472,10 @@ impl crate::Config for Test {
 	type OnboardingQueuePageSize = ConstU32<255>;
 	type MaxFlexibleRingExponent = FlexibleRingExp;
 	type RingBuildingMemberLimit = ConstU32<100>;
+	type RingCompactionThreshold = RingCompactionThreshold;
 	type OldRootRetentionDuration = ConstU64<600>; // 10 minutes in seconds for old root retention.
 	type OnRingRootChange = ();
+	type CleanUpAlias = MockCleanUpAlias;
 	type OffchainWorkerInterval = ConstU64<1>;
 	type ManagerOrigin = frame_system::EnsureRoot<Self::AccountId>;
 	#[cfg(feature = "runtime-benchmarks")]
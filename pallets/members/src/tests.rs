@@ -5725,3 +5725,1140 @@ mod tag_collision_tests {
 		});
 	}
 }
+
+mod key_rotation_tests {
+	use super::*;
+	use crate::extension::{AsMember, AsMemberInfo, CustomValidity};
+	use sp_runtime::{
+		traits::DispatchTransaction, transaction_validity::TransactionSource, DispatchError,
+	};
+
+	/// Helper to build a `rotate_member_key` RuntimeCall signed by `secret`.
+	fn build_rotate_key_ext(
+		secret: &SecretOf<Test>,
+		identifier: Identifier,
+		old_key: MemberOf<Test>,
+		new_key: MemberOf<Test>,
+	) -> (RuntimeCall, AsMember<Test>) {
+		let call_valid_at = MockTime::now().as_secs();
+		let call: RuntimeCall =
+			crate::Call::<Test>::rotate_member_key { identifier, old_key, new_key, call_valid_at }
+				.into();
+		let msg = (0u8, &call).using_encoded(sp_io::hashing::blake2_256);
+		let signature = MockCrypto::sign(secret, &msg[..]).expect("signing works");
+		(call, AsMember::<Test>::new(Some(AsMemberInfo::RotateKey(signature))))
+	}
+
+	/// Proof of `member` against a ring made of it alone and signature of `new_key` authorizing
+	/// the release of the alias of `member` in `context`.
+	fn release_alias_proof(
+		identifier: Identifier,
+		(member, secret): (MemberOf<Test>, SecretOf<Test>),
+		(new_key, new_secret): (MemberOf<Test>, SecretOf<Test>),
+		nonce: u32,
+		context: Context,
+	) -> (<MockCrypto as GenerateVerifiable>::Proof, SignatureOf<Test>, ContextualAlias) {
+		let commitment = MockCrypto::open((), &member, core::iter::once(member)).unwrap();
+		let msg = (RELEASE_ALIAS_MESSAGE, identifier, new_key, nonce).encode();
+		let (proof, alias) = MockCrypto::create(commitment, &secret, &context, &msg).unwrap();
+		let ca = ContextualAlias { alias, context };
+		let signature = MockCrypto::sign(&new_secret, &(&msg, &ca).encode()[..]).unwrap();
+		(proof, signature, ca)
+	}
+
+	#[test]
+	fn rotate_member_key_through_extension_works() {
+		TestExt::new().execute_with(|| {
+			let identifier = TEST_IDENTIFIER;
+			let members = setup_collection_with_built_ring(identifier, 10);
+			let (old_key, old_secret) = members[0];
+			let new_key = MockCrypto::member_from_secret(&create_unique_secret());
+			let Some(RingPosition::Included { ring_position, .. }) =
+				Members::<Test>::get(identifier, old_key)
+			else {
+				panic!("member should be included");
+			};
+
+			let (call, ext) = build_rotate_key_ext(&old_secret, identifier, old_key, new_key);
+			let info = call.get_dispatch_info();
+			let len = call.encode().len();
+			assert_ok!(ext
+				.dispatch_transaction(RuntimeOrigin::none(), call, &info, len, 0)
+				.expect("extension validation should succeed"));
+
+			// The old key is suspended and its ring position is pending removal.
+			assert_eq!(Members::<Test>::get(identifier, old_key), Some(RingPosition::Suspended));
+			assert_eq!(
+				PendingSuspensions::<Test>::get(identifier, 0).into_inner(),
+				vec![ring_position]
+			);
+			// The new key is waiting in the onboarding queue.
+			assert!(matches!(
+				Members::<Test>::get(identifier, new_key),
+				Some(RingPosition::Onboarding { .. })
+			));
+			System::assert_has_event(
+				Event::<Test>::MemberKeyRotated { identifier, old_key, new_key }.into(),
+			);
+
+			// The suspension is processed without a removal session.
+			assert!(MembersPallet::should_remove_suspended_keys(&identifier, 0, true));
+			MembersPallet::remove_suspended_keys(&identifier, 0);
+			assert_eq!(RingKeysStatus::<Test>::get(identifier, 0).total, 9);
+			assert_eq!(ActiveMembers::<Test>::get(identifier), 9);
+		});
+	}
+
+	#[test]
+	fn rotate_member_key_extension_rejects_other_signers() {
+		TestExt::new().execute_with(|| {
+			let identifier = TEST_IDENTIFIER;
+			let members = setup_collection_with_built_ring(identifier, 10);
+			let (old_key, _) = members[0];
+			let new_secret = create_unique_secret();
+			let new_key = MockCrypto::member_from_secret(&new_secret);
+
+			// Signed by the new key instead of the old one.
+			let (call, ext) = build_rotate_key_ext(&new_secret, identifier, old_key, new_key);
+			let info = call.get_dispatch_info();
+			let len = call.encode().len();
+			assert_eq!(
+				ext.validate_only(
+					RuntimeOrigin::none(),
+					&call,
+					&info,
+					len,
+					TransactionSource::External,
+					0
+				)
+				.map(|_| ()),
+				Err(InvalidTransaction::BadProof.into())
+			);
+
+			// A suspended key can no longer be rotated.
+			let (suspended_key, suspended_secret) = members[1];
+			assert_ok!(MembersPallet::start_removal_session(&identifier));
+			assert_ok!(MembersPallet::remove_members(&identifier, &[suspended_key]));
+			assert_ok!(MembersPallet::end_removal_session(&identifier));
+			let (call, ext) =
+				build_rotate_key_ext(&suspended_secret, identifier, suspended_key, new_key);
+			let info = call.get_dispatch_info();
+			let len = call.encode().len();
+			assert_eq!(
+				ext.validate_only(
+					RuntimeOrigin::none(),
+					&call,
+					&info,
+					len,
+					TransactionSource::External,
+					0
+				)
+				.map(|_| ()),
+				Err(InvalidTransaction::Custom(CustomValidity::MemberNotFound as u8).into())
+			);
+		});
+	}
+
+	#[test]
+	fn rotate_member_key_requires_rotate_key_origin() {
+		TestExt::new().execute_with(|| {
+			let identifier = TEST_IDENTIFIER;
+			let members = setup_collection_with_built_ring(identifier, 10);
+			let (old_key, _) = members[0];
+			let (other_key, _) = members[1];
+			let new_key = MockCrypto::member_from_secret(&create_unique_secret());
+
+			assert_noop!(
+				MembersPallet::rotate_member_key(
+					RuntimeOrigin::signed(1),
+					identifier,
+					old_key,
+					new_key,
+					0
+				),
+				DispatchError::BadOrigin
+			);
+			assert_noop!(
+				MembersPallet::rotate_member_key(
+					Origin::<Test>::RotateKey(other_key).into(),
+					identifier,
+					old_key,
+					new_key,
+					0
+				),
+				Error::<Test>::NotMember
+			);
+		});
+	}
+
+	#[test]
+	fn rotate_member_by_collection_owner_works() {
+		TestExt::new().execute_with(|| {
+			let identifier = TEST_IDENTIFIER;
+			let members = setup_collection_with_built_ring(identifier, 10);
+			let (old_key, _) = members[0];
+			let new_key = MockCrypto::member_from_secret(&create_unique_secret());
+
+			assert_noop!(
+				<MembersPallet as AppendOnlyMembers>::rotate_member(
+					MockLocation(2),
+					&identifier,
+					old_key,
+					new_key
+				),
+				Error::<Test>::NotCollectionOwner
+			);
+			assert_ok!(<MembersPallet as AppendOnlyMembers>::rotate_member(
+				MockLocation(1),
+				&identifier,
+				old_key,
+				new_key
+			));
+			assert_eq!(Members::<Test>::get(identifier, old_key), Some(RingPosition::Suspended));
+			assert!(matches!(
+				Members::<Test>::get(identifier, new_key),
+				Some(RingPosition::Onboarding { .. })
+			));
+		});
+	}
+
+	#[test]
+	fn rotate_member_rejects_onboarding_keys() {
+		TestExt::new().execute_with(|| {
+			let identifier = TEST_IDENTIFIER;
+			create_test_collection(identifier, 200);
+			let members = generate_members(identifier, 1, 3);
+			let (old_key, old_secret) = members[1];
+			let new_key = MockCrypto::member_from_secret(&create_unique_secret());
+
+			assert_noop!(
+				MembersPallet::do_rotate_member_key(&identifier, old_key, new_key),
+				Error::<Test>::NotMember
+			);
+
+			let (call, ext) = build_rotate_key_ext(&old_secret, identifier, old_key, new_key);
+			let info = call.get_dispatch_info();
+			let len = call.encode().len();
+			assert_eq!(
+				ext.validate_only(
+					RuntimeOrigin::none(),
+					&call,
+					&info,
+					len,
+					TransactionSource::External,
+					0
+				)
+				.map(|_| ()),
+				Err(InvalidTransaction::Custom(CustomValidity::MemberNotFound as u8).into())
+			);
+		});
+	}
+
+	#[test]
+	fn rotated_in_key_cannot_be_rotated_out_before_release_window_is_over() {
+		TestExt::new().execute_with(|| {
+			let identifier = TEST_IDENTIFIER;
+			let members = setup_collection_with_built_ring(identifier, 10);
+			let (old_key, _) = members[0];
+			let new_secret = create_unique_secret();
+			let new_key = MockCrypto::member_from_secret(&new_secret);
+			let next_key = MockCrypto::member_from_secret(&create_unique_secret());
+
+			assert_ok!(MembersPallet::do_rotate_member_key(&identifier, old_key, new_key));
+			MembersPallet::remove_suspended_keys(&identifier, 0);
+			OnboardingSize::<Test>::insert(identifier, 1);
+			assert_ok!(MembersPallet::onboard_members(&identifier, true));
+			assert!(matches!(
+				Members::<Test>::get(identifier, new_key),
+				Some(RingPosition::Included { .. })
+			));
+
+			// The new key is included, but it replaced the old key too recently.
+			assert_noop!(
+				MembersPallet::do_rotate_member_key(&identifier, new_key, next_key),
+				Error::<Test>::RotationTooSoon
+			);
+			let (call, ext) = build_rotate_key_ext(&new_secret, identifier, new_key, next_key);
+			let info = call.get_dispatch_info();
+			let len = call.encode().len();
+			assert_eq!(
+				ext.validate_only(
+					RuntimeOrigin::none(),
+					&call,
+					&info,
+					len,
+					TransactionSource::External,
+					0
+				)
+				.map(|_| ()),
+				Err(InvalidTransaction::Custom(CustomValidity::RotationTooSoon as u8).into())
+			);
+
+			// Once the window is over, the rotation prunes the key replaced by the new key.
+			advance_time(601);
+			assert_ok!(MembersPallet::do_rotate_member_key(&identifier, new_key, next_key));
+			assert_eq!(Members::<Test>::get(identifier, old_key), None);
+			assert_eq!(
+				PendingAliasReleases::<Test>::get(identifier, next_key).map(|r| r.old_key),
+				Some(new_key)
+			);
+			assert!(!PendingAliasReleases::<Test>::contains_key(identifier, new_key));
+		});
+	}
+
+	#[test]
+	fn rotated_out_keys_are_pruned_after_release_window() {
+		TestExt::new().execute_with(|| {
+			let identifier = TEST_IDENTIFIER;
+			let members = setup_collection_with_built_ring(identifier, 10);
+			let (old_key, _) = members[0];
+			let new_key = MockCrypto::member_from_secret(&create_unique_secret());
+
+			assert_ok!(MembersPallet::do_rotate_member_key(&identifier, old_key, new_key));
+			assert_eq!(
+				MembersPallet::ensure_can_prune_rotated_key(&identifier, &new_key).map(|_| ()),
+				Err(InvalidTransaction::Future.into())
+			);
+			assert_noop!(
+				MembersPallet::prune_rotated_key_authorized(
+					RuntimeOrigin::signed(1),
+					identifier,
+					new_key
+				),
+				DispatchError::BadOrigin
+			);
+
+			advance_time(601);
+			assert_ok!(MembersPallet::ensure_can_prune_rotated_key(&identifier, &new_key));
+			assert_ok!(MembersPallet::prune_rotated_key_authorized(
+				RuntimeOrigin::from(frame_system::RawOrigin::Authorized),
+				identifier,
+				new_key
+			));
+			assert_eq!(Members::<Test>::get(identifier, old_key), None);
+			assert!(!PendingAliasReleases::<Test>::contains_key(identifier, new_key));
+			System::assert_has_event(
+				Event::<Test>::RotatedKeyPruned { identifier, old_key, new_key }.into(),
+			);
+			assert_eq!(
+				MembersPallet::ensure_can_prune_rotated_key(&identifier, &new_key).map(|_| ()),
+				Err(InvalidTransaction::Stale.into())
+			);
+		});
+	}
+
+	#[test]
+	fn rotate_member_rejects_invalid_rotations() {
+		TestExt::new().execute_with(|| {
+			let identifier = TEST_IDENTIFIER;
+			let members = setup_collection_with_built_ring(identifier, 10);
+			let (old_key, _) = members[0];
+			let (other_key, _) = members[1];
+			let new_key = MockCrypto::member_from_secret(&create_unique_secret());
+
+			// The new key belongs to another member.
+			assert_noop!(
+				MembersPallet::do_rotate_member_key(&identifier, old_key, other_key),
+				Error::<Test>::KeyAlreadyInUse
+			);
+			// The new key is the old key.
+			assert_noop!(
+				MembersPallet::do_rotate_member_key(&identifier, old_key, old_key),
+				Error::<Test>::KeyAlreadyInUse
+			);
+			// The new key is not a valid member key.
+			assert_noop!(
+				MembersPallet::do_rotate_member_key(&identifier, old_key, INVALID_MEMBER),
+				Error::<Test>::InvalidMemberKey
+			);
+			// The old key is unknown.
+			let unknown = MockCrypto::member_from_secret(&create_unique_secret());
+			assert_noop!(
+				MembersPallet::do_rotate_member_key(&identifier, unknown, new_key),
+				Error::<Test>::NotMember
+			);
+			// The old key cannot be rotated twice.
+			assert_ok!(MembersPallet::do_rotate_member_key(&identifier, old_key, new_key));
+			let another_key = MockCrypto::member_from_secret(&create_unique_secret());
+			assert_noop!(
+				MembersPallet::do_rotate_member_key(&identifier, old_key, another_key),
+				Error::<Test>::NotMember
+			);
+
+			// Append-only collections don't support rotation.
+			create_append_only_collection(TEST_IDENTIFIER_2, 200);
+			let members = generate_members(TEST_IDENTIFIER_2, 1, 2);
+			assert_noop!(
+				MembersPallet::do_rotate_member_key(&TEST_IDENTIFIER_2, members[0].0, another_key),
+				Error::<Test>::RotationNotSupported
+			);
+		});
+	}
+
+	#[test]
+	fn release_alias_of_rotated_key_works() {
+		TestExt::new().execute_with(|| {
+			let identifier = TEST_IDENTIFIER;
+			let members = setup_collection_with_built_ring(identifier, 10);
+			let (old_key, old_secret) = members[0];
+			let new_secret = create_unique_secret();
+			let new_key = MockCrypto::member_from_secret(&new_secret);
+
+			assert_ok!(MembersPallet::do_rotate_member_key(&identifier, old_key, new_key));
+			assert_eq!(
+				PendingAliasReleases::<Test>::get(identifier, new_key),
+				Some(AliasRelease { old_key, rotated_at: MockTime::now().as_secs(), nonce: 0 })
+			);
+			MembersPallet::remove_suspended_keys(&identifier, 0);
+
+			// Proof made by the old key, even after it was removed from its ring.
+			let context = [9u8; 32];
+			let (proof, signature, ca) = release_alias_proof(
+				identifier,
+				(old_key, old_secret.clone()),
+				(new_key, new_secret.clone()),
+				0,
+				context,
+			);
+			assert_ok!(MembersPallet::release_alias(
+				RuntimeOrigin::signed(1),
+				identifier,
+				new_key,
+				context,
+				proof.clone(),
+				signature.clone(),
+			));
+			assert_eq!(released_aliases(), vec![ca.clone()]);
+			System::assert_has_event(Event::<Test>::AliasReleased { identifier, ca }.into());
+
+			// The nonce was consumed, so the proof cannot be replayed.
+			assert_noop!(
+				MembersPallet::release_alias(
+					RuntimeOrigin::signed(1),
+					identifier,
+					new_key,
+					context,
+					proof,
+					signature,
+				),
+				Error::<Test>::InvalidProof
+			);
+
+			// Aliases in other contexts can still be released with the next nonce.
+			let context = [10u8; 32];
+			let (proof, signature, ca) = release_alias_proof(
+				identifier,
+				(old_key, old_secret),
+				(new_key, new_secret),
+				1,
+				context,
+			);
+			assert_ok!(MembersPallet::release_alias(
+				RuntimeOrigin::signed(1),
+				identifier,
+				new_key,
+				context,
+				proof,
+				signature,
+			));
+			assert_eq!(released_aliases().last(), Some(&ca));
+		});
+	}
+
+	#[test]
+	fn release_alias_is_only_possible_after_a_rotation() {
+		TestExt::new().execute_with(|| {
+			let identifier = TEST_IDENTIFIER;
+			let members = setup_collection_with_built_ring(identifier, 10);
+			let (member, secret) = members[0];
+			let (other_member, other_secret) = members[1];
+			let new_secret = create_unique_secret();
+			let new_key = MockCrypto::member_from_secret(&new_secret);
+			let context = [9u8; 32];
+
+			// Without a rotation, members cannot release their aliases.
+			let (proof, signature, _) = release_alias_proof(
+				identifier,
+				(member, secret.clone()),
+				(new_key, new_secret.clone()),
+				0,
+				context,
+			);
+			assert_noop!(
+				MembersPallet::release_alias(
+					RuntimeOrigin::signed(1),
+					identifier,
+					new_key,
+					context,
+					proof,
+					signature,
+				),
+				Error::<Test>::NoPendingAliasRelease
+			);
+
+			assert_ok!(MembersPallet::do_rotate_member_key(&identifier, member, new_key));
+
+			// The proof must be over the release message.
+			let commitment = MockCrypto::open((), &member, core::iter::once(member)).unwrap();
+			let (proof, alias) =
+				MockCrypto::create(commitment, &secret, &context, b"other message").unwrap();
+			let signature = MockCrypto::sign(
+				&new_secret,
+				&(b"other message", ContextualAlias { alias, context }).encode()[..],
+			)
+			.unwrap();
+			assert_noop!(
+				MembersPallet::release_alias(
+					RuntimeOrigin::signed(1),
+					identifier,
+					new_key,
+					context,
+					proof,
+					signature,
+				),
+				Error::<Test>::InvalidProof
+			);
+
+			// Another member of the ring cannot release their own aliases through the rotation,
+			// even with the signature of the new key.
+			let (proof, signature, _) = release_alias_proof(
+				identifier,
+				(other_member, other_secret),
+				(new_key, new_secret.clone()),
+				0,
+				context,
+			);
+			assert_noop!(
+				MembersPallet::release_alias(
+					RuntimeOrigin::signed(1),
+					identifier,
+					new_key,
+					context,
+					proof,
+					signature,
+				),
+				Error::<Test>::InvalidProof
+			);
+
+			// The signature of the new key is required.
+			let (proof, _, _) = release_alias_proof(
+				identifier,
+				(member, secret.clone()),
+				(new_key, new_secret.clone()),
+				0,
+				context,
+			);
+			let (_, signature, _) = release_alias_proof(
+				identifier,
+				(member, secret.clone()),
+				(new_key, create_unique_secret()),
+				0,
+				context,
+			);
+			assert_noop!(
+				MembersPallet::release_alias(
+					RuntimeOrigin::signed(1),
+					identifier,
+					new_key,
+					context,
+					proof,
+					signature,
+				),
+				Error::<Test>::InvalidSignature
+			);
+
+			// Aliases can no longer be released once the release window is over.
+			let (proof, signature, _) = release_alias_proof(
+				identifier,
+				(member, secret),
+				(new_key, new_secret),
+				0,
+				context,
+			);
+			advance_time(601);
+			assert_noop!(
+				MembersPallet::release_alias(
+					RuntimeOrigin::signed(1),
+					identifier,
+					new_key,
+					context,
+					proof,
+					signature,
+				),
+				Error::<Test>::AliasReleaseExpired
+			);
+			assert!(released_aliases().is_empty());
+		});
+	}
+}
+
+mod ring_compaction_tests {
+	use super::*;
+
+	const COMPACT_IDENTIFIER: Identifier = [70u8; 32];
+
+	/// Onboard the queued members into `ring_index`, build it and move on to the next ring.
+	fn onboard_and_build(ring_index: RingIndex) {
+		manually_advance_to_ring(&COMPACT_IDENTIFIER, ring_index);
+		assert_ok!(MembersPallet::onboard_members(&COMPACT_IDENTIFIER, false));
+		let to_include =
+			MembersPallet::should_build_ring(&COMPACT_IDENTIFIER, ring_index, 255).unwrap();
+		assert_ok!(MembersPallet::build_ring(&COMPACT_IDENTIFIER, ring_index, to_include));
+		manually_advance_to_ring(&COMPACT_IDENTIFIER, ring_index + 1);
+	}
+
+	/// Set up a fuller ring 0 with 20 members and a sparse ring 1 with 5 members, with ring 2
+	/// as the current onboarding ring.
+	fn setup_sparse_rings() -> Vec<(MemberOf<Test>, SecretOf<Test>)> {
+		System::set_block_number(1);
+		RingCompactionThreshold::set(Percent::from_percent(50));
+		create_test_collection(COMPACT_IDENTIFIER, 1);
+
+		let mut members = generate_members(COMPACT_IDENTIFIER, 1, 20);
+		onboard_and_build(0);
+		members.extend(generate_members(COMPACT_IDENTIFIER, 21, 25));
+		onboard_and_build(1);
+
+		assert_eq!(RingKeysStatus::<Test>::get(COMPACT_IDENTIFIER, 0).total, 20);
+		assert_eq!(RingKeysStatus::<Test>::get(COMPACT_IDENTIFIER, 1).total, 5);
+		members
+	}
+
+	#[test]
+	fn should_compact_rings_is_disabled_by_zero_threshold() {
+		TestExt::new().execute_with(|| {
+			setup_sparse_rings();
+			RingCompactionThreshold::set(Percent::zero());
+
+			assert_eq!(MembersPallet::should_compact_rings(&COMPACT_IDENTIFIER), None);
+		});
+	}
+
+	#[test]
+	fn should_compact_rings_drains_sparsest_ring_into_fullest_ring() {
+		TestExt::new().execute_with(|| {
+			setup_sparse_rings();
+			generate_members(COMPACT_IDENTIFIER, 26, 35);
+			onboard_and_build(2);
+
+			// Ring 1 is the sparsest ring and ring 0 the fullest one with room left.
+			assert_eq!(MembersPallet::should_compact_rings(&COMPACT_IDENTIFIER), Some((1, 0, 5)));
+
+			// Rings above the threshold are left alone.
+			RingCompactionThreshold::set(Percent::from_percent(1));
+			assert_eq!(MembersPallet::should_compact_rings(&COMPACT_IDENTIFIER), None);
+		});
+	}
+
+	#[test]
+	fn compactable_count_rejects_ineligible_rings() {
+		TestExt::new().execute_with(|| {
+			let members = setup_sparse_rings();
+			assert_eq!(MembersPallet::compactable_count(&COMPACT_IDENTIFIER, 1, 0), Some(5));
+
+			// The target ring must be fuller than the source ring.
+			assert_eq!(MembersPallet::compactable_count(&COMPACT_IDENTIFIER, 0, 1), None);
+			assert_eq!(MembersPallet::compactable_count(&COMPACT_IDENTIFIER, 1, 1), None);
+
+			// The current onboarding ring is neither drained nor filled by compaction.
+			manually_advance_to_ring(&COMPACT_IDENTIFIER, 1);
+			assert_eq!(MembersPallet::compactable_count(&COMPACT_IDENTIFIER, 1, 0), None);
+			manually_advance_to_ring(&COMPACT_IDENTIFIER, 0);
+			assert_eq!(MembersPallet::compactable_count(&COMPACT_IDENTIFIER, 1, 0), None);
+			assert_eq!(MembersPallet::should_compact_rings(&COMPACT_IDENTIFIER), None);
+			manually_advance_to_ring(&COMPACT_IDENTIFIER, 2);
+
+			// Rings with pending suspensions are left to suspension processing first.
+			assert_ok!(<MembersPallet as FlexibleMembers>::start_removal_session(
+				&COMPACT_IDENTIFIER
+			));
+			assert_ok!(<MembersPallet as FlexibleMembers>::remove_members(
+				&COMPACT_IDENTIFIER,
+				&[members[0].0]
+			));
+			assert_ok!(<MembersPallet as FlexibleMembers>::end_removal_session(
+				&COMPACT_IDENTIFIER
+			));
+			assert_eq!(MembersPallet::compactable_count(&COMPACT_IDENTIFIER, 1, 0), None);
+		});
+	}
+
+	#[test]
+	fn compact_rings_deletes_drained_source_ring() {
+		TestExt::new().execute_with(|| {
+			let members = setup_sparse_rings();
+			let source_revision = Root::<Test>::get(COMPACT_IDENTIFIER, 1).unwrap().revision;
+
+			assert_ok!(MembersPallet::compact_rings(&COMPACT_IDENTIFIER, 1, 0, 5));
+
+			// All members of ring 1 were appended to ring 0.
+			let status = RingKeysStatus::<Test>::get(COMPACT_IDENTIFIER, 0);
+			assert_eq!(status.total, 25);
+			assert_eq!(status.included, 20);
+			assert!(StaleRings::<Test>::contains_key(COMPACT_IDENTIFIER, 0));
+			for (ring_position, (member, _)) in members.iter().enumerate() {
+				assert_eq!(
+					Members::<Test>::get(COMPACT_IDENTIFIER, member),
+					Some(RingPosition::Included {
+						ring_index: 0,
+						ring_page: 0,
+						ring_position: ring_position as u32,
+					})
+				);
+			}
+
+			// Ring 1 is gone, while its root is kept for proofs at the old revision.
+			assert!(!Root::<Test>::contains_key(COMPACT_IDENTIFIER, 1));
+			assert!(!RingKeysStatus::<Test>::contains_key(COMPACT_IDENTIFIER, 1));
+			assert!(RingKeys::<Test>::get((COMPACT_IDENTIFIER, 1u32, 0u32)).is_empty());
+			assert!(!StaleRings::<Test>::contains_key(COMPACT_IDENTIFIER, 1));
+			assert!(OldRoots::<Test>::contains_key((
+				COMPACT_IDENTIFIER,
+				1,
+				BigEndianU32(source_revision)
+			)));
+
+			System::assert_has_event(
+				Event::<Test>::RingCompacted {
+					identifier: COMPACT_IDENTIFIER,
+					source_ring_index: 1,
+					target_ring_index: 0,
+					moved: 5,
+				}
+				.into(),
+			);
+
+			// The next build includes the moved members in the target root.
+			let root_before = Root::<Test>::get(COMPACT_IDENTIFIER, 0).unwrap();
+			let to_include = MembersPallet::should_build_ring(&COMPACT_IDENTIFIER, 0, 255).unwrap();
+			assert_eq!(to_include, 5);
+			assert_ok!(MembersPallet::build_ring(&COMPACT_IDENTIFIER, 0, to_include));
+			let root_after = Root::<Test>::get(COMPACT_IDENTIFIER, 0).unwrap();
+			assert_eq!(root_after.revision, root_before.revision + 1);
+		});
+	}
+
+	#[test]
+	fn compact_rings_resets_partially_drained_source_ring() {
+		TestExt::new().execute_with(|| {
+			let members = setup_sparse_rings();
+			let source_revision = Root::<Test>::get(COMPACT_IDENTIFIER, 1).unwrap().revision;
+
+			assert_ok!(MembersPallet::compact_rings(&COMPACT_IDENTIFIER, 1, 0, 2));
+
+			// The last two members of ring 1 moved to ring 0.
+			assert_eq!(RingKeysStatus::<Test>::get(COMPACT_IDENTIFIER, 0).total, 22);
+			for (member, _) in &members[23..] {
+				assert!(matches!(
+					Members::<Test>::get(COMPACT_IDENTIFIER, member),
+					Some(RingPosition::Included { ring_index: 0, .. })
+				));
+			}
+
+			// Ring 1 keeps three members and is rebuilt from scratch under a new revision.
+			let status = RingKeysStatus::<Test>::get(COMPACT_IDENTIFIER, 1);
+			assert_eq!(status.total, 3);
+			assert_eq!(status.included, 0);
+			assert!(StaleRings::<Test>::contains_key(COMPACT_IDENTIFIER, 1));
+			assert_eq!(
+				Root::<Test>::get(COMPACT_IDENTIFIER, 1).unwrap().revision,
+				source_revision + 1
+			);
+			assert!(OldRoots::<Test>::contains_key((
+				COMPACT_IDENTIFIER,
+				1,
+				BigEndianU32(source_revision)
+			)));
+
+			// Moving more members than allowed is rejected.
+			assert_noop!(
+				MembersPallet::compact_rings(&COMPACT_IDENTIFIER, 1, 0, 4),
+				Error::<Test>::InvalidRing
+			);
+			assert_noop!(
+				MembersPallet::compact_rings(&COMPACT_IDENTIFIER, 1, 0, 0),
+				Error::<Test>::InvalidRing
+			);
+		});
+	}
+
+	#[test]
+	fn ensure_can_compact_rings_checks_revision() {
+		TestExt::new().execute_with(|| {
+			setup_sparse_rings();
+			let revision = Root::<Test>::get(COMPACT_IDENTIFIER, 1).unwrap().revision;
+
+			assert!(MembersPallet::ensure_can_compact_rings(
+				&COMPACT_IDENTIFIER,
+				1,
+				0,
+				5,
+				Some(revision)
+			)
+			.is_ok());
+			assert_eq!(
+				MembersPallet::ensure_can_compact_rings(&COMPACT_IDENTIFIER, 1, 0, 5, None),
+				Err(InvalidTransaction::Stale.into())
+			);
+			assert_eq!(
+				MembersPallet::ensure_can_compact_rings(
+					&COMPACT_IDENTIFIER,
+					1,
+					0,
+					5,
+					Some(revision + 1)
+				),
+				Err(InvalidTransaction::Future.into())
+			);
+			assert_eq!(
+				MembersPallet::ensure_can_compact_rings(
+					&COMPACT_IDENTIFIER,
+					1,
+					0,
+					6,
+					Some(revision)
+				),
+				Err(InvalidTransaction::Stale.into())
+			);
+		});
+	}
+
+	#[test]
+	fn ocw_compacts_sparse_rings() {
+		TestExt::new().execute_with(|| {
+			setup_sparse_rings();
+
+			advance_to_block(10);
+
+			// Ring 1 was drained into ring 0, which was rebuilt with all members.
+			assert!(!Root::<Test>::contains_key(COMPACT_IDENTIFIER, 1));
+			assert!(!RingKeysStatus::<Test>::contains_key(COMPACT_IDENTIFIER, 1));
+			let status = RingKeysStatus::<Test>::get(COMPACT_IDENTIFIER, 0);
+			assert_eq!(status.total, 25);
+			assert_eq!(status.included, 25);
+			assert_eq!(MembersPallet::should_compact_rings(&COMPACT_IDENTIFIER), None);
+		});
+	}
+}
+
+mod min_ring_population_tests {
+	use super::*;
+
+	const MIN_POP_IDENTIFIER: Identifier = [80u8; 32];
+
+	fn create_proof_for_member(
+		member: &MemberOf<Test>,
+		secret: &SecretOf<Test>,
+		context: Context,
+		message: &[u8],
+	) -> <MockCrypto as GenerateVerifiable>::Proof {
+		let ring_members =
+			<MembersPallet as AppendOnlyMembers>::ring_members(&MIN_POP_IDENTIFIER, 0);
+		let commitment = MockCrypto::open((), member, ring_members.into_iter()).unwrap();
+		MockCrypto::create(commitment, secret, &context, message).unwrap().0
+	}
+
+	fn build_ring_0() {
+		let to_include = MembersPallet::should_build_ring(&MIN_POP_IDENTIFIER, 0, 255).unwrap();
+		assert_ok!(MembersPallet::build_ring(&MIN_POP_IDENTIFIER, 0, to_include));
+	}
+
+	#[test]
+	fn set_min_ring_population_works_with_root() {
+		TestExt::new().execute_with(|| {
+			System::set_block_number(1);
+			create_test_collection(MIN_POP_IDENTIFIER, 1);
+
+			assert_ok!(MembersPallet::set_min_ring_population(
+				RuntimeOrigin::root(),
+				MIN_POP_IDENTIFIER,
+				10
+			));
+
+			assert_eq!(MinRingPopulation::<Test>::get(MIN_POP_IDENTIFIER), 10);
+			System::assert_has_event(
+				Event::<Test>::MinRingPopulationSet {
+					identifier: MIN_POP_IDENTIFIER,
+					min_ring_population: 10,
+				}
+				.into(),
+			);
+		});
+	}
+
+	#[test]
+	fn set_min_ring_population_fails_for_invalid_input() {
+		TestExt::new().execute_with(|| {
+			assert_noop!(
+				MembersPallet::set_min_ring_population(
+					RuntimeOrigin::root(),
+					MIN_POP_IDENTIFIER,
+					10
+				),
+				Error::<Test>::CollectionNotFound
+			);
+
+			create_test_collection(MIN_POP_IDENTIFIER, 1);
+			assert_noop!(
+				MembersPallet::set_min_ring_population(
+					RuntimeOrigin::signed(1),
+					MIN_POP_IDENTIFIER,
+					10
+				),
+				sp_runtime::DispatchError::BadOrigin
+			);
+
+			// Must be reachable within a single ring build.
+			let limit = <Test as Config>::RingBuildingMemberLimit::get();
+			assert_noop!(
+				MembersPallet::set_min_ring_population(
+					RuntimeOrigin::root(),
+					MIN_POP_IDENTIFIER,
+					limit + 1
+				),
+				Error::<Test>::InvalidMinRingPopulation
+			);
+		});
+	}
+
+	#[test]
+	fn onboarding_waits_for_min_ring_population() {
+		TestExt::new().execute_with(|| {
+			create_test_collection(MIN_POP_IDENTIFIER, 1);
+			assert_ok!(MembersPallet::set_min_ring_population(
+				RuntimeOrigin::root(),
+				MIN_POP_IDENTIFIER,
+				10
+			));
+
+			// Members are held in the queue until the ring can reach the minimum.
+			generate_members(MIN_POP_IDENTIFIER, 1, 9);
+			assert_eq!(MembersPallet::onboard_members(&MIN_POP_IDENTIFIER, false), Ok(false));
+			assert_noop!(
+				MembersPallet::onboard_members(&MIN_POP_IDENTIFIER, true),
+				Error::<Test>::PrematureOnboarding
+			);
+			assert_eq!(RingKeysStatus::<Test>::get(MIN_POP_IDENTIFIER, 0).total, 0);
+
+			generate_members(MIN_POP_IDENTIFIER, 10, 10);
+			assert_ok!(MembersPallet::onboard_members(&MIN_POP_IDENTIFIER, false));
+			assert_eq!(RingKeysStatus::<Test>::get(MIN_POP_IDENTIFIER, 0).total, 10);
+
+			// Once the minimum is met, further members are onboarded in regular batches.
+			generate_members(MIN_POP_IDENTIFIER, 11, 11);
+			assert_ok!(MembersPallet::onboard_members(&MIN_POP_IDENTIFIER, false));
+			assert_eq!(RingKeysStatus::<Test>::get(MIN_POP_IDENTIFIER, 0).total, 11);
+		});
+	}
+
+	#[test]
+	fn rings_below_min_population_are_not_built() {
+		TestExt::new().execute_with(|| {
+			create_test_collection(MIN_POP_IDENTIFIER, 1);
+			generate_members(MIN_POP_IDENTIFIER, 1, 5);
+			assert_ok!(MembersPallet::onboard_members(&MIN_POP_IDENTIFIER, false));
+
+			assert_ok!(MembersPallet::set_min_ring_population(
+				RuntimeOrigin::root(),
+				MIN_POP_IDENTIFIER,
+				10
+			));
+			assert_eq!(MembersPallet::should_build_ring(&MIN_POP_IDENTIFIER, 0, 255), None);
+
+			assert_ok!(MembersPallet::set_min_ring_population(
+				RuntimeOrigin::root(),
+				MIN_POP_IDENTIFIER,
+				5
+			));
+			assert_eq!(MembersPallet::should_build_ring(&MIN_POP_IDENTIFIER, 0, 255), Some(5));
+		});
+	}
+
+	#[test]
+	fn proofs_are_rejected_below_min_ring_population() {
+		TestExt::new().execute_with(|| {
+			create_test_collection(MIN_POP_IDENTIFIER, 1);
+			let members = generate_members(MIN_POP_IDENTIFIER, 1, 5);
+			assert_ok!(MembersPallet::onboard_members(&MIN_POP_IDENTIFIER, false));
+			build_ring_0();
+
+			let (member, secret) = &members[0];
+			let context = [1u8; 32];
+			let message = b"min ring population";
+			let proof = create_proof_for_member(member, secret, context, message);
+			let revision = Root::<Test>::get(MIN_POP_IDENTIFIER, 0).unwrap().revision;
+			assert!(<MembersPallet as AppendOnlyMembers>::is_ring_provable(&MIN_POP_IDENTIFIER, 0));
+
+			// Raising the minimum above the ring population makes the ring unprovable.
+			assert_ok!(MembersPallet::set_min_ring_population(
+				RuntimeOrigin::root(),
+				MIN_POP_IDENTIFIER,
+				10
+			));
+			assert!(!<MembersPallet as AppendOnlyMembers>::is_ring_provable(
+				&MIN_POP_IDENTIFIER,
+				0
+			));
+			let result = <MembersPallet as MembershipProver>::verify_membership(
+				&MIN_POP_IDENTIFIER,
+				&proof,
+				0,
+				context,
+				message,
+			);
+			assert_eq!(result.unwrap_err(), Error::<Test>::RingNotProvable.into());
+			let result = <MembersPallet as MembershipProver>::verify_membership_at_rev(
+				&MIN_POP_IDENTIFIER,
+				&proof,
+				0,
+				revision,
+				context,
+				message,
+			);
+			assert_eq!(result.unwrap_err(), Error::<Test>::RingNotProvable.into());
+			assert!(!<MembersPallet as MembershipProver>::is_revision_valid(
+				&MIN_POP_IDENTIFIER,
+				0,
+				revision
+			));
+
+			// Growing the ring past the minimum makes it provable again.
+			generate_members(MIN_POP_IDENTIFIER, 6, 10);
+			assert_ok!(MembersPallet::onboard_members(&MIN_POP_IDENTIFIER, false));
+			build_ring_0();
+			assert!(<MembersPallet as AppendOnlyMembers>::is_ring_provable(&MIN_POP_IDENTIFIER, 0));
+			let proof = create_proof_for_member(member, secret, context, message);
+			<MembersPallet as MembershipProver>::verify_membership(
+				&MIN_POP_IDENTIFIER,
+				&proof,
+				0,
+				context,
+				message,
+			)
+			.expect("Ring meets the minimum population");
+		});
+	}
+	#[test]
+	fn suspensions_requeue_rings_left_below_min_ring_population() {
+		TestExt::new().execute_with(|| {
+			System::set_block_number(1);
+			create_test_collection(MIN_POP_IDENTIFIER, 1);
+			let members = generate_members(MIN_POP_IDENTIFIER, 1, 12);
+			assert_ok!(MembersPallet::set_min_ring_population(
+				RuntimeOrigin::root(),
+				MIN_POP_IDENTIFIER,
+				10
+			));
+			assert_ok!(MembersPallet::onboard_members(&MIN_POP_IDENTIFIER, false));
+			build_ring_0();
+			assert!(<MembersPallet as AppendOnlyMembers>::is_ring_provable(&MIN_POP_IDENTIFIER, 0));
+			// Ring 0 no longer grows through onboarding.
+			CurrentRingIndex::<Test>::insert(MIN_POP_IDENTIFIER, 1);
+
+			// Suspensions leave 9 members in the ring, which could never be rebuilt.
+			let suspended: Vec<_> = members.iter().take(3).map(|(m, _)| *m).collect();
+			assert_ok!(<MembersPallet as FlexibleMembers>::start_removal_session(
+				&MIN_POP_IDENTIFIER
+			));
+			assert_ok!(<MembersPallet as FlexibleMembers>::remove_members(
+				&MIN_POP_IDENTIFIER,
+				&suspended
+			));
+			assert_ok!(<MembersPallet as FlexibleMembers>::end_removal_session(
+				&MIN_POP_IDENTIFIER
+			));
+			MembersPallet::remove_suspended_keys(&MIN_POP_IDENTIFIER, 0);
+
+			// The remaining members are back in the onboarding queue and the ring is removed.
+			System::assert_has_event(
+				Event::<Test>::RingMembersRequeued {
+					identifier: MIN_POP_IDENTIFIER,
+					ring_index: 0,
+					count: 9,
+				}
+				.into(),
+			);
+			assert!(Root::<Test>::get(MIN_POP_IDENTIFIER, 0).is_none());
+			assert!(!RingKeysStatus::<Test>::contains_key(MIN_POP_IDENTIFIER, 0));
+			assert_eq!(ActiveMembers::<Test>::get(MIN_POP_IDENTIFIER), 0);
+			for (member, _) in members.iter().skip(3) {
+				assert!(matches!(
+					Members::<Test>::get(MIN_POP_IDENTIFIER, member),
+					Some(RingPosition::Onboarding { .. })
+				));
+			}
+
+			// Along with a new member, they are onboarded into a ring meeting the minimum.
+			generate_members(MIN_POP_IDENTIFIER, 13, 13);
+			assert_ok!(MembersPallet::onboard_members(&MIN_POP_IDENTIFIER, false));
+			assert_eq!(RingKeysStatus::<Test>::get(MIN_POP_IDENTIFIER, 1).total, 10);
+			let to_include = MembersPallet::should_build_ring(&MIN_POP_IDENTIFIER, 1, 255).unwrap();
+			assert_ok!(MembersPallet::build_ring(&MIN_POP_IDENTIFIER, 1, to_include));
+			assert!(<MembersPallet as AppendOnlyMembers>::is_ring_provable(&MIN_POP_IDENTIFIER, 1));
+		});
+	}
+
+	#[test]
+	fn raising_min_ring_population_requeues_rings_left_below_it() {
+		TestExt::new().execute_with(|| {
+			System::set_block_number(1);
+			create_test_collection(MIN_POP_IDENTIFIER, 1);
+			let members = generate_members(MIN_POP_IDENTIFIER, 1, 10);
+			assert_ok!(MembersPallet::onboard_members(&MIN_POP_IDENTIFIER, false));
+			build_ring_0();
+			// Ring 0 no longer grows through onboarding.
+			CurrentRingIndex::<Test>::insert(MIN_POP_IDENTIFIER, 1);
+			assert_eq!(
+				MembersPallet::ensure_can_requeue_ring_members(&MIN_POP_IDENTIFIER, 0)
+					.map(|_| ()),
+				Err(InvalidTransaction::Stale.into())
+			);
+
+			// Raising the minimum leaves ring 0 unprovable.
+			assert_ok!(MembersPallet::set_min_ring_population(
+				RuntimeOrigin::root(),
+				MIN_POP_IDENTIFIER,
+				11
+			));
+			assert!(!<MembersPallet as AppendOnlyMembers>::is_ring_provable(
+				&MIN_POP_IDENTIFIER,
+				0
+			));
+
+			assert_ok!(MembersPallet::ensure_can_requeue_ring_members(&MIN_POP_IDENTIFIER, 0));
+			assert_noop!(
+				MembersPallet::requeue_ring_members_authorized(
+					RuntimeOrigin::signed(1),
+					MIN_POP_IDENTIFIER,
+					0
+				),
+				DispatchError::BadOrigin
+			);
+			assert_ok!(MembersPallet::requeue_ring_members_authorized(
+				RuntimeOrigin::from(frame_system::RawOrigin::Authorized),
+				MIN_POP_IDENTIFIER,
+				0
+			));
+			System::assert_has_event(
+				Event::<Test>::RingMembersRequeued {
+					identifier: MIN_POP_IDENTIFIER,
+					ring_index: 0,
+					count: 10,
+				}
+				.into(),
+			);
+			assert!(Root::<Test>::get(MIN_POP_IDENTIFIER, 0).is_none());
+			for (member, _) in members.iter() {
+				assert!(matches!(
+					Members::<Test>::get(MIN_POP_IDENTIFIER, member),
+					Some(RingPosition::Onboarding { .. })
+				));
+			}
+			assert_eq!(
+				MembersPallet::ensure_can_requeue_ring_members(&MIN_POP_IDENTIFIER, 0)
+					.map(|_| ()),
+				Err(InvalidTransaction::Stale.into())
+			);
+		});
+	}
+}
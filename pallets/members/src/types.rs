@@ -145,3 +145,15 @@ impl<T: Config> Decode for OldRoot<T> {
 		})
 	}
 }
+
+/// Release of the aliases of a rotated-out member key, recorded under the key replacing it.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, Debug, TypeInfo, MaxEncodedLen)]
+pub struct AliasRelease<Member> {
+	/// The rotated-out key. Releases are proven against a ring made of this key alone.
+	pub old_key: Member,
+	/// The time of the rotation, in seconds since the UNIX epoch. Aliases can be released until
+	/// `OldRootRetentionDuration` has passed since then.
+	pub rotated_at: u64,
+	/// The number of aliases released so far, bound into each release proof.
+	pub nonce: u32,
+}
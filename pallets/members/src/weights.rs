@@ -84,6 +84,16 @@ pub trait WeightInfo {
 	fn clean_up_old_roots_authorized(n: u32, ) -> Weight;
 	fn ensure_can_mark_ring_stale() -> Weight;
 	fn mark_ring_stale_authorized() -> Weight;
+	fn rotate_member_key() -> Weight;
+	fn validate_rotate_member_key() -> Weight;
+	fn release_alias() -> Weight;
+	fn ensure_can_compact_rings() -> Weight;
+	fn compact_rings_authorized(n: u32, ) -> Weight;
+	fn requeue_ring_members(n: u32, ) -> Weight;
+	fn set_min_ring_population() -> Weight;
+	fn ensure_can_prune_rotated_key() -> Weight;
+	fn prune_rotated_key_authorized() -> Weight;
+	fn ensure_can_requeue_ring_members() -> Weight;
 }
 
 /// Weights for `indiv_pallet_members` using the Substrate node and recommended hardware.
@@ -711,6 +721,212 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(13_357_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Members::Collections` (r:1 w:0)
+	/// Proof: `Members::Collections` (`max_values`: None, `max_size`: Some(646), added: 3121, mode: `MaxEncodedLen`)
+	/// Storage: `Members::Members` (r:3 w:3)
+	/// Proof: `Members::Members` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `Members::PendingAliasReleases` (r:1 w:2)
+	/// Proof: `Members::PendingAliasReleases` (`max_values`: None, `max_size`: Some(124), added: 2599, mode: `MaxEncodedLen`)
+	/// Storage: `Members::PendingSuspensions` (r:1 w:1)
+	/// Proof: `Members::PendingSuspensions` (`max_values`: None, `max_size`: Some(1066), added: 3541, mode: `MaxEncodedLen`)
+	/// Storage: `Members::QueuePageIndices` (r:1 w:1)
+	/// Proof: `Members::QueuePageIndices` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Members::OnboardingQueue` (r:1 w:1)
+	/// Proof: `Members::OnboardingQueue` (`max_values`: None, `max_size`: Some(8206), added: 10681, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn rotate_member_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `8779`
+		//  Estimated: `11671`
+		// Minimum execution time: 55_914_000 picoseconds.
+		Weight::from_parts(57_862_000, 11671)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Members::Collections` (r:1 w:0)
+	/// Proof: `Members::Collections` (`max_values`: None, `max_size`: Some(646), added: 3121, mode: `MaxEncodedLen`)
+	/// Storage: `Members::Members` (r:1 w:0)
+	/// Proof: `Members::Members` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `Members::PendingAliasReleases` (r:1 w:0)
+	/// Proof: `Members::PendingAliasReleases` (`max_values`: None, `max_size`: Some(124), added: 2599, mode: `MaxEncodedLen`)
+	fn validate_rotate_member_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `538`
+		//  Estimated: `4111`
+		// Minimum execution time: 1_898_104_000 picoseconds.
+		Weight::from_parts(1_920_377_000, 4111)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+	}
+	/// Storage: `Members::PendingAliasReleases` (r:1 w:1)
+	/// Proof: `Members::PendingAliasReleases` (`max_values`: None, `max_size`: Some(124), added: 2599, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Members::Collections` (r:1 w:0)
+	/// Proof: `Members::Collections` (`max_values`: None, `max_size`: Some(646), added: 3121, mode: `MaxEncodedLen`)
+	/// Storage: `ChunksManager::Chunks` (r:1 w:0)
+	/// Proof: `ChunksManager::Chunks` (`max_values`: None, `max_size`: Some(24503), added: 26978, mode: `MaxEncodedLen`)
+	fn release_alias() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `25024`
+		//  Estimated: `27968`
+		// Minimum execution time: 36_921_530_000 picoseconds.
+		Weight::from_parts(37_004_118_000, 27968)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Members::Collections` (r:1 w:0)
+	/// Proof: `Members::Collections` (`max_values`: None, `max_size`: Some(646), added: 3121, mode: `MaxEncodedLen`)
+	/// Storage: `Members::RingsState` (r:1 w:0)
+	/// Proof: `Members::RingsState` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	/// Storage: `Members::CurrentRingIndex` (r:1 w:0)
+	/// Proof: `Members::CurrentRingIndex` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Members::RingKeysStatus` (r:2 w:0)
+	/// Proof: `Members::RingKeysStatus` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `Members::PendingSuspensions` (r:2 w:0)
+	/// Proof: `Members::PendingSuspensions` (`max_values`: None, `max_size`: Some(1066), added: 3541, mode: `MaxEncodedLen`)
+	/// Storage: `Members::Root` (r:1 w:0)
+	/// Proof: `Members::Root` (`max_values`: None, `max_size`: Some(1672), added: 4147, mode: `MaxEncodedLen`)
+	fn ensure_can_compact_rings() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1311`
+		//  Estimated: `8072`
+		// Minimum execution time: 24_806_000 picoseconds.
+		Weight::from_parts(25_917_000, 8072)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+	}
+	/// Storage: `Members::Collections` (r:1 w:0)
+	/// Proof: `Members::Collections` (`max_values`: None, `max_size`: Some(646), added: 3121, mode: `MaxEncodedLen`)
+	/// Storage: `Members::RingsState` (r:1 w:0)
+	/// Proof: `Members::RingsState` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	/// Storage: `Members::CurrentRingIndex` (r:1 w:0)
+	/// Proof: `Members::CurrentRingIndex` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Members::RingKeysStatus` (r:2 w:2)
+	/// Proof: `Members::RingKeysStatus` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `Members::PendingSuspensions` (r:2 w:0)
+	/// Proof: `Members::PendingSuspensions` (`max_values`: None, `max_size`: Some(1066), added: 3541, mode: `MaxEncodedLen`)
+	/// Storage: `Members::RingKeys` (r:2 w:2)
+	/// Proof: `Members::RingKeys` (`max_values`: None, `max_size`: Some(8226), added: 10701, mode: `MaxEncodedLen`)
+	/// Storage: `Members::Root` (r:1 w:1)
+	/// Proof: `Members::Root` (`max_values`: None, `max_size`: Some(1672), added: 4147, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Members::Members` (r:0 w:255)
+	/// Proof: `Members::Members` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `Members::StaleRings` (r:0 w:2)
+	/// Proof: `Members::StaleRings` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Members::OldRoots` (r:0 w:1)
+	/// Proof: `Members::OldRoots` (`max_values`: None, `max_size`: Some(816), added: 3291, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 100]`.
+	fn compact_rings_authorized(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `8915 + n * (32 ±0)`
+		//  Estimated: `22392`
+		// Minimum execution time: 98_212_000 picoseconds.
+		Weight::from_parts(101_530_412, 22392)
+			// Standard Error: 4_118
+			.saturating_add(Weight::from_parts(3_201_894, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `Members::RingKeysStatus` (r:1 w:1)
+	/// Proof: `Members::RingKeysStatus` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `Members::MinRingPopulation` (r:1 w:0)
+	/// Proof: `Members::MinRingPopulation` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Members::CurrentRingIndex` (r:1 w:0)
+	/// Proof: `Members::CurrentRingIndex` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Members::RingKeys` (r:1 w:1)
+	/// Proof: `Members::RingKeys` (`max_values`: None, `max_size`: Some(8226), added: 10701, mode: `MaxEncodedLen`)
+	/// Storage: `Members::QueuePageIndices` (r:1 w:1)
+	/// Proof: `Members::QueuePageIndices` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Members::OnboardingQueue` (r:2 w:2)
+	/// Proof: `Members::OnboardingQueue` (`max_values`: None, `max_size`: Some(8206), added: 10681, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Members::Root` (r:1 w:1)
+	/// Proof: `Members::Root` (`max_values`: None, `max_size`: Some(1672), added: 4147, mode: `MaxEncodedLen`)
+	/// Storage: `Members::ActiveMembers` (r:1 w:1)
+	/// Proof: `Members::ActiveMembers` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Members::Members` (r:0 w:254)
+	/// Proof: `Members::Members` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `Members::StaleRings` (r:0 w:1)
+	/// Proof: `Members::StaleRings` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Members::OldRoots` (r:0 w:1)
+	/// Proof: `Members::OldRoots` (`max_values`: None, `max_size`: Some(816), added: 3291, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 254]`.
+	fn requeue_ring_members(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `10098 + n * (32 ±0)`
+		//  Estimated: `32352`
+		// Minimum execution time: 62_487_000 picoseconds.
+		Weight::from_parts(66_913_508, 32352)
+			// Standard Error: 3_742
+			.saturating_add(Weight::from_parts(2_874_316, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `Members::Collections` (r:1 w:0)
+	/// Proof: `Members::Collections` (`max_values`: None, `max_size`: Some(646), added: 3121, mode: `MaxEncodedLen`)
+	/// Storage: `Members::MinRingPopulation` (r:0 w:1)
+	/// Proof: `Members::MinRingPopulation` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn set_min_ring_population() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `172`
+		//  Estimated: `4111`
+		// Minimum execution time: 14_012_000 picoseconds.
+		Weight::from_parts(14_603_000, 4111)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Members::PendingAliasReleases` (r:1 w:0)
+	/// Proof: `Members::PendingAliasReleases` (`max_values`: None, `max_size`: Some(124), added: 2599, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn ensure_can_prune_rotated_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `226`
+		//  Estimated: `3589`
+		// Minimum execution time: 11_302_000 picoseconds.
+		Weight::from_parts(12_018_000, 3589)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+	}
+	/// Storage: `Members::PendingAliasReleases` (r:1 w:1)
+	/// Proof: `Members::PendingAliasReleases` (`max_values`: None, `max_size`: Some(124), added: 2599, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Members::Members` (r:1 w:1)
+	/// Proof: `Members::Members` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	fn prune_rotated_key_authorized() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `331`
+		//  Estimated: `3589`
+		// Minimum execution time: 19_476_000 picoseconds.
+		Weight::from_parts(20_583_000, 3589)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Members::SuspendedCollections` (r:1 w:0)
+	/// Proof: `Members::SuspendedCollections` (`max_values`: None, `max_size`: Some(646), added: 3121, mode: `MaxEncodedLen`)
+	/// Storage: `Members::PendingSuspensions` (r:1 w:0)
+	/// Proof: `Members::PendingSuspensions` (`max_values`: None, `max_size`: Some(1066), added: 3541, mode: `MaxEncodedLen`)
+	/// Storage: `Members::RingKeysStatus` (r:1 w:0)
+	/// Proof: `Members::RingKeysStatus` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `Members::MinRingPopulation` (r:1 w:0)
+	/// Proof: `Members::MinRingPopulation` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Members::CurrentRingIndex` (r:1 w:0)
+	/// Proof: `Members::CurrentRingIndex` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn ensure_can_requeue_ring_members() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `405`
+		//  Estimated: `4531`
+		// Minimum execution time: 16_144_000 picoseconds.
+		Weight::from_parts(16_902_000, 4531)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -1337,4 +1553,210 @@ impl WeightInfo for () {
 		Weight::from_parts(13_357_000, 0)
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Members::Collections` (r:1 w:0)
+	/// Proof: `Members::Collections` (`max_values`: None, `max_size`: Some(646), added: 3121, mode: `MaxEncodedLen`)
+	/// Storage: `Members::Members` (r:3 w:3)
+	/// Proof: `Members::Members` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `Members::PendingAliasReleases` (r:1 w:2)
+	/// Proof: `Members::PendingAliasReleases` (`max_values`: None, `max_size`: Some(124), added: 2599, mode: `MaxEncodedLen`)
+	/// Storage: `Members::PendingSuspensions` (r:1 w:1)
+	/// Proof: `Members::PendingSuspensions` (`max_values`: None, `max_size`: Some(1066), added: 3541, mode: `MaxEncodedLen`)
+	/// Storage: `Members::QueuePageIndices` (r:1 w:1)
+	/// Proof: `Members::QueuePageIndices` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Members::OnboardingQueue` (r:1 w:1)
+	/// Proof: `Members::OnboardingQueue` (`max_values`: None, `max_size`: Some(8206), added: 10681, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn rotate_member_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `8779`
+		//  Estimated: `11671`
+		// Minimum execution time: 55_914_000 picoseconds.
+		Weight::from_parts(57_862_000, 11671)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Members::Collections` (r:1 w:0)
+	/// Proof: `Members::Collections` (`max_values`: None, `max_size`: Some(646), added: 3121, mode: `MaxEncodedLen`)
+	/// Storage: `Members::Members` (r:1 w:0)
+	/// Proof: `Members::Members` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `Members::PendingAliasReleases` (r:1 w:0)
+	/// Proof: `Members::PendingAliasReleases` (`max_values`: None, `max_size`: Some(124), added: 2599, mode: `MaxEncodedLen`)
+	fn validate_rotate_member_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `538`
+		//  Estimated: `4111`
+		// Minimum execution time: 1_898_104_000 picoseconds.
+		Weight::from_parts(1_920_377_000, 4111)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+	}
+	/// Storage: `Members::PendingAliasReleases` (r:1 w:1)
+	/// Proof: `Members::PendingAliasReleases` (`max_values`: None, `max_size`: Some(124), added: 2599, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Members::Collections` (r:1 w:0)
+	/// Proof: `Members::Collections` (`max_values`: None, `max_size`: Some(646), added: 3121, mode: `MaxEncodedLen`)
+	/// Storage: `ChunksManager::Chunks` (r:1 w:0)
+	/// Proof: `ChunksManager::Chunks` (`max_values`: None, `max_size`: Some(24503), added: 26978, mode: `MaxEncodedLen`)
+	fn release_alias() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `25024`
+		//  Estimated: `27968`
+		// Minimum execution time: 36_921_530_000 picoseconds.
+		Weight::from_parts(37_004_118_000, 27968)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Members::Collections` (r:1 w:0)
+	/// Proof: `Members::Collections` (`max_values`: None, `max_size`: Some(646), added: 3121, mode: `MaxEncodedLen`)
+	/// Storage: `Members::RingsState` (r:1 w:0)
+	/// Proof: `Members::RingsState` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	/// Storage: `Members::CurrentRingIndex` (r:1 w:0)
+	/// Proof: `Members::CurrentRingIndex` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Members::RingKeysStatus` (r:2 w:0)
+	/// Proof: `Members::RingKeysStatus` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `Members::PendingSuspensions` (r:2 w:0)
+	/// Proof: `Members::PendingSuspensions` (`max_values`: None, `max_size`: Some(1066), added: 3541, mode: `MaxEncodedLen`)
+	/// Storage: `Members::Root` (r:1 w:0)
+	/// Proof: `Members::Root` (`max_values`: None, `max_size`: Some(1672), added: 4147, mode: `MaxEncodedLen`)
+	fn ensure_can_compact_rings() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1311`
+		//  Estimated: `8072`
+		// Minimum execution time: 24_806_000 picoseconds.
+		Weight::from_parts(25_917_000, 8072)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+	}
+	/// Storage: `Members::Collections` (r:1 w:0)
+	/// Proof: `Members::Collections` (`max_values`: None, `max_size`: Some(646), added: 3121, mode: `MaxEncodedLen`)
+	/// Storage: `Members::RingsState` (r:1 w:0)
+	/// Proof: `Members::RingsState` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	/// Storage: `Members::CurrentRingIndex` (r:1 w:0)
+	/// Proof: `Members::CurrentRingIndex` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Members::RingKeysStatus` (r:2 w:2)
+	/// Proof: `Members::RingKeysStatus` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `Members::PendingSuspensions` (r:2 w:0)
+	/// Proof: `Members::PendingSuspensions` (`max_values`: None, `max_size`: Some(1066), added: 3541, mode: `MaxEncodedLen`)
+	/// Storage: `Members::RingKeys` (r:2 w:2)
+	/// Proof: `Members::RingKeys` (`max_values`: None, `max_size`: Some(8226), added: 10701, mode: `MaxEncodedLen`)
+	/// Storage: `Members::Root` (r:1 w:1)
+	/// Proof: `Members::Root` (`max_values`: None, `max_size`: Some(1672), added: 4147, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Members::Members` (r:0 w:255)
+	/// Proof: `Members::Members` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `Members::StaleRings` (r:0 w:2)
+	/// Proof: `Members::StaleRings` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Members::OldRoots` (r:0 w:1)
+	/// Proof: `Members::OldRoots` (`max_values`: None, `max_size`: Some(816), added: 3291, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 100]`.
+	fn compact_rings_authorized(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `8915 + n * (32 ±0)`
+		//  Estimated: `22392`
+		// Minimum execution time: 98_212_000 picoseconds.
+		Weight::from_parts(101_530_412, 22392)
+			// Standard Error: 4_118
+			.saturating_add(Weight::from_parts(3_201_894, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `Members::RingKeysStatus` (r:1 w:1)
+	/// Proof: `Members::RingKeysStatus` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `Members::MinRingPopulation` (r:1 w:0)
+	/// Proof: `Members::MinRingPopulation` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Members::CurrentRingIndex` (r:1 w:0)
+	/// Proof: `Members::CurrentRingIndex` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Members::RingKeys` (r:1 w:1)
+	/// Proof: `Members::RingKeys` (`max_values`: None, `max_size`: Some(8226), added: 10701, mode: `MaxEncodedLen`)
+	/// Storage: `Members::QueuePageIndices` (r:1 w:1)
+	/// Proof: `Members::QueuePageIndices` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Members::OnboardingQueue` (r:2 w:2)
+	/// Proof: `Members::OnboardingQueue` (`max_values`: None, `max_size`: Some(8206), added: 10681, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Members::Root` (r:1 w:1)
+	/// Proof: `Members::Root` (`max_values`: None, `max_size`: Some(1672), added: 4147, mode: `MaxEncodedLen`)
+	/// Storage: `Members::ActiveMembers` (r:1 w:1)
+	/// Proof: `Members::ActiveMembers` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Members::Members` (r:0 w:254)
+	/// Proof: `Members::Members` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `Members::StaleRings` (r:0 w:1)
+	/// Proof: `Members::StaleRings` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Members::OldRoots` (r:0 w:1)
+	/// Proof: `Members::OldRoots` (`max_values`: None, `max_size`: Some(816), added: 3291, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 254]`.
+	fn requeue_ring_members(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `10098 + n * (32 ±0)`
+		//  Estimated: `32352`
+		// Minimum execution time: 62_487_000 picoseconds.
+		Weight::from_parts(66_913_508, 32352)
+			// Standard Error: 3_742
+			.saturating_add(Weight::from_parts(2_874_316, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `Members::Collections` (r:1 w:0)
+	/// Proof: `Members::Collections` (`max_values`: None, `max_size`: Some(646), added: 3121, mode: `MaxEncodedLen`)
+	/// Storage: `Members::MinRingPopulation` (r:0 w:1)
+	/// Proof: `Members::MinRingPopulation` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn set_min_ring_population() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `172`
+		//  Estimated: `4111`
+		// Minimum execution time: 14_012_000 picoseconds.
+		Weight::from_parts(14_603_000, 4111)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Members::PendingAliasReleases` (r:1 w:0)
+	/// Proof: `Members::PendingAliasReleases` (`max_values`: None, `max_size`: Some(124), added: 2599, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn ensure_can_prune_rotated_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `226`
+		//  Estimated: `3589`
+		// Minimum execution time: 11_302_000 picoseconds.
+		Weight::from_parts(12_018_000, 3589)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+	}
+	/// Storage: `Members::PendingAliasReleases` (r:1 w:1)
+	/// Proof: `Members::PendingAliasReleases` (`max_values`: None, `max_size`: Some(124), added: 2599, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Members::Members` (r:1 w:1)
+	/// Proof: `Members::Members` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	fn prune_rotated_key_authorized() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `331`
+		//  Estimated: `3589`
+		// Minimum execution time: 19_476_000 picoseconds.
+		Weight::from_parts(20_583_000, 3589)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Members::SuspendedCollections` (r:1 w:0)
+	/// Proof: `Members::SuspendedCollections` (`max_values`: None, `max_size`: Some(646), added: 3121, mode: `MaxEncodedLen`)
+	/// Storage: `Members::PendingSuspensions` (r:1 w:0)
+	/// Proof: `Members::PendingSuspensions` (`max_values`: None, `max_size`: Some(1066), added: 3541, mode: `MaxEncodedLen`)
+	/// Storage: `Members::RingKeysStatus` (r:1 w:0)
+	/// Proof: `Members::RingKeysStatus` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `Members::MinRingPopulation` (r:1 w:0)
+	/// Proof: `Members::MinRingPopulation` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Members::CurrentRingIndex` (r:1 w:0)
+	/// Proof: `Members::CurrentRingIndex` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn ensure_can_requeue_ring_members() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `405`
+		//  Estimated: `4531`
+		// Minimum execution time: 16_144_000 picoseconds.
+		Weight::from_parts(16_902_000, 4531)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+	}
 }
@@ -54,6 +54,26 @@ fn assert_last_event<T: Config>(generic_event: <T as frame_system::Config>::Runt
 	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
 }
 
+/// Returns an account funded to appeal a verdict.
+fn funded_appellant<T: Config>() -> T::AccountId {
+	let appellant: T::AccountId = account("appellant", 0, 0);
+	let _ = T::Currency::mint_into(
+		&appellant,
+		T::AppealDeposit::get() + T::Currency::minimum_balance(),
+	);
+	appellant
+}
+
+/// Appeals the verdict of a new appealable case. Returns the indices of the appealed case and of
+/// the appeal case.
+fn appeal_case<T: Config>() -> (CaseIndex, CaseIndex) {
+	let appealable_until = T::Clock::now().as_secs().saturating_add(1);
+	let case_index = helpers::create_appealable_case::<T>(appealable_until);
+	let origin = frame_system::RawOrigin::Signed(funded_appellant::<T>());
+	assert_ok!(Pallet::<T>::appeal(origin.into(), case_index));
+	(case_index, CaseCount::<T>::get() - 1)
+}
+
 #[benchmarks(
 	where T: Config + core::marker::Send + core::marker::Sync,
 )]
@@ -62,13 +82,14 @@ mod benches {
 	use frame_support::{dispatch::RawOrigin, traits::EnsureOriginWithArg};
 	use frame_system::pallet_prelude::BlockNumberFor;
 
-	// Worst case: Contempt vote that ripens the case.
+	// Worst case: Contempt vote of a juror that ripens the case.
 	#[benchmark]
 	fn vote() -> Result<(), BenchmarkError> {
 		T::BenchmarkHelper::set_valid_time();
 		T::BenchmarkHelper::setup_currency();
 
 		let case_index = helpers::create_voting_case::<T>();
+		helpers::seat_jury::<T>(case_index, 1);
 
 		// Force the ripening branch: pass the duration check, and make the result definitive.
 		OpenCases::<T>::mutate(case_index, |maybe_case| {
@@ -100,10 +121,15 @@ mod benches {
 	fn close_case() -> Result<(), BenchmarkError> {
 		T::BenchmarkHelper::set_valid_time();
 		T::BenchmarkHelper::setup_currency();
+		helpers::fund_pot::<T>();
 
-		let case_index = helpers::create_ripe_case::<T>();
-
-		assert!(RipeCases::<T>::contains_key(case_index));
+		// Close a failed appeal case, which moves the deposit of the appeal to the pot.
+		let (appealed_index, case_index) = appeal_case::<T>();
+		let verdict = Judgement::Truth(Truth::False);
+		let open_case = OpenCases::<T>::take(case_index).expect("Case should exist");
+		let ripe_case =
+			RipeCase { details: open_case.details, verdict, appealable_until: None, appeal: None };
+		RipeCases::<T>::insert(case_index, ripe_case);
 
 		let call = Call::<T>::close_case { case_index };
 
@@ -116,10 +142,10 @@ mod benches {
 		}
 
 		assert!(!RipeCases::<T>::contains_key(case_index));
+		assert!(!RipeCases::<T>::contains_key(appealed_index));
 		assert!(DoneCases::<T>::contains_key(case_index));
-		assert_last_event::<T>(
-			Event::CaseClosed { case_index, verdict: Judgement::Truth(Truth::True) }.into(),
-		);
+		assert!(DoneCases::<T>::contains_key(appealed_index));
+		assert_last_event::<T>(Event::CaseClosed { case_index, verdict }.into());
 
 		Ok(())
 	}
@@ -187,8 +213,10 @@ mod benches {
 	fn intervene() -> Result<(), BenchmarkError> {
 		T::BenchmarkHelper::set_valid_time();
 		T::BenchmarkHelper::setup_currency();
+		helpers::fund_pot::<T>();
 
-		let case_index = helpers::create_voting_case::<T>();
+		// Intervene in an appeal case, dismissing the appeal.
+		let (appealed_index, case_index) = appeal_case::<T>();
 		let verdict = Judgement::Truth(Truth::False);
 
 		assert!(OpenCases::<T>::contains_key(case_index));
@@ -198,6 +226,7 @@ mod benches {
 
 		assert!(!OpenCases::<T>::contains_key(case_index));
 		assert!(DoneCases::<T>::contains_key(case_index));
+		assert!(DoneCases::<T>::contains_key(appealed_index));
 		assert_last_event::<T>(Event::CaseIntervened { case_index, verdict }.into());
 
 		Ok(())
@@ -535,6 +564,131 @@ mod benches {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn commit_vote() -> Result<(), BenchmarkError> {
+		T::BenchmarkHelper::set_valid_time();
+		T::BenchmarkHelper::setup_currency();
+
+		let case_index = helpers::create_commit_reveal_case::<T>();
+		helpers::seat_jury::<T>(case_index, 1);
+
+		let origin = T::EnsurePerson::try_successful_origin(&MOB_CONTEXT)
+			.map_err(|_| BenchmarkError::Weightless)?;
+		let voter_alias = T::EnsurePerson::ensure_origin(origin.clone(), &MOB_CONTEXT).unwrap();
+		let commitment =
+			Pallet::<T>::vote_commitment(&voter_alias, &Judgement::Contempt, &[0u8; 32]);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, case_index, commitment);
+
+		assert_eq!(Commitments::<T>::get(case_index, voter_alias), Some(commitment));
+		assert_last_event::<T>(Event::VoteCommitted { case_index, voter: voter_alias }.into());
+
+		Ok(())
+	}
+
+	// Worst case: Contempt vote that ripens the case.
+	#[benchmark]
+	fn reveal_vote() -> Result<(), BenchmarkError> {
+		T::BenchmarkHelper::set_valid_time();
+		T::BenchmarkHelper::setup_currency();
+
+		let case_index = helpers::create_voting_case::<T>();
+		helpers::seat_jury::<T>(case_index, 1);
+
+		// Force the ripening branch: pass the duration check, and make the result definitive.
+		OpenCases::<T>::mutate(case_index, |maybe_case| {
+			if let Some(case) = maybe_case {
+				case.since = 0;
+			}
+		});
+		T::EnsurePerson::set_active_count(1);
+
+		let origin = T::EnsurePerson::try_successful_origin(&MOB_CONTEXT)
+			.map_err(|_| BenchmarkError::Weightless)?;
+		let voter_alias = T::EnsurePerson::ensure_origin(origin.clone(), &MOB_CONTEXT).unwrap();
+
+		let opinion = Judgement::Contempt;
+		let salt = [0u8; 32];
+		let now = T::Clock::now().as_secs();
+		RevealPeriods::<T>::insert(case_index, RevealPeriod { since: now, until: now + 1 });
+		Commitments::<T>::insert(
+			case_index,
+			voter_alias,
+			Pallet::<T>::vote_commitment(&voter_alias, &opinion, &salt),
+		);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, case_index, opinion, salt);
+
+		assert!(!Commitments::<T>::contains_key(case_index, voter_alias));
+		assert!(Votes::<T>::contains_key(case_index, voter_alias));
+		assert!(RipeCases::<T>::contains_key(case_index));
+		assert_last_event::<T>(Event::Voted { case_index, voter: voter_alias, opinion }.into());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn clean_commitment() -> Result<(), BenchmarkError> {
+		T::BenchmarkHelper::set_valid_time();
+		T::BenchmarkHelper::setup_currency();
+
+		let case_index = helpers::create_done_case::<T>(vec![], 0);
+		let now = T::Clock::now().as_secs();
+		RevealPeriods::<T>::insert(case_index, RevealPeriod { since: 0, until: now });
+		Commitments::<T>::insert(case_index, PERSON_0_ALIAS, T::Hash::default());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(account("acc", 0, 0)), case_index, PERSON_0_ALIAS);
+
+		assert!(!Commitments::<T>::contains_key(case_index, PERSON_0_ALIAS));
+		assert_last_event::<T>(
+			Event::CommitmentCleaned { case_index, voter: PERSON_0_ALIAS }.into(),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn replace_absent_jurors() -> Result<(), BenchmarkError> {
+		T::BenchmarkHelper::set_valid_time();
+		T::BenchmarkHelper::setup_currency();
+
+		let case_index = helpers::create_voting_case::<T>();
+		helpers::seat_jury::<T>(case_index, 2);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(account("acc", 0, 0)), case_index);
+
+		assert_last_event::<T>(Event::JurorsReplaced { case_index, seats: 2 }.into());
+
+		Ok(())
+	}
+
+	// Worst case: a jury is drawn for the appeal case, which is voted on through commit–reveal.
+	#[benchmark]
+	fn appeal() -> Result<(), BenchmarkError> {
+		T::BenchmarkHelper::set_valid_time();
+		T::BenchmarkHelper::setup_currency();
+
+		let appealable_until = T::Clock::now().as_secs().saturating_add(1);
+		let case_index = helpers::create_appealable_case::<T>(appealable_until);
+		RevealPeriods::<T>::insert(case_index, RevealPeriod { since: 0, until: 0 });
+		let appellant = funded_appellant::<T>();
+		T::EnsurePerson::set_active_count(T::AppealJurySize::get().saturating_add(1));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(appellant.clone()), case_index);
+
+		let appeal_index = CaseCount::<T>::get() - 1;
+		assert!(Juries::<T>::contains_key(appeal_index));
+		assert!(RevealPeriods::<T>::contains_key(appeal_index));
+		assert_last_event::<T>(Event::CaseAppealed { case_index, appeal_index, appellant }.into());
+
+		Ok(())
+	}
+
 	#[benchmark]
 	fn on_poll_base() -> Result<(), BenchmarkError> {
 		T::BenchmarkHelper::setup_currency();
@@ -34,6 +34,7 @@ mod tests;
 
 #[cfg(feature = "runtime-benchmarks")]
 pub mod benchmarking;
+pub mod migration;
 pub mod runtime_api;
 #[cfg(any(test, feature = "runtime-benchmarks"))]
 mod testing_utils;
@@ -50,8 +51,8 @@ pub mod pallet {
 		pallet_prelude::*,
 		traits::{
 			fungible::{Inspect, Mutate, MutateHold},
-			tokens::{Precision, Preservation},
-			EnsureOriginWithArg, UnixTime,
+			tokens::{Fortitude, Precision, Preservation, Restriction},
+			EnsureOriginWithArg, Randomness, UnixTime,
 		},
 		weights::WeightMeter,
 		PalletId,
@@ -60,7 +61,7 @@ pub mod pallet {
 	use indiv_support::traits::CountedMembers;
 	use sp_arithmetic::traits::{SaturatedConversion, Saturating};
 	use sp_runtime::{
-		traits::{AccountIdConversion, Dispatchable, Zero},
+		traits::{AccountIdConversion, Dispatchable, Hash, Zero},
 		Perbill, Percent,
 	};
 	use xcm::v5::Location;
@@ -68,10 +69,14 @@ pub mod pallet {
 	pub const MOB_CONTEXT: Context = *b"pop:polkadot.network/mob-rule   ";
 	const LOG_TARGET: &str = "runtime::mob-rule";
 
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	pub(crate) type BalanceOf<T> =
 		<<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
 
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
@@ -151,6 +156,63 @@ pub mod pallet {
 		#[pallet::constant]
 		type MinimumVoterThreshold: Get<u32>;
 
+		/// The number of seconds during which votes on a commit–reveal case can be committed,
+		/// counted from the moment commit–reveal voting was enabled on the case.
+		#[pallet::constant]
+		type CommitDuration: Get<u32>;
+
+		/// The number of seconds following the commit period during which committed votes can be
+		/// revealed.
+		#[pallet::constant]
+		type RevealDuration: Get<u32>;
+
+		/// The number of blocks a voter is penalized for when failing to reveal a committed vote.
+		/// The penalty is disabled when zero.
+		#[pallet::constant]
+		type UnrevealedPenaltyDuration: Get<BlockNumberFor<Self>>;
+
+		/// Randomness beacon used to draw the juries of new cases. It must not be known when the
+		/// case is opened, as whoever knows it can tell who sits on the jury.
+		type Randomness: Randomness<[u8; 32], BlockNumberFor<Self>>;
+
+		/// The number of jurors drawn for each new case. Cases created while there are no more
+		/// active voters than this are open to all of them instead.
+		#[pallet::constant]
+		type JurySize: Get<u32>;
+
+		/// The number of seconds jurors have to vote before the seats of those who did not can be
+		/// given to newly drawn jurors.
+		#[pallet::constant]
+		type JurorTimeout: Get<u32>;
+
+		/// How the votes on cases are weighted in their tallies.
+		#[pallet::constant]
+		type VoteWeighting: Get<VoteWeighting>;
+
+		/// The deposit held from the appellant of a verdict until the appeal is decided. It is
+		/// returned if the appeal succeeds and moved to the pot otherwise.
+		#[pallet::constant]
+		type AppealDeposit: Get<BalanceOf<Self>>;
+
+		/// The minimum share of the vote weight in favour of a statement for a failed verdict on
+		/// it to be appealable.
+		#[pallet::constant]
+		type AppealThreshold: Get<Percent>;
+
+		/// The number of seconds after a case ripened during which its verdict can be appealed.
+		/// Verdicts cannot be appealed when zero.
+		#[pallet::constant]
+		type AppealPeriod: Get<u32>;
+
+		/// The number of jurors drawn for each appeal case.
+		#[pallet::constant]
+		type AppealJurySize: Get<u32>;
+
+		/// The minimum number of votes as a percentage of the electorate an appeal case must
+		/// receive before a verdict can be reached.
+		#[pallet::constant]
+		type AppealTurnoutPercentage: Get<Percent>;
+
 		/// Benchmark helper for creating test data.
 		#[cfg(feature = "runtime-benchmarks")]
 		type BenchmarkHelper: crate::benchmarking::BenchmarkHelper<Self>;
@@ -171,6 +233,8 @@ pub mod pallet {
 		/// The Pallet has reserved it for storing users' credit until payout transfer is
 		/// triggered.
 		Credit,
+		/// The Pallet has reserved it as the deposit of an appeal until the appeal is decided.
+		Appeal,
 	}
 
 	#[derive(PartialEq, Eq, Clone, Default, Encode, Decode, Debug, TypeInfo, MaxEncodedLen)]
@@ -185,6 +249,53 @@ pub mod pallet {
 		pub credit: Balance,
 	}
 
+	/// How the votes on a case are weighted in its tally.
+	#[derive(
+		PartialEq, Eq, Clone, Copy, Default, Encode, Decode, Debug, TypeInfo, MaxEncodedLen,
+	)]
+	pub enum VoteWeighting {
+		/// Every vote weighs one.
+		#[default]
+		Equal,
+		/// Votes weigh from `min` to `max` in proportion to the share of the cleaned votes of the
+		/// voter which agreed with the verdict. Votes of voters with fewer than `min_record`
+		/// cleaned votes weigh `min`.
+		TrackRecord {
+			/// The weight of a vote of a voter who was never correct.
+			min: u32,
+			/// The weight of a vote of a voter who was always correct.
+			max: u32,
+			/// The number of cleaned votes a voter needs before their record is taken into
+			/// account.
+			min_record: u32,
+		},
+	}
+
+	impl VoteWeighting {
+		/// The weight of a vote cast by a voter with the voting record `credit`.
+		pub fn weight<Balance>(&self, credit: &MobCredit<Balance>) -> u32 {
+			match *self {
+				Self::Equal => 1,
+				Self::TrackRecord { min, max, min_record } => {
+					if credit.cleaned < min_record.max(1) {
+						return min;
+					}
+					let accuracy =
+						Perbill::from_rational(credit.correct.min(credit.cleaned), credit.cleaned);
+					min.saturating_add(accuracy.mul_floor(max.saturating_sub(min)))
+				},
+			}
+		}
+
+		/// The maximum weight of a single vote.
+		pub fn max_weight(&self) -> u32 {
+			match *self {
+				Self::Equal => 1,
+				Self::TrackRecord { min, max, .. } => min.max(max),
+			}
+		}
+	}
+
 	#[derive(PartialEq, Eq, Clone, Default, Encode, Decode, Debug, TypeInfo, MaxEncodedLen)]
 	pub struct VoteTally {
 		/// Number of "aye" votes.
@@ -194,6 +305,13 @@ pub mod pallet {
 
 		/// Number of voters that believe the case is contemptuous.
 		pub contempt: u32,
+
+		/// Total weight of "aye" votes.
+		pub aye_weight: u32,
+		/// Total weight of "nay" votes.
+		pub nay_weight: u32,
+		/// Total weight of "contempt" votes.
+		pub contempt_weight: u32,
 	}
 
 	pub struct VoteCountResult {
@@ -201,8 +319,10 @@ pub mod pallet {
 		pub verdict: Judgement,
 		/// The weight of "aye" votes.
 		pub aye_weight: u32,
-		/// The weight of "nay" votes.
+		/// The weight of "nay" votes, including "contempt" votes.
 		pub nay_weight: u32,
+		/// The weight of "contempt" votes.
+		pub contempt_weight: u32,
 		/// The turnout at the moment when the votes were counted.
 		pub turnout: Percent,
 		/// Total number of votes cast.
@@ -218,6 +338,7 @@ pub mod pallet {
 				verdict: Judgement::Truth(Truth::False),
 				aye_weight: 0,
 				nay_weight: 0,
+				contempt_weight: 0,
 				turnout: Percent::from_percent(0),
 				voter_count: 0,
 				definitive: false,
@@ -229,11 +350,20 @@ pub mod pallet {
 			verdict: Judgement,
 			aye_weight: u32,
 			nay_weight: u32,
+			contempt_weight: u32,
 			turnout: Percent,
 			voter_count: u32,
 			definitive: bool,
 		) -> Self {
-			Self { verdict, aye_weight, nay_weight, turnout, voter_count, definitive }
+			Self {
+				verdict,
+				aye_weight,
+				nay_weight,
+				contempt_weight,
+				turnout,
+				voter_count,
+				definitive,
+			}
 		}
 
 		/// Return whether the current vote is passing at a specific point in time in the case's
@@ -254,30 +384,36 @@ pub mod pallet {
 	}
 
 	impl VoteTally {
-		pub fn count(&mut self, judgement: &Judgement) {
+		pub fn count(&mut self, judgement: &Judgement, weight: u32) {
 			use indiv_support::traits::{Judgement::*, Truth::*};
-			match judgement {
-				Truth(True) => self.aye.saturating_inc(),
-				Truth(False) => self.nay.saturating_inc(),
-				Contempt => self.contempt.saturating_inc(),
-			}
+			let (count, total_weight) = match judgement {
+				Truth(True) => (&mut self.aye, &mut self.aye_weight),
+				Truth(False) => (&mut self.nay, &mut self.nay_weight),
+				Contempt => (&mut self.contempt, &mut self.contempt_weight),
+			};
+			count.saturating_inc();
+			total_weight.saturating_accrue(weight);
 		}
 
-		pub fn discount(&mut self, judgement: Judgement) {
+		pub fn discount(&mut self, judgement: Judgement, weight: u32) {
 			use indiv_support::traits::{Judgement::*, Truth::*};
-			match judgement {
-				Truth(True) => self.aye.saturating_dec(),
-				Truth(False) => self.nay.saturating_dec(),
-				Contempt => self.contempt.saturating_dec(),
-			}
+			let (count, total_weight) = match judgement {
+				Truth(True) => (&mut self.aye, &mut self.aye_weight),
+				Truth(False) => (&mut self.nay, &mut self.nay_weight),
+				Contempt => (&mut self.contempt, &mut self.contempt_weight),
+			};
+			count.saturating_dec();
+			total_weight.saturating_reduce(weight);
 		}
 	}
 
 	pub type Dissent = Percent;
 
 	impl VoteTally {
-		/// Count the votes and return the vote tally result.
-		fn collapsed(&self, active_voters: u32) -> VoteCountResult {
+		/// Count the votes and return the vote tally result. The verdict is reached on the
+		/// weights of the votes, and is definitive once the votes of the remaining voters, each
+		/// weighing at most `max_weight`, cannot flip it.
+		fn collapsed(&self, active_voters: u32, max_weight: u32) -> VoteCountResult {
 			use indiv_support::traits::{Judgement::*, Truth::*};
 			let vote_count = self.aye.saturating_add(self.nay).saturating_add(self.contempt);
 			if vote_count == 0 {
@@ -285,18 +421,19 @@ pub mod pallet {
 			}
 
 			// Contempt votes have the same weight as "nay" votes.
-			let nay_weight = self.nay.saturating_add(self.contempt);
+			let nay_weight = self.nay_weight.saturating_add(self.contempt_weight);
 
 			let remaining_voters = active_voters.saturating_sub(vote_count);
+			let remaining_weight = remaining_voters.saturating_mul(max_weight);
 			let mut definitive = remaining_voters == 0;
-			let verdict = if self.aye > nay_weight {
-				definitive |= self.aye.saturating_sub(nay_weight) > remaining_voters;
+			let verdict = if self.aye_weight > nay_weight {
+				definitive |= self.aye_weight.saturating_sub(nay_weight) > remaining_weight;
 				Truth(True)
 			} else {
-				definitive |= nay_weight.saturating_sub(self.aye) > remaining_voters;
-				// The case is contemptuous if the vote fails and there are more `contempt` votes
-				// than `nay`s.
-				if self.contempt >= self.nay {
+				definitive |= nay_weight.saturating_sub(self.aye_weight) > remaining_weight;
+				// The case is contemptuous if the vote fails and `contempt` votes weigh more than
+				// `nay`s.
+				if self.contempt_weight >= self.nay_weight {
 					Contempt
 				} else {
 					Truth(False)
@@ -304,8 +441,9 @@ pub mod pallet {
 			};
 			VoteCountResult::new(
 				verdict,
-				self.aye,
+				self.aye_weight,
 				nay_weight,
+				self.contempt_weight,
 				Percent::from_rational(vote_count, active_voters),
 				vote_count,
 				definitive,
@@ -326,13 +464,18 @@ pub mod pallet {
 	pub struct OpenCase<RuntimeCall> {
 		pub(crate) since: SecsSinceGenesis,
 		pub(crate) details: CaseDetails<RuntimeCall>,
-		tally: VoteTally,
+		pub(crate) tally: VoteTally,
+		pub(crate) appeal: bool,
 	}
 
 	#[derive(PartialEq, Eq, Clone, Encode, Decode, Debug, TypeInfo, MaxEncodedLen)]
 	pub struct RipeCase<RuntimeCall> {
 		pub(crate) details: CaseDetails<RuntimeCall>,
 		pub(crate) verdict: Judgement,
+		/// The moment until which the verdict can be appealed, if it can be appealed at all.
+		pub(crate) appealable_until: Option<SecsSinceGenesis>,
+		/// The case in which the pending appeal against the verdict is judged.
+		pub(crate) appeal: Option<CaseIndex>,
 	}
 
 	#[derive(PartialEq, Eq, Clone, Encode, Decode, Debug, TypeInfo, MaxEncodedLen)]
@@ -341,6 +484,63 @@ pub mod pallet {
 		pub(crate) verdict: Judgement,
 	}
 
+	/// The reveal period of a case voted on through commit–reveal. Votes can be committed until
+	/// the period starts and revealed until it ends.
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, Debug, TypeInfo, MaxEncodedLen)]
+	pub struct RevealPeriod {
+		/// The moment from which committed votes can be revealed.
+		pub since: SecsSinceGenesis,
+		/// The moment from which committed votes can no longer be revealed.
+		pub until: SecsSinceGenesis,
+	}
+
+	/// The jury drawn for a case.
+	///
+	/// Aliases cannot be enumerated, so every alias is instead assigned one of `population` seats
+	/// derived from the seed of the jury and the alias itself. The jurors are the aliases whose
+	/// seat falls within the window of `seats` seats starting at `first_seat`.
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, Debug, TypeInfo, MaxEncodedLen)]
+	pub struct Jury {
+		/// The seed from which the seats of aliases are derived.
+		pub seed: [u8; 32],
+		/// The number of seats, i.e. the number of active voters when the jury was drawn.
+		pub population: u32,
+		/// The number of jurors on the case.
+		pub size: u32,
+		/// The number of jurors who voted on the case.
+		pub cast: u32,
+		/// The first seat of the current window.
+		pub first_seat: u32,
+		/// The number of seats in the current window.
+		pub seats: u32,
+		/// The moment the current window was drawn.
+		pub drawn: SecsSinceGenesis,
+	}
+
+	impl Jury {
+		/// Return whether `alias` is seated within the current window of the jury.
+		pub fn seats(&self, alias: &Alias) -> bool {
+			let population = u64::from(self.population.max(1));
+			let hash = (self.seed, alias).using_encoded(sp_io::hashing::blake2_256);
+			let mut seat = [0u8; 8];
+			seat.copy_from_slice(&hash[..8]);
+			let seat = u64::from_le_bytes(seat) % population;
+			let offset = (seat + population - u64::from(self.first_seat) % population) % population;
+			offset < u64::from(self.seats)
+		}
+	}
+
+	/// An appeal against the verdict of a ripe case, judged in a case of its own.
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, Debug, TypeInfo, MaxEncodedLen)]
+	pub struct Appeal<AccountId, Balance> {
+		/// The case whose verdict is appealed.
+		pub case_index: CaseIndex,
+		/// The account which appealed the verdict.
+		pub appellant: AccountId,
+		/// The deposit held from the appellant.
+		pub deposit: Balance,
+	}
+
 	pub type CaseIndex = u32;
 	pub type RoundIndex = u32;
 	pub type VoteCount = u64;
@@ -402,6 +602,44 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// The reveal periods of cases voted on through commit–reveal. Cases without an entry are voted
+	/// on openly through `vote`.
+	#[pallet::storage]
+	pub type RevealPeriods<T> =
+		StorageMap<_, Blake2_128Concat, CaseIndex, RevealPeriod, OptionQuery>;
+
+	/// The vote commitments on commit–reveal cases which were not revealed yet, segregated by case
+	/// and voter.
+	#[pallet::storage]
+	pub type Commitments<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CaseIndex,
+		Blake2_128Concat,
+		Alias,
+		<T as frame_system::Config>::Hash,
+		OptionQuery,
+	>;
+
+	/// The juries drawn for cases. Cases without an entry are open to all active voters.
+	#[pallet::storage]
+	pub type Juries<T> = StorageMap<_, Blake2_128Concat, CaseIndex, Jury, OptionQuery>;
+
+	/// The weights of all votes cast, snapshotted when the votes were cast.
+	#[pallet::storage]
+	pub type VoteWeights<T> =
+		StorageDoubleMap<_, Blake2_128Concat, CaseIndex, Blake2_128Concat, Alias, u32, OptionQuery>;
+
+	/// The pending appeals, indexed by the case in which they are judged.
+	#[pallet::storage]
+	pub type Appeals<T> = StorageMap<
+		_,
+		Blake2_128Concat,
+		CaseIndex,
+		Appeal<<T as frame_system::Config>::AccountId, BalanceOf<T>>,
+		OptionQuery,
+	>;
+
 	/// The number of cases, open or recently closed, stored in the pallet.
 	#[pallet::storage]
 	pub type CaseCount<T> = StorageValue<_, CaseIndex, ValueQuery>;
@@ -574,6 +812,63 @@ pub mod pallet {
 			/// The alias whose penalty was cleared.
 			who: Alias,
 		},
+		/// A case has been opened for commit–reveal voting.
+		CommitRevealEnabled {
+			/// The case voted on through commit–reveal.
+			case_index: CaseIndex,
+			/// The moment from which committed votes can be revealed.
+			reveal_since: SecsSinceGenesis,
+			/// The moment from which committed votes can no longer be revealed.
+			reveal_until: SecsSinceGenesis,
+		},
+		/// A vote has been committed on a commit–reveal case.
+		VoteCommitted {
+			/// The case voted on.
+			case_index: CaseIndex,
+			/// The alias that committed the vote.
+			voter: Alias,
+		},
+		/// A commitment which was never revealed has been cleaned.
+		CommitmentCleaned {
+			/// The case voted on.
+			case_index: CaseIndex,
+			/// The alias that failed to reveal their vote.
+			voter: Alias,
+		},
+		/// A jury has been drawn for a case.
+		JuryDrawn {
+			/// The case the jury was drawn for.
+			case_index: CaseIndex,
+			/// The number of jurors on the case.
+			size: u32,
+		},
+		/// The jurors of a case who did not vote in time have been replaced.
+		JurorsReplaced {
+			/// The case whose jurors were replaced.
+			case_index: CaseIndex,
+			/// The number of newly drawn jurors.
+			seats: u32,
+		},
+		/// The verdict of a case has been appealed.
+		CaseAppealed {
+			/// The case whose verdict was appealed.
+			case_index: CaseIndex,
+			/// The case in which the appeal is judged.
+			appeal_index: CaseIndex,
+			/// The account which appealed the verdict.
+			appellant: T::AccountId,
+		},
+		/// An appeal has been decided.
+		AppealDecided {
+			/// The case whose verdict was appealed.
+			case_index: CaseIndex,
+			/// The case in which the appeal was judged.
+			appeal_index: CaseIndex,
+			/// The final verdict on the appealed case.
+			verdict: Judgement,
+			/// Whether the appeal overturned the verdict.
+			successful: bool,
+		},
 	}
 
 	#[pallet::error]
@@ -618,6 +913,32 @@ pub mod pallet {
 		UnderPenalty,
 		/// The open case expiration is disabled due to insufficient active voters.
 		CaseExpirationDisabled,
+		/// The case is voted on through commit–reveal.
+		CommitRevealCase,
+		/// The case is not voted on through commit–reveal.
+		NotCommitReveal,
+		/// The commit period of the case is over.
+		CommitPeriodOver,
+		/// The case is not in its reveal period.
+		NotRevealPeriod,
+		/// The commitment does not exist.
+		NoSuchCommitment,
+		/// The revealed vote does not match its commitment.
+		BadReveal,
+		/// The reveal period of the case is not over yet.
+		RevealOngoing,
+		/// The voter is not a juror on the case.
+		NotJuror,
+		/// The case is open to all active voters.
+		NoJury,
+		/// All jurors of the case already voted.
+		JuryComplete,
+		/// The jurors of the case still have time to vote.
+		JurorsNotLate,
+		/// The verdict of the case cannot be appealed.
+		NotAppealable,
+		/// The verdict of the case can still be appealed or is being appealed.
+		AppealOngoing,
 	}
 
 	#[pallet::call]
@@ -636,42 +957,27 @@ pub mod pallet {
 					!VotingPenalties::<T>::contains_key(alias),
 				Error::<T>::UnderPenalty
 			);
+			ensure!(!RevealPeriods::<T>::contains_key(case_index), Error::<T>::CommitRevealCase);
 			let mut case = OpenCases::<T>::take(case_index).ok_or(Error::<T>::NotOpen)?;
 			let mut credit = Credits::<T>::get(alias);
-			let (_first_time, pays) = match Votes::<T>::get(case_index, alias) {
+			let (_first_time, pays, weight) = match Votes::<T>::get(case_index, alias) {
 				Some(old_opinion) => {
-					case.tally.discount(old_opinion);
-					(false, Pays::Yes)
+					// A changed vote keeps the weight it was cast with.
+					let weight = VoteWeights::<T>::get(case_index, alias).unwrap_or(1);
+					case.tally.discount(old_opinion, weight);
+					(false, Pays::Yes, weight)
 				},
 				None => {
+					Self::take_jury_seat(case_index, &alias)?;
 					credit.voted.saturating_inc();
-					(true, Pays::No)
+					(true, Pays::No, T::VoteWeighting::get().weight(&credit))
 				},
 			};
-			case.tally.count(&opinion);
+			case.tally.count(&opinion, weight);
 			Votes::<T>::insert(case_index, alias, opinion);
+			VoteWeights::<T>::insert(case_index, alias, weight);
 
-			let secs = T::Clock::now().as_secs().saturating_sub(case.since);
-
-			let active_voters = T::EnsurePerson::active_count();
-			let vote_count_result = case.tally.collapsed(active_voters);
-
-			let minimum_turnout_reached = vote_count_result.turnout >=
-				T::MinTurnoutPercentage::get() &&
-				vote_count_result.voter_count >= T::MinTurnoutNominal::get();
-			let minimum_duration_elapsed = secs > T::MinCaseDuration::get().into();
-
-			if minimum_turnout_reached &&
-				minimum_duration_elapsed &&
-				(vote_count_result.definitive ||
-					vote_count_result.is_passing(T::MaxVotingDuration::get().into(), secs))
-			{
-				let ripe_case =
-					RipeCase { details: case.details, verdict: vote_count_result.verdict };
-				RipeCases::<T>::insert(case_index, ripe_case);
-			} else {
-				OpenCases::<T>::insert(case_index, case);
-			}
+			Self::ripen_or_reopen(case_index, case);
 
 			Credits::<T>::insert(alias, credit);
 
@@ -691,7 +997,8 @@ pub mod pallet {
 			let case = Self::validate_close_case(case_index)?;
 			RipeCases::<T>::remove(case_index);
 
-			match case.details.callback.curry((case_index, case.details.context, case.verdict)) {
+			let (ticket, verdict) = Self::settle_appeal(case_index, case.verdict);
+			match case.details.callback.curry((ticket, case.details.context, verdict)) {
 				Err(_error) => {
 					// TODO: Report error in event, but codec::Error doesn't implement TypeInfo.
 					Self::deposit_event(Event::CallbackError);
@@ -737,6 +1044,8 @@ pub mod pallet {
 			ensure_none(origin)?;
 			Self::validate_reap_case(case_index)?;
 			DoneCases::<T>::remove(case_index);
+			RevealPeriods::<T>::remove(case_index);
+			Juries::<T>::remove(case_index);
 			Self::deposit_event(Event::CaseRemoved { case_index });
 			Ok(().into())
 		}
@@ -751,7 +1060,8 @@ pub mod pallet {
 			T::InterventionOrigin::ensure_origin_or_root(origin)?;
 			let case = OpenCases::<T>::take(case_index).ok_or(Error::<T>::NotOpen)?;
 
-			match case.details.callback.curry((case_index, case.details.context, verdict)) {
+			let (ticket, final_verdict) = Self::settle_appeal(case_index, verdict);
+			match case.details.callback.curry((ticket, case.details.context, final_verdict)) {
 				Err(_error) => {
 					// TODO: Report error in event, but codec::Error doesn't implement TypeInfo.
 					Self::deposit_event(Event::CallbackError);
@@ -1024,10 +1334,16 @@ pub mod pallet {
 			let case = Self::validate_timeout_case(case_index)?;
 			OpenCases::<T>::remove(case_index);
 
-			let active_voters = T::EnsurePerson::active_count();
-			let vote_count_result = case.tally.collapsed(active_voters);
+			let vote_count_result = case
+				.tally
+				.collapsed(Self::electorate(case_index), T::VoteWeighting::get().max_weight());
 
-			let ripe_case = RipeCase { details: case.details, verdict: vote_count_result.verdict };
+			let ripe_case = RipeCase {
+				details: case.details,
+				verdict: vote_count_result.verdict,
+				appealable_until: Self::appealable_until(case.appeal, &vote_count_result),
+				appeal: None,
+			};
 			RipeCases::<T>::insert(case_index, ripe_case);
 
 			Self::deposit_event(Event::CaseClosed {
@@ -1045,31 +1361,11 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			let _ = ensure_signed(origin)?;
 			let case = OpenCases::<T>::take(case_index).ok_or(Error::<T>::NotOpen)?;
-			let secs = T::Clock::now().as_secs().saturating_sub(case.since);
 
-			let active_voters = T::EnsurePerson::active_count();
-			let vote_count_result = case.tally.collapsed(active_voters);
-
-			let minimum_turnout_reached = vote_count_result.turnout >=
-				T::MinTurnoutPercentage::get() &&
-				vote_count_result.voter_count >= T::MinTurnoutNominal::get();
-			let minimum_duration_elapsed = secs > T::MinCaseDuration::get().into();
-
-			if minimum_turnout_reached &&
-				minimum_duration_elapsed &&
-				(vote_count_result.definitive ||
-					vote_count_result.is_passing(T::MaxVotingDuration::get().into(), secs))
-			{
-				let ripe_case =
-					RipeCase { details: case.details, verdict: vote_count_result.verdict };
-				RipeCases::<T>::insert(case_index, ripe_case);
-				Self::deposit_event(Event::CaseTouched { case_index, ripened: true });
-				Ok(Pays::No.into())
-			} else {
-				OpenCases::<T>::insert(case_index, case);
-				Self::deposit_event(Event::CaseTouched { case_index, ripened: false });
-				Ok(Pays::Yes.into())
-			}
+			let ripened = Self::ripen_or_reopen(case_index, case);
+			Self::deposit_event(Event::CaseTouched { case_index, ripened });
+			let pays = if ripened { Pays::No } else { Pays::Yes };
+			Ok(pays.into())
 		}
 
 		#[pallet::weight(T::WeightInfo::clear_voting_penalty())]
@@ -1100,6 +1396,180 @@ pub mod pallet {
 			Self::clean_vote_inner(case_index, voter)?;
 			Ok(Pays::No.into())
 		}
+
+		/// Commit a vote on a commit–reveal case before its reveal period starts. The
+		/// `commitment` must be computed through `vote_commitment`.
+		///
+		/// Feeless on the first commitment of the voter on the case.
+		#[pallet::weight(T::WeightInfo::commit_vote())]
+		#[pallet::call_index(18)]
+		pub fn commit_vote(
+			origin: OriginFor<T>,
+			case_index: CaseIndex,
+			commitment: T::Hash,
+		) -> DispatchResultWithPostInfo {
+			let alias = T::EnsurePerson::ensure_origin(origin, &MOB_CONTEXT)?;
+			ensure!(OpenCases::<T>::contains_key(case_index), Error::<T>::NotOpen);
+			let period = RevealPeriods::<T>::get(case_index).ok_or(Error::<T>::NotCommitReveal)?;
+			ensure!(T::Clock::now().as_secs() < period.since, Error::<T>::CommitPeriodOver);
+
+			let pays = if Commitments::<T>::contains_key(case_index, alias) {
+				Pays::Yes
+			} else {
+				Self::take_jury_seat(case_index, &alias)?;
+				Pays::No
+			};
+			Commitments::<T>::insert(case_index, alias, commitment);
+
+			Self::deposit_event(Event::VoteCommitted { case_index, voter: alias });
+			Ok(pays.into())
+		}
+
+		/// Reveal a vote committed on a commit–reveal case during its reveal period. The vote is
+		/// counted towards the tally if the case is still open, and is otherwise only recorded so
+		/// that it can be claimed once the case is done.
+		#[pallet::weight(T::WeightInfo::reveal_vote())]
+		#[pallet::call_index(19)]
+		pub fn reveal_vote(
+			origin: OriginFor<T>,
+			case_index: CaseIndex,
+			opinion: Judgement,
+			salt: [u8; 32],
+		) -> DispatchResultWithPostInfo {
+			let alias = T::EnsurePerson::ensure_origin(origin, &MOB_CONTEXT)?;
+			ensure!(
+				!matches!(opinion, Judgement::Contempt) ||
+					!VotingPenalties::<T>::contains_key(alias),
+				Error::<T>::UnderPenalty
+			);
+			let period = RevealPeriods::<T>::get(case_index).ok_or(Error::<T>::NotCommitReveal)?;
+			let now = T::Clock::now().as_secs();
+			ensure!(period.since <= now && now < period.until, Error::<T>::NotRevealPeriod);
+			let commitment =
+				Commitments::<T>::take(case_index, alias).ok_or(Error::<T>::NoSuchCommitment)?;
+			ensure!(
+				commitment == Self::vote_commitment(&alias, &opinion, &salt),
+				Error::<T>::BadReveal
+			);
+
+			let weight = Credits::<T>::mutate(alias, |credit| {
+				credit.voted.saturating_inc();
+				T::VoteWeighting::get().weight(credit)
+			});
+			Votes::<T>::insert(case_index, alias, opinion);
+			VoteWeights::<T>::insert(case_index, alias, weight);
+			if let Some(mut case) = OpenCases::<T>::take(case_index) {
+				case.tally.count(&opinion, weight);
+				Self::ripen_or_reopen(case_index, case);
+			}
+
+			Self::deposit_event(Event::Voted { case_index, voter: alias, opinion });
+			Ok(Pays::No.into())
+		}
+
+		/// Remove a commitment which was not revealed during the reveal period of its case and
+		/// penalize the voter for `UnrevealedPenaltyDuration` blocks.
+		///
+		/// Origin must be signed.
+		#[pallet::weight(T::WeightInfo::clean_commitment())]
+		#[pallet::call_index(20)]
+		pub fn clean_commitment(
+			origin: OriginFor<T>,
+			case_index: CaseIndex,
+			voter: Alias,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			ensure!(
+				Commitments::<T>::contains_key(case_index, voter),
+				Error::<T>::NoSuchCommitment
+			);
+			Self::ensure_reveal_over(case_index)?;
+			Commitments::<T>::remove(case_index, voter);
+
+			let duration = T::UnrevealedPenaltyDuration::get();
+			if !duration.is_zero() {
+				// Penalties are recorded by their start and last for `VotingPenaltyDuration`, so
+				// the start is shifted for this one to expire after `duration` instead.
+				let start = frame_system::Pallet::<T>::block_number()
+					.saturating_add(duration)
+					.saturating_sub(T::VotingPenaltyDuration::get());
+				VotingPenalties::<T>::mutate(voter, |penalty| {
+					*penalty = Some(penalty.map_or(start, |current| current.max(start)));
+				});
+			}
+
+			Self::deposit_event(Event::CommitmentCleaned { case_index, voter });
+			Ok(Pays::No.into())
+		}
+
+		/// Give the seats of the jurors of an open case who did not vote within `JurorTimeout`
+		/// seconds to newly drawn jurors. The jurors who did not vote lose their seat.
+		///
+		/// Origin must be signed.
+		#[pallet::weight(T::WeightInfo::replace_absent_jurors())]
+		#[pallet::call_index(21)]
+		pub fn replace_absent_jurors(
+			origin: OriginFor<T>,
+			case_index: CaseIndex,
+		) -> DispatchResultWithPostInfo {
+			let _ = ensure_signed(origin)?;
+			ensure!(OpenCases::<T>::contains_key(case_index), Error::<T>::NotOpen);
+			let mut jury = Juries::<T>::get(case_index).ok_or(Error::<T>::NoJury)?;
+			let now = T::Clock::now().as_secs();
+			ensure!(
+				now >= jury.drawn.saturating_add(T::JurorTimeout::get().into()),
+				Error::<T>::JurorsNotLate
+			);
+			// Votes can no longer be cast once the reveal period of a commit–reveal case started.
+			ensure!(
+				RevealPeriods::<T>::get(case_index).is_none_or(|period| now < period.since),
+				Error::<T>::CommitPeriodOver
+			);
+			let absent = jury.size.saturating_sub(jury.cast);
+			ensure!(absent > 0, Error::<T>::JuryComplete);
+
+			jury.first_seat = jury.first_seat.saturating_add(jury.seats) % jury.population.max(1);
+			jury.seats = absent.min(jury.population);
+			jury.drawn = now;
+			Juries::<T>::insert(case_index, &jury);
+
+			Self::deposit_event(Event::JurorsReplaced { case_index, seats: jury.seats });
+			Ok(Pays::No.into())
+		}
+
+		/// Appeal the failed verdict of a ripe case within `AppealPeriod` seconds of it ripening,
+		/// holding `AppealDeposit` from the appellant. The statement is judged again in an appeal
+		/// case with a larger jury and a higher minimum turnout. The verdict of the appealed case
+		/// is only overturned if the appeal case finds the statement true.
+		///
+		/// Origin must be signed.
+		#[pallet::weight(T::WeightInfo::appeal())]
+		#[pallet::call_index(22)]
+		pub fn appeal(origin: OriginFor<T>, case_index: CaseIndex) -> DispatchResult {
+			let appellant = ensure_signed(origin)?;
+			let mut case = RipeCases::<T>::get(case_index).ok_or(Error::<T>::NotRipe)?;
+			let now = T::Clock::now().as_secs();
+			ensure!(
+				case.appeal.is_none() && case.appealable_until.is_some_and(|until| now < until),
+				Error::<T>::NotAppealable
+			);
+
+			let deposit = T::AppealDeposit::get();
+			T::Currency::hold(&HoldReason::Appeal.into(), &appellant, deposit)?;
+
+			// The appeal is voted on the same way as the appealed case.
+			let commit_reveal = RevealPeriods::<T>::contains_key(case_index);
+			let appeal_index = Self::open_case(case.details.clone(), true, commit_reveal);
+			Appeals::<T>::insert(
+				appeal_index,
+				Appeal { case_index, appellant: appellant.clone(), deposit },
+			);
+			case.appeal = Some(appeal_index);
+			RipeCases::<T>::insert(case_index, case);
+
+			Self::deposit_event(Event::CaseAppealed { case_index, appeal_index, appellant });
+			Ok(())
+		}
 	}
 
 	#[pallet::extra_constants]
@@ -1175,6 +1645,24 @@ pub mod pallet {
 				"the time when a case becomes reaped (`CaseTimeoutSecs`) should be longer than the \
 				time given to voters to claim their votes (`VotesOpenForClaimsDuration`)",
 			);
+			assert!(
+				T::CommitDuration::get().saturating_add(T::RevealDuration::get()) <=
+					T::MaxVotingDuration::get(),
+				"votes on commit–reveal cases should be revealed (`CommitDuration` + \
+				`RevealDuration`) before the cases time out (`MaxVotingDuration`)",
+			);
+			assert!(
+				T::AppealJurySize::get() >= T::JurySize::get() &&
+					T::AppealTurnoutPercentage::get() >= T::MinTurnoutPercentage::get(),
+				"appeal cases should need at least the quorum of first-round cases \
+				(`AppealJurySize`, `AppealTurnoutPercentage`)",
+			);
+			if let VoteWeighting::TrackRecord { min, max, .. } = T::VoteWeighting::get() {
+				assert!(
+					min > 0 && min <= max,
+					"votes should weigh at least one and at most their maximum weight",
+				);
+			}
 		}
 
 		fn on_poll(_: BlockNumberFor<T>, weight_meter: &mut WeightMeter) {
@@ -1203,6 +1691,10 @@ pub mod pallet {
 						// Case is still open, not yet ripe
 						Error::NotRipe if OpenCases::<T>::contains_key(case_index) =>
 							InvalidTransaction::Future,
+						// Case is ripe but votes can still be revealed
+						Error::RevealOngoing => InvalidTransaction::Future,
+						// Case is ripe but its verdict can still be or is being appealed
+						Error::AppealOngoing => InvalidTransaction::Future,
 						_ => InvalidTransaction::Stale,
 					})?;
 					build_transaction_validity("PersonhoodMobRuleCaseClosing", case_index)
@@ -1233,8 +1725,8 @@ pub mod pallet {
 				},
 				Call::force_ripen_case { case_index } => {
 					Self::validate_timeout_case(*case_index).map_err(|e| match e {
-						// Case is open but voting duration not reached yet
-						Error::Recent => InvalidTransaction::Future,
+						// Case is open but voting or reveal period not over yet
+						Error::Recent | Error::RevealOngoing => InvalidTransaction::Future,
 						_ => InvalidTransaction::Stale,
 					})?;
 					build_transaction_validity("PersonhoodMobRuleCaseTimeout", case_index)
@@ -1309,6 +1801,7 @@ pub mod pallet {
 			let mut correct_votes = 0;
 			for case_index in &case_indices {
 				let vote = Votes::<T>::take(case_index, voter).ok_or(Error::<T>::NoSuchVote)?;
+				VoteWeights::<T>::remove(case_index, voter);
 				let case = DoneCases::<T>::get(case_index).ok_or(Error::<T>::NotDone)?;
 				if case.verdict.matches_intent(vote) ||
 					(case.verdict == Judgement::Contempt &&
@@ -1336,7 +1829,208 @@ pub mod pallet {
 		fn validate_close_case(
 			case_index: CaseIndex,
 		) -> Result<RipeCase<<T as frame_system::Config>::RuntimeCall>, Error<T>> {
-			RipeCases::<T>::get(case_index).ok_or(Error::<T>::NotRipe)
+			let case = RipeCases::<T>::get(case_index).ok_or(Error::<T>::NotRipe)?;
+			// Votes on commit–reveal cases can still be revealed after the case ripened, so the
+			// case must not be closed before those votes are recorded.
+			Self::ensure_reveal_over(case_index)?;
+			let now = T::Clock::now().as_secs();
+			ensure!(
+				case.appeal.is_none() && case.appealable_until.is_none_or(|until| until <= now),
+				Error::<T>::AppealOngoing
+			);
+			Ok(case)
+		}
+
+		/// The number of voters the turnout on a case is measured against: the size of its jury,
+		/// or all active voters if it has none.
+		fn electorate(case_index: CaseIndex) -> u32 {
+			Juries::<T>::get(case_index)
+				.map_or_else(T::EnsurePerson::active_count, |jury| jury.size)
+		}
+
+		/// Ensure `voter` is seated on the jury of the case, if any, and account for their vote.
+		///
+		/// As several aliases can be assigned the same seat, more aliases than `seats` can fall
+		/// within a window. Each window is drawn with as many seats as absent jurors, so capping
+		/// the jury at `size` votes caps the votes of every window at its `seats`.
+		fn take_jury_seat(case_index: CaseIndex, voter: &Alias) -> Result<(), Error<T>> {
+			let Some(mut jury) = Juries::<T>::get(case_index) else { return Ok(()) };
+			ensure!(jury.seats(voter), Error::<T>::NotJuror);
+			ensure!(jury.cast < jury.size, Error::<T>::JuryComplete);
+			jury.cast.saturating_inc();
+			Juries::<T>::insert(case_index, jury);
+			Ok(())
+		}
+
+		fn ensure_reveal_over(case_index: CaseIndex) -> Result<(), Error<T>> {
+			let now = T::Clock::now().as_secs();
+			ensure!(
+				RevealPeriods::<T>::get(case_index).is_none_or(|period| period.until <= now),
+				Error::<T>::RevealOngoing
+			);
+			Ok(())
+		}
+
+		/// Move an open case to `RipeCases` if its tally reached the minimum turnout after the
+		/// minimum case duration and yields a definitive or passing verdict, or store it back in
+		/// `OpenCases` otherwise. Returns whether the case was ripened.
+		fn ripen_or_reopen(
+			case_index: CaseIndex,
+			case: OpenCase<<T as frame_system::Config>::RuntimeCall>,
+		) -> bool {
+			let secs = T::Clock::now().as_secs().saturating_sub(case.since);
+
+			let vote_count_result = case
+				.tally
+				.collapsed(Self::electorate(case_index), T::VoteWeighting::get().max_weight());
+
+			let minimum_turnout = if case.appeal {
+				T::AppealTurnoutPercentage::get()
+			} else {
+				T::MinTurnoutPercentage::get()
+			};
+			let minimum_turnout_reached = vote_count_result.turnout >= minimum_turnout &&
+				vote_count_result.voter_count >= T::MinTurnoutNominal::get();
+			let minimum_duration_elapsed = secs > T::MinCaseDuration::get().into();
+
+			if minimum_turnout_reached &&
+				minimum_duration_elapsed &&
+				(vote_count_result.definitive ||
+					vote_count_result.is_passing(T::MaxVotingDuration::get().into(), secs))
+			{
+				let ripe_case = RipeCase {
+					details: case.details,
+					verdict: vote_count_result.verdict,
+					appealable_until: Self::appealable_until(case.appeal, &vote_count_result),
+					appeal: None,
+				};
+				RipeCases::<T>::insert(case_index, ripe_case);
+				true
+			} else {
+				OpenCases::<T>::insert(case_index, case);
+				false
+			}
+		}
+
+		/// The moment until which a verdict reached by `result` can be appealed, if it can be
+		/// appealed at all. Only the failed verdicts of cases which are not appeal cases themselves
+		/// and had at least `AppealThreshold` of the vote weight in favour can be appealed.
+		fn appealable_until(appeal: bool, result: &VoteCountResult) -> Option<SecsSinceGenesis> {
+			let total_weight = result.aye_weight.saturating_add(result.nay_weight);
+			let approval = Percent::from_rational(result.aye_weight, total_weight);
+			let appealable = !appeal &&
+				result.verdict != Judgement::Truth(Truth::True) &&
+				total_weight > 0 &&
+				approval >= T::AppealThreshold::get();
+			appealable
+				.then(|| T::Clock::now().as_secs().saturating_add(T::AppealPeriod::get().into()))
+		}
+
+		/// Decide the appeal judged in `case_index` through its `verdict`, if the case is an appeal
+		/// case. The appeal succeeds if the statement is found true, in which case the appealed
+		/// case is done with that verdict and the deposit is returned. Otherwise the appealed case
+		/// is done with its own verdict and the deposit is moved to the pot.
+		///
+		/// Returns the case and verdict the callback of the statement must be dispatched with.
+		fn settle_appeal(case_index: CaseIndex, verdict: Judgement) -> (CaseIndex, Judgement) {
+			let Some(appeal) = Appeals::<T>::take(case_index) else {
+				return (case_index, verdict);
+			};
+			let successful = verdict == Judgement::Truth(Truth::True);
+			let appealed_verdict =
+				RipeCases::<T>::take(appeal.case_index).map_or(verdict, |case| case.verdict);
+			let final_verdict = if successful { verdict } else { appealed_verdict };
+
+			let reason = HoldReason::Appeal.into();
+			let settled = if successful {
+				T::Currency::release(
+					&reason,
+					&appeal.appellant,
+					appeal.deposit,
+					Precision::BestEffort,
+				)
+			} else {
+				T::Currency::transfer_on_hold(
+					&reason,
+					&appeal.appellant,
+					&Self::mob_rule_pot_id(),
+					appeal.deposit,
+					Precision::BestEffort,
+					Restriction::Free,
+					Fortitude::Force,
+				)
+			};
+			if let Err(e) = settled {
+				log::error!(target: LOG_TARGET, "failed to settle the deposit of appeal {case_index}: {e:?}");
+			}
+
+			let done_case = DoneCase { since: T::Clock::now().as_secs(), verdict: final_verdict };
+			DoneCases::<T>::insert(appeal.case_index, done_case);
+			Self::deposit_event(Event::AppealDecided {
+				case_index: appeal.case_index,
+				appeal_index: case_index,
+				verdict: final_verdict,
+				successful,
+			});
+			(appeal.case_index, final_verdict)
+		}
+
+		/// Open a case judging the statement of `details`, drawing a jury for it if there are more
+		/// active voters than the jury size. Returns the index of the new case.
+		///
+		/// With `commit_reveal`, votes are committed through `commit_vote` for `CommitDuration`
+		/// seconds from the opening of the case and revealed through `reveal_vote` for
+		/// `RevealDuration` seconds after that. Only revealed votes are counted towards the tally
+		/// of the case.
+		fn open_case(
+			details: CaseDetails<<T as frame_system::Config>::RuntimeCall>,
+			appeal: bool,
+			commit_reveal: bool,
+		) -> CaseIndex {
+			let ticket = CaseCount::<T>::get();
+			CaseCount::<T>::mutate(|c| c.saturating_inc());
+			let since = T::Clock::now().as_secs();
+			let status = OpenCase { details, since, tally: Default::default(), appeal };
+			OpenCases::<T>::insert(ticket, status);
+			Self::deposit_event(Event::CaseCreated { case_index: ticket });
+
+			if commit_reveal {
+				let reveal_since = since.saturating_add(T::CommitDuration::get().into());
+				let reveal_until = reveal_since.saturating_add(T::RevealDuration::get().into());
+				RevealPeriods::<T>::insert(
+					ticket,
+					RevealPeriod { since: reveal_since, until: reveal_until },
+				);
+				Self::deposit_event(Event::CommitRevealEnabled {
+					case_index: ticket,
+					reveal_since,
+					reveal_until,
+				});
+			}
+
+			let population = T::EnsurePerson::active_count();
+			let size = if appeal { T::AppealJurySize::get() } else { T::JurySize::get() };
+			if population > size {
+				let seed = (MOB_CONTEXT, ticket).using_encoded(|s| T::Randomness::random(s).0);
+				let jury = Jury {
+					seed,
+					population,
+					size,
+					cast: 0,
+					first_seat: 0,
+					seats: size,
+					drawn: T::Clock::now().as_secs(),
+				};
+				Juries::<T>::insert(ticket, jury);
+				Self::deposit_event(Event::JuryDrawn { case_index: ticket, size });
+			}
+			ticket
+		}
+
+		/// The commitment of `voter` to `opinion` on a commit–reveal case, blinded by `salt`. The
+		/// salt must be kept secret until the vote is revealed.
+		pub fn vote_commitment(voter: &Alias, opinion: &Judgement, salt: &[u8; 32]) -> T::Hash {
+			T::Hashing::hash_of(&(voter, opinion, salt))
 		}
 
 		fn validate_clean_vote(case_index: CaseIndex, voter: Alias) -> Result<Judgement, Error<T>> {
@@ -1372,6 +2066,8 @@ pub mod pallet {
 				.as_secs()
 				.saturating_sub(core::cmp::max(case.since, active_since));
 			ensure!(secs >= T::MaxVotingDuration::get().into(), Error::<T>::Recent);
+			// Committed votes must have had the chance to be revealed before the case is decided.
+			Self::ensure_reveal_over(case_index)?;
 			Ok(case)
 		}
 
@@ -1433,6 +2129,7 @@ pub mod pallet {
 
 			let maybe_verdict = DoneCases::<T>::get(case_index).map(|c| c.verdict);
 			Votes::<T>::remove(case_index, voter);
+			VoteWeights::<T>::remove(case_index, voter);
 
 			let mut credit = Credits::<T>::get(voter);
 			credit.cleaned.saturating_inc();
@@ -1479,16 +2176,18 @@ pub mod pallet {
 				<T as frame_system::Config>::RuntimeCall,
 			>,
 		) -> Result<Self::Ticket, DispatchError> {
-			let ticket = CaseCount::<T>::get();
-			CaseCount::<T>::mutate(|c| c.saturating_inc());
-			let status = OpenCase {
-				details: CaseDetails { statement, context, callback },
-				since: T::Clock::now().as_secs(),
-				tally: Default::default(),
-			};
-			OpenCases::<T>::insert(ticket, status);
-			Self::deposit_event(Event::CaseCreated { case_index: ticket });
-			Ok(ticket)
+			Ok(Self::open_case(CaseDetails { statement, context, callback }, false, false))
+		}
+
+		fn judge_statement_with_commit_reveal(
+			statement: Statement,
+			context: JudgementContext,
+			callback: Callback<
+				(Self::Ticket, JudgementContext, Judgement),
+				<T as frame_system::Config>::RuntimeCall,
+			>,
+		) -> Result<Self::Ticket, DispatchError> {
+			Ok(Self::open_case(CaseDetails { statement, context, callback }, false, true))
 		}
 	}
 }
@@ -0,0 +1,135 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Individuality.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Storage migrations for the mob rule pallet.
+
+use crate::*;
+use frame_support::{
+	migrations::VersionedMigration, pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade,
+};
+
+const LOG_TARGET: &str = "runtime::mob-rule::migration";
+
+/// Migration to storage version 1, in which votes are weighted and verdicts can be appealed.
+///
+/// The votes cast on open cases before the migration all weigh 1, so the weight of each side of
+/// their tallies is its number of votes. None of the existing cases is an appeal, and the verdicts
+/// already reached cannot be appealed.
+pub mod v1 {
+	use super::*;
+
+	/// The storage of the pallet before votes were weighted.
+	pub(crate) mod v0 {
+		use super::*;
+
+		#[derive(Encode, Decode)]
+		pub(crate) struct VoteTally {
+			pub aye: u32,
+			pub nay: u32,
+			pub contempt: u32,
+		}
+
+		#[derive(Encode, Decode)]
+		pub(crate) struct OpenCase<RuntimeCall> {
+			pub since: SecsSinceGenesis,
+			pub details: CaseDetails<RuntimeCall>,
+			pub tally: VoteTally,
+		}
+
+		#[derive(Encode, Decode)]
+		pub(crate) struct RipeCase<RuntimeCall> {
+			pub details: CaseDetails<RuntimeCall>,
+			pub verdict: Judgement,
+		}
+	}
+
+	/// Implements [`UncheckedOnRuntimeUpgrade`], migrating the open and ripe cases.
+	pub struct UncheckedMigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut open_cases = 0u64;
+			OpenCases::<T>::translate::<v0::OpenCase<<T as frame_system::Config>::RuntimeCall>, _>(
+				|_, old| {
+					open_cases += 1;
+					let v0::VoteTally { aye, nay, contempt } = old.tally;
+					Some(OpenCase {
+						since: old.since,
+						details: old.details,
+						tally: VoteTally {
+							aye,
+							nay,
+							contempt,
+							aye_weight: aye,
+							nay_weight: nay,
+							contempt_weight: contempt,
+						},
+						appeal: false,
+					})
+				},
+			);
+			let mut ripe_cases = 0u64;
+			RipeCases::<T>::translate::<v0::RipeCase<<T as frame_system::Config>::RuntimeCall>, _>(
+				|_, old| {
+					ripe_cases += 1;
+					Some(RipeCase {
+						details: old.details,
+						verdict: old.verdict,
+						appealable_until: None,
+						appeal: None,
+					})
+				},
+			);
+			log::info!(
+				target: LOG_TARGET,
+				"migrated {open_cases} open cases and {ripe_cases} ripe cases",
+			);
+			let cases = open_cases.saturating_add(ripe_cases);
+			T::DbWeight::get().reads_writes(cases, cases)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<alloc::vec::Vec<u8>, sp_runtime::TryRuntimeError> {
+			let open_cases = OpenCases::<T>::iter_keys().count() as u32;
+			let ripe_cases = RipeCases::<T>::iter_keys().count() as u32;
+			Ok((open_cases, ripe_cases).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: alloc::vec::Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let (open_cases, ripe_cases) = <(u32, u32)>::decode(&mut &state[..])
+				.map_err(|_| "the pre-upgrade state must decode")?;
+			ensure!(
+				OpenCases::<T>::iter_values().count() as u32 == open_cases,
+				"every open case must decode after the migration"
+			);
+			ensure!(
+				RipeCases::<T>::iter_values().count() as u32 == ripe_cases,
+				"every ripe case must decode after the migration"
+			);
+			Ok(())
+		}
+	}
+
+	/// Migrate the pallet to storage version 1, if it is at version 0.
+	pub type MigrateToV1<T> = VersionedMigration<
+		0,
+		1,
+		UncheckedMigrateToV1<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
@@ -22,7 +22,7 @@ use frame_support::{
 };
 use frame_system::{
 	offchain::{CreateBare, CreateTransactionBase},
-	pallet_prelude::ExtrinsicFor,
+	pallet_prelude::{BlockNumberFor, ExtrinsicFor},
 	EnsureRoot,
 };
 use indiv_support::traits::CountedMembers;
@@ -93,6 +93,8 @@ impl pallet_balances::Config for Test {
 
 parameter_types! {
 	pub static Now: core::time::Duration = core::time::Duration::from_millis(0);
+	pub static MobVoteWeighting: VoteWeighting = VoteWeighting::Equal;
+	pub static MobAppealPeriod: u32 = 0;
 }
 
 pub struct TestClock;
@@ -105,6 +107,8 @@ impl UnixTime for TestClock {
 parameter_types! {
 	pub const PotId: PalletId = PalletId(*b"PotRwrds");
 	pub const MinTurnoutPercentage: Percent = Percent::from_percent(10);
+	pub const AppealThreshold: Percent = Percent::from_percent(40);
+	pub const AppealTurnoutPercentage: Percent = Percent::from_percent(20);
 	pub const BalancesLocation: Location = Location::here();
 }
 
@@ -202,11 +206,42 @@ impl crate::Config for Test {
 	type CleanVotesBatchSize = ConstU32<6>;
 	type VotesOpenForClaimsDuration = ConstU32<{ 60 * 60 }>;
 	type MinimumVoterThreshold = ConstU32<1>;
+	type CommitDuration = ConstU32<{ 2 * 24 * 60 * 60 }>;
+	type RevealDuration = ConstU32<{ 24 * 60 * 60 }>;
+	type UnrevealedPenaltyDuration = ConstU64<20>;
+	type Randomness = TestRandomness<Self>;
+	type JurySize = ConstU32<10>;
+	type JurorTimeout = ConstU32<{ 24 * 60 * 60 }>;
+	type VoteWeighting = MobVoteWeighting;
+	type AppealDeposit = ConstU64<100>;
+	type AppealThreshold = AppealThreshold;
+	type AppealPeriod = MobAppealPeriod;
+	type AppealJurySize = ConstU32<20>;
+	type AppealTurnoutPercentage = AppealTurnoutPercentage;
 
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = BenchHelper;
 }
 
+/// Provides an implementation of [`frame_support::traits::Randomness`] that should only be used in
+/// tests!
+pub struct TestRandomness<T>(core::marker::PhantomData<T>);
+
+impl<Output: codec::Decode + Default, T>
+	frame_support::traits::Randomness<Output, BlockNumberFor<T>> for TestRandomness<T>
+where
+	T: frame_system::Config,
+{
+	fn random(subject: &[u8]) -> (Output, BlockNumberFor<T>) {
+		use sp_runtime::traits::TrailingZeroInput;
+
+		(
+			Output::decode(&mut TrailingZeroInput::new(subject)).unwrap_or_default(),
+			frame_system::Pallet::<T>::block_number(),
+		)
+	}
+}
+
 pub fn advance_to(b: u64) {
 	while System::block_number() < b {
 		System::set_block_number(System::block_number() + 1);
@@ -37,6 +37,35 @@ pub mod helpers {
 		judge_statement_result.unwrap()
 	}
 
+	/// Creates a voting case voted on through commit–reveal for testing purposes.
+	pub fn create_commit_reveal_case<T: Config>() -> CaseIndex {
+		let judge_statement_result = Pallet::<T>::judge_statement_with_commit_reveal(
+			Statement::ProofOfInk {
+				design: InkSpec::DesignedElective(0, 0),
+				evidence: [0; 32],
+				probable_acceptable: true,
+			},
+			Default::default(),
+			Callback::from_parts(0, 0),
+		);
+		assert_ok!(judge_statement_result);
+		judge_statement_result.unwrap()
+	}
+
+	/// Seats a jury of `size` jurors on a case in which every alias is seated.
+	pub fn seat_jury<T: Config>(case_index: CaseIndex, size: u32) {
+		let jury = Jury {
+			seed: [0; 32],
+			population: size,
+			size,
+			cast: 0,
+			first_seat: 0,
+			seats: size,
+			drawn: 0,
+		};
+		Juries::<T>::insert(case_index, jury);
+	}
+
 	/// Funds the mob rule pot with a specified amount.
 	pub fn fund_pot<T: Config>() {
 		let pot = Pallet::<T>::mob_rule_pot_id();
@@ -49,13 +78,31 @@ pub mod helpers {
 		let case_index = create_voting_case::<T>();
 
 		let open_case = OpenCases::<T>::take(case_index).expect("Case should exist");
-		let ripe_case =
-			RipeCase { details: open_case.details, verdict: Judgement::Truth(Truth::True) };
+		let ripe_case = RipeCase {
+			details: open_case.details,
+			verdict: Judgement::Truth(Truth::True),
+			appealable_until: None,
+			appeal: None,
+		};
 		RipeCases::<T>::insert(case_index, ripe_case);
 
 		case_index
 	}
 
+	/// Creates a ripe case whose failed verdict can be appealed until `appealable_until`.
+	pub fn create_appealable_case<T: Config>(appealable_until: u64) -> CaseIndex {
+		let case_index = create_ripe_case::<T>();
+
+		RipeCases::<T>::mutate(case_index, |maybe_case| {
+			if let Some(case) = maybe_case {
+				case.verdict = Judgement::Truth(Truth::False);
+				case.appealable_until = Some(appealable_until);
+			}
+		});
+
+		case_index
+	}
+
 	/// Creates a done case with specified voters and timing.
 	///
 	/// # Parameters
@@ -1580,6 +1580,776 @@ mod rewards {
 	}
 }
 
+mod commit_reveal {
+	use super::*;
+	use frame_support::{assert_noop, dispatch::Pays};
+	use indiv_support::traits::Truth;
+	use sp_runtime::transaction_validity::{InvalidTransaction, TransactionSource};
+
+	const SALT: [u8; 32] = [7u8; 32];
+	const ONE_DAY_MS: u64 = 24 * 60 * 60 * 1000;
+
+	fn commit(voter: AccountId, case_index: CaseIndex, opinion: Judgement) {
+		let alias = EnsureAliasLowerThan5::get_alias(RuntimeOrigin::signed(voter));
+		let commitment = MobRule::vote_commitment(&alias, &opinion, &SALT);
+		assert_ok!(MobRule::commit_vote(RuntimeOrigin::signed(voter), case_index, commitment));
+	}
+
+	#[test]
+	fn commit_reveal_is_chosen_when_the_case_is_opened() {
+		TestExt::new().execute_with(|| {
+			advance_to(1);
+			mock::Now::set(Duration::from_millis(ONE_DAY_MS));
+			let voted_case_index = helpers::create_voting_case::<Test>();
+			assert!(!RevealPeriods::<Test>::contains_key(voted_case_index));
+
+			// The periods of a commit–reveal case start from its opening.
+			let case_index = helpers::create_commit_reveal_case::<Test>();
+			System::assert_last_event(
+				Event::CommitRevealEnabled {
+					case_index,
+					reveal_since: 3 * 24 * 60 * 60,
+					reveal_until: 4 * 24 * 60 * 60,
+				}
+				.into(),
+			);
+			assert_eq!(
+				RevealPeriods::<Test>::get(case_index),
+				Some(RevealPeriod { since: 3 * 24 * 60 * 60, until: 4 * 24 * 60 * 60 })
+			);
+
+			// Open votes are not accepted on the case.
+			assert_noop!(
+				MobRule::vote(
+					RuntimeOrigin::signed(VOTER_VALID),
+					case_index,
+					Judgement::Truth(Truth::True),
+				),
+				Error::<Test>::CommitRevealCase
+			);
+			// Nor are commitments on open voting cases.
+			assert_noop!(
+				MobRule::commit_vote(
+					RuntimeOrigin::signed(VOTER_VALID),
+					voted_case_index,
+					Default::default(),
+				),
+				Error::<Test>::NotCommitReveal
+			);
+		});
+	}
+
+	#[test]
+	fn case_does_not_time_out_before_the_reveal_period_is_over() {
+		TestExt::new().execute_with(|| {
+			advance_to(1);
+			ActiveSince::<Test>::put(0);
+			// GIVEN a commit–reveal case whose reveal period outlasts its voting duration
+			let case_index = helpers::create_commit_reveal_case::<Test>();
+			let until = constants::TWO_WEEKS_MS / 1000 + 1;
+			RevealPeriods::<Test>::insert(case_index, RevealPeriod { since: 0, until });
+
+			// WHEN the voting duration is over
+			mock::Now::set(Duration::from_millis(constants::TWO_WEEKS_MS));
+
+			// THEN the case cannot time out until the reveal period is over
+			assert_noop!(
+				MobRule::force_ripen_case(RuntimeOrigin::none(), case_index),
+				Error::<Test>::RevealOngoing
+			);
+			assert_eq!(
+				MobRule::validate_unsigned(
+					TransactionSource::Local,
+					&Call::<Test>::force_ripen_case { case_index },
+				),
+				InvalidTransaction::Future.into()
+			);
+
+			mock::Now::set(Duration::from_millis(until * 1000));
+			assert_ok!(MobRule::force_ripen_case(RuntimeOrigin::none(), case_index));
+			assert!(RipeCases::<Test>::contains_key(case_index));
+		});
+	}
+
+	#[test]
+	fn votes_are_counted_only_once_revealed() {
+		TestExt::new().execute_with(|| {
+			advance_to(1);
+			EnsureAliasLowerThan5::set_voter_count(2);
+			// GIVEN a commit–reveal case
+			let case_index = helpers::create_commit_reveal_case::<Test>();
+
+			// WHEN two voters commit their votes
+			commit(VOTER_VALID, case_index, Judgement::Truth(Truth::True));
+			commit(VOTER_VALID_2, case_index, Judgement::Truth(Truth::True));
+			let alias = EnsureAliasLowerThan5::get_alias(RuntimeOrigin::signed(VOTER_VALID));
+			System::assert_last_event(
+				Event::VoteCommitted {
+					case_index,
+					voter: EnsureAliasLowerThan5::get_alias(RuntimeOrigin::signed(VOTER_VALID_2)),
+				}
+				.into(),
+			);
+
+			// THEN no vote is recorded and the case cannot ripen
+			assert!(!Votes::<Test>::contains_key(case_index, alias));
+			mock::Now::set(Duration::from_millis(2 * ONE_DAY_MS - 1000));
+			assert_ok!(MobRule::touch_case(RuntimeOrigin::signed(VOTER_VALID), case_index));
+			assert!(OpenCases::<Test>::contains_key(case_index));
+
+			// AND votes cannot be revealed before the reveal period
+			assert_noop!(
+				MobRule::reveal_vote(
+					RuntimeOrigin::signed(VOTER_VALID),
+					case_index,
+					Judgement::Truth(Truth::True),
+					SALT,
+				),
+				Error::<Test>::NotRevealPeriod
+			);
+
+			// WHEN the reveal period starts
+			mock::Now::set(Duration::from_millis(2 * ONE_DAY_MS));
+			// THEN votes can no longer be committed
+			assert_noop!(
+				MobRule::commit_vote(
+					RuntimeOrigin::signed(VOTER_VALID_3),
+					case_index,
+					Default::default(),
+				),
+				Error::<Test>::CommitPeriodOver
+			);
+			// AND reveals must match their commitment
+			assert_noop!(
+				MobRule::reveal_vote(
+					RuntimeOrigin::signed(VOTER_VALID),
+					case_index,
+					Judgement::Truth(Truth::False),
+					SALT,
+				),
+				Error::<Test>::BadReveal
+			);
+			assert_noop!(
+				MobRule::reveal_vote(
+					RuntimeOrigin::signed(VOTER_VALID_3),
+					case_index,
+					Judgement::Truth(Truth::True),
+					SALT,
+				),
+				Error::<Test>::NoSuchCommitment
+			);
+
+			// WHEN both votes are revealed
+			assert_ok!(MobRule::reveal_vote(
+				RuntimeOrigin::signed(VOTER_VALID),
+				case_index,
+				Judgement::Truth(Truth::True),
+				SALT,
+			));
+			System::assert_last_event(
+				Event::Voted { case_index, voter: alias, opinion: Judgement::Truth(Truth::True) }
+					.into(),
+			);
+			assert_eq!(Votes::<Test>::get(case_index, alias), Some(Judgement::Truth(Truth::True)));
+			assert!(!Commitments::<Test>::contains_key(case_index, alias));
+			assert_eq!(Credits::<Test>::get(alias).voted, 1);
+			assert_ok!(MobRule::reveal_vote(
+				RuntimeOrigin::signed(VOTER_VALID_2),
+				case_index,
+				Judgement::Truth(Truth::True),
+				SALT,
+			));
+
+			// THEN the case ripens on the revealed votes
+			assert!(!OpenCases::<Test>::contains_key(case_index));
+			assert_eq!(
+				RipeCases::<Test>::get(case_index).unwrap().verdict,
+				Judgement::Truth(Truth::True)
+			);
+		});
+	}
+
+	#[test]
+	fn ripe_case_is_closed_only_after_the_reveal_period() {
+		TestExt::new().execute_with(|| {
+			advance_to(1);
+			EnsureAliasLowerThan5::set_voter_count(3);
+			// GIVEN a commit–reveal case with two committed votes
+			let case_index = helpers::create_commit_reveal_case::<Test>();
+			commit(VOTER_VALID, case_index, Judgement::Truth(Truth::True));
+			commit(VOTER_VALID_2, case_index, Judgement::Truth(Truth::False));
+
+			// WHEN the first vote is revealed, the case ripens
+			mock::Now::set(Duration::from_millis(2 * ONE_DAY_MS));
+			assert_ok!(MobRule::reveal_vote(
+				RuntimeOrigin::signed(VOTER_VALID),
+				case_index,
+				Judgement::Truth(Truth::True),
+				SALT,
+			));
+			assert!(RipeCases::<Test>::contains_key(case_index));
+
+			// THEN the case cannot be closed during the reveal period
+			assert_noop!(
+				MobRule::close_case(RuntimeOrigin::none(), case_index),
+				Error::<Test>::RevealOngoing
+			);
+
+			// AND the second vote can still be revealed and claimed later on
+			let alias_2 = EnsureAliasLowerThan5::get_alias(RuntimeOrigin::signed(VOTER_VALID_2));
+			assert_ok!(MobRule::reveal_vote(
+				RuntimeOrigin::signed(VOTER_VALID_2),
+				case_index,
+				Judgement::Truth(Truth::False),
+				SALT,
+			));
+			assert_eq!(
+				Votes::<Test>::get(case_index, alias_2),
+				Some(Judgement::Truth(Truth::False))
+			);
+			assert_eq!(
+				RipeCases::<Test>::get(case_index).unwrap().verdict,
+				Judgement::Truth(Truth::True)
+			);
+
+			// WHEN the reveal period is over
+			mock::Now::set(Duration::from_millis(3 * ONE_DAY_MS));
+			// THEN the case can be closed
+			assert_ok!(MobRule::close_case(RuntimeOrigin::none(), case_index));
+			assert!(DoneCases::<Test>::contains_key(case_index));
+		});
+	}
+
+	#[test]
+	fn unrevealed_commitments_are_penalized() {
+		TestExt::new().execute_with(|| {
+			advance_to(1);
+			// GIVEN a commit–reveal case with a committed vote
+			let case_index = helpers::create_commit_reveal_case::<Test>();
+			let alias = EnsureAliasLowerThan5::get_alias(RuntimeOrigin::signed(VOTER_VALID));
+			let commitment =
+				MobRule::vote_commitment(&alias, &Judgement::Truth(Truth::True), &SALT);
+			let result =
+				MobRule::commit_vote(RuntimeOrigin::signed(VOTER_VALID), case_index, commitment);
+			assert_eq!(result.unwrap().pays_fee, Pays::No);
+			// Replacing the commitment is not free
+			let result =
+				MobRule::commit_vote(RuntimeOrigin::signed(VOTER_VALID), case_index, commitment);
+			assert_eq!(result.unwrap().pays_fee, Pays::Yes);
+
+			// WHEN the vote is not revealed, the commitment cannot be cleaned before the reveal
+			// period is over
+			mock::Now::set(Duration::from_millis(3 * ONE_DAY_MS - 1000));
+			assert_noop!(
+				MobRule::clean_commitment(
+					RuntimeOrigin::signed(MOCK_ACCOUNT_ID1),
+					case_index,
+					alias
+				),
+				Error::<Test>::RevealOngoing
+			);
+
+			// WHEN the reveal period is over
+			mock::Now::set(Duration::from_millis(3 * ONE_DAY_MS));
+			assert_noop!(
+				MobRule::reveal_vote(
+					RuntimeOrigin::signed(VOTER_VALID),
+					case_index,
+					Judgement::Truth(Truth::True),
+					SALT,
+				),
+				Error::<Test>::NotRevealPeriod
+			);
+			assert_ok!(MobRule::clean_commitment(
+				RuntimeOrigin::signed(MOCK_ACCOUNT_ID1),
+				case_index,
+				alias
+			));
+			System::assert_last_event(Event::CommitmentCleaned { case_index, voter: alias }.into());
+
+			// THEN the voter is penalized for `UnrevealedPenaltyDuration` blocks
+			assert!(!Commitments::<Test>::contains_key(case_index, alias));
+			assert!(!Votes::<Test>::contains_key(case_index, alias));
+			assert_eq!(Credits::<Test>::get(alias).voted, 0);
+			let unrevealed_penalty: u64 = <Test as Config>::UnrevealedPenaltyDuration::get();
+			advance_to(unrevealed_penalty);
+			assert_noop!(
+				MobRule::clear_voting_penalty(RuntimeOrigin::signed(VOTER_VALID)),
+				Error::<Test>::Early
+			);
+			advance_to(1 + unrevealed_penalty);
+			assert_ok!(MobRule::clear_voting_penalty(RuntimeOrigin::signed(VOTER_VALID)));
+		});
+	}
+}
+
+mod juries {
+	use super::*;
+	use frame_support::{assert_noop, dispatch::Pays};
+	use indiv_support::traits::Truth;
+
+	// With the mock randomness and 20 active voters, accounts 0 to 3 are seated on the first 10
+	// seats while account 4 is seated on seat 16.
+	const POPULATION: u32 = 20;
+	const NOT_JUROR: AccountId = 4;
+
+	#[test]
+	fn jury_is_drawn_only_when_population_exceeds_jury_size() {
+		TestExt::new().execute_with(|| {
+			advance_to(1);
+			let jury_size: u32 = <Test as Config>::JurySize::get();
+
+			EnsureAliasLowerThan5::set_voter_count(jury_size);
+			let case_index = helpers::create_voting_case::<Test>();
+			assert!(!Juries::<Test>::contains_key(case_index));
+
+			EnsureAliasLowerThan5::set_voter_count(jury_size + 1);
+			let case_index = helpers::create_voting_case::<Test>();
+			System::assert_last_event(Event::JuryDrawn { case_index, size: jury_size }.into());
+			let jury = Juries::<Test>::get(case_index).unwrap();
+			assert_eq!(jury.population, jury_size + 1);
+			assert_eq!((jury.first_seat, jury.seats), (0, jury_size));
+		});
+	}
+
+	#[test]
+	fn only_jurors_can_vote() {
+		TestExt::new().execute_with(|| {
+			advance_to(1);
+			EnsureAliasLowerThan5::set_voter_count(POPULATION);
+			let case_index = helpers::create_voting_case::<Test>();
+
+			assert_noop!(
+				MobRule::vote(
+					RuntimeOrigin::signed(NOT_JUROR),
+					case_index,
+					Judgement::Truth(Truth::True),
+				),
+				Error::<Test>::NotJuror
+			);
+			assert_ok!(MobRule::vote(
+				RuntimeOrigin::signed(VOTER_VALID),
+				case_index,
+				Judgement::Truth(Truth::True),
+			));
+			assert_eq!(Juries::<Test>::get(case_index).unwrap().cast, 1);
+
+			// Jurors also commit on commit–reveal cases.
+			let case_index = helpers::create_commit_reveal_case::<Test>();
+			assert_noop!(
+				MobRule::commit_vote(
+					RuntimeOrigin::signed(NOT_JUROR),
+					case_index,
+					Default::default(),
+				),
+				Error::<Test>::NotJuror
+			);
+			assert_ok!(MobRule::commit_vote(
+				RuntimeOrigin::signed(VOTER_VALID),
+				case_index,
+				Default::default(),
+			));
+		});
+	}
+
+	#[test]
+	fn absent_jurors_are_replaced_after_timeout() {
+		TestExt::new().execute_with(|| {
+			advance_to(1);
+			EnsureAliasLowerThan5::set_voter_count(POPULATION);
+			// GIVEN a case on which a single juror voted
+			let case_index = helpers::create_voting_case::<Test>();
+			assert_ok!(MobRule::vote(
+				RuntimeOrigin::signed(VOTER_VALID),
+				case_index,
+				Judgement::Truth(Truth::True),
+			));
+
+			// WHEN the jurors still have time to vote
+			assert_noop!(
+				MobRule::replace_absent_jurors(RuntimeOrigin::signed(MOCK_ACCOUNT_ID1), case_index),
+				Error::<Test>::JurorsNotLate
+			);
+
+			// WHEN the timeout passes, the absent jurors are replaced
+			let timeout: u32 = <Test as Config>::JurorTimeout::get();
+			mock::Now::set(Duration::from_secs(timeout.into()));
+			assert_ok!(MobRule::replace_absent_jurors(
+				RuntimeOrigin::signed(MOCK_ACCOUNT_ID1),
+				case_index
+			));
+			System::assert_last_event(Event::JurorsReplaced { case_index, seats: 9 }.into());
+			let jury = Juries::<Test>::get(case_index).unwrap();
+			assert_eq!((jury.first_seat, jury.seats), (10, 9));
+
+			// THEN the absent jurors lost their seat
+			assert_noop!(
+				MobRule::vote(
+					RuntimeOrigin::signed(VOTER_VALID_2),
+					case_index,
+					Judgement::Truth(Truth::True),
+				),
+				Error::<Test>::NotJuror
+			);
+			// AND the newly drawn jurors can vote
+			assert_ok!(MobRule::vote(
+				RuntimeOrigin::signed(NOT_JUROR),
+				case_index,
+				Judgement::Truth(Truth::False),
+			));
+			// AND the jurors who voted can still change their vote
+			let result = MobRule::vote(
+				RuntimeOrigin::signed(VOTER_VALID),
+				case_index,
+				Judgement::Truth(Truth::False),
+			);
+			assert_eq!(result.unwrap().pays_fee, Pays::Yes);
+			assert_eq!(Juries::<Test>::get(case_index).unwrap().cast, 2);
+		});
+	}
+
+	#[test]
+	fn jurors_cannot_outnumber_the_seats() {
+		TestExt::new().execute_with(|| {
+			advance_to(1);
+			EnsureAliasLowerThan5::set_voter_count(POPULATION);
+			let case_index = helpers::create_voting_case::<Test>();
+			// A single seat is left, while several aliases are seated within the window.
+			Juries::<Test>::mutate(case_index, |jury| {
+				let jury = jury.as_mut().unwrap();
+				jury.cast = jury.size - 1;
+			});
+
+			assert_ok!(MobRule::vote(
+				RuntimeOrigin::signed(VOTER_VALID),
+				case_index,
+				Judgement::Truth(Truth::True),
+			));
+			assert_noop!(
+				MobRule::vote(
+					RuntimeOrigin::signed(VOTER_VALID_2),
+					case_index,
+					Judgement::Truth(Truth::True),
+				),
+				Error::<Test>::JuryComplete
+			);
+			// The juror who voted can still change their vote.
+			assert_ok!(MobRule::vote(
+				RuntimeOrigin::signed(VOTER_VALID),
+				case_index,
+				Judgement::Truth(Truth::False),
+			));
+		});
+	}
+
+	#[test]
+	fn turnout_is_measured_against_the_jury() {
+		TestExt::new().execute_with(|| {
+			advance_to(1);
+			EnsureAliasLowerThan5::set_voter_count(POPULATION);
+			let case_index = helpers::create_voting_case::<Test>();
+			mock::Now::set(Duration::from_millis(constants::TWO_DAYS_MS));
+
+			// A single vote is 5% of the active voters but 10% of the jury, which meets the
+			// minimum turnout.
+			assert_ok!(MobRule::vote(
+				RuntimeOrigin::signed(VOTER_VALID),
+				case_index,
+				Judgement::Truth(Truth::True),
+			));
+			assert!(RipeCases::<Test>::contains_key(case_index));
+		});
+	}
+}
+
+mod vote_weighting {
+	use super::*;
+	use indiv_support::traits::Truth;
+
+	const TRACK_RECORD: VoteWeighting =
+		VoteWeighting::TrackRecord { min: 1, max: 5, min_record: 5 };
+
+	fn record(cleaned: u32, correct: u32) -> MobCredit<u64> {
+		MobCredit { voted: cleaned, cleaned, correct, credit: 0 }
+	}
+
+	#[test]
+	fn weight_is_bounded_by_the_track_record() {
+		assert_eq!(VoteWeighting::Equal.weight(&record(10, 10)), 1);
+		assert_eq!(VoteWeighting::Equal.max_weight(), 1);
+
+		// Too short a record weighs the minimum.
+		assert_eq!(TRACK_RECORD.weight(&record(4, 4)), 1);
+		assert_eq!(TRACK_RECORD.weight(&record(10, 0)), 1);
+		assert_eq!(TRACK_RECORD.weight(&record(10, 5)), 3);
+		assert_eq!(TRACK_RECORD.weight(&record(10, 10)), 5);
+		assert_eq!(TRACK_RECORD.max_weight(), 5);
+	}
+
+	#[test]
+	fn experienced_voters_outweigh_newcomers() {
+		TestExt::new().execute_with(|| {
+			advance_to(1);
+			MobVoteWeighting::set(TRACK_RECORD);
+			EnsureAliasLowerThan5::set_voter_count(3);
+			// GIVEN a voter who was always right
+			let alias = EnsureAliasLowerThan5::get_alias(RuntimeOrigin::signed(VOTER_VALID));
+			Credits::<Test>::insert(alias, record(10, 10));
+			let case_index = helpers::create_voting_case::<Test>();
+
+			// WHEN they are outvoted by two newcomers
+			assert_ok!(MobRule::vote(
+				RuntimeOrigin::signed(VOTER_VALID),
+				case_index,
+				Judgement::Truth(Truth::True),
+			));
+			assert_eq!(VoteWeights::<Test>::get(case_index, alias), Some(5));
+			mock::Now::set(Duration::from_millis(constants::TWO_DAYS_MS));
+			assert_ok!(MobRule::vote(
+				RuntimeOrigin::signed(VOTER_VALID_2),
+				case_index,
+				Judgement::Truth(Truth::False),
+			));
+			assert_ok!(MobRule::vote(
+				RuntimeOrigin::signed(VOTER_VALID_3),
+				case_index,
+				Judgement::Truth(Truth::False),
+			));
+
+			// THEN their vote still carries the verdict
+			assert_eq!(
+				RipeCases::<Test>::get(case_index).unwrap().verdict,
+				Judgement::Truth(Truth::True)
+			);
+		});
+	}
+
+	#[test]
+	fn weights_are_snapshotted_when_cast() {
+		TestExt::new().execute_with(|| {
+			advance_to(1);
+			MobVoteWeighting::set(TRACK_RECORD);
+			EnsureAliasLowerThan5::set_voter_count(2);
+			// GIVEN a voter who was always right votes
+			let alias = EnsureAliasLowerThan5::get_alias(RuntimeOrigin::signed(VOTER_VALID));
+			Credits::<Test>::insert(alias, record(10, 10));
+			let case_index = helpers::create_voting_case::<Test>();
+			assert_ok!(MobRule::vote(
+				RuntimeOrigin::signed(VOTER_VALID),
+				case_index,
+				Judgement::Truth(Truth::False),
+			));
+
+			// WHEN their record worsens before they change their vote
+			Credits::<Test>::insert(alias, record(20, 0));
+			assert_ok!(MobRule::vote(
+				RuntimeOrigin::signed(VOTER_VALID),
+				case_index,
+				Judgement::Truth(Truth::True),
+			));
+
+			// THEN the vote keeps the weight it was cast with
+			assert_eq!(VoteWeights::<Test>::get(case_index, alias), Some(5));
+			mock::Now::set(Duration::from_millis(constants::TWO_DAYS_MS));
+			assert_ok!(MobRule::vote(
+				RuntimeOrigin::signed(VOTER_VALID_2),
+				case_index,
+				Judgement::Truth(Truth::False),
+			));
+			assert_eq!(
+				RipeCases::<Test>::get(case_index).unwrap().verdict,
+				Judgement::Truth(Truth::True)
+			);
+		});
+	}
+}
+
+mod appeals {
+	use super::*;
+	use frame_support::{
+		assert_noop,
+		traits::fungible::{InspectHold, Mutate},
+	};
+	use indiv_support::traits::Truth;
+
+	const APPELLANT: AccountId = MOCK_ACCOUNT_ID1;
+	const ONE_DAY_SECS: u32 = 24 * 60 * 60;
+
+	fn vote(who: AccountId, case_index: CaseIndex, truth: Truth) {
+		assert_ok!(MobRule::vote(RuntimeOrigin::signed(who), case_index, Judgement::Truth(truth)));
+	}
+
+	/// Has the five active voters judge a case, `ayes` of them finding the statement true, until
+	/// the case ripens.
+	fn judge(case_index: CaseIndex, ayes: AccountId) {
+		let truth = |who| if who < ayes { Truth::True } else { Truth::False };
+		for who in 0..4 {
+			vote(who, case_index, truth(who));
+		}
+		mock::Now::set(mock::Now::get() + Duration::from_secs(2 * ONE_DAY_SECS as u64));
+		vote(4, case_index, truth(4));
+		assert!(RipeCases::<Test>::contains_key(case_index));
+	}
+
+	fn ripe_case(ayes: AccountId) -> CaseIndex {
+		EnsureAliasLowerThan5::set_voter_count(5);
+		let case_index = helpers::create_voting_case::<Test>();
+		judge(case_index, ayes);
+		case_index
+	}
+
+	fn appeal(case_index: CaseIndex) -> CaseIndex {
+		let _ = Balances::mint_into(&APPELLANT, 1000);
+		assert_ok!(MobRule::appeal(RuntimeOrigin::signed(APPELLANT), case_index));
+		let appeal_index = CaseCount::<Test>::get() - 1;
+		System::assert_last_event(
+			Event::CaseAppealed { case_index, appeal_index, appellant: APPELLANT }.into(),
+		);
+		appeal_index
+	}
+
+	#[test]
+	fn narrowly_failed_verdicts_can_be_appealed() {
+		TestExt::new().execute_with(|| {
+			advance_to(1);
+			MobAppealPeriod::set(ONE_DAY_SECS);
+			// GIVEN a case which failed with two votes out of five in favour
+			let case_index = ripe_case(2);
+
+			// THEN it cannot be closed while it can be appealed
+			assert_noop!(
+				MobRule::close_case(RuntimeOrigin::none(), case_index),
+				Error::<Test>::AppealOngoing
+			);
+
+			// WHEN its verdict is appealed
+			let appeal_index = appeal(case_index);
+
+			// THEN the statement is judged again while the deposit is held
+			assert_eq!(
+				Balances::balance_on_hold(&HoldReason::Appeal.into(), &APPELLANT),
+				<Test as Config>::AppealDeposit::get()
+			);
+			assert!(OpenCases::<Test>::contains_key(appeal_index));
+			assert_eq!(RipeCases::<Test>::get(case_index).unwrap().appeal, Some(appeal_index));
+			assert_noop!(
+				MobRule::appeal(RuntimeOrigin::signed(APPELLANT), case_index),
+				Error::<Test>::NotAppealable
+			);
+			assert_noop!(
+				MobRule::close_case(RuntimeOrigin::none(), case_index),
+				Error::<Test>::AppealOngoing
+			);
+		});
+	}
+
+	#[test]
+	fn appeals_are_limited_in_time_and_to_narrow_failures() {
+		TestExt::new().execute_with(|| {
+			advance_to(1);
+			MobAppealPeriod::set(ONE_DAY_SECS);
+
+			// A clear failure cannot be appealed and closes right away.
+			let case_index = ripe_case(1);
+			assert_noop!(
+				MobRule::appeal(RuntimeOrigin::signed(APPELLANT), case_index),
+				Error::<Test>::NotAppealable
+			);
+			assert_ok!(MobRule::close_case(RuntimeOrigin::none(), case_index));
+
+			// Neither can a passed case.
+			let case_index = ripe_case(5);
+			assert_noop!(
+				MobRule::appeal(RuntimeOrigin::signed(APPELLANT), case_index),
+				Error::<Test>::NotAppealable
+			);
+
+			// A narrow failure can no longer be appealed once the appeal period is over.
+			let case_index = ripe_case(2);
+			mock::Now::set(mock::Now::get() + Duration::from_secs(ONE_DAY_SECS as u64));
+			assert_noop!(
+				MobRule::appeal(RuntimeOrigin::signed(APPELLANT), case_index),
+				Error::<Test>::NotAppealable
+			);
+			assert_ok!(MobRule::close_case(RuntimeOrigin::none(), case_index));
+		});
+	}
+
+	#[test]
+	fn successful_appeal_overturns_the_verdict() {
+		TestExt::new().execute_with(|| {
+			advance_to(1);
+			MobAppealPeriod::set(ONE_DAY_SECS);
+			// GIVEN a narrowly failed case under appeal
+			let case_index = ripe_case(2);
+			let appeal_index = appeal(case_index);
+
+			// WHEN the appeal case finds the statement true
+			judge(appeal_index, 5);
+			assert_ok!(MobRule::close_case(RuntimeOrigin::none(), appeal_index));
+
+			// THEN the verdict is overturned and the deposit returned
+			System::assert_has_event(
+				Event::AppealDecided {
+					case_index,
+					appeal_index,
+					verdict: Judgement::Truth(Truth::True),
+					successful: true,
+				}
+				.into(),
+			);
+			assert_eq!(
+				DoneCases::<Test>::get(case_index).unwrap().verdict,
+				Judgement::Truth(Truth::True)
+			);
+			assert_eq!(Balances::balance_on_hold(&HoldReason::Appeal.into(), &APPELLANT), 0);
+			assert_eq!(Balances::free_balance(APPELLANT), 1000);
+
+			// AND the first-round voters who were overturned are not credited
+			assert_ok!(MobRule::claim_vote(RuntimeOrigin::signed(VOTER_VALID), case_index));
+			assert_ok!(MobRule::claim_vote(RuntimeOrigin::signed(VOTER_VALID_3), case_index));
+			let credit = |who| {
+				Credits::<Test>::get(EnsureAliasLowerThan5::get_alias(RuntimeOrigin::signed(who)))
+			};
+			assert_eq!(credit(VOTER_VALID).correct, 1);
+			assert_eq!(credit(VOTER_VALID_3).correct, 0);
+		});
+	}
+
+	#[test]
+	fn failed_appeal_keeps_the_verdict_and_forfeits_the_deposit() {
+		TestExt::new().execute_with(|| {
+			advance_to(1);
+			MobAppealPeriod::set(ONE_DAY_SECS);
+			// GIVEN a narrowly failed case under appeal
+			let case_index = ripe_case(2);
+			let appeal_index = appeal(case_index);
+
+			// WHEN the appeal case finds the statement false again
+			judge(appeal_index, 0);
+			assert_ok!(MobRule::close_case(RuntimeOrigin::none(), appeal_index));
+
+			// THEN the verdict stands and the deposit is moved to the pot
+			System::assert_has_event(
+				Event::AppealDecided {
+					case_index,
+					appeal_index,
+					verdict: Judgement::Truth(Truth::False),
+					successful: false,
+				}
+				.into(),
+			);
+			assert_eq!(
+				DoneCases::<Test>::get(case_index).unwrap().verdict,
+				Judgement::Truth(Truth::False)
+			);
+			let deposit = <Test as Config>::AppealDeposit::get();
+			assert_eq!(Balances::free_balance(APPELLANT), 1000 - deposit);
+			assert_eq!(Balances::free_balance(MobRule::mob_rule_pot_id()), deposit);
+		});
+	}
+}
+
 mod offchain_worker {
 	use super::*;
 	use codec::Decode;
@@ -2013,6 +2783,76 @@ mod offchain_worker {
 // TODO: remove the `allow(deprecated)` once mob-rule migrates to `#[pallet::authorize]`
 // (deadline April 2027). See https://github.com/paritytech/polkadot-sdk/issues/2415.
 #[allow(deprecated)]
+mod migration_to_v1 {
+	use super::*;
+	use crate::migration::v1::{v0, MigrateToV1};
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	#[test]
+	fn open_cases_get_tallies_weighted_by_count() {
+		TestExt::new().execute_with(|| {
+			advance_to(1);
+			let case_index = helpers::create_voting_case::<Test>();
+			let case = OpenCases::<Test>::get(case_index).unwrap();
+			frame_support::storage::unhashed::put(
+				&OpenCases::<Test>::hashed_key_for(case_index),
+				&v0::OpenCase {
+					since: case.since,
+					details: case.details.clone(),
+					tally: v0::VoteTally { aye: 3, nay: 2, contempt: 1 },
+				},
+			);
+			StorageVersion::new(0).put::<MobRule>();
+
+			MigrateToV1::<Test>::on_runtime_upgrade();
+
+			assert_eq!(MobRule::on_chain_storage_version(), StorageVersion::new(1));
+			assert_eq!(
+				OpenCases::<Test>::get(case_index),
+				Some(OpenCase {
+					since: case.since,
+					details: case.details,
+					tally: VoteTally {
+						aye: 3,
+						nay: 2,
+						contempt: 1,
+						aye_weight: 3,
+						nay_weight: 2,
+						contempt_weight: 1,
+					},
+					appeal: false,
+				})
+			);
+		});
+	}
+
+	#[test]
+	fn ripe_cases_cannot_be_appealed() {
+		TestExt::new().execute_with(|| {
+			advance_to(1);
+			let case_index = helpers::create_ripe_case::<Test>();
+			let case = RipeCases::<Test>::get(case_index).unwrap();
+			frame_support::storage::unhashed::put(
+				&RipeCases::<Test>::hashed_key_for(case_index),
+				&v0::RipeCase { details: case.details.clone(), verdict: case.verdict.clone() },
+			);
+			StorageVersion::new(0).put::<MobRule>();
+
+			MigrateToV1::<Test>::on_runtime_upgrade();
+
+			assert_eq!(
+				RipeCases::<Test>::get(case_index),
+				Some(RipeCase {
+					details: case.details,
+					verdict: case.verdict,
+					appealable_until: None,
+					appeal: None,
+				})
+			);
+		});
+	}
+}
+
 mod validate_unsigned {
 	use super::*;
 	use indiv_support::traits::Truth::True;
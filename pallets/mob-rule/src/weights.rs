@@ -63,13 +63,18 @@ pub trait WeightInfo {
 	fn on_poll_base() -> Weight;
 	fn set_active_since() -> Weight;
 	fn kill_active_since() -> Weight;
+	fn commit_vote() -> Weight;
+	fn reveal_vote() -> Weight;
+	fn clean_commitment() -> Weight;
+	fn replace_absent_jurors() -> Weight;
+	fn appeal() -> Weight;
 }
 
 /// Weights for indiv_pallet_mob_rule using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `MobRule::OpenCases` (r:1 w:1)
-	/// Proof: `MobRule::OpenCases` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Proof: `MobRule::OpenCases` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
 	/// Storage: `MobRule::Credits` (r:1 w:1)
 	/// Proof: `MobRule::Credits` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
 	/// Storage: `MobRule::Votes` (r:1 w:1)
@@ -78,36 +83,50 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `People::ActiveMembers` (r:1 w:0)
 	/// Proof: `People::ActiveMembers` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::RevealPeriods` (r:1 w:0)
+	/// Proof: `MobRule::RevealPeriods` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::Juries` (r:1 w:1)
+	/// Proof: `MobRule::Juries` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::VoteWeights` (r:1 w:1)
+	/// Proof: `MobRule::VoteWeights` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
 	fn vote() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `472`
-		//  Estimated: `3642`
+		//  Estimated: `3655`
 		// Minimum execution time: 21_732_000 picoseconds.
 		Weight::from_parts(23_975_000, 0)
-			.saturating_add(Weight::from_parts(0, 3642))
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(Weight::from_parts(0, 3655))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
-	/// Storage: `MobRule::RipeCases` (r:1 w:1)
-	/// Proof: `MobRule::RipeCases` (`max_values`: None, `max_size`: Some(159), added: 2634, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::RipeCases` (r:2 w:2)
+	/// Proof: `MobRule::RipeCases` (`max_values`: None, `max_size`: Some(173), added: 2648, mode: `MaxEncodedLen`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
-	/// Storage: `MobRule::DoneCases` (r:0 w:1)
+	/// Storage: `MobRule::DoneCases` (r:0 w:2)
 	/// Proof: `MobRule::DoneCases` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::RevealPeriods` (r:1 w:0)
+	/// Proof: `MobRule::RevealPeriods` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::Appeals` (r:1 w:1)
+	/// Proof: `MobRule::Appeals` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
 	fn close_case() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `264`
-		//  Estimated: `3624`
-		// Minimum execution time: 14_918_000 picoseconds.
-		Weight::from_parts(22_373_000, 0)
-			.saturating_add(Weight::from_parts(0, 3624))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+		//  Estimated: `6196`
+		// Minimum execution time: 41_307_000 picoseconds.
+		Weight::from_parts(48_914_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(8))
 	}
 	/// Storage: `MobRule::OpenCases` (r:1 w:0)
-	/// Proof: `MobRule::OpenCases` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Proof: `MobRule::OpenCases` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
 	/// Storage: `MobRule::RipeCases` (r:1 w:0)
-	/// Proof: `MobRule::RipeCases` (`max_values`: None, `max_size`: Some(159), added: 2634, mode: `MaxEncodedLen`)
+	/// Proof: `MobRule::RipeCases` (`max_values`: None, `max_size`: Some(173), added: 2648, mode: `MaxEncodedLen`)
 	/// Storage: `MobRule::DoneCases` (r:1 w:0)
 	/// Proof: `MobRule::DoneCases` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
@@ -116,20 +135,26 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `MobRule::Votes` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
 	/// Storage: `MobRule::Credits` (r:1 w:1)
 	/// Proof: `MobRule::Credits` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::VoteWeights` (r:0 w:1)
+	/// Proof: `MobRule::VoteWeights` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
 	fn clean_vote() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `323`
-		//  Estimated: `3642`
+		//  Estimated: `3655`
 		// Minimum execution time: 19_778_000 picoseconds.
 		Weight::from_parts(26_620_000, 0)
-			.saturating_add(Weight::from_parts(0, 3642))
+			.saturating_add(Weight::from_parts(0, 3655))
 			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `MobRule::DoneCases` (r:1 w:1)
 	/// Proof: `MobRule::DoneCases` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::RevealPeriods` (r:0 w:1)
+	/// Proof: `MobRule::RevealPeriods` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::Juries` (r:0 w:1)
+	/// Proof: `MobRule::Juries` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	fn reap_case() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `228`
@@ -138,23 +163,31 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(15_850_000, 0)
 			.saturating_add(Weight::from_parts(0, 3495))
 			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `MobRule::OpenCases` (r:1 w:1)
-	/// Proof: `MobRule::OpenCases` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Proof: `MobRule::OpenCases` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
-	/// Storage: `MobRule::DoneCases` (r:0 w:1)
+	/// Storage: `MobRule::DoneCases` (r:0 w:2)
 	/// Proof: `MobRule::DoneCases` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::RipeCases` (r:1 w:1)
+	/// Proof: `MobRule::RipeCases` (`max_values`: None, `max_size`: Some(173), added: 2648, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::Appeals` (r:1 w:1)
+	/// Proof: `MobRule::Appeals` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
 	fn intervene() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `282`
-		//  Estimated: `3642`
-		// Minimum execution time: 13_826_000 picoseconds.
-		Weight::from_parts(21_170_000, 0)
-			.saturating_add(Weight::from_parts(0, 3642))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+		//  Estimated: `6196`
+		// Minimum execution time: 39_764_000 picoseconds.
+		Weight::from_parts(46_532_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(8))
 	}
 	/// Storage: `MobRule::Credits` (r:1 w:1)
 	/// Proof: `MobRule::Credits` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
@@ -168,6 +201,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `MobRule::VotingPoints` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
 	/// Storage: `MobRule::AccumulatedPoints` (r:1 w:1)
 	/// Proof: `MobRule::AccumulatedPoints` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::VoteWeights` (r:0 w:1)
+	/// Proof: `MobRule::VoteWeights` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
 	fn claim_vote() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `215`
@@ -176,7 +211,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(20_369_000, 0)
 			.saturating_add(Weight::from_parts(0, 3541))
 			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: `MobRule::Credits` (r:1 w:1)
 	/// Proof: `MobRule::Credits` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
@@ -190,6 +225,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `MobRule::VotingPoints` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
 	/// Storage: `MobRule::AccumulatedPoints` (r:1 w:1)
 	/// Proof: `MobRule::AccumulatedPoints` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::VoteWeights` (r:0 w:10)
+	/// Proof: `MobRule::VoteWeights` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
 	/// The range of component `v` is `[1, 10]`.
 	fn claim_votes(v: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -203,7 +240,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(v.into())))
 			.saturating_add(T::DbWeight::get().writes(3))
-			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(v.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(v.into())))
 			.saturating_add(Weight::from_parts(0, 2545).saturating_mul(v.into()))
 	}
 	/// Storage: `MobRule::Credits` (r:1 w:1)
@@ -305,39 +342,45 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `MobRule::OpenCases` (r:1 w:1)
-	/// Proof: `MobRule::OpenCases` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Proof: `MobRule::OpenCases` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `People::ActiveMembers` (r:1 w:0)
 	/// Proof: `People::ActiveMembers` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `MobRule::RipeCases` (r:0 w:1)
-	/// Proof: `MobRule::RipeCases` (`max_values`: None, `max_size`: Some(159), added: 2634, mode: `MaxEncodedLen`)
+	/// Proof: `MobRule::RipeCases` (`max_values`: None, `max_size`: Some(173), added: 2648, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::Juries` (r:1 w:0)
+	/// Proof: `MobRule::Juries` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::RevealPeriods` (r:1 w:0)
+	/// Proof: `MobRule::RevealPeriods` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
 	fn force_ripen_case() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `472`
-		//  Estimated: `3642`
+		//  Estimated: `3655`
 		// Minimum execution time: 14_568_000 picoseconds.
 		Weight::from_parts(21_030_000, 0)
-			.saturating_add(Weight::from_parts(0, 3642))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(Weight::from_parts(0, 3655))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `MobRule::OpenCases` (r:1 w:1)
-	/// Proof: `MobRule::OpenCases` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Proof: `MobRule::OpenCases` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `People::ActiveMembers` (r:1 w:0)
 	/// Proof: `People::ActiveMembers` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `MobRule::RipeCases` (r:0 w:1)
-	/// Proof: `MobRule::RipeCases` (`max_values`: None, `max_size`: Some(159), added: 2634, mode: `MaxEncodedLen`)
+	/// Proof: `MobRule::RipeCases` (`max_values`: None, `max_size`: Some(173), added: 2648, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::Juries` (r:1 w:0)
+	/// Proof: `MobRule::Juries` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	fn touch_case() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `506`
-		//  Estimated: `3642`
+		//  Estimated: `3655`
 		// Minimum execution time: 12_384_000 picoseconds.
 		Weight::from_parts(15_840_000, 0)
-			.saturating_add(Weight::from_parts(0, 3642))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(Weight::from_parts(0, 3655))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `MobRule::VotingPenalties` (r:1 w:1)
@@ -387,12 +430,130 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(0, 0))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `MobRule::OpenCases` (r:1 w:0)
+	/// Proof: `MobRule::OpenCases` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::RevealPeriods` (r:1 w:0)
+	/// Proof: `MobRule::RevealPeriods` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::Commitments` (r:1 w:1)
+	/// Proof: `MobRule::Commitments` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::Juries` (r:1 w:1)
+	/// Proof: `MobRule::Juries` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	fn commit_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `318`
+		//  Estimated: `3655`
+		// Minimum execution time: 13_207_000 picoseconds.
+		Weight::from_parts(14_019_000, 0)
+			.saturating_add(Weight::from_parts(0, 3655))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `MobRule::VotingPenalties` (r:1 w:0)
+	/// Proof: `MobRule::VotingPenalties` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::RevealPeriods` (r:1 w:0)
+	/// Proof: `MobRule::RevealPeriods` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::Commitments` (r:1 w:1)
+	/// Proof: `MobRule::Commitments` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::Credits` (r:1 w:1)
+	/// Proof: `MobRule::Credits` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::OpenCases` (r:1 w:1)
+	/// Proof: `MobRule::OpenCases` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
+	/// Storage: `People::ActiveMembers` (r:1 w:0)
+	/// Proof: `People::ActiveMembers` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::Votes` (r:0 w:1)
+	/// Proof: `MobRule::Votes` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::RipeCases` (r:0 w:1)
+	/// Proof: `MobRule::RipeCases` (`max_values`: None, `max_size`: Some(173), added: 2648, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::Juries` (r:1 w:0)
+	/// Proof: `MobRule::Juries` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::VoteWeights` (r:0 w:1)
+	/// Proof: `MobRule::VoteWeights` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	fn reveal_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `596`
+		//  Estimated: `3655`
+		// Minimum execution time: 31_446_000 picoseconds.
+		Weight::from_parts(33_180_000, 0)
+			.saturating_add(Weight::from_parts(0, 3655))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	/// Storage: `MobRule::Commitments` (r:1 w:1)
+	/// Proof: `MobRule::Commitments` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::RevealPeriods` (r:1 w:0)
+	/// Proof: `MobRule::RevealPeriods` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::VotingPenalties` (r:1 w:1)
+	/// Proof: `MobRule::VotingPenalties` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn clean_commitment() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `291`
+		//  Estimated: `3565`
+		// Minimum execution time: 13_774_000 picoseconds.
+		Weight::from_parts(14_532_000, 0)
+			.saturating_add(Weight::from_parts(0, 3565))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `MobRule::OpenCases` (r:1 w:0)
+	/// Proof: `MobRule::OpenCases` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::Juries` (r:1 w:1)
+	/// Proof: `MobRule::Juries` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::RevealPeriods` (r:1 w:0)
+	/// Proof: `MobRule::RevealPeriods` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn replace_absent_jurors() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `352`
+		//  Estimated: `3655`
+		// Minimum execution time: 12_688_000 picoseconds.
+		Weight::from_parts(13_315_000, 0)
+			.saturating_add(Weight::from_parts(0, 3655))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `MobRule::RipeCases` (r:1 w:1)
+	/// Proof: `MobRule::RipeCases` (`max_values`: None, `max_size`: Some(173), added: 2648, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::CaseCount` (r:1 w:1)
+	/// Proof: `MobRule::CaseCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `People::ActiveMembers` (r:1 w:0)
+	/// Proof: `People::ActiveMembers` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::OpenCases` (r:0 w:1)
+	/// Proof: `MobRule::OpenCases` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::Juries` (r:0 w:1)
+	/// Proof: `MobRule::Juries` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::Appeals` (r:0 w:1)
+	/// Proof: `MobRule::Appeals` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::RevealPeriods` (r:1 w:1)
+	/// Proof: `MobRule::RevealPeriods` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn appeal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `418`
+		//  Estimated: `3658`
+		// Minimum execution time: 38_921_000 picoseconds.
+		Weight::from_parts(40_187_000, 0)
+			.saturating_add(Weight::from_parts(0, 3658))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
 	/// Storage: `MobRule::OpenCases` (r:1 w:1)
-	/// Proof: `MobRule::OpenCases` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Proof: `MobRule::OpenCases` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
 	/// Storage: `MobRule::Credits` (r:1 w:1)
 	/// Proof: `MobRule::Credits` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
 	/// Storage: `MobRule::Votes` (r:1 w:1)
@@ -401,36 +562,50 @@ impl WeightInfo for () {
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `People::ActiveMembers` (r:1 w:0)
 	/// Proof: `People::ActiveMembers` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::RevealPeriods` (r:1 w:0)
+	/// Proof: `MobRule::RevealPeriods` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::Juries` (r:1 w:1)
+	/// Proof: `MobRule::Juries` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::VoteWeights` (r:1 w:1)
+	/// Proof: `MobRule::VoteWeights` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
 	fn vote() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `472`
-		//  Estimated: `3642`
+		//  Estimated: `3655`
 		// Minimum execution time: 21_732_000 picoseconds.
 		Weight::from_parts(23_975_000, 0)
-			.saturating_add(Weight::from_parts(0, 3642))
-			.saturating_add(RocksDbWeight::get().reads(5))
-			.saturating_add(RocksDbWeight::get().writes(3))
+			.saturating_add(Weight::from_parts(0, 3655))
+			.saturating_add(RocksDbWeight::get().reads(8))
+			.saturating_add(RocksDbWeight::get().writes(5))
 	}
-	/// Storage: `MobRule::RipeCases` (r:1 w:1)
-	/// Proof: `MobRule::RipeCases` (`max_values`: None, `max_size`: Some(159), added: 2634, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::RipeCases` (r:2 w:2)
+	/// Proof: `MobRule::RipeCases` (`max_values`: None, `max_size`: Some(173), added: 2648, mode: `MaxEncodedLen`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
-	/// Storage: `MobRule::DoneCases` (r:0 w:1)
+	/// Storage: `MobRule::DoneCases` (r:0 w:2)
 	/// Proof: `MobRule::DoneCases` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::RevealPeriods` (r:1 w:0)
+	/// Proof: `MobRule::RevealPeriods` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::Appeals` (r:1 w:1)
+	/// Proof: `MobRule::Appeals` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
 	fn close_case() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `264`
-		//  Estimated: `3624`
-		// Minimum execution time: 14_918_000 picoseconds.
-		Weight::from_parts(22_373_000, 0)
-			.saturating_add(Weight::from_parts(0, 3624))
-			.saturating_add(RocksDbWeight::get().reads(2))
-			.saturating_add(RocksDbWeight::get().writes(2))
+		//  Estimated: `6196`
+		// Minimum execution time: 41_307_000 picoseconds.
+		Weight::from_parts(48_914_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(RocksDbWeight::get().reads(8))
+			.saturating_add(RocksDbWeight::get().writes(8))
 	}
 	/// Storage: `MobRule::OpenCases` (r:1 w:0)
-	/// Proof: `MobRule::OpenCases` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Proof: `MobRule::OpenCases` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
 	/// Storage: `MobRule::RipeCases` (r:1 w:0)
-	/// Proof: `MobRule::RipeCases` (`max_values`: None, `max_size`: Some(159), added: 2634, mode: `MaxEncodedLen`)
+	/// Proof: `MobRule::RipeCases` (`max_values`: None, `max_size`: Some(173), added: 2648, mode: `MaxEncodedLen`)
 	/// Storage: `MobRule::DoneCases` (r:1 w:0)
 	/// Proof: `MobRule::DoneCases` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
@@ -439,20 +614,26 @@ impl WeightInfo for () {
 	/// Proof: `MobRule::Votes` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
 	/// Storage: `MobRule::Credits` (r:1 w:1)
 	/// Proof: `MobRule::Credits` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::VoteWeights` (r:0 w:1)
+	/// Proof: `MobRule::VoteWeights` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
 	fn clean_vote() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `323`
-		//  Estimated: `3642`
+		//  Estimated: `3655`
 		// Minimum execution time: 19_778_000 picoseconds.
 		Weight::from_parts(26_620_000, 0)
-			.saturating_add(Weight::from_parts(0, 3642))
+			.saturating_add(Weight::from_parts(0, 3655))
 			.saturating_add(RocksDbWeight::get().reads(6))
-			.saturating_add(RocksDbWeight::get().writes(2))
+			.saturating_add(RocksDbWeight::get().writes(3))
 	}
 	/// Storage: `MobRule::DoneCases` (r:1 w:1)
 	/// Proof: `MobRule::DoneCases` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::RevealPeriods` (r:0 w:1)
+	/// Proof: `MobRule::RevealPeriods` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::Juries` (r:0 w:1)
+	/// Proof: `MobRule::Juries` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	fn reap_case() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `228`
@@ -461,23 +642,31 @@ impl WeightInfo for () {
 		Weight::from_parts(15_850_000, 0)
 			.saturating_add(Weight::from_parts(0, 3495))
 			.saturating_add(RocksDbWeight::get().reads(2))
-			.saturating_add(RocksDbWeight::get().writes(1))
+			.saturating_add(RocksDbWeight::get().writes(3))
 	}
 	/// Storage: `MobRule::OpenCases` (r:1 w:1)
-	/// Proof: `MobRule::OpenCases` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Proof: `MobRule::OpenCases` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
-	/// Storage: `MobRule::DoneCases` (r:0 w:1)
+	/// Storage: `MobRule::DoneCases` (r:0 w:2)
 	/// Proof: `MobRule::DoneCases` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::RipeCases` (r:1 w:1)
+	/// Proof: `MobRule::RipeCases` (`max_values`: None, `max_size`: Some(173), added: 2648, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::Appeals` (r:1 w:1)
+	/// Proof: `MobRule::Appeals` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
 	fn intervene() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `282`
-		//  Estimated: `3642`
-		// Minimum execution time: 13_826_000 picoseconds.
-		Weight::from_parts(21_170_000, 0)
-			.saturating_add(Weight::from_parts(0, 3642))
-			.saturating_add(RocksDbWeight::get().reads(2))
-			.saturating_add(RocksDbWeight::get().writes(2))
+		//  Estimated: `6196`
+		// Minimum execution time: 39_764_000 picoseconds.
+		Weight::from_parts(46_532_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(RocksDbWeight::get().reads(7))
+			.saturating_add(RocksDbWeight::get().writes(8))
 	}
 	/// Storage: `MobRule::Credits` (r:1 w:1)
 	/// Proof: `MobRule::Credits` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
@@ -491,6 +680,8 @@ impl WeightInfo for () {
 	/// Proof: `MobRule::VotingPoints` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
 	/// Storage: `MobRule::AccumulatedPoints` (r:1 w:1)
 	/// Proof: `MobRule::AccumulatedPoints` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::VoteWeights` (r:0 w:1)
+	/// Proof: `MobRule::VoteWeights` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
 	fn claim_vote() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `215`
@@ -499,7 +690,7 @@ impl WeightInfo for () {
 		Weight::from_parts(20_369_000, 0)
 			.saturating_add(Weight::from_parts(0, 3541))
 			.saturating_add(RocksDbWeight::get().reads(6))
-			.saturating_add(RocksDbWeight::get().writes(4))
+			.saturating_add(RocksDbWeight::get().writes(5))
 	}
 	/// Storage: `MobRule::Credits` (r:1 w:1)
 	/// Proof: `MobRule::Credits` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
@@ -513,6 +704,8 @@ impl WeightInfo for () {
 	/// Proof: `MobRule::VotingPoints` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
 	/// Storage: `MobRule::AccumulatedPoints` (r:1 w:1)
 	/// Proof: `MobRule::AccumulatedPoints` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::VoteWeights` (r:0 w:10)
+	/// Proof: `MobRule::VoteWeights` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
 	/// The range of component `v` is `[1, 10]`.
 	fn claim_votes(v: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -526,7 +719,7 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(4))
 			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(v.into())))
 			.saturating_add(RocksDbWeight::get().writes(3))
-			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(v.into())))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(v.into())))
 			.saturating_add(Weight::from_parts(0, 2545).saturating_mul(v.into()))
 	}
 	/// Storage: `MobRule::Credits` (r:1 w:1)
@@ -628,39 +821,45 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(1))
 	}
 	/// Storage: `MobRule::OpenCases` (r:1 w:1)
-	/// Proof: `MobRule::OpenCases` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Proof: `MobRule::OpenCases` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `People::ActiveMembers` (r:1 w:0)
 	/// Proof: `People::ActiveMembers` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `MobRule::RipeCases` (r:0 w:1)
-	/// Proof: `MobRule::RipeCases` (`max_values`: None, `max_size`: Some(159), added: 2634, mode: `MaxEncodedLen`)
+	/// Proof: `MobRule::RipeCases` (`max_values`: None, `max_size`: Some(173), added: 2648, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::Juries` (r:1 w:0)
+	/// Proof: `MobRule::Juries` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::RevealPeriods` (r:1 w:0)
+	/// Proof: `MobRule::RevealPeriods` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
 	fn force_ripen_case() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `472`
-		//  Estimated: `3642`
+		//  Estimated: `3655`
 		// Minimum execution time: 14_568_000 picoseconds.
 		Weight::from_parts(21_030_000, 0)
-			.saturating_add(Weight::from_parts(0, 3642))
-			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(Weight::from_parts(0, 3655))
+			.saturating_add(RocksDbWeight::get().reads(5))
 			.saturating_add(RocksDbWeight::get().writes(2))
 	}
 	/// Storage: `MobRule::OpenCases` (r:1 w:1)
-	/// Proof: `MobRule::OpenCases` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Proof: `MobRule::OpenCases` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `People::ActiveMembers` (r:1 w:0)
 	/// Proof: `People::ActiveMembers` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `MobRule::RipeCases` (r:0 w:1)
-	/// Proof: `MobRule::RipeCases` (`max_values`: None, `max_size`: Some(159), added: 2634, mode: `MaxEncodedLen`)
+	/// Proof: `MobRule::RipeCases` (`max_values`: None, `max_size`: Some(173), added: 2648, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::Juries` (r:1 w:0)
+	/// Proof: `MobRule::Juries` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	fn touch_case() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `506`
-		//  Estimated: `3642`
+		//  Estimated: `3655`
 		// Minimum execution time: 12_384_000 picoseconds.
 		Weight::from_parts(15_840_000, 0)
-			.saturating_add(Weight::from_parts(0, 3642))
-			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(Weight::from_parts(0, 3655))
+			.saturating_add(RocksDbWeight::get().reads(4))
 			.saturating_add(RocksDbWeight::get().writes(2))
 	}
 	/// Storage: `MobRule::VotingPenalties` (r:1 w:1)
@@ -710,4 +909,122 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(0, 0))
 			.saturating_add(RocksDbWeight::get().writes(1))
 	}
+	/// Storage: `MobRule::OpenCases` (r:1 w:0)
+	/// Proof: `MobRule::OpenCases` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::RevealPeriods` (r:1 w:0)
+	/// Proof: `MobRule::RevealPeriods` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::Commitments` (r:1 w:1)
+	/// Proof: `MobRule::Commitments` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::Juries` (r:1 w:1)
+	/// Proof: `MobRule::Juries` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	fn commit_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `318`
+		//  Estimated: `3655`
+		// Minimum execution time: 13_207_000 picoseconds.
+		Weight::from_parts(14_019_000, 0)
+			.saturating_add(Weight::from_parts(0, 3655))
+			.saturating_add(RocksDbWeight::get().reads(5))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: `MobRule::VotingPenalties` (r:1 w:0)
+	/// Proof: `MobRule::VotingPenalties` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::RevealPeriods` (r:1 w:0)
+	/// Proof: `MobRule::RevealPeriods` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::Commitments` (r:1 w:1)
+	/// Proof: `MobRule::Commitments` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::Credits` (r:1 w:1)
+	/// Proof: `MobRule::Credits` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::OpenCases` (r:1 w:1)
+	/// Proof: `MobRule::OpenCases` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
+	/// Storage: `People::ActiveMembers` (r:1 w:0)
+	/// Proof: `People::ActiveMembers` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::Votes` (r:0 w:1)
+	/// Proof: `MobRule::Votes` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::RipeCases` (r:0 w:1)
+	/// Proof: `MobRule::RipeCases` (`max_values`: None, `max_size`: Some(173), added: 2648, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::Juries` (r:1 w:0)
+	/// Proof: `MobRule::Juries` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::VoteWeights` (r:0 w:1)
+	/// Proof: `MobRule::VoteWeights` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	fn reveal_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `596`
+		//  Estimated: `3655`
+		// Minimum execution time: 31_446_000 picoseconds.
+		Weight::from_parts(33_180_000, 0)
+			.saturating_add(Weight::from_parts(0, 3655))
+			.saturating_add(RocksDbWeight::get().reads(8))
+			.saturating_add(RocksDbWeight::get().writes(6))
+	}
+	/// Storage: `MobRule::Commitments` (r:1 w:1)
+	/// Proof: `MobRule::Commitments` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::RevealPeriods` (r:1 w:0)
+	/// Proof: `MobRule::RevealPeriods` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::VotingPenalties` (r:1 w:1)
+	/// Proof: `MobRule::VotingPenalties` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn clean_commitment() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `291`
+		//  Estimated: `3565`
+		// Minimum execution time: 13_774_000 picoseconds.
+		Weight::from_parts(14_532_000, 0)
+			.saturating_add(Weight::from_parts(0, 3565))
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: `MobRule::OpenCases` (r:1 w:0)
+	/// Proof: `MobRule::OpenCases` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::Juries` (r:1 w:1)
+	/// Proof: `MobRule::Juries` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::RevealPeriods` (r:1 w:0)
+	/// Proof: `MobRule::RevealPeriods` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn replace_absent_jurors() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `352`
+		//  Estimated: `3655`
+		// Minimum execution time: 12_688_000 picoseconds.
+		Weight::from_parts(13_315_000, 0)
+			.saturating_add(Weight::from_parts(0, 3655))
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: `MobRule::RipeCases` (r:1 w:1)
+	/// Proof: `MobRule::RipeCases` (`max_values`: None, `max_size`: Some(173), added: 2648, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::CaseCount` (r:1 w:1)
+	/// Proof: `MobRule::CaseCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `People::ActiveMembers` (r:1 w:0)
+	/// Proof: `People::ActiveMembers` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::OpenCases` (r:0 w:1)
+	/// Proof: `MobRule::OpenCases` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::Juries` (r:0 w:1)
+	/// Proof: `MobRule::Juries` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::Appeals` (r:0 w:1)
+	/// Proof: `MobRule::Appeals` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::RevealPeriods` (r:1 w:1)
+	/// Proof: `MobRule::RevealPeriods` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn appeal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `418`
+		//  Estimated: `3658`
+		// Minimum execution time: 38_921_000 picoseconds.
+		Weight::from_parts(40_187_000, 0)
+			.saturating_add(Weight::from_parts(0, 3658))
+			.saturating_add(RocksDbWeight::get().reads(7))
+			.saturating_add(RocksDbWeight::get().writes(8))
+	}
 }
@@ -349,6 +349,26 @@ impl AppendOnlyMembers for MockMemberService {
 		Ok(())
 	}
 
+	fn rotate_member(
+		_owner: Self::Location,
+		identifier: &Identifier,
+		old: <Self::Crypto as verifiable::GenerateVerifiable>::Member,
+		new: <Self::Crypto as verifiable::GenerateVerifiable>::Member,
+	) -> frame_support::dispatch::DispatchResult {
+		MOCK_COLLECTION_MEMBERS.with(|members_by_collection| {
+			let mut members_by_collection = members_by_collection.borrow_mut();
+			let members = members_by_collection
+				.get_mut(identifier)
+				.ok_or(DispatchError::Other("mock collection not found"))?;
+			let position = members
+				.iter()
+				.position(|existing| *existing == old)
+				.ok_or(DispatchError::Other("mock member not found"))?;
+			members[position] = new;
+			Ok(())
+		})
+	}
+
 	fn remove_ring(
 		_identifier: &Identifier,
 		_ring_index: RingIndex,
@@ -360,6 +380,10 @@ impl AppendOnlyMembers for MockMemberService {
 		None
 	}
 
+	fn is_ring_provable(_identifier: &Identifier, _ring_index: RingIndex) -> bool {
+		false
+	}
+
 	fn member_status(
 		identifier: &Identifier,
 		member: &<Self::Crypto as verifiable::GenerateVerifiable>::Member,
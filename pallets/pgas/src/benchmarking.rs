@@ -19,7 +19,10 @@
 #![allow(unused)]
 
 use super::*;
-use crate::{extension::AsPgas, Pallet as Pgas};
+use crate::{
+	extension::{AsPgas, ChargeAliasPgas},
+	Pallet as Pgas,
+};
 use codec::Encode;
 use core::time::Duration;
 use frame_benchmarking::v2::{benchmarks, *};
@@ -27,13 +30,14 @@ use frame_support::{
 	dispatch::{DispatchInfo, GetDispatchInfo, PostDispatchInfo},
 	traits::{
 		fungibles::{Create, Inspect},
-		Get, IsSubType,
+		EnsureOrigin, Get, IsSubType,
 	},
+	BoundedVec,
 };
 use frame_system::{offchain::CreateAuthorizedTransaction, RawOrigin as SystemOrigin};
 use indiv_support::traits::{Identifier, RingIndex};
 use sp_runtime::traits::{
-	AsTransactionAuthorizedOrigin, DispatchTransaction, Dispatchable, TxBaseImplication,
+	AsTransactionAuthorizedOrigin, Bounded, DispatchTransaction, Dispatchable, TxBaseImplication,
 };
 use verifiable::GenerateVerifiable;
 
@@ -62,6 +66,15 @@ pub trait BenchmarkHelper<T: Config> {
 		context: &Context,
 		message: &[u8],
 	) -> ProofOf<T>;
+
+	/// Same as [`Self::seed_and_create_proof`], but return a multi-context proof that will
+	/// verify against all of `contexts` at once.
+	fn seed_and_create_multi_context_proof(
+		identifier: &Identifier,
+		ring_index: RingIndex,
+		contexts: &[Context],
+		message: &[u8],
+	) -> ProofOf<T>;
 }
 
 #[benchmarks(
@@ -231,5 +244,205 @@ mod benches {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn claim_pgas_for_alias() -> Result<(), BenchmarkError> {
+		T::BenchmarkHelper::set_time(Duration::from_secs(86400));
+
+		let alias: Alias = [0x42u8; 32];
+		let day = Day::from(1u32);
+		let origin: T::RuntimeOrigin =
+			crate::Origin::ClaimAlias { alias, day, collection: PgasCollection::People }.into();
+
+		let beneficiary: Alias = [0x24u8; 32];
+
+		#[extrinsic_call]
+		_(origin, 0u32, beneficiary);
+
+		assert!(ClaimedGasAliases::<T>::contains_key(day, alias));
+		assert_eq!(Pgas::<T>::alias_pgas_balance(&beneficiary), T::PgasClaimAmount::get());
+		Ok(())
+	}
+
+	/// Weight of the [`ChargeAliasPgas`] transaction extension charging an alias.
+	#[benchmark]
+	fn charge_alias_pgas_tx_ext() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AliasFeeOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let alias = T::AliasFeeOrigin::ensure_origin(origin.clone())
+			.map_err(|_| BenchmarkError::Weightless)?;
+		Pgas::<T>::deposit_alias_pgas(&alias, PgasBalanceOf::<T>::max_value());
+
+		let call: <T as frame_system::Config>::RuntimeCall =
+			frame_system::Call::<T>::remark { remark: alloc::vec![] }.into();
+		let info = call.get_dispatch_info();
+		let len = call.encoded_size();
+		let tx_ext = ChargeAliasPgas::<T>::new();
+
+		#[block]
+		{
+			tx_ext
+				.test_run(origin, &call, &info, len, 0, |_| Ok(Default::default()))
+				.expect("test_run must produce a result")
+				.expect("dispatch substitute must succeed");
+		}
+
+		assert!(Pgas::<T>::alias_pgas_balance(&alias) < PgasBalanceOf::<T>::max_value());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn claim_accrued_pgas(
+		n: Linear<1, { T::MaxAccruedClaims::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let day_index = T::MaxAccruedClaims::get();
+		T::BenchmarkHelper::set_time(Duration::from_secs(SECS_PER_DAY * day_index as u64));
+
+		let admin: T::AccountId = account("admin", 0, 0);
+		frame_system::Pallet::<T>::inc_sufficients(&admin);
+		T::Fungibles::create(T::PgasAssetId::get(), admin, true, T::PgasMinBalance::get())
+			.expect("asset creation should work");
+
+		// Worst case: every day of the window is unclaimed.
+		let aliases: BoundedVec<Alias, _> = (0..n)
+			.map(|i| {
+				let mut alias: Alias = [0x42u8; 32];
+				alias[0..4].copy_from_slice(&i.to_le_bytes());
+				alias
+			})
+			.collect::<alloc::vec::Vec<_>>()
+			.try_into()
+			.expect("`MaxAccruedClaims` is at most `MAX_ACCRUAL_WINDOW`");
+		let day = Day::from(day_index);
+		let origin: T::RuntimeOrigin = crate::Origin::AccrualAliases {
+			aliases: aliases.clone(),
+			day,
+			collection: PgasCollection::People,
+		}
+		.into();
+
+		let target: T::AccountId = account("target", 0, 0);
+
+		#[extrinsic_call]
+		_(origin, 0u32, target.clone());
+
+		for (window_day, alias) in Pgas::<T>::accrual_window(day_index).zip(aliases.iter()) {
+			assert!(ClaimedGasAliases::<T>::contains_key(Day::from(window_day), alias));
+		}
+		Ok(())
+	}
+
+	/// Weight of the [`AsPgas`] transaction extension for a successful accrued claim over an
+	/// accrual window of `n` days.
+	#[benchmark]
+	fn as_pgas_claim_accrued_tx_ext(
+		n: Linear<1, { T::MaxAccruedClaims::get() }>,
+	) -> Result<(), BenchmarkError> {
+		// Middle of the day whose accrual window spans `n` days.
+		let now = Duration::from_secs(SECS_PER_DAY * (n as u64 - 1) + SECS_PER_DAY / 2);
+		T::BenchmarkHelper::set_time(now);
+
+		// Asset must exist.
+		let admin: T::AccountId = account("pgas_admin", 0, 0);
+		frame_system::Pallet::<T>::inc_sufficients(&admin);
+		T::Fungibles::create(T::PgasAssetId::get(), admin, true, T::PgasMinBalance::get())
+			.expect("asset create should succeed");
+
+		let target: T::AccountId = whitelisted_caller();
+		let slot_index = 0u32;
+		let call = Call::<T>::claim_accrued_pgas { slot_index, target };
+		let call: <T as frame_system::Config>::RuntimeCall = call.into();
+		let extension_version = 0u8;
+
+		let msg =
+			TxBaseImplication((extension_version, &call)).using_encoded(sp_io::hashing::blake2_256);
+		let day = Pgas::<T>::current_day();
+		let contexts: alloc::vec::Vec<_> = Pgas::<T>::accrual_window(day)
+			.map(|window_day| Pgas::<T>::build_gas_context(window_day, slot_index))
+			.collect();
+		assert_eq!(contexts.len(), n as usize);
+		let identifier = PgasCollection::People.identifier();
+		let ring_index = 0u32;
+
+		let proof = T::BenchmarkHelper::seed_and_create_multi_context_proof(
+			&identifier,
+			ring_index,
+			&contexts,
+			&msg,
+		);
+		let revision =
+			<T::MembershipProver as MembershipProver>::ring_revision(&identifier, ring_index)
+				.expect("`seed_and_create_multi_context_proof` must leave a ring in place");
+
+		let tx_ext = AsPgas::<T>::new(Some(AsPgasInfo::ClaimAccrued {
+			proof,
+			ring_index,
+			revision,
+			collection: PgasCollection::People,
+			day,
+		}));
+		let info = call.get_dispatch_info();
+		let len = call.encoded_size();
+
+		#[block]
+		{
+			tx_ext
+				.test_run(SystemOrigin::None.into(), &call, &info, len, extension_version, |_| {
+					Ok(Default::default())
+				})
+				.expect("test_run must produce a result")
+				.expect("dispatch substitute must succeed");
+		}
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn clean_expired_alias_pgas(
+		n: Linear<1, { T::MaxPgasClaimRecordCleanupPerCall::get() }>,
+	) -> Result<(), BenchmarkError> {
+		for i in 0..n {
+			let mut alias: Alias = [0u8; 32];
+			alias[0..4].copy_from_slice(&i.to_le_bytes());
+			Pgas::<T>::deposit_alias_pgas(&alias, T::PgasClaimAmount::get());
+		}
+		let day_index = Pgas::<T>::current_day() + T::AliasPgasLifetime::get();
+		let day = Day::from(day_index);
+		T::BenchmarkHelper::set_time(Duration::from_secs(SECS_PER_DAY * day_index as u64));
+
+		assert_eq!(AliasPgasExpiries::<T>::iter_prefix(day).count(), n as usize);
+
+		let first_alias = AliasPgasExpiries::<T>::iter_key_prefix(day)
+			.next()
+			.expect("at least one balance credited above");
+
+		#[extrinsic_call]
+		_(SystemOrigin::Authorized, day_index, first_alias);
+
+		assert!(AliasPgasExpiries::<T>::iter_prefix(day).next().is_none());
+		assert!(AliasPgasBalances::<T>::iter().next().is_none());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn authorize_clean_expired_alias_pgas() -> Result<(), BenchmarkError> {
+		use sp_runtime::transaction_validity::TransactionSource;
+		let alias: Alias = [0u8; 32];
+		Pgas::<T>::deposit_alias_pgas(&alias, T::PgasClaimAmount::get());
+		let day_index = Pgas::<T>::current_day() + T::AliasPgasLifetime::get();
+		T::BenchmarkHelper::set_time(Duration::from_secs(SECS_PER_DAY * day_index as u64));
+
+		#[block]
+		{
+			Pgas::<T>::authorize_clean_expired_alias_pgas(
+				TransactionSource::Local,
+				day_index,
+				alias,
+			)
+			.expect("authorize should succeed");
+		}
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -21,27 +21,42 @@
 //! [`Call::claim_pgas`](crate::Call::claim_pgas) can mint to the requested `target` without
 //! requiring the caller to be a signed account on the chain hosting this pallet (typically
 //! Asset Hub).
+//!
+//! With [`AsPgasInfo::ClaimAccrued`], the proof yields the aliases of the slot for every day of
+//! its accrual window and the origin becomes [`Origin::AccrualAliases`], as required by
+//! [`Call::claim_accrued_pgas`](crate::Call::claim_accrued_pgas).
+//!
+//! [`ChargeAliasPgas`] lets origins accepted by [`Config::AliasFeeOrigin`] pay their transaction
+//! fee from the PGAS balance attached to their alias (see
+//! [`Call::claim_pgas_for_alias`](crate::Call::claim_pgas_for_alias)).
 
 use crate::{
-	pallet::Day, weights::WeightInfo as _, ClaimedGasAliases, Config, Origin, Pallet, ProofOf,
+	pallet::Day, weights::WeightInfo as _, ClaimedGasAliases, Config, Event, Origin, Pallet,
+	PgasBalanceOf, ProofOf,
 };
+use alloc::vec::Vec;
 use codec::{Decode, DecodeWithMemTracking, Encode};
 use core::fmt;
 use frame_support::{
 	ensure,
 	pallet_prelude::TransactionSource,
-	traits::{fungibles::Inspect, IsSubType, OriginTrait},
+	traits::{fungibles::Inspect, EnsureOrigin, IsSubType, OriginTrait},
 	weights::Weight,
-	CloneNoBound, DefaultNoBound, EqNoBound, PartialEqNoBound,
+	BoundedVec, CloneNoBound, DefaultNoBound, EqNoBound, PartialEqNoBound,
 };
-use indiv_support::traits::{
-	MembershipProver, RevisionIndex, RingIndex, PEOPLE_IDENTIFIER, PEOPLE_LITE_IDENTIFIER,
+use indiv_support::{
+	pgas::PgasFee,
+	traits::{
+		Alias, MembershipMultiProver, MembershipProver, RevisionIndex, RingIndex,
+		PEOPLE_IDENTIFIER, PEOPLE_LITE_IDENTIFIER,
+	},
 };
 use scale_info::TypeInfo;
 use sp_core::twox_64;
 use sp_runtime::{
-	traits::{DispatchInfoOf, Get, TransactionExtension, ValidateResult},
+	traits::{DispatchInfoOf, Get, PostDispatchInfoOf, TransactionExtension, ValidateResult, Zero},
 	transaction_validity::{InvalidTransaction, TransactionValidityError, ValidTransaction},
+	Saturating,
 };
 
 /// Custom invalidity reasons surfaced by this pallet's transaction extension and authorize
@@ -54,7 +69,8 @@ pub enum CustomValidity {
 	/// The `day` the caller built the proof for is neither the current day nor (while the grace
 	/// window is open) the previous day.
 	InvalidClaimDay = 231,
-	/// The (day, alias) pair has already been used this period.
+	/// The (day, alias) pair has already been used this period, or every day of the accrual
+	/// window has already been claimed.
 	AlreadyClaimed = 232,
 	/// The PGAS asset has not been created yet — `create_pgas_asset` must be called first.
 	PgasAssetNotCreated = 233,
@@ -62,6 +78,8 @@ pub enum CustomValidity {
 	/// currently stored under the given `day_index` prefix — the prefix state has moved on
 	/// since the caller constructed the call.
 	FirstAliasMismatch = 234,
+	/// The PGAS balance attached to the alias cannot cover the transaction fee.
+	InsufficientAliasPgas = 235,
 }
 
 impl From<CustomValidity> for TransactionValidityError {
@@ -130,6 +148,19 @@ pub enum AsPgasInfo<T: Config> {
 		collection: PgasCollection,
 		day: u32,
 	},
+	/// Claim the PGAS accrued by a claim slot of the specified ring collection.
+	///
+	/// Same as [`AsPgasInfo::Claim`], except that the proof is a multi-context proof over
+	/// `build_gas_context(d, slot_index)` for every day `d` of
+	/// [`Pallet::accrual_window`]`(day)`, in ascending order, verified via
+	/// [`MembershipMultiProver::verify_membership_multi_context_at_rev`].
+	ClaimAccrued {
+		proof: ProofOf<T>,
+		ring_index: RingIndex,
+		revision: RevisionIndex,
+		collection: PgasCollection,
+		day: u32,
+	},
 }
 
 /// Extension that validates a PGAS claim proof and replaces the outer origin with
@@ -173,8 +204,10 @@ impl<T: Config> AsPgas<T> {
 			InvalidTransaction::BadSigner
 		);
 
-		let slot_index: u32 = match call.is_sub_type() {
-			Some(crate::Call::<T>::claim_pgas { slot_index, .. }) => *slot_index,
+		let (slot_index, mints) = match call.is_sub_type() {
+			Some(crate::Call::<T>::claim_pgas { slot_index, .. }) => (*slot_index, true),
+			Some(crate::Call::<T>::claim_pgas_for_alias { slot_index, .. }) =>
+				(*slot_index, false),
 			_ => return Err(InvalidTransaction::Call.into()),
 		};
 
@@ -186,15 +219,11 @@ impl<T: Config> AsPgas<T> {
 		// Reject early if the PGAS asset has not been created yet — minting in dispatch would
 		// fail anyway, and we'd rather not run proof verification for a claim that can't settle.
 		ensure!(
-			T::Fungibles::asset_exists(T::PgasAssetId::get()),
+			!mints || T::Fungibles::asset_exists(T::PgasAssetId::get()),
 			CustomValidity::PgasAssetNotCreated
 		);
 
-		// Accept only the current day, or the previous day while still within the grace window.
-		// When outside the grace window, `grace_day() == current_day()`.
-		let current_day = Pallet::<T>::current_day();
-		let grace_day = Pallet::<T>::grace_day();
-		ensure!(day == current_day || day == grace_day, CustomValidity::InvalidClaimDay);
+		Self::ensure_claimable_day(day)?;
 
 		let identifier = collection.identifier();
 		let context = Pallet::<T>::build_gas_context(day, slot_index);
@@ -227,6 +256,93 @@ impl<T: Config> AsPgas<T> {
 
 		Ok((validity.into(), (), origin))
 	}
+
+	fn validate_accrued_claim(
+		origin: <T as frame_system::Config>::RuntimeOrigin,
+		call: &<T as frame_system::Config>::RuntimeCall,
+		inherited_implication: &impl Encode,
+		proof: &ProofOf<T>,
+		ring_index: RingIndex,
+		revision: RevisionIndex,
+		collection: PgasCollection,
+		day: u32,
+	) -> ValidateResult<(), <T as frame_system::Config>::RuntimeCall> {
+		ensure!(
+			matches!(origin.as_system_ref(), Some(frame_system::RawOrigin::None)),
+			InvalidTransaction::BadSigner
+		);
+
+		let slot_index: u32 = match call.is_sub_type() {
+			Some(crate::Call::<T>::claim_accrued_pgas { slot_index, .. }) => *slot_index,
+			_ => return Err(InvalidTransaction::Call.into()),
+		};
+
+		ensure!(
+			slot_index < Pallet::<T>::max_claims_for(collection),
+			CustomValidity::InvalidClaimSlot
+		);
+		ensure!(
+			T::Fungibles::asset_exists(T::PgasAssetId::get()),
+			CustomValidity::PgasAssetNotCreated
+		);
+		Self::ensure_claimable_day(day)?;
+
+		let window = Pallet::<T>::accrual_window(day);
+		// Accrual is disabled.
+		ensure!(!window.is_empty(), InvalidTransaction::Call);
+
+		let identifier = collection.identifier();
+		let contexts: Vec<_> = window
+			.clone()
+			.map(|window_day| Pallet::<T>::build_gas_context(window_day, slot_index))
+			.collect();
+		let msg = inherited_implication.using_encoded(sp_io::hashing::blake2_256);
+
+		let aliases = T::MembershipProver::verify_membership_multi_context_at_rev(
+			&identifier,
+			proof,
+			ring_index,
+			revision,
+			&contexts,
+			&msg[..],
+		)
+		.map_err(|_| InvalidTransaction::BadProof)?;
+		let aliases: BoundedVec<_, _> = aliases
+			.into_iter()
+			.map(|ca| ca.alias)
+			.collect::<Vec<_>>()
+			.try_into()
+			.map_err(|_| InvalidTransaction::BadProof)?;
+		ensure!(aliases.len() == window.len(), InvalidTransaction::BadProof);
+
+		// Pool-hygiene pre-check; dispatch re-checks authoritatively.
+		let unclaimed = Pallet::<T>::unclaimed_days(&aliases, day);
+		ensure!(!unclaimed.is_empty(), CustomValidity::AlreadyClaimed);
+
+		// Provide the same tags as the daily claims of the unclaimed days, so that the pool
+		// keeps a single claim per (day, slot).
+		let mut validity = ValidTransaction::with_tag_prefix("Pgas:Claim");
+		for (unclaimed_day, alias) in unclaimed {
+			validity = validity.and_provides(twox_64(
+				&("pgas-slot", identifier, alias, unclaimed_day, slot_index).encode(),
+			));
+		}
+
+		let local_origin = Origin::AccrualAliases { aliases, day: Day::from(day), collection };
+		let mut origin = origin;
+		origin.set_caller_from(local_origin);
+
+		Ok((validity.into(), (), origin))
+	}
+
+	/// Accept only the current day, or the previous day while still within the grace window.
+	/// When outside the grace window, `grace_day() == current_day()`.
+	fn ensure_claimable_day(day: u32) -> Result<(), TransactionValidityError> {
+		let current_day = Pallet::<T>::current_day();
+		let grace_day = Pallet::<T>::grace_day();
+		ensure!(day == current_day || day == grace_day, CustomValidity::InvalidClaimDay);
+		Ok(())
+	}
 }
 
 impl<T: Config> TransactionExtension<<T as frame_system::Config>::RuntimeCall> for AsPgas<T> {
@@ -238,6 +354,8 @@ impl<T: Config> TransactionExtension<<T as frame_system::Config>::RuntimeCall> f
 	fn weight(&self, _call: &<T as frame_system::Config>::RuntimeCall) -> Weight {
 		match self.0 {
 			Some(AsPgasInfo::Claim { .. }) => <T as Config>::WeightInfo::as_pgas_claim_tx_ext(),
+			Some(AsPgasInfo::ClaimAccrued { .. }) =>
+				<T as Config>::WeightInfo::as_pgas_claim_accrued_tx_ext(T::MaxAccruedClaims::get()),
 			None => Weight::zero(),
 		}
 	}
@@ -264,6 +382,17 @@ impl<T: Config> TransactionExtension<<T as frame_system::Config>::RuntimeCall> f
 					*collection,
 					*day,
 				),
+			Some(AsPgasInfo::ClaimAccrued { proof, ring_index, revision, collection, day }) =>
+				Self::validate_accrued_claim(
+					origin,
+					call,
+					inherited_implication,
+					proof,
+					*ring_index,
+					*revision,
+					*collection,
+					*day,
+				),
 			None => Ok((ValidTransaction::default(), (), origin)),
 		}
 	}
@@ -279,3 +408,121 @@ impl<T: Config> TransactionExtension<<T as frame_system::Config>::RuntimeCall> f
 		Ok(())
 	}
 }
+
+/// Extension that pays the transaction fee from the PGAS balance attached to the alias of an
+/// origin accepted by [`Config::AliasFeeOrigin`].
+///
+/// Other origins are left untouched, and so are free transactions and the claims of this pallet,
+/// which are refunded by their dispatch. The fee estimated by [`Config::AliasFee`] is withdrawn
+/// before dispatch and the unused part is refunded afterwards. Withdrawn PGAS is not credited
+/// anywhere, mirroring the burn of PGAS fees paid by accounts.
+///
+/// The extension must come after the extensions producing the alias origins.
+#[derive(
+	Encode,
+	Decode,
+	TypeInfo,
+	EqNoBound,
+	CloneNoBound,
+	PartialEqNoBound,
+	DefaultNoBound,
+	DecodeWithMemTracking,
+)]
+#[scale_info(skip_type_params(T))]
+pub struct ChargeAliasPgas<T: Config>(core::marker::PhantomData<T>);
+
+impl<T: Config> fmt::Debug for ChargeAliasPgas<T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "ChargeAliasPgas")
+	}
+}
+
+impl<T: Config> ChargeAliasPgas<T> {
+	pub fn new() -> Self {
+		Self(Default::default())
+	}
+}
+
+impl<T: Config> TransactionExtension<<T as frame_system::Config>::RuntimeCall>
+	for ChargeAliasPgas<T>
+{
+	const IDENTIFIER: &'static str = "ChargeAliasPgas";
+	type Implicit = ();
+	/// The paying alias and the withdrawn fee, if the transaction is charged.
+	type Val = Option<(Alias, PgasBalanceOf<T>)>;
+	type Pre = Option<(Alias, PgasBalanceOf<T>)>;
+
+	fn weight(&self, _call: &<T as frame_system::Config>::RuntimeCall) -> Weight {
+		<T as Config>::WeightInfo::charge_alias_pgas_tx_ext()
+	}
+
+	fn validate(
+		&self,
+		origin: <T as frame_system::Config>::RuntimeOrigin,
+		call: &<T as frame_system::Config>::RuntimeCall,
+		info: &DispatchInfoOf<<T as frame_system::Config>::RuntimeCall>,
+		len: usize,
+		_self_implicit: Self::Implicit,
+		_inherited_implication: &impl Encode,
+		_source: TransactionSource,
+	) -> ValidateResult<Self::Val, <T as frame_system::Config>::RuntimeCall> {
+		if matches!(
+			call.is_sub_type(),
+			Some(
+				crate::Call::<T>::claim_pgas { .. } |
+					crate::Call::<T>::claim_pgas_for_alias { .. } |
+					crate::Call::<T>::claim_accrued_pgas { .. }
+			)
+		) {
+			return Ok((ValidTransaction::default(), None, origin));
+		}
+		let Ok(alias) = T::AliasFeeOrigin::try_origin(origin.clone()) else {
+			return Ok((ValidTransaction::default(), None, origin));
+		};
+		let fee = T::AliasFee::fee(len as u32, info)?;
+		if fee.is_zero() {
+			return Ok((ValidTransaction::default(), None, origin));
+		}
+		ensure!(
+			Pallet::<T>::alias_pgas_balance(&alias) >= fee,
+			CustomValidity::InsufficientAliasPgas
+		);
+
+		Ok((ValidTransaction::default(), Some((alias, fee)), origin))
+	}
+
+	fn prepare(
+		self,
+		val: Self::Val,
+		_origin: &<T as frame_system::Config>::RuntimeOrigin,
+		_call: &<T as frame_system::Config>::RuntimeCall,
+		_info: &DispatchInfoOf<<T as frame_system::Config>::RuntimeCall>,
+		_len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		if let Some((alias, fee)) = &val {
+			Pallet::<T>::withdraw_alias_pgas(alias, *fee)?;
+		}
+		Ok(val)
+	}
+
+	fn post_dispatch_details(
+		pre: Self::Pre,
+		info: &DispatchInfoOf<<T as frame_system::Config>::RuntimeCall>,
+		post_info: &PostDispatchInfoOf<<T as frame_system::Config>::RuntimeCall>,
+		len: usize,
+		_result: &sp_runtime::DispatchResult,
+	) -> Result<Weight, TransactionValidityError> {
+		// Nothing was charged, so nothing was read or written.
+		let Some((alias, fee)) = pre else {
+			return Ok(<T as Config>::WeightInfo::charge_alias_pgas_tx_ext());
+		};
+
+		let actual_fee = T::AliasFee::actual_fee(len as u32, info, post_info)?.min(fee);
+		let refund = fee.saturating_sub(actual_fee);
+		// Also prunes the balance if the fee emptied it.
+		Pallet::<T>::refund_alias_pgas(&alias, refund);
+		Pallet::<T>::deposit_event(Event::AliasPgasFeePaid { alias, fee: actual_fee });
+
+		Ok(Weight::zero())
+	}
+}
@@ -36,8 +36,36 @@
 //!    [`Config::PgasClaimAmount`] into the `target` account.
 //!
 //! Per (day, alias) uniqueness is enforced authoritatively in dispatch and pre-checked in
-//! validate for transaction pool hygiene. Records for elapsed days are pruned by a permissionless
-//! authorized cleanup call submitted by an offchain worker.
+//! validate for transaction pool hygiene. Records for days no claim can cover anymore are pruned
+//! by a permissionless authorized cleanup call submitted by an offchain worker.
+//!
+//! ## Alias balances
+//!
+//! Instead of minting into an account, [`Call::claim_pgas_for_alias`] credits the claim to a PGAS
+//! balance attached to an alias (typically the alias of a `RingAlias`-like origin). The
+//! [`ChargeAliasPgas`] transaction extension then charges the fees of transactions dispatched with
+//! an origin accepted by [`Config::AliasFeeOrigin`] to that balance, so that no account holding
+//! funds is ever involved. The claims themselves are never charged, so claim slots never need a
+//! balance.
+//!
+//! Alias balances are not backed by asset accounts: they can only be spent on fees, which burns
+//! PGAS anyway, so they are accounted for in this pallet only. A balance expires
+//! [`Config::AliasPgasLifetime`] days after it was last credited, and spent or expired balances
+//! are pruned (the latter by the offchain worker, like claim records).
+//!
+//! ## Accrued claims
+//!
+//! A daily slot left unclaimed is not lost right away: [`Call::claim_accrued_pgas`] mints one
+//! [`Config::PgasClaimAmount`] for every day of the slot's accrual window that went unclaimed.
+//! The accrual window of a day spans the [`Config::MaxAccruedClaims`] days ending with it (see
+//! [`Pallet::accrual_window`]), so it rolls with each claim rather than resetting at fixed
+//! boundaries, and no more than that many days accrue.
+//!
+//! The claim is authorized by [`AsPgasInfo::ClaimAccrued`], a single proof over the regular day
+//! contexts of the slot for every day of the window. Each unclaimed day is recorded in
+//! [`ClaimedGasAliases`] exactly like a daily claim, so a (day, slot) pair is claimed at most
+//! once, whatever the claim flavour. To that end, claim records are kept until no accrual window
+//! covers their day anymore.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -47,6 +75,7 @@ extern crate alloc;
 pub mod benchmarking;
 pub mod extension;
 pub mod migration;
+pub mod runtime_api;
 pub mod weights;
 
 #[cfg(test)]
@@ -54,21 +83,27 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
-pub use extension::{AsPgas, AsPgasInfo, CustomValidity, PgasCollection};
+pub use extension::{AsPgas, AsPgasInfo, ChargeAliasPgas, CustomValidity, PgasCollection};
 pub use pallet::*;
 pub use weights::WeightInfo;
 
+use core::marker::PhantomData;
 use frame_support::{
 	dispatch::DispatchResultWithPostInfo,
-	traits::{fungibles, IsSubType, OriginTrait, UnixTime},
+	traits::{fungibles, EnsureOrigin, IsSubType, OriginTrait, UnixTime},
 };
 use indiv_support::{
-	traits::{Alias, Context, MembershipProver},
+	pgas::PgasFee,
+	traits::{Alias, Context, MembershipMultiProver, MembershipProver},
 	utils::BigEndianU32,
 };
-use sp_runtime::SaturatedConversion;
+use sp_runtime::{traits::Zero, SaturatedConversion, Saturating};
 use verifiable::GenerateVerifiable;
 
+/// Balance type of the PGAS asset.
+pub type PgasBalanceOf<T> =
+	<<T as Config>::Fungibles as fungibles::Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
 /// Ring-VRF proof type for [`Config::MembershipProver`].
 pub type ProofOf<T> =
 	<<<T as Config>::MembershipProver as MembershipProver>::Crypto as GenerateVerifiable>::Proof;
@@ -83,6 +118,9 @@ pub mod pallet {
 	/// Prefix for PGAS claim contexts.
 	pub const PGAS_CONTEXT_PREFIX: [u8; 8] = *b"pop:gas:";
 
+	/// Upper bound of [`Config::MaxAccruedClaims`].
+	pub const MAX_ACCRUAL_WINDOW: u32 = 31;
+
 	/// Day index type. Uses big-endian encoding so that `Identity`-hashed storage
 	/// iteration yields days in ascending chronological order.
 	pub type Day = BigEndianU32;
@@ -123,7 +161,7 @@ pub mod pallet {
 		///
 		/// On Asset Hub this is typically `pallet-members-subscriber`, which tracks the
 		/// people and lite-people ring roots received from People chain via XCM.
-		type MembershipProver: MembershipProver<
+		type MembershipProver: MembershipMultiProver<
 			Crypto: GenerateVerifiable<
 				Proof: Parameter + Send + Sync + DecodeWithMemTracking,
 				Signature: Parameter + Send + Sync + DecodeWithMemTracking,
@@ -163,6 +201,13 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxClaimsPerPeriodPerLitePerson: Get<u32>;
 
+		/// Length in days of the accrual window of a claim slot, and thus the maximum number of
+		/// days a single accrued claim collects. Zero disables accrued claims.
+		///
+		/// Claim records are kept for as many days, so must not exceed [`MAX_ACCRUAL_WINDOW`].
+		#[pallet::constant]
+		type MaxAccruedClaims: Get<u32>;
+
 		/// Maximum number of PGAS claim records that can be cleaned up in a single call.
 		#[pallet::constant]
 		type MaxPgasClaimRecordCleanupPerCall: Get<u32>;
@@ -178,6 +223,19 @@ pub mod pallet {
 			<<Self as Config>::Fungibles as fungibles::Inspect<Self::AccountId>>::Balance,
 		>;
 
+		/// Origins paying their transaction fees from the PGAS balance attached to their alias,
+		/// through the [`ChargeAliasPgas`] transaction extension.
+		///
+		/// [`EnsureClaimAlias`] accepts the origins produced by [`AsPgas`].
+		type AliasFeeOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Alias>;
+
+		/// Computes the PGAS fee of transactions paid from an alias balance.
+		type AliasFee: PgasFee<<Self as frame_system::Config>::RuntimeCall, PgasBalanceOf<Self>>;
+
+		/// Number of days an alias balance remains spendable after it was last credited.
+		#[pallet::constant]
+		type AliasPgasLifetime: Get<u32>;
+
 		/// Benchmark helper trait.
 		#[cfg(feature = "runtime-benchmarks")]
 		type BenchmarkHelper: benchmarking::BenchmarkHelper<Self>;
@@ -193,16 +251,44 @@ pub mod pallet {
 		/// A verified claim-slot alias. `day` is the day the proof's context was built for
 		/// (either the current day or the grace day).
 		ClaimAlias { alias: Alias, day: Day, collection: PgasCollection },
+		/// The verified aliases of a claim slot over the accrual window of `day`, oldest first,
+		/// so the last one is the slot's alias for `day`, as for [`Origin::ClaimAlias`].
+		AccrualAliases {
+			aliases: BoundedVec<Alias, ConstU32<MAX_ACCRUAL_WINDOW>>,
+			day: Day,
+			collection: PgasCollection,
+		},
 	}
 
 	/// Aliases that have been used to claim PGAS, keyed by (day, alias).
 	/// `Day` uses big-endian encoding with `Identity` hashing so iteration yields days
 	/// in ascending order, allowing the offchain worker to find the oldest stale day
-	/// without scanning.
+	/// without scanning. Records are kept while an accrual window covers their day.
 	#[pallet::storage]
 	pub type ClaimedGasAliases<T: Config> =
 		StorageDoubleMap<_, Identity, Day, Blake2_128Concat, Alias, (), OptionQuery>;
 
+	/// PGAS balance attached to an alias, see [`AliasPgasBalances`].
+	#[derive(Clone, PartialEq, Eq, Debug, Default, Encode, Decode, MaxEncodedLen, TypeInfo)]
+	pub struct AliasPgasBalance<Balance> {
+		/// The spendable amount.
+		pub balance: Balance,
+		/// The day from which the balance can no longer be spent.
+		pub expires_on: u32,
+	}
+
+	/// PGAS balances attached to aliases, spendable on transaction fees only.
+	#[pallet::storage]
+	pub type AliasPgasBalances<T: Config> =
+		StorageMap<_, Blake2_128Concat, Alias, AliasPgasBalance<PgasBalanceOf<T>>, OptionQuery>;
+
+	/// Aliases of [`AliasPgasBalances`] keyed by the day their balance expires on.
+	/// Uses the same big-endian day encoding as [`ClaimedGasAliases`] so the offchain worker finds
+	/// the oldest expiry day without scanning.
+	#[pallet::storage]
+	pub type AliasPgasExpiries<T: Config> =
+		StorageDoubleMap<_, Identity, Day, Blake2_128Concat, Alias, (), OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -218,6 +304,27 @@ pub mod pallet {
 		PgasAssetCreated,
 		/// Old PGAS claim records were cleaned up.
 		PgasClaimRecordsCleaned { day_index: u32, count: u32 },
+		/// PGAS was claimed by a person into the balance of `beneficiary`.
+		PgasClaimedForAlias {
+			alias: Alias,
+			beneficiary: Alias,
+			amount: PgasBalanceOf<T>,
+			collection: PgasCollection,
+			day: u32,
+		},
+		/// A transaction fee was paid from the PGAS balance of `alias`.
+		AliasPgasFeePaid { alias: Alias, fee: PgasBalanceOf<T> },
+		/// The PGAS accrued by a claim slot over `days` unclaimed days was claimed.
+		AccruedPgasClaimed {
+			alias: Alias,
+			target: T::AccountId,
+			amount: PgasBalanceOf<T>,
+			days: u32,
+			collection: PgasCollection,
+			day: u32,
+		},
+		/// Alias balances that expired on `day_index` were removed.
+		ExpiredAliasPgasCleaned { day_index: u32, count: u32 },
 	}
 
 	#[pallet::error]
@@ -226,11 +333,14 @@ pub mod pallet {
 		AlreadyClaimed,
 		/// The PGAS asset does not exist or minting failed.
 		PgasMintFailed,
-		/// `clean_pgas_claim_records` was called with a day that has no stored records.
+		/// `clean_pgas_claim_records` or `clean_expired_alias_pgas` was called with a day that has
+		/// no stored records.
 		NoRecordsForDay,
-		/// The `first_alias` passed to `clean_pgas_claim_records` does not match the first alias
+		/// The `first_alias` passed to one of the cleanup calls does not match the first alias
 		/// currently stored under the prefix.
 		FirstAliasMismatch,
+		/// The accrual window of the claim holds no unclaimed day.
+		NothingAccrued,
 	}
 
 	#[pallet::hooks]
@@ -238,29 +348,46 @@ pub mod pallet {
 		fn offchain_worker(_block_number: BlockNumberFor<T>) {
 			use frame_system::offchain::SubmitTransaction;
 
-			// Only clean days that are fully outside the grace window.
-			let grace_day = Self::grace_day();
+			// Only clean days that no accrual window of a claimable day covers.
+			let first_retained_day = Self::first_retained_day();
 
 			// Iterate days in ascending order. First key in the iterator should be a key that
 			// belongs to the oldest day.
-			let Some((day, first_alias)) = ClaimedGasAliases::<T>::iter_keys().next() else {
-				return;
-			};
-			let day_u32: u32 = day.into();
-			if day_u32 >= grace_day {
-				return;
+			if let Some((day, first_alias)) = ClaimedGasAliases::<T>::iter_keys().next() {
+				let day_u32: u32 = day.into();
+				if day_u32 < first_retained_day {
+					let call = Call::clean_pgas_claim_records { day_index: day_u32, first_alias };
+					let tx = T::create_authorized_transaction(call.into());
+					match SubmitTransaction::<T, _>::submit_transaction(tx) {
+						Ok(()) => log::debug!(
+							target: LOG_TARGET,
+							"pgas: submitted clean_pgas_claim_records for day {day_u32}"
+						),
+						Err(()) => log::warn!(
+							target: LOG_TARGET,
+							"pgas: failed to submit clean_pgas_claim_records for day {day_u32}"
+						),
+					}
+				}
 			}
-			let call = Call::clean_pgas_claim_records { day_index: day_u32, first_alias };
-			let tx = T::create_authorized_transaction(call.into());
-			match SubmitTransaction::<T, _>::submit_transaction(tx) {
-				Ok(()) => log::debug!(
-					target: LOG_TARGET,
-					"pgas: submitted clean_pgas_claim_records for day {day_u32}"
-				),
-				Err(()) => log::warn!(
-					target: LOG_TARGET,
-					"pgas: failed to submit clean_pgas_claim_records for day {day_u32}"
-				),
+
+			// Same for alias balances, which expire at the start of their expiry day.
+			if let Some((day, first_alias)) = AliasPgasExpiries::<T>::iter_keys().next() {
+				let day_u32: u32 = day.into();
+				if day_u32 <= Self::current_day() {
+					let call = Call::clean_expired_alias_pgas { day_index: day_u32, first_alias };
+					let tx = T::create_authorized_transaction(call.into());
+					match SubmitTransaction::<T, _>::submit_transaction(tx) {
+						Ok(()) => log::debug!(
+							target: LOG_TARGET,
+							"pgas: submitted clean_expired_alias_pgas for day {day_u32}"
+						),
+						Err(()) => log::warn!(
+							target: LOG_TARGET,
+							"pgas: failed to submit clean_expired_alias_pgas for day {day_u32}"
+						),
+					}
+				}
 			}
 		}
 
@@ -270,6 +397,10 @@ pub mod pallet {
 				"`PgasClaimAmount` must be >= `PgasMinBalance`, otherwise the first claim to a \
 				 fresh account would fail the asset's existential-deposit check",
 			);
+			assert!(
+				T::MaxAccruedClaims::get() <= MAX_ACCRUAL_WINDOW,
+				"`MaxAccruedClaims` must not exceed `MAX_ACCRUAL_WINDOW`",
+			);
 		}
 	}
 
@@ -327,9 +458,8 @@ pub mod pallet {
 		/// Remove old PGAS claim records for a specific `day_index`.
 		///
 		/// This is an authorized extrinsic submitted by the offchain worker.
-		/// Only records from days that have fully elapsed (outside the grace window) can
-		/// be cleaned. Up to [`Config::MaxPgasClaimRecordCleanupPerCall`] entries are
-		/// removed per call.
+		/// Only records from days before [`Pallet::first_retained_day`] can be cleaned. Up to
+		/// [`Config::MaxPgasClaimRecordCleanupPerCall`] entries are removed per call.
 		///
 		/// `first_alias` is the first alias currently stored for `day_index` and is included in the
 		/// tags for transaction uniqueness.
@@ -359,6 +489,115 @@ pub mod pallet {
 
 			Ok(Some(<T as Config>::WeightInfo::clean_pgas_claim_records(result.unique)).into())
 		}
+
+		/// Credit a verified claim slot to the PGAS balance attached to `beneficiary`.
+		///
+		/// Same as [`Call::claim_pgas`], except that nothing is minted: the claimed amount can
+		/// only be spent by `beneficiary` on transaction fees, through the [`ChargeAliasPgas`]
+		/// transaction extension.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::claim_pgas_for_alias())]
+		pub fn claim_pgas_for_alias(
+			origin: OriginFor<T>,
+			_slot_index: u32,
+			beneficiary: Alias,
+		) -> DispatchResultWithPostInfo {
+			let (alias, day, collection) = Self::ensure_claim_alias(origin)?;
+
+			ensure!(!ClaimedGasAliases::<T>::contains_key(day, alias), Error::<T>::AlreadyClaimed);
+
+			let amount = T::PgasClaimAmount::get();
+			Self::deposit_alias_pgas(&beneficiary, amount);
+			ClaimedGasAliases::<T>::insert(day, alias, ());
+
+			Self::deposit_event(Event::PgasClaimedForAlias {
+				alias,
+				beneficiary,
+				amount,
+				collection,
+				day: day.into(),
+			});
+
+			Ok(Pays::No.into())
+		}
+
+		/// Mint the PGAS accrued by a claim slot.
+		///
+		/// Must be submitted with the [`AsPgas`] transaction extension carrying
+		/// [`AsPgasInfo::ClaimAccrued`], which produces an [`Origin::AccrualAliases`]. Mints
+		/// [`Config::PgasClaimAmount`] for each day of the slot's accrual window that was not
+		/// claimed yet, and records these days as claimed.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::claim_accrued_pgas(T::MaxAccruedClaims::get()))]
+		pub fn claim_accrued_pgas(
+			origin: OriginFor<T>,
+			_slot_index: u32,
+			target: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let (aliases, day, collection) = Self::ensure_accrual_aliases(origin)?;
+			let alias = *aliases.last().ok_or(Error::<T>::NothingAccrued)?;
+			let day_index: u32 = day.into();
+
+			let unclaimed = Self::unclaimed_days(&aliases, day_index);
+			let days = unclaimed.len() as u32;
+			ensure!(days > 0, Error::<T>::NothingAccrued);
+
+			let amount = T::PgasClaimAmount::get().saturating_mul(days.into());
+			T::Fungibles::mint_into(T::PgasAssetId::get(), &target, amount)
+				.map_err(|_| Error::<T>::PgasMintFailed)?;
+			for (unclaimed_day, alias) in unclaimed {
+				ClaimedGasAliases::<T>::insert(Day::from(unclaimed_day), alias, ());
+			}
+
+			Self::deposit_event(Event::AccruedPgasClaimed {
+				alias,
+				target,
+				amount,
+				days,
+				collection,
+				day: day_index,
+			});
+
+			let weight = <T as Config>::WeightInfo::claim_accrued_pgas(aliases.len() as u32);
+			Ok((Some(weight), Pays::No).into())
+		}
+
+		/// Remove the alias balances that expired on `day_index`.
+		///
+		/// This is an authorized extrinsic submitted by the offchain worker, like
+		/// [`Call::clean_pgas_claim_records`]. Only balances expired by the current day can be
+		/// removed. Up to [`Config::MaxPgasClaimRecordCleanupPerCall`] balances are removed per
+		/// call.
+		#[pallet::call_index(6)]
+		#[pallet::authorize(|source, day_index, first_alias| {
+			Self::authorize_clean_expired_alias_pgas(source, *day_index, *first_alias)
+		})]
+		#[pallet::weight_of_authorize(<T as Config>::WeightInfo::authorize_clean_expired_alias_pgas())]
+		#[pallet::weight(<T as Config>::WeightInfo::clean_expired_alias_pgas(T::MaxPgasClaimRecordCleanupPerCall::get()))]
+		pub fn clean_expired_alias_pgas(
+			origin: OriginFor<T>,
+			day_index: u32,
+			first_alias: Alias,
+		) -> DispatchResultWithPostInfo {
+			ensure_authorized(origin)?;
+
+			let day = Day::from(day_index);
+			let actual_first = AliasPgasExpiries::<T>::iter_key_prefix(day)
+				.next()
+				.ok_or(Error::<T>::NoRecordsForDay)?;
+			ensure!(actual_first == first_alias, Error::<T>::FirstAliasMismatch);
+
+			let limit = T::MaxPgasClaimRecordCleanupPerCall::get();
+			let mut count = 0;
+			for (alias, ()) in AliasPgasExpiries::<T>::drain_prefix(day).take(limit as usize) {
+				AliasPgasBalances::<T>::remove(alias);
+				count += 1;
+			}
+
+			Self::deposit_event(Event::ExpiredAliasPgasCleaned { day_index, count });
+
+			Ok(Some(<T as Config>::WeightInfo::clean_expired_alias_pgas(count)).into())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -372,6 +611,90 @@ pub mod pallet {
 			}
 		}
 
+		/// Claim eligibility of `slot_index` in `collection` for the aliases of each claimable
+		/// day, as exposed by [`runtime_api::PgasApi::claim_eligibility`].
+		pub fn claim_eligibility(
+			collection: PgasCollection,
+			slot_index: u32,
+			today: runtime_api::ClaimAliases,
+			grace: Option<runtime_api::ClaimAliases>,
+		) -> runtime_api::ClaimEligibility<PgasBalanceOf<T>> {
+			let slot_claimable = slot_index < Self::max_claims_for(collection) &&
+				T::Fungibles::asset_exists(T::PgasAssetId::get());
+			let eligibility = |day: u32, aliases: Option<runtime_api::ClaimAliases>| {
+				let window = Self::accrual_window(day);
+				let unclaimed = aliases.as_ref().is_some_and(|aliases| {
+					!ClaimedGasAliases::<T>::contains_key(Day::from(day), aliases.alias)
+				});
+				let accrued_days = aliases
+					.and_then(|aliases| aliases.accrual_aliases)
+					.filter(|accrual_aliases| {
+						slot_claimable && accrual_aliases.len() == window.len()
+					})
+					.map(|accrual_aliases| Self::unclaimed_days(&accrual_aliases, day).len() as u32)
+					.filter(|days| *days > 0);
+				runtime_api::ClaimDayEligibility {
+					day,
+					context: Self::build_gas_context(day, slot_index),
+					accrual_contexts: window
+						.map(|window_day| Self::build_gas_context(window_day, slot_index))
+						.collect(),
+					claimable: slot_claimable && unclaimed,
+					accrued_days,
+				}
+			};
+
+			let current_day = Self::current_day();
+			let grace_day = Self::grace_day();
+			runtime_api::ClaimEligibility {
+				today: eligibility(current_day, Some(today)),
+				grace: (grace_day != current_day).then(|| eligibility(grace_day, grace)),
+				amount: T::PgasClaimAmount::get(),
+			}
+		}
+
+		/// Extract a verified [`Origin::AccrualAliases`] from a runtime origin.
+		pub fn ensure_accrual_aliases(
+			origin: OriginFor<T>,
+		) -> Result<
+			(BoundedVec<Alias, ConstU32<MAX_ACCRUAL_WINDOW>>, Day, PgasCollection),
+			sp_runtime::DispatchError,
+		> {
+			match origin.into_caller().try_into() {
+				Ok(Origin::AccrualAliases { aliases, day, collection }) =>
+					Ok((aliases, day, collection)),
+				_ => Err(sp_runtime::DispatchError::BadOrigin),
+			}
+		}
+
+		/// The days of the accrual window of `day`: the [`Config::MaxAccruedClaims`] days ending
+		/// with `day`, or fewer around the epoch.
+		pub fn accrual_window(day: u32) -> core::ops::Range<u32> {
+			let end = day.saturating_add(1);
+			end.saturating_sub(T::MaxAccruedClaims::get())..end
+		}
+
+		/// The unclaimed days of the accrual window of `day`, along with the slot's alias for
+		/// each of them.
+		///
+		/// `aliases` are the slot's aliases for the days of the window, oldest first. Any extra
+		/// alias is ignored.
+		pub fn unclaimed_days(aliases: &[Alias], day: u32) -> alloc::vec::Vec<(u32, Alias)> {
+			Self::accrual_window(day)
+				.zip(aliases.iter().copied())
+				.filter(|(day, alias)| {
+					!ClaimedGasAliases::<T>::contains_key(Day::from(*day), alias)
+				})
+				.collect()
+		}
+
+		/// The oldest day whose claim records are kept: the first day of the accrual window of
+		/// the oldest claimable day.
+		pub fn first_retained_day() -> u32 {
+			let grace_day = Self::grace_day();
+			Self::accrual_window(grace_day).start.min(grace_day)
+		}
+
 		/// Create the PGAS asset and emit [`Event::PgasAssetCreated`].
 		///
 		/// Shared between the [`Call::create_pgas_asset`] extrinsic and the
@@ -405,9 +728,9 @@ pub mod pallet {
 
 		/// Authorize the cleanup of PGAS claim records for a given day.
 		///
-		/// Only local/in-block sources are accepted. Rejects if the day is still within
-		/// the grace window, if there are no records to clean, or if `first_alias` does not
-		/// match the first alias currently stored under the day's prefix.
+		/// Only local/in-block sources are accepted. Rejects if the day is still retained (see
+		/// [`Self::first_retained_day`]), if there are no records to clean, or if `first_alias`
+		/// does not match the first alias currently stored under the day's prefix.
 		pub(crate) fn authorize_clean_pgas_claim_records(
 			source: TransactionSource,
 			day_index: u32,
@@ -416,7 +739,7 @@ pub mod pallet {
 			if !matches!(source, TransactionSource::InBlock | TransactionSource::Local) {
 				return Err(InvalidTransaction::Call.into());
 			}
-			if day_index >= Self::grace_day() {
+			if day_index >= Self::first_retained_day() {
 				return Err(InvalidTransaction::Future.into());
 			}
 			let day = Day::from(day_index);
@@ -433,6 +756,34 @@ pub mod pallet {
 				.map(|v| (v, <T as Config>::WeightInfo::authorize_clean_pgas_claim_records()))
 		}
 
+		/// Authorize the removal of the alias balances expiring on a given day.
+		///
+		/// Same rules as [`Self::authorize_clean_pgas_claim_records`], except that the balances
+		/// must have expired by the current day.
+		pub(crate) fn authorize_clean_expired_alias_pgas(
+			source: TransactionSource,
+			day_index: u32,
+			first_alias: Alias,
+		) -> Result<(ValidTransaction, Weight), TransactionValidityError> {
+			if !matches!(source, TransactionSource::InBlock | TransactionSource::Local) {
+				return Err(InvalidTransaction::Call.into());
+			}
+			if day_index > Self::current_day() {
+				return Err(InvalidTransaction::Future.into());
+			}
+			let actual_first = AliasPgasExpiries::<T>::iter_key_prefix(Day::from(day_index))
+				.next()
+				.ok_or(InvalidTransaction::Stale)?;
+			if actual_first != first_alias {
+				return Err(CustomValidity::FirstAliasMismatch.into());
+			}
+			ValidTransaction::with_tag_prefix("pgas:clean-expired-alias-pgas")
+				.and_provides((day_index, first_alias))
+				.propagate(false)
+				.build()
+				.map(|v| (v, <T as Config>::WeightInfo::authorize_clean_expired_alias_pgas()))
+		}
+
 		/// Build the context bytes for a PGAS claim.
 		///
 		/// Format: [`PGAS_CONTEXT_PREFIX`] (8 bytes) + day (u32 LE, 4 bytes) + slot_index (u32
@@ -457,7 +808,65 @@ pub mod pallet {
 				.saturated_into()
 		}
 
-		/// Per-collection maximum slot index.
+		/// The spendable PGAS balance of `alias`, zero once expired.
+		pub fn alias_pgas_balance(alias: &Alias) -> PgasBalanceOf<T> {
+			AliasPgasBalances::<T>::get(alias)
+				.filter(|entry| entry.expires_on > Self::current_day())
+				.map_or_else(Zero::zero, |entry| entry.balance)
+		}
+
+		/// Credit `amount` to the PGAS balance of `alias`, which then expires
+		/// [`Config::AliasPgasLifetime`] days from today.
+		pub(crate) fn deposit_alias_pgas(alias: &Alias, amount: PgasBalanceOf<T>) {
+			let expires_on = Self::current_day().saturating_add(T::AliasPgasLifetime::get());
+			let balance = match AliasPgasBalances::<T>::get(alias) {
+				Some(entry) => {
+					AliasPgasExpiries::<T>::remove(Day::from(entry.expires_on), alias);
+					// An expired balance is gone, even if not pruned yet.
+					if entry.expires_on > Self::current_day() {
+						entry.balance.saturating_add(amount)
+					} else {
+						amount
+					}
+				},
+				None => amount,
+			};
+			AliasPgasBalances::<T>::insert(alias, AliasPgasBalance { balance, expires_on });
+			AliasPgasExpiries::<T>::insert(Day::from(expires_on), alias, ());
+		}
+
+		/// Debit `amount` from the PGAS balance of `alias`, failing if the balance is too low or
+		/// expired.
+		///
+		/// An emptied balance is kept until [`Self::refund_alias_pgas`] is called.
+		pub(crate) fn withdraw_alias_pgas(
+			alias: &Alias,
+			amount: PgasBalanceOf<T>,
+		) -> Result<(), TransactionValidityError> {
+			ensure!(
+				Self::alias_pgas_balance(alias) >= amount,
+				CustomValidity::InsufficientAliasPgas
+			);
+			AliasPgasBalances::<T>::mutate_extant(alias, |entry| {
+				entry.balance.saturating_reduce(amount)
+			});
+			Ok(())
+		}
+
+		/// Give back `amount` previously withdrawn from the balance of `alias`, without extending
+		/// its lifetime, and prune the balance if it is empty.
+		pub(crate) fn refund_alias_pgas(alias: &Alias, amount: PgasBalanceOf<T>) {
+			AliasPgasBalances::<T>::mutate_exists(alias, |maybe_entry| {
+				let Some(entry) = maybe_entry else { return };
+				entry.balance.saturating_accrue(amount);
+				if entry.balance.is_zero() {
+					AliasPgasExpiries::<T>::remove(Day::from(entry.expires_on), alias);
+					*maybe_entry = None;
+				}
+			});
+		}
+
+		/// Per-collection number of daily claim slots.
 		pub fn max_claims_for(collection: PgasCollection) -> u32 {
 			match collection {
 				PgasCollection::People => T::MaxClaimsPerPeriodPerPerson::get(),
@@ -466,3 +875,29 @@ pub mod pallet {
 		}
 	}
 }
+
+/// Ensures the origin is an [`Origin::ClaimAlias`] or an [`Origin::AccrualAliases`], returning the
+/// alias of the claim slot for its day.
+pub struct EnsureClaimAlias<T>(PhantomData<T>);
+
+impl<T: Config> EnsureOrigin<T::RuntimeOrigin> for EnsureClaimAlias<T> {
+	type Success = Alias;
+
+	fn try_origin(o: T::RuntimeOrigin) -> Result<Self::Success, T::RuntimeOrigin> {
+		match o.clone().into_caller().try_into() {
+			Ok(Origin::ClaimAlias { alias, .. }) => Ok(alias),
+			Ok(Origin::AccrualAliases { aliases, .. }) => aliases.last().copied().ok_or(o),
+			_ => Err(o),
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<T::RuntimeOrigin, ()> {
+		Ok(Origin::ClaimAlias {
+			alias: [0u8; 32],
+			day: Day::from(0),
+			collection: PgasCollection::People,
+		}
+		.into())
+	}
+}
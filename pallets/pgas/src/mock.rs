@@ -23,16 +23,21 @@ use alloc::{collections::BTreeMap, vec::Vec};
 use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use core::{cell::RefCell, ops::Range, time::Duration};
 use frame_support::{
-	derive_impl, parameter_types,
-	traits::{AsEnsureOriginWithArg, UnixTime},
+	derive_impl,
+	dispatch::{DispatchInfo, Pays, PostDispatchInfo},
+	parameter_types,
+	traits::{AsEnsureOriginWithArg, EitherOf, EnsureOrigin, UnixTime},
 };
 use frame_system::{
 	offchain::{CreateAuthorizedTransaction, CreateBare, CreateTransaction, CreateTransactionBase},
 	AuthorizeCall,
 };
-use indiv_support::traits::{
-	Alias, BatchProofItem, Context, ContextualAlias, Identifier, MembershipProver,
-	RevisedContextualAlias, RevisionIndex, RingIndex,
+use indiv_support::{
+	pgas::PgasFee,
+	traits::{
+		Alias, BatchProofItem, Context, ContextualAlias, Identifier, MembershipMultiProver,
+		MembershipProver, RevisedContextualAlias, RevisionIndex, RingIndex,
+	},
 };
 use scale_info::TypeInfo;
 use sp_core::ConstU32;
@@ -42,6 +47,7 @@ use sp_runtime::{
 		OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
 	},
 	traits::{IdentifyAccount, Verify},
+	transaction_validity::TransactionValidityError,
 	AccountId32, BoundedVec, BuildStorage, DispatchError,
 };
 use std::sync::Arc;
@@ -185,19 +191,24 @@ pub struct TestMemberKey(pub u64);
 
 #[derive(Clone, PartialEq, Eq, Debug, Encode, Decode, TypeInfo, DecodeWithMemTracking)]
 pub struct TestProof {
-	pub context: Vec<u8>,
+	pub contexts: Vec<Vec<u8>>,
 	pub member: TestMemberKey,
 	pub members: Vec<u64>,
 	pub message: Vec<u8>,
 }
 
 impl TestProof {
-	pub fn alias(&self) -> Alias {
+	/// The aliases of the proof, one per context.
+	pub fn aliases(&self) -> AliasVec {
+		self.contexts.iter().map(|context| self.alias_in(context)).collect()
+	}
+
+	fn alias_in(&self, context: &[u8]) -> Alias {
 		// Seed alias with the context so different contexts yield different aliases;
 		// XOR the member id into the first byte so different members get distinct aliases.
 		let mut r = [0u8; 32];
-		let ctx_len = self.context.len().min(32);
-		r[..ctx_len].copy_from_slice(&self.context[..ctx_len]);
+		let ctx_len = context.len().min(32);
+		r[..ctx_len].copy_from_slice(&context[..ctx_len]);
 		r[0] ^= self.member.0 as u8;
 		r
 	}
@@ -270,13 +281,17 @@ impl GenerateVerifiable for TestVerifiable {
 		contexts: &[&[u8]],
 		message: &[u8],
 	) -> Result<(Self::Proof, AliasVec), VerifiableError> {
-		if contexts.len() != 1 || &member != secret {
+		if contexts.is_empty() || &member != secret {
 			return Err(VerifiableError::NotInRing);
 		}
-		let proof =
-			TestProof { context: contexts[0].to_vec(), member, members, message: message.to_vec() };
-		let alias = proof.alias();
-		Ok((proof, core::iter::once(alias).collect()))
+		let proof = TestProof {
+			contexts: contexts.iter().map(|c| c.to_vec()).collect(),
+			member,
+			members,
+			message: message.to_vec(),
+		};
+		let aliases = proof.aliases();
+		Ok((proof, aliases))
 	}
 
 	fn validate_multi_context(
@@ -286,12 +301,12 @@ impl GenerateVerifiable for TestVerifiable {
 		contexts: &[&[u8]],
 		message: &[u8],
 	) -> Result<AliasVec, VerifiableError> {
-		if contexts.len() == 1 &&
-			proof.context == contexts[0] &&
+		if proof.contexts.len() == contexts.len() &&
+			proof.contexts.iter().zip(contexts).all(|(a, b)| a[..] == b[..]) &&
 			proof.members[..] == members[..] &&
 			proof.message == message
 		{
-			Ok(core::iter::once(proof.alias()).collect())
+			Ok(proof.aliases())
 		} else {
 			Err(VerifiableError::VerificationFailed)
 		}
@@ -469,6 +484,44 @@ impl MembershipProver for MockProver {
 	}
 }
 
+impl MembershipMultiProver for MockProver {
+	fn verify_membership_multi_context(
+		identifier: &Identifier,
+		proof: &<Self::Crypto as GenerateVerifiable>::Proof,
+		ring_index: RingIndex,
+		contexts: &[Context],
+		msg: &[u8],
+	) -> Result<Vec<RevisedContextualAlias>, DispatchError> {
+		let members = Self::members_for(identifier, ring_index)
+			.ok_or(DispatchError::Other("ring not registered"))?;
+		let context_slices: Vec<&[u8]> = contexts.iter().map(|c| &c[..]).collect();
+		let aliases =
+			TestVerifiable::validate_multi_context((), proof, &members, &context_slices, msg)
+				.map_err(|_| DispatchError::Other("invalid proof"))?;
+		Ok(aliases
+			.into_iter()
+			.zip(contexts.iter().copied())
+			.map(|(alias, context)| RevisedContextualAlias {
+				revision: 0,
+				ring: ring_index,
+				ca: ContextualAlias { alias, context },
+			})
+			.collect())
+	}
+
+	fn verify_membership_multi_context_at_rev(
+		identifier: &Identifier,
+		proof: &<Self::Crypto as GenerateVerifiable>::Proof,
+		ring_index: RingIndex,
+		_revision: RevisionIndex,
+		contexts: &[Context],
+		msg: &[u8],
+	) -> Result<Vec<ContextualAlias>, DispatchError> {
+		Self::verify_membership_multi_context(identifier, proof, ring_index, contexts, msg)
+			.map(|aliases| aliases.into_iter().map(|r| r.ca).collect())
+	}
+}
+
 // ---- Config + helpers ----------------------------------------------------------------------
 
 parameter_types! {
@@ -476,9 +529,11 @@ parameter_types! {
 	pub PgasClaimAmount: u64 = 1000;
 	pub const MaxClaimsPerPeriodPerPerson: u32 = 4;
 	pub const MaxClaimsPerPeriodPerLitePerson: u32 = 2;
+	pub static MaxAccruedClaims: u32 = 1;
 	pub const MaxPgasClaimRecordCleanupPerCall: u32 = 3;
 	pub PgasAdmin: AccountId32 = AccountId32::new([0xaa; 32]);
 	pub PgasMinBalance: u64 = 1;
+	pub const AliasPgasLifetime: u32 = 5;
 }
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -494,6 +549,20 @@ impl pallet_pgas::benchmarking::BenchmarkHelper<Test> for BenchmarkHelper {
 		ring_index: RingIndex,
 		context: &Context,
 		message: &[u8],
+	) -> <TestVerifiable as GenerateVerifiable>::Proof {
+		Self::seed_and_create_multi_context_proof(
+			identifier,
+			ring_index,
+			core::slice::from_ref(context),
+			message,
+		)
+	}
+
+	fn seed_and_create_multi_context_proof(
+		identifier: &Identifier,
+		ring_index: RingIndex,
+		contexts: &[Context],
+		message: &[u8],
 	) -> <TestVerifiable as GenerateVerifiable>::Proof {
 		// Deterministic test member — benchmarks want a fresh, reproducible setup.
 		let secret = TestVerifiable::new_secret([0u8; 32]);
@@ -502,12 +571,51 @@ impl pallet_pgas::benchmarking::BenchmarkHelper<Test> for BenchmarkHelper {
 		let members = MockProver::ring_members(identifier, ring_index);
 		let commitment = TestVerifiable::open((), &member, members.into_iter().map(TestMemberKey))
 			.expect("commitment opens on test crypto");
-		let (proof, _) = TestVerifiable::create(commitment, &secret, context, message)
-			.expect("proof creation on test crypto is infallible");
+		let context_slices: Vec<&[u8]> = contexts.iter().map(|c| &c[..]).collect();
+		let (proof, _) =
+			TestVerifiable::create_multi_context(commitment, &secret, &context_slices, message)
+				.expect("proof creation on test crypto is infallible");
 		proof
 	}
 }
 
+/// Lets signed accounts pay fees from the alias balance keyed by their account bytes.
+pub struct SignedAsAlias;
+impl EnsureOrigin<RuntimeOrigin> for SignedAsAlias {
+	type Success = Alias;
+
+	fn try_origin(o: RuntimeOrigin) -> Result<Alias, RuntimeOrigin> {
+		frame_system::EnsureSigned::<AccountId32>::try_origin(o).map(Into::into)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+		Ok(RuntimeOrigin::signed(id_to_account(0)))
+	}
+}
+
+/// Fee of one PGAS unit per byte and per unit of `ref_time`, for transactions that pay fees.
+pub struct TestAliasFee;
+impl PgasFee<RuntimeCall, u64> for TestAliasFee {
+	fn fee(len: u32, info: &DispatchInfo) -> Result<u64, TransactionValidityError> {
+		if info.pays_fee == Pays::No {
+			return Ok(0);
+		}
+		Ok(len as u64 + info.total_weight().ref_time())
+	}
+
+	fn actual_fee(
+		len: u32,
+		info: &DispatchInfo,
+		post_info: &PostDispatchInfo,
+	) -> Result<u64, TransactionValidityError> {
+		if post_info.pays_fee(info) == Pays::No {
+			return Ok(0);
+		}
+		Ok(len as u64 + post_info.calc_actual_weight(info).ref_time())
+	}
+}
+
 impl pallet_pgas::Config for Test {
 	type WeightInfo = ();
 	type MembershipProver = MockProver;
@@ -517,9 +625,13 @@ impl pallet_pgas::Config for Test {
 	type PgasClaimAmount = PgasClaimAmount;
 	type MaxClaimsPerPeriodPerPerson = MaxClaimsPerPeriodPerPerson;
 	type MaxClaimsPerPeriodPerLitePerson = MaxClaimsPerPeriodPerLitePerson;
+	type MaxAccruedClaims = MaxAccruedClaims;
 	type MaxPgasClaimRecordCleanupPerCall = MaxPgasClaimRecordCleanupPerCall;
 	type PgasAdmin = PgasAdmin;
 	type PgasMinBalance = PgasMinBalance;
+	type AliasFeeOrigin = EitherOf<SignedAsAlias, pallet_pgas::EnsureClaimAlias<Test>>;
+	type AliasFee = TestAliasFee;
+	type AliasPgasLifetime = AliasPgasLifetime;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = BenchmarkHelper;
 }
@@ -546,6 +658,17 @@ pub fn register_member(identifier: &Identifier, ring_index: RingIndex, member_id
 	});
 }
 
+/// The alias the mock crypto derives for `member_id` in `context`.
+pub fn alias_for(member_id: u64, context: &Context) -> Alias {
+	let proof = TestProof {
+		contexts: alloc::vec![context.to_vec()],
+		member: TestMemberKey(member_id),
+		members: Vec::new(),
+		message: Vec::new(),
+	};
+	proof.aliases()[0]
+}
+
 /// Compute the inherited-implication message that `AsPgas` validates the proof against.
 pub fn proof_message_for(call: &RuntimeCall, extension_version: u8) -> [u8; 32] {
 	sp_runtime::traits::TxBaseImplication((extension_version, call))
@@ -560,6 +683,32 @@ pub fn build_claim_tx(
 	slot_index: u32,
 	target: AccountId32,
 	day: u32,
+) -> (RuntimeCall, pallet_pgas::AsPgas<Test>) {
+	let call = RuntimeCall::Pgas(pallet_pgas::Call::claim_pgas { slot_index, target });
+	build_claim_tx_for_call(member_id, ring_index, collection, slot_index, day, call)
+}
+
+/// Build a proof and `AsPgas` extension for a claim credited to the balance of `beneficiary`.
+pub fn build_claim_for_alias_tx(
+	member_id: u64,
+	ring_index: RingIndex,
+	collection: pallet_pgas::PgasCollection,
+	slot_index: u32,
+	beneficiary: Alias,
+	day: u32,
+) -> (RuntimeCall, pallet_pgas::AsPgas<Test>) {
+	let call =
+		RuntimeCall::Pgas(pallet_pgas::Call::claim_pgas_for_alias { slot_index, beneficiary });
+	build_claim_tx_for_call(member_id, ring_index, collection, slot_index, day, call)
+}
+
+fn build_claim_tx_for_call(
+	member_id: u64,
+	ring_index: RingIndex,
+	collection: pallet_pgas::PgasCollection,
+	slot_index: u32,
+	day: u32,
+	call: RuntimeCall,
 ) -> (RuntimeCall, pallet_pgas::AsPgas<Test>) {
 	register_member(&collection.identifier(), ring_index, member_id);
 	let secret = TestVerifiable::new_secret({
@@ -572,7 +721,6 @@ pub fn build_claim_tx(
 	let commitment = TestVerifiable::open((), &member, members.into_iter().map(TestMemberKey))
 		.expect("commitment should open");
 
-	let call = RuntimeCall::Pgas(pallet_pgas::Call::claim_pgas { slot_index, target });
 	let msg = proof_message_for(&call, 0);
 	let context = pallet_pgas::Pallet::<Test>::build_gas_context(day, slot_index);
 	let (proof, _) =
@@ -590,6 +738,49 @@ pub fn build_claim_tx(
 	(call, tx_ext)
 }
 
+/// Build a multi-context proof over the accrual window of `day` and `AsPgas` extension for an
+/// accrued claim.
+pub fn build_accrued_claim_tx(
+	member_id: u64,
+	ring_index: RingIndex,
+	collection: pallet_pgas::PgasCollection,
+	slot_index: u32,
+	target: AccountId32,
+	day: u32,
+) -> (RuntimeCall, pallet_pgas::AsPgas<Test>) {
+	register_member(&collection.identifier(), ring_index, member_id);
+	let secret = TestVerifiable::new_secret({
+		let mut e = [0u8; 32];
+		e[..8].copy_from_slice(&member_id.to_le_bytes());
+		e
+	});
+	let member = TestVerifiable::member_from_secret(&secret);
+	let members = MockProver::ring_members(&collection.identifier(), ring_index);
+	let commitment = TestVerifiable::open((), &member, members.into_iter().map(TestMemberKey))
+		.expect("commitment should open");
+
+	let call = RuntimeCall::Pgas(pallet_pgas::Call::claim_accrued_pgas { slot_index, target });
+	let msg = proof_message_for(&call, 0);
+	let contexts: Vec<_> = pallet_pgas::Pallet::<Test>::accrual_window(day)
+		.map(|window_day| pallet_pgas::Pallet::<Test>::build_gas_context(window_day, slot_index))
+		.collect();
+	let context_slices: Vec<&[u8]> = contexts.iter().map(|c| &c[..]).collect();
+	let (proof, _) =
+		TestVerifiable::create_multi_context(commitment, &secret, &context_slices, &msg)
+			.expect("proof should build");
+
+	let revision = MockProver::ring_revision(&collection.identifier(), ring_index).unwrap_or(0);
+
+	let tx_ext = pallet_pgas::AsPgas::<Test>::new(Some(pallet_pgas::AsPgasInfo::ClaimAccrued {
+		proof,
+		ring_index,
+		revision,
+		collection,
+		day,
+	}));
+	(call, tx_ext)
+}
+
 pub fn new_test_ext() -> sp_io::TestExternalities {
 	let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
 	let mut ext = sp_io::TestExternalities::new(t);
@@ -602,6 +793,7 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 	ext.execute_with(|| {
 		System::set_block_number(1);
 		MockUnixTime::set(Duration::ZERO);
+		MaxAccruedClaims::set(1);
 		RING_REGISTRY.with(|r| r.borrow_mut().clear());
 	});
 	ext
@@ -0,0 +1,89 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Individuality.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the PGAS pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use crate::PgasCollection;
+use alloc::vec::Vec;
+use codec::{Codec, Decode, Encode};
+use indiv_support::traits::{Alias, Context};
+use scale_info::TypeInfo;
+
+/// The aliases of a slot for one of the days accepted by the [`AsPgas`](crate::AsPgas) extension.
+///
+/// Aliases are contextual, each is derived for a context of the day (which does not require
+/// building a proof).
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct ClaimAliases {
+	/// The alias for [`ClaimDayEligibility::context`].
+	pub alias: Alias,
+	/// The aliases for [`ClaimDayEligibility::accrual_contexts`], to query an accrued claim.
+	pub accrual_aliases: Option<Vec<Alias>>,
+}
+
+/// Claim eligibility of a slot for one of the days accepted by the [`AsPgas`](crate::AsPgas)
+/// extension.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct ClaimDayEligibility {
+	/// The day index to put in [`AsPgasInfo::Claim`](crate::AsPgasInfo::Claim) or
+	/// [`AsPgasInfo::ClaimAccrued`](crate::AsPgasInfo::ClaimAccrued).
+	pub day: u32,
+	/// The context the proof must be built against for this day.
+	pub context: Context,
+	/// The contexts the proof of an accrued claim must be built against for this day, i.e. the
+	/// contexts of the days of its accrual window, oldest first. Empty if accrual is disabled.
+	pub accrual_contexts: Vec<Context>,
+	/// Whether a daily claim with the queried aliases would currently be accepted for this day.
+	pub claimable: bool,
+	/// The number of unclaimed days an accrued claim would currently mint
+	/// [`ClaimEligibility::amount`] for, `None` if the accrual aliases weren't queried or nothing
+	/// accrued.
+	pub accrued_days: Option<u32>,
+}
+
+/// Claim eligibility of a slot, as reported by [`PgasApi::claim_eligibility`].
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct ClaimEligibility<Balance> {
+	/// Eligibility for the current day.
+	pub today: ClaimDayEligibility,
+	/// Eligibility for the previous day, while the grace window around the day rollover is
+	/// open.
+	pub grace: Option<ClaimDayEligibility>,
+	/// The amount minted by a successful daily claim, or for each day of an accrued claim.
+	pub amount: Balance,
+}
+
+sp_api::decl_runtime_apis! {
+	/// The API to check PGAS claim eligibility before building a proof.
+	pub trait PgasApi<Balance>
+	where
+		Balance: Codec,
+	{
+		/// Returns the claim eligibility of `slot_index` in `collection`, daily or accrued.
+		///
+		/// `today` are the aliases of the slot for the contexts of the current day, `grace` for
+		/// the contexts of the previous day. A day is reported as not claimable when its aliases
+		/// are missing.
+		fn claim_eligibility(
+			collection: PgasCollection,
+			slot_index: u32,
+			today: ClaimAliases,
+			grace: Option<ClaimAliases>,
+		) -> ClaimEligibility<Balance>;
+	}
+}
@@ -15,17 +15,20 @@
 // limitations under the License.
 
 use crate::{
-	extension::{CustomValidity, PgasCollection},
+	extension::{ChargeAliasPgas, CustomValidity, PgasCollection},
 	mock::*,
 	pallet::{Day, PGAS_DAY_GRACE_WINDOW},
-	ClaimedGasAliases, Event, Pallet,
+	runtime_api::ClaimAliases,
+	AliasPgasBalances, AliasPgasExpiries, ClaimedGasAliases, Event, Pallet,
 };
 use frame_support::{
 	assert_noop, assert_ok,
-	dispatch::GetDispatchInfo,
+	dispatch::{DispatchInfo, GetDispatchInfo, PostDispatchInfo},
 	traits::{fungibles::Inspect, OffchainWorker},
+	weights::Weight,
 };
 use frame_system::RawOrigin as SystemOrigin;
+use indiv_support::traits::Alias;
 use sp_runtime::{
 	traits::{DispatchTransaction, Dispatchable},
 	transaction_validity::{InvalidTransaction, TransactionSource, TransactionValidityError},
@@ -758,3 +761,546 @@ fn create_pgas_asset_authorize_rejects_when_asset_exists() {
 		));
 	});
 }
+
+// ==================== Alias balances ====================
+
+/// Run an alias claim through the full `AsPgas` validate → dispatch pipeline.
+fn submit_claim_for_alias(
+	member_id: u64,
+	slot_index: u32,
+	beneficiary: Alias,
+	day: u32,
+) -> sp_runtime::DispatchResult {
+	let (call, tx_ext) =
+		build_claim_for_alias_tx(member_id, 0, PgasCollection::People, slot_index, beneficiary, day);
+	let info = call.get_dispatch_info();
+	let (_, _val, origin) = tx_ext
+		.validate_only(SystemOrigin::None.into(), &call, &info, 0, TransactionSource::External, 0)
+		.map_err(|_| sp_runtime::DispatchError::Other("validate_only failed"))?;
+	call.dispatch(origin).map(|_| ()).map_err(|e| e.error)
+}
+
+/// Dispatch info of a call whose declared weight is `ref_time` units.
+fn info_with_weight(ref_time: u64) -> DispatchInfo {
+	DispatchInfo { call_weight: Weight::from_parts(ref_time, 0), ..Default::default() }
+}
+
+fn remark_call() -> RuntimeCall {
+	RuntimeCall::System(frame_system::Call::remark { remark: vec![] })
+}
+
+#[test]
+fn claim_pgas_for_alias_credits_alias_balance() {
+	new_test_ext().execute_with(|| {
+		set_time_sec(DAY);
+
+		// Nothing is minted, so the asset does not need to exist.
+		let beneficiary = [7u8; 32];
+		assert_ok!(submit_claim_for_alias(100, 0, beneficiary, 1));
+		assert_ok!(submit_claim_for_alias(100, 1, beneficiary, 1));
+
+		assert_eq!(Pallet::<Test>::alias_pgas_balance(&beneficiary), PgasClaimAmount::get() * 2);
+		assert!(System::events().iter().any(|r| matches!(
+			&r.event,
+			RuntimeEvent::Pgas(Event::PgasClaimedForAlias { beneficiary: b, .. }) if *b == beneficiary,
+		)));
+
+		// The slot is spent for the day, whatever the claim flavour.
+		setup_pgas_asset();
+		assert!(submit_claim_for_alias(100, 0, beneficiary, 1).is_err());
+		assert!(submit_claim(100, 0, PgasCollection::People, 1, id_to_account(1), 1).is_err());
+	});
+}
+
+#[test]
+fn charge_alias_pgas_withdraws_fee_and_refunds_unused_weight() {
+	new_test_ext().execute_with(|| {
+		let who = id_to_account(1);
+		let alias: Alias = who.clone().into();
+		Pallet::<Test>::deposit_alias_pgas(&alias, 10_000);
+
+		let call = remark_call();
+		let info = info_with_weight(500);
+		let post_info = PostDispatchInfo {
+			actual_weight: Some(Weight::from_parts(200, 0)),
+			..Default::default()
+		};
+
+		assert_ok!(ChargeAliasPgas::<Test>::new()
+			.test_run(RuntimeOrigin::signed(who), &call, &info, 10, 0, |_| {
+				// The estimated fee is withdrawn before dispatch.
+				assert_eq!(Pallet::<Test>::alias_pgas_balance(&alias), 10_000 - 510);
+				Ok(post_info)
+			})
+			.expect("extension must accept the transaction"));
+
+		// Only the actual fee is kept.
+		assert_eq!(Pallet::<Test>::alias_pgas_balance(&alias), 10_000 - 210);
+		System::assert_has_event(Event::<Test>::AliasPgasFeePaid { alias, fee: 210 }.into());
+	});
+}
+
+#[test]
+fn charge_alias_pgas_rejects_insufficient_balance() {
+	new_test_ext().execute_with(|| {
+		let who = id_to_account(1);
+		let alias: Alias = who.clone().into();
+		Pallet::<Test>::deposit_alias_pgas(&alias, 100);
+
+		let call = remark_call();
+		let result = ChargeAliasPgas::<Test>::new().validate_only(
+			RuntimeOrigin::signed(who),
+			&call,
+			&info_with_weight(500),
+			10,
+			TransactionSource::External,
+			0,
+		);
+		assert!(matches!(
+			result,
+			Err(TransactionValidityError::Invalid(InvalidTransaction::Custom(code)))
+				if code == CustomValidity::InsufficientAliasPgas as u8
+		));
+		assert_eq!(Pallet::<Test>::alias_pgas_balance(&alias), 100);
+	});
+}
+
+#[test]
+fn charge_alias_pgas_ignores_other_origins() {
+	new_test_ext().execute_with(|| {
+		let call = remark_call();
+		let info = info_with_weight(500);
+
+		let (_, val, _) = ChargeAliasPgas::<Test>::new()
+			.validate_only(
+				SystemOrigin::None.into(),
+				&call,
+				&info,
+				10,
+				TransactionSource::External,
+				0,
+			)
+			.expect("other origins are not charged");
+		assert_eq!(val, None);
+	});
+}
+
+#[test]
+fn charge_alias_pgas_charges_claim_aliases_except_for_free_claims() {
+	new_test_ext().execute_with(|| {
+		set_time_sec(DAY);
+		setup_pgas_asset();
+
+		let (call, tx_ext) = build_claim_tx(100, 0, PgasCollection::People, 0, id_to_account(1), 1);
+		let info = call.get_dispatch_info();
+		let (_, _, origin) = tx_ext
+			.validate_only(
+				SystemOrigin::None.into(),
+				&call,
+				&info,
+				0,
+				TransactionSource::External,
+				0,
+			)
+			.expect("claim proof must be valid");
+
+		// Claims are never charged, so an alias without balance can claim.
+		let (_, val, _) = ChargeAliasPgas::<Test>::new()
+			.validate_only(origin.clone(), &call, &info, 10, TransactionSource::External, 0)
+			.expect("claims are not charged");
+		assert_eq!(val, None);
+
+		// Any other call dispatched by the claim alias pays from its balance.
+		let result = ChargeAliasPgas::<Test>::new().validate_only(
+			origin,
+			&remark_call(),
+			&info_with_weight(500),
+			10,
+			TransactionSource::External,
+			0,
+		);
+		assert!(matches!(
+			result,
+			Err(TransactionValidityError::Invalid(InvalidTransaction::Custom(code)))
+				if code == CustomValidity::InsufficientAliasPgas as u8
+		));
+	});
+}
+
+#[test]
+fn emptied_alias_balances_are_pruned() {
+	new_test_ext().execute_with(|| {
+		let who = id_to_account(1);
+		let alias: Alias = who.clone().into();
+		Pallet::<Test>::deposit_alias_pgas(&alias, 210);
+
+		let post_info = PostDispatchInfo {
+			actual_weight: Some(Weight::from_parts(200, 0)),
+			..Default::default()
+		};
+		assert_ok!(ChargeAliasPgas::<Test>::new()
+			.test_run(
+				RuntimeOrigin::signed(who),
+				&remark_call(),
+				&info_with_weight(200),
+				10,
+				0,
+				|_| { Ok(post_info) }
+			)
+			.expect("extension must accept the transaction"));
+
+		assert!(!AliasPgasBalances::<Test>::contains_key(alias));
+		assert_eq!(AliasPgasExpiries::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn alias_balances_expire_and_are_pruned_by_offchain_worker() {
+	new_test_ext().execute_with(|| {
+		set_time_sec(DAY);
+		let beneficiary = [7u8; 32];
+		assert_ok!(submit_claim_for_alias(100, 0, beneficiary, 1));
+		let expires_on = 1 + AliasPgasLifetime::get();
+		assert_eq!(
+			AliasPgasBalances::<Test>::get(beneficiary).map(|entry| entry.expires_on),
+			Some(expires_on)
+		);
+
+		// A later credit extends the lifetime of the whole balance.
+		set_time_sec(DAY * 2);
+		assert_ok!(submit_claim_for_alias(100, 0, beneficiary, 2));
+		assert_eq!(AliasPgasExpiries::<Test>::iter_prefix(Day::from(expires_on)).count(), 0);
+		let expires_on = expires_on + 1;
+		assert!(AliasPgasExpiries::<Test>::contains_key(Day::from(expires_on), beneficiary));
+
+		// Nothing is pruned while the balance is spendable.
+		set_time_sec(DAY * (expires_on as u64 - 1));
+		assert_eq!(Pallet::<Test>::alias_pgas_balance(&beneficiary), PgasClaimAmount::get() * 2);
+		Pgas::offchain_worker(System::block_number());
+		drain_ocw_transactions();
+		assert!(AliasPgasBalances::<Test>::contains_key(beneficiary));
+
+		// Once expired, the balance can't be spent anymore and is pruned.
+		set_time_sec(DAY * expires_on as u64);
+		assert_eq!(Pallet::<Test>::alias_pgas_balance(&beneficiary), 0);
+		let result = ChargeAliasPgas::<Test>::new().validate_only(
+			RuntimeOrigin::signed(AccountId32::new(beneficiary)),
+			&remark_call(),
+			&info_with_weight(1),
+			10,
+			TransactionSource::External,
+			0,
+		);
+		assert!(matches!(
+			result,
+			Err(TransactionValidityError::Invalid(InvalidTransaction::Custom(code)))
+				if code == CustomValidity::InsufficientAliasPgas as u8
+		));
+
+		Pgas::offchain_worker(System::block_number());
+		drain_ocw_transactions();
+		assert!(!AliasPgasBalances::<Test>::contains_key(beneficiary));
+		assert_eq!(AliasPgasExpiries::<Test>::iter().count(), 0);
+		System::assert_has_event(
+			Event::ExpiredAliasPgasCleaned { day_index: expires_on, count: 1 }.into(),
+		);
+	});
+}
+
+// ==================== Accrued claims ====================
+
+/// Run an accrued claim through the full `AsPgas` validate → dispatch pipeline.
+fn submit_accrued_claim(
+	member_id: u64,
+	slot_index: u32,
+	target: AccountId32,
+	day: u32,
+) -> sp_runtime::DispatchResult {
+	let (call, tx_ext) =
+		build_accrued_claim_tx(member_id, 0, PgasCollection::People, slot_index, target, day);
+	let info = call.get_dispatch_info();
+	let (_, _val, origin) = tx_ext
+		.validate_only(SystemOrigin::None.into(), &call, &info, 0, TransactionSource::External, 0)
+		.map_err(|_| sp_runtime::DispatchError::Other("validate_only failed"))?;
+	call.dispatch(origin).map(|_| ()).map_err(|e| e.error)
+}
+
+fn pgas_balance(who: &AccountId32) -> u64 {
+	<Assets as Inspect<AccountId32>>::balance(PgasAssetId::get(), who)
+}
+
+#[test]
+fn accrued_claims_collect_the_unclaimed_days_of_a_rolling_window() {
+	new_test_ext().execute_with(|| {
+		MaxAccruedClaims::set(3);
+		setup_pgas_asset();
+		let target = id_to_account(42);
+
+		// The window of day 4 spans days 2 to 4, all unclaimed.
+		set_time_sec(DAY * 4);
+		assert_ok!(submit_accrued_claim(100, 0, target.clone(), 4));
+		assert_eq!(pgas_balance(&target), PgasClaimAmount::get() * 3);
+
+		// Claiming again the same day is rejected.
+		assert!(submit_accrued_claim(100, 0, target.clone(), 4).is_err());
+
+		// The window of day 5 only holds day 5 unclaimed, and it does not reset at any fixed
+		// boundary.
+		set_time_sec(DAY * 5);
+		assert_ok!(submit_accrued_claim(100, 0, target.clone(), 5));
+		assert_eq!(pgas_balance(&target), PgasClaimAmount::get() * 4);
+
+		// Days 6 to 9 are missed; day 10 collects no more than the window (8 to 10).
+		set_time_sec(DAY * 10);
+		assert_ok!(submit_accrued_claim(100, 0, target.clone(), 10));
+		assert_eq!(pgas_balance(&target), PgasClaimAmount::get() * 7);
+		assert!(System::events().iter().any(|r| matches!(
+			&r.event,
+			RuntimeEvent::Pgas(Event::AccruedPgasClaimed { days: 3, day: 10, .. }),
+		)));
+
+		// Each collected day is recorded like a daily claim of the slot.
+		for day in [2, 3, 4, 5, 8, 9, 10] {
+			let alias = alias_for(100, &Pallet::<Test>::build_gas_context(day, 0));
+			assert!(ClaimedGasAliases::<Test>::contains_key(Day::from(day), alias));
+		}
+	});
+}
+
+#[test]
+fn daily_and_accrued_claims_never_claim_the_same_day_twice() {
+	new_test_ext().execute_with(|| {
+		MaxAccruedClaims::set(3);
+		setup_pgas_asset();
+		let target = id_to_account(42);
+
+		set_time_sec(DAY * 2);
+		assert_ok!(submit_claim(100, 0, PgasCollection::People, 0, target.clone(), 2));
+
+		// Day 2 was claimed day by day, so only days 3 and 4 accrued.
+		set_time_sec(DAY * 4);
+		assert_ok!(submit_accrued_claim(100, 0, target.clone(), 4));
+		assert_eq!(pgas_balance(&target), PgasClaimAmount::get() * 3);
+
+		// Conversely, accrued days cannot be claimed day by day anymore.
+		assert!(submit_claim(100, 0, PgasCollection::People, 0, target.clone(), 4).is_err());
+
+		// Other slots are untouched.
+		assert_ok!(submit_claim(100, 1, PgasCollection::People, 0, target.clone(), 4));
+		assert_eq!(pgas_balance(&target), PgasClaimAmount::get() * 4);
+
+		// Nothing is left to accrue.
+		let (call, tx_ext) =
+			build_accrued_claim_tx(100, 0, PgasCollection::People, 0, target.clone(), 4);
+		let result = tx_ext.validate_only(
+			SystemOrigin::None.into(),
+			&call,
+			&call.get_dispatch_info(),
+			0,
+			TransactionSource::External,
+			0,
+		);
+		assert!(matches!(
+			result,
+			Err(TransactionValidityError::Invalid(InvalidTransaction::Custom(code)))
+				if code == CustomValidity::AlreadyClaimed as u8
+		));
+	});
+}
+
+#[test]
+fn claim_records_are_kept_while_an_accrual_window_covers_their_day() {
+	new_test_ext().execute_with(|| {
+		MaxAccruedClaims::set(3);
+		setup_pgas_asset();
+
+		set_time_sec(DAY);
+		assert_ok!(submit_claim(100, 0, PgasCollection::People, 0, id_to_account(42), 1));
+
+		// Day 1 is outside the grace window but still in the accrual window of day 3.
+		set_time_sec(DAY * 3 + PGAS_DAY_GRACE_WINDOW + 1);
+		assert_eq!(Pallet::<Test>::first_retained_day(), 1);
+		Pgas::offchain_worker(System::block_number());
+		assert_eq!(pending_ocw_tx_count(), 0);
+
+		// Once no claimable day covers it, day 1 is pruned.
+		set_time_sec(DAY * 4 + PGAS_DAY_GRACE_WINDOW + 1);
+		Pgas::offchain_worker(System::block_number());
+		drain_ocw_transactions();
+		assert_eq!(ClaimedGasAliases::<Test>::iter_prefix(Day::from(1)).count(), 0);
+	});
+}
+
+#[test]
+fn accrued_claims_are_rejected_when_accrual_is_disabled() {
+	new_test_ext().execute_with(|| {
+		MaxAccruedClaims::set(3);
+		set_time_sec(DAY);
+		setup_pgas_asset();
+
+		let (call, tx_ext) =
+			build_accrued_claim_tx(100, 0, PgasCollection::People, 0, id_to_account(42), 1);
+		MaxAccruedClaims::set(0);
+		let result = tx_ext.validate_only(
+			SystemOrigin::None.into(),
+			&call,
+			&call.get_dispatch_info(),
+			0,
+			TransactionSource::External,
+			0,
+		);
+		assert!(matches!(result, Err(TransactionValidityError::Invalid(InvalidTransaction::Call))));
+
+		// Accrued claims are bound to the daily slots of the collection.
+		MaxAccruedClaims::set(3);
+		let slots = MaxClaimsPerPeriodPerPerson::get();
+		let (call, tx_ext) =
+			build_accrued_claim_tx(100, 0, PgasCollection::People, slots, id_to_account(42), 1);
+		let result = tx_ext.validate_only(
+			SystemOrigin::None.into(),
+			&call,
+			&call.get_dispatch_info(),
+			0,
+			TransactionSource::External,
+			0,
+		);
+		assert!(matches!(
+			result,
+			Err(TransactionValidityError::Invalid(InvalidTransaction::Custom(code)))
+				if code == CustomValidity::InvalidClaimSlot as u8
+		));
+	});
+}
+
+// ==================== Runtime API ====================
+
+fn claim_aliases(alias: Alias) -> ClaimAliases {
+	ClaimAliases { alias, accrual_aliases: None }
+}
+
+#[test]
+fn claim_eligibility_reports_contexts_and_claimed_aliases() {
+	new_test_ext().execute_with(|| {
+		set_time_sec(DAY + PGAS_DAY_GRACE_WINDOW + 1);
+		setup_pgas_asset();
+
+		let alias = alias_for(100, &Pallet::<Test>::build_gas_context(1, 0));
+
+		let eligibility = Pallet::<Test>::claim_eligibility(
+			PgasCollection::People,
+			0,
+			claim_aliases(alias),
+			None,
+		);
+		assert_eq!(eligibility.today.day, 1);
+		assert_eq!(eligibility.today.context, Pallet::<Test>::build_gas_context(1, 0));
+		assert_eq!(
+			eligibility.today.accrual_contexts,
+			vec![Pallet::<Test>::build_gas_context(1, 0)]
+		);
+		assert!(eligibility.today.claimable);
+		assert_eq!(eligibility.grace, None);
+		assert_eq!(eligibility.amount, PgasClaimAmount::get());
+
+		assert_ok!(submit_claim(100, 0, PgasCollection::People, 0, id_to_account(42), 1));
+		let eligibility = Pallet::<Test>::claim_eligibility(
+			PgasCollection::People,
+			0,
+			claim_aliases(alias),
+			None,
+		);
+		assert!(!eligibility.today.claimable);
+
+		// Out-of-range slots are never claimable.
+		let slots = MaxClaimsPerPeriodPerPerson::get();
+		assert!(
+			!Pallet::<Test>::claim_eligibility(
+				PgasCollection::People,
+				slots,
+				claim_aliases([0; 32]),
+				None
+			)
+			.today
+			.claimable
+		);
+
+		// After the rollover, the previous day is reported with its own alias while the grace
+		// window is open.
+		set_time_sec(DAY * 2);
+		let today_alias = alias_for(100, &Pallet::<Test>::build_gas_context(2, 0));
+		let eligibility = Pallet::<Test>::claim_eligibility(
+			PgasCollection::People,
+			0,
+			claim_aliases(today_alias),
+			Some(claim_aliases(alias)),
+		);
+		assert_eq!(eligibility.today.day, 2);
+		assert!(eligibility.today.claimable);
+		let grace = eligibility.grace.expect("grace window is open");
+		assert_eq!(grace.day, 1);
+		assert_eq!(grace.context, Pallet::<Test>::build_gas_context(1, 0));
+		assert!(!grace.claimable);
+
+		// A day queried without its alias is not claimable.
+		let eligibility = Pallet::<Test>::claim_eligibility(
+			PgasCollection::People,
+			0,
+			claim_aliases(today_alias),
+			None,
+		);
+		assert!(!eligibility.grace.expect("grace window is open").claimable);
+	});
+}
+
+#[test]
+fn claim_eligibility_reports_accrued_days() {
+	new_test_ext().execute_with(|| {
+		MaxAccruedClaims::set(3);
+		setup_pgas_asset();
+		let aliases = |member_id: u64, day: u32| ClaimAliases {
+			alias: alias_for(member_id, &Pallet::<Test>::build_gas_context(day, 0)),
+			accrual_aliases: Some(
+				Pallet::<Test>::accrual_window(day)
+					.map(|day| alias_for(member_id, &Pallet::<Test>::build_gas_context(day, 0)))
+					.collect(),
+			),
+		};
+
+		set_time_sec(DAY * 3);
+		assert_ok!(submit_claim(100, 0, PgasCollection::People, 0, id_to_account(42), 3));
+
+		set_time_sec(DAY * 4);
+		let eligibility =
+			Pallet::<Test>::claim_eligibility(PgasCollection::People, 0, aliases(100, 4), None);
+		assert_eq!(eligibility.today.day, 4);
+		assert_eq!(
+			eligibility.today.accrual_contexts,
+			(2..=4).map(|day| Pallet::<Test>::build_gas_context(day, 0)).collect::<Vec<_>>()
+		);
+		assert!(eligibility.today.claimable);
+		// Day 3 was claimed.
+		assert_eq!(eligibility.today.accrued_days, Some(2));
+
+		// Without the accrual aliases, accrued days are unknown.
+		let eligibility = Pallet::<Test>::claim_eligibility(
+			PgasCollection::People,
+			0,
+			ClaimAliases { accrual_aliases: None, ..aliases(100, 4) },
+			None,
+		);
+		assert_eq!(eligibility.today.accrued_days, None);
+
+		assert_ok!(submit_accrued_claim(100, 0, id_to_account(42), 4));
+		let eligibility =
+			Pallet::<Test>::claim_eligibility(PgasCollection::People, 0, aliases(100, 4), None);
+		assert!(!eligibility.today.claimable);
+		assert_eq!(eligibility.today.accrued_days, None);
+
+		// The next day accrued since.
+		set_time_sec(DAY * 5 + PGAS_DAY_GRACE_WINDOW + 1);
+		let eligibility =
+			Pallet::<Test>::claim_eligibility(PgasCollection::People, 0, aliases(100, 5), None);
+		assert!(eligibility.today.claimable);
+		assert_eq!(eligibility.today.accrued_days, Some(1));
+	});
+}
@@ -59,6 +59,12 @@ pub trait WeightInfo {
 	fn clean_pgas_claim_records(n: u32, ) -> Weight;
 	fn authorize_clean_pgas_claim_records() -> Weight;
 	fn as_pgas_claim_tx_ext() -> Weight;
+	fn claim_pgas_for_alias() -> Weight;
+	fn charge_alias_pgas_tx_ext() -> Weight;
+	fn claim_accrued_pgas(n: u32, ) -> Weight;
+	fn as_pgas_claim_accrued_tx_ext(n: u32, ) -> Weight;
+	fn clean_expired_alias_pgas(n: u32, ) -> Weight;
+	fn authorize_clean_expired_alias_pgas() -> Weight;
 }
 
 /// Weights for `indiv_pallet_pgas` using the Substrate node and recommended hardware.
@@ -152,6 +158,124 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(35_508_633_000, 5898)
 			.saturating_add(T::DbWeight::get().reads(5_u64))
 	}
+	/// Storage: `Pgas::ClaimedGasAliases` (r:1 w:1)
+	/// Proof: `Pgas::ClaimedGasAliases` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Pgas::AliasPgasBalances` (r:1 w:1)
+	/// Proof: `Pgas::AliasPgasBalances` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `Pgas::AliasPgasExpiries` (r:0 w:2)
+	/// Proof: `Pgas::AliasPgasExpiries` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn claim_pgas_for_alias() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `214`
+		//  Estimated: `3533`
+		// Minimum execution time: 24_873_000 picoseconds.
+		Weight::from_parts(26_012_000, 3533)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Pgas::AliasPgasBalances` (r:1 w:1)
+	/// Proof: `Pgas::AliasPgasBalances` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Pgas::AliasPgasExpiries` (r:0 w:1)
+	/// Proof: `Pgas::AliasPgasExpiries` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Proof: `System::Number` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Proof: `System::ExecutionPhase` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Proof: `System::EventCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Proof: `System::Events` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn charge_alias_pgas_tx_ext() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `249`
+		//  Estimated: `3533`
+		// Minimum execution time: 28_406_000 picoseconds.
+		Weight::from_parts(29_731_000, 3533)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Pgas::ClaimedGasAliases` (r:7 w:7)
+	/// Proof: `Pgas::ClaimedGasAliases` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Revive::OriginalAccount` (r:1 w:1)
+	/// Proof: `Revive::OriginalAccount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 7]`.
+	fn claim_accrued_pgas(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3709 + n * (53 ±0)`
+		//  Estimated: `3675 + n * (2527 ±0)`
+		// Minimum execution time: 92_816_000 picoseconds.
+		Weight::from_parts(91_407_522, 3675)
+			// Standard Error: 21_318
+			.saturating_add(Weight::from_parts(4_982_164, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2527).saturating_mul(n.into()))
+	}
+	/// Storage: `Assets::Asset` (r:1 w:0)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `MembersSubscriber::RingCollectionExponents` (r:1 w:0)
+	/// Proof: `MembersSubscriber::RingCollectionExponents` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `MembersSubscriber::RingRoots` (r:1 w:0)
+	/// Proof: `MembersSubscriber::RingRoots` (`max_values`: None, `max_size`: Some(2433), added: 4908, mode: `MaxEncodedLen`)
+	/// Storage: `Pgas::ClaimedGasAliases` (r:7 w:0)
+	/// Proof: `Pgas::ClaimedGasAliases` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 7]`.
+	fn as_pgas_claim_accrued_tx_ext(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1335`
+		//  Estimated: `5898 + n * (2527 ±0)`
+		// Minimum execution time: 35_931_604_000 picoseconds.
+		Weight::from_parts(35_487_225_000, 5898)
+			// Standard Error: 61_502_000
+			.saturating_add(Weight::from_parts(468_417_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2527).saturating_mul(n.into()))
+	}
+	/// Storage: `Pgas::AliasPgasExpiries` (r:21 w:20)
+	/// Proof: `Pgas::AliasPgasExpiries` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Pgas::AliasPgasBalances` (r:0 w:20)
+	/// Proof: `Pgas::AliasPgasBalances` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 20]`.
+	fn clean_expired_alias_pgas(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `141 + n * (53 ±0)`
+		//  Estimated: `3517 + n * (2527 ±0)`
+		// Minimum execution time: 23_118_000 picoseconds.
+		Weight::from_parts(24_052_317, 3517)
+			// Standard Error: 11_204
+			.saturating_add(Weight::from_parts(2_916_480, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2527).saturating_mul(n.into()))
+	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Pgas::AliasPgasExpiries` (r:1 w:0)
+	/// Proof: `Pgas::AliasPgasExpiries` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn authorize_clean_expired_alias_pgas() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `266`
+		//  Estimated: `3517`
+		// Minimum execution time: 12_407_000 picoseconds.
+		Weight::from_parts(13_402_000, 3517)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -244,4 +368,122 @@ impl WeightInfo for () {
 		Weight::from_parts(35_508_633_000, 5898)
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
 	}
+	/// Storage: `Pgas::ClaimedGasAliases` (r:1 w:1)
+	/// Proof: `Pgas::ClaimedGasAliases` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Pgas::AliasPgasBalances` (r:1 w:1)
+	/// Proof: `Pgas::AliasPgasBalances` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `Pgas::AliasPgasExpiries` (r:0 w:2)
+	/// Proof: `Pgas::AliasPgasExpiries` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn claim_pgas_for_alias() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `214`
+		//  Estimated: `3533`
+		// Minimum execution time: 24_873_000 picoseconds.
+		Weight::from_parts(26_012_000, 3533)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Pgas::AliasPgasBalances` (r:1 w:1)
+	/// Proof: `Pgas::AliasPgasBalances` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Pgas::AliasPgasExpiries` (r:0 w:1)
+	/// Proof: `Pgas::AliasPgasExpiries` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Proof: `System::Number` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Proof: `System::ExecutionPhase` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Proof: `System::EventCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Proof: `System::Events` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn charge_alias_pgas_tx_ext() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `249`
+		//  Estimated: `3533`
+		// Minimum execution time: 28_406_000 picoseconds.
+		Weight::from_parts(29_731_000, 3533)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Pgas::ClaimedGasAliases` (r:7 w:7)
+	/// Proof: `Pgas::ClaimedGasAliases` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Revive::OriginalAccount` (r:1 w:1)
+	/// Proof: `Revive::OriginalAccount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 7]`.
+	fn claim_accrued_pgas(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3709 + n * (53 ±0)`
+		//  Estimated: `3675 + n * (2527 ±0)`
+		// Minimum execution time: 92_816_000 picoseconds.
+		Weight::from_parts(91_407_522, 3675)
+			// Standard Error: 21_318
+			.saturating_add(Weight::from_parts(4_982_164, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2527).saturating_mul(n.into()))
+	}
+	/// Storage: `Assets::Asset` (r:1 w:0)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `MembersSubscriber::RingCollectionExponents` (r:1 w:0)
+	/// Proof: `MembersSubscriber::RingCollectionExponents` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `MembersSubscriber::RingRoots` (r:1 w:0)
+	/// Proof: `MembersSubscriber::RingRoots` (`max_values`: None, `max_size`: Some(2433), added: 4908, mode: `MaxEncodedLen`)
+	/// Storage: `Pgas::ClaimedGasAliases` (r:7 w:0)
+	/// Proof: `Pgas::ClaimedGasAliases` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 7]`.
+	fn as_pgas_claim_accrued_tx_ext(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1335`
+		//  Estimated: `5898 + n * (2527 ±0)`
+		// Minimum execution time: 35_931_604_000 picoseconds.
+		Weight::from_parts(35_487_225_000, 5898)
+			// Standard Error: 61_502_000
+			.saturating_add(Weight::from_parts(468_417_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2527).saturating_mul(n.into()))
+	}
+	/// Storage: `Pgas::AliasPgasExpiries` (r:21 w:20)
+	/// Proof: `Pgas::AliasPgasExpiries` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Pgas::AliasPgasBalances` (r:0 w:20)
+	/// Proof: `Pgas::AliasPgasBalances` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 20]`.
+	fn clean_expired_alias_pgas(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `141 + n * (53 ±0)`
+		//  Estimated: `3517 + n * (2527 ±0)`
+		// Minimum execution time: 23_118_000 picoseconds.
+		Weight::from_parts(24_052_317, 3517)
+			// Standard Error: 11_204
+			.saturating_add(Weight::from_parts(2_916_480, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2527).saturating_mul(n.into()))
+	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Pgas::AliasPgasExpiries` (r:1 w:0)
+	/// Proof: `Pgas::AliasPgasExpiries` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn authorize_clean_expired_alias_pgas() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `266`
+		//  Estimated: `3517`
+		// Minimum execution time: 12_407_000 picoseconds.
+		Weight::from_parts(13_402_000, 3517)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+	}
 }
@@ -30,6 +30,7 @@ use frame_support::{
 	assert_ok,
 	pallet_prelude::ConstU32,
 	traits::{fungible::Mutate, Consideration, ConstU16, EnsureOrigin, Get},
+	weights::WeightMeter,
 };
 use frame_system::RawOrigin as SystemOrigin;
 use indiv_support::traits::{Judgement, JudgementContext};
@@ -335,6 +336,8 @@ mod benches {
 
 		register_families::<T>();
 		register_reimbursement_values::<T>(referred_value, referred_value);
+		// The worst case credits a royalty to the artist of the design family.
+		<FamilyArtists<T>>::insert(0, account::<T::AccountId>("artist", 0, SEED));
 		register_people::<T>(PEOPLE_COUNT as u32);
 		let candidates = register_candidates::<T>(CANDIDATE_COUNT as u32);
 
@@ -385,6 +388,8 @@ mod benches {
 
 		register_families::<T>();
 		register_reimbursement_values::<T>(referred_value, referrer_value);
+		// The worst case credits a royalty to the artist of the design family.
+		<FamilyArtists<T>>::insert(0, account::<T::AccountId>("artist", 0, SEED));
 		register_people::<T>(PEOPLE_COUNT as u32);
 		let candidates = register_candidates::<T>(CANDIDATE_COUNT as u32);
 
@@ -1061,6 +1066,181 @@ mod benches {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn reattest() -> Result<(), BenchmarkError> {
+		register_families::<T>();
+		let people = register_people::<T>(PEOPLE_COUNT as u32);
+
+		let personal_id: PersonalId = people.first().cloned().unwrap().1;
+		let deadline = frame_system::Pallet::<T>::block_number();
+		let status = Reattestation::Pending { deadline, judging: None, failed: 0 };
+		<Reattestations<T>>::insert(personal_id, status);
+		let origin = T::BenchmarkHelper::build_person_origin(personal_id);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, EvidenceHash::default());
+
+		assert!(matches!(
+			<Reattestations<T>>::get(personal_id),
+			Some(Reattestation::Pending { judging: Some(_), .. })
+		));
+		assert_last_event::<T>(Event::ReattestationRequested { personal_id }.into());
+		Ok(())
+	}
+
+	// Worst case: the evidence of a suspended person is accepted and their personhood resumed.
+	#[benchmark]
+	fn reattestation_judged() -> Result<(), BenchmarkError> {
+		register_families::<T>();
+		let people = register_people::<T>(PEOPLE_COUNT as u32);
+
+		let personal_id: PersonalId = people.first().cloned().unwrap().1;
+		T::PeopleSuspension::start_people_set_mutation_session()?;
+		T::PeopleSuspension::suspend_personhood(&[personal_id])?;
+		T::PeopleSuspension::end_people_set_mutation_session()?;
+		let ticket: OracleTicketOf<T> = Default::default();
+		let status = Reattestation::Suspended { judging: Some(ticket.clone()), failed: 1 };
+		<Reattestations<T>>::insert(personal_id, status);
+		let context: JudgementContext = personal_id.encode().try_into().unwrap();
+
+		#[extrinsic_call]
+		_(SystemOrigin::Root, ticket, context, Judgement::Truth(True));
+
+		assert!(!<Reattestations<T>>::contains_key(personal_id));
+		frame_system::Pallet::<T>::assert_has_event(Event::PersonResumed { personal_id }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn suspend_lapsed() -> Result<(), BenchmarkError> {
+		register_families::<T>();
+		let people = register_people::<T>(PEOPLE_COUNT as u32);
+
+		let personal_id: PersonalId = people.first().cloned().unwrap().1;
+		let deadline = frame_system::Pallet::<T>::block_number();
+		let status = Reattestation::Pending { deadline, judging: None, failed: 1 };
+		<Reattestations<T>>::insert(personal_id, status);
+		let now = deadline
+			.saturating_add(T::ReattestationGracePeriod::get())
+			.saturating_add(1u32.into());
+		frame_system::Pallet::<T>::set_block_number(now);
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(SystemOrigin::Signed(caller), personal_id);
+
+		assert_eq!(
+			<Reattestations<T>>::get(personal_id),
+			Some(Reattestation::Suspended { judging: None, failed: 1 })
+		);
+		assert_last_event::<T>(Event::PersonSuspended { personal_id }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn draw_for_reattestation(
+		n: Linear<0, { T::ReattestationBatchSize::get().saturating_mul(2) }>,
+	) -> Result<(), BenchmarkError> {
+		let batch = T::ReattestationBatchSize::get();
+		let people = register_people::<T>(n);
+		// Suspended people are examined but not drawn, so that all `n` people can be examined.
+		for (_, personal_id) in people.iter().take(n.saturating_sub(batch) as usize) {
+			let status = Reattestation::Suspended { judging: None, failed: 0 };
+			<Reattestations<T>>::insert(personal_id, status);
+		}
+		let now = frame_system::Pallet::<T>::block_number();
+		let mut meter = WeightMeter::new();
+
+		#[block]
+		{
+			PoI::<T>::draw_for_reattestation(now, &mut meter);
+		}
+
+		let drawn = <Reattestations<T>>::iter_values()
+			.filter(|status| matches!(status, Reattestation::Pending { .. }))
+			.count();
+		assert_eq!(drawn as u32, n.min(batch));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn propose_design_family() -> Result<(), BenchmarkError> {
+		let kind = FamilyKind::Designed { count: 10000 };
+		let id = [0u8; 32];
+		let artist: T::AccountId = whitelisted_caller();
+		T::Deposit::ensure_successful(&artist, Pallet::<T>::proposal_footprint());
+
+		#[extrinsic_call]
+		_(SystemOrigin::Signed(artist.clone()), kind, id);
+
+		assert!(<DesignFamilyProposals<T>>::contains_key(0));
+		assert_last_event::<T>(Event::DesignFamilyProposed { proposal: 0, artist }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn approve_design_family() -> Result<(), BenchmarkError> {
+		register_families::<T>();
+
+		let index = PROCEDURAL_FAMILY_START_INDEX + FAMILIES_PER_KIND;
+		let artist: T::AccountId = whitelisted_caller();
+		T::Deposit::ensure_successful(&artist, Pallet::<T>::proposal_footprint());
+		assert_ok!(PoI::<T>::propose_design_family(
+			SystemOrigin::Signed(artist.clone()).into(),
+			FamilyKind::Designed { count: 10000 },
+			[0u8; 32],
+		));
+
+		let origin =
+			T::ManagerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, 0, index);
+
+		assert_eq!(<FamilyArtists<T>>::get(index), Some(artist.clone()));
+		assert_last_event::<T>(Event::DesignFamilyApproved { proposal: 0, index, artist }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn reject_design_family() -> Result<(), BenchmarkError> {
+		let artist: T::AccountId = whitelisted_caller();
+		T::Deposit::ensure_successful(&artist, Pallet::<T>::proposal_footprint());
+		assert_ok!(PoI::<T>::propose_design_family(
+			SystemOrigin::Signed(artist.clone()).into(),
+			FamilyKind::Designed { count: 10000 },
+			[0u8; 32],
+		));
+
+		let origin =
+			T::ManagerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, 0, false);
+
+		assert!(!<DesignFamilyProposals<T>>::contains_key(0));
+		assert_last_event::<T>(Event::DesignFamilyRejected { proposal: 0, artist }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn claim_royalties() -> Result<(), BenchmarkError> {
+		T::BenchmarkHelper::setup_currency();
+		let amount = reimbursement_value::<T>();
+		let pot = Pallet::<T>::proof_of_ink_pot_id();
+		assert_ok!(T::Currency::mint_into(&pot, amount));
+
+		let artist: T::AccountId = whitelisted_caller();
+		<ArtistRoyalties<T>>::insert(&artist, amount);
+
+		#[extrinsic_call]
+		_(SystemOrigin::Signed(artist.clone()));
+
+		assert!(!<ArtistRoyalties<T>>::contains_key(&artist));
+		assert_last_event::<T>(Event::RoyaltiesClaimed { artist, amount }.into());
+		Ok(())
+	}
+
 	// Implements a test for each benchmark. Execute with:
 	// `cargo test -p indiv-pallet-proof-of-ink --features runtime-benchmarks`.
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
@@ -36,11 +36,11 @@ use frame_support::{
 	DefaultNoBound,
 };
 use indiv_support::traits::{
-	AddOnlyPeopleTrait, Callback, EvidenceHash, InkSpec, Judgement, JudgementContext, PersonalId,
-	Statement::ProofOfInk, StatementOracle, Truth::*,
+	AddOnlyPeopleTrait, Callback, EvidenceHash, InkSpec, Judgement, JudgementContext, PeopleTrait,
+	PersonalId, Statement::ProofOfInk, StatementOracle, Truth::*,
 };
 use scale_info::TypeInfo;
-use sp_arithmetic::traits::Saturating;
+use sp_arithmetic::{traits::Saturating, Percent};
 use sp_runtime::traits::{BadOrigin, IdentifyAccount, Verify};
 use sp_std::vec;
 use verifiable::GenerateVerifiable;
@@ -69,6 +69,7 @@ pub mod pallet {
 	use frame_support::{
 		pallet_prelude::{DispatchResultWithPostInfo, ValueQuery, *},
 		traits::{Consideration, Defensive},
+		weights::WeightMeter,
 		PalletId, Twox64Concat,
 	};
 	use frame_system::pallet_prelude::*;
@@ -106,6 +107,11 @@ pub mod pallet {
 		/// Who to tell when we recognise personhood.
 		type People: AddOnlyPeopleTrait;
 
+		/// Who to tell when a person must be suspended for failing or skipping re-attestation.
+		///
+		/// Only used when re-attestation is enabled through `ReattestationPeriod`.
+		type PeopleSuspension: PeopleTrait;
+
 		/// How to recognise an origin representing a person.
 		type EnsurePerson: EnsureOrigin<OriginFor<Self>, Success = PersonalId>;
 
@@ -141,6 +147,24 @@ pub mod pallet {
 		/// The maximum number of reimbursement values.
 		type MaxReimbursementValues: Get<u32>;
 
+		/// How often, in blocks, proven people are drawn to re-attest their tattoo. Zero disables
+		/// re-attestation.
+		#[pallet::constant]
+		type ReattestationPeriod: Get<BlockNumberFor<Self>>;
+
+		/// The maximum number of people drawn for re-attestation at once.
+		#[pallet::constant]
+		type ReattestationBatchSize: Get<u32>;
+
+		/// The number of blocks a person drawn for re-attestation has to submit fresh evidence.
+		#[pallet::constant]
+		type ReattestationWindow: Get<BlockNumberFor<Self>>;
+
+		/// The number of blocks after a missed deadline or a failed judgement before the person
+		/// can be suspended. A successful re-attestation within this period prevents suspension.
+		#[pallet::constant]
+		type ReattestationGracePeriod: Get<BlockNumberFor<Self>>;
+
 		/// Currency used for reward payouts.
 		type Currency: Inspect<Self::AccountId> + Mutate<Self::AccountId>;
 
@@ -155,6 +179,11 @@ pub mod pallet {
 		#[pallet::constant]
 		type PotId: Get<PalletId>;
 
+		/// The share of the reimbursement of a candidate proven with a design, withheld in the pot
+		/// and credited to the artist who proposed the design family.
+		#[pallet::constant]
+		type ArtistRoyalty: Get<Percent>;
+
 		/// The origin that can issue proof-of-ink invitations.
 		type InvitationsOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
@@ -176,6 +205,11 @@ pub mod pallet {
 	pub type CandidateOf<T> =
 		Candidate<OracleTicketOf<T>, <T as Config>::Deposit, BlockNumberFor<T>, TicketOf<T>>;
 
+	pub type ReattestationOf<T> = Reattestation<OracleTicketOf<T>, BlockNumberFor<T>>;
+
+	pub type DesignFamilyProposalOf<T> =
+		DesignFamilyProposal<<T as frame_system::Config>::AccountId, <T as Config>::Deposit>;
+
 	pub type MemberOf<T> = <<T as Config>::People as AddOnlyPeopleTrait>::Member;
 	pub type BalanceOf<T> =
 		<<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
@@ -191,6 +225,7 @@ pub mod pallet {
 	pub type Entropy = [u8; 32];
 	pub type ProceduralSeed = [u8; 4];
 	pub type FamilyIndex = u16;
+	pub type ProposalIndex = u32;
 	pub type DesignIndex = u16;
 	pub type VariantIndex = u8;
 	pub type Counter = u32;
@@ -245,6 +280,17 @@ pub mod pallet {
 		pub id: FamilyId,
 	}
 
+	/// A design family proposed by an artist, awaiting vetting.
+	#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode, MaxEncodedLen, TypeInfo)]
+	pub struct DesignFamilyProposal<AccountId, Deposit> {
+		/// The artist who proposed the family and receives royalties for it once approved.
+		pub artist: AccountId,
+		/// The proposed family.
+		pub family: Family,
+		/// The deposit held for the proposal.
+		pub deposit: Deposit,
+	}
+
 	#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode, MaxEncodedLen, TypeInfo)]
 	pub enum DesignStatus {
 		Reserved,
@@ -332,6 +378,22 @@ pub mod pallet {
 		pub(super) pending_referral_rewards: Counter,
 	}
 
+	/// The re-attestation status of a proven person.
+	#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode, MaxEncodedLen, TypeInfo)]
+	pub enum Reattestation<JudgementId, BlockNumber> {
+		/// The person has been drawn and must submit fresh evidence of their tattoo.
+		Pending {
+			/// The block after which the grace period starts. A failed judgement brings it
+			/// forward to the block of the judgement.
+			deadline: BlockNumber,
+			judging: Option<JudgementId>,
+			failed: Counter,
+		},
+		/// The personhood has been suspended for failing or skipping re-attestation. It is resumed
+		/// once fresh evidence is accepted.
+		Suspended { judging: Option<JudgementId>, failed: Counter },
+	}
+
 	/// Information for a referral ticket.
 	#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode, MaxEncodedLen, TypeInfo)]
 	pub struct ReferralTicket<Ticket> {
@@ -366,6 +428,24 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type DesignFamilies<T> = StorageMap<_, Blake2_128Concat, FamilyIndex, Family>;
 
+	/// The design families proposed by artists and awaiting vetting.
+	#[pallet::storage]
+	pub type DesignFamilyProposals<T: Config> =
+		StorageMap<_, Twox64Concat, ProposalIndex, DesignFamilyProposalOf<T>>;
+
+	/// The index of the next design family proposal.
+	#[pallet::storage]
+	pub type NextDesignFamilyProposal<T> = StorageValue<_, ProposalIndex, ValueQuery>;
+
+	/// The artists of the design families which were proposed and approved.
+	#[pallet::storage]
+	pub type FamilyArtists<T: Config> = StorageMap<_, Blake2_128Concat, FamilyIndex, T::AccountId>;
+
+	/// The royalties credited to artists, ready to be claimed from the pot.
+	#[pallet::storage]
+	pub type ArtistRoyalties<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
 	/// The committed designs which are no longer available.
 	#[pallet::storage]
 	pub type CommittedDesigns<T> = StorageDoubleMap<
@@ -377,6 +457,11 @@ pub mod pallet {
 		DesignStatus,
 	>;
 
+	/// The people drawn for re-attestation, along with the ones suspended for failing it.
+	#[pallet::storage]
+	pub type Reattestations<T: Config> =
+		StorageMap<_, Twox64Concat, PersonalId, ReattestationOf<T>>;
+
 	/// The number of judgements currently ongoing.
 	#[pallet::storage]
 	pub type AllocationCount<T> = StorageValue<_, Counter, ValueQuery>;
@@ -465,6 +550,29 @@ pub mod pallet {
 		InviteTicketCancelled { inviter: T::AccountId, ticket: T::Ticket },
 		/// The pallet configuration has been updated.
 		ConfigurationSet { config: ConfigRecord<BlockNumberFor<T>> },
+		/// A person has been drawn to re-attest their tattoo before `deadline`.
+		DrawnForReattestation { personal_id: PersonalId, deadline: BlockNumberFor<T> },
+		/// A person opened a Mob Rule case for their re-attestation evidence.
+		ReattestationRequested { personal_id: PersonalId },
+		/// A person's re-attestation evidence has been accepted.
+		Reattested { personal_id: PersonalId },
+		/// A person's re-attestation evidence has been rejected.
+		ReattestationFailed { personal_id: PersonalId, judgement: Judgement, failures: u32 },
+		/// A person has been suspended for failing or skipping re-attestation.
+		PersonSuspended { personal_id: PersonalId },
+		/// A suspended person's personhood has been resumed after their re-attestation evidence
+		/// was accepted.
+		PersonResumed { personal_id: PersonalId },
+		/// An artist proposed a design family.
+		DesignFamilyProposed { proposal: ProposalIndex, artist: T::AccountId },
+		/// A proposed design family has been approved and added at `index`.
+		DesignFamilyApproved { proposal: ProposalIndex, index: FamilyIndex, artist: T::AccountId },
+		/// A proposed design family has been rejected and its deposit burned.
+		DesignFamilyRejected { proposal: ProposalIndex, artist: T::AccountId },
+		/// An artist has been credited a royalty for a person proven with their design.
+		RoyaltyCredited { artist: T::AccountId, family: FamilyIndex, amount: BalanceOf<T> },
+		/// An artist claimed their royalties.
+		RoyaltiesClaimed { artist: T::AccountId, amount: BalanceOf<T> },
 	}
 
 	#[pallet::error]
@@ -549,6 +657,16 @@ pub mod pallet {
 		InvalidProofOfOwnership,
 		/// The reimbursement values are invalid.
 		InvalidReimbursementValues,
+		/// The person has not been drawn for re-attestation.
+		NotDrawn,
+		/// People cannot be suspended at the moment; try again later.
+		SuspensionUnavailable,
+		/// The design family index is already used.
+		FamilyExists,
+		/// The design family proposal doesn't exist.
+		NoProposal,
+		/// There are no royalties to claim.
+		NoRoyalties,
 	}
 
 	/// A reason for this pallet placing a hold on funds.
@@ -572,6 +690,7 @@ pub mod pallet {
 	pub trait Callbacks {
 		type T: Config;
 		fn judged() -> CallbackOf<Self::T>;
+		fn reattestation_judged() -> CallbackOf<Self::T>;
 	}
 	impl<T: Config> Callbacks for Call<T> {
 		type T = T;
@@ -586,6 +705,17 @@ pub mod pallet {
 			let call_index = judgement_call.get_call_index();
 			Callback::from_parts(Pallet::<T>::index() as u8, call_index)
 		}
+		fn reattestation_judged() -> CallbackOf<T> {
+			use frame_support::traits::GetCallIndex;
+			// Parameters to this call do not matter, as we are just extracting the call index.
+			let judgement_call = Call::<T>::reattestation_judged {
+				ticket: Default::default(),
+				context: Default::default(),
+				judgement: Judgement::Contempt,
+			};
+			let call_index = judgement_call.get_call_index();
+			Callback::from_parts(Pallet::<T>::index() as u8, call_index)
+		}
 	}
 
 	#[pallet::origin]
@@ -620,6 +750,23 @@ pub mod pallet {
 				<T as Config>::MaxActiveReferrals::get() <= 200,
 				"maximum number of active referrals cannot exceed 200"
 			);
+			assert!(
+				T::ReattestationPeriod::get().is_zero() || T::ReattestationBatchSize::get() > 0,
+				"re-attestation batch size must be positive when re-attestation is enabled"
+			);
+		}
+
+		fn on_poll(n: BlockNumberFor<T>, weight_meter: &mut WeightMeter) {
+			let period = T::ReattestationPeriod::get();
+			if period.is_zero() || !(n % period).is_zero() {
+				return;
+			}
+			// Use at most 50% of available weight to be cautious about weight
+			// underestimates.
+			let budget = weight_meter.remaining() / 2;
+			let mut meter = WeightMeter::with_limit(budget);
+			Self::draw_for_reattestation(n, &mut meter);
+			weight_meter.consume(meter.consumed());
 		}
 	}
 
@@ -1232,6 +1379,7 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			T::ManagerOrigin::ensure_origin_or_root(origin)?;
 			DesignFamilies::<T>::insert(index, Family { kind: kind.clone(), id });
+			FamilyArtists::<T>::remove(index);
 			Self::deposit_event(Event::FamilyAdded { index, kind, id });
 			Ok(Pays::No.into())
 		}
@@ -1451,6 +1599,231 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Submit fresh evidence of the tattoo after being drawn for re-attestation, or after
+		/// being suspended for failing or skipping it to resume the personhood.
+		///
+		/// The evidence is judged against the design the person registered with. No storage is
+		/// allocated for it, so it must be retrievable by the oracle through other means.
+		#[pallet::weight(T::WeightInfo::reattest())]
+		#[pallet::call_index(20)]
+		pub fn reattest(
+			origin: OriginFor<T>,
+			evidence: EvidenceHash,
+		) -> DispatchResultWithPostInfo {
+			let personal_id = T::EnsurePerson::ensure_origin(origin)?;
+			let person = People::<T>::get(personal_id).ok_or(Error::<T>::NotPoiPerson)?;
+			let mut status = Reattestations::<T>::get(personal_id).ok_or(Error::<T>::NotDrawn)?;
+			let (judging, failed) = match &mut status {
+				Reattestation::Pending { judging, failed, .. } |
+				Reattestation::Suspended { judging, failed } => (judging, *failed),
+			};
+			ensure!(judging.is_none(), Error::<T>::AlreadyStarted);
+			let design = person.design.ok_or(Error::<T>::NotPoiPerson)?;
+			let context = JudgementContext::truncate_from(personal_id.encode());
+			let callback = Call::<T>::reattestation_judged();
+			let statement = ProofOfInk { design, evidence, probable_acceptable: false };
+			*judging = Some(T::Oracle::judge_statement(statement, context, callback)?);
+			let pays = if failed == 0 { Pays::No } else { Pays::Yes };
+			Reattestations::<T>::insert(personal_id, status);
+			Self::deposit_event(Event::ReattestationRequested { personal_id });
+			Ok(pays.into())
+		}
+
+		/// Is called by the Oracle when the re-attestation evidence has been judged.
+		///
+		/// A rejected evidence brings the deadline forward, the person can still submit new
+		/// evidence until the grace period ends. An accepted evidence of a suspended person resumes
+		/// their personhood.
+		#[pallet::weight(T::WeightInfo::reattestation_judged())]
+		#[pallet::call_index(21)]
+		pub fn reattestation_judged(
+			origin: OriginFor<T>,
+			ticket: OracleTicketOf<T>,
+			context: JudgementContext,
+			judgement: Judgement,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			let personal_id =
+				PersonalId::decode(&mut &context[..]).map_err(|_| Error::<T>::BadContext)?;
+			let status = Reattestations::<T>::get(personal_id).ok_or(Error::<T>::BadContext)?;
+			let (judging, mut failed) = match &status {
+				Reattestation::Pending { judging, failed, .. } |
+				Reattestation::Suspended { judging, failed } => (judging.clone(), *failed),
+			};
+			let Some(id) = judging else { Err(Error::<T>::BadContext)? };
+			ensure!(id == ticket, Error::<T>::UnexpectedJudgement);
+
+			if let Judgement::Truth(True) = judgement {
+				if let Reattestation::Suspended { .. } = status {
+					let resumed = T::PeopleSuspension::recognize_personhood(personal_id, None);
+					if let Err(error) = resumed {
+						// The person stays suspended and can submit new evidence.
+						log::error!(
+							target: LOG_TARGET,
+							"Failed to resume personhood of {personal_id}: {error:?}",
+						);
+						let status = Reattestation::Suspended { judging: None, failed };
+						Reattestations::<T>::insert(personal_id, status);
+						return Ok(Pays::No.into());
+					}
+					Self::deposit_event(Event::PersonResumed { personal_id });
+				}
+				Reattestations::<T>::remove(personal_id);
+				Self::deposit_event(Event::Reattested { personal_id });
+			} else {
+				failed.saturating_inc();
+				let status = match status {
+					Reattestation::Pending { deadline, .. } => {
+						let now = frame_system::Pallet::<T>::block_number();
+						Reattestation::Pending {
+							deadline: deadline.min(now),
+							judging: None,
+							failed,
+						}
+					},
+					Reattestation::Suspended { .. } =>
+						Reattestation::Suspended { judging: None, failed },
+				};
+				Reattestations::<T>::insert(personal_id, status);
+				Self::deposit_event(Event::ReattestationFailed {
+					personal_id,
+					judgement,
+					failures: failed,
+				});
+			}
+			Ok(Pays::No.into())
+		}
+
+		/// Suspend a person who failed or skipped their re-attestation.
+		///
+		/// Can be called by anyone once the grace period following the deadline has passed and no
+		/// judgement is ongoing for the person.
+		#[pallet::weight(T::WeightInfo::suspend_lapsed())]
+		#[pallet::call_index(22)]
+		pub fn suspend_lapsed(
+			origin: OriginFor<T>,
+			personal_id: PersonalId,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let Some(Reattestation::Pending { deadline, judging, failed }) =
+				Reattestations::<T>::get(personal_id)
+			else {
+				Err(Error::<T>::NotDrawn)?
+			};
+			ensure!(judging.is_none(), Error::<T>::AlreadyStarted);
+			let now = frame_system::Pallet::<T>::block_number();
+			let expiry = deadline.saturating_add(T::ReattestationGracePeriod::get());
+			ensure!(expiry < now, Error::<T>::TooEarly);
+			ensure!(
+				T::PeopleSuspension::can_start_people_set_mutation_session(),
+				Error::<T>::SuspensionUnavailable
+			);
+
+			T::PeopleSuspension::start_people_set_mutation_session()?;
+			T::PeopleSuspension::suspend_personhood(&[personal_id])?;
+			T::PeopleSuspension::end_people_set_mutation_session()?;
+			let status = Reattestation::Suspended { judging: None, failed };
+			Reattestations::<T>::insert(personal_id, status);
+			Self::deposit_event(Event::PersonSuspended { personal_id });
+			Ok(Pays::No.into())
+		}
+
+		/// Propose a design family.
+		///
+		/// A deposit is taken and returned once the family is approved, at which point it is
+		/// given its index. For designed families, the artist is credited a share of the
+		/// reimbursement of every candidate proven with an elective design of the family.
+		#[pallet::weight(T::WeightInfo::propose_design_family())]
+		#[pallet::call_index(23)]
+		pub fn propose_design_family(
+			origin: OriginFor<T>,
+			kind: FamilyKind,
+			id: FamilyId,
+		) -> DispatchResult {
+			let artist = ensure_signed(origin)?;
+
+			let footprint = Self::proposal_footprint();
+			let deposit = T::Deposit::new(&artist, footprint)?;
+			let proposal = NextDesignFamilyProposal::<T>::mutate(|next| {
+				let proposal = *next;
+				next.saturating_inc();
+				proposal
+			});
+			DesignFamilyProposals::<T>::insert(
+				proposal,
+				DesignFamilyProposal {
+					artist: artist.clone(),
+					family: Family { kind, id },
+					deposit,
+				},
+			);
+			Self::deposit_event(Event::DesignFamilyProposed { proposal, artist });
+			Ok(())
+		}
+
+		/// Approve a proposed design family, adding it at `index`. Must have privileged access
+		/// to do this.
+		///
+		/// The deposit of the artist is returned.
+		#[pallet::weight(T::WeightInfo::approve_design_family())]
+		#[pallet::call_index(24)]
+		pub fn approve_design_family(
+			origin: OriginFor<T>,
+			proposal: ProposalIndex,
+			index: FamilyIndex,
+		) -> DispatchResultWithPostInfo {
+			T::ManagerOrigin::ensure_origin_or_root(origin)?;
+			ensure!(!DesignFamilies::<T>::contains_key(index), Error::<T>::FamilyExists);
+			let DesignFamilyProposal { artist, family, deposit } =
+				DesignFamilyProposals::<T>::take(proposal).ok_or(Error::<T>::NoProposal)?;
+
+			deposit.drop(&artist)?;
+			let Family { kind, id } = family.clone();
+			DesignFamilies::<T>::insert(index, family);
+			FamilyArtists::<T>::insert(index, &artist);
+			Self::deposit_event(Event::FamilyAdded { index, kind, id });
+			Self::deposit_event(Event::DesignFamilyApproved { proposal, index, artist });
+			Ok(Pays::No.into())
+		}
+
+		/// Reject a proposed design family. Must have privileged access to do this.
+		///
+		/// The deposit of the artist is returned, unless the proposal is rejected as `spam` in
+		/// which case it is burned.
+		#[pallet::weight(T::WeightInfo::reject_design_family())]
+		#[pallet::call_index(25)]
+		pub fn reject_design_family(
+			origin: OriginFor<T>,
+			proposal: ProposalIndex,
+			spam: bool,
+		) -> DispatchResultWithPostInfo {
+			T::ManagerOrigin::ensure_origin_or_root(origin)?;
+			let DesignFamilyProposal { artist, deposit, .. } =
+				DesignFamilyProposals::<T>::take(proposal).ok_or(Error::<T>::NoProposal)?;
+
+			if spam {
+				deposit.burn(&artist);
+			} else {
+				deposit.drop(&artist)?;
+			}
+			Self::deposit_event(Event::DesignFamilyRejected { proposal, artist });
+			Ok(Pays::No.into())
+		}
+
+		/// Claim the royalties credited to the artist from the pot.
+		#[pallet::weight(T::WeightInfo::claim_royalties())]
+		#[pallet::call_index(26)]
+		pub fn claim_royalties(origin: OriginFor<T>) -> DispatchResult {
+			let artist = ensure_signed(origin)?;
+			let amount = ArtistRoyalties::<T>::take(&artist);
+			ensure!(!amount.is_zero(), Error::<T>::NoRoyalties);
+
+			let pot_id = Self::proof_of_ink_pot_id();
+			T::Currency::transfer(&pot_id, &artist, amount, Preservation::Expendable)?;
+			Self::deposit_event(Event::RoyaltiesClaimed { artist, amount });
+			Ok(())
+		}
+
 		/// Set the configuration record. Must have privileged access to do this.
 		#[pallet::weight(T::WeightInfo::set_configuration())]
 		#[pallet::call_index(50)]
@@ -1688,7 +2061,7 @@ pub mod pallet {
 			People::<T>::insert(
 				id,
 				Person {
-					design: Some(design),
+					design: Some(design.clone()),
 					allowed_referral_tickets: 1,
 					active_referrals: Default::default(),
 					bad_referrals: 0,
@@ -1706,7 +2079,7 @@ pub mod pallet {
 			if referrer_reward_kind == RewardKind::Enabled {
 				Self::register_referrer_reward(destination.clone())?;
 			}
-			Self::register_referred_reward(destination)?;
+			Self::register_referred_reward(destination, &design)?;
 
 			Self::deposit_event(Event::PersonRegistered { account_id: account, personal_id: id });
 			Ok(())
@@ -1716,7 +2089,16 @@ pub mod pallet {
 			Footprint::from_mel::<(T::AccountId, CandidateOf<T>)>()
 		}
 
-		pub(crate) fn register_referred_reward(destination: T::AccountId) -> DispatchResult {
+		pub(crate) fn proposal_footprint() -> Footprint {
+			Footprint::from_mel::<(ProposalIndex, DesignFamilyProposalOf<T>)>()
+		}
+
+		/// Transfer the referred reward to `destination`, withholding in the pot the royalty
+		/// credited to the artist of `design`.
+		pub(crate) fn register_referred_reward(
+			destination: T::AccountId,
+			design: &InkSpec,
+		) -> DispatchResult {
 			let pot_id = Self::proof_of_ink_pot_id();
 
 			let mut reward_values = ReferredReimbursementValues::<T>::get().unwrap_or_default();
@@ -1731,13 +2113,17 @@ pub mod pallet {
 				Some((current_value, count)) => (current_value, count),
 			};
 
-			let transferred = T::Currency::transfer(
-				&pot_id,
-				&destination,
-				current_value,
-				Preservation::Expendable,
-			)?;
-			debug_assert_eq!(transferred, current_value);
+			let royalty = Self::credit_artist_royalty(design, current_value);
+			let reimbursement = current_value.saturating_sub(royalty);
+			if !reimbursement.is_zero() {
+				let transferred = T::Currency::transfer(
+					&pot_id,
+					&destination,
+					reimbursement,
+					Preservation::Expendable,
+				)?;
+				debug_assert_eq!(transferred, reimbursement);
+			}
 
 			count.saturating_dec();
 			if count > 0 {
@@ -1749,6 +2135,26 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Credit the artist of the family of `design`, if any, with their share of `value`,
+		/// returning the amount credited.
+		///
+		/// Only elective designs earn royalties: procedural designs are generated rather than
+		/// picked from the artist's work.
+		pub(crate) fn credit_artist_royalty(
+			design: &InkSpec,
+			value: BalanceOf<T>,
+		) -> BalanceOf<T> {
+			let InkSpec::DesignedElective(family, _) = *design else { return Zero::zero() };
+			let Some(artist) = FamilyArtists::<T>::get(family) else { return Zero::zero() };
+			let amount = T::ArtistRoyalty::get().mul_floor(value);
+			if amount.is_zero() {
+				return amount;
+			}
+			ArtistRoyalties::<T>::mutate(&artist, |royalties| royalties.saturating_accrue(amount));
+			Self::deposit_event(Event::RoyaltyCredited { artist, family, amount });
+			amount
+		}
+
 		pub(crate) fn register_referrer_reward(destination: T::AccountId) -> DispatchResult {
 			let pot_id = Self::proof_of_ink_pot_id();
 
@@ -1781,6 +2187,48 @@ pub mod pallet {
 			ReferrerReimbursementValues::<T>::put(reward_values);
 			Ok(())
 		}
+
+		/// Draw proven people at random for re-attestation.
+		///
+		/// The walk over `People` starts at a random position and examines at most twice the
+		/// batch size of people, skipping the ones already drawn or suspended.
+		pub(crate) fn draw_for_reattestation(
+			now: BlockNumberFor<T>,
+			weight_meter: &mut WeightMeter,
+		) {
+			let batch = T::ReattestationBatchSize::get();
+			let max_examined = batch.saturating_mul(2);
+			if !weight_meter.can_consume(T::WeightInfo::draw_for_reattestation(max_examined)) {
+				log::warn!(
+					target: LOG_TARGET,
+					"Not enough weight to draw people for re-attestation",
+				);
+				return;
+			}
+
+			let entropy = (b"poi/reattest", now).using_encoded(|s| T::Randomness::random(s).0);
+			let start = PersonalId::decode(&mut &entropy[..]).unwrap_or_default();
+			let deadline = now.saturating_add(T::ReattestationWindow::get());
+			let people = People::<T>::iter_keys_from(People::<T>::hashed_key_for(start))
+				.chain(People::<T>::iter_keys());
+
+			let mut examined = 0;
+			let mut drawn = 0;
+			for personal_id in people {
+				if examined == max_examined || drawn == batch {
+					break;
+				}
+				examined += 1;
+				if Reattestations::<T>::contains_key(personal_id) {
+					continue;
+				}
+				let status = Reattestation::Pending { deadline, judging: None, failed: 0 };
+				Reattestations::<T>::insert(personal_id, status);
+				drawn += 1;
+				Self::deposit_event(Event::DrawnForReattestation { personal_id, deadline });
+			}
+			weight_meter.consume(T::WeightInfo::draw_for_reattestation(examined));
+		}
 	}
 
 	/// Ensure that the origin `o` represents a signed origin or a `ReferredCandidate` origin.
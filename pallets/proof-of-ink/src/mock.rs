@@ -37,7 +37,7 @@ use sp_runtime::{
 		TransactionExtension as TransactionExtensionTrait, ValidateResult,
 	},
 	transaction_validity::{InvalidTransaction, TransactionSource},
-	BuildStorage, DispatchError, TransactionOutcome, Weight,
+	BuildStorage, DispatchError, Percent, TransactionOutcome, Weight,
 };
 
 pub const DENIED_PAYMENT_ACCOUNT: u64 = 111;
@@ -118,7 +118,7 @@ use verifiable::mock::Mock;
 #[frame_support::pallet]
 pub mod mock_people {
 	use frame_support::pallet_prelude::*;
-	use indiv_support::traits::{AddOnlyPeopleTrait, PersonalId};
+	use indiv_support::traits::{AddOnlyPeopleTrait, PeopleTrait, PersonalId};
 	use sp_runtime::Saturating;
 
 	#[pallet::config]
@@ -136,6 +136,12 @@ pub mod mock_people {
 	#[pallet::storage]
 	pub type MockRecognized<T: Config> = StorageMap<_, Twox64Concat, PersonalId, (), OptionQuery>;
 
+	#[pallet::storage]
+	pub type MockSuspended<T: Config> = StorageMap<_, Twox64Concat, PersonalId, (), OptionQuery>;
+
+	#[pallet::storage]
+	pub type MockMutationSessionBlocked<T> = StorageValue<_, bool, ValueQuery>;
+
 	impl<T: Config> AddOnlyPeopleTrait for Pallet<T> {
 		type Member = [u8; 32];
 		fn reserve_new_id() -> PersonalId {
@@ -153,10 +159,15 @@ pub mod mock_people {
 		) -> Result<(), DispatchError> {
 			// people pallets usually allow recognized people to be recognized again.
 			// For simplicity this implementation doesn't, but we can modify when we need it.
+			if maybe_key.is_none() {
+				// Resuming a suspended personhood.
+				MockSuspended::<T>::take(who).ok_or(DispatchError::Other("Id not suspended"))?;
+				MockRecognized::<T>::insert(who, ());
+				return Ok(());
+			}
 			MockReserved::<T>::take(who).expect("We always reserve before recognizing.");
 			assert!(!MockRecognized::<T>::contains_key(who), "Id already recognized");
 			MockRecognized::<T>::insert(who, ());
-			maybe_key.expect("We always recognize with key in the context of POI");
 			Ok(())
 		}
 		fn cancel_id_reservation(personal_id: PersonalId) -> Result<(), DispatchError> {
@@ -184,9 +195,34 @@ pub mod mock_people {
 		#[cfg(feature = "runtime-benchmarks")]
 		fn initialize_people_collection() {}
 	}
+
+	impl<T: Config> PeopleTrait for Pallet<T> {
+		fn suspend_personhood(suspensions: &[PersonalId]) -> DispatchResult {
+			for who in suspensions {
+				MockRecognized::<T>::take(who).ok_or(DispatchError::Other("Id not recognized"))?;
+				MockSuspended::<T>::insert(who, ());
+			}
+			Ok(())
+		}
+		fn can_start_people_set_mutation_session() -> bool {
+			!MockMutationSessionBlocked::<T>::get()
+		}
+		fn start_people_set_mutation_session() -> DispatchResult {
+			ensure!(
+				!MockMutationSessionBlocked::<T>::get(),
+				DispatchError::Other("Mutation session blocked")
+			);
+			Ok(())
+		}
+		fn end_people_set_mutation_session() -> DispatchResult {
+			Ok(())
+		}
+	}
 }
 use crate::extension::AsProofOfInkParticipantInfo;
-pub use mock_people::{MockNextId, MockRecognized, MockReserved};
+pub use mock_people::{
+	MockMutationSessionBlocked, MockNextId, MockRecognized, MockReserved, MockSuspended,
+};
 
 // Configure a mock runtime to test the pallet.
 frame_support::construct_runtime!(
@@ -235,6 +271,8 @@ morph_types! {
 parameter_types! {
 	pub const PoiPotId: PalletId = PalletId(*b"poi/pot ");
 	pub const ExistentialDeposit: u64 = 1;
+	pub static ReattestationPeriod: BlockNumber = 0;
+	pub const ArtistRoyalty: Percent = Percent::from_percent(10);
 }
 
 pub type MaxActiveReferrals = ConstU32<10>;
@@ -267,6 +305,7 @@ impl crate::Config for Test {
 	type Oracle = MockOracle;
 	type Deposit = ();
 	type People = MockPeople;
+	type PeopleSuspension = MockPeople;
 	type Randomness = TestRandomness<Self>;
 	type EnsurePerson = TryMapSuccess<EnsureSigned<u64>, Under10>;
 	type TicketSignature = TestSignature;
@@ -276,8 +315,13 @@ impl crate::Config for Test {
 	type MaxActiveReferrals = MaxActiveReferrals;
 	type MaxRetryAttempts = ConstU32<1>;
 	type MaxReimbursementValues = ConstU32<10>;
+	type ReattestationPeriod = ReattestationPeriod;
+	type ReattestationBatchSize = ConstU32<2>;
+	type ReattestationWindow = ConstU64<10>;
+	type ReattestationGracePeriod = ConstU64<5>;
 	type Currency = Balances;
 	type PotId = PoiPotId;
+	type ArtistRoyalty = ArtistRoyalty;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = Test;
 	type InvitationsOrigin = EnsureRoot<Self::AccountId>;
@@ -2840,3 +2840,410 @@ fn remove_available_and_pending_invites_emits_event() {
 		assert!(PendingInvites::<Test>::get(INVITER, INVITATION_TICKET_2).is_none());
 	});
 }
+
+mod reattestation {
+	use super::*;
+	use frame_support::{
+		dispatch::DispatchResultWithPostInfo,
+		traits::Hooks,
+		weights::{Weight, WeightMeter},
+	};
+
+	const PERSON: u64 = 1;
+	const CALLER: u64 = 7;
+
+	fn mock_drawn_person(deadline: BlockNumber) {
+		assert_ok!(mock_person(PERSON, Some(InkSpec::ProceduralPersonal(3, PERSON))));
+		let status = Reattestation::Pending { deadline, judging: None, failed: 0 };
+		Reattestations::<Test>::insert(PERSON, status);
+	}
+
+	fn judge(judgement: Judgement) -> DispatchResultWithPostInfo {
+		let ticket: OracleTicketOf<Test> = Default::default();
+		let context = JudgementContext::truncate_from(PERSON.encode());
+		PoI::reattestation_judged(RuntimeOrigin::root(), ticket, context, judgement)
+	}
+
+	#[test]
+	fn people_are_drawn_every_period() {
+		TestExt::new().execute_with(|| {
+			for id in 0..4 {
+				assert_ok!(mock_person(id, Some(InkSpec::ProceduralPersonal(3, id))));
+			}
+
+			// Re-attestation is disabled by default.
+			PoI::on_poll(5, &mut WeightMeter::new());
+			assert_eq!(Reattestations::<Test>::iter().count(), 0);
+
+			ReattestationPeriod::set(5);
+			PoI::on_poll(4, &mut WeightMeter::new());
+			assert_eq!(Reattestations::<Test>::iter().count(), 0);
+
+			// At most `ReattestationBatchSize` people are drawn each period.
+			advance_to(5);
+			PoI::on_poll(5, &mut WeightMeter::new());
+			assert_eq!(Reattestations::<Test>::iter().count(), 2);
+			let drawn = Reattestation::Pending { deadline: 15, judging: None, failed: 0 };
+			assert!(Reattestations::<Test>::iter_values().all(|status| status == drawn));
+			assert_eq!(
+				System::events()
+					.iter()
+					.filter(|record| matches!(
+						record.event,
+						RuntimeEvent::PoI(Event::DrawnForReattestation { deadline: 15, .. })
+					))
+					.count(),
+				2
+			);
+
+			// People already drawn are not drawn again.
+			advance_to(10);
+			PoI::on_poll(10, &mut WeightMeter::new());
+			assert_eq!(Reattestations::<Test>::iter().count(), 4);
+			PoI::on_poll(15, &mut WeightMeter::new());
+			assert_eq!(Reattestations::<Test>::iter().count(), 4);
+
+			// Nothing is drawn without enough weight.
+			let _ = Reattestations::<Test>::clear(u32::MAX, None);
+			PoI::on_poll(20, &mut WeightMeter::with_limit(Weight::zero()));
+			assert_eq!(Reattestations::<Test>::iter().count(), 0);
+		});
+	}
+
+	#[test]
+	fn successful_reattestation_works() {
+		TestExt::new().execute_with(|| {
+			advance_to(1);
+			mock_drawn_person(10);
+			assert_ok!(mock_person(2, None));
+
+			assert_noop!(
+				PoI::reattest(RuntimeOrigin::signed(3), mock_evidence()),
+				Error::<Test>::NotPoiPerson
+			);
+			assert_noop!(
+				PoI::reattest(RuntimeOrigin::signed(2), mock_evidence()),
+				Error::<Test>::NotDrawn
+			);
+
+			let result = PoI::reattest(RuntimeOrigin::signed(PERSON), mock_evidence());
+			assert_eq!(result.unwrap().pays_fee, Pays::No);
+			System::assert_last_event(Event::ReattestationRequested { personal_id: PERSON }.into());
+			assert_noop!(
+				PoI::reattest(RuntimeOrigin::signed(PERSON), mock_evidence()),
+				Error::<Test>::AlreadyStarted
+			);
+
+			assert_noop!(
+				PoI::reattestation_judged(
+					RuntimeOrigin::root(),
+					[1; 32],
+					JudgementContext::truncate_from(PERSON.encode()),
+					Judgement::Truth(Truth::True)
+				),
+				Error::<Test>::UnexpectedJudgement
+			);
+			assert_ok!(judge(Judgement::Truth(Truth::True)));
+			assert!(!Reattestations::<Test>::contains_key(PERSON));
+			assert!(MockRecognized::<Test>::contains_key(PERSON));
+			System::assert_last_event(Event::Reattested { personal_id: PERSON }.into());
+		});
+	}
+
+	#[test]
+	fn failed_reattestation_leads_to_suspension_after_grace_period() {
+		TestExt::new().execute_with(|| {
+			advance_to(1);
+			mock_drawn_person(10);
+			advance_to(3);
+			assert_ok!(PoI::reattest(RuntimeOrigin::signed(PERSON), mock_evidence()));
+
+			// A failed judgement brings the deadline forward.
+			let judgement = Judgement::Truth(Truth::False);
+			assert_ok!(judge(judgement));
+			assert_eq!(
+				Reattestations::<Test>::get(PERSON),
+				Some(Reattestation::Pending { deadline: 3, judging: None, failed: 1 })
+			);
+			System::assert_last_event(
+				Event::ReattestationFailed { personal_id: PERSON, judgement, failures: 1 }.into(),
+			);
+
+			advance_to(8);
+			assert_noop!(
+				PoI::suspend_lapsed(RuntimeOrigin::signed(CALLER), PERSON),
+				Error::<Test>::TooEarly
+			);
+
+			advance_to(9);
+			MockMutationSessionBlocked::<Test>::put(true);
+			assert_noop!(
+				PoI::suspend_lapsed(RuntimeOrigin::signed(CALLER), PERSON),
+				Error::<Test>::SuspensionUnavailable
+			);
+			MockMutationSessionBlocked::<Test>::put(false);
+
+			let result = PoI::suspend_lapsed(RuntimeOrigin::signed(CALLER), PERSON);
+			assert_eq!(result.unwrap().pays_fee, Pays::No);
+			assert!(MockSuspended::<Test>::contains_key(PERSON));
+			assert!(!MockRecognized::<Test>::contains_key(PERSON));
+			assert_eq!(
+				Reattestations::<Test>::get(PERSON),
+				Some(Reattestation::Suspended { judging: None, failed: 1 })
+			);
+			System::assert_last_event(Event::PersonSuspended { personal_id: PERSON }.into());
+
+			// Suspended people are not suspended again.
+			assert_noop!(
+				PoI::suspend_lapsed(RuntimeOrigin::signed(CALLER), PERSON),
+				Error::<Test>::NotDrawn
+			);
+		});
+	}
+
+	#[test]
+	fn suspended_person_is_resumed_by_reattesting() {
+		TestExt::new().execute_with(|| {
+			advance_to(1);
+			mock_drawn_person(10);
+			advance_to(16);
+			assert_ok!(PoI::suspend_lapsed(RuntimeOrigin::signed(CALLER), PERSON));
+			assert!(MockSuspended::<Test>::contains_key(PERSON));
+
+			// The first attempt after skipping re-attestation is free.
+			let result = PoI::reattest(RuntimeOrigin::signed(PERSON), mock_evidence());
+			assert_eq!(result.unwrap().pays_fee, Pays::No);
+			assert_noop!(
+				PoI::reattest(RuntimeOrigin::signed(PERSON), mock_evidence()),
+				Error::<Test>::AlreadyStarted
+			);
+
+			// A rejected evidence leaves the person suspended, and later attempts are paid.
+			let judgement = Judgement::Truth(Truth::False);
+			assert_ok!(judge(judgement));
+			assert_eq!(
+				Reattestations::<Test>::get(PERSON),
+				Some(Reattestation::Suspended { judging: None, failed: 1 })
+			);
+			System::assert_last_event(
+				Event::ReattestationFailed { personal_id: PERSON, judgement, failures: 1 }.into(),
+			);
+			let result = PoI::reattest(RuntimeOrigin::signed(PERSON), mock_evidence());
+			assert_eq!(result.unwrap().pays_fee, Pays::Yes);
+
+			// An accepted evidence resumes the personhood.
+			assert_ok!(judge(Judgement::Truth(Truth::True)));
+			assert!(!Reattestations::<Test>::contains_key(PERSON));
+			assert!(!MockSuspended::<Test>::contains_key(PERSON));
+			assert!(MockRecognized::<Test>::contains_key(PERSON));
+			System::assert_has_event(Event::PersonResumed { personal_id: PERSON }.into());
+			System::assert_last_event(Event::Reattested { personal_id: PERSON }.into());
+		});
+	}
+
+	#[test]
+	fn suspended_person_stays_suspended_if_personhood_cannot_be_resumed() {
+		TestExt::new().execute_with(|| {
+			advance_to(1);
+			mock_drawn_person(10);
+			advance_to(16);
+			assert_ok!(PoI::suspend_lapsed(RuntimeOrigin::signed(CALLER), PERSON));
+			assert_ok!(PoI::reattest(RuntimeOrigin::signed(PERSON), mock_evidence()));
+
+			// The people pallet refuses to resume the personhood.
+			MockSuspended::<Test>::remove(PERSON);
+			assert_ok!(judge(Judgement::Truth(Truth::True)));
+
+			// New evidence can be submitted.
+			assert_eq!(
+				Reattestations::<Test>::get(PERSON),
+				Some(Reattestation::Suspended { judging: None, failed: 0 })
+			);
+			assert_ok!(PoI::reattest(RuntimeOrigin::signed(PERSON), mock_evidence()));
+		});
+	}
+
+	#[test]
+	fn skipped_reattestation_leads_to_suspension_after_grace_period() {
+		TestExt::new().execute_with(|| {
+			advance_to(1);
+			mock_drawn_person(10);
+
+			advance_to(15);
+			assert_noop!(
+				PoI::suspend_lapsed(RuntimeOrigin::signed(CALLER), PERSON),
+				Error::<Test>::TooEarly
+			);
+
+			// Evidence can still be submitted during the grace period, and the person cannot be
+			// suspended while it is being judged.
+			assert_ok!(PoI::reattest(RuntimeOrigin::signed(PERSON), mock_evidence()));
+			advance_to(16);
+			assert_noop!(
+				PoI::suspend_lapsed(RuntimeOrigin::signed(CALLER), PERSON),
+				Error::<Test>::AlreadyStarted
+			);
+
+			assert_ok!(judge(Judgement::Contempt));
+			assert_ok!(PoI::suspend_lapsed(RuntimeOrigin::signed(CALLER), PERSON));
+			assert!(MockSuspended::<Test>::contains_key(PERSON));
+		});
+	}
+}
+
+mod design_family_submission {
+	use super::*;
+
+	const ARTIST: AccountId = 42;
+	const INDEX: FamilyIndex = 4;
+	const ID: FamilyId = [7u8; 32];
+
+	fn propose() -> DispatchResult {
+		PoI::propose_design_family(
+			RuntimeOrigin::signed(ARTIST),
+			FamilyKind::Designed { count: 100 },
+			ID,
+		)
+	}
+
+	#[test]
+	fn proposals_are_vetted() {
+		TestExt::new().execute_with(|| {
+			advance_by(1);
+			assert_ok!(mock_designs());
+			assert_noop!(
+				PoI::approve_design_family(RuntimeOrigin::root(), 0, INDEX),
+				Error::<Test>::NoProposal
+			);
+
+			// Proposals are numbered in order, whatever family they offer.
+			assert_ok!(propose());
+			System::assert_last_event(
+				Event::DesignFamilyProposed { proposal: 0, artist: ARTIST }.into(),
+			);
+			assert_ok!(propose());
+			System::assert_last_event(
+				Event::DesignFamilyProposed { proposal: 1, artist: ARTIST }.into(),
+			);
+
+			// Rejection removes the proposal.
+			assert_noop!(
+				PoI::reject_design_family(RuntimeOrigin::signed(ARTIST), 0, false),
+				BadOrigin
+			);
+			assert_ok!(PoI::reject_design_family(RuntimeOrigin::root(), 0, false));
+			System::assert_last_event(
+				Event::DesignFamilyRejected { proposal: 0, artist: ARTIST }.into(),
+			);
+			assert!(!DesignFamilyProposals::<Test>::contains_key(0));
+			assert_noop!(
+				PoI::approve_design_family(RuntimeOrigin::root(), 0, INDEX),
+				Error::<Test>::NoProposal
+			);
+
+			// Approval picks the index of the family, which must be free.
+			assert_noop!(
+				PoI::approve_design_family(RuntimeOrigin::signed(ARTIST), 1, INDEX),
+				BadOrigin
+			);
+			assert_noop!(
+				PoI::approve_design_family(RuntimeOrigin::root(), 1, 0),
+				Error::<Test>::FamilyExists
+			);
+			assert_ok!(PoI::approve_design_family(RuntimeOrigin::root(), 1, INDEX));
+			System::assert_last_event(
+				Event::DesignFamilyApproved { proposal: 1, index: INDEX, artist: ARTIST }.into(),
+			);
+			assert_eq!(
+				DesignFamilies::<Test>::get(INDEX),
+				Some(Family { kind: FamilyKind::Designed { count: 100 }, id: ID })
+			);
+			assert_eq!(FamilyArtists::<Test>::get(INDEX), Some(ARTIST));
+			assert!(!DesignFamilyProposals::<Test>::contains_key(1));
+
+			// Overwriting the family drops its artist.
+			assert_ok!(PoI::add_design_family(
+				RuntimeOrigin::root(),
+				INDEX,
+				FamilyKind::Designed { count: 100 },
+				ID,
+			));
+			assert_eq!(FamilyArtists::<Test>::get(INDEX), None);
+
+			// Spam proposals are rejected the same way.
+			assert_ok!(propose());
+			assert_ok!(PoI::reject_design_family(RuntimeOrigin::root(), 2, true));
+			assert!(!DesignFamilyProposals::<Test>::contains_key(2));
+		});
+	}
+
+	#[test]
+	fn artists_are_credited_royalties() {
+		TestExt::new().execute_with(|| {
+			const CANDIDATE: AccountId = 6;
+			const REFERRER: AccountId = 0;
+			const DESTINATION: AccountId = 50;
+			let (pk, sk) = mock_key(1234);
+			let proof = {
+				let mut m = b"pop register using".to_vec();
+				m.extend_from_slice(&CANDIDATE.encode()[..]);
+				Mock::sign(&sk, &m[..]).unwrap()
+			};
+
+			advance_by(1);
+			assert_ok!(mock_designs());
+			assert_ok!(propose());
+			assert_ok!(PoI::approve_design_family(RuntimeOrigin::root(), 0, INDEX));
+			assert_ok!(mock_person(REFERRER, Some(InkSpec::DesignedElective(0, 0))));
+			assert_ok!(mock_candidate(
+				CANDIDATE,
+				Some(REFERRER),
+				Some((InkChoice::DesignedElective(INDEX, 3), Allocation::Full)),
+				None,
+				true
+			));
+			assert_ok!(append_reimbursement_values(50, 20, 1));
+			advance_by(1);
+
+			assert_noop!(
+				PoI::claim_royalties(RuntimeOrigin::signed(ARTIST)),
+				Error::<Test>::NoRoyalties
+			);
+			assert_ok!(PoI::register_referred(
+				RuntimeOrigin::signed(CANDIDATE),
+				pk,
+				DESTINATION,
+				proof
+			));
+			// The royalty is withheld from the reimbursement.
+			assert_reward_value(DESTINATION, 45);
+			System::assert_has_event(
+				Event::RoyaltyCredited { artist: ARTIST, family: INDEX, amount: 5 }.into(),
+			);
+			assert_eq!(ArtistRoyalties::<Test>::get(ARTIST), 5);
+
+			assert_ok!(PoI::claim_royalties(RuntimeOrigin::signed(ARTIST)));
+			System::assert_last_event(Event::RoyaltiesClaimed { artist: ARTIST, amount: 5 }.into());
+			assert_reward_value(ARTIST, 5);
+			assert_noop!(
+				PoI::claim_royalties(RuntimeOrigin::signed(ARTIST)),
+				Error::<Test>::NoRoyalties
+			);
+		});
+	}
+
+	#[test]
+	fn procedural_designs_earn_no_royalties() {
+		TestExt::new().execute_with(|| {
+			FamilyArtists::<Test>::insert(INDEX, ARTIST);
+
+			PoI::credit_artist_royalty(&InkSpec::Procedural(INDEX, [0; 4]), 50);
+			PoI::credit_artist_royalty(&InkSpec::ProceduralAccount(INDEX, [0; 32]), 50);
+			PoI::credit_artist_royalty(&InkSpec::ProceduralPersonal(INDEX, 1), 50);
+			assert_eq!(ArtistRoyalties::<Test>::get(ARTIST), 0);
+
+			PoI::credit_artist_royalty(&InkSpec::DesignedElective(INDEX, 3), 50);
+			assert_eq!(ArtistRoyalties::<Test>::get(ARTIST), 5);
+		});
+	}
+}
@@ -78,6 +78,14 @@ pub trait WeightInfo {
 	fn as_apply_with_sig_tx_ext() -> Weight;
 	fn as_referred_tx_ext() -> Weight;
 	fn set_reimbursement_values(c: u32, ) -> Weight;
+	fn reattest() -> Weight;
+	fn reattestation_judged() -> Weight;
+	fn suspend_lapsed() -> Weight;
+	fn draw_for_reattestation(n: u32, ) -> Weight;
+	fn propose_design_family() -> Weight;
+	fn approve_design_family() -> Weight;
+	fn reject_design_family() -> Weight;
+	fn claim_royalties() -> Weight;
 }
 
 /// Weights for `indiv_pallet_proof_of_ink` using the Substrate node and recommended hardware.
@@ -173,14 +181,18 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `ProofOfInk::People` (`max_values`: None, `max_size`: Some(398), added: 2873, mode: `MaxEncodedLen`)
 	/// Storage: `People::People` (r:0 w:1)
 	/// Proof: `People::People` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `ProofOfInk::FamilyArtists` (r:1 w:0)
+	/// Proof: `ProofOfInk::FamilyArtists` (`max_values`: None, `max_size`: Some(50), added: 2525, mode: `MaxEncodedLen`)
+	/// Storage: `ProofOfInk::ArtistRoyalties` (r:1 w:1)
+	/// Proof: `ProofOfInk::ArtistRoyalties` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
 	fn register_referred() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `9931`
 		//  Estimated: `11671`
 		// Minimum execution time: 4_838_649_000 picoseconds.
 		Weight::from_parts(4_876_634_000, 11671)
-			.saturating_add(T::DbWeight::get().reads(17_u64))
-			.saturating_add(T::DbWeight::get().writes(17_u64))
+			.saturating_add(T::DbWeight::get().reads(19_u64))
+			.saturating_add(T::DbWeight::get().writes(18_u64))
 	}
 	/// Storage: `ProofOfInk::Candidates` (r:1 w:1)
 	/// Proof: `ProofOfInk::Candidates` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
@@ -218,14 +230,18 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `ProofOfInk::People` (`max_values`: None, `max_size`: Some(398), added: 2873, mode: `MaxEncodedLen`)
 	/// Storage: `People::People` (r:0 w:1)
 	/// Proof: `People::People` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `ProofOfInk::FamilyArtists` (r:1 w:0)
+	/// Proof: `ProofOfInk::FamilyArtists` (`max_values`: None, `max_size`: Some(50), added: 2525, mode: `MaxEncodedLen`)
+	/// Storage: `ProofOfInk::ArtistRoyalties` (r:1 w:1)
+	/// Proof: `ProofOfInk::ArtistRoyalties` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
 	fn register_non_referred() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `9909`
 		//  Estimated: `11671`
 		// Minimum execution time: 4_895_109_000 picoseconds.
 		Weight::from_parts(4_923_679_000, 11671)
-			.saturating_add(T::DbWeight::get().reads(18_u64))
-			.saturating_add(T::DbWeight::get().writes(18_u64))
+			.saturating_add(T::DbWeight::get().reads(20_u64))
+			.saturating_add(T::DbWeight::get().writes(19_u64))
 	}
 	/// Storage: `ProofOfInk::Candidates` (r:1 w:1)
 	/// Proof: `ProofOfInk::Candidates` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
@@ -542,6 +558,151 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(20_780, 0).saturating_mul(c.into()))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: `ProofOfInk::People` (r:1 w:0)
+	/// Proof: `ProofOfInk::People` (`max_values`: None, `max_size`: Some(398), added: 2873, mode: `MaxEncodedLen`)
+	/// Storage: `ProofOfInk::Reattestations` (r:1 w:1)
+	/// Proof: `ProofOfInk::Reattestations` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::CaseCount` (r:1 w:1)
+	/// Proof: `MobRule::CaseCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::OpenCases` (r:0 w:1)
+	/// Proof: `MobRule::OpenCases` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
+	fn reattest() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1562`
+		//  Estimated: `3863`
+		// Minimum execution time: 68_417_000 picoseconds.
+		Weight::from_parts(71_203_000, 3863)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ProofOfInk::Reattestations` (r:1 w:1)
+	/// Proof: `ProofOfInk::Reattestations` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `People::People` (r:1 w:0)
+	/// Proof: `People::People` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `People::Keys` (r:1 w:0)
+	/// Proof: `People::Keys` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `Members::Collections` (r:1 w:0)
+	/// Proof: `Members::Collections` (`max_values`: None, `max_size`: Some(646), added: 3121, mode: `MaxEncodedLen`)
+	/// Storage: `Members::Members` (r:1 w:1)
+	/// Proof: `Members::Members` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `Members::QueuePageIndices` (r:1 w:1)
+	/// Proof: `Members::QueuePageIndices` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Members::OnboardingQueue` (r:1 w:1)
+	/// Proof: `Members::OnboardingQueue` (`max_values`: None, `max_size`: Some(8206), added: 10681, mode: `MaxEncodedLen`)
+	fn reattestation_judged() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1204`
+		//  Estimated: `11671`
+		// Minimum execution time: 44_318_000 picoseconds.
+		Weight::from_parts(46_052_000, 11671)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `ProofOfInk::Reattestations` (r:1 w:1)
+	/// Proof: `ProofOfInk::Reattestations` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `Members::Collections` (r:1 w:0)
+	/// Proof: `Members::Collections` (`max_values`: None, `max_size`: Some(646), added: 3121, mode: `MaxEncodedLen`)
+	/// Storage: `Members::RingsState` (r:1 w:1)
+	/// Proof: `Members::RingsState` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	/// Storage: `People::People` (r:1 w:1)
+	/// Proof: `People::People` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `Members::Members` (r:1 w:1)
+	/// Proof: `Members::Members` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `Members::PendingSuspensions` (r:1 w:1)
+	/// Proof: `Members::PendingSuspensions` (`max_values`: None, `max_size`: Some(1066), added: 3541, mode: `MaxEncodedLen`)
+	/// Storage: `People::AccountToPersonalId` (r:0 w:1)
+	/// Proof: `People::AccountToPersonalId` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn suspend_lapsed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1893`
+		//  Estimated: `4531`
+		// Minimum execution time: 61_245_000 picoseconds.
+		Weight::from_parts(63_918_000, 4531)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `System::BlockHash` (r:1 w:0)
+	/// Proof: `System::BlockHash` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `ProofOfInk::People` (r:20 w:0)
+	/// Proof: `ProofOfInk::People` (`max_values`: None, `max_size`: Some(398), added: 2873, mode: `MaxEncodedLen`)
+	/// Storage: `ProofOfInk::Reattestations` (r:20 w:20)
+	/// Proof: `ProofOfInk::Reattestations` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 20]`.
+	fn draw_for_reattestation(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `212 + n * (419 ±0)`
+		//  Estimated: `3509 + n * (2873 ±0)`
+		// Minimum execution time: 9_184_000 picoseconds.
+		Weight::from_parts(10_027_413, 3509)
+			// Standard Error: 4_902
+			.saturating_add(Weight::from_parts(9_316_772, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2873).saturating_mul(n.into()))
+	}
+	/// Storage: `ProofOfInk::NextDesignFamilyProposal` (r:1 w:1)
+	/// Proof: `ProofOfInk::NextDesignFamilyProposal` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	/// Storage: `ProofOfInk::DesignFamilyProposals` (r:0 w:1)
+	/// Proof: `ProofOfInk::DesignFamilyProposals` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
+	fn propose_design_family() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `3694`
+		// Minimum execution time: 52_381_000 picoseconds.
+		Weight::from_parts(54_107_000, 3694)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ProofOfInk::DesignFamilyProposals` (r:1 w:1)
+	/// Proof: `ProofOfInk::DesignFamilyProposals` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
+	/// Storage: `ProofOfInk::DesignFamilies` (r:1 w:1)
+	/// Proof: `ProofOfInk::DesignFamilies` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	/// Storage: `ProofOfInk::FamilyArtists` (r:0 w:1)
+	/// Proof: `ProofOfInk::FamilyArtists` (`max_values`: None, `max_size`: Some(50), added: 2525, mode: `MaxEncodedLen`)
+	fn approve_design_family() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `323`
+		//  Estimated: `3694`
+		// Minimum execution time: 49_826_000 picoseconds.
+		Weight::from_parts(51_394_000, 3694)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `ProofOfInk::DesignFamilyProposals` (r:1 w:1)
+	/// Proof: `ProofOfInk::DesignFamilyProposals` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn reject_design_family() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `323`
+		//  Estimated: `3694`
+		// Minimum execution time: 56_719_000 picoseconds.
+		Weight::from_parts(58_245_000, 3694)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ProofOfInk::ArtistRoyalties` (r:1 w:1)
+	/// Proof: `ProofOfInk::ArtistRoyalties` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn claim_royalties() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `246`
+		//  Estimated: `6196`
+		// Minimum execution time: 61_503_000 picoseconds.
+		Weight::from_parts(63_092_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -636,14 +797,18 @@ impl WeightInfo for () {
 	/// Proof: `ProofOfInk::People` (`max_values`: None, `max_size`: Some(398), added: 2873, mode: `MaxEncodedLen`)
 	/// Storage: `People::People` (r:0 w:1)
 	/// Proof: `People::People` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `ProofOfInk::FamilyArtists` (r:1 w:0)
+	/// Proof: `ProofOfInk::FamilyArtists` (`max_values`: None, `max_size`: Some(50), added: 2525, mode: `MaxEncodedLen`)
+	/// Storage: `ProofOfInk::ArtistRoyalties` (r:1 w:1)
+	/// Proof: `ProofOfInk::ArtistRoyalties` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
 	fn register_referred() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `9931`
 		//  Estimated: `11671`
 		// Minimum execution time: 4_838_649_000 picoseconds.
 		Weight::from_parts(4_876_634_000, 11671)
-			.saturating_add(RocksDbWeight::get().reads(17_u64))
-			.saturating_add(RocksDbWeight::get().writes(17_u64))
+			.saturating_add(RocksDbWeight::get().reads(19_u64))
+			.saturating_add(RocksDbWeight::get().writes(18_u64))
 	}
 	/// Storage: `ProofOfInk::Candidates` (r:1 w:1)
 	/// Proof: `ProofOfInk::Candidates` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
@@ -681,14 +846,18 @@ impl WeightInfo for () {
 	/// Proof: `ProofOfInk::People` (`max_values`: None, `max_size`: Some(398), added: 2873, mode: `MaxEncodedLen`)
 	/// Storage: `People::People` (r:0 w:1)
 	/// Proof: `People::People` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `ProofOfInk::FamilyArtists` (r:1 w:0)
+	/// Proof: `ProofOfInk::FamilyArtists` (`max_values`: None, `max_size`: Some(50), added: 2525, mode: `MaxEncodedLen`)
+	/// Storage: `ProofOfInk::ArtistRoyalties` (r:1 w:1)
+	/// Proof: `ProofOfInk::ArtistRoyalties` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
 	fn register_non_referred() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `9909`
 		//  Estimated: `11671`
 		// Minimum execution time: 4_895_109_000 picoseconds.
 		Weight::from_parts(4_923_679_000, 11671)
-			.saturating_add(RocksDbWeight::get().reads(18_u64))
-			.saturating_add(RocksDbWeight::get().writes(18_u64))
+			.saturating_add(RocksDbWeight::get().reads(20_u64))
+			.saturating_add(RocksDbWeight::get().writes(19_u64))
 	}
 	/// Storage: `ProofOfInk::Candidates` (r:1 w:1)
 	/// Proof: `ProofOfInk::Candidates` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
@@ -1005,4 +1174,149 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(20_780, 0).saturating_mul(c.into()))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Storage: `ProofOfInk::People` (r:1 w:0)
+	/// Proof: `ProofOfInk::People` (`max_values`: None, `max_size`: Some(398), added: 2873, mode: `MaxEncodedLen`)
+	/// Storage: `ProofOfInk::Reattestations` (r:1 w:1)
+	/// Proof: `ProofOfInk::Reattestations` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::CaseCount` (r:1 w:1)
+	/// Proof: `MobRule::CaseCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `MobRule::OpenCases` (r:0 w:1)
+	/// Proof: `MobRule::OpenCases` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
+	fn reattest() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1562`
+		//  Estimated: `3863`
+		// Minimum execution time: 68_417_000 picoseconds.
+		Weight::from_parts(71_203_000, 3863)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ProofOfInk::Reattestations` (r:1 w:1)
+	/// Proof: `ProofOfInk::Reattestations` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `People::People` (r:1 w:0)
+	/// Proof: `People::People` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `People::Keys` (r:1 w:0)
+	/// Proof: `People::Keys` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `Members::Collections` (r:1 w:0)
+	/// Proof: `Members::Collections` (`max_values`: None, `max_size`: Some(646), added: 3121, mode: `MaxEncodedLen`)
+	/// Storage: `Members::Members` (r:1 w:1)
+	/// Proof: `Members::Members` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `Members::QueuePageIndices` (r:1 w:1)
+	/// Proof: `Members::QueuePageIndices` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Members::OnboardingQueue` (r:1 w:1)
+	/// Proof: `Members::OnboardingQueue` (`max_values`: None, `max_size`: Some(8206), added: 10681, mode: `MaxEncodedLen`)
+	fn reattestation_judged() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1204`
+		//  Estimated: `11671`
+		// Minimum execution time: 44_318_000 picoseconds.
+		Weight::from_parts(46_052_000, 11671)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `ProofOfInk::Reattestations` (r:1 w:1)
+	/// Proof: `ProofOfInk::Reattestations` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `Members::Collections` (r:1 w:0)
+	/// Proof: `Members::Collections` (`max_values`: None, `max_size`: Some(646), added: 3121, mode: `MaxEncodedLen`)
+	/// Storage: `Members::RingsState` (r:1 w:1)
+	/// Proof: `Members::RingsState` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	/// Storage: `People::People` (r:1 w:1)
+	/// Proof: `People::People` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `Members::Members` (r:1 w:1)
+	/// Proof: `Members::Members` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `Members::PendingSuspensions` (r:1 w:1)
+	/// Proof: `Members::PendingSuspensions` (`max_values`: None, `max_size`: Some(1066), added: 3541, mode: `MaxEncodedLen`)
+	/// Storage: `People::AccountToPersonalId` (r:0 w:1)
+	/// Proof: `People::AccountToPersonalId` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn suspend_lapsed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1893`
+		//  Estimated: `4531`
+		// Minimum execution time: 61_245_000 picoseconds.
+		Weight::from_parts(63_918_000, 4531)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `System::BlockHash` (r:1 w:0)
+	/// Proof: `System::BlockHash` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `ProofOfInk::People` (r:20 w:0)
+	/// Proof: `ProofOfInk::People` (`max_values`: None, `max_size`: Some(398), added: 2873, mode: `MaxEncodedLen`)
+	/// Storage: `ProofOfInk::Reattestations` (r:20 w:20)
+	/// Proof: `ProofOfInk::Reattestations` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 20]`.
+	fn draw_for_reattestation(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `212 + n * (419 ±0)`
+		//  Estimated: `3509 + n * (2873 ±0)`
+		// Minimum execution time: 9_184_000 picoseconds.
+		Weight::from_parts(10_027_413, 3509)
+			// Standard Error: 4_902
+			.saturating_add(Weight::from_parts(9_316_772, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2873).saturating_mul(n.into()))
+	}
+	/// Storage: `ProofOfInk::NextDesignFamilyProposal` (r:1 w:1)
+	/// Proof: `ProofOfInk::NextDesignFamilyProposal` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	/// Storage: `ProofOfInk::DesignFamilyProposals` (r:0 w:1)
+	/// Proof: `ProofOfInk::DesignFamilyProposals` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
+	fn propose_design_family() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `3694`
+		// Minimum execution time: 52_381_000 picoseconds.
+		Weight::from_parts(54_107_000, 3694)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ProofOfInk::DesignFamilyProposals` (r:1 w:1)
+	/// Proof: `ProofOfInk::DesignFamilyProposals` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
+	/// Storage: `ProofOfInk::DesignFamilies` (r:1 w:1)
+	/// Proof: `ProofOfInk::DesignFamilies` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	/// Storage: `ProofOfInk::FamilyArtists` (r:0 w:1)
+	/// Proof: `ProofOfInk::FamilyArtists` (`max_values`: None, `max_size`: Some(50), added: 2525, mode: `MaxEncodedLen`)
+	fn approve_design_family() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `323`
+		//  Estimated: `3694`
+		// Minimum execution time: 49_826_000 picoseconds.
+		Weight::from_parts(51_394_000, 3694)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `ProofOfInk::DesignFamilyProposals` (r:1 w:1)
+	/// Proof: `ProofOfInk::DesignFamilyProposals` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn reject_design_family() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `323`
+		//  Estimated: `3694`
+		// Minimum execution time: 56_719_000 picoseconds.
+		Weight::from_parts(58_245_000, 3694)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ProofOfInk::ArtistRoyalties` (r:1 w:1)
+	/// Proof: `ProofOfInk::ArtistRoyalties` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn claim_royalties() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `246`
+		//  Estimated: `6196`
+		// Minimum execution time: 61_503_000 picoseconds.
+		Weight::from_parts(63_092_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
 }
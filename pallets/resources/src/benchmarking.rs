@@ -63,6 +63,36 @@ fn assert_last_event<T: Config>(generic_event: <T as frame_system::Config>::Runt
 	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
 }
 
+/// Insert a long-term storage authorization for `account` granted at time `100`, returning its
+/// expiry.
+fn insert_long_term_storage_authorization<T: Config>(
+	account: &T::AccountId,
+	status: LongTermStorageAuthorizationStatus,
+	pending_ack: Option<StorageAllocationTicket>,
+) -> u64 {
+	let expires_at = 100 + T::LongTermStorageAuthorizationPeriod::get() as u64;
+	if let Some(ticket) = pending_ack {
+		PendingLongTermStorageAcks::<T>::insert(ticket, account);
+	}
+	LongTermStorageAuthorizations::<T>::insert(
+		account,
+		LongTermStorageAuthorization {
+			transactions: 1,
+			bytes: 1,
+			expires_at,
+			refreshes: 0,
+			status,
+			pending_ack,
+			request: LongTermStorageRequest::Authorize(LongTermStorageAllocation {
+				transactions: 1,
+				bytes: 1,
+			}),
+			requested_at: 100,
+		},
+	);
+	expires_at
+}
+
 fn setup_people_ring_with_one_member<
 	T: Config + indiv_pallet_people::Config<MemberService = <T as Config>::MemberService>,
 >() -> Result<(SecretOf<T>, MemberOf<T>), BenchmarkError> {
@@ -1100,5 +1130,109 @@ mod benches {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn acknowledge_long_term_storage() -> Result<(), BenchmarkError> {
+		<T as Config>::BenchmarkHelper::set_time(Duration::from_secs(100));
+		let account: T::AccountId = whitelisted_caller();
+		let ticket = 0;
+		insert_long_term_storage_authorization::<T>(
+			&account,
+			LongTermStorageAuthorizationStatus::Pending,
+			Some(ticket),
+		);
+		let origin = T::LongTermStorageAckOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, ticket, Response::DispatchResult(MaybeErrorCode::Success));
+
+		assert_last_event::<T>(
+			Event::LongTermStorageAcknowledged { account, accepted: true }.into(),
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn refresh_long_term_storage_authorization() -> Result<(), BenchmarkError> {
+		let account: T::AccountId = whitelisted_caller();
+		// Worst case: an acknowledgement of a previous request is still outstanding.
+		let expires_at = insert_long_term_storage_authorization::<T>(
+			&account,
+			LongTermStorageAuthorizationStatus::Confirmed,
+			Some(0),
+		);
+		let refresh_at = expires_at - T::LongTermStorageRefreshWindow::get() as u64;
+		<T as Config>::BenchmarkHelper::set_time(Duration::from_secs(refresh_at));
+
+		#[extrinsic_call]
+		_(SystemOrigin::Authorized, account.clone());
+
+		let authorization = LongTermStorageAuthorizations::<T>::get(&account)
+			.ok_or(BenchmarkError::Stop("authorization must exist"))?;
+		assert_eq!(authorization.refreshes, 1);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn authorize_refresh_long_term_storage_authorization() -> Result<(), BenchmarkError> {
+		let account: T::AccountId = whitelisted_caller();
+		let expires_at = insert_long_term_storage_authorization::<T>(
+			&account,
+			LongTermStorageAuthorizationStatus::Confirmed,
+			None,
+		);
+		let refresh_at = expires_at - T::LongTermStorageRefreshWindow::get() as u64;
+		<T as Config>::BenchmarkHelper::set_time(Duration::from_secs(refresh_at));
+
+		let call = Call::<T>::refresh_long_term_storage_authorization { account };
+
+		#[block]
+		{
+			call.authorize(TransactionSource::InBlock)
+				.ok_or("Call must give some authorization")??;
+		}
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn clear_expired_long_term_storage_authorization() -> Result<(), BenchmarkError> {
+		let account: T::AccountId = whitelisted_caller();
+		let expires_at = insert_long_term_storage_authorization::<T>(
+			&account,
+			LongTermStorageAuthorizationStatus::Pending,
+			Some(0),
+		);
+		<T as Config>::BenchmarkHelper::set_time(Duration::from_secs(expires_at));
+
+		#[extrinsic_call]
+		_(SystemOrigin::Authorized, account.clone());
+
+		assert!(!LongTermStorageAuthorizations::<T>::contains_key(&account));
+		assert!(!PendingLongTermStorageAcks::<T>::contains_key(0));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn authorize_clear_expired_long_term_storage_authorization() -> Result<(), BenchmarkError> {
+		let account: T::AccountId = whitelisted_caller();
+		let expires_at = insert_long_term_storage_authorization::<T>(
+			&account,
+			LongTermStorageAuthorizationStatus::Confirmed,
+			None,
+		);
+		<T as Config>::BenchmarkHelper::set_time(Duration::from_secs(expires_at));
+
+		let call = Call::<T>::clear_expired_long_term_storage_authorization { account };
+
+		#[block]
+		{
+			call.authorize(TransactionSource::InBlock)
+				.ok_or("Call must give some authorization")??;
+		}
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
 }
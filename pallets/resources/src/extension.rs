@@ -74,6 +74,10 @@ pub enum CustomValidity {
 	LongTermStoragePeriodNotExpired = 233,
 	/// There are no long-term storage aliases to clear for the period.
 	NothingToClearForLongTermStoragePeriod = 234,
+	/// The long-term storage authorization is not due for a refresh.
+	InvalidLongTermStorageAuthorizationRefresh = 235,
+	/// The requested expired long-term storage authorization cleanup is not currently valid.
+	InvalidExpiredLongTermStorageCleanup = 236,
 }
 
 impl From<CustomValidity> for TransactionValidityError {
@@ -54,16 +54,19 @@ use indiv_support::{
 	labels::is_lite_person_label,
 	traits::{
 		Alias, AllocateStorage, AppendOnlyMembers, CommunicationIdentifier, ConsumerRegistrar,
-		Context, MembershipProver, RingExponent, Username,
+		Context, MembershipProver, RingExponent, StorageAllocationTicket, Username,
 	},
 	utils::BigEndianU32,
 };
 use sp_runtime::traits::{IdentifyAccount, Verify};
 use types::{
 	ConsumerInfo, Credibility, FriendRequestReference, LongTermStorageAllocation,
-	MembershipCollection, PersonalUsernameChoice, ReservationQueueEntryOf, StmtStoreAllowanceEntry,
+	LongTermStorageAuthorization, LongTermStorageAuthorizationStatus, LongTermStorageRequest,
+	MembershipCollection, PersonalUsernameChoice, ReservationQueueEntryOf,
+	StmtStoreAllowanceEntry,
 };
 use verifiable::GenerateVerifiable;
+use xcm::latest::{MaybeErrorCode, QueryId, Response};
 
 // TODO:
 // - Get rid of the "friend request" naming.
@@ -295,6 +298,35 @@ pub mod pallet {
 		#[pallet::constant]
 		type LongTermStorageCleanupLimit: Get<u32>;
 
+		/// How long, in seconds, a long-term storage authorization stays valid on the remote
+		/// chain after it was granted or refreshed.
+		///
+		/// Must match the authorization period of the remote chain, so that the local record
+		/// expires together with the remote authorization.
+		#[pallet::constant]
+		type LongTermStorageAuthorizationPeriod: Get<u32>;
+
+		/// Time, in seconds, before the expiry of a confirmed long-term storage authorization
+		/// during which the offchain worker refreshes it on the remote chain.
+		#[pallet::constant]
+		type LongTermStorageRefreshWindow: Get<u32>;
+
+		/// Maximum number of automatic refreshes of a long-term storage authorization between two
+		/// claims for the same account.
+		#[pallet::constant]
+		type LongTermStorageMaxRefreshes: Get<u8>;
+
+		/// Time, in seconds, after which a long-term storage request still waiting for its
+		/// acknowledgement is considered lost and sent again by the offchain worker.
+		///
+		/// Should not be shorter than the time the remote chain is given to respond.
+		#[pallet::constant]
+		type LongTermStorageAckTimeout: Get<u32>;
+
+		/// The origin which delivers acknowledgements of long-term storage requests from the
+		/// remote chain. Normally a response origin for the remote chain's location.
+		type LongTermStorageAckOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
 		/// Benchmark helper trait.
 		#[cfg(feature = "runtime-benchmarks")]
 		type BenchmarkHelper: benchmarking::BenchmarkHelper<Self>;
@@ -378,6 +410,22 @@ pub mod pallet {
 	pub type SpentLongTermStorageAliases<T: Config> =
 		StorageDoubleMap<_, Identity, BigEndianU32, Blake2_128Concat, Alias, (), OptionQuery>;
 
+	/// Long-term storage authorized on the remote chain, per account.
+	///
+	/// Claims for the same account accumulate into a single entry. Entries are refreshed by the
+	/// offchain worker while within `LongTermStorageMaxRefreshes`, and removed once expired.
+	#[pallet::storage]
+	pub type LongTermStorageAuthorizations<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, LongTermStorageAuthorization, OptionQuery>;
+
+	/// Outstanding acknowledgements of long-term storage requests, mapping the ticket handed out
+	/// by `LongTermStorageDataStore` to the account the request was made for.
+	///
+	/// At most one ticket is outstanding per account; a newer request supersedes the older one.
+	#[pallet::storage]
+	pub type PendingLongTermStorageAcks<T: Config> =
+		StorageMap<_, Twox64Concat, StorageAllocationTicket, T::AccountId, OptionQuery>;
+
 	/// Reverse lookup from `username` to the `AccountId` that has registered it. The `owner` value
 	/// should be a key in the `Consumers` map. There can be at most 2 usernames pointing to the
 	/// same `owner`:
@@ -459,6 +507,12 @@ pub mod pallet {
 		},
 		/// Expired long-term storage aliases have been cleared for a period.
 		LongTermStorageAliasesCleared { period: u32, count: u32 },
+		/// The remote chain acknowledged the latest long-term storage request for an account.
+		LongTermStorageAcknowledged { account: T::AccountId, accepted: bool },
+		/// A long-term storage authorization was refreshed on the remote chain.
+		LongTermStorageAuthorizationRefreshed { account: T::AccountId, expires_at: u64 },
+		/// An expired long-term storage authorization was removed.
+		LongTermStorageAuthorizationExpired { account: T::AccountId },
 	}
 
 	#[pallet::error]
@@ -511,6 +565,14 @@ pub mod pallet {
 		StmtStoreReplacementTooEarly,
 		/// The provided `limit` exceeds `LongTermStorageCleanupLimit`.
 		LongTermStorageCleanupLimitExceeded,
+		/// The acknowledgement does not match any outstanding long-term storage request.
+		UnknownLongTermStorageAck,
+		/// The account has no long-term storage authorization.
+		NoLongTermStorageAuthorization,
+		/// The long-term storage authorization cannot be refreshed right now.
+		LongTermStorageRefreshNotDue,
+		/// The long-term storage authorization has not expired yet.
+		LongTermStorageAuthorizationNotExpired,
 	}
 
 	#[pallet::hooks]
@@ -569,6 +631,24 @@ pub mod pallet {
 					);
 				}
 			}
+
+			// Refresh long-term storage authorizations nearing their expiry or whose latest
+			// request went unacknowledged, and drop the ones that have lapsed on the remote chain.
+			for (account, authorization) in LongTermStorageAuthorizations::<T>::iter() {
+				if Self::is_long_term_storage_authorization_expired(&authorization) {
+					let call = Call::clear_expired_long_term_storage_authorization { account };
+					Self::submit_authorized_transaction(
+						call,
+						"Clear expired long-term storage authorization",
+					);
+				} else if Self::is_long_term_storage_authorization_refreshable(&authorization) {
+					let call = Call::refresh_long_term_storage_authorization { account };
+					Self::submit_authorized_transaction(
+						call,
+						"Refresh long-term storage authorization",
+					);
+				}
+			}
 		}
 
 		fn integrity_test() {
@@ -634,6 +714,15 @@ pub mod pallet {
 				T::LongTermStorageCleanupLimit::get() > 0,
 				"LongTermStorageCleanupLimit must be non-zero",
 			);
+			assert!(
+				T::LongTermStorageAuthorizationPeriod::get() > 0,
+				"LongTermStorageAuthorizationPeriod must be non-zero",
+			);
+			assert!(
+				T::LongTermStorageRefreshWindow::get() <
+					T::LongTermStorageAuthorizationPeriod::get(),
+				"LongTermStorageRefreshWindow must be smaller than LongTermStorageAuthorizationPeriod",
+			);
 		}
 	}
 
@@ -1048,13 +1137,11 @@ pub mod pallet {
 
 			// The alias is consumed regardless of allocation success, so a failing remote chain
 			// cannot be used to spam this extrinsic with the same proof.
-			if T::LongTermStorageDataStore::allocate_storage(
+			let Ok(ticket) = T::LongTermStorageDataStore::allocate_storage_with_ack(
 				&account_id,
 				allocation.bytes,
 				allocation.transactions,
-			)
-			.is_err()
-			{
+			) else {
 				Self::deposit_event(Event::LongTermStorageAllocationFailed {
 					alias,
 					period,
@@ -1063,7 +1150,8 @@ pub mod pallet {
 					collection,
 				});
 				return Ok(Pays::No.into());
-			}
+			};
+			Self::note_long_term_storage_granted(&account_id, allocation, ticket);
 
 			Self::deposit_event(Event::LongTermStorageClaimed {
 				alias,
@@ -1110,6 +1198,127 @@ pub mod pallet {
 			Self::deposit_event(Event::LongTermStorageAliasesCleared { period, count });
 			Ok(Pays::No.into())
 		}
+
+		/// Record the remote chain's acknowledgement of a long-term storage request.
+		///
+		/// Dispatched by the XCM response handler with the `query_id` handed out by
+		/// `LongTermStorageDataStore` and the transact status reported by the remote chain.
+		/// Acknowledgements for superseded requests are rejected.
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as Config>::WeightInfo::acknowledge_long_term_storage())]
+		pub fn acknowledge_long_term_storage(
+			origin: OriginFor<T>,
+			query_id: QueryId,
+			response: Response,
+		) -> DispatchResultWithPostInfo {
+			T::LongTermStorageAckOrigin::ensure_origin(origin)?;
+			let account = PendingLongTermStorageAcks::<T>::take(query_id)
+				.ok_or(Error::<T>::UnknownLongTermStorageAck)?;
+			let accepted = matches!(response, Response::DispatchResult(MaybeErrorCode::Success));
+			LongTermStorageAuthorizations::<T>::mutate(&account, |maybe_authorization| {
+				if let Some(authorization) = maybe_authorization {
+					authorization.pending_ack = None;
+					authorization.status = if accepted {
+						LongTermStorageAuthorizationStatus::Confirmed
+					} else {
+						LongTermStorageAuthorizationStatus::Rejected
+					};
+				}
+			});
+			Self::deposit_event(Event::LongTermStorageAcknowledged { account, accepted });
+			Ok(Pays::No.into())
+		}
+
+		/// Refresh a confirmed long-term storage authorization on the remote chain before it
+		/// expires.
+		///
+		/// This is a permissionless call authorized via the `authorize` attribute and submitted
+		/// by the offchain worker once the authorization enters `LongTermStorageRefreshWindow`.
+		/// At most `LongTermStorageMaxRefreshes` refreshes happen between two claims.
+		///
+		/// Requests left unacknowledged for `LongTermStorageAckTimeout` are retried through this
+		/// call as well, superseding the lost acknowledgement. The original request is sent again,
+		/// so a lost grant is not turned into a refresh. Retries are not counted as refreshes.
+		///
+		/// Parameters:
+		/// * `account`: the account whose authorization is refreshed.
+		#[pallet::call_index(15)]
+		#[pallet::authorize(|source, account| {
+			Self::authorize_refresh_long_term_storage_authorization(source, account)
+		})]
+		#[pallet::weight_of_authorize(
+			<T as Config>::WeightInfo::authorize_refresh_long_term_storage_authorization()
+		)]
+		#[pallet::weight(<T as Config>::WeightInfo::refresh_long_term_storage_authorization())]
+		pub fn refresh_long_term_storage_authorization(
+			origin: OriginFor<T>,
+			account: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			ensure_authorized(origin)?;
+			let mut authorization =
+				Self::validate_refresh_long_term_storage_authorization(&account)?;
+			let retry = Self::is_long_term_storage_ack_timed_out(&authorization);
+			let request =
+				if retry { authorization.request } else { LongTermStorageRequest::Refresh };
+			let ticket = match request {
+				LongTermStorageRequest::Authorize(allocation) =>
+					T::LongTermStorageDataStore::allocate_storage_with_ack(
+						&account,
+						allocation.bytes,
+						allocation.transactions,
+					)?,
+				LongTermStorageRequest::Refresh =>
+					T::LongTermStorageDataStore::refresh_allocation_with_ack(&account)?,
+			};
+
+			let now = T::Clock::now().as_secs();
+			authorization.expires_at =
+				now.saturating_add(u64::from(T::LongTermStorageAuthorizationPeriod::get()));
+			if !retry {
+				authorization.refreshes = authorization.refreshes.saturating_add(1);
+			}
+			Self::track_long_term_storage_ack(&account, &mut authorization, request, ticket);
+			let expires_at = authorization.expires_at;
+			LongTermStorageAuthorizations::<T>::insert(&account, authorization);
+
+			Self::deposit_event(Event::LongTermStorageAuthorizationRefreshed {
+				account,
+				expires_at,
+			});
+			Ok(Pays::No.into())
+		}
+
+		/// Remove a long-term storage authorization which has lapsed on the remote chain.
+		///
+		/// This is a permissionless call authorized via the `authorize` attribute and submitted
+		/// by the offchain worker.
+		///
+		/// Parameters:
+		/// * `account`: the account whose authorization has expired.
+		#[pallet::call_index(16)]
+		#[pallet::authorize(|source, account| {
+			Self::authorize_clear_expired_long_term_storage_authorization(source, account)
+		})]
+		#[pallet::weight_of_authorize(
+			<T as Config>::WeightInfo::authorize_clear_expired_long_term_storage_authorization()
+		)]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::clear_expired_long_term_storage_authorization()
+		)]
+		pub fn clear_expired_long_term_storage_authorization(
+			origin: OriginFor<T>,
+			account: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			ensure_authorized(origin)?;
+			let authorization =
+				Self::validate_clear_expired_long_term_storage_authorization(&account)?;
+			if let Some(ticket) = authorization.pending_ack {
+				PendingLongTermStorageAcks::<T>::remove(ticket);
+			}
+			LongTermStorageAuthorizations::<T>::remove(&account);
+			Self::deposit_event(Event::LongTermStorageAuthorizationExpired { account });
+			Ok(Pays::No.into())
+		}
 	}
 
 	#[pallet::view_functions]
@@ -1263,6 +1472,38 @@ pub mod pallet {
 			.map(|valid_tx| (valid_tx, Weight::zero()))
 		}
 
+		fn authorize_refresh_long_term_storage_authorization(
+			source: TransactionSource,
+			account: &T::AccountId,
+		) -> Result<(ValidTransaction, Weight), TransactionValidityError> {
+			Self::ensure_local_source(source)?;
+			Self::validate_refresh_long_term_storage_authorization(account).map_err(|_| {
+				crate::extension::CustomValidity::InvalidLongTermStorageAuthorizationRefresh
+			})?;
+			ValidTransaction::with_tag_prefix("PersonhoodResourcesRefreshLongTermStorage")
+				.and_provides(account)
+				.propagate(true)
+				.build()
+				.map(|valid_tx| (valid_tx, Weight::zero()))
+		}
+
+		fn authorize_clear_expired_long_term_storage_authorization(
+			source: TransactionSource,
+			account: &T::AccountId,
+		) -> Result<(ValidTransaction, Weight), TransactionValidityError> {
+			Self::ensure_local_source(source)?;
+			Self::validate_clear_expired_long_term_storage_authorization(account).map_err(
+				|_| crate::extension::CustomValidity::InvalidExpiredLongTermStorageCleanup,
+			)?;
+			ValidTransaction::with_tag_prefix(
+				"PersonhoodResourcesClearExpiredLongTermStorageAuthorization",
+			)
+			.and_provides(account)
+			.propagate(true)
+			.build()
+			.map(|valid_tx| (valid_tx, Weight::zero()))
+		}
+
 		/// Weight of `register_person` dispatched to the correct branch.
 		fn register_person_weight(username_choice: &PersonalUsernameChoice) -> Weight {
 			match username_choice {
@@ -1634,6 +1875,123 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Record a long-term storage grant for `account` after the remote request was sent.
+		///
+		/// Grants accumulate into the account's authorization, which is valid for a full
+		/// `LongTermStorageAuthorizationPeriod` from now and may again be refreshed automatically.
+		pub(crate) fn note_long_term_storage_granted(
+			account: &T::AccountId,
+			allocation: LongTermStorageAllocation,
+			ticket: Option<StorageAllocationTicket>,
+		) {
+			let expires_at = T::Clock::now()
+				.as_secs()
+				.saturating_add(u64::from(T::LongTermStorageAuthorizationPeriod::get()));
+			let mut authorization = LongTermStorageAuthorizations::<T>::get(account)
+				.filter(|a| !Self::is_long_term_storage_authorization_expired(a))
+				.unwrap_or(LongTermStorageAuthorization {
+					transactions: 0,
+					bytes: 0,
+					expires_at,
+					refreshes: 0,
+					status: LongTermStorageAuthorizationStatus::Pending,
+					pending_ack: None,
+					request: LongTermStorageRequest::Authorize(allocation),
+					requested_at: 0,
+				});
+			authorization.transactions =
+				authorization.transactions.saturating_add(allocation.transactions);
+			authorization.bytes = authorization.bytes.saturating_add(allocation.bytes);
+			authorization.expires_at = expires_at;
+			authorization.refreshes = 0;
+			let request = LongTermStorageRequest::Authorize(allocation);
+			Self::track_long_term_storage_ack(account, &mut authorization, request, ticket);
+			LongTermStorageAuthorizations::<T>::insert(account, authorization);
+		}
+
+		/// Make `ticket` the outstanding acknowledgement of `request` for `account`, superseding
+		/// any previous one. Without a ticket the request is considered applied right away.
+		fn track_long_term_storage_ack(
+			account: &T::AccountId,
+			authorization: &mut LongTermStorageAuthorization,
+			request: LongTermStorageRequest,
+			ticket: Option<StorageAllocationTicket>,
+		) {
+			if let Some(previous) = authorization.pending_ack.take() {
+				PendingLongTermStorageAcks::<T>::remove(previous);
+			}
+			authorization.request = request;
+			match ticket {
+				Some(ticket) => {
+					PendingLongTermStorageAcks::<T>::insert(ticket, account);
+					authorization.pending_ack = Some(ticket);
+					authorization.requested_at = T::Clock::now().as_secs();
+					authorization.status = LongTermStorageAuthorizationStatus::Pending;
+				},
+				None => authorization.status = LongTermStorageAuthorizationStatus::Confirmed,
+			}
+		}
+
+		/// Whether the remote authorization has lapsed.
+		pub(crate) fn is_long_term_storage_authorization_expired(
+			authorization: &LongTermStorageAuthorization,
+		) -> bool {
+			T::Clock::now().as_secs() >= authorization.expires_at
+		}
+
+		/// Whether the acknowledgement of the latest request is overdue, so the request should be
+		/// sent again.
+		pub(crate) fn is_long_term_storage_ack_timed_out(
+			authorization: &LongTermStorageAuthorization,
+		) -> bool {
+			authorization.status == LongTermStorageAuthorizationStatus::Pending &&
+				T::Clock::now().as_secs() >=
+					authorization
+						.requested_at
+						.saturating_add(u64::from(T::LongTermStorageAckTimeout::get()))
+		}
+
+		/// Whether an authorization should be refreshed: either it is confirmed, inside its refresh
+		/// window and has refreshes left, or its latest request was never acknowledged.
+		pub(crate) fn is_long_term_storage_authorization_refreshable(
+			authorization: &LongTermStorageAuthorization,
+		) -> bool {
+			if Self::is_long_term_storage_authorization_expired(authorization) {
+				return false;
+			}
+			let refresh_from = authorization
+				.expires_at
+				.saturating_sub(u64::from(T::LongTermStorageRefreshWindow::get()));
+			Self::is_long_term_storage_ack_timed_out(authorization) ||
+				(authorization.status == LongTermStorageAuthorizationStatus::Confirmed &&
+					authorization.refreshes < T::LongTermStorageMaxRefreshes::get() &&
+					T::Clock::now().as_secs() >= refresh_from)
+		}
+
+		fn validate_refresh_long_term_storage_authorization(
+			account: &T::AccountId,
+		) -> Result<LongTermStorageAuthorization, Error<T>> {
+			let authorization = LongTermStorageAuthorizations::<T>::get(account)
+				.ok_or(Error::<T>::NoLongTermStorageAuthorization)?;
+			ensure!(
+				Self::is_long_term_storage_authorization_refreshable(&authorization),
+				Error::<T>::LongTermStorageRefreshNotDue
+			);
+			Ok(authorization)
+		}
+
+		fn validate_clear_expired_long_term_storage_authorization(
+			account: &T::AccountId,
+		) -> Result<LongTermStorageAuthorization, Error<T>> {
+			let authorization = LongTermStorageAuthorizations::<T>::get(account)
+				.ok_or(Error::<T>::NoLongTermStorageAuthorization)?;
+			ensure!(
+				Self::is_long_term_storage_authorization_expired(&authorization),
+				Error::<T>::LongTermStorageAuthorizationNotExpired
+			);
+			Ok(authorization)
+		}
+
 		/// Ensure a username is valid depending on the owner's credibility.
 		pub fn validate_username(username: &Username, person: bool) -> Result<(), Error<T>> {
 			// Ensure the username is available.
@@ -311,8 +311,10 @@ impl indiv_pallet_members::Config for Test {
 	type OnboardingQueuePageSize = ConstU32<40>;
 	type MaxFlexibleRingExponent = FlexibleRingExp;
 	type RingBuildingMemberLimit = ConstU32<100>;
+	type RingCompactionThreshold = frame_support::traits::GetDefault;
 	type OldRootRetentionDuration = ConstU64<600>;
 	type OnRingRootChange = ();
+	type CleanUpAlias = ();
 	type OffchainWorkerInterval = ConstU64<1>;
 	type ManagerOrigin = EnsureRoot<Self::AccountId>;
 	#[cfg(feature = "runtime-benchmarks")]
@@ -322,6 +324,8 @@ impl indiv_pallet_members::Config for Test {
 thread_local! {
 	pub static MOCK_UNIX_TIME: RefCell<Duration> = RefCell::new(Default::default());
 	pub static BULLETIN_STORAGE_SHOULD_FAIL: core::cell::Cell<bool> = const { core::cell::Cell::new(false) };
+	pub static BULLETIN_STORAGE_NEXT_TICKET: core::cell::Cell<u64> = const { core::cell::Cell::new(0) };
+	pub static BULLETIN_STORAGE_LAST_REQUEST: core::cell::Cell<Option<LongTermStorageRequest>> = const { core::cell::Cell::new(None) };
 }
 
 pub struct MockBulletinStorage;
@@ -341,6 +345,38 @@ impl indiv_support::traits::AllocateStorage<AccountId32> for MockBulletinStorage
 			Ok(())
 		}
 	}
+	fn allocate_storage_with_ack(
+		who: &AccountId32,
+		len: u64,
+		count: u32,
+	) -> Result<Option<u64>, sp_runtime::DispatchError> {
+		Self::allocate_storage(who, len, count)?;
+		let allocation = LongTermStorageAllocation { transactions: count, bytes: len };
+		BULLETIN_STORAGE_LAST_REQUEST
+			.with(|r| r.set(Some(LongTermStorageRequest::Authorize(allocation))));
+		Ok(Some(next_bulletin_ticket()))
+	}
+	fn refresh_allocation_with_ack(
+		who: &AccountId32,
+	) -> Result<Option<u64>, sp_runtime::DispatchError> {
+		Self::refresh_allocation(who)?;
+		BULLETIN_STORAGE_LAST_REQUEST.with(|r| r.set(Some(LongTermStorageRequest::Refresh)));
+		Ok(Some(next_bulletin_ticket()))
+	}
+}
+
+/// Hand out the ticket of the next acknowledgement requested from the mock Bulletin chain.
+fn next_bulletin_ticket() -> u64 {
+	BULLETIN_STORAGE_NEXT_TICKET.with(|t| {
+		let ticket = t.get();
+		t.set(ticket + 1);
+		ticket
+	})
+}
+
+/// The last request which asked the mock Bulletin chain for an acknowledgement.
+pub fn last_bulletin_request() -> Option<LongTermStorageRequest> {
+	BULLETIN_STORAGE_LAST_REQUEST.with(|r| r.get())
 }
 
 pub struct TestClock;
@@ -472,6 +508,10 @@ parameter_types! {
 	pub const LongTermStorageGraceWindow: u32 = 60 * 60;
 	pub const LongTermStorageClaimsPerPeriod: u8 = 10;
 	pub const LongTermStorageCleanupLimit: u32 = 50;
+	pub const LongTermStorageAuthorizationPeriod: u32 = 14 * 24 * 60 * 60;
+	pub const LongTermStorageRefreshWindow: u32 = 24 * 60 * 60;
+	pub const LongTermStorageMaxRefreshes: u8 = 2;
+	pub const LongTermStorageAckTimeout: u32 = 10 * 60;
 	pub LongTermStorageAllowanceForPeople: LongTermStorageAllocation = LongTermStorageAllocation {
 		transactions: 32,
 		bytes: 64 * 1024 * 1024,
@@ -533,6 +573,11 @@ impl Config for Test {
 	type LongTermStorageAllowanceForLitePeople = LongTermStorageAllowanceForLitePeople;
 	type LongTermStorageDataStore = MockBulletinStorage;
 	type LongTermStorageCleanupLimit = LongTermStorageCleanupLimit;
+	type LongTermStorageAuthorizationPeriod = LongTermStorageAuthorizationPeriod;
+	type LongTermStorageRefreshWindow = LongTermStorageRefreshWindow;
+	type LongTermStorageMaxRefreshes = LongTermStorageMaxRefreshes;
+	type LongTermStorageAckTimeout = LongTermStorageAckTimeout;
+	type LongTermStorageAckOrigin = EnsureRoot<Self::AccountId>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = BenchmarkHelper;
 }
@@ -3109,6 +3109,336 @@ mod long_term_storage {
 			);
 		});
 	}
+
+	fn claim_for(alias_id: u64, account: &AccountId32) {
+		let period =
+			Resources::long_term_storage_period_from_timestamp(TestClock::now().as_secs());
+		assert_ok!(Resources::claim_long_term_storage(
+			lts_people_origin(alias_id),
+			period,
+			0,
+			account.clone(),
+		));
+	}
+
+	fn pending_ticket(account: &AccountId32) -> u64 {
+		LongTermStorageAuthorizations::<Test>::get(account)
+			.and_then(|a| a.pending_ack)
+			.expect("claim requested an acknowledgement")
+	}
+
+	#[test]
+	fn claim_records_pending_authorization() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			let now = 3 * 24 * 60 * 60 + 100;
+			set_time_sec(now);
+			let account = id_to_account(99);
+
+			claim_for(7, &account);
+
+			let allowance = LongTermStorageAllowanceForPeople::get();
+			let ticket = pending_ticket(&account);
+			assert_eq!(
+				LongTermStorageAuthorizations::<Test>::get(&account),
+				Some(LongTermStorageAuthorization {
+					transactions: allowance.transactions,
+					bytes: allowance.bytes,
+					expires_at: now + LongTermStorageAuthorizationPeriod::get() as u64,
+					refreshes: 0,
+					status: LongTermStorageAuthorizationStatus::Pending,
+					pending_ack: Some(ticket),
+					request: LongTermStorageRequest::Authorize(allowance),
+					requested_at: now,
+				})
+			);
+			assert_eq!(PendingLongTermStorageAcks::<Test>::get(ticket), Some(account));
+		});
+	}
+
+	#[test]
+	fn claims_for_same_account_accumulate() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			set_time_sec(3 * 24 * 60 * 60 + 100);
+			let account = id_to_account(99);
+
+			claim_for(7, &account);
+			let first_ticket = pending_ticket(&account);
+			claim_for(8, &account);
+			let second_ticket = pending_ticket(&account);
+
+			let allowance = LongTermStorageAllowanceForPeople::get();
+			let authorization = LongTermStorageAuthorizations::<Test>::get(&account).unwrap();
+			assert_eq!(authorization.transactions, 2 * allowance.transactions);
+			assert_eq!(authorization.bytes, 2 * allowance.bytes);
+			// Only the latest request is awaited.
+			assert_ne!(first_ticket, second_ticket);
+			assert!(!PendingLongTermStorageAcks::<Test>::contains_key(first_ticket));
+			assert_noop!(
+				Resources::acknowledge_long_term_storage(
+					RuntimeOrigin::root(),
+					first_ticket,
+					Response::DispatchResult(MaybeErrorCode::Success),
+				),
+				Error::<Test>::UnknownLongTermStorageAck
+			);
+		});
+	}
+
+	#[test]
+	fn successful_ack_confirms_authorization() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			set_time_sec(3 * 24 * 60 * 60 + 100);
+			let account = id_to_account(99);
+			claim_for(7, &account);
+			let ticket = pending_ticket(&account);
+
+			assert_noop!(
+				Resources::acknowledge_long_term_storage(
+					RuntimeOrigin::signed(account.clone()),
+					ticket,
+					Response::DispatchResult(MaybeErrorCode::Success),
+				),
+				sp_runtime::DispatchError::BadOrigin
+			);
+			assert_ok!(Resources::acknowledge_long_term_storage(
+				RuntimeOrigin::root(),
+				ticket,
+				Response::DispatchResult(MaybeErrorCode::Success),
+			));
+
+			let authorization = LongTermStorageAuthorizations::<Test>::get(&account).unwrap();
+			assert_eq!(authorization.status, LongTermStorageAuthorizationStatus::Confirmed);
+			assert_eq!(authorization.pending_ack, None);
+			assert!(!PendingLongTermStorageAcks::<Test>::contains_key(ticket));
+			System::assert_last_event(
+				Event::<Test>::LongTermStorageAcknowledged { account, accepted: true }.into(),
+			);
+		});
+	}
+
+	#[test]
+	fn failed_ack_rejects_authorization() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			set_time_sec(3 * 24 * 60 * 60 + 100);
+			let account = id_to_account(99);
+			claim_for(7, &account);
+			let ticket = pending_ticket(&account);
+
+			assert_ok!(Resources::acknowledge_long_term_storage(
+				RuntimeOrigin::root(),
+				ticket,
+				Response::DispatchResult(MaybeErrorCode::Error(Default::default())),
+			));
+
+			let authorization = LongTermStorageAuthorizations::<Test>::get(&account).unwrap();
+			assert_eq!(authorization.status, LongTermStorageAuthorizationStatus::Rejected);
+			System::assert_last_event(
+				Event::<Test>::LongTermStorageAcknowledged { account, accepted: false }.into(),
+			);
+		});
+	}
+
+	#[test]
+	fn refresh_authorize_requires_confirmed_authorization_in_window() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			let now = 3 * 24 * 60 * 60 + 100;
+			set_time_sec(now);
+			let account = id_to_account(99);
+			claim_for(7, &account);
+			let call = crate::Call::<Test>::refresh_long_term_storage_authorization {
+				account: account.clone(),
+			};
+
+			// Not yet acknowledged, and the acknowledgement is not overdue.
+			let expires_at = now + LongTermStorageAuthorizationPeriod::get() as u64;
+			set_time_sec(now + LongTermStorageAckTimeout::get() as u64 - 1);
+			assert!(call.authorize(TransactionSource::Local).unwrap().is_err());
+
+			let ticket = pending_ticket(&account);
+			assert_ok!(Resources::acknowledge_long_term_storage(
+				RuntimeOrigin::root(),
+				ticket,
+				Response::DispatchResult(MaybeErrorCode::Success),
+			));
+
+			// Confirmed but not yet in the refresh window.
+			set_time_sec(expires_at - LongTermStorageRefreshWindow::get() as u64 - 1);
+			assert!(call.authorize(TransactionSource::Local).unwrap().is_err());
+
+			// Inside the window, only local submissions are accepted.
+			set_time_sec(expires_at - LongTermStorageRefreshWindow::get() as u64);
+			assert!(call.authorize(TransactionSource::External).unwrap().is_err());
+			assert!(call.authorize(TransactionSource::Local).unwrap().is_ok());
+
+			// Once expired, it can only be cleared.
+			set_time_sec(expires_at);
+			assert!(call.authorize(TransactionSource::Local).unwrap().is_err());
+		});
+	}
+
+	#[test]
+	fn timed_out_ack_is_retried_without_using_a_refresh() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			let claimed_at = 3 * 24 * 60 * 60 + 100;
+			set_time_sec(claimed_at);
+			let account = id_to_account(99);
+			claim_for(7, &account);
+			let lost_ticket = pending_ticket(&account);
+
+			// The acknowledgement never arrives: the grant is sent again.
+			let now = claimed_at + LongTermStorageAckTimeout::get() as u64;
+			set_time_sec(now);
+			advance_to_block(2);
+
+			let allowance = LongTermStorageAllowanceForPeople::get();
+			assert_eq!(last_bulletin_request(), Some(LongTermStorageRequest::Authorize(allowance)));
+			let authorization = LongTermStorageAuthorizations::<Test>::get(&account).unwrap();
+			let ticket = authorization.pending_ack.unwrap();
+			assert_ne!(ticket, lost_ticket);
+			assert_eq!(authorization.refreshes, 0);
+			assert_eq!(authorization.requested_at, now);
+			assert_eq!(authorization.status, LongTermStorageAuthorizationStatus::Pending);
+			assert_eq!(
+				authorization.expires_at,
+				now + LongTermStorageAuthorizationPeriod::get() as u64
+			);
+			assert!(!PendingLongTermStorageAcks::<Test>::contains_key(lost_ticket));
+			assert_eq!(PendingLongTermStorageAcks::<Test>::get(ticket), Some(account.clone()));
+
+			// Rejected requests are not retried.
+			assert_ok!(Resources::acknowledge_long_term_storage(
+				RuntimeOrigin::root(),
+				ticket,
+				Response::DispatchResult(MaybeErrorCode::Error(Default::default())),
+			));
+			set_time_sec(now + LongTermStorageAckTimeout::get() as u64);
+			let call = crate::Call::<Test>::refresh_long_term_storage_authorization { account };
+			assert!(call.authorize(TransactionSource::Local).unwrap().is_err());
+		});
+	}
+
+	#[test]
+	fn timed_out_refresh_ack_is_retried_as_a_refresh() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			let claimed_at = 3 * 24 * 60 * 60 + 100;
+			set_time_sec(claimed_at);
+			let account = id_to_account(99);
+			claim_for(7, &account);
+			assert_ok!(Resources::acknowledge_long_term_storage(
+				RuntimeOrigin::root(),
+				pending_ticket(&account),
+				Response::DispatchResult(MaybeErrorCode::Success),
+			));
+
+			// The authorization is refreshed inside its refresh window.
+			let expires_at = claimed_at + LongTermStorageAuthorizationPeriod::get() as u64;
+			let refreshed_at = expires_at - LongTermStorageRefreshWindow::get() as u64;
+			set_time_sec(refreshed_at);
+			advance_to_block(2);
+			assert_eq!(last_bulletin_request(), Some(LongTermStorageRequest::Refresh));
+			let lost_ticket = pending_ticket(&account);
+
+			// The acknowledgement of the refresh never arrives: the refresh is sent again.
+			let now = refreshed_at + LongTermStorageAckTimeout::get() as u64;
+			set_time_sec(now);
+			advance_to_block(3);
+
+			assert_eq!(last_bulletin_request(), Some(LongTermStorageRequest::Refresh));
+			let authorization = LongTermStorageAuthorizations::<Test>::get(&account).unwrap();
+			assert_ne!(authorization.pending_ack, Some(lost_ticket));
+			assert_eq!(authorization.refreshes, 1);
+			assert_eq!(authorization.request, LongTermStorageRequest::Refresh);
+			assert_eq!(authorization.requested_at, now);
+		});
+	}
+
+	#[test]
+	fn offchain_worker_refreshes_until_limit_then_clears() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			let period_secs = LongTermStorageAuthorizationPeriod::get() as u64;
+			let window_secs = LongTermStorageRefreshWindow::get() as u64;
+			let mut now = 3 * 24 * 60 * 60 + 100;
+			set_time_sec(now);
+			let account = id_to_account(99);
+			claim_for(7, &account);
+
+			let mut block = 1;
+			for refreshes in 1..=LongTermStorageMaxRefreshes::get() {
+				assert_ok!(Resources::acknowledge_long_term_storage(
+					RuntimeOrigin::root(),
+					pending_ticket(&account),
+					Response::DispatchResult(MaybeErrorCode::Success),
+				));
+				now += period_secs - window_secs;
+				set_time_sec(now);
+				block += 1;
+				advance_to_block(block);
+
+				let authorization = LongTermStorageAuthorizations::<Test>::get(&account).unwrap();
+				assert_eq!(authorization.refreshes, refreshes);
+				assert_eq!(authorization.expires_at, now + period_secs);
+				assert_eq!(authorization.status, LongTermStorageAuthorizationStatus::Pending);
+				System::assert_has_event(
+					Event::<Test>::LongTermStorageAuthorizationRefreshed {
+						account: account.clone(),
+						expires_at: now + period_secs,
+					}
+					.into(),
+				);
+			}
+
+			// Out of refreshes: the authorization is left to expire and then removed.
+			assert_ok!(Resources::acknowledge_long_term_storage(
+				RuntimeOrigin::root(),
+				pending_ticket(&account),
+				Response::DispatchResult(MaybeErrorCode::Success),
+			));
+			now += period_secs - window_secs;
+			set_time_sec(now);
+			block += 1;
+			advance_to_block(block);
+			assert_eq!(
+				LongTermStorageAuthorizations::<Test>::get(&account).unwrap().refreshes,
+				LongTermStorageMaxRefreshes::get()
+			);
+
+			now += window_secs;
+			set_time_sec(now);
+			block += 1;
+			advance_to_block(block);
+			assert!(!LongTermStorageAuthorizations::<Test>::contains_key(&account));
+			System::assert_has_event(
+				Event::<Test>::LongTermStorageAuthorizationExpired { account }.into(),
+			);
+		});
+	}
+
+	#[test]
+	fn claim_after_expiry_starts_fresh_authorization() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			let now = 3 * 24 * 60 * 60 + 100;
+			set_time_sec(now);
+			let account = id_to_account(99);
+			claim_for(7, &account);
+
+			set_time_sec(now + LongTermStorageAuthorizationPeriod::get() as u64);
+			claim_for(8, &account);
+
+			let allowance = LongTermStorageAllowanceForPeople::get();
+			let authorization = LongTermStorageAuthorizations::<Test>::get(&account).unwrap();
+			assert_eq!(authorization.transactions, allowance.transactions);
+			assert_eq!(authorization.bytes, allowance.bytes);
+		});
+	}
 }
 
 mod stmt_store_allowance {
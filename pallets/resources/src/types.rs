@@ -177,3 +177,78 @@ pub struct LongTermStorageAllocation {
 	/// Maximum total bytes allowed.
 	pub bytes: u64,
 }
+
+/// Whether the remote chain has confirmed a long-term storage authorization.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	Debug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub enum LongTermStorageAuthorizationStatus {
+	/// The authorization was sent and the acknowledgement is still outstanding.
+	Pending,
+	/// The remote chain reported that the authorization was applied.
+	Confirmed,
+	/// The remote chain reported that the authorization could not be applied.
+	Rejected,
+}
+
+/// A long-term storage request sent to the remote chain.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	Debug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub enum LongTermStorageRequest {
+	/// Grant of an allocation, added to the remote authorization.
+	Authorize(LongTermStorageAllocation),
+	/// Extension of the remote authorization's expiry.
+	Refresh,
+}
+
+/// Long-term storage authorized on the remote chain for an account, as tracked locally.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	Debug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub struct LongTermStorageAuthorization {
+	/// Total transactions granted to the account since the authorization was first created.
+	pub transactions: u32,
+	/// Total bytes granted to the account since the authorization was first created.
+	pub bytes: u64,
+	/// Unix time, in seconds, at which the remote authorization lapses unless refreshed.
+	pub expires_at: u64,
+	/// Number of automatic refreshes performed since the last claim.
+	pub refreshes: u8,
+	/// Acknowledgement state of the most recent request sent for this account.
+	pub status: LongTermStorageAuthorizationStatus,
+	/// Ticket of the outstanding acknowledgement, if any.
+	pub pending_ack: Option<StorageAllocationTicket>,
+	/// The most recent request sent for this account, sent again if it is not acknowledged in
+	/// time.
+	pub request: LongTermStorageRequest,
+	/// Unix time, in seconds, at which the request behind `pending_ack` was sent.
+	pub requested_at: u64,
+}
@@ -76,6 +76,11 @@ pub trait WeightInfo {
 	fn claim_long_term_storage_tx_ext() -> Weight;
 	fn clear_expired_long_term_storage_aliases(n: u32, ) -> Weight;
 	fn authorize_clear_expired_long_term_storage_aliases() -> Weight;
+	fn acknowledge_long_term_storage() -> Weight;
+	fn refresh_long_term_storage_authorization() -> Weight;
+	fn authorize_refresh_long_term_storage_authorization() -> Weight;
+	fn clear_expired_long_term_storage_authorization() -> Weight;
+	fn authorize_clear_expired_long_term_storage_authorization() -> Weight;
 }
 
 /// Weights for `indiv_pallet_resources` using the Substrate node and recommended hardware.
@@ -552,6 +557,79 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(9_341_000, 3517)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 	}
+	/// Storage: `Resources::PendingLongTermStorageAcks` (r:1 w:1)
+	/// Proof: `Resources::PendingLongTermStorageAcks` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `Resources::LongTermStorageAuthorizations` (r:1 w:1)
+	/// Proof: `Resources::LongTermStorageAuthorizations` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
+	fn acknowledge_long_term_storage() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `245`
+		//  Estimated: `3560`
+		// Minimum execution time: 16_204_000 picoseconds.
+		Weight::from_parts(16_871_000, 3560)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Resources::LongTermStorageAuthorizations` (r:1 w:1)
+	/// Proof: `Resources::LongTermStorageAuthorizations` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `PolkadotXcm::SupportedVersion` (r:1 w:0)
+	/// Proof: `PolkadotXcm::SupportedVersion` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `XcmpQueue::OutboundXcmpStatus` (r:1 w:0)
+	/// Proof: `XcmpQueue::OutboundXcmpStatus` (`max_values`: Some(1), `max_size`: Some(1794), added: 2289, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainSystem::RelevantMessagingState` (r:1 w:0)
+	/// Proof: `ParachainSystem::RelevantMessagingState` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Resources::PendingLongTermStorageAcks` (r:0 w:2)
+	/// Proof: `Resources::PendingLongTermStorageAcks` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn refresh_long_term_storage_authorization() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `243`
+		//  Estimated: `3708`
+		// Minimum execution time: 31_442_000 picoseconds.
+		Weight::from_parts(32_690_000, 3708)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Resources::LongTermStorageAuthorizations` (r:1 w:0)
+	/// Proof: `Resources::LongTermStorageAuthorizations` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn authorize_refresh_long_term_storage_authorization() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `184`
+		//  Estimated: `3560`
+		// Minimum execution time: 9_113_000 picoseconds.
+		Weight::from_parts(9_502_000, 3560)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+	}
+	/// Storage: `Resources::LongTermStorageAuthorizations` (r:1 w:1)
+	/// Proof: `Resources::LongTermStorageAuthorizations` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Resources::PendingLongTermStorageAcks` (r:0 w:1)
+	/// Proof: `Resources::PendingLongTermStorageAcks` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn clear_expired_long_term_storage_authorization() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `184`
+		//  Estimated: `3560`
+		// Minimum execution time: 14_087_000 picoseconds.
+		Weight::from_parts(14_651_000, 3560)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Resources::LongTermStorageAuthorizations` (r:1 w:0)
+	/// Proof: `Resources::LongTermStorageAuthorizations` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn authorize_clear_expired_long_term_storage_authorization() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `184`
+		//  Estimated: `3560`
+		// Minimum execution time: 8_842_000 picoseconds.
+		Weight::from_parts(9_215_000, 3560)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -1027,4 +1105,77 @@ impl WeightInfo for () {
 		Weight::from_parts(9_341_000, 3517)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 	}
+	/// Storage: `Resources::PendingLongTermStorageAcks` (r:1 w:1)
+	/// Proof: `Resources::PendingLongTermStorageAcks` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `Resources::LongTermStorageAuthorizations` (r:1 w:1)
+	/// Proof: `Resources::LongTermStorageAuthorizations` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
+	fn acknowledge_long_term_storage() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `245`
+		//  Estimated: `3560`
+		// Minimum execution time: 16_204_000 picoseconds.
+		Weight::from_parts(16_871_000, 3560)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Resources::LongTermStorageAuthorizations` (r:1 w:1)
+	/// Proof: `Resources::LongTermStorageAuthorizations` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `PolkadotXcm::SupportedVersion` (r:1 w:0)
+	/// Proof: `PolkadotXcm::SupportedVersion` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `XcmpQueue::OutboundXcmpStatus` (r:1 w:0)
+	/// Proof: `XcmpQueue::OutboundXcmpStatus` (`max_values`: Some(1), `max_size`: Some(1794), added: 2289, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainSystem::RelevantMessagingState` (r:1 w:0)
+	/// Proof: `ParachainSystem::RelevantMessagingState` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Resources::PendingLongTermStorageAcks` (r:0 w:2)
+	/// Proof: `Resources::PendingLongTermStorageAcks` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn refresh_long_term_storage_authorization() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `243`
+		//  Estimated: `3708`
+		// Minimum execution time: 31_442_000 picoseconds.
+		Weight::from_parts(32_690_000, 3708)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Resources::LongTermStorageAuthorizations` (r:1 w:0)
+	/// Proof: `Resources::LongTermStorageAuthorizations` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn authorize_refresh_long_term_storage_authorization() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `184`
+		//  Estimated: `3560`
+		// Minimum execution time: 9_113_000 picoseconds.
+		Weight::from_parts(9_502_000, 3560)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+	}
+	/// Storage: `Resources::LongTermStorageAuthorizations` (r:1 w:1)
+	/// Proof: `Resources::LongTermStorageAuthorizations` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Resources::PendingLongTermStorageAcks` (r:0 w:1)
+	/// Proof: `Resources::PendingLongTermStorageAcks` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn clear_expired_long_term_storage_authorization() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `184`
+		//  Estimated: `3560`
+		// Minimum execution time: 14_087_000 picoseconds.
+		Weight::from_parts(14_651_000, 3560)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Resources::LongTermStorageAuthorizations` (r:1 w:0)
+	/// Proof: `Resources::LongTermStorageAuthorizations` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn authorize_clear_expired_long_term_storage_authorization() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `184`
+		//  Estimated: `3560`
+		// Minimum execution time: 8_842_000 picoseconds.
+		Weight::from_parts(9_215_000, 3560)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+	}
 }
@@ -41,6 +41,14 @@ pub trait BenchmarkHelper<T: Config> {
 	fn setup_currency();
 }
 
+/// Weights for every source of personhood signals, the worst case for the combined score.
+pub fn max_signal_weights() -> SignalWeights {
+	let weights = (0..MAX_SIGNAL_SOURCES)
+		.map(|source| SignalWeight { source: source as SignalSource, points: 1 })
+		.collect::<Vec<_>>();
+	BoundedVec::truncate_from(weights)
+}
+
 #[benchmarks(
 	where T: Config,
 	<T as frame_system::Config>::RuntimeCall:
@@ -349,9 +357,10 @@ mod benches {
 		Participants::<T>::mutate(&participant_key, |maybe_p| {
 			let mut p = maybe_p.take().expect("participant must exist");
 			p.score = PersonhoodThreshold::<T>::get();
-			p.reached_personhood = true;
 			*maybe_p = Some(p);
 		});
+		// The combined score is checked for participants not having reached personhood yet.
+		SignalWeightSchedule::<T>::put(max_signal_weights());
 
 		let sk = T::Crypto::new_secret([12; 32]);
 		let pk = T::Crypto::member_from_secret(&sk);
@@ -426,6 +435,19 @@ mod benches {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn set_signal_weights() -> Result<(), BenchmarkError> {
+		// Maximum-length weights — worst case for validation cost.
+		let weights = max_signal_weights();
+		assert_eq!(weights.len() as u32, MAX_SIGNAL_SOURCES);
+
+		#[extrinsic_call]
+		_(SystemOrigin::Root, weights.clone());
+
+		assert_eq!(SignalWeightSchedule::<T>::get(), weights);
+		Ok(())
+	}
+
 	#[benchmark]
 	fn as_participant_tx_ext() -> Result<(), BenchmarkError> {
 		let caller: T::AccountId = whitelisted_caller();
@@ -73,6 +73,10 @@ pub mod pallet {
 	/// only increases maximum encoded size and worst-case validation work.
 	pub const MAX_PERSONHOOD_THRESHOLD_TIERS: u32 = 16;
 
+	/// Upper bound on how many sources of personhood signals can be weighted in the combined
+	/// score.
+	pub const MAX_SIGNAL_SOURCES: u32 = 8;
+
 	const LOG_TARGET: &str = "runtime::indiv-pallet-score";
 
 	/// The maximum value for the personhood threshold and participant score.
@@ -149,6 +153,13 @@ pub mod pallet {
 		/// Who to tell when we recognize or suspend personhood.
 		type People: PeopleTrait;
 
+		/// Personhood signals of other DIMs, added to the score of account participants according
+		/// to [`SignalWeightSchedule`].
+		///
+		/// Signals are read whenever the personhood threshold is checked, including in
+		/// `set_attendance`, so they must be cheap to compute.
+		type Signals: PersonhoodSignals<Self::AccountId>;
+
 		/// Account Identifier from which the internal Pot is generated.
 		type ScorePotId: Get<PalletId>;
 
@@ -246,6 +257,15 @@ pub mod pallet {
 	pub type AbsenceGraceSchedule<T: Config> =
 		StorageValue<_, AbsenceGraceTiers, ValueQuery, DefaultAbsenceGraceTiers<T>>;
 
+	/// Runtime-configurable weights of the sources of personhood signals, sorted by ascending
+	/// source.
+	///
+	/// The threshold checks run against the combined score: the attendance score of a participant
+	/// plus the points of each source, scaled by the strength of its signal. Empty by default,
+	/// i.e. only attendance counts, until governance sets it via [`Pallet::set_signal_weights`].
+	#[pallet::storage]
+	pub type SignalWeightSchedule<T> = StorageValue<_, SignalWeights, ValueQuery>;
+
 	/// The currently active absence-grace ratio `(allowed_misses, window)`,
 	/// derived from `AbsenceGraceSchedule` and the current active-person count.
 	///
@@ -367,6 +387,20 @@ pub mod pallet {
 		PersonhoodThresholdScheduleSet,
 		/// The absence-grace schedule has been set.
 		AbsenceGraceScheduleSet,
+		/// The weights of the sources of personhood signals have been set.
+		SignalWeightsSet,
+		/// Personhood signals contributed to the combined score of a participant reaching
+		/// personhood.
+		SignalsContributed {
+			/// The participant.
+			who: AccountOrPerson<T::AccountId>,
+			/// The attendance score of the participant.
+			score: u32,
+			/// The points contributed by each source.
+			contributions: SignalContributions,
+			/// The combined score of the participant.
+			combined_score: u32,
+		},
 	}
 
 	#[pallet::error]
@@ -428,6 +462,12 @@ pub mod pallet {
 		/// The last personhood-threshold tier must cover all populations
 		/// (`population_size_threshold == u32::MAX`).
 		PersonhoodScheduleNotTotal,
+		/// The last game recorded for the participant is not an absence.
+		NoAbsenceToRestore,
+		/// Signal weights must be sorted by ascending source, without duplicates.
+		SignalWeightsNotSorted,
+		/// A signal weight exceeds `MAX_PERSONHOOD_THRESHOLD`.
+		SignalPointsTooLarge,
 	}
 
 	/// A reason for the pallet placing a hold on funds.
@@ -509,6 +549,23 @@ pub mod pallet {
 		Ok(())
 	}
 
+	/// Validates that signal weights satisfy all invariants:
+	/// - sources sorted ascending without duplicates
+	/// - per-weight: `points <= MAX_PERSONHOOD_THRESHOLD`
+	fn validate_signal_weights<T>(weights: &[SignalWeight]) -> Result<(), Error<T>> {
+		let mut prev_source = None;
+		for weight in weights {
+			ensure!(weight.points <= MAX_PERSONHOOD_THRESHOLD, Error::<T>::SignalPointsTooLarge);
+			ensure!(
+				prev_source.is_none_or(|prev| prev < weight.source),
+				Error::<T>::SignalWeightsNotSorted
+			);
+			prev_source = Some(weight.source);
+		}
+
+		Ok(())
+	}
+
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		/// Performs payout round state machine
@@ -952,10 +1009,14 @@ pub mod pallet {
 			let who = AccountOrPerson::Account(account.clone());
 			let mut score = Participants::<T>::get(&who).ok_or(Error::<T>::NoScore)?;
 
-			ensure!(
-				score.reached_personhood || score.score >= PersonhoodThreshold::<T>::get(),
-				Error::<T>::HasNotReachedPersonhood
-			);
+			if !score.reached_personhood {
+				let (combined_score, contributions) = Self::combined_score(&who, score.score);
+				ensure!(
+					combined_score >= PersonhoodThreshold::<T>::get(),
+					Error::<T>::HasNotReachedPersonhood
+				);
+				Self::note_signals(&who, score.score, contributions, combined_score);
+			}
 
 			let (id, resumed) = match score.recognition {
 				Suspended(id) => {
@@ -1050,6 +1111,29 @@ pub mod pallet {
 			Self::deposit_event(Event::PersonhoodThresholdScheduleSet);
 			Ok(Pays::No.into())
 		}
+
+		/// Set the weights of the sources of personhood signals.
+		///
+		/// Weights must be:
+		/// - sorted ascending by `source`, without duplicates,
+		/// - per-weight: `points <= MAX_PERSONHOOD_THRESHOLD` (= 21).
+		///
+		/// The new weights apply to the next threshold check. As with the personhood-threshold
+		/// schedule, already-recognized participants are not retroactively suspended.
+		///
+		/// Called from `ManagerOrigin` or root.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::set_signal_weights())]
+		pub fn set_signal_weights(
+			origin: OriginFor<T>,
+			weights: SignalWeights,
+		) -> DispatchResultWithPostInfo {
+			T::ManagerOrigin::ensure_origin_or_root(origin)?;
+			validate_signal_weights::<T>(&weights)?;
+			SignalWeightSchedule::<T>::put(weights);
+			Self::deposit_event(Event::SignalWeightsSet);
+			Ok(Pays::No.into())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -1208,8 +1292,9 @@ pub mod pallet {
 			let (allowed_misses, window) = AbsenceGraceRatio::<T>::get();
 			let misses = score.attendance_history.misses_in_window(window);
 
+			let (combined_score, contributions) = Self::combined_score(who, score.score);
 			let acquire_personhood =
-				!score.reached_personhood && score.score >= personhood_threshold;
+				!score.reached_personhood && combined_score >= personhood_threshold;
 
 			let suspend_personhood = !attended &&
 				score.reached_personhood &&
@@ -1229,11 +1314,14 @@ pub mod pallet {
 					// call `register()` later when the threshold has increased —
 					// bypassing it entirely.
 					NotRecognized =>
-						window == 0 || misses > allowed_misses || score.score < personhood_threshold,
+						window == 0 ||
+							misses > allowed_misses ||
+							combined_score < personhood_threshold,
 				};
 
 			if acquire_personhood {
 				score.reached_personhood = true;
+				Self::note_signals(who, score.score, contributions, combined_score);
 			} else if suspend_personhood {
 				score.reached_personhood = false;
 			}
@@ -1257,6 +1345,127 @@ pub mod pallet {
 			Ok(score)
 		}
 
+		/// Reduce the score of a participant by `amount`, e.g. as a penalty for misbehaving in a
+		/// game. Returns the new score.
+		///
+		/// Externally recognized participants do not lose score. A participant not recognized in
+		/// `People` whose score drops below the personhood threshold loses their personhood, as
+		/// in [`Self::set_attendance`].
+		pub fn reduce_score(
+			who: &AccountOrPerson<T::AccountId>,
+			amount: u32,
+		) -> Result<u32, DispatchError> {
+			Participants::<T>::try_mutate(who, |maybe_participant| {
+				let participant = maybe_participant.as_mut().ok_or(Error::<T>::NoScore)?;
+				if participant.recognition.is_externally_recognized() {
+					return Ok(participant.score);
+				}
+
+				participant.score = participant.score.saturating_sub(amount);
+				if matches!(participant.recognition, NotRecognized) &&
+					Self::combined_score(who, participant.score).0 <
+						PersonhoodThreshold::<T>::get()
+				{
+					participant.reached_personhood = false;
+				}
+
+				Ok(participant.score)
+			})
+		}
+
+		/// Overturn the absence recorded for `game_index`, e.g. after a successful dispute.
+		///
+		/// The absence must be the last game recorded for the participant. The penalty of the
+		/// absence is refunded, then the game is credited as an attendance starting a new streak.
+		/// A participant reaching the personhood threshold again regains their personhood; if
+		/// they were suspended by the absence, they can `register` again to be resumed.
+		///
+		/// The refund is exact as long as the absence did not bring the score down to zero.
+		pub fn restore_attendance(
+			who: &AccountOrPerson<T::AccountId>,
+			game_index: u32,
+		) -> Result<Participant<BalanceOf<T>>, DispatchError> {
+			Participants::<T>::try_mutate(who, |maybe_participant| {
+				let participant = maybe_participant.as_mut().ok_or(Error::<T>::NoScore)?;
+				let Streak::Absent(absences) = participant.streak else {
+					return Err(Error::<T>::NoAbsenceToRestore.into());
+				};
+				ensure!(
+					participant.last_attended_game.is_none_or(|last| last < game_index),
+					Error::<T>::NoAbsenceToRestore
+				);
+
+				let personhood_threshold = PersonhoodThreshold::<T>::get();
+				if participant.recognition.is_externally_recognized() {
+					participant.score = personhood_threshold;
+				} else {
+					participant.score = participant.score.saturating_add(absences);
+				}
+
+				participant.streak.add_attendance(1);
+				participant.score = participant
+					.score
+					.saturating_add(participant.streak.attendance())
+					.min(MAX_PERSONHOOD_THRESHOLD);
+				participant.attendance_history.restore_last_attendance();
+				participant.last_attended_game = Some(game_index);
+
+				let (combined_score, contributions) = Self::combined_score(who, participant.score);
+				if combined_score >= personhood_threshold {
+					if !participant.reached_personhood {
+						Self::note_signals(who, participant.score, contributions, combined_score);
+					}
+					participant.reached_personhood = true;
+					participant.has_ever_reached_personhood = true;
+				}
+
+				Ok(participant.clone())
+			})
+		}
+
+		/// The combined score of a participant with attendance score `score`, and the points
+		/// contributed by each source of personhood signals.
+		///
+		/// Signals only apply to account participants; persons are already recognized through
+		/// another DIM. The combined score is capped at `MAX_PERSONHOOD_THRESHOLD`.
+		pub fn combined_score(
+			who: &AccountOrPerson<T::AccountId>,
+			score: u32,
+		) -> (u32, SignalContributions) {
+			let mut contributions = SignalContributions::new();
+			let Some(account) = who.account() else { return (score, contributions) };
+
+			let mut combined_score = score;
+			for SignalWeight { source, points } in SignalWeightSchedule::<T>::get() {
+				let contributed = T::Signals::strength(source, account).mul_floor(points);
+				if contributed > 0 {
+					combined_score = combined_score.saturating_add(contributed);
+					// Both lists are bounded by `MAX_SIGNAL_SOURCES`.
+					let _ = contributions.try_push((source, contributed));
+				}
+			}
+
+			(combined_score.min(MAX_PERSONHOOD_THRESHOLD), contributions)
+		}
+
+		/// Explain the contributions of personhood signals to a participant reaching personhood.
+		fn note_signals(
+			who: &AccountOrPerson<T::AccountId>,
+			score: u32,
+			contributions: SignalContributions,
+			combined_score: u32,
+		) {
+			if contributions.is_empty() {
+				return
+			}
+			Self::deposit_event(Event::SignalsContributed {
+				who: who.clone(),
+				score,
+				contributions,
+				combined_score,
+			});
+		}
+
 		/// Ensure the origin is either a person or a signed account.
 		pub fn ensure_signed_or_person(
 			origin: OriginFor<T>,
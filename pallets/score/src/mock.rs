@@ -34,7 +34,7 @@ use sp_runtime::{
 	testing::UintAuthorityId,
 	traits::{Applyable, BlakeTwo256, Checkable, IdentityLookup},
 	transaction_validity::{InvalidTransaction, TransactionSource, TransactionValidityError},
-	BuildStorage, DispatchError, TransactionOutcome,
+	BuildStorage, DispatchError, Percent, TransactionOutcome,
 };
 pub use verifiable::{mock::Mock, GenerateVerifiable};
 
@@ -130,8 +130,10 @@ impl indiv_pallet_members::Config for Test {
 	type OnboardingQueuePageSize = ConstU32<40>;
 	type MaxFlexibleRingExponent = FlexibleRingExp;
 	type RingBuildingMemberLimit = ConstU32<100>;
+	type RingCompactionThreshold = frame_support::traits::GetDefault;
 	type OldRootRetentionDuration = ConstU64<600>;
 	type OnRingRootChange = ();
+	type CleanUpAlias = ();
 	type OffchainWorkerInterval = ConstU64<1>;
 	type ManagerOrigin = EnsureRoot<Self::AccountId>;
 	#[cfg(feature = "runtime-benchmarks")]
@@ -216,6 +218,21 @@ impl indiv_pallet_people::Config for Test {
 	type BenchmarkHelper = ();
 }
 
+parameter_types! {
+	/// The strength of the personhood signals, by source and account.
+	pub static SignalStrengths: Vec<(SignalSource, u64, Percent)> = Vec::new();
+}
+
+pub struct MockSignals;
+impl PersonhoodSignals<u64> for MockSignals {
+	fn strength(source: SignalSource, who: &u64) -> Percent {
+		SignalStrengths::get()
+			.into_iter()
+			.find(|(s, account, _)| *s == source && account == who)
+			.map_or(Percent::from_percent(0), |(_, _, strength)| strength)
+	}
+}
+
 impl crate::Config for Test {
 	type WeightInfo = ();
 	type EnsurePerson = indiv_pallet_people::EnsurePersonalAliasInContext<Test>;
@@ -226,6 +243,7 @@ impl crate::Config for Test {
 	type MaxPayoutRoundSchedules = ConstUint<10>;
 	type OffchainWorkInterval = ConstUint<2>;
 	type People = People;
+	type Signals = MockSignals;
 	type Crypto = Mock;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = Test;
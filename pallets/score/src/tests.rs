@@ -33,6 +33,7 @@ use indiv_support::traits::{
 use sp_runtime::{
 	transaction_validity::InvalidTransaction,
 	DispatchError::{self, Token},
+	Percent,
 	TokenError::{BelowMinimum, FundsUnavailable},
 };
 use verifiable::{mock::Mock, GenerateVerifiable};
@@ -1065,6 +1066,93 @@ fn no_points_minted_for_persons_on_attendance() {
 	});
 }
 
+#[test]
+fn reduce_score_works() {
+	new_test_ext().execute_with(|| {
+		PersonhoodThreshold::<Test>::put(8);
+
+		PalletScore::onboard_for_recognition(&0).unwrap();
+		let candidate = AccountOrPerson::Account(0);
+		Participants::<Test>::mutate(&candidate, |participant| {
+			let participant = participant.as_mut().unwrap();
+			participant.score = 10;
+			participant.reached_personhood = true;
+		});
+
+		// Still above the threshold: personhood is kept.
+		assert_eq!(PalletScore::reduce_score(&candidate, 2), Ok(8));
+		assert!(PalletScore::reached_personhood(&candidate));
+
+		// Below the threshold: personhood is lost.
+		assert_eq!(PalletScore::reduce_score(&candidate, 3), Ok(5));
+		assert!(!PalletScore::reached_personhood(&candidate));
+
+		// The score saturates at zero.
+		assert_eq!(PalletScore::reduce_score(&candidate, 10), Ok(0));
+
+		// Externally recognized participants do not lose score.
+		let person_alias = [42u8; 32];
+		assert_ok!(PalletScore::onboard_externally_recognized(&person_alias));
+		let person = AccountOrPerson::Person(person_alias);
+		let score = Participants::<Test>::get(&person).unwrap().score;
+		assert_eq!(PalletScore::reduce_score(&person, 3), Ok(score));
+		assert!(PalletScore::reached_personhood(&person));
+
+		assert_eq!(
+			PalletScore::reduce_score(&AccountOrPerson::Account(1), 1),
+			Err(Error::<Test>::NoScore.into())
+		);
+	});
+}
+
+#[test]
+fn restore_attendance_works() {
+	new_test_ext().execute_with(|| {
+		PalletScore::onboard_for_recognition(&0).unwrap();
+		let participant = AccountOrPerson::Account(0);
+		for game_index in 0..3 {
+			PalletScore::start_attendance_report_session().unwrap();
+			PalletScore::set_attendance(&participant, true, game_index).unwrap();
+			PalletScore::end_attendance_report_session().unwrap();
+		}
+		assert_eq!(Participants::<Test>::get(&participant).unwrap().score, 6);
+
+		// Nothing to restore while attending.
+		assert_noop!(
+			PalletScore::restore_attendance(&participant, 3),
+			Error::<Test>::NoAbsenceToRestore
+		);
+
+		PalletScore::start_attendance_report_session().unwrap();
+		PalletScore::set_attendance(&participant, false, 3).unwrap();
+		PalletScore::end_attendance_report_session().unwrap();
+		let absent = Participants::<Test>::get(&participant).unwrap();
+		assert_eq!(absent.score, 5);
+		assert_eq!(absent.attendance_history.misses_in_window(8), 1);
+
+		// The absence must be for a game after the last attendance.
+		assert_noop!(
+			PalletScore::restore_attendance(&participant, 2),
+			Error::<Test>::NoAbsenceToRestore
+		);
+
+		// The penalty is refunded and the game is credited as the start of a new streak.
+		PersonhoodThreshold::<Test>::put(8);
+		let restored = PalletScore::restore_attendance(&participant, 3).unwrap();
+		assert_eq!(restored.score, 7);
+		assert_eq!(restored.streak, Streak::Attended(1));
+		assert_eq!(restored.attendance_history.misses_in_window(8), 0);
+		assert_eq!(restored.last_attended_game, Some(3));
+		assert!(!restored.reached_personhood);
+		assert_eq!(Participants::<Test>::get(&participant), Some(restored));
+
+		assert_noop!(
+			PalletScore::restore_attendance(&AccountOrPerson::Account(1), 3),
+			Error::<Test>::NoScore
+		);
+	});
+}
+
 #[test]
 fn remainder_distribution_works_even() {
 	new_test_ext().execute_with(|| {
@@ -2693,3 +2781,167 @@ fn default_personhood_curve_matches_calculate_at_session_start() {
 		}
 	});
 }
+
+fn signal_weights(weights: &[(SignalSource, u32)]) -> SignalWeights {
+	let weights = weights.iter().map(|&(source, points)| SignalWeight { source, points });
+	BoundedVec::truncate_from(weights.collect())
+}
+
+#[test]
+fn set_signal_weights_works() {
+	new_test_ext().execute_with(|| {
+		let weights = signal_weights(&[(0, 10), (3, 21)]);
+		assert_noop!(
+			PalletScore::set_signal_weights(RuntimeOrigin::signed(1), weights.clone()),
+			DispatchError::BadOrigin,
+		);
+		assert_ok!(PalletScore::set_signal_weights(RuntimeOrigin::root(), weights.clone()));
+		assert_eq!(SignalWeightSchedule::<Test>::get(), weights);
+		System::assert_last_event(Event::SignalWeightsSet.into());
+
+		// Weights are cleared with an empty list.
+		assert_ok!(PalletScore::set_signal_weights(RuntimeOrigin::root(), signal_weights(&[])));
+		assert!(SignalWeightSchedule::<Test>::get().is_empty());
+	});
+}
+
+#[test]
+fn set_signal_weights_rejects_invalid_weights() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			PalletScore::set_signal_weights(
+				RuntimeOrigin::root(),
+				signal_weights(&[(1, 10), (0, 10)]),
+			),
+			Error::<Test>::SignalWeightsNotSorted,
+		);
+		assert_noop!(
+			PalletScore::set_signal_weights(
+				RuntimeOrigin::root(),
+				signal_weights(&[(0, 10), (0, 5)]),
+			),
+			Error::<Test>::SignalWeightsNotSorted,
+		);
+		assert_noop!(
+			PalletScore::set_signal_weights(RuntimeOrigin::root(), signal_weights(&[(0, 22)]),),
+			Error::<Test>::SignalPointsTooLarge,
+		);
+	});
+}
+
+/// Signals of other DIMs are added to the attendance score, so that fewer games are needed to
+/// reach personhood.
+#[test]
+fn signals_contribute_to_personhood() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Members::create_collection(
+			0,
+			PEOPLE_MEMBER_IDENTIFIER,
+			1,
+			RingMode::Flexible,
+			RingExponent::R2e9,
+			None,
+		));
+		advance_to(1);
+
+		let user = 12u64;
+		let other = 13u64;
+		let who = AccountOrPerson::Account(user);
+		assert_ok!(PalletScore::onboard_for_recognition(&user));
+		assert_ok!(PalletScore::onboard_for_recognition(&other));
+
+		// Source 2 has no weight, source 1 contributes half of its points.
+		assert_ok!(PalletScore::set_signal_weights(
+			RuntimeOrigin::root(),
+			signal_weights(&[(0, 10), (1, 6)]),
+		));
+		SignalStrengths::set(vec![
+			(0, user, Percent::from_percent(100)),
+			(1, user, Percent::from_percent(50)),
+			(2, user, Percent::from_percent(100)),
+		]);
+		let contributions = BoundedVec::truncate_from(vec![(0, 10), (1, 3)]);
+		assert_eq!(PalletScore::combined_score(&who, 6), (19, contributions.clone()));
+		assert_eq!(PalletScore::combined_score(&who, 20), (21, contributions));
+		assert_eq!(PalletScore::combined_score(&AccountOrPerson::Account(other), 6).0, 6);
+
+		// Scores 1, 3 and 6 stay below the threshold of 21 once combined.
+		for _ in 0..3 {
+			attend(&who, true);
+			attend(&AccountOrPerson::Account(other), true);
+			assert!(!PalletScore::reached_personhood(&who));
+		}
+
+		// Score 10 combined with 13 points of signals reaches it, 2 games earlier than without.
+		attend(&who, true);
+		System::assert_has_event(
+			Event::SignalsContributed {
+				who: who.clone(),
+				score: 10,
+				contributions: BoundedVec::truncate_from(vec![(0, 10), (1, 3)]),
+				combined_score: 21,
+			}
+			.into(),
+		);
+		assert!(PalletScore::reached_personhood(&who));
+		assert_eq!(Participants::<Test>::get(&who).unwrap().score, 10);
+
+		// Without signals, the other participant is not there yet.
+		attend(&AccountOrPerson::Account(other), true);
+		assert!(!PalletScore::reached_personhood(&AccountOrPerson::Account(other)));
+
+		// A participant not recognized in `People` losing their signals falls below the threshold.
+		SignalStrengths::set(vec![]);
+		assert_ok!(PalletScore::reduce_score(&who, 0));
+		assert!(!PalletScore::reached_personhood(&who));
+	});
+}
+
+#[test]
+fn register_checks_combined_score() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Members::create_collection(
+			0,
+			PEOPLE_MEMBER_IDENTIFIER,
+			1,
+			RingMode::Flexible,
+			RingExponent::R2e9,
+			None,
+		));
+		advance_to(1);
+
+		let user = 12u64;
+		let who = AccountOrPerson::Account(user);
+		assert_ok!(PalletScore::onboard_for_recognition(&user));
+		let (key, sk) = mock_key(user);
+		let proof = {
+			let mut msg = b"pop register using".to_vec();
+			msg.extend_from_slice(&user.encode()[..]);
+			Mock::sign(&sk, &msg[..]).unwrap()
+		};
+
+		PersonhoodThreshold::<Test>::put(5);
+		assert_ok!(PalletScore::set_signal_weights(
+			RuntimeOrigin::root(),
+			signal_weights(&[(0, 5)]),
+		));
+		SignalStrengths::set(vec![(0, user, Percent::from_percent(80))]);
+		assert_noop!(
+			PalletScore::register(RuntimeOrigin::signed(user), Some((key, proof.clone()))),
+			Error::<Test>::HasNotReachedPersonhood
+		);
+
+		SignalStrengths::set(vec![(0, user, Percent::from_percent(100))]);
+		assert_ok!(PalletScore::register(RuntimeOrigin::signed(user), Some((key, proof))));
+		System::assert_has_event(
+			Event::SignalsContributed {
+				who: who.clone(),
+				score: 0,
+				contributions: BoundedVec::truncate_from(vec![(0, 5)]),
+				combined_score: 5,
+			}
+			.into(),
+		);
+		System::assert_last_event(Event::PersonhoodRecognized { who, resumed: false }.into());
+	});
+}
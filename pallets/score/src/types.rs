@@ -18,7 +18,7 @@ use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use indiv_support::traits::{Alias, PersonalId};
 use scale_info::TypeInfo;
 use sp_core::ConstU32;
-use sp_runtime::BoundedVec;
+use sp_runtime::{BoundedVec, Percent};
 
 /// A rolling bitfield tracking attendance over the last 8 games.
 ///
@@ -57,6 +57,11 @@ impl AttendanceHistory {
 		let attended_bits = self.0 & mask;
 		window.saturating_sub(attended_bits.count_ones() as u8)
 	}
+
+	/// Overwrite the last game's attendance flag with an attendance.
+	pub fn restore_last_attendance(&mut self) {
+		self.0 |= 1;
+	}
 }
 
 /// A streak of attendance or absence.
@@ -183,6 +188,44 @@ pub struct AbsenceGraceTier {
 /// schedule and as the input to `set_absence_grace_schedule`.
 pub type AbsenceGraceTiers = BoundedVec<AbsenceGraceTier, ConstU32<8>>;
 
+/// The identifier of a source of personhood signals, e.g. another DIM.
+pub type SignalSource = u8;
+
+/// The weight of a source of personhood signals in the combined score.
+#[derive(
+	Encode, Decode, MaxEncodedLen, TypeInfo, Eq, PartialEq, Debug, Clone, DecodeWithMemTracking,
+)]
+pub struct SignalWeight {
+	/// The source of the signal.
+	pub source: SignalSource,
+	/// The points added to the score of a participant with a full strength signal.
+	pub points: u32,
+}
+
+/// A bounded list of signal weights (at most
+/// [`MAX_SIGNAL_SOURCES`](crate::MAX_SIGNAL_SOURCES)), sorted by ascending `source`. Used as the
+/// storage type for the runtime-configurable weights and as the input to `set_signal_weights`.
+pub type SignalWeights = BoundedVec<SignalWeight, ConstU32<{ crate::pallet::MAX_SIGNAL_SOURCES }>>;
+
+/// The points contributed by each source of personhood signals to the combined score of a
+/// participant.
+pub type SignalContributions =
+	BoundedVec<(SignalSource, u32), ConstU32<{ crate::pallet::MAX_SIGNAL_SOURCES }>>;
+
+/// Personhood signals of other DIMs, combined with the attendance score to check personhood.
+pub trait PersonhoodSignals<AccountId> {
+	/// The strength of the signal of `source` for the account `who`.
+	///
+	/// Returns zero if `source` is unknown or has no signal for `who`.
+	fn strength(source: SignalSource, who: &AccountId) -> Percent;
+}
+
+impl<AccountId> PersonhoodSignals<AccountId> for () {
+	fn strength(_source: SignalSource, _who: &AccountId) -> Percent {
+		Percent::from_percent(0)
+	}
+}
+
 // TODO: change score and streak to u8.
 /// The participant informations.
 #[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Eq, PartialEq, Debug)]
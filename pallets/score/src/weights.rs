@@ -63,6 +63,7 @@ pub trait WeightInfo {
 	fn register() -> Weight;
 	fn set_absence_grace_schedule() -> Weight;
 	fn set_personhood_threshold_schedule() -> Weight;
+	fn set_signal_weights() -> Weight;
 	fn as_participant_tx_ext() -> Weight;
 }
 
@@ -195,6 +196,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	/// Storage: `Score::Participants` (r:1 w:1)
 	/// Proof: `Score::Participants` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `Score::SignalWeightSchedule` (r:1 w:0)
+	/// Proof: `Score::SignalWeightSchedule` (`max_values`: Some(1), `max_size`: Some(41), added: 536, mode: `MaxEncodedLen`)
+	/// Storage: `ProofOfInk::Candidates` (r:1 w:0)
+	/// Proof: `ProofOfInk::Candidates` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
+	/// Storage: `PeopleLite::LitePeople` (r:1 w:0)
+	/// Proof: `PeopleLite::LitePeople` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Honour::Tally` (r:1 w:0)
+	/// Proof: `Honour::Tally` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `People::NextPersonalId` (r:1 w:1)
 	/// Proof: `People::NextPersonalId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `People::Keys` (r:1 w:1)
@@ -221,7 +230,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		//  Estimated: `11671`
 		// Minimum execution time: 2_601_982_000 picoseconds.
 		Weight::from_parts(2_630_878_000, 11671)
-			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().reads(13_u64))
 			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
 	/// Storage: `Score::AbsenceGraceSchedule` (r:0 w:1)
@@ -244,6 +253,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(6_585_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Score::SignalWeightSchedule` (r:0 w:1)
+	/// Proof: `Score::SignalWeightSchedule` (`max_values`: Some(1), `max_size`: Some(41), added: 536, mode: `MaxEncodedLen`)
+	fn set_signal_weights() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 5_912_000 picoseconds.
+		Weight::from_parts(6_304_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 	/// Storage: `Score::Participants` (r:1 w:0)
 	/// Proof: `Score::Participants` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
 	fn as_participant_tx_ext() -> Weight {
@@ -384,6 +403,14 @@ impl WeightInfo for () {
 	}
 	/// Storage: `Score::Participants` (r:1 w:1)
 	/// Proof: `Score::Participants` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `Score::SignalWeightSchedule` (r:1 w:0)
+	/// Proof: `Score::SignalWeightSchedule` (`max_values`: Some(1), `max_size`: Some(41), added: 536, mode: `MaxEncodedLen`)
+	/// Storage: `ProofOfInk::Candidates` (r:1 w:0)
+	/// Proof: `ProofOfInk::Candidates` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
+	/// Storage: `PeopleLite::LitePeople` (r:1 w:0)
+	/// Proof: `PeopleLite::LitePeople` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Honour::Tally` (r:1 w:0)
+	/// Proof: `Honour::Tally` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `People::NextPersonalId` (r:1 w:1)
 	/// Proof: `People::NextPersonalId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `People::Keys` (r:1 w:1)
@@ -410,7 +437,7 @@ impl WeightInfo for () {
 		//  Estimated: `11671`
 		// Minimum execution time: 2_601_982_000 picoseconds.
 		Weight::from_parts(2_630_878_000, 11671)
-			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
 			.saturating_add(RocksDbWeight::get().writes(9_u64))
 	}
 	/// Storage: `Score::AbsenceGraceSchedule` (r:0 w:1)
@@ -433,6 +460,16 @@ impl WeightInfo for () {
 		Weight::from_parts(6_585_000, 0)
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Score::SignalWeightSchedule` (r:0 w:1)
+	/// Proof: `Score::SignalWeightSchedule` (`max_values`: Some(1), `max_size`: Some(41), added: 536, mode: `MaxEncodedLen`)
+	fn set_signal_weights() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 5_912_000 picoseconds.
+		Weight::from_parts(6_304_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 	/// Storage: `Score::Participants` (r:1 w:0)
 	/// Proof: `Score::Participants` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
 	fn as_participant_tx_ext() -> Weight {
@@ -281,8 +281,10 @@ impl indiv_pallet_members::Config for Test {
 	type OnboardingQueuePageSize = ConstU32<40>;
 	type MaxFlexibleRingExponent = FlexibleRingExp;
 	type RingBuildingMemberLimit = ConstU32<100>;
+	type RingCompactionThreshold = frame_support::traits::GetDefault;
 	type OldRootRetentionDuration = ConstU64<600>;
 	type OnRingRootChange = ();
+	type CleanUpAlias = ();
 	type OffchainWorkerInterval = ConstU64<1>;
 	type ManagerOrigin = EnsureRoot<Self::AccountId>;
 	#[cfg(feature = "runtime-benchmarks")]
@@ -318,12 +320,16 @@ parameter_types! {
 	pub const PoiPotId: PalletId = PalletId(*b"PoiPotId");
 	pub const MobRulePotId: PalletId = PalletId(*b"MobRwrds");
 	pub const MinTurnoutPercentage: Percent = Percent::from_percent(10);
+	pub const AppealThreshold: Percent = Percent::from_percent(40);
+	pub const ArtistRoyalty: Percent = Percent::from_percent(10);
+	pub const AppealTurnoutPercentage: Percent = Percent::from_percent(20);
 	pub const BalancesLocation: Location = Location::here();
 	pub const ExistentialDeposit: u32 = 1;
 	pub const FundingAccount: AccountId32 = AccountId32::new([1u8; 32]);
    pub const InviteRecipient: AccountId32 = AccountId32::new([2u8; 32]);
 	pub storage PeopleVoteWeight: u8 = 1;
 	pub storage CandidateVoteWeight: u8 = 1;
+	pub const MinReportConsistency: Percent = Percent::from_percent(50);
 	pub PlayerStatementLimit: StatementAllowance = StatementAllowance {
 		max_size: 1000,
 		max_count: 1000,
@@ -449,6 +455,7 @@ impl indiv_pallet_proof_of_ink::Config for Test {
 	type WeightInfo = ();
 	type Deposit = ();
 	type People = PeoplePallet;
+	type PeopleSuspension = PeoplePallet;
 	type EnsurePerson = TryMapSuccess<EnsureSigned<Self::AccountId>, AlwaysFineAccountId32>;
 	type TicketSignature = sp_runtime::testing::TestSignature;
 	type TicketPublic = UintAuthorityId;
@@ -459,8 +466,13 @@ impl indiv_pallet_proof_of_ink::Config for Test {
 	type MaxActiveReferrals = ConstU32<10>;
 	type MaxRetryAttempts = ConstU32<1>;
 	type MaxReimbursementValues = ConstU32<10>;
+	type ReattestationPeriod = ConstU64<0>;
+	type ReattestationBatchSize = ConstU32<10>;
+	type ReattestationWindow = ConstU64<100>;
+	type ReattestationGracePeriod = ConstU64<50>;
 	type Currency = Balances;
 	type PotId = PoiPotId;
+	type ArtistRoyalty = ArtistRoyalty;
 	type InvitationsOrigin = EnsureRoot<Self::AccountId>;
 	type ManagerOrigin = EnsureRoot<Self::AccountId>;
 	type Crypto = BandersnatchVrfVerifiable;
@@ -580,6 +592,7 @@ impl indiv_pallet_score::Config for Test {
 	type WeightInfo = ();
 	type EnsurePerson = MockPerson;
 	type People = PeoplePallet;
+	type Signals = ();
 	type ScorePotId = ScorePotId;
 	type Currency = Balances;
 	type CurrencyLocationInfo = BalancesLocation;
@@ -593,6 +606,7 @@ impl indiv_pallet_score::Config for Test {
 
 parameter_types! {
 	pub const GameDefaultPlayDeposit: u32 = 1;
+	pub const GameAttendanceDisputeDeposit: u32 = 1;
 }
 
 impl indiv_pallet_game::Config for Test {
@@ -609,12 +623,24 @@ impl indiv_pallet_game::Config for Test {
 	type DefaultPlayDeposit = GameDefaultPlayDeposit;
 	type DefaultPhaseDurations = GamePhaseDurations;
 	type MaxGameSchedules = ConstU32<100>;
+	type MaxScheduleRules = ConstU32<4>;
+	type ScheduleRuleHorizon = ConstU32<{ 28 * 24 * 3600 }>;
+	type MaxRegions = ConstU32<1>;
+	type MaxRecentCoPlayers = ConstU32<30>;
 	type MaxAttendanceHistoryDepth = ConstU32<12>;
 	type TicketSignature = TestSignature;
 	type PlayerStatementLimit = PlayerStatementLimit;
 	type AccountSignature = MultiSignature;
 	type PeopleVoteWeight = PeopleVoteWeight;
 	type CandidateVoteWeight = CandidateVoteWeight;
+	type AttendanceRule = indiv_pallet_game::DefaultAttendanceRule<Test>;
+	type MinReportConsistency = MinReportConsistency;
+	type MaxInconsistentReportStreak = ConstU32<3>;
+	type InconsistentReportPenalty = ConstU32<0>;
+	type Oracle = ();
+	type AttendanceDisputeWindow = ConstU64<100>;
+	type AttendanceDisputeDeposit = MockDeposit;
+	type AttendanceDisputeDepositAmount = GameAttendanceDisputeDeposit;
 	type AirdropAssetId = u32;
 	type AirdropAssetBalance = u128;
 	type Airdrop = Airdrop;
@@ -701,6 +727,18 @@ impl indiv_pallet_mob_rule::Config for Test {
 	type CleanVotesBatchSize = ConstU32<6>;
 	type VotesOpenForClaimsDuration = ConstU32<{ 60 * 60 }>;
 	type MinimumVoterThreshold = ConstU32<1>;
+	type CommitDuration = ConstU32<{ 2 * 24 * 60 * 60 }>;
+	type RevealDuration = ConstU32<{ 24 * 60 * 60 }>;
+	type UnrevealedPenaltyDuration = ConstU64<20>;
+	type Randomness = MockRandomness;
+	type JurySize = ConstU32<10>;
+	type JurorTimeout = ConstU32<{ 24 * 60 * 60 }>;
+	type VoteWeighting = ();
+	type AppealDeposit = ConstU32<100>;
+	type AppealThreshold = AppealThreshold;
+	type AppealPeriod = ConstU32<0>;
+	type AppealJurySize = ConstU32<20>;
+	type AppealTurnoutPercentage = AppealTurnoutPercentage;
 
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = MobRuleBenchHelper;
@@ -24,6 +24,7 @@ pub mod genesis;
 pub mod labels;
 pub mod members_notifier_subscriber;
 pub mod pgas;
+pub mod ring_proof_cache;
 pub mod traits;
 
 pub mod utils {
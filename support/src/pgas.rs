@@ -25,8 +25,29 @@
 //! - [`PgasOnChargeAssetTransaction`]: An `OnChargeAssetTransaction` wrapper for
 //!   `pallet_asset_conversion_tx_payment` that intercepts PGAS fee payments before they reach the
 //!   swap logic.
+//!
+//! - [`TransactionPaymentPgasFee`]: A [`PgasFee`] implementation pricing transactions like
+//!   `pallet_transaction_payment`, for fees paid in PGAS outside of the regular payment pipeline.
 
 use core::marker::PhantomData;
+use sp_runtime::{
+	traits::{DispatchInfoOf, Dispatchable, PostDispatchInfoOf},
+	transaction_validity::TransactionValidityError,
+};
+
+/// Computes the PGAS fee of a transaction that is not paid through the regular transaction
+/// payment pipeline, e.g. when paid from a PGAS balance attached to an alias.
+pub trait PgasFee<Call: Dispatchable, Balance> {
+	/// The fee to withdraw upfront for a transaction of `len` bytes.
+	fn fee(len: u32, info: &DispatchInfoOf<Call>) -> Result<Balance, TransactionValidityError>;
+
+	/// The fee actually due once the transaction has been dispatched.
+	fn actual_fee(
+		len: u32,
+		info: &DispatchInfoOf<Call>,
+		post_info: &PostDispatchInfoOf<Call>,
+	) -> Result<Balance, TransactionValidityError>;
+}
 
 /// A [`HandleCredit`](pallet_asset_tx_payment::HandleCredit) adapter that burns PGAS credits
 /// (by dropping them) and delegates to an inner handler for all other assets.
@@ -56,6 +77,7 @@ where
 mod conversion {
 	use super::*;
 	use frame_support::{
+		dispatch::{DispatchInfo, PostDispatchInfo},
 		traits::{
 			fungibles,
 			tokens::{
@@ -63,7 +85,6 @@ mod conversion {
 				Preservation::Expendable, WithdrawConsequence,
 			},
 		},
-		unsigned::TransactionValidityError,
 	};
 	use pallet_transaction_payment::OnChargeTransaction;
 	use sp_runtime::{
@@ -207,9 +228,48 @@ mod conversion {
 			}
 		}
 	}
+
+	/// A [`PgasFee`] pricing transactions like `pallet_transaction_payment` (without tip) and
+	/// converting the native fee to the PGAS asset `PgasId` with `C`, rounding up like
+	/// [`PgasOnChargeAssetTransaction`].
+	pub struct TransactionPaymentPgasFee<T, AssetId, PgasId, C>(
+		PhantomData<(T, AssetId, PgasId, C)>,
+	);
+
+	impl<T, AssetId, PgasId, C> PgasFee<T::RuntimeCall, NativeBalanceOf<T>>
+		for TransactionPaymentPgasFee<T, AssetId, PgasId, C>
+	where
+		T: pallet_transaction_payment::Config,
+		T::RuntimeCall: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+		AssetId: Clone,
+		PgasId: Get<AssetId>,
+		C: ConversionToAssetBalance<NativeBalanceOf<T>, AssetId, NativeBalanceOf<T>>,
+	{
+		fn fee(
+			len: u32,
+			info: &DispatchInfoOf<T::RuntimeCall>,
+		) -> Result<NativeBalanceOf<T>, TransactionValidityError> {
+			let fee = pallet_transaction_payment::Pallet::<T>::compute_fee(len, info, Zero::zero());
+			native_to_pgas_fee::<_, _, C>(fee, PgasId::get())
+		}
+
+		fn actual_fee(
+			len: u32,
+			info: &DispatchInfoOf<T::RuntimeCall>,
+			post_info: &PostDispatchInfoOf<T::RuntimeCall>,
+		) -> Result<NativeBalanceOf<T>, TransactionValidityError> {
+			let fee = pallet_transaction_payment::Pallet::<T>::compute_actual_fee(
+				len,
+				info,
+				post_info,
+				Zero::zero(),
+			);
+			native_to_pgas_fee::<_, _, C>(fee, PgasId::get())
+		}
+	}
 }
 
-pub use conversion::{PgasLiquidityInfo, PgasOnChargeAssetTransaction};
+pub use conversion::{PgasLiquidityInfo, PgasOnChargeAssetTransaction, TransactionPaymentPgasFee};
 
 #[cfg(test)]
 mod tests {
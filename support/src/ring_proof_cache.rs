@@ -0,0 +1,1031 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Individuality.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Shared ring-VRF proof verification layer.
+//!
+//! Ring proofs are expensive to verify and the same proof is frequently verified more than once
+//! while applying a single extrinsic: once by a transaction extension in `validate`, and again in
+//! `prepare` or in the dispatched call. [`CachedMembershipProver`] wraps any [`MembershipProver`]
+//! and remembers every successful verification for the rest of the extrinsic, keyed by the hash of
+//! the proof together with the collection, ring, revision, context and message it was checked
+//! against. Failed verifications are never cached.
+//!
+//! Batch verification goes through the cache as well: proofs already verified are answered from
+//! the cache and only the remaining ones are handed to the inner batch verifier, in one call.
+//!
+//! Every cache hit is counted. [`RefundCachedRingProofs`] turns the hits of the extrinsic into a
+//! weight refund so that callers charging for a full verification on every path are not
+//! over-charged when the verification was answered from the cache.
+//!
+//! Entries live in unhashed storage scoped to the extrinsic being applied, and
+//! [`ClearRingProofCache`] drops them once all transactions of the block have been applied. Outside
+//! of extrinsic application (e.g. runtime API calls or offchain workers) there is no extrinsic to
+//! share the results with, and the cache is bypassed.
+//!
+//! An extrinsic accesses the cache at most [`MAX_ACCESSES_PER_EXTRINSIC`] times, verifications past
+//! that bypassing it. [`RefundCachedRingProofs`] accounts for the storage of the accesses actually
+//! made once the extrinsic is dispatched, out of the weight of the verifications answered from the
+//! cache.
+//!
+//! Pallets verify ring proofs through their members service, and runtimes opt into the cache by
+//! wrapping that service in [`CachedMembershipProver`]. Such runtimes must use both
+//! [`ClearRingProofCache`] and [`RefundCachedRingProofs`].
+
+use crate::traits::{
+	Alias, AppendOnlyMembers, AppendOnlyMembersWeightInfo, Context, ContextualAlias,
+	FlexibleMembers, Identifier, MembershipMultiProver, MembershipProver, RevisedContextualAlias,
+	RevisionIndex, RingExponent, RingIndex, RingMembersState, RingMode, RingPosition, RingStatus,
+};
+use alloc::vec::Vec;
+use codec::{Decode, DecodeWithMemTracking, Encode};
+use core::marker::PhantomData;
+use frame_support::{
+	storage::unhashed,
+	traits::{Get, PostTransactions},
+	weights::{RuntimeDbWeight, Weight},
+	CloneNoBound, DebugNoBound, DefaultNoBound, EqNoBound, PartialEqNoBound,
+};
+use scale_info::TypeInfo;
+use sp_core::storage::well_known_keys;
+use sp_runtime::{
+	traits::{
+		DispatchInfoOf, Implication, PostDispatchInfoOf, TransactionExtension, ValidateResult,
+	},
+	transaction_validity::{TransactionSource, TransactionValidityError, ValidTransaction},
+	DispatchError, DispatchResult,
+};
+use verifiable::{BatchProofItem, GenerateVerifiable};
+
+/// The proof type verified by the membership prover `P`.
+type ProofOf<P> = <<P as MembershipProver>::Crypto as GenerateVerifiable>::Proof;
+
+/// Cache entries verified with a single context.
+const SINGLE_CONTEXT: u8 = 0;
+/// Cache entries verified with multiple contexts.
+const MULTI_CONTEXT: u8 = 1;
+
+/// Suffix of the key holding the cache usage of the current extrinsic.
+///
+/// Entry keys are 32-byte hashes appended to the prefix, so this shorter suffix can never collide
+/// with them.
+const USAGE_SUFFIX: &[u8] = b"usage";
+
+/// The maximum number of cache accesses of a single extrinsic.
+///
+/// Every proof looked up in the cache is one access. Verifications which would exceed it bypass
+/// the cache.
+pub const MAX_ACCESSES_PER_EXTRINSIC: u32 = 16;
+
+/// The cache usage of an extrinsic.
+#[derive(Encode, Decode, Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Usage {
+	/// The number of verifications answered from the cache.
+	pub hits: u32,
+	/// The number of proofs looked up in the cache.
+	pub accesses: u32,
+}
+
+/// An upper bound of the storage cost of one cache access.
+///
+/// That is reading the entry, updating the usage on access and on a hit, and writing the entry on
+/// a miss, then removing it in [`ClearRingProofCache`].
+pub fn access_weight(db: RuntimeDbWeight) -> Weight {
+	db.reads_writes(3, 3)
+}
+
+/// A cached verification result.
+#[derive(Encode, Decode)]
+struct Entry {
+	/// The extrinsic the verification happened in.
+	extrinsic: u32,
+	/// The aliases resulting from the verification, one per context.
+	aliases: Vec<Alias>,
+}
+
+/// The storage prefix under which all cache data is kept.
+fn cache_prefix() -> [u8; 16] {
+	sp_io::hashing::twox_128(b"RingProofCache")
+}
+
+/// The storage key of the entry for the given verification inputs.
+fn entry_key(payload: impl Encode) -> Vec<u8> {
+	let mut key = cache_prefix().to_vec();
+	key.extend_from_slice(&payload.using_encoded(sp_io::hashing::blake2_256));
+	key
+}
+
+/// The storage key of the usage.
+fn usage_key() -> Vec<u8> {
+	let mut key = cache_prefix().to_vec();
+	key.extend_from_slice(USAGE_SUFFIX);
+	key
+}
+
+/// The index of the extrinsic being applied, if any.
+fn current_extrinsic() -> Option<u32> {
+	unhashed::get(well_known_keys::EXTRINSIC_INDEX)
+}
+
+/// Look up the aliases cached for `key` within extrinsic `extrinsic`.
+fn lookup(extrinsic: u32, key: &[u8]) -> Option<Vec<Alias>> {
+	unhashed::get::<Entry>(key)
+		.filter(|entry| entry.extrinsic == extrinsic)
+		.map(|entry| entry.aliases)
+}
+
+/// Cache `aliases` under `key` for extrinsic `extrinsic`.
+fn record(extrinsic: u32, key: &[u8], aliases: Vec<Alias>) {
+	unhashed::put(key, &Entry { extrinsic, aliases });
+}
+
+/// The cache usage of extrinsic `extrinsic`.
+fn usage(extrinsic: u32) -> Usage {
+	unhashed::get::<(u32, Usage)>(&usage_key())
+		.filter(|(index, _)| *index == extrinsic)
+		.map_or_else(Default::default, |(_, usage)| usage)
+}
+
+/// Reserve `n` cache accesses for the extrinsic being applied.
+///
+/// Returns the index of the extrinsic, or `None` if the cache must be bypassed.
+fn reserve_accesses(n: u32) -> Option<u32> {
+	let extrinsic = current_extrinsic()?;
+	let mut usage = usage(extrinsic);
+	usage.accesses = usage
+		.accesses
+		.checked_add(n)
+		.filter(|accesses| *accesses <= MAX_ACCESSES_PER_EXTRINSIC)?;
+	unhashed::put(&usage_key(), &(extrinsic, usage));
+	Some(extrinsic)
+}
+
+/// Account `n` cache hits to extrinsic `extrinsic`.
+fn note_hits(extrinsic: u32, n: u32) {
+	if n == 0 {
+		return;
+	}
+	let mut usage = usage(extrinsic);
+	usage.hits = usage.hits.saturating_add(n);
+	unhashed::put(&usage_key(), &(extrinsic, usage));
+}
+
+/// Take the cache usage accounted to the extrinsic being applied.
+///
+/// Usage accounted to any other extrinsic is discarded.
+pub fn take_usage() -> Usage {
+	let extrinsic = current_extrinsic();
+	match unhashed::take::<(u32, Usage)>(&usage_key()) {
+		Some((index, usage)) if Some(index) == extrinsic => usage,
+		_ => Usage::default(),
+	}
+}
+
+/// Remove every cached verification result and usage.
+pub fn clear() {
+	let _ = unhashed::clear_prefix(&cache_prefix(), None, None);
+}
+
+/// A [`MembershipProver`] answering repeated verifications of the same proof from a cache.
+///
+/// All verification functions behave exactly as those of `P`: the cache only ever returns what `P`
+/// returned for the same inputs earlier in the same extrinsic. Lookups of revision-specific
+/// results re-check [`MembershipProver::is_revision_valid`] so that a revision expiring in the
+/// middle of an extrinsic is still rejected.
+///
+/// Member set management is forwarded to `P`, so that the wrapper can be used as the members
+/// service of a pallet.
+pub struct CachedMembershipProver<P>(PhantomData<P>);
+
+impl<P: MembershipProver> CachedMembershipProver<P> {
+	/// Verify several single-context proofs against revision `revision` of a ring, answering
+	/// already verified proofs from the cache and handing the rest to `verify` in one batch.
+	fn verify_batch_cached(
+		extrinsic: u32,
+		identifier: &Identifier,
+		ring_index: RingIndex,
+		revision: RevisionIndex,
+		items: &[BatchProofItem<ProofOf<P>>],
+		verify: impl FnOnce(&[BatchProofItem<ProofOf<P>>]) -> Result<Vec<Alias>, DispatchError>,
+	) -> Result<Vec<Alias>, DispatchError> {
+		let keys = items
+			.iter()
+			.map(|item| {
+				entry_key((
+					SINGLE_CONTEXT,
+					identifier,
+					ring_index,
+					revision,
+					&item.context[..],
+					&item.message[..],
+					&item.proof,
+				))
+			})
+			.collect::<Vec<_>>();
+		let mut aliases = keys
+			.iter()
+			.map(|key| match lookup(extrinsic, key).as_deref() {
+				Some([alias]) => Some(*alias),
+				_ => None,
+			})
+			.collect::<Vec<_>>();
+
+		let misses = items
+			.iter()
+			.zip(aliases.iter())
+			.filter(|(_, alias)| alias.is_none())
+			.map(|(item, _)| BatchProofItem {
+				proof: item.proof.clone(),
+				message: item.message.clone(),
+				context: item.context.clone(),
+			})
+			.collect::<Vec<_>>();
+		let hits = items.len().saturating_sub(misses.len()) as u32;
+
+		if !misses.is_empty() {
+			let verified = verify(&misses)?;
+			if verified.len() != misses.len() {
+				return Err(DispatchError::Other("batch verification result length mismatch"));
+			}
+			let mut verified = verified.into_iter();
+			for (slot, key) in aliases.iter_mut().zip(keys.iter()) {
+				if slot.is_none() {
+					let alias = verified.next().ok_or(DispatchError::Corruption)?;
+					record(extrinsic, key, [alias].into());
+					*slot = Some(alias);
+				}
+			}
+		}
+
+		note_hits(extrinsic, hits);
+		aliases
+			.into_iter()
+			.map(|alias| alias.ok_or(DispatchError::Corruption))
+			.collect()
+	}
+}
+
+/// The context of a batch item, as returned in verification results.
+fn item_context<Proof>(item: &BatchProofItem<Proof>) -> Result<Context, DispatchError> {
+	item.context
+		.as_slice()
+		.try_into()
+		.map_err(|_| DispatchError::Other("invalid batch item context"))
+}
+
+impl<P: MembershipProver> MembershipProver for CachedMembershipProver<P> {
+	type Crypto = P::Crypto;
+
+	fn verify_membership(
+		identifier: &Identifier,
+		proof: &ProofOf<P>,
+		ring_index: RingIndex,
+		context: Context,
+		msg: &[u8],
+	) -> Result<RevisedContextualAlias, DispatchError> {
+		let Some((revision, extrinsic)) = P::ring_revision(identifier, ring_index)
+			.and_then(|revision| Some((revision, reserve_accesses(1)?)))
+		else {
+			return P::verify_membership(identifier, proof, ring_index, context, msg);
+		};
+
+		let key =
+			entry_key((SINGLE_CONTEXT, identifier, ring_index, revision, &context[..], msg, proof));
+		if let Some([alias]) = lookup(extrinsic, &key).as_deref() {
+			note_hits(extrinsic, 1);
+			return Ok(RevisedContextualAlias {
+				revision,
+				ring: ring_index,
+				ca: ContextualAlias { alias: *alias, context },
+			});
+		}
+
+		let rev_ca = P::verify_membership(identifier, proof, ring_index, context, msg)?;
+		if rev_ca.revision == revision {
+			record(extrinsic, &key, [rev_ca.ca.alias].into());
+		}
+		Ok(rev_ca)
+	}
+
+	fn verify_membership_at_rev(
+		identifier: &Identifier,
+		proof: &ProofOf<P>,
+		ring_index: RingIndex,
+		revision: RevisionIndex,
+		context: Context,
+		msg: &[u8],
+	) -> Result<ContextualAlias, DispatchError> {
+		let Some(extrinsic) = reserve_accesses(1) else {
+			return P::verify_membership_at_rev(
+				identifier, proof, ring_index, revision, context, msg,
+			);
+		};
+
+		let key =
+			entry_key((SINGLE_CONTEXT, identifier, ring_index, revision, &context[..], msg, proof));
+		if let Some([alias]) = lookup(extrinsic, &key).as_deref() {
+			if P::is_revision_valid(identifier, ring_index, revision) {
+				note_hits(extrinsic, 1);
+				return Ok(ContextualAlias { alias: *alias, context });
+			}
+		}
+
+		let ca =
+			P::verify_membership_at_rev(identifier, proof, ring_index, revision, context, msg)?;
+		record(extrinsic, &key, [ca.alias].into());
+		Ok(ca)
+	}
+
+	fn verify_memberships_in_ring(
+		identifier: &Identifier,
+		ring_index: RingIndex,
+		items: &[BatchProofItem<ProofOf<P>>],
+	) -> Result<Vec<RevisedContextualAlias>, DispatchError> {
+		let Some((revision, extrinsic)) = P::ring_revision(identifier, ring_index)
+			.and_then(|revision| Some((revision, reserve_accesses(items.len() as u32)?)))
+		else {
+			return P::verify_memberships_in_ring(identifier, ring_index, items);
+		};
+
+		let aliases = Self::verify_batch_cached(
+			extrinsic,
+			identifier,
+			ring_index,
+			revision,
+			items,
+			|misses| {
+				let verified = P::verify_memberships_in_ring(identifier, ring_index, misses)?;
+				if verified.iter().any(|rev_ca| rev_ca.revision != revision) {
+					return Err(DispatchError::Other("ring revised during batch verification"));
+				}
+				Ok(verified.into_iter().map(|rev_ca| rev_ca.ca.alias).collect())
+			},
+		)?;
+
+		items
+			.iter()
+			.zip(aliases)
+			.map(|(item, alias)| {
+				Ok(RevisedContextualAlias {
+					revision,
+					ring: ring_index,
+					ca: ContextualAlias { alias, context: item_context(item)? },
+				})
+			})
+			.collect()
+	}
+
+	fn verify_memberships_in_ring_at_rev(
+		identifier: &Identifier,
+		ring_index: RingIndex,
+		revision: RevisionIndex,
+		items: &[BatchProofItem<ProofOf<P>>],
+	) -> Result<Vec<ContextualAlias>, DispatchError> {
+		// A revision expiring mid-extrinsic must not be answered from the cache.
+		let Some(extrinsic) = P::is_revision_valid(identifier, ring_index, revision)
+			.then(|| reserve_accesses(items.len() as u32))
+			.flatten()
+		else {
+			return P::verify_memberships_in_ring_at_rev(identifier, ring_index, revision, items);
+		};
+
+		let aliases = Self::verify_batch_cached(
+			extrinsic,
+			identifier,
+			ring_index,
+			revision,
+			items,
+			|misses| {
+				let verified =
+					P::verify_memberships_in_ring_at_rev(identifier, ring_index, revision, misses)?;
+				Ok(verified.into_iter().map(|ca| ca.alias).collect())
+			},
+		)?;
+
+		items
+			.iter()
+			.zip(aliases)
+			.map(|(item, alias)| Ok(ContextualAlias { alias, context: item_context(item)? }))
+			.collect()
+	}
+
+	fn ring_revision(identifier: &Identifier, ring_index: RingIndex) -> Option<RevisionIndex> {
+		P::ring_revision(identifier, ring_index)
+	}
+
+	fn is_revision_valid(
+		identifier: &Identifier,
+		ring_index: RingIndex,
+		revision: RevisionIndex,
+	) -> bool {
+		P::is_revision_valid(identifier, ring_index, revision)
+	}
+
+	fn revision_source_time(
+		identifier: &Identifier,
+		ring_index: RingIndex,
+		revision: RevisionIndex,
+	) -> Option<u64> {
+		P::revision_source_time(identifier, ring_index, revision)
+	}
+}
+
+impl<P: MembershipMultiProver> MembershipMultiProver for CachedMembershipProver<P> {
+	/// Multi-context proofs are checked against a sliding window of roots rather than a single
+	/// revision, so their results are not cached.
+	fn verify_membership_multi_context(
+		identifier: &Identifier,
+		proof: &ProofOf<P>,
+		ring_index: RingIndex,
+		contexts: &[Context],
+		msg: &[u8],
+	) -> Result<Vec<RevisedContextualAlias>, DispatchError> {
+		P::verify_membership_multi_context(identifier, proof, ring_index, contexts, msg)
+	}
+
+	fn verify_membership_multi_context_at_rev(
+		identifier: &Identifier,
+		proof: &ProofOf<P>,
+		ring_index: RingIndex,
+		revision: RevisionIndex,
+		contexts: &[Context],
+		msg: &[u8],
+	) -> Result<Vec<ContextualAlias>, DispatchError> {
+		let Some(extrinsic) = reserve_accesses(1) else {
+			return P::verify_membership_multi_context_at_rev(
+				identifier, proof, ring_index, revision, contexts, msg,
+			);
+		};
+
+		let key =
+			entry_key((MULTI_CONTEXT, identifier, ring_index, revision, contexts, msg, proof));
+		if let Some(aliases) = lookup(extrinsic, &key) {
+			if aliases.len() == contexts.len() &&
+				P::is_revision_valid(identifier, ring_index, revision)
+			{
+				note_hits(extrinsic, 1);
+				return Ok(aliases
+					.into_iter()
+					.zip(contexts.iter())
+					.map(|(alias, context)| ContextualAlias { alias, context: *context })
+					.collect());
+			}
+		}
+
+		let cas = P::verify_membership_multi_context_at_rev(
+			identifier, proof, ring_index, revision, contexts, msg,
+		)?;
+		record(extrinsic, &key, cas.iter().map(|ca| ca.alias).collect());
+		Ok(cas)
+	}
+}
+
+impl<P: AppendOnlyMembers> AppendOnlyMembers for CachedMembershipProver<P> {
+	type Location = P::Location;
+
+	fn create_collection(
+		owner: Self::Location,
+		identifier: &Identifier,
+		onboarding_size: u32,
+		mode: RingMode,
+		ring_size: RingExponent,
+		self_inclusion_delay: Option<u64>,
+	) -> DispatchResult {
+		P::create_collection(
+			owner,
+			identifier,
+			onboarding_size,
+			mode,
+			ring_size,
+			self_inclusion_delay,
+		)
+	}
+
+	fn delete_collection(owner: Self::Location, identifier: &Identifier) -> DispatchResult {
+		P::delete_collection(owner, identifier)
+	}
+
+	fn active_count(identifier: &Identifier) -> u32 {
+		P::active_count(identifier)
+	}
+
+	fn add_members(
+		identifier: &Identifier,
+		members: Vec<<Self::Crypto as GenerateVerifiable>::Member>,
+	) -> DispatchResult {
+		P::add_members(identifier, members)
+	}
+
+	fn rotate_member(
+		owner: Self::Location,
+		identifier: &Identifier,
+		old: <Self::Crypto as GenerateVerifiable>::Member,
+		new: <Self::Crypto as GenerateVerifiable>::Member,
+	) -> DispatchResult {
+		P::rotate_member(owner, identifier, old, new)
+	}
+
+	fn remove_ring(identifier: &Identifier, ring_index: RingIndex) -> DispatchResult {
+		P::remove_ring(identifier, ring_index)
+	}
+
+	fn ring_status(identifier: &Identifier, ring_index: RingIndex) -> Option<RingStatus> {
+		P::ring_status(identifier, ring_index)
+	}
+
+	fn is_ring_provable(identifier: &Identifier, ring_index: RingIndex) -> bool {
+		P::is_ring_provable(identifier, ring_index)
+	}
+
+	fn member_status(
+		identifier: &Identifier,
+		member: &<Self::Crypto as GenerateVerifiable>::Member,
+	) -> Option<RingPosition> {
+		P::member_status(identifier, member)
+	}
+
+	fn ring_members(
+		identifier: &Identifier,
+		ring_index: RingIndex,
+	) -> Vec<<Self::Crypto as GenerateVerifiable>::Member> {
+		P::ring_members(identifier, ring_index)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn set_active_count(identifier: &Identifier, count: u32) {
+		P::set_active_count(identifier, count)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn initialize_chunks(ring_size: RingExponent) {
+		P::initialize_chunks(ring_size)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn onboard_all_and_build_ring(
+		identifier: &Identifier,
+		ring_index: RingIndex,
+	) -> DispatchResult {
+		P::onboard_all_and_build_ring(identifier, ring_index)
+	}
+}
+
+impl<P: FlexibleMembers> FlexibleMembers for CachedMembershipProver<P> {
+	fn remove_members(
+		identifier: &Identifier,
+		suspensions: &[<Self::Crypto as GenerateVerifiable>::Member],
+	) -> DispatchResult {
+		P::remove_members(identifier, suspensions)
+	}
+
+	fn start_removal_session(identifier: &Identifier) -> DispatchResult {
+		P::start_removal_session(identifier)
+	}
+
+	fn end_removal_session(identifier: &Identifier) -> DispatchResult {
+		P::end_removal_session(identifier)
+	}
+
+	fn rings_state(identifier: &Identifier) -> RingMembersState {
+		P::rings_state(identifier)
+	}
+}
+
+impl<P: AppendOnlyMembersWeightInfo> AppendOnlyMembersWeightInfo for CachedMembershipProver<P> {
+	fn add_member_background_weight() -> Weight {
+		P::add_member_background_weight()
+	}
+}
+
+/// Drops the ring proof cache once all transactions of a block have been applied.
+///
+/// To be used as `frame_system::Config::PostTransactions` by runtimes using
+/// [`CachedMembershipProver`].
+pub struct ClearRingProofCache;
+
+impl PostTransactions for ClearRingProofCache {
+	fn post_transactions() {
+		clear();
+	}
+}
+
+/// Transaction extension refunding the weight of ring proof verifications answered from the
+/// [`CachedMembershipProver`] cache, net of the cache storage accessed by the extrinsic.
+///
+/// Only the usage record, which is taken for every extrinsic, is charged upfront. Cache accesses
+/// are made by verifications charged in full by their callers, so their storage is deducted from
+/// the refund. Should it exceed the refund, the rest is registered as extra block weight.
+///
+/// `W` is the weight of one ring proof verification. The extension must come before any
+/// extension consuming the actual weight of the extrinsic, such as `CheckWeight` and the
+/// transaction payment extension, in the pipeline.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	DefaultNoBound,
+	EqNoBound,
+	PartialEqNoBound,
+	CloneNoBound,
+	DebugNoBound,
+	TypeInfo,
+)]
+#[scale_info(skip_type_params(T, W))]
+pub struct RefundCachedRingProofs<T, W>(#[codec(skip)] pub PhantomData<(T, W)>);
+
+impl<T, W> RefundCachedRingProofs<T, W> {
+	/// Create a new instance of the extension.
+	pub fn new() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<T, W> TransactionExtension<<T as frame_system::Config>::RuntimeCall>
+	for RefundCachedRingProofs<T, W>
+where
+	T: frame_system::Config + Send + Sync,
+	W: Get<Weight> + Send + Sync + 'static,
+{
+	const IDENTIFIER: &'static str = "RefundCachedRingProofs";
+	type Implicit = ();
+	type Val = ();
+	type Pre = ();
+
+	fn weight(&self, _call: &<T as frame_system::Config>::RuntimeCall) -> Weight {
+		// Taking the usage.
+		T::DbWeight::get().reads_writes(1, 1)
+	}
+
+	fn post_dispatch_details(
+		_pre: Self::Pre,
+		info: &DispatchInfoOf<<T as frame_system::Config>::RuntimeCall>,
+		_post_info: &PostDispatchInfoOf<<T as frame_system::Config>::RuntimeCall>,
+		_len: usize,
+		_result: &sp_runtime::DispatchResult,
+	) -> Result<Weight, TransactionValidityError> {
+		let usage = take_usage();
+		let hits = W::get().saturating_mul(usage.hits as u64);
+		let accesses = access_weight(T::DbWeight::get()).saturating_mul(usage.accesses as u64);
+		let uncovered = accesses.saturating_sub(hits);
+		if !uncovered.is_zero() {
+			frame_system::Pallet::<T>::register_extra_weight_unchecked(uncovered, info.class);
+		}
+		Ok(hits.saturating_sub(accesses))
+	}
+
+	fn validate(
+		&self,
+		origin: <T as frame_system::Config>::RuntimeOrigin,
+		_call: &<T as frame_system::Config>::RuntimeCall,
+		_info: &DispatchInfoOf<<T as frame_system::Config>::RuntimeCall>,
+		_len: usize,
+		_self_implicit: Self::Implicit,
+		_inherited_implication: &impl Implication,
+		_source: TransactionSource,
+	) -> ValidateResult<Self::Val, <T as frame_system::Config>::RuntimeCall> {
+		Ok((ValidTransaction::default(), (), origin))
+	}
+
+	fn prepare(
+		self,
+		_val: Self::Val,
+		_origin: &<T as frame_system::Config>::RuntimeOrigin,
+		_call: &<T as frame_system::Config>::RuntimeCall,
+		_info: &DispatchInfoOf<<T as frame_system::Config>::RuntimeCall>,
+		_len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use core::cell::RefCell;
+	use sp_io::TestExternalities;
+	use verifiable::mock::{Mock, MockProof};
+
+	const IDENTIFIER: Identifier = [7u8; 32];
+	const CONTEXT: Context = [1u8; 32];
+
+	std::thread_local! {
+		static SINGLE_CALLS: RefCell<u32> = const { RefCell::new(0) };
+		static BATCH_CALLS: RefCell<Vec<usize>> = const { RefCell::new(Vec::new()) };
+		static REVISION: RefCell<RevisionIndex> = const { RefCell::new(0) };
+		static VALID_REVISION: RefCell<bool> = const { RefCell::new(true) };
+	}
+
+	/// Accepts any message but `b"bad"`, deriving the alias from the context and message.
+	struct TestProver;
+
+	fn alias_for(context: &[u8], msg: &[u8]) -> Result<Alias, DispatchError> {
+		if msg == b"bad" {
+			return Err(DispatchError::Other("invalid proof"));
+		}
+		Ok((context, msg).using_encoded(sp_io::hashing::blake2_256))
+	}
+
+	impl MembershipProver for TestProver {
+		type Crypto = Mock;
+
+		fn verify_membership(
+			_identifier: &Identifier,
+			_proof: &MockProof,
+			ring_index: RingIndex,
+			context: Context,
+			msg: &[u8],
+		) -> Result<RevisedContextualAlias, DispatchError> {
+			SINGLE_CALLS.with(|c| *c.borrow_mut() += 1);
+			Ok(RevisedContextualAlias {
+				revision: REVISION.with(|r| *r.borrow()),
+				ring: ring_index,
+				ca: ContextualAlias { alias: alias_for(&context, msg)?, context },
+			})
+		}
+
+		fn verify_membership_at_rev(
+			_identifier: &Identifier,
+			_proof: &MockProof,
+			_ring_index: RingIndex,
+			_revision: RevisionIndex,
+			context: Context,
+			msg: &[u8],
+		) -> Result<ContextualAlias, DispatchError> {
+			SINGLE_CALLS.with(|c| *c.borrow_mut() += 1);
+			if !VALID_REVISION.with(|v| *v.borrow()) {
+				return Err(DispatchError::Other("expired revision"));
+			}
+			Ok(ContextualAlias { alias: alias_for(&context, msg)?, context })
+		}
+
+		fn verify_memberships_in_ring(
+			_identifier: &Identifier,
+			ring_index: RingIndex,
+			items: &[BatchProofItem<MockProof>],
+		) -> Result<Vec<RevisedContextualAlias>, DispatchError> {
+			BATCH_CALLS.with(|c| c.borrow_mut().push(items.len()));
+			items
+				.iter()
+				.map(|item| {
+					Ok(RevisedContextualAlias {
+						revision: REVISION.with(|r| *r.borrow()),
+						ring: ring_index,
+						ca: ContextualAlias {
+							alias: alias_for(&item.context, &item.message)?,
+							context: item_context(item)?,
+						},
+					})
+				})
+				.collect()
+		}
+
+		fn verify_memberships_in_ring_at_rev(
+			_identifier: &Identifier,
+			_ring_index: RingIndex,
+			_revision: RevisionIndex,
+			items: &[BatchProofItem<MockProof>],
+		) -> Result<Vec<ContextualAlias>, DispatchError> {
+			BATCH_CALLS.with(|c| c.borrow_mut().push(items.len()));
+			items
+				.iter()
+				.map(|item| {
+					Ok(ContextualAlias {
+						alias: alias_for(&item.context, &item.message)?,
+						context: item_context(item)?,
+					})
+				})
+				.collect()
+		}
+
+		fn ring_revision(
+			_identifier: &Identifier,
+			_ring_index: RingIndex,
+		) -> Option<RevisionIndex> {
+			Some(REVISION.with(|r| *r.borrow()))
+		}
+
+		fn is_revision_valid(
+			_identifier: &Identifier,
+			_ring_index: RingIndex,
+			_revision: RevisionIndex,
+		) -> bool {
+			VALID_REVISION.with(|v| *v.borrow())
+		}
+
+		fn revision_source_time(
+			_identifier: &Identifier,
+			_ring_index: RingIndex,
+			_revision: RevisionIndex,
+		) -> Option<u64> {
+			None
+		}
+	}
+
+	type Cached = CachedMembershipProver<TestProver>;
+
+	fn single_calls() -> u32 {
+		SINGLE_CALLS.with(|c| *c.borrow())
+	}
+
+	fn batch_calls() -> Vec<usize> {
+		BATCH_CALLS.with(|c| c.borrow().clone())
+	}
+
+	fn set_extrinsic(index: Option<u32>) {
+		match index {
+			Some(index) => unhashed::put(well_known_keys::EXTRINSIC_INDEX, &index),
+			None => unhashed::kill(well_known_keys::EXTRINSIC_INDEX),
+		}
+	}
+
+	fn item(msg: &[u8]) -> BatchProofItem<MockProof> {
+		BatchProofItem {
+			proof: MockProof::default(),
+			message: msg.to_vec(),
+			context: CONTEXT.to_vec(),
+		}
+	}
+
+	fn new_test_ext() -> TestExternalities {
+		SINGLE_CALLS.with(|c| *c.borrow_mut() = 0);
+		BATCH_CALLS.with(|c| c.borrow_mut().clear());
+		REVISION.with(|r| *r.borrow_mut() = 0);
+		VALID_REVISION.with(|v| *v.borrow_mut() = true);
+		let mut ext = TestExternalities::default();
+		ext.execute_with(|| set_extrinsic(Some(0)));
+		ext
+	}
+
+	#[test]
+	fn repeated_verification_is_answered_from_cache() {
+		new_test_ext().execute_with(|| {
+			let proof = MockProof::default();
+			let first = Cached::verify_membership(&IDENTIFIER, &proof, 0, CONTEXT, b"msg").unwrap();
+			let second =
+				Cached::verify_membership(&IDENTIFIER, &proof, 0, CONTEXT, b"msg").unwrap();
+			assert_eq!(first, second);
+			assert_eq!(single_calls(), 1);
+
+			// The revision-specific lookup shares the entry.
+			let ca = Cached::verify_membership_at_rev(&IDENTIFIER, &proof, 0, 0, CONTEXT, b"msg")
+				.unwrap();
+			assert_eq!(ca, first.ca);
+			assert_eq!(single_calls(), 1);
+			assert_eq!(take_usage().hits, 2);
+			assert_eq!(take_usage().hits, 0);
+		});
+	}
+
+	#[test]
+	fn different_inputs_are_not_shared() {
+		new_test_ext().execute_with(|| {
+			let proof = MockProof::default();
+			Cached::verify_membership(&IDENTIFIER, &proof, 0, CONTEXT, b"msg").unwrap();
+			Cached::verify_membership(&IDENTIFIER, &proof, 0, CONTEXT, b"other").unwrap();
+			Cached::verify_membership(&IDENTIFIER, &proof, 1, CONTEXT, b"msg").unwrap();
+			Cached::verify_membership(&IDENTIFIER, &proof, 0, [2u8; 32], b"msg").unwrap();
+			assert_eq!(single_calls(), 4);
+			assert_eq!(take_usage().hits, 0);
+		});
+	}
+
+	#[test]
+	fn failures_are_not_cached() {
+		new_test_ext().execute_with(|| {
+			let proof = MockProof::default();
+			assert!(Cached::verify_membership(&IDENTIFIER, &proof, 0, CONTEXT, b"bad").is_err());
+			assert!(Cached::verify_membership(&IDENTIFIER, &proof, 0, CONTEXT, b"bad").is_err());
+			assert_eq!(single_calls(), 2);
+			assert_eq!(take_usage().hits, 0);
+		});
+	}
+
+	#[test]
+	fn cache_is_scoped_to_extrinsic_and_revision() {
+		new_test_ext().execute_with(|| {
+			let proof = MockProof::default();
+			Cached::verify_membership(&IDENTIFIER, &proof, 0, CONTEXT, b"msg").unwrap();
+
+			// A ring revision invalidates the entry.
+			REVISION.with(|r| *r.borrow_mut() = 1);
+			let rev_ca =
+				Cached::verify_membership(&IDENTIFIER, &proof, 0, CONTEXT, b"msg").unwrap();
+			assert_eq!(rev_ca.revision, 1);
+			assert_eq!(single_calls(), 2);
+
+			// So does moving on to the next extrinsic.
+			set_extrinsic(Some(1));
+			Cached::verify_membership(&IDENTIFIER, &proof, 0, CONTEXT, b"msg").unwrap();
+			assert_eq!(single_calls(), 3);
+
+			// And outside of extrinsic application nothing is cached.
+			set_extrinsic(None);
+			Cached::verify_membership(&IDENTIFIER, &proof, 0, CONTEXT, b"msg").unwrap();
+			Cached::verify_membership(&IDENTIFIER, &proof, 0, CONTEXT, b"msg").unwrap();
+			assert_eq!(single_calls(), 5);
+			assert_eq!(take_usage().hits, 0);
+		});
+	}
+
+	#[test]
+	fn expired_revision_is_not_answered_from_cache() {
+		new_test_ext().execute_with(|| {
+			let proof = MockProof::default();
+			Cached::verify_membership_at_rev(&IDENTIFIER, &proof, 0, 0, CONTEXT, b"msg").unwrap();
+
+			VALID_REVISION.with(|v| *v.borrow_mut() = false);
+			assert!(Cached::verify_membership_at_rev(&IDENTIFIER, &proof, 0, 0, CONTEXT, b"msg")
+				.is_err());
+			assert_eq!(single_calls(), 2);
+			assert_eq!(take_usage().hits, 0);
+		});
+	}
+
+	#[test]
+	fn accesses_past_the_budget_bypass_cache() {
+		new_test_ext().execute_with(|| {
+			let proof = MockProof::default();
+			for _ in 0..MAX_ACCESSES_PER_EXTRINSIC {
+				Cached::verify_membership(&IDENTIFIER, &proof, 0, CONTEXT, b"msg").unwrap();
+			}
+			assert_eq!(single_calls(), 1);
+
+			Cached::verify_membership(&IDENTIFIER, &proof, 0, CONTEXT, b"msg").unwrap();
+			assert_eq!(single_calls(), 2);
+			assert!(Cached::verify_memberships_in_ring(&IDENTIFIER, 0, &[item(b"msg")]).is_ok());
+			assert_eq!(batch_calls(), vec![1]);
+			assert_eq!(
+				take_usage(),
+				Usage {
+					hits: MAX_ACCESSES_PER_EXTRINSIC - 1,
+					accesses: MAX_ACCESSES_PER_EXTRINSIC
+				}
+			);
+		});
+	}
+
+	#[test]
+	fn batch_only_verifies_misses() {
+		new_test_ext().execute_with(|| {
+			let proof = MockProof::default();
+			let single = Cached::verify_membership(&IDENTIFIER, &proof, 0, CONTEXT, b"b").unwrap();
+
+			let items = [item(b"a"), item(b"b"), item(b"c")];
+			let results = Cached::verify_memberships_in_ring(&IDENTIFIER, 0, &items).unwrap();
+			assert_eq!(results.len(), 3);
+			assert_eq!(results[1], single);
+			assert_eq!(results[0].ca.alias, alias_for(&CONTEXT, b"a").unwrap());
+			assert_eq!(results[2].ca.alias, alias_for(&CONTEXT, b"c").unwrap());
+			assert_eq!(batch_calls(), vec![2]);
+			assert_eq!(take_usage().hits, 1);
+
+			// Everything is cached now, including for the revision-specific variant.
+			let results =
+				Cached::verify_memberships_in_ring_at_rev(&IDENTIFIER, 0, 0, &items).unwrap();
+			assert_eq!(results[0].alias, alias_for(&CONTEXT, b"a").unwrap());
+			assert_eq!(batch_calls(), vec![2]);
+			Cached::verify_membership_at_rev(&IDENTIFIER, &proof, 0, 0, CONTEXT, b"c").unwrap();
+			assert_eq!(single_calls(), 1);
+			assert_eq!(take_usage().hits, 4);
+		});
+	}
+
+	#[test]
+	fn failed_batch_caches_nothing() {
+		new_test_ext().execute_with(|| {
+			let items = [item(b"a"), item(b"bad")];
+			assert!(Cached::verify_memberships_in_ring(&IDENTIFIER, 0, &items).is_err());
+
+			let proof = MockProof::default();
+			Cached::verify_membership(&IDENTIFIER, &proof, 0, CONTEXT, b"a").unwrap();
+			assert_eq!(single_calls(), 1);
+			assert_eq!(take_usage().hits, 0);
+		});
+	}
+
+	#[test]
+	fn clear_drops_entries() {
+		new_test_ext().execute_with(|| {
+			let proof = MockProof::default();
+			Cached::verify_membership(&IDENTIFIER, &proof, 0, CONTEXT, b"msg").unwrap();
+			Cached::verify_membership(&IDENTIFIER, &proof, 0, CONTEXT, b"msg").unwrap();
+
+			ClearRingProofCache::post_transactions();
+			assert_eq!(take_usage().hits, 0);
+			Cached::verify_membership(&IDENTIFIER, &proof, 0, CONTEXT, b"msg").unwrap();
+			assert_eq!(single_calls(), 2);
+		});
+	}
+}
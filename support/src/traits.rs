@@ -24,6 +24,7 @@ pub use reality::{
 	PageIndex, PeopleTrait, PersonalId, PersonhoodLookup, PersonhoodProofRequest, RevisedAlias,
 	RevisedContextualAlias, RevisionIndex, RingExponent, RingIndex, RingMembersState, RingMode,
 	RingMutationMode, RingPosition, RingSize, RingStatus, Social, Statement, StatementOracle,
-	Truth, Username, CONTEXT_SIZE, PEOPLE_IDENTIFIER, PEOPLE_LITE_IDENTIFIER, RI_ZERO,
+	StorageAllocationTicket, Truth, Username, CONTEXT_SIZE, PEOPLE_IDENTIFIER,
+	PEOPLE_LITE_IDENTIFIER, RI_ZERO,
 };
 pub use verifiable::BatchProofItem;
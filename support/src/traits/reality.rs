@@ -595,6 +595,16 @@ pub trait AppendOnlyMembers: MembershipProver {
 		identifier: &Identifier,
 		members: Vec<<Self::Crypto as GenerateVerifiable>::Member>,
 	) -> DispatchResult;
+	/// Rotate the key of a member in a collection owned by `owner`.
+	///
+	/// The old key is suspended and the new key is queued for onboarding in a single operation.
+	/// Implementations may only support this for some ring modes.
+	fn rotate_member(
+		owner: Self::Location,
+		identifier: &Identifier,
+		old: <Self::Crypto as GenerateVerifiable>::Member,
+		new: <Self::Crypto as GenerateVerifiable>::Member,
+	) -> DispatchResult;
 	/// Remove a ring from a collection, but not the current building ring. Attempting to remove the
 	/// top-most ring will result in an error.
 	///
@@ -604,6 +614,9 @@ pub trait AppendOnlyMembers: MembershipProver {
 	fn remove_ring(identifier: &Identifier, ring_index: RingIndex) -> DispatchResult;
 	/// Query the status of a particular ring.
 	fn ring_status(identifier: &Identifier, ring_index: RingIndex) -> Option<RingStatus>;
+	/// Whether membership proofs are accepted against the current root of a ring, which requires
+	/// the collection's minimum ring population to be included.
+	fn is_ring_provable(identifier: &Identifier, ring_index: RingIndex) -> bool;
 	/// Query the status of a particular member.
 	fn member_status(
 		identifier: &Identifier,
@@ -843,6 +856,11 @@ pub mod identity {
 
 pub use identity::{Data as IdentityData, Social};
 
+/// The maximum number of rounds of a game a [`Statement::GameAttendance`] can refer to.
+pub const MAX_GAME_ROUNDS: u32 = 16;
+/// The index of the group a player played in, for each round of a game.
+pub type GameGroups = BoundedVec<u32, ConstU32<MAX_GAME_ROUNDS>>;
+
 #[derive(Clone, PartialEq, Eq, Debug, Encode, Decode, MaxEncodedLen, TypeInfo)]
 pub enum Statement {
 	/// Ask for whether evidence exists to confirm that a particular tattoo uniquely exists at a
@@ -871,6 +889,12 @@ pub enum Statement {
 	/// - is visually distinct and readable in user interfaces,
 	/// - complies with other oracle guidelines.
 	UsernameValid { username: identity::Data },
+	/// Ask for whether a player attended the game `game_index` as a person, although the
+	/// players of their groups reported them as not being one.
+	///
+	/// The player is identified by the context of the judgement. `groups` holds the group the
+	/// player played in for each round of the game.
+	GameAttendance { game_index: u32, groups: GameGroups },
 }
 
 pub const CONTEXT_SIZE: u32 = 64;
@@ -919,6 +943,18 @@ pub trait StatementOracle<RuntimeCall> {
 		context: JudgementContext,
 		callback: Callback<(Self::Ticket, JudgementContext, Judgement), RuntimeCall>,
 	) -> Result<Self::Ticket, DispatchError>;
+
+	/// Judge a `statement` like [`Self::judge_statement`], but hide the opinions given on it
+	/// until they can no longer change, so that they cannot follow one another.
+	///
+	/// Oracles which cannot hide opinions judge the statement as [`Self::judge_statement`] does.
+	fn judge_statement_with_commit_reveal(
+		statement: Statement,
+		context: JudgementContext,
+		callback: Callback<(Self::Ticket, JudgementContext, Judgement), RuntimeCall>,
+	) -> Result<Self::Ticket, DispatchError> {
+		Self::judge_statement(statement, context, callback)
+	}
 }
 
 impl<C> StatementOracle<C> for () {
@@ -988,10 +1024,37 @@ impl CleanUpAlias for () {
 	}
 }
 
+/// Identifier under which a remote chain reports the outcome of a storage allocation request.
+pub type StorageAllocationTicket = u64;
+
 /// An abstract interface for allocating storage on a remote chain (e.g. the Bulletin chain).
 pub trait AllocateStorage<AccountId> {
 	fn allocate_storage(who: &AccountId, len: u64, count: u32) -> DispatchResult;
 	fn refresh_allocation(who: &AccountId) -> DispatchResult;
+
+	/// Same as `allocate_storage`, but additionally asks the remote chain to report back whether
+	/// the authorization was applied.
+	///
+	/// Returns the ticket under which the report will be delivered, or `None` if the
+	/// implementation does not support acknowledgements.
+	fn allocate_storage_with_ack(
+		who: &AccountId,
+		len: u64,
+		count: u32,
+	) -> Result<Option<StorageAllocationTicket>, DispatchError> {
+		Self::allocate_storage(who, len, count).map(|()| None)
+	}
+
+	/// Same as `refresh_allocation`, but additionally asks the remote chain to report back
+	/// whether the refresh was applied.
+	///
+	/// Returns the ticket under which the report will be delivered, or `None` if the
+	/// implementation does not support acknowledgements.
+	fn refresh_allocation_with_ack(
+		who: &AccountId,
+	) -> Result<Option<StorageAllocationTicket>, DispatchError> {
+		Self::refresh_allocation(who).map(|()| None)
+	}
 }
 
 impl<A> AllocateStorage<A> for () {
@@ -22,6 +22,7 @@ use crate::{
 };
 use alloc::vec::Vec;
 use pallet_revive::AddressMapper;
+use verifiable::ring::ark_vrf::suites::bandersnatch::BandersnatchSha512Ell2;
 use parachains_common::AuraId;
 use sp_core::sr25519;
 use sp_genesis_builder::PresetId;
@@ -104,6 +105,13 @@ fn asset_hub_paseo_genesis(
 				.collect(),
 			..Default::default()
 		},
+		"chunksManager": ChunksManagerConfig {
+			encoded_chunk_page_hashes:
+				indiv_support::genesis::ring_verifier_r2e9_r2e10_builder_params_hashes::<BandersnatchSha512Ell2>(
+					ChunkPageSize::get()
+				),
+			_phantom: Default::default(),
+		},
 		"revive": ReviveConfig {
 			mapped_accounts: endowed_accounts.iter().filter(|x| !<Runtime as pallet_revive::Config>::AddressMapper::is_eth_derived(x)).cloned().collect(),
 			accounts: Vec::new(),
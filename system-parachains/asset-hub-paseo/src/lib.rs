@@ -202,10 +202,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	impl_name: Cow::Borrowed("asset-hub-paseo"),
 	spec_name: Cow::Borrowed("asset-hub-paseo"),
 	authoring_version: 1,
-	spec_version: 2_004_002,
+	spec_version: 2_004_003,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 16,
+	transaction_version: 17,
 	system_version: 1,
 };
 
@@ -312,7 +312,7 @@ impl frame_system::Config for Runtime {
 	type MultiBlockMigrator = MultiBlockMigrations;
 	type PreInherents = ();
 	type PostInherents = ();
-	type PostTransactions = ();
+	type PostTransactions = indiv_support::ring_proof_cache::ClearRingProofCache;
 }
 
 parameter_types! {
@@ -1633,7 +1633,7 @@ parameter_types! {
 
 impl indiv_pallet_dotns_gateway::Config for Runtime {
 	type WeightInfo = indiv_pallet_dotns_gateway::weights::SubstrateWeight<Runtime>;
-	type MemberService = MembersSubscriber;
+	type MemberService = CachedMembersSubscriber;
 	type ContractCaller = ReviveContractCaller;
 	type AddressMapper = ReviveAddressMapper;
 	type MaxContractCallWeight = DotnsMaxContractCallWeight;
@@ -1956,6 +1956,21 @@ impl indiv_pallet_members_subscriber::Config for Runtime {
 	type OffchainWorkerInterval = ConstU32<1>;
 }
 
+/// The members service of the pallets verifying ring proofs against the notified roots, sharing
+/// verifications within an extrinsic through the ring proof cache.
+pub type CachedMembersSubscriber =
+	indiv_support::ring_proof_cache::CachedMembershipProver<MembersSubscriber>;
+
+parameter_types! {
+	/// The weight of one ring-VRF membership proof verification, refunded for every verification
+	/// answered from the ring proof cache. Taken from the proof-carrying PGAS claim extension,
+	/// whose weight is dominated by the verification.
+	pub RingProofVerificationWeight: Weight =
+		<indiv_pallet_pgas::weights::SubstrateWeight<Runtime> as
+			indiv_pallet_pgas::WeightInfo>::as_pgas_claim_tx_ext()
+			.set_proof_size(0);
+}
+
 #[cfg(feature = "runtime-benchmarks")]
 impl indiv_pallet_members_subscriber::benchmarking::BenchmarkHelper<Runtime> for Runtime {
 	fn init() {
@@ -2032,7 +2047,7 @@ const BENCH_ALIAS_CONTEXT: indiv_support::traits::Context = *b"pop:ah-bench-cont
 
 impl indiv_pallet_alias_accounts::Config for Runtime {
 	type WeightInfo = indiv_pallet_alias_accounts::weights::SubstrateWeight<Runtime>;
-	type MemberService = MembersSubscriber;
+	type MemberService = CachedMembersSubscriber;
 	type UnixTime = Timestamp;
 	type ProofValidityWindow = ConstU64<300>;
 	type CleanupGracePeriod = ConstU64<3600>;
@@ -2213,20 +2228,49 @@ parameter_types! {
 
 impl indiv_pallet_pgas::Config for Runtime {
 	type WeightInfo = indiv_pallet_pgas::weights::SubstrateWeight<Runtime>;
-	type MembershipProver = MembersSubscriber;
+	type MembershipProver = CachedMembersSubscriber;
 	type Clock = Timestamp;
 	type Fungibles = Assets;
 	type PgasAssetId = PgasAssetId;
 	type PgasClaimAmount = PgasClaimAmount;
 	type MaxClaimsPerPeriodPerPerson = ConstU32<100>;
 	type MaxClaimsPerPeriodPerLitePerson = ConstU32<40>;
+	type MaxAccruedClaims = ConstU32<7>;
 	type MaxPgasClaimRecordCleanupPerCall = ConstU32<20>;
 	type PgasAdmin = PgasAdmin;
 	type PgasMinBalance = PgasMinBalance;
+	type AliasFeeOrigin = frame_support::traits::EitherOf<
+		frame_support::traits::MapSuccess<
+			indiv_pallet_alias_accounts::origin::EnsureRingAlias<Runtime>,
+			RingAliasOf,
+		>,
+		indiv_pallet_pgas::EnsureClaimAlias<Runtime>,
+	>;
+	// PGAS is pegged 1:1 to the native fee.
+	type AliasFee = indiv_support::pgas::TransactionPaymentPgasFee<
+		Runtime,
+		AssetIdForTrustBackedAssets,
+		PgasAssetId,
+		frame_support::traits::tokens::UnityAssetBalanceConversion,
+	>;
+	type AliasPgasLifetime = ConstU32<30>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = PgasBenchHelper;
 }
 
+/// Extracts the alias of a `RingAlias` origin, so that ring aliases pay their transaction fees
+/// from the PGAS balance attached to that alias.
+pub struct RingAliasOf;
+impl frame_support::traits::Morph<indiv_pallet_alias_accounts::types::AliasAccountInfo>
+	for RingAliasOf
+{
+	type Outcome = indiv_support::traits::Alias;
+
+	fn morph(info: indiv_pallet_alias_accounts::types::AliasAccountInfo) -> Self::Outcome {
+		info.ca.alias
+	}
+}
+
 /// Calls eligible to be paid for with PGAS.
 pub struct PGASCallFilter;
 impl frame_support::traits::Contains<RuntimeCall> for PGASCallFilter {
@@ -2293,6 +2337,21 @@ impl indiv_pallet_pgas::benchmarking::BenchmarkHelper<Runtime> for PgasBenchHelp
 		ring_index: indiv_support::traits::RingIndex,
 		context: &indiv_support::traits::Context,
 		message: &[u8],
+	) -> indiv_pallet_pgas::ProofOf<Runtime> {
+		Self::seed_and_create_multi_context_proof(
+			identifier,
+			ring_index,
+			core::slice::from_ref(context),
+			message,
+		)
+	}
+
+	/// Same as `seed_and_create_proof`, with a single proof over all of `contexts`.
+	fn seed_and_create_multi_context_proof(
+		identifier: &indiv_support::traits::Identifier,
+		ring_index: indiv_support::traits::RingIndex,
+		contexts: &[indiv_support::traits::Context],
+		message: &[u8],
 	) -> indiv_pallet_pgas::ProofOf<Runtime> {
 		use indiv_support::genesis::ring_verifier_builder_params;
 		use verifiable::{
@@ -2337,12 +2396,362 @@ impl indiv_pallet_pgas::benchmarking::BenchmarkHelper<Runtime> for PgasBenchHelp
 		);
 
 		let commitment = Crypto::open(domain, &member, core::iter::once(member)).expect("open");
-		let (proof, _alias) =
-			Crypto::create(commitment, &secret, &context[..], message).expect("create proof");
+		let contexts: Vec<&[u8]> = contexts.iter().map(|c| &c[..]).collect();
+		let (proof, _aliases) =
+			Crypto::create_multi_context(commitment, &secret, &contexts, message)
+				.expect("create proof");
 		proof
 	}
 }
 
+parameter_types! {
+	/// The page size for chunks manager.
+	pub const ChunkPageSize: u32 = 255;
+	/// Controls the ring size for the flexible rings of the members pallet.
+	pub const MembersFlexibleRingExponent: indiv_support::traits::RingExponent =
+		indiv_support::traits::RingExponent::R2e9;
+}
+
+impl indiv_pallet_chunks_manager::Config for Runtime {
+	type WeightInfo = indiv_pallet_chunks_manager::weights::SubstrateWeight<Runtime>;
+	type Chunk = <verifiable::ring::bandersnatch::BandersnatchVrfVerifiable as
+		verifiable::GenerateVerifiable>::StaticChunk;
+	type PageSize = ChunkPageSize;
+	type ManagerOrigin = EnsureRoot<Self::AccountId>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ChunksManagerBenchHelper;
+}
+
+// Only hosts the append-only collections of coinage, the people collections live on the people
+// chain and are followed through `MembersSubscriber`.
+impl indiv_pallet_members::Config for Runtime {
+	type WeightInfo = indiv_pallet_members::weights::SubstrateWeight<Runtime>;
+	type Crypto = verifiable::ring::bandersnatch::BandersnatchVrfVerifiable;
+	type Location = xcm::v5::Location;
+	type ChunksManager = ChunksManager;
+	type Clock = Timestamp;
+	type MaxCollections = ConstU32<100>;
+	type OnboardingQueuePageSize = ConstU32<255>;
+	type MaxFlexibleRingExponent = MembersFlexibleRingExponent;
+	type RingBuildingMemberLimit = ConstU32<100>;
+	type RingCompactionThreshold = frame_support::traits::GetDefault;
+	/// 10 minutes in seconds for old root retention.
+	type OldRootRetentionDuration = ConstU64<600>;
+	type OnRingRootChange = ();
+	type CleanUpAlias = ();
+	type OffchainWorkerInterval = ConstU32<1>;
+	type ManagerOrigin = EnsureRoot<AccountId>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = MembersBenchHelper;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct ChunksManagerBenchHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl
+	indiv_pallet_chunks_manager::BenchmarkHelper<
+		<verifiable::ring::bandersnatch::BandersnatchVrfVerifiable as
+			verifiable::GenerateVerifiable>::StaticChunk,
+	> for ChunksManagerBenchHelper
+{
+	fn chunk_page() -> Vec<
+		<verifiable::ring::bandersnatch::BandersnatchVrfVerifiable as
+			verifiable::GenerateVerifiable>::StaticChunk,
+	> {
+		use indiv_support::genesis::ring_verifier_builder_params;
+		use verifiable::ring::{
+			ark_vrf::suites::bandersnatch::BandersnatchSha512Ell2, RingDomainSize,
+		};
+
+		let chunks = ring_verifier_builder_params::<BandersnatchSha512Ell2>(RingDomainSize::Domain16);
+		chunks.into_iter().take(ChunkPageSize::get() as usize).collect()
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct MembersBenchHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl
+	indiv_pallet_members::BenchmarkHelper<
+		<verifiable::ring::bandersnatch::BandersnatchVrfVerifiable as
+			verifiable::GenerateVerifiable>::StaticChunk,
+	> for MembersBenchHelper
+{
+	fn initialize_chunks(
+		ring_size: indiv_support::traits::RingExponent,
+	) -> Vec<
+		<verifiable::ring::bandersnatch::BandersnatchVrfVerifiable as
+			verifiable::GenerateVerifiable>::StaticChunk,
+	> {
+		use indiv_support::genesis::ring_verifier_builder_params;
+		use verifiable::ring::{
+			ark_vrf::suites::bandersnatch::BandersnatchSha512Ell2, RingDomainSize,
+		};
+
+		let domain_size: RingDomainSize =
+			ring_size.try_into().expect("ring_size should be convertible to RingDomainSize");
+		ring_verifier_builder_params::<BandersnatchSha512Ell2>(domain_size)
+	}
+	fn set_time(now: core::time::Duration) {
+		pallet_timestamp::Now::<Runtime>::put(now.as_millis() as u64);
+	}
+	fn set_valid_time() {
+		let duration = core::time::Duration::from_secs(5);
+		pallet_timestamp::Now::<Runtime>::put(duration.as_millis() as u64);
+	}
+}
+
+parameter_types! {
+	// Must match the `PalletId` of the coinage pallet of the people chain, the teleported coins
+	// being backed by the assets held on the pallet account of either chain.
+	pub const CoinagePalletId: PalletId = PalletId(*b"coinage ");
+	/// The owner of the coinage collections. This matches the `Coinage` pallet index.
+	pub CoinageCollectionOwner: Location = Location::new(0, [PalletInstance(96)]);
+	/// Controls the ring size for recycler rings in coinage append-only collections.
+	pub const RecyclerRingExponent: indiv_support::traits::RingExponent =
+		indiv_support::traits::RingExponent::R2e10;
+	/// Controls the ring size for paid unload token rings in coinage append-only collections.
+	pub const PaidUnloadTokenRingExponent: indiv_support::traits::RingExponent =
+		indiv_support::traits::RingExponent::R2e10;
+	pub const PeopleIdentifier: &'static indiv_support::traits::Identifier =
+		indiv_support::traits::PEOPLE_IDENTIFIER;
+	pub const PeopleLiteIdentifier: &'static indiv_support::traits::Identifier =
+		indiv_support::traits::PEOPLE_LITE_IDENTIFIER;
+	/// Index of the coinage pallet in the people chain `construct_runtime!`.
+	pub const PeopleCoinagePalletIndex: u8 = 68;
+	/// The people chain, the only source of teleported coins.
+	pub PeopleChainParaId: ParaId = PEOPLE_ID.into();
+}
+
+/// A ring-VRF proof of membership to a ring of a people collection of the people chain.
+#[derive(
+	Clone,
+	PartialEq,
+	Eq,
+	Debug,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	scale_info::TypeInfo,
+	MaxEncodedLen,
+)]
+pub struct CoinageRingProof {
+	pub proof: <verifiable::ring::bandersnatch::BandersnatchVrfVerifiable as
+		verifiable::GenerateVerifiable>::Proof,
+	pub ring: indiv_support::traits::RingIndex,
+}
+
+/// Validates coinage proofs against the roots of the collection `Identifier` notified by the
+/// people chain.
+pub struct SubscribedCollectionProof<Identifier>(core::marker::PhantomData<Identifier>);
+impl<Identifier: Get<&'static indiv_support::traits::Identifier>>
+	indiv_pallet_coinage::ValidateProof for SubscribedCollectionProof<Identifier>
+{
+	type Proof = CoinageRingProof;
+	fn validate_proof(
+		proof: &Self::Proof,
+		context: &[u8],
+		msg: &[u8],
+	) -> Result<indiv_support::traits::Alias, ()> {
+		use indiv_support::traits::MembershipProver;
+		let context_arr: [u8; 32] = context.try_into().map_err(|_| ())?;
+		let result = MembersSubscriber::verify_membership(
+			Identifier::get(),
+			&proof.proof,
+			proof.ring,
+			context_arr,
+			msg,
+		)
+		.map_err(|_| ())?;
+		Ok(result.ca.alias)
+	}
+}
+
+/// Converts native balances to the underlying asset of the coins at the ratio of their minimum
+/// balances.
+pub struct CoinageToAssetBalance;
+impl
+	frame_support::traits::tokens::ConversionToAssetBalance<
+		Balance,
+		AssetIdForTrustBackedAssets,
+		Balance,
+	> for CoinageToAssetBalance
+{
+	type Error = sp_runtime::DispatchError;
+	fn to_asset_balance(
+		balance: Balance,
+		asset_id: AssetIdForTrustBackedAssets,
+	) -> Result<Balance, Self::Error> {
+		pallet_assets::BalanceToAssetBalance::<
+			Balances,
+			Runtime,
+			ConvertInto,
+			TrustBackedAssetsInstance,
+		>::to_asset_balance(balance, asset_id)
+		.map_err(|e| sp_runtime::TokenError::from(e).into())
+	}
+}
+
+/// Coins can be teleported to and from the people chain while this chain is subscribed to its
+/// rings.
+pub struct SubscribedCoinTeleportDestinations;
+impl indiv_pallet_coinage::CoinTeleportDestinations for SubscribedCoinTeleportDestinations {
+	fn coinage_pallet_index(para_id: ParaId) -> Option<u8> {
+		let subscribed = matches!(
+			indiv_pallet_members_subscriber::Subscription::<Runtime>::get(),
+			indiv_pallet_members_subscriber::SubscriptionStatus::Active { .. }
+		);
+		(u32::from(para_id) == PEOPLE_ID && subscribed).then(PeopleCoinagePalletIndex::get)
+	}
+}
+
+impl indiv_pallet_coinage::Config for Runtime {
+	type MemberService = indiv_support::ring_proof_cache::CachedMembershipProver<Members>;
+	type CollectionOwner = CoinageCollectionOwner;
+	type RecyclerRingExponent = RecyclerRingExponent;
+	type PaidUnloadTokenRingExponent = PaidUnloadTokenRingExponent;
+	type UnixTime = Timestamp;
+	type PalletId = CoinagePalletId;
+	type WeightInfo = indiv_pallet_coinage::weights::SubstrateWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = CoinageBenchHelper;
+	// The coin parameters must match the coinage pallet of the people chain, so that coins keep
+	// their value when teleported.
+	type MaximumAge = frame_support::traits::ConstU16<16>;
+	type NativeFungible = Balances;
+	type Fungibles = indiv_support::fungibles::CombineAssetsWithHolder<Assets, AssetsHolder>;
+	type UnderlyingAssetIdManager = EnsureRoot<AccountId>;
+	type MinimumExponent = sp_runtime::traits::ConstI8<0>;
+	type MaximumExponent = sp_runtime::traits::ConstI8<14>;
+	type MinimumExponentForOutputUnloadFee = sp_runtime::traits::ConstI8<0>;
+	type MaxSplitOutputs = ConstU32<32>;
+	type MaxConsolidation = ConstU32<64>;
+	type MaxBatchUnpaidLoad = ConstU32<10>;
+	// $0.01, the unit is 10^6.
+	type UnderlyingAssetUnit = frame_support::traits::ConstUint<{ 10u128.pow(4) }>;
+	type RecyclerExpirationTime = ConstU32<{ 90 * 24 * 60 * 60 }>; // ~3 months
+	type UnloadTokenTimePeriodPeopleLitePeople = ConstU32<{ 24 * 60 * 60 }>; // 1 day
+
+	// Allowance of $2 per time period (fee is dynamic based on multiplier)
+	type UnloadTokenAllowancePerTimePeriodForPeople = ConstU128<{ 200 * 10u128.pow(4) }>;
+	// Allowance of $0.5 per time period (fee is dynamic based on multiplier)
+	type UnloadTokenAllowancePerTimePeriodForLitePeople = ConstU128<{ 50 * 10u128.pow(4) }>;
+	type MaxFreeUnloadTokensPerTimePeriod = ConstU32<1000>;
+	type LitePeopleProof = SubscribedCollectionProof<PeopleLiteIdentifier>;
+	type PeopleProof = SubscribedCollectionProof<PeopleIdentifier>;
+	type ConversionToAssetBalance = CoinageToAssetBalance;
+	type WeightToFee = TransactionPayment;
+	type PaidUnloadTokenTimePeriod = ConstU32<{ 3 * 24 * 60 * 60 }>; // 3 days
+	type PaidUnloadTokenRingExpirationTime = ConstU32<{ 4 * 24 * 60 * 60 }>; // 4 days
+	type FeeDestination = xcm_config::StakingPot;
+	type OffchainWorkerInterval = ConstU32<4>;
+	type CoinFailureLockPeriod = ConstU64<60>;
+	type MaxSwapDuration = ConstU64<{ 24 * 60 * 60 }>; // 1 day
+	type CoinSignature = Signature;
+	type TeleportDestinations = SubscribedCoinTeleportDestinations;
+	type XcmSender = xcm_config::XcmRouter;
+	type EnsureTeleportSource = frame_support::traits::MapSuccess<
+		EnsureNotifierSibling,
+		sp_runtime::traits::Replace<PeopleChainParaId>,
+	>;
+	type UnderlyingAssetLocation =
+		AssetIdForTrustBackedAssetsConvert<TrustBackedAssetsPalletLocation, Location>;
+	type AccountIdToLocation = xcm_builder::AliasesIntoAccountId32<(), AccountId>;
+	type UniversalLocation = xcm_config::UniversalLocation;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct CoinageBenchHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl indiv_pallet_coinage::BenchmarkHelper<Runtime> for CoinageBenchHelper {
+	fn setup_assets() {
+		use frame_support::traits::fungibles::{Create, Inspect};
+		let asset_id = paseo_runtime_constants::PROTECTED_ASSET_ID;
+		if !<Assets as Inspect<AccountId>>::asset_exists(asset_id) {
+			<Assets as Create<AccountId>>::create(
+				asset_id,
+				CoinagePalletId::get().into_account_truncating(),
+				true,
+				1,
+			)
+			.expect("create the underlying asset");
+		}
+		if !indiv_pallet_coinage::UnderlyingAssetId::<Runtime>::exists() {
+			indiv_pallet_coinage::UnderlyingAssetId::<Runtime>::put(asset_id);
+		}
+	}
+	fn fund_account(who: &AccountId, amount: Balance) {
+		use frame_support::traits::fungibles::Mutate;
+		<Assets as Mutate<AccountId>>::mint_into(
+			paseo_runtime_constants::PROTECTED_ASSET_ID,
+			who,
+			amount,
+		)
+		.expect("fund the account");
+	}
+	fn set_time(now: core::time::Duration) {
+		pallet_timestamp::Now::<Runtime>::put(now.as_millis() as u64);
+	}
+	fn setup_conversion_rate() {
+		// `CoinageToAssetBalance` converts at the ratio of the minimum balances, set on creation.
+	}
+
+	fn create_people_proof(
+		context: &[u8],
+		msg: &[u8],
+		_alias: indiv_support::traits::Alias,
+	) -> CoinageRingProof {
+		use indiv_pallet_pgas::benchmarking::BenchmarkHelper;
+		let context: indiv_support::traits::Context =
+			context.try_into().expect("coinage contexts are 32 bytes");
+		let proof =
+			PgasBenchHelper::seed_and_create_proof(PeopleIdentifier::get(), 0, &context, msg);
+		CoinageRingProof { proof, ring: 0 }
+	}
+
+	fn create_lite_people_proof(
+		context: &[u8],
+		msg: &[u8],
+		_alias: indiv_support::traits::Alias,
+	) -> CoinageRingProof {
+		use indiv_pallet_pgas::benchmarking::BenchmarkHelper;
+		let context: indiv_support::traits::Context =
+			context.try_into().expect("coinage contexts are 32 bytes");
+		let proof =
+			PgasBenchHelper::seed_and_create_proof(PeopleLiteIdentifier::get(), 0, &context, msg);
+		CoinageRingProof { proof, ring: 0 }
+	}
+
+	fn setup_teleport_destination() -> Result<ParaId, frame_benchmarking::BenchmarkError> {
+		use cumulus_pallet_parachain_system::RelevantMessagingState;
+		use cumulus_primitives_core::relay_chain::AbridgedHrmpChannel;
+
+		// Fake HRMP egress channel to the people chain so that teleports can be delivered.
+		let channel = AbridgedHrmpChannel {
+			max_capacity: 1000,
+			max_total_size: 1_000_000,
+			max_message_size: 100_000,
+			msg_count: 0,
+			total_size: 0,
+			mqc_head: None,
+		};
+		let messaging_state =
+			cumulus_pallet_parachain_system::relay_state_snapshot::MessagingStateSnapshot {
+				dmq_mqc_head: Default::default(),
+				relay_dispatch_queue_remaining_capacity: Default::default(),
+				ingress_channels: Vec::new(),
+				egress_channels: vec![(PEOPLE_ID.into(), channel)],
+			};
+		RelevantMessagingState::<Runtime>::put(messaging_state);
+		indiv_pallet_members_subscriber::Subscription::<Runtime>::put(
+			indiv_pallet_members_subscriber::SubscriptionStatus::Active {
+				initialized_at_sequence: 0,
+			},
+		);
+		Ok(PEOPLE_ID.into())
+	}
+}
+
 /// Precompile address identifier (embedded at bytes [16..18] of the H160 address).
 pub const ASSET_CONVERSION_PRECOMPILE: u16 = 0x0420;
 
@@ -2427,6 +2836,9 @@ construct_runtime!(
 		Staking: pallet_staking_async = 89,
 
 		// Individuality
+		ChunksManager: indiv_pallet_chunks_manager = 94,
+		Members: indiv_pallet_members = 95,
+		Coinage: indiv_pallet_coinage = 96,
 		MembersSubscriber: indiv_pallet_members_subscriber = 97,
 		AliasAccounts: indiv_pallet_alias_accounts = 98,
 		Pgas: indiv_pallet_pgas = 99,
@@ -2472,6 +2884,7 @@ pub type TxExtension = cumulus_pallet_weight_reclaim::StorageWeightReclaim<
 			indiv_pallet_pgas::AsPgas<Runtime>,
 			indiv_pallet_alias_accounts::AsRingAlias<Runtime>,
 			indiv_pallet_dotns_gateway::AsDotnsGateway<Runtime>,
+			indiv_pallet_coinage::extension::AsCoinage<Runtime>,
 		),
 		// General checks and operations
 		indiv_pallet_origin_restriction::RestrictOrigin<Runtime>,
@@ -2481,11 +2894,16 @@ pub type TxExtension = cumulus_pallet_weight_reclaim::StorageWeightReclaim<
 		frame_system::CheckGenesis<Runtime>,
 		frame_system::CheckEra<Runtime>,
 		frame_system::CheckNonce<Runtime>,
+		indiv_support::ring_proof_cache::RefundCachedRingProofs<
+			Runtime,
+			RingProofVerificationWeight,
+		>,
 		frame_system::CheckWeight<Runtime>,
 		pallet_pgas_allowance::ChargePGAS<
 			Runtime,
 			pallet_asset_conversion_tx_payment::ChargeAssetTxPayment<Runtime>,
 		>,
+		indiv_pallet_pgas::ChargeAliasPgas<Runtime>,
 		(
 			pallet_claims::PrevalidateAttests<Runtime>,
 			frame_metadata_hash_extension::CheckMetadataHash<Runtime>,
@@ -2514,6 +2932,7 @@ impl pallet_revive::evm::runtime::EthExtra for EthExtraImpl {
 				indiv_pallet_pgas::AsPgas::<Runtime>::new(None),
 				indiv_pallet_alias_accounts::AsRingAlias::<Runtime>::new(None),
 				indiv_pallet_dotns_gateway::AsDotnsGateway::<Runtime>::new(None),
+				indiv_pallet_coinage::extension::AsCoinage::<Runtime>::new(None),
 			),
 			indiv_pallet_origin_restriction::RestrictOrigin::<Runtime>::new(true),
 			frame_system::CheckNonZeroSender::<Runtime>::new(),
@@ -2522,6 +2941,7 @@ impl pallet_revive::evm::runtime::EthExtra for EthExtraImpl {
 			frame_system::CheckGenesis::<Runtime>::new(),
 			frame_system::CheckMortality::from(generic::Era::Immortal),
 			frame_system::CheckNonce::<Runtime>::from(nonce),
+			indiv_support::ring_proof_cache::RefundCachedRingProofs::new(),
 			frame_system::CheckWeight::<Runtime>::new(),
 			pallet_pgas_allowance::ChargePGAS::<
 				Runtime,
@@ -2531,6 +2951,7 @@ impl pallet_revive::evm::runtime::EthExtra for EthExtraImpl {
 					tip, None,
 				),
 			),
+			indiv_pallet_pgas::ChargeAliasPgas::<Runtime>::new(),
 			(
 				pallet_claims::PrevalidateAttests::<Runtime>::new(),
 				frame_metadata_hash_extension::CheckMetadataHash::<Runtime>::new(false),
@@ -2559,6 +2980,7 @@ where
 				indiv_pallet_pgas::AsPgas::<Runtime>::new(None),
 				indiv_pallet_alias_accounts::AsRingAlias::<Runtime>::new(None),
 				indiv_pallet_dotns_gateway::AsDotnsGateway::<Runtime>::new(None),
+				indiv_pallet_coinage::extension::AsCoinage::<Runtime>::new(None),
 			),
 			indiv_pallet_origin_restriction::RestrictOrigin::<Runtime>::new(true),
 			frame_system::CheckNonZeroSender::<Runtime>::new(),
@@ -2567,6 +2989,7 @@ where
 			frame_system::CheckGenesis::<Runtime>::new(),
 			frame_system::CheckEra::<Runtime>::from(generic::Era::Immortal),
 			frame_system::CheckNonce::<Runtime>::from(0),
+			indiv_support::ring_proof_cache::RefundCachedRingProofs::new(),
 			frame_system::CheckWeight::<Runtime>::new(),
 			pallet_pgas_allowance::ChargePGAS::<
 				Runtime,
@@ -2574,6 +2997,7 @@ where
 			>::new_skip_pgas(
 				pallet_asset_conversion_tx_payment::ChargeAssetTxPayment::<Runtime>::from(0, None),
 			),
+			indiv_pallet_pgas::ChargeAliasPgas::<Runtime>::new(),
 			(
 				pallet_claims::PrevalidateAttests::<Runtime>::new(),
 				frame_metadata_hash_extension::CheckMetadataHash::<Runtime>::new(false),
@@ -2722,7 +3146,10 @@ mod benches {
 
 		// Individuality
 		[indiv_pallet_alias_accounts, AliasAccounts]
+		[indiv_pallet_chunks_manager, ChunksManager]
+		[indiv_pallet_coinage, Coinage]
 		[indiv_pallet_dotns_gateway, DotnsGateway]
+		[indiv_pallet_members, Members]
 		[indiv_pallet_members_subscriber, MembersSubscriber]
 		[indiv_pallet_origin_restriction, OriginRestriction]
 		[indiv_pallet_pgas, Pgas]
@@ -3670,6 +4097,17 @@ pallet_revive::impl_runtime_apis_plus_revive_traits!(
 		}
 	}
 
+	impl indiv_pallet_pgas::runtime_api::PgasApi<Block, Balance> for Runtime {
+		fn claim_eligibility(
+			collection: indiv_pallet_pgas::PgasCollection,
+			slot_index: u32,
+			today: indiv_pallet_pgas::runtime_api::ClaimAliases,
+			grace: Option<indiv_pallet_pgas::runtime_api::ClaimAliases>,
+		) -> indiv_pallet_pgas::runtime_api::ClaimEligibility<Balance> {
+			Pgas::claim_eligibility(collection, slot_index, today, grace)
+		}
+	}
+
 	#[cfg(feature = "try-runtime")]
 	impl frame_try_runtime::TryRuntime<Block> for Runtime {
 		fn on_runtime_upgrade(checks: frame_try_runtime::UpgradeCheckSelect) -> (Weight, Weight) {
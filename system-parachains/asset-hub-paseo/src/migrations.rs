@@ -138,6 +138,61 @@ impl frame_support::traits::OnRuntimeUpgrade for MigrateBountyAccountAssets {
 	}
 }
 
+/// Sets the expected hashes of the chunk pages of the ring verifier builder params, as the
+/// genesis of the people chain does.
+///
+/// The chunks manager, the members and the coinage pallets are new on Asset Hub. The chunks can
+/// only be uploaded with `ChunksManager::add_chunks` once their page hashes are known, and the
+/// rings of the coinage collections can't be built before. Coinage itself stays inert until
+/// governance sets its underlying asset.
+pub struct InitializeChunkPageHashes;
+impl frame_support::traits::OnRuntimeUpgrade for InitializeChunkPageHashes {
+	fn on_runtime_upgrade() -> frame_support::weights::Weight {
+		use frame_support::traits::Get;
+		use indiv_support::traits::RingExponent;
+		use verifiable::ring::ark_vrf::suites::bandersnatch::BandersnatchSha512Ell2;
+
+		let db_weight = <Runtime as frame_system::Config>::DbWeight::get();
+		if indiv_pallet_chunks_manager::ChunkPageHashes::<Runtime>::iter_keys()
+			.next()
+			.is_some()
+		{
+			return db_weight.reads(1);
+		}
+
+		let mut weight = db_weight.reads(1);
+		for (exponent, page_hashes) in
+			indiv_support::genesis::ring_verifier_r2e9_r2e10_builder_params_hashes::<
+				BandersnatchSha512Ell2,
+			>(crate::ChunkPageSize::get())
+		{
+			let (Ok(ring_exponent), Ok(page_hashes)) =
+				(RingExponent::new_from_exponent(exponent), page_hashes.try_into())
+			else {
+				log::error!(
+					target: "runtime::migrations",
+					"invalid chunk page hashes for exponent {exponent}"
+				);
+				continue;
+			};
+			let count = page_hashes.len() as u32;
+			if let Err(e) = indiv_pallet_chunks_manager::Pallet::<Runtime>::set_chunk_page_hashes(
+				frame_system::RawOrigin::Root.into(),
+				ring_exponent,
+				page_hashes,
+			) {
+				log::error!(
+					target: "runtime::migrations",
+					"failed to set chunk page hashes for exponent {exponent}: {e:?}"
+				);
+			}
+			weight = weight.saturating_add(db_weight.writes(count.into()));
+		}
+
+		weight
+	}
+}
+
 /// Unreleased migrations. Add new ones here:
 pub type Unreleased = (
 	// Remove an old staking value
@@ -155,6 +210,8 @@ pub type Unreleased = (
 	MigrateBountyAccountAssets,
 	// Create the PGAS asset (id 2_000_000_000) used by the individuality pallets.
 	indiv_pallet_pgas::migration::CreatePgasAsset<Runtime>,
+	// Let the ring verifier chunks of the new coinage collections be uploaded.
+	InitializeChunkPageHashes,
 );
 
 /// Migrations/checks that do not need to be versioned and can run on every update.
@@ -33,11 +33,69 @@ impl Contains<RuntimeCall> for AhValueTransferFilter {
 			RuntimeCall::AssetConversion(inner) =>
 				asset_conversion_call_touches_protected_asset(inner),
 
+			// Coins are backed by the protected asset.
+			RuntimeCall::Coinage(inner) => coinage_call_requires_value_transfer_auth(inner),
+
 			_ => false,
 		}
 	}
 }
 
+fn coinage_call_requires_value_transfer_auth(call: &indiv_pallet_coinage::Call<Runtime>) -> bool {
+	use indiv_pallet_coinage::Call;
+	// Exhaustive on purpose: a newly-added coinage call won't compile here until it's
+	// explicitly classified as requiring value-transfer auth or not.
+	match call {
+		// Calls that spend or release coin-denominated value on behalf of the holder.
+		Call::split { .. } |
+		Call::transfer { .. } |
+		Call::merge { .. } |
+		Call::offer_swap { .. } |
+		Call::accept_swap_with_coin { .. } |
+		Call::accept_swap_with_asset { .. } |
+		Call::teleport_coin { .. } |
+		Call::load_recycler_with_coin { .. } |
+		Call::unload_recycler_into_coin { .. } |
+		Call::unload_recycler_into_external_asset { .. } |
+		Call::pay_for_recycler_unload_fee_token_with_coin { .. } |
+		Call::pay_for_recycler_unload_fee_token_with_native { .. } |
+		Call::pay_for_recycler_unload_fee_token_with_external_asset { .. } |
+		Call::unload_recycler_into_external_asset_and_vouchers { .. } |
+		Call::unload_recycler_into_external_asset_non_anonymous { .. } |
+		Call::unload_recyclers_into_external_asset_non_anonymous { .. } |
+		Call::unload_recycler_into_coins { .. } |
+		Call::direct_offboard_coin_into_external_asset { .. } => true,
+
+		// Loading a recycler from an external asset moves value *into* the coinage system
+		// from the caller's external-asset balance; it never spends an existing coin, so
+		// the value-transfer gate does not apply.
+		Call::load_recycler_with_external_asset { .. } |
+		Call::load_recycler_with_external_asset_unpaid { .. } |
+		Call::load_recycler_with_external_asset_unpaid_batch { .. } |
+		// Governance/admin configuration of the pallet's underlying asset; not a value transfer.
+		Call::set_underlying_asset_id { .. } |
+		// Permissionless/authorized housekeeping that only reclaims expired or dust state. These
+		// touch no holder's coin value (and several are authorized, not signed by a holder).
+		Call::clean_recycler { .. } |
+		Call::clean_consumed_free_token { .. } |
+		Call::clean_paid_unload_token_ring { .. } |
+		Call::clean_recycler_dust { .. } |
+		Call::clean_paid_unload_token_dust { .. } |
+		Call::delete_expired_paid_unload_token_collection { .. } |
+		// Settling a lapsed swap offer is authorized housekeeping, and cancelling one hands the
+		// offered coin back to its own maker.
+		Call::expire_swap { .. } |
+		Call::cancel_swap { .. } |
+		// Crediting a coin teleported from a linked chain, sent by that chain, and releasing a
+		// parked one to the destination it was teleported to.
+		Call::receive_teleported_coin { .. } |
+		Call::release_parked_coin { .. } => false,
+
+		// Uninhabited placeholder variant generated by FRAME; never constructed.
+		Call::__Ignore(..) => false,
+	}
+}
+
 fn assets_call_targets_protected_asset(
 	call: &pallet_assets::Call<Runtime, pallet_assets::Instance1>,
 ) -> bool {
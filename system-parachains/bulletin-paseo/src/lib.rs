@@ -68,6 +68,7 @@ use sp_runtime::{
 use sp_version::NativeVersion;
 use sp_version::RuntimeVersion;
 use system_parachains_constants::paseo::{
+	bulletin,
 	currency::{system_para_deposit, CENTS, SYSTEM_PARA_EXISTENTIAL_DEPOSIT, UNITS},
 	fee::TRANSACTION_BYTE_FEE,
 };
@@ -197,8 +198,8 @@ const BLOCK_PROCESSING_VELOCITY: u32 = 1;
 /// Relay chain slot duration, in milliseconds.
 const RELAY_CHAIN_SLOT_DURATION_MILLIS: u32 = 6000;
 /// Average expected block time targeted by the parachain. Picked up by `pallet_timestamp` and
-/// `pallet_aura`.
-const MILLISECS_PER_BLOCK: u64 = 6000;
+/// `pallet_aura`. Shared with the sibling chains timing their requests on this chain.
+const MILLISECS_PER_BLOCK: u64 = bulletin::MILLISECS_PER_BLOCK;
 /// 2 seconds of compute with a 6 second average block.
 const MAXIMUM_BLOCK_WEIGHT: Weight = Weight::from_parts(
 	WEIGHT_REF_TIME_PER_SECOND.saturating_mul(2),
@@ -691,6 +692,8 @@ mod runtime {
 	pub type TransactionStorage = pallet_bulletin_transaction_storage;
 	#[runtime::pallet_index(41)]
 	pub type HopPromotion = pallet_bulletin_hop_promotion;
+	#[runtime::pallet_index(42)]
+	pub type ContentIndex = indiv_pallet_content_index;
 
 	// Collator support. The order of these 5 are important and shall not change.
 	#[runtime::pallet_index(20)]
@@ -732,6 +735,7 @@ mod benches {
 		[pallet_session, SessionBench::<Runtime>]
 		[pallet_bulletin_transaction_storage, TransactionStorage]
 		[pallet_bulletin_hop_promotion, HopPromotion]
+		[indiv_pallet_content_index, ContentIndex]
 		[cumulus_pallet_xcmp_queue, XcmpQueue]
 		[pallet_xcm, PalletXcmExtrinsicsBenchmark::<Runtime>]
 		[pallet_message_queue, MessageQueue]
@@ -1052,6 +1056,18 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl indiv_pallet_content_index::runtime_api::ContentIndexApi<Block, AccountId, BlockNumber> for Runtime {
+		fn content_location(
+			content_hash: indiv_pallet_content_index::ContentHash,
+		) -> Option<indiv_pallet_content_index::ContentLocation<BlockNumber>> {
+			ContentIndex::content_location(content_hash)
+		}
+
+		fn pinners(content_hash: indiv_pallet_content_index::ContentHash) -> Vec<AccountId> {
+			ContentIndex::pinners(content_hash)
+		}
+	}
+
 	#[cfg(feature = "try-runtime")]
 	impl frame_try_runtime::TryRuntime<Block> for Runtime {
 		fn on_runtime_upgrade(checks: frame_try_runtime::UpgradeCheckSelect) -> (Weight, Weight) {
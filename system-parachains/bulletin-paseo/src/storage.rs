@@ -4,19 +4,21 @@
 //! Storage-specific configurations.
 
 use super::{
-	xcm_config::IsAuthorizerParachain, Runtime, RuntimeCall, RuntimeEvent, RuntimeHoldReason,
+	xcm_config::IsAuthorizerParachain, Balances, Runtime, RuntimeCall, RuntimeEvent,
+	RuntimeHoldReason,
 };
 use alloc::vec::Vec;
 use bulletin_pallets_common::{inspect_utility_wrapper, NoCurrency};
 use frame_support::{
 	parameter_types,
-	traits::{Contains, EitherOf},
+	traits::{fungible::HoldConsideration, Contains, EitherOf, LinearStoragePrice},
 };
 use pallet_bulletin_transaction_storage::{
 	AsAuthorizer, CallInspector, EnsureAllowedAuthorizers, DEFAULT_MAX_BLOCK_TRANSACTIONS,
 	DEFAULT_MAX_TRANSACTION_SIZE,
 };
 use pallet_xcm::EnsureXcm;
+use parachains_common::{AccountId, Balance};
 use sp_runtime::transaction_validity::{TransactionLongevity, TransactionPriority};
 
 parameter_types! {
@@ -27,7 +29,9 @@ parameter_types! {
 }
 
 parameter_types! {
-	pub const AuthorizationPeriod: crate::BlockNumber = 14 * crate::DAYS;
+	/// Two weeks. The People Chain expects authorizations to lapse after exactly this period.
+	pub const AuthorizationPeriod: crate::BlockNumber =
+		system_parachains_constants::paseo::bulletin::AUTHORIZATION_PERIOD;
 	// Priorities and longevities used by the transaction storage pallet extrinsics.
 	//
 	// `RemoveExpiredAuthorization` (permissionless cleanup) sits at the top so it always
@@ -117,3 +121,49 @@ impl pallet_bulletin_hop_promotion::Config for Runtime {
 	type SubmitTimestampTolerance = SubmitTimestampTolerance;
 	type WeightInfo = crate::weights::pallet_bulletin_hop_promotion::WeightInfo<Runtime>;
 }
+
+parameter_types! {
+	/// Maximum number of accounts that can be registered as pinners of a single piece of content.
+	pub const MaxPinnersPerContent: u32 = 16;
+	pub const PinDepositBase: Balance = crate::system_para_deposit(1, 0);
+	pub const PinDepositPerByte: Balance = crate::system_para_deposit(0, 1);
+	pub const PinHoldReason: RuntimeHoldReason =
+		RuntimeHoldReason::ContentIndex(indiv_pallet_content_index::HoldReason::Pin);
+}
+
+impl indiv_pallet_content_index::Config for Runtime {
+	type WeightInfo = indiv_pallet_content_index::weights::SubstrateWeight<Runtime>;
+	type MaxPinners = MaxPinnersPerContent;
+	type PinDeposit = HoldConsideration<
+		AccountId,
+		Balances,
+		PinHoldReason,
+		LinearStoragePrice<PinDepositBase, PinDepositPerByte, Balance>,
+	>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ContentIndexBenchmarkHelper;
+}
+
+/// Stores content through root calls to the transaction storage for the content index
+/// benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct ContentIndexBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl indiv_pallet_content_index::BenchmarkHelper<AccountId> for ContentIndexBenchmarkHelper {
+	fn store(who: &AccountId, count: u32) {
+		use crate::{RuntimeOrigin, TransactionStorage};
+
+		TransactionStorage::authorize_account(
+			RuntimeOrigin::root(),
+			who.clone(),
+			count,
+			u64::from(count) * 4,
+		)
+		.expect("root can authorize accounts");
+		for i in 0..count {
+			TransactionStorage::store(RuntimeOrigin::root(), i.to_le_bytes().to_vec())
+				.expect("root can store data");
+		}
+	}
+}
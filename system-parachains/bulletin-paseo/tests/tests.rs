@@ -5,14 +5,18 @@
 
 use bulletin_paseo_runtime as runtime;
 use bulletin_paseo_runtime::{
-	xcm_config::LocationToAccountId, AllPalletsWithoutSystem, Balances, Block, HopPromotion,
-	Runtime, RuntimeCall, RuntimeEvent, RuntimeGenesisConfig, RuntimeOrigin, SessionKeys, System,
-	TransactionStorage, TxExtension, UncheckedExtrinsic, WeightToFee,
+	xcm_config::LocationToAccountId, AllPalletsWithoutSystem, Balances, Block, ContentIndex,
+	HopPromotion, Runtime, RuntimeCall, RuntimeEvent, RuntimeGenesisConfig, RuntimeOrigin,
+	SessionKeys, System, TransactionStorage, TxExtension, UncheckedExtrinsic, WeightToFee,
 };
 use bulletin_transaction_storage_primitives::cids::{calculate_cid, CidConfig, HashingAlgorithm};
 use frame_support::{
-	assert_err, assert_ok, dispatch::GetDispatchInfo, pallet_prelude::Hooks, traits::Get,
+	assert_err, assert_ok,
+	dispatch::GetDispatchInfo,
+	pallet_prelude::Hooks,
+	traits::{fungible::Mutate, Get},
 };
+use indiv_pallet_content_index::Error as ContentIndexError;
 use pallet_bulletin_transaction_storage::{
 	extension::{AllowanceBasedPriority, ALLOWANCE_PRIORITY_BOOST},
 	AuthorizationExtent, AuthorizationScope, Call as TxStorageCall, Config as TxStorageConfig,
@@ -54,6 +58,7 @@ fn advance_block() {
 
 	<System as Hooks<_>>::on_initialize(next);
 	<TransactionStorage as Hooks<_>>::on_initialize(next);
+	<ContentIndex as Hooks<_>>::on_initialize(next);
 }
 
 fn construct_extrinsic(
@@ -410,6 +415,122 @@ fn renew_one_shot_prepays_bytes_permanent() {
 		});
 }
 
+#[test]
+fn content_index_tracks_stored_and_renewed_data() {
+	sp_io::TestExternalities::new(RuntimeGenesisConfig::default().build_storage().unwrap())
+		.execute_with(|| {
+			let account = Sr25519Keyring::Bob;
+			let who: AccountId = account.to_account_id();
+			let data = vec![0u8; 24];
+			let content_hash = sp_io::hashing::blake2_256(&data);
+			let retention_period = TransactionStorage::retention_period();
+
+			assert_ok!(TransactionStorage::authorize_account(
+				RuntimeOrigin::root(),
+				who.clone(),
+				2,
+				48,
+			));
+			let stored_block = System::block_number();
+			assert_ok_ok(construct_and_apply_extrinsic(
+				Some(account.pair()),
+				RuntimeCall::TransactionStorage(TxStorageCall::<Runtime>::store {
+					data: data.clone(),
+				}),
+			));
+
+			// Content is indexed once its block is finalized.
+			assert_eq!(ContentIndex::content_location(content_hash), None);
+			advance_block();
+			let stored = ContentIndex::content_location(content_hash).unwrap();
+			assert_eq!(stored.block, stored_block);
+			assert_eq!(stored.index, 0);
+			assert_eq!(stored.size, data.len() as u32);
+			assert_eq!(stored.expires_at, stored_block + retention_period + 1);
+
+			// Renewing moves the content to the renewing block.
+			let renewed_block = System::block_number();
+			assert_ok_ok(construct_and_apply_extrinsic(
+				Some(account.pair()),
+				RuntimeCall::TransactionStorage(TxStorageCall::<Runtime>::renew {
+					entry: TransactionRef::ContentHash(content_hash),
+				}),
+			));
+			advance_block();
+			let renewed = ContentIndex::content_location(content_hash).unwrap();
+			assert_eq!(renewed.block, renewed_block);
+			assert_eq!(renewed.expires_at, renewed_block + retention_period + 1);
+
+			// The original expiry no longer affects the renewed content.
+			<ContentIndex as Hooks<_>>::on_initialize(stored.expires_at);
+			assert_eq!(ContentIndex::content_location(content_hash), Some(renewed.clone()));
+
+			<ContentIndex as Hooks<_>>::on_initialize(renewed.expires_at);
+			assert_eq!(ContentIndex::content_location(content_hash), None);
+		});
+}
+
+#[test]
+fn content_index_pinning_requires_renewable_content() {
+	sp_io::TestExternalities::new(RuntimeGenesisConfig::default().build_storage().unwrap())
+		.execute_with(|| {
+			let account = Sr25519Keyring::Bob;
+			let who: AccountId = account.to_account_id();
+			let stranger: AccountId = Sr25519Keyring::Ferdie.to_account_id();
+			let data = vec![0u8; 24];
+			let content_hash = sp_io::hashing::blake2_256(&data);
+			let balance = 1_000_000_000_000;
+			assert_ok!(Balances::mint_into(&who, balance));
+
+			assert_ok!(TransactionStorage::authorize_account(
+				RuntimeOrigin::root(),
+				who.clone(),
+				2,
+				48,
+			));
+			assert_ok_ok(construct_and_apply_extrinsic(
+				Some(account.pair()),
+				RuntimeCall::TransactionStorage(TxStorageCall::<Runtime>::store { data }),
+			));
+			assert_err!(
+				ContentIndex::pin(RuntimeOrigin::signed(who.clone()), content_hash),
+				ContentIndexError::<Runtime>::UnknownContent
+			);
+			advance_block();
+
+			// Only accounts able to renew the content can pin it.
+			assert_err!(
+				ContentIndex::pin(RuntimeOrigin::signed(stranger.clone()), content_hash),
+				ContentIndexError::<Runtime>::CannotRenew
+			);
+			assert_ok!(ContentIndex::pin(RuntimeOrigin::signed(who.clone()), content_hash));
+			assert_err!(
+				ContentIndex::pin(RuntimeOrigin::signed(who.clone()), content_hash),
+				ContentIndexError::<Runtime>::AlreadyPinned
+			);
+			assert_eq!(ContentIndex::pinners(content_hash), vec![who.clone()]);
+
+			assert_err!(
+				ContentIndex::unpin(RuntimeOrigin::signed(stranger), content_hash),
+				ContentIndexError::<Runtime>::NotPinned
+			);
+			// Pinning holds a deposit.
+			assert!(Balances::free_balance(&who) < balance);
+			assert_ok!(ContentIndex::unpin(RuntimeOrigin::signed(who.clone()), content_hash));
+			assert!(ContentIndex::pinners(content_hash).is_empty());
+			assert_eq!(Balances::free_balance(&who), balance);
+
+			// Pins outlive the expired content, until the deposit is released by unpinning.
+			assert_ok!(ContentIndex::pin(RuntimeOrigin::signed(who.clone()), content_hash));
+			let location = ContentIndex::content_location(content_hash).unwrap();
+			<ContentIndex as Hooks<_>>::on_initialize(location.expires_at);
+			assert_eq!(ContentIndex::content_location(content_hash), None);
+			assert_eq!(ContentIndex::pinners(content_hash), vec![who.clone()]);
+			assert_ok!(ContentIndex::unpin(RuntimeOrigin::signed(who.clone()), content_hash));
+			assert_eq!(Balances::free_balance(&who), balance);
+		});
+}
+
 /// Run `AllowanceBasedPriority::validate` and return the contributed priority.
 fn allowance_based_priority(
 	origin: RuntimeOrigin,
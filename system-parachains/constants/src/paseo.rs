@@ -160,3 +160,19 @@ pub mod locations {
 		}
 	}
 }
+
+/// Constants of the Bulletin Chain that sibling chains depend on.
+pub mod bulletin {
+	use polkadot_core_primitives::BlockNumber;
+
+	/// Average expected block time of the Bulletin Chain.
+	pub const MILLISECS_PER_BLOCK: u64 = 6000;
+
+	/// How long a storage authorization stays valid on the Bulletin Chain, in its blocks.
+	pub const AUTHORIZATION_PERIOD: BlockNumber =
+		14 * 24 * 60 * 60 * 1000 / (MILLISECS_PER_BLOCK as BlockNumber);
+
+	/// [`AUTHORIZATION_PERIOD`] converted to seconds, for chains measuring it in time.
+	pub const AUTHORIZATION_PERIOD_SECS: u32 =
+		AUTHORIZATION_PERIOD * (MILLISECS_PER_BLOCK / 1000) as u32;
+}
@@ -26,6 +26,7 @@ use xcm::v5::{Junction::Parachain, Location};
 pub type Unreleased = (
 	cumulus_pallet_xcmp_queue::migration::v6::MigrateV5ToV6<Runtime>,
 	cumulus_pallet_parachain_system::migration::Migration<Runtime>,
+	indiv_pallet_mob_rule::migration::v1::MigrateToV1<Runtime>,
 );
 
 /// Migrations/checks that do not need to be versioned and can run on every update.
@@ -38,12 +39,17 @@ pub type SingleBlockMigrations = (Unreleased, Permanent);
 ///
 /// `pallet_assets::Config::ReserveData` changed from `()` to `ForeignAssetReserveData`, so the
 /// per-asset reserve entries must be backfilled from the previously hardcoded XCM rules.
-pub type MbmMigrations =
+///
+/// The game pallet runs its games per region, so its game, schedules, players and per-game maps
+/// move to the first region.
+pub type MbmMigrations = (
 	assets_common::migrations::foreign_assets_reserves::ForeignAssetsReservesMigration<
 		Runtime,
 		(),
 		PeoplePaseoAssetsReservesProvider,
-	>;
+	>,
+	indiv_pallet_game::migration::v1::MigrateToRegions<Runtime>,
+);
 
 fn reserve_data_for(asset_id: &Location) -> Option<ForeignAssetReserveData> {
 	let (parents, interior) = asset_id.unpack();
@@ -23,7 +23,7 @@ use parachains_common::{impls::ToParentTreasury, DAYS, MINUTES};
 use scale_info::TypeInfo;
 use sp_runtime::{
 	traits::{AccountIdConversion, Verify},
-	Debug,
+	Debug, SaturatedConversion,
 };
 use xcm::latest::prelude::BodyId;
 
@@ -262,7 +262,7 @@ use indiv_pallet_origin_restriction::Allowance;
 use indiv_support::traits::PersonalId;
 use indiv_support::{
 	fungibles::CombineAssetsWithHolder,
-	traits::{Alias, AllocateStorage, Context, RingIndex},
+	traits::{Alias, AllocateStorage, Context, RingIndex, StorageAllocationTicket},
 	utils::TypedGetToGet,
 };
 use paseo_runtime_constants::system_parachain::ASSET_HUB_ID;
@@ -311,6 +311,16 @@ parameter_types! {
 	/// so the effective member capacity is 255.
 	pub const MembersFlexibleRingExponent: indiv_support::traits::RingExponent =
 		indiv_support::traits::RingExponent::R2e9;
+	/// Rings of the people collection filled below a quarter of their capacity, typically after
+	/// a suspension round, are compacted into fuller rings to keep anonymity sets large.
+	pub const MembersRingCompactionThreshold: Percent = Percent::from_percent(25);
+	/// The weight of one ring-VRF membership proof verification, refunded for every verification
+	/// answered from the ring proof cache. Taken from the proof-carrying people-lite extension,
+	/// whose weight is dominated by the verification.
+	pub RingProofVerificationWeight: Weight =
+		<indiv_pallet_people_lite::weights::SubstrateWeight<Runtime> as
+			indiv_pallet_people_lite::WeightInfo>::as_lite_alias_with_proof_tx_ext()
+			.set_proof_size(0);
 	/// Controls the ring size for recycler rings in coinage append-only collections.
 	pub const RecyclerRingExponent: indiv_support::traits::RingExponent =
 		indiv_support::traits::RingExponent::R2e10;
@@ -332,6 +342,10 @@ parameter_types! {
 	pub const SelfInclusionDelayValue: u64 = 3600;
 }
 
+/// The members service of the pallets verifying ring proofs, sharing verifications within an
+/// extrinsic through the ring proof cache.
+pub type CachedMembers = indiv_support::ring_proof_cache::CachedMembershipProver<Members>;
+
 impl indiv_pallet_chunks_manager::Config for Runtime {
 	type WeightInfo = indiv_pallet_chunks_manager::weights::SubstrateWeight<Runtime>;
 	type Chunk = <BandersnatchVrfVerifiable as GenerateVerifiable>::StaticChunk;
@@ -351,9 +365,11 @@ impl indiv_pallet_members::Config for Runtime {
 	type OnboardingQueuePageSize = ConstU32<255>;
 	type MaxFlexibleRingExponent = MembersFlexibleRingExponent;
 	type RingBuildingMemberLimit = ConstU32<100>;
+	type RingCompactionThreshold = MembersRingCompactionThreshold;
 	/// 10 minutes in seconds for old root retention.
 	type OldRootRetentionDuration = ConstU64<600>;
 	type OnRingRootChange = MembersNotifier;
+	type CleanUpAlias = People;
 	type OffchainWorkerInterval = ConstU32<1>;
 	type ManagerOrigin = EnsureRoot<AccountId>;
 	#[cfg(feature = "runtime-benchmarks")]
@@ -368,7 +384,7 @@ parameter_types! {
 
 impl indiv_pallet_people::Config for Runtime {
 	type WeightInfo = indiv_pallet_people::weights::SubstrateWeight<Runtime>;
-	type MemberService = Members;
+	type MemberService = CachedMembers;
 	type RingExponent = MembersFlexibleRingExponent;
 	type CollectionOwner = PeopleCollectionOwner;
 	type AccountContexts = AccountContexts;
@@ -603,6 +619,12 @@ parameter_types! {
 	pub const VotingPenaltyDuration: BlockNumber = DAYS;
 	pub const OffchainWorkInterval: BlockNumber = 5 * MINUTES;
 	pub const MinimumVoterThreshold: u32 = 3;
+	// A voter who was always right weighs twice as much as a newcomer.
+	pub const MobRuleVoteWeighting: indiv_pallet_mob_rule::VoteWeighting =
+		indiv_pallet_mob_rule::VoteWeighting::TrackRecord { min: 2, max: 4, min_record: 5 };
+	pub const MobRuleAppealDeposit: Balance = 100 * CENTS;
+	pub const MobRuleAppealThreshold: Percent = Percent::from_percent(40);
+	pub const MobRuleAppealTurnoutPercentage: Percent = Percent::from_percent(20);
 }
 
 impl indiv_pallet_mob_rule::Config for Runtime {
@@ -626,6 +648,18 @@ impl indiv_pallet_mob_rule::Config for Runtime {
 	type CleanVotesBatchSize = ConstU32<1000>;
 	type VotesOpenForClaimsDuration = ConstU32<{ 10 * 60 }>;
 	type MinimumVoterThreshold = MinimumVoterThreshold;
+	type CommitDuration = ConstU32<{ 10 * 60 }>;
+	type RevealDuration = ConstU32<{ 5 * 60 }>;
+	type UnrevealedPenaltyDuration = VotingPenaltyDuration;
+	type Randomness = RelayChainRandomness<Runtime>;
+	type JurySize = ConstU32<25>;
+	type JurorTimeout = ConstU32<{ 5 * 60 }>;
+	type VoteWeighting = MobRuleVoteWeighting;
+	type AppealDeposit = MobRuleAppealDeposit;
+	type AppealThreshold = MobRuleAppealThreshold;
+	type AppealPeriod = ConstU32<{ 10 * 60 }>;
+	type AppealJurySize = ConstU32<50>;
+	type AppealTurnoutPercentage = MobRuleAppealTurnoutPercentage;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = MobRuleBenchHelper;
 }
@@ -653,6 +687,7 @@ parameter_types! {
 	pub const ProofOfInkByteDeposit: Balance = CENTS;
 	pub const ProofOfInkHoldReason: RuntimeHoldReason = RuntimeHoldReason::ProofOfInk(indiv_pallet_proof_of_ink::HoldReason::ProofOfInk);
 	pub const ProofOfInkPotId: PalletId = PalletId(*b"PoIPot__");
+	pub const ProofOfInkArtistRoyalty: Percent = Percent::from_percent(10);
 }
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -712,18 +747,25 @@ impl indiv_pallet_proof_of_ink::Config for Runtime {
 		LinearStoragePrice<ProofOfInkBaseDeposit, ProofOfInkByteDeposit, Balance>,
 	>;
 	type People = People;
+	type PeopleSuspension = People;
 	type EnsurePerson = indiv_pallet_people::EnsurePersonalIdentity<Runtime>;
 	type TicketSignature = MultiSignature;
 	type TicketPublic = MultiSigner;
 	type Ticket = AccountId;
 	type Oracle = MobRule;
-	type Randomness = SubjectBlockRandommess<Runtime>;
+	type Randomness = RelayChainRandomness<Runtime>;
 	type DataStore = BulletinDataStore;
 	type MaxActiveReferrals = ConstU32<10>;
 	type MaxRetryAttempts = ConstU32<1>;
 	type MaxReimbursementValues = ConstU32<50>;
+	// Re-attestation is disabled until evidence storage can be allocated to people.
+	type ReattestationPeriod = ConstU32<0>;
+	type ReattestationBatchSize = ConstU32<10>;
+	type ReattestationWindow = ConstU32<{ 14 * DAYS }>;
+	type ReattestationGracePeriod = ConstU32<{ 7 * DAYS }>;
 	type Currency = FungibleExternalAsset;
 	type PotId = ProofOfInkPotId;
+	type ArtistRoyalty = ProofOfInkArtistRoyalty;
 	type InvitationsOrigin = EnsureRoot<Self::AccountId>;
 	type ManagerOrigin = EnsureRoot<Self::AccountId>;
 	type Crypto = BandersnatchVrfVerifiable;
@@ -748,6 +790,40 @@ impl indiv_pallet_score::benchmarking::BenchmarkHelper<Runtime> for ScoreBenchma
 	}
 }
 
+/// The source of the personhood signal of accounts proven through proof-of-ink.
+pub const SCORE_SIGNAL_PROOF_OF_INK: indiv_pallet_score::SignalSource = 0;
+/// The source of the personhood signal of lite people with an honour history.
+pub const SCORE_SIGNAL_PEOPLE_LITE: indiv_pallet_score::SignalSource = 1;
+/// The honour of a lite person giving the full strength of their personhood signal.
+pub const SCORE_SIGNAL_FULL_LITE_HONOUR: u32 = 100;
+
+/// Personhood signals of other DIMs for the score of game participants.
+///
+/// The honour of a lite person is read with their account as subject.
+pub struct ScoreSignals;
+impl indiv_pallet_score::PersonhoodSignals<AccountId> for ScoreSignals {
+	fn strength(source: indiv_pallet_score::SignalSource, who: &AccountId) -> Percent {
+		match source {
+			SCORE_SIGNAL_PROOF_OF_INK =>
+				match indiv_pallet_proof_of_ink::Candidates::<Runtime>::get(who) {
+					Some(indiv_pallet_proof_of_ink::Candidate::Proven { .. }) =>
+						Percent::from_percent(100),
+					_ => Percent::from_percent(0),
+				},
+			SCORE_SIGNAL_PEOPLE_LITE
+				if indiv_pallet_people_lite::LitePeople::<Runtime>::contains_key(who) =>
+			{
+				let honour = <Honour as indiv_pallet_honour::inspect::Score>::read(who.as_ref());
+				Percent::from_rational(
+					honour.max(0).saturated_into::<u32>(),
+					SCORE_SIGNAL_FULL_LITE_HONOUR,
+				)
+			},
+			_ => Percent::from_percent(0),
+		}
+	}
+}
+
 impl indiv_pallet_score::Config for Runtime {
 	type WeightInfo = indiv_pallet_score::weights::SubstrateWeight<Runtime>;
 	type EnsurePerson = indiv_pallet_people::EnsurePersonalAliasInContext<Runtime>;
@@ -758,6 +834,7 @@ impl indiv_pallet_score::Config for Runtime {
 	type MaxPayoutRoundSchedules = ConstU32<10>;
 	type OffchainWorkInterval = ConstU32<2>;
 	type People = People;
+	type Signals = ScoreSignals;
 	type Crypto = BandersnatchVrfVerifiable;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ScoreBenchmarkHelper;
@@ -767,12 +844,16 @@ parameter_types! {
 	pub const PlayDepositReason: RuntimeHoldReason =
 		RuntimeHoldReason::Game(indiv_pallet_game::HoldReason::PlayDeposit);
 	pub const PlayDepositDefault: Balance = 2 * UNITS;
+	pub const AttendanceDisputeReason: RuntimeHoldReason =
+		RuntimeHoldReason::Game(indiv_pallet_game::HoldReason::AttendanceDispute);
+	pub const AttendanceDisputeDepositAmount: Balance = 5 * UNITS;
 	// TODO: Find a reasonable value
 	pub PlayerStatementLimit: StatementAllowance = StatementAllowance {
 		max_size: 1_000_000,
 		max_count: 1_000_000,
 	};
 	pub GameAirdropSource: AccountId = PalletId(*b"pop/gads").into_account_truncating();
+	pub const GameMinReportConsistency: Percent = Percent::from_percent(60);
 }
 
 impl indiv_pallet_game::Config for Runtime {
@@ -797,12 +878,32 @@ impl indiv_pallet_game::Config for Runtime {
 	// Weekly game cadence on devnet: 26 lets a single `schedule_games` sudo call queue
 	// ~6 months ahead, so the top-up keeper runs at most twice a year.
 	type MaxGameSchedules = ConstU32<26>;
+	type MaxScheduleRules = ConstU32<8>;
+	// Games of the schedule rules are scheduled four weeks ahead.
+	type ScheduleRuleHorizon = ConstU32<{ 28 * 24 * 3600 }>;
+	type MaxRegions = ConstU32<3>;
+	// Two games of `MaxRounds` rounds in full groups.
+	type MaxRecentCoPlayers = ConstU32<30>;
 	type MaxAttendanceHistoryDepth = ConstU32<12>;
 	type DefaultPhaseDurations = GamePhaseDurations;
 	type AccountSignature = Signature;
 	type PlayerStatementLimit = PlayerStatementLimit;
 	type PeopleVoteWeight = ConstUint<2>;
 	type CandidateVoteWeight = ConstUint<1>;
+	type AttendanceRule = indiv_pallet_game::DefaultAttendanceRule<Runtime>;
+	type MinReportConsistency = GameMinReportConsistency;
+	type MaxInconsistentReportStreak = ConstU32<3>;
+	type InconsistentReportPenalty = ConstU32<2>;
+	type Oracle = MobRule;
+	type AttendanceDisputeWindow = ConstU32<{ 7 * DAYS }>;
+	type AttendanceDisputeDeposit = HoldConsideration<
+		AccountId,
+		Balances,
+		AttendanceDisputeReason,
+		sp_runtime::traits::Identity,
+		Balance,
+	>;
+	type AttendanceDisputeDepositAmount = AttendanceDisputeDepositAmount;
 	// Production-realistic minimum: a group must hold at least 2 players for mutual
 	// verification to mean anything. Kept at the production floor (not 0) so devnet games
 	// exercise the same grouping behaviour as mainnet. Note: with a low active-player count
@@ -890,7 +991,7 @@ impl indiv_pallet_honour::benchmarking::BenchmarkHelper<Runtime> for HonourBench
 
 impl indiv_pallet_honour::Config for Runtime {
 	type WeightInfo = indiv_pallet_honour::weights::SubstrateWeight<Runtime>;
-	type MemberService = Members;
+	type MemberService = CachedMembers;
 	type Clock = Timestamp;
 	type PointFreezeDuration = HonourPointFreezeDuration;
 	type CallMortality = HonourCallMortality;
@@ -904,7 +1005,7 @@ parameter_types! {
 
 impl indiv_pallet_airdrop::Config for Runtime {
 	type WeightInfo = indiv_pallet_airdrop::weights::SubstrateWeight<Runtime>;
-	type MemberService = Members;
+	type MemberService = CachedMembers;
 	type Fungibles = AssetsWithHolder;
 	type ManagerOrigin = EnsureRoot<Self::AccountId>;
 	type PalletId = AirdropPalletId;
@@ -1156,6 +1257,8 @@ parameter_types! {
 	pub PeopleChainParaId: ParaId = parachain_info::Pallet::<Runtime>::parachain_id();
 	/// XCM destination location for the Bulletin Chain.
 	pub BulletinChainLocation: Location = Location::new(1, [Parachain(BULLETIN_CHAIN_PARA_ID)]);
+	/// How long to wait for the Bulletin Chain to acknowledge a storage authorization, in blocks.
+	pub const LongTermStorageAckTimeout: BlockNumber = 10 * MINUTES;
 }
 
 impl indiv_pallet_storage_initialization::Config for Runtime {
@@ -1216,7 +1319,7 @@ mod bench_xcm_sender {
 impl indiv_pallet_people_lite::Config for Runtime {
 	type WeightInfo = indiv_pallet_people_lite::weights::SubstrateWeight<Runtime>;
 	type AttestationAllowanceManager = EnsureRoot<Self::AccountId>;
-	type MemberService = Members;
+	type MemberService = CachedMembers;
 	type CollectionOwner = LitePeopleCollectionOwner;
 	type LiteRingExponent = LitePeopleRingExponent;
 	type LiteOnboardingSize = LitePeopleOnboardingSize;
@@ -1262,6 +1365,14 @@ parameter_types! {
 	pub const LongTermStoragePeriodDuration: u32 = 14 * 24 * 60 * 60; // 2 weeks
 	pub const LongTermStorageClaimsPerPeriod: u8 = 100;
 	pub const LongTermStorageCleanupLimit: u32 = 20;
+	/// `AuthorizationPeriod` of the Bulletin Chain, converted from its blocks to seconds.
+	pub const LongTermStorageAuthorizationPeriod: u32 =
+		system_parachains_constants::paseo::bulletin::AUTHORIZATION_PERIOD_SECS;
+	/// `LongTermStorageAckTimeout` in seconds: requests left unacknowledged are sent again.
+	pub const LongTermStorageAckTimeoutSecs: u32 = LongTermStorageAckTimeout::get() *
+		(parachains_common::MILLISECS_PER_BLOCK / 1000) as u32;
+	pub const LongTermStorageRefreshWindow: u32 = 24 * 60 * 60; // 1 day
+	pub const LongTermStorageMaxRefreshes: u8 = 1;
 	pub LongTermStorageAllowanceForPeople: indiv_pallet_resources::types::LongTermStorageAllocation =
 		indiv_pallet_resources::types::LongTermStorageAllocation {
 			transactions: 100,
@@ -1276,7 +1387,7 @@ parameter_types! {
 
 impl indiv_pallet_resources::Config for Runtime {
 	type WeightInfo = indiv_pallet_resources::weights::SubstrateWeight<Runtime>;
-	type MemberService = Members;
+	type MemberService = CachedMembers;
 	type MaxUsernameLength = MaxUsernameLength;
 	type MinUsernameLength = MinUsernameLength;
 	type PersonAuthDuration = PersonAuthDuration;
@@ -1309,6 +1420,12 @@ impl indiv_pallet_resources::Config for Runtime {
 	type LongTermStorageAllowanceForLitePeople = LongTermStorageAllowanceForLitePeople;
 	type LongTermStorageDataStore = BulletinDataStore;
 	type LongTermStorageCleanupLimit = LongTermStorageCleanupLimit;
+	type LongTermStorageAuthorizationPeriod = LongTermStorageAuthorizationPeriod;
+	type LongTermStorageRefreshWindow = LongTermStorageRefreshWindow;
+	type LongTermStorageMaxRefreshes = LongTermStorageMaxRefreshes;
+	type LongTermStorageAckTimeout = LongTermStorageAckTimeoutSecs;
+	type LongTermStorageAckOrigin =
+		pallet_xcm::EnsureResponse<frame_support::traits::Equals<BulletinChainLocation>>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = benchmark_utils::ResourcesBenchHelper;
 }
@@ -1510,10 +1627,57 @@ impl indiv_pallet_coinage::BenchmarkHelper<Runtime> for CoinageBenchHelper {
 
 		LitePeopleProof { proof, ring: ring_index }
 	}
+
+	fn sign_merge_input(
+		index: u32,
+		payload: &[u8],
+	) -> Result<(AccountId, Signature), frame_benchmarking::BenchmarkError> {
+		let seed = u64::from(index);
+		Ok((
+			GamePalletBenchmarkHelper::create_account_id(seed),
+			GamePalletBenchmarkHelper::sign(seed, payload),
+		))
+	}
+
+	fn setup_teleport_destination(
+	) -> Result<cumulus_primitives_core::ParaId, frame_benchmarking::BenchmarkError> {
+		use cumulus_pallet_parachain_system::RelevantMessagingState;
+		use cumulus_primitives_core::relay_chain::AbridgedHrmpChannel;
+
+		// Fake HRMP egress channel to Asset Hub so that teleports can be delivered.
+		let channel = AbridgedHrmpChannel {
+			max_capacity: 1000,
+			max_total_size: 1_000_000,
+			max_message_size: 100_000,
+			msg_count: 0,
+			total_size: 0,
+			mqc_head: None,
+		};
+		let messaging_state =
+			cumulus_pallet_parachain_system::relay_state_snapshot::MessagingStateSnapshot {
+				dmq_mqc_head: Default::default(),
+				relay_dispatch_queue_remaining_capacity: Default::default(),
+				ingress_channels: Vec::new(),
+				egress_channels: vec![(ASSET_HUB_ID.into(), channel)],
+			};
+		RelevantMessagingState::<Runtime>::put(messaging_state);
+
+		// Subscribe Asset Hub to the rings of this chain, 97 being the index of its members
+		// subscriber pallet.
+		indiv_pallet_members_notifier::Subscribers::<Runtime>::insert(
+			cumulus_primitives_core::ParaId::from(ASSET_HUB_ID),
+			indiv_pallet_members_notifier::SubscriberInfo {
+				collections: Default::default(),
+				last_init_sequence: 0,
+				pallet_index: 97,
+			},
+		);
+		Ok(ASSET_HUB_ID.into())
+	}
 }
 
 impl indiv_pallet_coinage::Config for Runtime {
-	type MemberService = Members;
+	type MemberService = CachedMembers;
 	type CollectionOwner = CoinageCollectionOwner;
 	type RecyclerRingExponent = RecyclerRingExponent;
 	type PaidUnloadTokenRingExponent = PaidUnloadTokenRingExponent;
@@ -1552,6 +1716,30 @@ impl indiv_pallet_coinage::Config for Runtime {
 	type FeeDestination = TypedGetToGet<pallet_collator_selection::StakingPotAccountId<Runtime>>;
 	type OffchainWorkerInterval = ConstU32<4>; // higher in prod
 	type CoinFailureLockPeriod = ConstU64<60>;
+	type MaxSwapDuration = ConstU64<{ 24 * 60 * 60 }>; // 1 day
+	type CoinSignature = Signature;
+	type TeleportDestinations = SubscribedCoinTeleportDestinations;
+	type XcmSender = crate::xcm_config::XcmRouter;
+	type EnsureTeleportSource = EnsureSiblingParachain;
+	type UnderlyingAssetLocation = sp_runtime::traits::Identity;
+	type AccountIdToLocation = xcm_builder::AliasesIntoAccountId32<(), AccountId>;
+	type UniversalLocation = crate::xcm_config::UniversalLocation;
+}
+
+parameter_types! {
+	/// Index of the coinage pallet in the Asset Hub `construct_runtime!`.
+	pub const AssetHubCoinagePalletIndex: u8 = 96;
+}
+
+/// Coins can be teleported to and from Asset Hub while it is subscribed to the rings of this
+/// chain through the members notifier.
+pub struct SubscribedCoinTeleportDestinations;
+impl indiv_pallet_coinage::CoinTeleportDestinations for SubscribedCoinTeleportDestinations {
+	fn coinage_pallet_index(para_id: cumulus_primitives_core::ParaId) -> Option<u8> {
+		(u32::from(para_id) == ASSET_HUB_ID &&
+			indiv_pallet_members_notifier::Subscribers::<Runtime>::contains_key(para_id))
+		.then(AssetHubCoinagePalletIndex::get)
+	}
 }
 
 /// Origin check that validates the caller is a sibling parachain and extracts its `ParaId`.
@@ -1599,27 +1787,49 @@ parameter_types! {
 	pub ConstantWeight: Weight = Weight::from_parts(10_000, 0);
 }
 
-pub struct SubjectBlockRandommess<Runtime>(PhantomData<Runtime>);
-impl<Runtime> Randomness<[u8; 32], u32> for SubjectBlockRandommess<Runtime>
+/// Randomness for a subject derived from the relay-chain VRF output of the relay parent, read
+/// from the relay state proof of the current block.
+///
+/// The hash of the block being executed is not known yet, and the parachain block author only
+/// chooses among a few relay parents, each with a VRF output fixed by the relay chain. Falls
+/// back to the parent block hash if the relay state proof is not available.
+pub struct RelayChainRandomness<Runtime>(PhantomData<Runtime>);
+impl<Runtime> RelayChainRandomness<Runtime>
+where
+	Runtime: cumulus_pallet_parachain_system::Config + parachain_info::Config,
+{
+	/// The VRF output of the relay parent, along with the relay parent number.
+	fn relay_randomness() -> Option<([u8; 32], u32)> {
+		let validation_data = cumulus_pallet_parachain_system::ValidationData::<Runtime>::get()?;
+		let proof = cumulus_pallet_parachain_system::RelayStateProof::<Runtime>::get()?;
+		let relay_state =
+			cumulus_pallet_parachain_system::relay_state_snapshot::RelayChainStateProof::new(
+				parachain_info::Pallet::<Runtime>::parachain_id(),
+				validation_data.relay_parent_storage_root,
+				proof,
+			)
+			.ok()?;
+		let randomness = relay_state
+			.read_optional_entry::<Option<[u8; 32]>>(
+				cumulus_primitives_core::relay_chain::well_known_keys::CURRENT_BLOCK_RANDOMNESS,
+			)
+			.ok()
+			.flatten()
+			.flatten()?;
+		Some((randomness, validation_data.relay_parent_number))
+	}
+}
+
+impl<Runtime> Randomness<[u8; 32], u32> for RelayChainRandomness<Runtime>
 where
-	Runtime: frame_system::Config,
+	Runtime: cumulus_pallet_parachain_system::Config + parachain_info::Config,
 	[u8; 32]: From<<Runtime as frame_system::Config>::Hash>,
 {
 	fn random(subject: &[u8]) -> ([u8; 32], u32) {
-		// hash subject into 32 bytes
-		let subject_hash = subject.using_encoded(sp_io::hashing::blake2_256);
-
-		// hash current block into 32 bytes
-		let block_number = frame_system::Pallet::<Runtime>::block_number();
-		let block_hash = frame_system::Pallet::<Runtime>::block_hash(block_number);
-		let block_hash: [u8; 32] = block_hash.into();
-
-		// bitwise XOR on subject hash and block hash
-		let mut randomness = [0u8; 32];
-		for byte in 0..subject_hash.len() {
-			randomness[byte] = subject_hash[byte] ^ block_hash[byte];
-		}
-		(randomness, 0)
+		let (randomness, known_since) = Self::relay_randomness()
+			.unwrap_or_else(|| (frame_system::Pallet::<Runtime>::parent_hash().into(), 0));
+		let output = (subject, randomness).using_encoded(sp_io::hashing::blake2_256);
+		(output, known_since)
 	}
 }
 
@@ -1656,90 +1866,125 @@ enum TransactionStorageCalls<AccountId: Encode> {
 
 #[allow(unused)]
 pub struct BulletinDataStore;
-impl AllocateStorage<AccountId> for BulletinDataStore {
-	fn allocate_storage(who: &AccountId, len: u64, count: u32) -> DispatchResult {
-		use crate::people::TransactionStorageCalls::AuthorizeAccount;
+impl BulletinDataStore {
+	/// Send `call` to the Bulletin Chain for execution with the People Chain as origin.
+	///
+	/// If `ack` is set, the Bulletin Chain reports the dispatch outcome back, which is delivered
+	/// to `Resources::acknowledge_long_term_storage` under the returned query id.
+	fn send(
+		call: BulletinPallets<AccountId>,
+		ack: bool,
+	) -> Result<Option<xcm::latest::QueryId>, sp_runtime::DispatchError> {
+		use indiv_pallet_resources::WeightInfo as _;
+		use xcm::latest::prelude::{QueryResponseInfo, ReportTransactStatus, Response};
+		type ResourcesWeight = <Runtime as indiv_pallet_resources::Config>::WeightInfo;
 
 		let destination = BulletinChainLocation::get();
-		let authorize = BulletinPallets::<AccountId>::TransactionStorage(AuthorizeAccount(
-			who.clone(),
-			count,
-			len,
-		));
 
 		// The program to execute on the Bulletin Chain.
-		let program = alloc::vec![
+		let mut program = alloc::vec![
 			UnpaidExecution { weight_limit: WeightLimit::Unlimited, check_origin: None },
 			Transact {
 				origin_kind: OriginKind::Xcm,
 				fallback_max_weight: None,
-				call: authorize.encode().into(),
+				call: call.encode().into(),
 			},
-		]
-		.into();
-
-		// send
-		#[allow(clippy::bind_instead_of_map)]
-		send_xcm::<xcm_config::XcmRouter>(destination, program)
-			.map(|_| ())
-			.or_else(|e| {
-				// Ignore errors during benchmarks.
-				// TODO: maybe revisit
-				#[cfg(feature = "runtime-benchmarks")]
-				{
-					let _ = e;
-					Ok::<(), ()>(())
-				}
+		];
+
+		// Don't leave a registered query behind if the message cannot be sent.
+		frame_support::storage::with_storage_layer(|| {
+			let query_id = ack.then(|| {
+				let notify = RuntimeCall::Resources(
+					indiv_pallet_resources::Call::acknowledge_long_term_storage {
+						query_id: 0,
+						response: Response::Null,
+					},
+				);
+				let query_id = PolkadotXcm::new_notify_query(
+					destination.clone(),
+					notify,
+					frame_system::Pallet::<Runtime>::block_number()
+						.saturating_add(LongTermStorageAckTimeout::get()),
+					Location::here(),
+				);
+				program.push(ReportTransactStatus(QueryResponseInfo {
+					destination: Location::new(1, [Parachain(PeopleChainParaId::get().into())]),
+					query_id,
+					max_weight: ResourcesWeight::acknowledge_long_term_storage(),
+				}));
+				query_id
+			});
+
+			// send
+			#[allow(clippy::bind_instead_of_map)]
+			send_xcm::<xcm_config::XcmRouter>(destination, program.into())
+				.map(|_| ())
+				.or_else(|e| {
+					// Ignore errors during benchmarks.
+					// TODO: maybe revisit
+					#[cfg(feature = "runtime-benchmarks")]
+					{
+						let _ = e;
+						Ok::<(), ()>(())
+					}
+
+					#[cfg(not(feature = "runtime-benchmarks"))]
+					{
+						Err(e)
+					}
+				})
+				.map_err(|_| pallet_xcm::Error::<Runtime>::SendFailure)?;
+
+			Ok(query_id)
+		})
+	}
+}
 
-				#[cfg(not(feature = "runtime-benchmarks"))]
-				{
-					Err(e)
-				}
-			})
-			.map_err(|_| pallet_xcm::Error::<Runtime>::SendFailure)?;
+impl AllocateStorage<AccountId> for BulletinDataStore {
+	fn allocate_storage(who: &AccountId, len: u64, count: u32) -> DispatchResult {
+		use crate::people::TransactionStorageCalls::AuthorizeAccount;
 
-		Ok(())
+		let authorize = BulletinPallets::<AccountId>::TransactionStorage(AuthorizeAccount(
+			who.clone(),
+			count,
+			len,
+		));
+		Self::send(authorize, false).map(|_| ())
 	}
 
 	fn refresh_allocation(who: &AccountId) -> DispatchResult {
 		use crate::people::TransactionStorageCalls::RefreshAccountAuthorization;
 
-		let destination = BulletinChainLocation::get();
 		let refresh = BulletinPallets::<AccountId>::TransactionStorage(
 			RefreshAccountAuthorization(who.clone()),
 		);
+		Self::send(refresh, false).map(|_| ())
+	}
 
-		// The program to execute on the Bulletin Chain.
-		let program = alloc::vec![
-			UnpaidExecution { weight_limit: WeightLimit::Unlimited, check_origin: None },
-			Transact {
-				origin_kind: OriginKind::Xcm,
-				fallback_max_weight: None,
-				call: refresh.encode().into(),
-			},
-		]
-		.into();
-
-		// send
-		#[allow(clippy::bind_instead_of_map)]
-		send_xcm::<xcm_config::XcmRouter>(destination, program)
-			.map(|_| ())
-			.or_else(|e| {
-				// Ignore errors during benchmarks.
-				// TODO: maybe revisit
-				#[cfg(feature = "runtime-benchmarks")]
-				{
-					let _ = e;
-					Ok::<(), ()>(())
-				}
+	fn allocate_storage_with_ack(
+		who: &AccountId,
+		len: u64,
+		count: u32,
+	) -> Result<Option<StorageAllocationTicket>, sp_runtime::DispatchError> {
+		use crate::people::TransactionStorageCalls::AuthorizeAccount;
 
-				#[cfg(not(feature = "runtime-benchmarks"))]
-				{
-					Err(e)
-				}
-			})
-			.map_err(|_| pallet_xcm::Error::<Runtime>::SendFailure)?;
-		Ok(())
+		let authorize = BulletinPallets::<AccountId>::TransactionStorage(AuthorizeAccount(
+			who.clone(),
+			count,
+			len,
+		));
+		Self::send(authorize, true)
+	}
+
+	fn refresh_allocation_with_ack(
+		who: &AccountId,
+	) -> Result<Option<StorageAllocationTicket>, sp_runtime::DispatchError> {
+		use crate::people::TransactionStorageCalls::RefreshAccountAuthorization;
+
+		let refresh = BulletinPallets::<AccountId>::TransactionStorage(
+			RefreshAccountAuthorization(who.clone()),
+		);
+		Self::send(refresh, true)
 	}
 }
 
@@ -45,6 +45,11 @@ fn coinage_call_requires_value_transfer_auth(call: &indiv_pallet_coinage::Call<R
 		// Calls that spend or release coin-denominated value on behalf of the holder.
 		Call::split { .. } |
 		Call::transfer { .. } |
+		Call::merge { .. } |
+		Call::offer_swap { .. } |
+		Call::accept_swap_with_coin { .. } |
+		Call::accept_swap_with_asset { .. } |
+		Call::teleport_coin { .. } |
 		Call::load_recycler_with_coin { .. } |
 		Call::unload_recycler_into_coin { .. } |
 		Call::unload_recycler_into_external_asset { .. } |
@@ -72,7 +77,15 @@ fn coinage_call_requires_value_transfer_auth(call: &indiv_pallet_coinage::Call<R
 		Call::clean_paid_unload_token_ring { .. } |
 		Call::clean_recycler_dust { .. } |
 		Call::clean_paid_unload_token_dust { .. } |
-		Call::delete_expired_paid_unload_token_collection { .. } => false,
+		Call::delete_expired_paid_unload_token_collection { .. } |
+		// Settling a lapsed swap offer is authorized housekeeping, and cancelling one hands the
+		// offered coin back to its own maker.
+		Call::expire_swap { .. } |
+		Call::cancel_swap { .. } |
+		// Crediting a coin teleported from a linked chain, sent by that chain, and releasing a
+		// parked one to the destination it was teleported to.
+		Call::receive_teleported_coin { .. } |
+		Call::release_parked_coin { .. } => false,
 
 		// Uninhabited placeholder variant generated by FRAME; never constructed.
 		Call::__Ignore(..) => false,